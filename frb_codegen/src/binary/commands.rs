@@ -1,6 +1,12 @@
-use crate::codegen::ConfigDumpContent;
+use crate::codegen::{ConfigDumpContent, IrDumpFormat, IrDumpStage, MinimalOutput};
 use clap::{Args, Parser, Subcommand, ValueEnum};
+use lib_flutter_rust_bridge_codegen::build_android::AndroidAbi;
+use lib_flutter_rust_bridge_codegen::build_apple::ApplePlatform;
 use lib_flutter_rust_bridge_codegen::misc::Template;
+use lib_flutter_rust_bridge_codegen::sbom::SbomFormat;
+use lib_flutter_rust_bridge_codegen::test::TestPlatform;
+use lib_flutter_rust_bridge_codegen::utils::command_options::ShellMode;
+use lib_flutter_rust_bridge_codegen::utils::message_format::MessageFormat;
 use std::path::PathBuf;
 
 // The name `Cli`, `Commands` come from https://docs.rs/clap/latest/clap/_derive/_tutorial/chapter_0/index.html
@@ -11,10 +17,45 @@ pub(crate) struct Cli {
     #[arg(short, long)]
     pub verbose: bool,
 
+    /// Format of the output, where `json` emits one event per line on stdout for IDEs and CI
+    #[arg(long, value_enum, default_value = "human")]
+    pub message_format: MessageFormatArg,
+
+    /// Show the output of external commands (e.g. `cargo`, `dart`) while they are running
+    #[arg(long)]
+    pub stream_command_output: bool,
+
+    /// Kill external commands which do not finish within this number of seconds
+    #[arg(long, value_name = "SECONDS")]
+    pub command_timeout: Option<u64>,
+
+    /// Launch external commands via this shell, instead of executing them directly
+    #[arg(long, value_enum, default_value = "direct")]
+    pub shell: ShellArg,
+
     #[command(subcommand)]
     pub(crate) command: Commands,
 }
 
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum MessageFormatArg {
+    /// Progress bars and colored logs
+    Human,
+    /// Events such as stage start/finish, warnings and generated files, one JSON object per line
+    Json,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum ShellArg {
+    /// No shell, thus the arguments are passed without quoting
+    Direct,
+    Sh,
+    Powershell,
+    #[value(name = "nu")]
+    Nushell,
+    Fish,
+}
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Subcommand)]
 pub(crate) enum Commands {
@@ -30,6 +71,30 @@ pub(crate) enum Commands {
     /// Compile for the Web (WASM)
     BuildWeb(BuildWebCommandArgs),
 
+    /// Compile for Android via cargo-ndk, and copy the libraries into the Android project
+    BuildAndroid(BuildAndroidCommandArgs),
+
+    /// Compile for iOS and macOS into an XCFramework, and add it to the podspec of the plugin
+    BuildApple(BuildAppleCommandArgs),
+
+    /// Run the Rust tests, and the Dart tests against both the native library and the WASM build
+    Test(TestCommandArgs),
+
+    /// Generate the API reference of the Rust code, showing what each function and type looks like in Dart
+    Docs(DocsCommandArgs),
+
+    /// Dump the parsed intermediate representation, e.g. for API diffing or lint rules in external tools
+    Dump(DumpCommandArgs),
+
+    /// Check whether the API changes incompatibly compared with a committed snapshot, e.g. in CI
+    CheckCompat(CheckCompatCommandArgs),
+
+    /// Generate SBOM (software bill of materials) of the Rust dependencies bundled into the app
+    Sbom(SbomCommandArgs),
+
+    /// Check whether the tools needed by the code generator are installed and new enough
+    Doctor(DoctorCommandArgs),
+
     /// Generate internally used code
     #[clap(hide = true)]
     InternalGenerate(InternalGenerateCommandArgs),
@@ -85,10 +150,22 @@ pub(crate) struct GenerateCommandArgsPrimary {
     #[arg(long)]
     pub dart_entrypoint_class_name: Option<String>,
 
-    /// Line length for Dart formatting
+    /// Line length for Dart formatting, otherwise `formatter.page_width` in `analysis_options.yaml` or 80
     #[arg(long)]
     pub dart_format_line_length: Option<u32>,
 
+    /// Command to format the generated Dart code instead of `dart format`, which receives the file paths as extra arguments
+    #[arg(long, num_args = 1.., allow_hyphen_values = true)]
+    pub dart_format_command: Option<Vec<String>>,
+
+    /// Skip formatting the generated Rust code
+    #[arg(long)]
+    pub no_rust_format: bool,
+
+    /// Command to format the generated Rust code instead of `rustfmt`, which receives the file paths as extra arguments
+    #[arg(long, num_args = 1.., allow_hyphen_values = true)]
+    pub rust_format_command: Option<Vec<String>>,
+
     /// Raw header of output generated Dart code, pasted as-it-is.
     #[arg(long)]
     pub dart_preamble: Option<String>,
@@ -137,14 +214,26 @@ pub(crate) struct GenerateCommandArgsPrimary {
     #[arg(long)]
     pub default_external_library_loader_web_prefix: Option<String>,
 
+    /// The operating systems (e.g. `ios`) where defaultExternalLibraryLoader uses `DynamicLibrary.process()`
+    #[arg(long, num_args = 1..)]
+    pub default_external_library_loader_io_process: Option<Vec<String>>,
+
     /// Disable language features introduced in Dart 3.
     #[arg(long)]
     pub no_dart3: bool,
 
+    /// Generate enums with associated data as plain Dart 3 sealed classes instead of freezed classes
+    #[arg(long)]
+    pub dart_sealed_enums: bool,
+
     /// Enable full dependencies
     #[arg(long)]
     pub full_dep: bool,
 
+    /// Ensure the generated code can be called via `MethodChannelHandler`, which does not support `--full-dep`
+    #[arg(long)]
+    pub method_channel: bool,
+
     /// Use local version instead of the release version
     #[arg(long, hide = true)]
     pub local: bool,
@@ -165,6 +254,54 @@ pub(crate) struct GenerateCommandArgsPrimary {
     #[arg(long)]
     pub stop_on_error: bool,
 
+    /// Only parse the third-party crates in `rust_input` and `--third-party` that are referenced by the first-party bridged code
+    #[arg(long)]
+    pub lazy_third_party: bool,
+
+    /// Dependency crates to scan for types, so that the plain-data types used by the API are mirrored automatically instead of being opaque
+    #[arg(long, num_args = 1..)]
+    pub third_party: Option<Vec<String>>,
+
+    /// Omit debugging niceties (e.g. function names, argument lists and panic backtraces) from the generated code to reduce binary size, in all builds or only in release builds. If specified without a value, defaults to always.
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "always")]
+    pub minimal_output: Option<MinimalOutput>,
+
+    /// Also generate `frb_generated.mocks.dart` with fakes of the API and opaque types, for tests without the Rust library
+    #[arg(long)]
+    pub dart_mocks: bool,
+
+    /// Omit the flutter_rust_bridge version from the header comment of generated files
+    #[arg(long)]
+    pub no_version_in_header: bool,
+
+    /// Name of this version of the API (e.g. `v2`), when several versions are generated side by side into the same crate
+    #[arg(long)]
+    pub api_version: Option<String>,
+
+    /// Generator plugins (dynamic libraries, or `.wasm` files run by `wasmtime`), which receive the resolved API and emit additional files
+    #[arg(long, num_args = 1..)]
+    pub plugins: Option<Vec<String>>,
+
+    /// Do not reuse the outputs of cargo-expand, ffigen and cbindgen cached in `target/frb_codegen_cache` from previous runs
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Additional paths to watch in `--watch` mode, such as shared crates in the same workspace
+    #[arg(long, num_args = 1..)]
+    pub watch_paths: Option<Vec<String>>,
+
+    /// Glob patterns of paths whose changes are ignored in `--watch` mode, in addition to `**/target/**` and the generated files
+    #[arg(long, num_args = 1..)]
+    pub watch_ignore: Option<Vec<String>>,
+
+    /// Poll for file changes in `--watch` mode instead of using native notifications, which may not work on network filesystems or Docker volumes
+    #[arg(long)]
+    pub watch_poll: bool,
+
+    /// Interval between polls when `--watch-poll` is enabled
+    #[arg(long)]
+    pub watch_poll_interval_ms: Option<u64>,
+
     /// A list of data to be dumped. If specified without a value, defaults to all.
     #[arg(long, value_enum, num_args = 0.., default_missing_values = ["config", "ir"])]
     pub dump: Option<Vec<ConfigDumpContent>>,
@@ -197,6 +334,14 @@ pub(crate) struct IntegrateCommandArgs {
     #[arg(long)]
     pub(crate) no_enable_integration_test: bool,
 
+    /// Overwrite existing files with the template without asking
+    #[arg(long, conflicts_with = "skip_existing")]
+    pub(crate) force: bool,
+
+    /// Keep existing files untouched without asking
+    #[arg(long)]
+    pub(crate) skip_existing: bool,
+
     #[clap(flatten)]
     pub common: CreateOrIntegrateCommandCommonArgs,
 
@@ -240,11 +385,239 @@ pub(crate) struct BuildWebCommandArgs {
     #[arg(long, hide = true)]
     pub dart_coverage: bool,
 
+    /// Port of the VM service to collect the Dart coverage from
+    #[arg(long, default_value_t = 8181, hide = true)]
+    pub vm_service_port: u16,
+
+    /// Packages to collect the Dart coverage of, otherwise all packages
+    #[arg(long, num_args = 1.., hide = true)]
+    pub coverage_scope: Vec<String>,
+
     // https://stackoverflow.com/questions/72399790/clap-capture-all-remaining-arguments-in-one-field-in-derive-api
     #[arg(trailing_var_arg = true, allow_hyphen_values = true, hide = true)]
     pub(crate) args: Vec<String>,
 }
 
+#[derive(Debug, Args)]
+pub(crate) struct BuildAndroidCommandArgs {
+    /// Path to root of Dart project, otherwise inferred from current working directory
+    #[arg(long)]
+    pub dart_root: Option<PathBuf>,
+
+    /// Directory of the Rust crate, relative to the Dart project
+    #[arg(long, default_value = "rust")]
+    pub rust_root: PathBuf,
+
+    /// Android ABIs to compile for
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "arm64-v8a,armeabi-v7a,x86_64"
+    )]
+    pub abis: Vec<AndroidAbiArg>,
+
+    /// Minimum Android API level, otherwise the default of cargo-ndk
+    #[arg(long)]
+    pub platform: Option<u32>,
+
+    /// Compile in debug mode instead of release mode
+    #[arg(long)]
+    pub debug: bool,
+
+    /// Strip the symbols to reduce the size of the libraries
+    #[arg(long)]
+    pub strip: bool,
+
+    /// Directory to copy the libraries into, relative to the Dart project.
+    /// Otherwise `android/app/src/main/jniLibs` for apps and `android/src/main/jniLibs` for plugins
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum AndroidAbiArg {
+    /// 64-bit ARM, used by most devices
+    #[value(name = "arm64-v8a")]
+    Arm64V8a,
+    /// 32-bit ARM, used by old devices
+    #[value(name = "armeabi-v7a")]
+    ArmeabiV7a,
+    /// 64-bit x86, used by emulators
+    #[value(name = "x86_64")]
+    X86_64,
+    /// 32-bit x86, used by old emulators
+    #[value(name = "x86")]
+    X86,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct BuildAppleCommandArgs {
+    /// Path to root of Dart project, otherwise inferred from current working directory
+    #[arg(long)]
+    pub dart_root: Option<PathBuf>,
+
+    /// Directory of the Rust crate, relative to the Dart project
+    #[arg(long, default_value = "rust")]
+    pub rust_root: PathBuf,
+
+    /// Platforms to compile for
+    #[arg(long, value_enum, value_delimiter = ',', default_value = "ios,macos")]
+    pub platforms: Vec<ApplePlatformArg>,
+
+    /// Compile in debug mode instead of release mode
+    #[arg(long)]
+    pub debug: bool,
+
+    /// Directory to create the XCFramework in, relative to the Dart project.
+    /// Otherwise the `Frameworks` folder next to each podspec of the plugin, which is updated to vendor the XCFramework
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum ApplePlatformArg {
+    /// iOS devices and simulators
+    Ios,
+    /// macOS, for both Apple silicon and Intel
+    Macos,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct TestCommandArgs {
+    /// Path to root of Dart project, otherwise inferred from current working directory
+    #[arg(long)]
+    pub dart_root: Option<PathBuf>,
+
+    /// Directory of the Rust crate, relative to the Dart project
+    #[arg(long, default_value = "rust")]
+    pub rust_root: PathBuf,
+
+    /// Platforms to run the tests on
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "rust,native,web"
+    )]
+    pub platforms: Vec<TestPlatformArg>,
+
+    /// Only run the tests whose names match this regular expression (or contain this text for the Rust tests), on all platforms
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Collect the coverage of the Rust tests and the Dart tests on native, and merge them into one lcov report
+    #[arg(long)]
+    pub coverage: bool,
+
+    /// Path of the merged coverage report, relative to the Dart project
+    #[arg(long, default_value = "coverage/lcov.info")]
+    pub coverage_output: PathBuf,
+
+    /// Port of the VM service to collect the Dart coverage from
+    #[arg(long, default_value_t = 8181)]
+    pub vm_service_port: u16,
+
+    /// Packages to collect the Dart coverage of, otherwise the Dart project itself
+    #[arg(long, num_args = 1..)]
+    pub coverage_scope: Vec<String>,
+
+    /// Test files or directories to run, otherwise all tests
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum TestPlatformArg {
+    /// Run the unit tests of the Rust crate
+    Rust,
+    /// Load the Rust library compiled for the current machine
+    Native,
+    /// Load the WASM build in Chrome
+    Web,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct DocsCommandArgs {
+    /// Directory to write the HTML files into
+    #[arg(long, default_value = "doc/rust_api")]
+    pub docs_output: PathBuf,
+
+    #[clap(flatten)]
+    pub primary: GenerateCommandArgsPrimary,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct DumpCommandArgs {
+    /// Which intermediate representation to dump
+    #[arg(long, value_enum, default_value = "mir")]
+    pub stage: IrDumpStage,
+
+    /// Format of the output
+    #[arg(long, value_enum, default_value = "json")]
+    pub format: IrDumpFormat,
+
+    /// Path of the output file
+    #[arg(long, default_value = "frb_ir.json")]
+    pub output: PathBuf,
+
+    #[clap(flatten)]
+    pub primary: GenerateCommandArgsPrimary,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct CheckCompatCommandArgs {
+    /// Path of the API snapshot
+    #[arg(long, default_value = "frb_api.lock.json")]
+    pub snapshot: PathBuf,
+
+    /// Write the current API into the snapshot instead of checking against it
+    #[arg(long)]
+    pub update: bool,
+
+    #[clap(flatten)]
+    pub primary: GenerateCommandArgsPrimary,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct SbomCommandArgs {
+    /// Directory of the Rust crate bundled into the app
+    #[arg(long, default_value = "rust")]
+    pub rust_root: PathBuf,
+
+    /// Format of the SBOM
+    #[arg(long, value_enum, default_value = "cyclonedx")]
+    pub format: SbomFormatArg,
+
+    /// Rust target triples to generate SBOM for, one file per target.
+    /// Defaults to the targets of all platforms supported by Flutter, including `wasm32-unknown-unknown`
+    #[arg(long, num_args = 1..)]
+    pub targets: Vec<String>,
+
+    /// Directory to write the SBOM files into
+    #[arg(long, default_value = "sbom")]
+    pub output_dir: PathBuf,
+}
+
+#[derive(Debug, Args)]
+pub(crate) struct DoctorCommandArgs {
+    /// Directory of the Flutter/Dart project, defaults to the one containing the current directory
+    #[arg(long)]
+    pub dart_root: Option<PathBuf>,
+
+    /// Path to the installed LLVM, defaults to the `llvm_path` in the configuration file
+    #[arg(long, num_args = 1..)]
+    pub llvm_path: Option<Vec<String>>,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub(crate) enum SbomFormatArg {
+    /// (default) CycloneDX 1.5 JSON
+    #[value(name = "cyclonedx")]
+    CycloneDx,
+    /// SPDX 2.3 JSON
+    Spdx,
+}
+
 #[derive(Debug, Args)]
 pub(crate) struct InternalGenerateCommandArgs {}
 
@@ -256,3 +629,63 @@ impl From<TemplateArg> for Template {
         }
     }
 }
+
+impl From<AndroidAbiArg> for AndroidAbi {
+    fn from(value: AndroidAbiArg) -> Self {
+        match value {
+            AndroidAbiArg::Arm64V8a => AndroidAbi::Arm64V8a,
+            AndroidAbiArg::ArmeabiV7a => AndroidAbi::ArmeabiV7a,
+            AndroidAbiArg::X86_64 => AndroidAbi::X86_64,
+            AndroidAbiArg::X86 => AndroidAbi::X86,
+        }
+    }
+}
+
+impl From<ApplePlatformArg> for ApplePlatform {
+    fn from(value: ApplePlatformArg) -> Self {
+        match value {
+            ApplePlatformArg::Ios => ApplePlatform::Ios,
+            ApplePlatformArg::Macos => ApplePlatform::Macos,
+        }
+    }
+}
+
+impl From<MessageFormatArg> for MessageFormat {
+    fn from(value: MessageFormatArg) -> Self {
+        match value {
+            MessageFormatArg::Human => MessageFormat::Human,
+            MessageFormatArg::Json => MessageFormat::Json,
+        }
+    }
+}
+
+impl From<ShellArg> for ShellMode {
+    fn from(value: ShellArg) -> Self {
+        match value {
+            ShellArg::Direct => ShellMode::Direct,
+            ShellArg::Sh => ShellMode::Sh,
+            ShellArg::Powershell => ShellMode::Powershell,
+            ShellArg::Nushell => ShellMode::Nushell,
+            ShellArg::Fish => ShellMode::Fish,
+        }
+    }
+}
+
+impl From<SbomFormatArg> for SbomFormat {
+    fn from(value: SbomFormatArg) -> Self {
+        match value {
+            SbomFormatArg::CycloneDx => SbomFormat::CycloneDx,
+            SbomFormatArg::Spdx => SbomFormat::Spdx,
+        }
+    }
+}
+
+impl From<TestPlatformArg> for TestPlatform {
+    fn from(value: TestPlatformArg) -> Self {
+        match value {
+            TestPlatformArg::Rust => TestPlatform::Rust,
+            TestPlatformArg::Native => TestPlatform::Native,
+            TestPlatformArg::Web => TestPlatform::Web,
+        }
+    }
+}
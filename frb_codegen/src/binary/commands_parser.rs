@@ -38,26 +38,51 @@ fn compute_codegen_config_from_naive_command_args(args: GenerateCommandArgsPrima
         rust_output: args.rust_output,
         dart_entrypoint_class_name: args.dart_entrypoint_class_name,
         dart_format_line_length: args.dart_format_line_length,
+        dart_format_command: args.dart_format_command,
+        rust_format: negative_bool_arg(args.no_rust_format),
+        rust_format_command: args.rust_format_command,
         dart_preamble: args.dart_preamble,
         rust_preamble: args.rust_preamble,
         dart_enums_style: negative_bool_arg(args.no_dart_enums_style),
         add_mod_to_lib: negative_bool_arg(args.no_add_mod_to_lib),
         llvm_path: args.llvm_path,
         llvm_compiler_opts: args.llvm_compiler_opts,
+        cbindgen_config: None, // complex type, not supported on command line yet
+        ffigen_config: None,   // complex type, not supported on command line yet
         dart_root: args.dart_root,
         build_runner: negative_bool_arg(args.no_build_runner),
         extra_headers: args.extra_headers,
         web: negative_bool_arg(args.no_web),
         deps_check: negative_bool_arg(args.no_deps_check),
         dart3: negative_bool_arg(args.no_dart3),
+        dart_sealed_enums: positive_bool_arg(args.dart_sealed_enums),
         full_dep: positive_bool_arg(args.full_dep),
+        method_channel: positive_bool_arg(args.method_channel),
         local: positive_bool_arg(args.local),
         default_external_library_loader_web_prefix: args.default_external_library_loader_web_prefix,
+        default_external_library_loader_io_stems: None, // complex type, not supported on command line yet
+        default_external_library_loader_io_paths: None, // complex type, not supported on command line yet
+        default_external_library_loader_io_process: args.default_external_library_loader_io_process,
         dart_type_rename: None, // complex type, not supported on command line yet
+        type_mappings: None,    // complex type, not supported on command line yet
         enable_lifetime: positive_bool_arg(args.enable_lifetime),
         type_64bit_int: positive_bool_arg(args.type_64bit_int),
         default_dart_async: negative_bool_arg(args.no_default_dart_async),
         stop_on_error: positive_bool_arg(args.stop_on_error),
+        lazy_third_party: positive_bool_arg(args.lazy_third_party),
+        third_party: args.third_party,
+        rust_feature_sets: None, // complex type, not supported on command line yet
+        shared_crates: None,     // complex type, not supported on command line yet
+        minimal_output: args.minimal_output,
+        dart_mocks: positive_bool_arg(args.dart_mocks),
+        version_in_header: negative_bool_arg(args.no_version_in_header),
+        api_version: args.api_version,
+        plugins: args.plugins,
+        cache: negative_bool_arg(args.no_cache),
+        watch_paths: args.watch_paths,
+        watch_ignore: args.watch_ignore,
+        watch_poll: positive_bool_arg(args.watch_poll),
+        watch_poll_interval_ms: args.watch_poll_interval_ms,
         dump: args.dump,
         dump_all: positive_bool_arg(args.dump_all),
     }
@@ -0,0 +1,183 @@
+//! Build Android platform for a Flutter+Rust app
+
+use crate::command_run;
+use crate::library::commands::cargo_metadata::{execute_cargo_metadata, find_lib_target_name};
+use crate::library::commands::command_runner::call_shell;
+use crate::utils::path_utils::find_dart_package_dir;
+use anyhow::{bail, Context};
+use itertools::Itertools;
+use log::info;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use strum_macros::Display;
+
+pub struct BuildAndroidConfig {
+    pub dart_root: Option<PathBuf>,
+    /// Relative to `dart_root`
+    pub rust_root: PathBuf,
+    pub abis: Vec<AndroidAbi>,
+    /// Minimum Android API level, or the default of `cargo-ndk`
+    pub platform: Option<u32>,
+    pub release: bool,
+    pub strip: bool,
+    /// Directory to copy the libraries into, or `jniLibs` of the detected Android project
+    pub output: Option<PathBuf>,
+}
+
+/// Please refer to `AndroidAbiArg` for doc
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum AndroidAbi {
+    #[strum(serialize = "arm64-v8a")]
+    Arm64V8a,
+    #[strum(serialize = "armeabi-v7a")]
+    ArmeabiV7a,
+    #[strum(serialize = "x86_64")]
+    X86_64,
+    #[strum(serialize = "x86")]
+    X86,
+}
+
+impl AndroidAbi {
+    fn rust_target(&self) -> &'static str {
+        match self {
+            AndroidAbi::Arm64V8a => "aarch64-linux-android",
+            AndroidAbi::ArmeabiV7a => "armv7-linux-androideabi",
+            AndroidAbi::X86_64 => "x86_64-linux-android",
+            AndroidAbi::X86 => "i686-linux-android",
+        }
+    }
+}
+
+pub fn build(config: BuildAndroidConfig) -> anyhow::Result<()> {
+    let dart_root = match config.dart_root {
+        Some(dart_root) => fs::canonicalize(dart_root)?,
+        None => find_dart_package_dir(&env::current_dir()?)
+            .context("Please provide --dart-root, or run command inside a Flutter/Dart package")?,
+    };
+    let rust_root = dart_root.join(&config.rust_root);
+    let output_dir = match config.output {
+        Some(output) => dart_root.join(output),
+        None => find_jni_libs_dir(&dart_root)?,
+    };
+
+    execute_cargo_ndk(
+        &rust_root,
+        &config.abis,
+        config.platform,
+        config.release,
+        config.strip,
+    )?;
+
+    let metadata = execute_cargo_metadata(&rust_root.join("Cargo.toml"))?;
+    let lib_name = find_lib_target_name(&metadata, "cdylib")?;
+    let profile_dir = if config.release { "release" } else { "debug" };
+    for abi in &config.abis {
+        let src = (metadata.target_directory.as_std_path())
+            .join(abi.rust_target())
+            .join(profile_dir)
+            .join(format!("lib{lib_name}.so"));
+        let dst_dir = output_dir.join(abi.to_string());
+        fs::create_dir_all(&dst_dir)?;
+        fs::copy(&src, dst_dir.join(src.file_name().unwrap()))
+            .with_context(|| format!("Fail to copy the library {src:?}"))?;
+    }
+
+    info!(
+        "Copied lib{lib_name}.so of {} into {output_dir:?}",
+        config.abis.iter().join(", ")
+    );
+    Ok(())
+}
+
+/// The libraries of an app are bundled from `android/app/src/main/jniLibs`,
+/// while a plugin bundles its own `android/src/main/jniLibs` into the apps using it.
+fn find_jni_libs_dir(dart_root: &Path) -> anyhow::Result<PathBuf> {
+    let android_dir = dart_root.join("android");
+    for main_dir in [
+        android_dir.join("app/src/main"),
+        android_dir.join("src/main"),
+    ] {
+        if main_dir.join("AndroidManifest.xml").exists() {
+            return Ok(main_dir.join("jniLibs"));
+        }
+    }
+    bail!("Fail to find the Android project in {android_dir:?}, please create it (e.g. via `flutter create --platforms=android .`) or provide --output")
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn execute_cargo_ndk(
+    rust_root: &Path,
+    abis: &[AndroidAbi],
+    platform: Option<u32>,
+    release: bool,
+    strip: bool,
+) -> anyhow::Result<()> {
+    let target_args = (abis.iter())
+        .flat_map(|abi| ["-t".to_owned(), abi.to_string()])
+        .collect_vec();
+    let profile = if release { "RELEASE" } else { "DEV" };
+    let envs: HashMap<String, String> = if strip {
+        [(
+            format!("CARGO_PROFILE_{profile}_STRIP"),
+            "symbols".to_owned(),
+        )]
+        .into()
+    } else {
+        HashMap::new()
+    };
+
+    let res = command_run!(
+        call_shell[Some(rust_root), Some(envs)],
+        "cargo",
+        "ndk",
+        *target_args,
+        ?platform.map(|_| "--platform"),
+        ?platform.map(|x| x.to_string()),
+        "build",
+        (release, "--release"),
+    )?;
+    if !res.status.success() {
+        // This will stop the whole generator and tell the users, so we do not care about testing it
+        // frb-coverage:ignore-start
+        let stderr = String::from_utf8_lossy(&res.stderr);
+        if stderr.contains("no such command: `ndk`") {
+            bail!("Please install cargo-ndk via `cargo install cargo-ndk`");
+        }
+        bail!("Fail to build the Rust code for Android: {stderr}");
+        // frb-coverage:ignore-end
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_find_jni_libs_dir() -> anyhow::Result<()> {
+        let dir = tempdir()?;
+        let dart_root = dir.path();
+        assert!(find_jni_libs_dir(dart_root).is_err());
+
+        fs::create_dir_all(dart_root.join("android/src/main"))?;
+        fs::write(dart_root.join("android/src/main/AndroidManifest.xml"), "")?;
+        assert_eq!(
+            find_jni_libs_dir(dart_root)?,
+            dart_root.join("android/src/main/jniLibs")
+        );
+
+        fs::create_dir_all(dart_root.join("android/app/src/main"))?;
+        fs::write(
+            dart_root.join("android/app/src/main/AndroidManifest.xml"),
+            "",
+        )?;
+        assert_eq!(
+            find_jni_libs_dir(dart_root)?,
+            dart_root.join("android/app/src/main/jniLibs")
+        );
+
+        Ok(())
+    }
+}
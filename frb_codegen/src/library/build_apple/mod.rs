@@ -0,0 +1,312 @@
+//! Build iOS and macOS platforms for a Flutter+Rust app, as an XCFramework
+
+use crate::command_run;
+use crate::library::commands::cargo_metadata::{execute_cargo_metadata, find_lib_target_name};
+use crate::library::commands::command_runner::{call_shell, check_exit_code};
+use crate::utils::path_utils::find_dart_package_dir;
+use anyhow::{bail, Context};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use log::info;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use strum_macros::Display;
+
+pub struct BuildAppleConfig {
+    pub dart_root: Option<PathBuf>,
+    /// Relative to `dart_root`
+    pub rust_root: PathBuf,
+    pub platforms: Vec<ApplePlatform>,
+    pub release: bool,
+    /// Directory to create the XCFramework in, or the podspec directories of the detected plugin
+    pub output: Option<PathBuf>,
+}
+
+/// Please refer to `ApplePlatformArg` for doc
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ApplePlatform {
+    Ios,
+    Macos,
+}
+
+impl ApplePlatform {
+    /// Each slice of the XCFramework is a (possibly universal) library of these targets
+    fn slices(&self) -> Vec<AppleSlice> {
+        match self {
+            ApplePlatform::Ios => vec![
+                AppleSlice {
+                    name: "ios",
+                    targets: &["aarch64-apple-ios"],
+                },
+                AppleSlice {
+                    name: "ios_simulator",
+                    targets: &["aarch64-apple-ios-sim", "x86_64-apple-ios"],
+                },
+            ],
+            ApplePlatform::Macos => vec![AppleSlice {
+                name: "macos",
+                targets: &["aarch64-apple-darwin", "x86_64-apple-darwin"],
+            }],
+        }
+    }
+}
+
+struct AppleSlice {
+    name: &'static str,
+    targets: &'static [&'static str],
+}
+
+const FRAMEWORKS_DIR: &str = "Frameworks";
+
+pub fn build(config: BuildAppleConfig) -> anyhow::Result<()> {
+    if !cfg!(target_os = "macos") {
+        bail!("build-apple needs Xcode, thus should be run on macOS");
+    }
+
+    let dart_root = match config.dart_root {
+        Some(dart_root) => fs::canonicalize(dart_root)?,
+        None => find_dart_package_dir(&env::current_dir()?)
+            .context("Please provide --dart-root, or run command inside a Flutter/Dart package")?,
+    };
+    let rust_root = dart_root.join(&config.rust_root);
+    let podspecs = match config.output {
+        Some(_) => vec![],
+        None => find_podspecs(&dart_root)?,
+    };
+
+    let metadata = execute_cargo_metadata(&rust_root.join("Cargo.toml"))?;
+    let lib_name = find_lib_target_name(&metadata, "staticlib")?;
+    let target_dir = metadata.target_directory.as_std_path();
+    let profile_dir = if config.release { "release" } else { "debug" };
+
+    let mut libraries = vec![];
+    for slice in config
+        .platforms
+        .iter()
+        .flat_map(|platform| platform.slices())
+    {
+        for target in slice.targets {
+            execute_cargo_build(&rust_root, target, config.release)?;
+        }
+        let inputs = (slice.targets.iter())
+            .map(|target| target_dir.join(target).join(profile_dir))
+            .map(|dir| dir.join(format!("lib{lib_name}.a")))
+            .collect_vec();
+        let output =
+            (target_dir.join("frb_apple").join(slice.name)).join(format!("lib{lib_name}.a"));
+        fs::create_dir_all(output.parent().unwrap())?;
+        execute_lipo(&inputs, &output)?;
+        libraries.push(output);
+    }
+
+    let framework_name = format!("{lib_name}.xcframework");
+    let output_dirs = match &config.output {
+        Some(output) => vec![dart_root.join(output)],
+        None => (podspecs.iter())
+            .map(|podspec| podspec.parent().unwrap().join(FRAMEWORKS_DIR))
+            .collect_vec(),
+    };
+    for output_dir in &output_dirs {
+        execute_create_xcframework(&libraries, &output_dir.join(&framework_name))?;
+    }
+
+    for podspec in &podspecs {
+        let framework_path = format!("{FRAMEWORKS_DIR}/{framework_name}");
+        let pod_name = podspec.file_stem().unwrap().to_string_lossy();
+        let force_load_flag = compute_force_load_flag(&pod_name, &lib_name);
+        let content = fs::read_to_string(podspec)?;
+        let new_content = add_vendored_framework(&content, &framework_path)?;
+        let new_content = add_force_load_flag(&new_content, &force_load_flag)
+            .with_context(|| format!("Fail to update {podspec:?}"))?;
+        if new_content != content {
+            fs::write(podspec, new_content)?;
+            info!("Updated {podspec:?} to vendor and force load {framework_path}");
+        }
+    }
+
+    info!("Created {framework_name} in {output_dirs:?}");
+    Ok(())
+}
+
+/// The XCFramework is shipped by the podspec of the plugin, e.g. `ios/my_plugin.podspec`,
+/// or `darwin/my_plugin.podspec` when the plugin shares its code between iOS and macOS.
+fn find_podspecs(dart_root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut ans = vec![];
+    for dir in ["ios", "macos", "darwin"] {
+        let dir = dart_root.join(dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.extension() == Some("podspec".as_ref()) {
+                ans.push(path);
+            }
+        }
+    }
+    if ans.is_empty() {
+        bail!("Fail to find the podspec of the Flutter plugin in {dart_root:?}, please provide --output");
+    }
+    Ok(ans.into_iter().sorted().collect())
+}
+
+lazy_static! {
+    static ref POD_SPEC_NEW: Regex = Regex::new(r"Pod::Spec\.new\s+do\s+\|(\w+)\|").unwrap();
+    static ref POD_TARGET_XCCONFIG: Regex =
+        Regex::new(r"\w+\.pod_target_xcconfig\s*=\s*\{").unwrap();
+}
+
+fn add_vendored_framework(podspec_content: &str, framework_path: &str) -> anyhow::Result<String> {
+    if podspec_content.contains(framework_path) {
+        return Ok(podspec_content.to_owned());
+    }
+    add_podspec_line(
+        podspec_content,
+        &format!("vendored_frameworks = '{framework_path}'"),
+    )
+}
+
+/// Nothing in the app refers to the symbols of the Rust library (Dart looks them up at runtime),
+/// thus the linker would drop all of them from the static library unless forced to load it.
+/// CocoaPods copies the library of the XCFramework into `PODS_XCFRAMEWORKS_BUILD_DIR`.
+fn compute_force_load_flag(pod_name: &str, lib_name: &str) -> String {
+    format!("-force_load ${{PODS_XCFRAMEWORKS_BUILD_DIR}}/{pod_name}/lib{lib_name}.a")
+}
+
+fn add_force_load_flag(podspec_content: &str, flag: &str) -> anyhow::Result<String> {
+    if podspec_content.contains(flag) {
+        return Ok(podspec_content.to_owned());
+    }
+    if podspec_content.contains("OTHER_LDFLAGS") {
+        bail!("The podspec already sets OTHER_LDFLAGS, please add `{flag}` to it manually");
+    }
+    let entry = format!("'OTHER_LDFLAGS' => '{flag}'");
+    // e.g. `s.pod_target_xcconfig = { 'DEFINES_MODULE' => 'YES' }` in the template of Flutter plugins
+    if let Some(captures) = POD_TARGET_XCCONFIG.captures(podspec_content) {
+        let index = captures.get(0).unwrap().end();
+        return Ok(format!(
+            "{} {entry},{}",
+            &podspec_content[..index],
+            &podspec_content[index..],
+        ));
+    }
+    add_podspec_line(
+        podspec_content,
+        &format!("pod_target_xcconfig = {{ {entry} }}"),
+    )
+}
+
+/// Adds `s.{line}` before the `end` of the podspec
+fn add_podspec_line(podspec_content: &str, line: &str) -> anyhow::Result<String> {
+    let spec_var = (POD_SPEC_NEW.captures(podspec_content))
+        .context("Fail to find `Pod::Spec.new do |s|` in the podspec")?[1]
+        .to_owned();
+    let end_index = (podspec_content.trim_end().rfind("end"))
+        .context("Fail to find the `end` of the podspec")?;
+    Ok(format!(
+        "{}  {spec_var}.{line}\n{}",
+        &podspec_content[..end_index],
+        &podspec_content[end_index..],
+    ))
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn execute_cargo_build(rust_root: &Path, target: &str, release: bool) -> anyhow::Result<()> {
+    let res = command_run!(
+        call_shell[Some(rust_root), None],
+        "cargo",
+        "build",
+        "--target",
+        target,
+        (release, "--release"),
+    )?;
+    if !res.status.success() {
+        // This will stop the whole generator and tell the users, so we do not care about testing it
+        // frb-coverage:ignore-start
+        bail!(
+            "Fail to build the Rust code for {target} (if the target is missing, install it via `rustup target add {target}`): {}",
+            String::from_utf8_lossy(&res.stderr)
+        );
+        // frb-coverage:ignore-end
+    }
+    Ok(())
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn execute_lipo(inputs: &[PathBuf], output: &Path) -> anyhow::Result<()> {
+    check_exit_code(&command_run!(
+        call_shell[None, None],
+        "lipo",
+        "-create",
+        *inputs,
+        "-output",
+        output,
+    )?)
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn execute_create_xcframework(libraries: &[PathBuf], output: &Path) -> anyhow::Result<()> {
+    // `xcodebuild` refuses to overwrite an existing one
+    if output.exists() {
+        fs::remove_dir_all(output)?;
+    }
+    let library_args = (libraries.iter())
+        .flat_map(|library| [PathBuf::from("-library"), library.to_owned()])
+        .collect_vec();
+    check_exit_code(&command_run!(
+        call_shell[None, None],
+        "xcodebuild",
+        "-create-xcframework",
+        *library_args,
+        "-output",
+        output,
+    )?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_vendored_framework() -> anyhow::Result<()> {
+        let podspec = "Pod::Spec.new do |s|\n  s.name = 'my_plugin'\n  s.source_files = 'Classes/**/*'\nend\n";
+        let framework_path = "Frameworks/rust_lib_my_plugin.xcframework";
+
+        let expect = "Pod::Spec.new do |s|\n  s.name = 'my_plugin'\n  s.source_files = 'Classes/**/*'\n  s.vendored_frameworks = 'Frameworks/rust_lib_my_plugin.xcframework'\nend\n";
+        assert_eq!(add_vendored_framework(podspec, framework_path)?, expect);
+        assert_eq!(add_vendored_framework(expect, framework_path)?, expect);
+        assert!(add_vendored_framework("", framework_path).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_force_load_flag() -> anyhow::Result<()> {
+        let flag = compute_force_load_flag("my_plugin", "rust_lib_my_plugin");
+        assert_eq!(
+            flag,
+            "-force_load ${PODS_XCFRAMEWORKS_BUILD_DIR}/my_plugin/librust_lib_my_plugin.a"
+        );
+
+        // Merged into the existing settings, since assigning again would replace them
+        let podspec =
+            "Pod::Spec.new do |s|\n  s.pod_target_xcconfig = { 'DEFINES_MODULE' => 'YES' }\nend\n";
+        let expect = format!("Pod::Spec.new do |s|\n  s.pod_target_xcconfig = {{ 'OTHER_LDFLAGS' => '{flag}', 'DEFINES_MODULE' => 'YES' }}\nend\n");
+        assert_eq!(add_force_load_flag(podspec, &flag)?, expect);
+        assert_eq!(add_force_load_flag(&expect, &flag)?, expect);
+
+        let podspec = "Pod::Spec.new do |s|\n  s.name = 'my_plugin'\nend\n";
+        assert_eq!(
+            add_force_load_flag(podspec, &flag)?,
+            format!("Pod::Spec.new do |s|\n  s.name = 'my_plugin'\n  s.pod_target_xcconfig = {{ 'OTHER_LDFLAGS' => '{flag}' }}\nend\n")
+        );
+
+        let podspec =
+            "Pod::Spec.new do |s|\n  s.pod_target_xcconfig = { 'OTHER_LDFLAGS' => '-lz' }\nend\n";
+        assert!(add_force_load_flag(podspec, &flag).is_err());
+
+        Ok(())
+    }
+}
@@ -1,14 +1,12 @@
 //! Build web platform for a Flutter+Rust app
 
-use crate::command_run;
-use crate::library::commands::command_runner::{call_shell, call_shell_info, check_exit_code};
+use crate::utils::dart_coverage::{dart_run, DartCoverageConfig};
 use crate::utils::dart_repository::dart_repo::DartRepository;
 use crate::utils::path_utils::{find_dart_package_dir, path_to_string};
 use anyhow::{bail, Context};
-use itertools::Itertools;
 use log::debug;
 use std::path::{Path, PathBuf};
-use std::process::{Command, ExitStatus};
+use std::process::Stdio;
 use std::{env, fs};
 
 // We make the core build-web logic in Dart, and Rust is just a wrapper.
@@ -16,12 +14,12 @@ use std::{env, fs};
 // and invoked in machines without flutter_rust_bridge_codegen binary.
 pub fn build(
     dart_root: Option<PathBuf>,
-    dart_coverage: bool,
+    dart_coverage: Option<DartCoverageConfig>,
     args: Vec<String>,
 ) -> anyhow::Result<()> {
     let dart_root = parse_dart_root(dart_root)?;
     debug!("build dart_root={dart_root:?} args={args:?}");
-    execute_dart_command(&dart_root, &args, dart_coverage)
+    execute_dart_command(&dart_root, &args, dart_coverage.as_ref())
 }
 
 fn parse_dart_root(dart_root: Option<PathBuf>) -> anyhow::Result<PathBuf> {
@@ -36,7 +34,7 @@ fn parse_dart_root(dart_root: Option<PathBuf>) -> anyhow::Result<PathBuf> {
 fn execute_dart_command(
     dart_root: &Path,
     args: &[String],
-    dart_coverage: bool,
+    dart_coverage: Option<&DartCoverageConfig>,
 ) -> anyhow::Result<()> {
     let repo = DartRepository::from_path(dart_root)?;
 
@@ -50,9 +48,15 @@ fn execute_dart_command(
         ans.extend(args.to_owned());
         ans
     };
-    let status = dart_run(&repo, dart_root, dart_coverage, dart_run_args)?;
+    let output = dart_run(
+        &repo,
+        dart_root,
+        dart_run_args,
+        dart_coverage,
+        Stdio::inherit(),
+    )?;
 
-    if !status.success() {
+    if !output.status.success() {
         // This will stop the whole generator and tell the users, so we do not care about testing it
         // frb-coverage:ignore-start
         bail!("Fail to execute command, please see logs above for details.")
@@ -61,53 +65,3 @@ fn execute_dart_command(
 
     Ok(())
 }
-
-// ref: https://pub.dev/packages/coverage
-#[allow(clippy::vec_init_then_push)]
-fn dart_run(
-    repo: &DartRepository,
-    current_dir: &Path,
-    dart_coverage: bool,
-    args: Vec<String>,
-) -> anyhow::Result<ExitStatus> {
-    let handle = {
-        let mut cmd_args: Vec<PathBuf> = vec!["dart".into()];
-        cmd_args.extend(repo.command_extra_args().into_iter().map_into());
-        cmd_args.push("run".into());
-        if dart_coverage {
-            cmd_args.extend([
-                "--pause-isolates-on-exit".into(),
-                "--disable-service-auth-codes".into(),
-                "--enable-vm-service=8181".into(),
-            ]);
-        }
-        cmd_args.extend(args.into_iter().map_into());
-
-        let info = call_shell_info(&cmd_args);
-        Command::new(info.program)
-            .args(info.args)
-            .current_dir(current_dir)
-            .spawn()?
-    };
-
-    if dart_coverage {
-        let res = command_run!(
-            call_shell[Some(current_dir), None],
-            "dart",
-            "pub",
-            "global",
-            "run",
-            "coverage:collect_coverage",
-            "--wait-paused",
-            "--uri=http://127.0.0.1:8181/",
-            "-o",
-            "coverage/coverage.json",
-            "--resume-isolates",
-            // TODO this scope-output?
-            "--scope-output=foo",
-        )?;
-        check_exit_code(&res)?;
-    }
-
-    Ok(handle.wait_with_output()?.status)
-}
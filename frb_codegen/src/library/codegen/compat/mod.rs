@@ -0,0 +1,312 @@
+//! Detect the incompatible changes of the bridged API, by comparing it with a snapshot committed to the repository.
+//!
+//! Since the two sides of the bridge may be released separately, any change to an existing function or type
+//! is considered incompatible (e.g. a new struct field changes the wire format), while new items are not,
+//! unless they shift the ids of the existing functions.
+
+mod snapshot;
+
+use crate::codegen::compat::snapshot::{ApiSnapshot, MemberSnapshot};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::utils::file_utils::create_dir_all_and_write;
+use crate::utils::message_format::print_human;
+use anyhow::{bail, Context};
+use itertools::Itertools;
+use log::info;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+pub(crate) fn check(mir_pack: &MirPack, snapshot_path: &Path, update: bool) -> anyhow::Result<()> {
+    let actual = ApiSnapshot::compute(mir_pack);
+    if update {
+        create_dir_all_and_write(snapshot_path, serde_json::to_string_pretty(&actual)? + "\n")?;
+        info!("Write API snapshot to {snapshot_path:?}");
+        return Ok(());
+    }
+
+    let expect: ApiSnapshot =
+        serde_json::from_str(&fs::read_to_string(snapshot_path).with_context(|| {
+            format!("Cannot read API snapshot {snapshot_path:?}, please create it via `--update`")
+        })?)?;
+
+    let changes = compute_incompatible_changes(&expect, &actual);
+    if !changes.is_empty() {
+        bail!(
+            "The API has incompatible changes compared with {snapshot_path:?}:\n{}\n\
+            If they are intended, please run with `--update` to accept them.",
+            changes.iter().map(|x| format!("- {x}")).join("\n"),
+        );
+    }
+
+    if expect == actual {
+        print_human("The API is unchanged.");
+    } else {
+        print_human(
+            "The API is compatible, but has new items. Run with `--update` to record them.",
+        );
+    }
+    Ok(())
+}
+
+fn compute_incompatible_changes(expect: &ApiSnapshot, actual: &ApiSnapshot) -> Vec<String> {
+    let mut ans = vec![];
+
+    diff_items(
+        "function",
+        &expect.functions,
+        &actual.functions,
+        &mut ans,
+        |owner, expect, actual, ans| {
+            // The ids follow the order of the names, so a new function may renumber the existing ones
+            if expect.id != actual.id {
+                ans.push(format!(
+                    "{owner} changes id from {} to {}",
+                    expect.id, actual.id
+                ));
+            }
+            if expect.sync != actual.sync {
+                let mode = |sync| if sync { "sync" } else { "async" };
+                ans.push(format!(
+                    "{owner} changes from {} to {}",
+                    mode(expect.sync),
+                    mode(actual.sync)
+                ));
+            }
+            diff_members(owner, "input", &expect.inputs, &actual.inputs, ans);
+            if expect.output != actual.output {
+                ans.push(format!(
+                    "{owner} changes output from `{}` to `{}`",
+                    expect.output, actual.output
+                ));
+            }
+        },
+    );
+
+    diff_items(
+        "struct",
+        &expect.structs,
+        &actual.structs,
+        &mut ans,
+        |owner, expect, actual, ans| diff_members(owner, "field", expect, actual, ans),
+    );
+
+    diff_items(
+        "enum",
+        &expect.enums,
+        &actual.enums,
+        &mut ans,
+        |owner, expect, actual, ans| {
+            diff_named(
+                owner,
+                "variant",
+                expect,
+                actual,
+                |x| &x.name,
+                ans,
+                |expect, actual, ans| {
+                    let owner = format!("{owner} variant `{}`", expect.name);
+                    diff_members(&owner, "field", &expect.fields, &actual.fields, ans)
+                },
+            )
+        },
+    );
+
+    ans
+}
+
+/// New items are compatible, thus only the existing ones are checked
+fn diff_items<T>(
+    kind: &str,
+    expect: &BTreeMap<String, T>,
+    actual: &BTreeMap<String, T>,
+    ans: &mut Vec<String>,
+    diff: impl Fn(&str, &T, &T, &mut Vec<String>),
+) {
+    for (name, expect_item) in expect {
+        let owner = format!("{kind} `{name}`");
+        match actual.get(name) {
+            Some(actual_item) => diff(&owner, expect_item, actual_item, ans),
+            None => ans.push(format!("{owner} is removed")),
+        }
+    }
+}
+
+fn diff_members(
+    owner: &str,
+    kind: &str,
+    expect: &[MemberSnapshot],
+    actual: &[MemberSnapshot],
+    ans: &mut Vec<String>,
+) {
+    diff_named(
+        owner,
+        kind,
+        expect,
+        actual,
+        |x| &x.name,
+        ans,
+        |expect, actual, ans| {
+            if expect.ty != actual.ty {
+                ans.push(format!(
+                    "{owner} changes the type of {kind} `{}` from `{}` to `{}`",
+                    expect.name, expect.ty, actual.ty
+                ));
+            }
+        },
+    )
+}
+
+/// The order matters, since both the positional arguments and the wire format depend on it
+fn diff_named<T>(
+    owner: &str,
+    kind: &str,
+    expect: &[T],
+    actual: &[T],
+    name: impl Fn(&T) -> &String,
+    ans: &mut Vec<String>,
+    diff: impl Fn(&T, &T, &mut Vec<String>),
+) {
+    for expect_item in expect {
+        match actual.iter().find(|x| name(x) == name(expect_item)) {
+            Some(actual_item) => diff(expect_item, actual_item, ans),
+            None => ans.push(format!("{owner} removes {kind} `{}`", name(expect_item))),
+        }
+    }
+    for actual_item in actual {
+        if !expect.iter().any(|x| name(x) == name(actual_item)) {
+            ans.push(format!("{owner} adds {kind} `{}`", name(actual_item)));
+        }
+    }
+
+    let common_names = |items: &[T], others: &[T]| {
+        (items.iter())
+            .map(|x| name(x).to_owned())
+            .filter(|x| others.iter().any(|other| name(other) == x))
+            .collect_vec()
+    };
+    if common_names(expect, actual) != common_names(actual, expect) {
+        ans.push(format!("{owner} reorders its {kind}s"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::compat::snapshot::{FunctionSnapshot, VariantSnapshot};
+    use crate::codegen::{check_compat, Config};
+    use crate::utils::logs::configure_opinionated_test_logging;
+    use crate::utils::test_utils::get_test_fixture_dir;
+    use serial_test::serial;
+    use std::env;
+
+    fn member(name: &str, ty: &str) -> MemberSnapshot {
+        MemberSnapshot {
+            name: name.to_owned(),
+            ty: ty.to_owned(),
+        }
+    }
+
+    fn create_snapshot(
+        inputs: Vec<MemberSnapshot>,
+        fields: Vec<MemberSnapshot>,
+        variants: &[&str],
+    ) -> ApiSnapshot {
+        ApiSnapshot {
+            functions: [(
+                "crate::api::greet".to_owned(),
+                FunctionSnapshot {
+                    id: 1,
+                    inputs,
+                    output: "String".to_owned(),
+                    sync: false,
+                },
+            )]
+            .into_iter()
+            .collect(),
+            structs: [("crate::api::Person".to_owned(), fields)]
+                .into_iter()
+                .collect(),
+            enums: [(
+                "crate::api::Mood".to_owned(),
+                (variants.iter())
+                    .map(|name| VariantSnapshot {
+                        name: name.to_string(),
+                        fields: vec![],
+                    })
+                    .collect(),
+            )]
+            .into_iter()
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compute_incompatible_changes() {
+        let expect = create_snapshot(
+            vec![member("name", "String")],
+            vec![member("name", "String"), member("age", "u32")],
+            &["Happy", "Sad"],
+        );
+
+        // Functions are numbered by their names, thus `a_new_fn` shifts the id of `greet`
+        let with_new_function = |name: &str, greet_id| {
+            let mut ans = expect.clone();
+            let greet = ans.functions.get_mut("crate::api::greet").unwrap();
+            greet.id = greet_id;
+            let new_function = FunctionSnapshot {
+                id: 3 - greet_id,
+                ..greet.clone()
+            };
+            ans.functions.insert(name.to_owned(), new_function);
+            ans
+        };
+        assert_eq!(
+            compute_incompatible_changes(&expect, &with_new_function("crate::api::hello", 1)),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            compute_incompatible_changes(&expect, &with_new_function("crate::api::a_new_fn", 2)),
+            vec!["function `crate::api::greet` changes id from 1 to 2"]
+        );
+
+        let actual = create_snapshot(
+            vec![member("name", "i32")],
+            vec![member("age", "u32"), member("name", "String")],
+            &["Happy", "Angry"],
+        );
+        assert_eq!(
+            compute_incompatible_changes(&expect, &actual),
+            vec![
+                "function `crate::api::greet` changes the type of input `name` from `String` to `i32`",
+                "struct `crate::api::Person` reorders its fields",
+                "enum `crate::api::Mood` removes variant `Sad`",
+                "enum `crate::api::Mood` adds variant `Angry`",
+            ]
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_check_compat_flags_shifted_function_ids() -> anyhow::Result<()> {
+        configure_opinionated_test_logging();
+        let temp_dir = tempfile::tempdir()?;
+        let snapshot_path = temp_dir.path().join("frb_api.lock.json");
+        let execute_check_compat = |fixture_name: &str, update: bool| {
+            env::set_current_dir(get_test_fixture_dir(fixture_name))?;
+            check_compat(Config::from_files_auto()?, &snapshot_path, update)
+        };
+
+        execute_check_compat("library/codegen/compat/mod/v1", true)?;
+        execute_check_compat("library/codegen/compat/mod/v1", false)?;
+
+        // `a_new_fn` is sorted before `greet`, thus takes over its id
+        let err = execute_check_compat("library/codegen/compat/mod/v2", false).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("- function `crate::api::greet` changes id from 1 to 2\n"),
+            "{err}"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::codegen::ir::mir::field::MirField;
+use crate::codegen::ir::mir::func::{MirFunc, MirFuncMode};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::ir::mir::ty::enumeration::MirVariantKind;
+use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The part of the API that both sides of the bridge rely on, keyed by the fully qualified Rust names
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct ApiSnapshot {
+    pub functions: BTreeMap<String, FunctionSnapshot>,
+    pub structs: BTreeMap<String, Vec<MemberSnapshot>>,
+    pub enums: BTreeMap<String, Vec<VariantSnapshot>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct FunctionSnapshot {
+    /// The generated code on both sides dispatches the calls by it
+    pub id: i32,
+    pub inputs: Vec<MemberSnapshot>,
+    pub output: String,
+    pub sync: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct MemberSnapshot {
+    pub name: String,
+    pub ty: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) struct VariantSnapshot {
+    pub name: String,
+    pub fields: Vec<MemberSnapshot>,
+}
+
+impl ApiSnapshot {
+    pub(super) fn compute(mir_pack: &MirPack) -> Self {
+        Self {
+            functions: (mir_pack.funcs_all.iter())
+                .map(|func| (func.name.rust_style(), FunctionSnapshot::compute(func)))
+                .collect(),
+            structs: (mir_pack.struct_pool.values())
+                .filter(|item| !item.ignore)
+                .map(|item| (item.name.rust_style(), compute_members(&item.fields)))
+                .collect(),
+            enums: (mir_pack.enum_pool.values())
+                .filter(|item| !item.ignore)
+                .map(|item| {
+                    let variants = (item.variants.iter())
+                        .map(|variant| VariantSnapshot {
+                            name: variant.name.rust_style(),
+                            fields: match &variant.kind {
+                                MirVariantKind::Value => vec![],
+                                MirVariantKind::Struct(st) => compute_members(&st.fields),
+                            },
+                        })
+                        .collect();
+                    (item.name.rust_style(), variants)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FunctionSnapshot {
+    fn compute(func: &MirFunc) -> Self {
+        let normal = rust_type(&func.output.normal);
+        Self {
+            id: func.id.expect("functions are numbered after parsing"),
+            inputs: (func.inputs.iter())
+                .map(|input| compute_member(&input.inner))
+                .collect(),
+            output: match &func.output.error {
+                Some(error) => format!("Result<{normal}, {}>", rust_type(error)),
+                None => normal,
+            },
+            sync: func.mode == MirFuncMode::Sync,
+        }
+    }
+}
+
+fn compute_members(fields: &[MirField]) -> Vec<MemberSnapshot> {
+    fields.iter().map(compute_member).collect()
+}
+
+fn compute_member(field: &MirField) -> MemberSnapshot {
+    MemberSnapshot {
+        name: field.name.rust_style(),
+        ty: rust_type(&field.ty),
+    }
+}
+
+/// Use the type in the Rust source, since the wrapper of the auto opaque types is an implementation detail
+fn rust_type(ty: &MirType) -> String {
+    match ty {
+        MirType::RustAutoOpaqueImplicit(ty) => ty.raw.string.with_original_lifetime().to_owned(),
+        _ => ty.rust_api_type(),
+    }
+}
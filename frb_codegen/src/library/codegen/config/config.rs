@@ -1,4 +1,6 @@
 use crate::codegen::dumper::internal_config::ConfigDumpContent;
+use crate::codegen::generator::misc::minimal_output::MinimalOutput;
+use crate::codegen::parser::mir::internal_config::TypeMapping;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -16,26 +18,51 @@ pub struct Config {
     pub rust_output: Option<String>,
     pub dart_entrypoint_class_name: Option<String>,
     pub dart_format_line_length: Option<u32>,
+    pub dart_format_command: Option<Vec<String>>,
+    pub rust_format: Option<bool>,
+    pub rust_format_command: Option<Vec<String>>,
     pub dart_preamble: Option<String>,
     pub rust_preamble: Option<String>,
     pub dart_enums_style: Option<bool>,
     pub add_mod_to_lib: Option<bool>,
     pub llvm_path: Option<Vec<String>>,
     pub llvm_compiler_opts: Option<String>,
+    pub cbindgen_config: Option<serde_json::Value>,
+    pub ffigen_config: Option<serde_json::Value>,
     pub dart_root: Option<String>,
     pub build_runner: Option<bool>,
     pub extra_headers: Option<String>,
     pub web: Option<bool>,
     pub deps_check: Option<bool>,
     pub dart3: Option<bool>,
+    pub dart_sealed_enums: Option<bool>,
     pub full_dep: Option<bool>,
+    pub method_channel: Option<bool>,
     pub local: Option<bool>,
     pub default_external_library_loader_web_prefix: Option<String>,
+    pub default_external_library_loader_io_stems: Option<HashMap<String, String>>,
+    pub default_external_library_loader_io_paths: Option<HashMap<String, String>>,
+    pub default_external_library_loader_io_process: Option<Vec<String>>,
     pub dart_type_rename: Option<HashMap<String, String>>,
+    pub type_mappings: Option<Vec<TypeMapping>>,
     pub enable_lifetime: Option<bool>,
     pub type_64bit_int: Option<bool>,
     pub default_dart_async: Option<bool>,
     pub stop_on_error: Option<bool>,
+    pub lazy_third_party: Option<bool>,
+    pub third_party: Option<Vec<String>>,
+    pub rust_feature_sets: Option<HashMap<String, Vec<String>>>,
+    pub shared_crates: Option<HashMap<String, String>>,
+    pub minimal_output: Option<MinimalOutput>,
+    pub dart_mocks: Option<bool>,
+    pub version_in_header: Option<bool>,
+    pub api_version: Option<String>,
+    pub plugins: Option<Vec<String>>,
+    pub cache: Option<bool>,
+    pub watch_paths: Option<Vec<String>>,
+    pub watch_ignore: Option<Vec<String>>,
+    pub watch_poll: Option<bool>,
+    pub watch_poll_interval_ms: Option<u64>,
     pub dump: Option<Vec<ConfigDumpContent>>,
     pub dump_all: Option<bool>,
 }
@@ -71,26 +98,51 @@ generate_merge!(
     rust_output,
     dart_entrypoint_class_name,
     dart_format_line_length,
+    dart_format_command,
+    rust_format,
+    rust_format_command,
     dart_preamble,
     rust_preamble,
     dart_enums_style,
     add_mod_to_lib,
     llvm_path,
     llvm_compiler_opts,
+    cbindgen_config,
+    ffigen_config,
     dart_root,
     build_runner,
     extra_headers,
     web,
     deps_check,
     dart3,
+    dart_sealed_enums,
     full_dep,
+    method_channel,
     local,
     default_external_library_loader_web_prefix,
+    default_external_library_loader_io_stems,
+    default_external_library_loader_io_paths,
+    default_external_library_loader_io_process,
     dart_type_rename,
+    type_mappings,
     enable_lifetime,
     type_64bit_int,
     default_dart_async,
     stop_on_error,
+    lazy_third_party,
+    third_party,
+    rust_feature_sets,
+    shared_crates,
+    minimal_output,
+    dart_mocks,
+    version_in_header,
+    api_version,
+    plugins,
+    cache,
+    watch_paths,
+    watch_ignore,
+    watch_poll,
+    watch_poll_interval_ms,
     dump,
     dump_all,
 );
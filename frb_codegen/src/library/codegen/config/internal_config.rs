@@ -4,10 +4,12 @@ use crate::codegen::generator::wire::c::internal_config::GeneratorWireCInternalC
 use crate::codegen::generator::wire::dart::internal_config::GeneratorWireDartInternalConfig;
 use crate::codegen::generator::wire::rust::internal_config::GeneratorWireRustInternalConfig;
 use crate::codegen::parser::internal_config::ParserInternalConfig;
+use crate::codegen::plugin::internal_config::PluginInternalConfig;
 use crate::codegen::polisher::internal_config::PolisherInternalConfig;
 use crate::codegen::preparer::internal_config::PreparerInternalConfig;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub(crate) struct InternalConfig {
@@ -15,6 +17,7 @@ pub(crate) struct InternalConfig {
     pub preparer: PreparerInternalConfig,
     pub parser: ParserInternalConfig,
     pub generator: GeneratorInternalConfig,
+    pub plugin: PluginInternalConfig,
     pub polisher: PolisherInternalConfig,
     pub dumper: DumperInternalConfig,
 }
@@ -24,6 +27,9 @@ pub(crate) struct ControllerInternalConfig {
     pub watch: bool,
     pub watching_paths: Vec<PathBuf>,
     pub exclude_paths: Vec<PathBuf>,
+    pub exclude_globs: Vec<String>,
+    /// Poll with this interval instead of using native notifications
+    pub poll_interval: Option<Duration>,
     pub max_count: Option<usize>,
 }
 
@@ -1,23 +1,64 @@
 use crate::codegen::config::internal_config::ControllerInternalConfig;
-use crate::codegen::MetaConfig;
-use std::path::Path;
+use crate::codegen::{Config, MetaConfig};
+use crate::utils::path_utils::{canonicalize_with_error_message, path_to_string};
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub(super) fn parse(
+    config: &Config,
     meta_config: &MetaConfig,
+    base_dir: &Path,
     rust_crate_dir: &Path,
+    first_party_crate_dirs: &[PathBuf],
     rust_output_path: &Path,
 ) -> anyhow::Result<ControllerInternalConfig> {
-    let watching_paths = vec![
+    let mut watching_paths = vec![
         // The whole crate needs to be watched, because e.g. when a struct definition changes
         // in a non-input file, it may still cause the generated code to change.
         rust_crate_dir.join("src"),
     ];
+    watching_paths.extend(first_party_crate_dirs.iter().map(|dir| dir.join("src")));
+    for path in config.watch_paths.clone().unwrap_or_default() {
+        watching_paths.push(canonicalize_with_error_message(&base_dir.join(path))?);
+    }
+
     let exclude_paths = vec![rust_output_path.to_owned()];
 
+    // Build outputs are never inputs, and may be inside the additionally watched crates
+    let mut exclude_globs = vec!["**/target/**".to_owned()];
+    for pattern in config.watch_ignore.clone().unwrap_or_default() {
+        exclude_globs.push(compute_exclude_glob(&pattern, base_dir)?);
+    }
+
+    let poll_interval = (config.watch_poll.unwrap_or_default()).then(|| {
+        Duration::from_millis(
+            config
+                .watch_poll_interval_ms
+                .unwrap_or(DEFAULT_POLL_INTERVAL_MS),
+        )
+    });
+
     Ok(ControllerInternalConfig {
         watch: meta_config.watch,
         watching_paths,
         exclude_paths,
+        exclude_globs,
+        poll_interval,
         max_count: None,
     })
 }
+
+const DEFAULT_POLL_INTERVAL_MS: u64 = 1000;
+
+/// Relative patterns are relative to the base dir, unless they start with `**`
+fn compute_exclude_glob(pattern: &str, base_dir: &Path) -> anyhow::Result<String> {
+    let pattern = if pattern.starts_with("**") || Path::new(pattern).is_absolute() {
+        pattern.to_owned()
+    } else {
+        path_to_string(&base_dir.join(pattern))?
+    };
+    glob::Pattern::new(&pattern)
+        .with_context(|| format!("Invalid watch_ignore glob: {pattern}"))?;
+    Ok(pattern)
+}
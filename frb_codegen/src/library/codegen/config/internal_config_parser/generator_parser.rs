@@ -13,19 +13,21 @@ use crate::codegen::generator::wire::rust::internal_config::GeneratorWireRustInt
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::codegen::Config;
 use crate::library::commands::cargo_metadata::execute_cargo_metadata;
+use crate::utils::crate_name::CrateName;
 use crate::utils::dart_repository::get_dart_package_name;
 use crate::utils::path_utils::path_to_string;
 use crate::utils::syn_utils::canonicalize_rust_type;
-use anyhow::Context;
+use anyhow::{ensure, Context};
 use itertools::Itertools;
 use pathdiff::diff_paths;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 
 pub(super) struct Args<'a> {
     pub config: &'a Config,
     pub dart_root: &'a Path,
     pub rust_crate_dir: &'a Path,
+    pub first_party_crate_names: &'a [CrateName],
     pub dart_output_path_pack: &'a DartOutputPathPack,
     pub dart_output_class_name_pack: &'a DartOutputClassNamePack,
     pub rust_output_path: &'a Path,
@@ -34,6 +36,8 @@ pub(super) struct Args<'a> {
     pub c_output_path: &'a Option<PathBuf>,
     pub web_enabled: bool,
     pub full_dep: bool,
+    pub cache_directory: Option<&'a Path>,
+    pub api_version: Option<&'a str>,
 }
 
 pub(super) fn parse(args: Args) -> anyhow::Result<GeneratorInternalConfig> {
@@ -41,6 +45,7 @@ pub(super) fn parse(args: Args) -> anyhow::Result<GeneratorInternalConfig> {
         config,
         dart_root,
         rust_crate_dir,
+        first_party_crate_names,
         dart_output_path_pack,
         dart_output_class_name_pack,
         rust_output_path,
@@ -49,23 +54,38 @@ pub(super) fn parse(args: Args) -> anyhow::Result<GeneratorInternalConfig> {
         c_output_path,
         web_enabled,
         full_dep,
+        cache_directory,
+        api_version,
     } = args;
 
     let dart_enums_style = config.dart_enums_style.unwrap_or(true);
     let dart3 = config.dart3.unwrap_or(true);
+    let dart_sealed_enums = config.dart_sealed_enums.unwrap_or_default();
+    ensure!(
+        dart3 || !dart_sealed_enums,
+        "dart_sealed_enums requires Dart 3, thus cannot be used together with dart3: false"
+    );
     let default_external_library_loader =
-        compute_default_external_library_loader(rust_crate_dir, dart_root, config);
-    let c_symbol_prefix = compute_c_symbol_prefix(dart_root)?;
+        compute_default_external_library_loader(rust_crate_dir, dart_root, config)?;
+    let c_symbol_prefix = compute_c_symbol_prefix(dart_root, api_version)?;
+    // Only the symbols exported by each generated file, while the ones from the runtime are shared
+    let exported_symbol_prefix = api_version.map(|x| format!("{x}_")).unwrap_or_default();
+    let minimal_output = config.minimal_output.unwrap_or_default();
+    let version_in_header = config.version_in_header.unwrap_or(true);
 
     Ok(GeneratorInternalConfig {
         api_dart: GeneratorApiDartInternalConfig {
             dart_enums_style,
             dart3,
+            dart_sealed_enums,
             dart_decl_base_output_path: dart_output_path_pack.dart_decl_base_output_path.clone(),
             dart_impl_output_path: dart_output_path_pack.dart_impl_output_path.clone(),
             dart_entrypoint_class_name: dart_output_class_name_pack.entrypoint_class_name.clone(),
             dart_preamble: config.dart_preamble.clone().unwrap_or_default(),
             dart_type_rename: compute_dart_type_rename(config)?,
+            shared_crates: compute_shared_crates(config),
+            first_party_crate_names: first_party_crate_names.to_owned(),
+            version_in_header,
         },
         wire: GeneratorWireInternalConfig {
             dart: GeneratorWireDartInternalConfig {
@@ -79,22 +99,31 @@ pub(super) fn parse(args: Args) -> anyhow::Result<GeneratorInternalConfig> {
                     .map(PathBuf::from)
                     .collect_vec(),
                 llvm_compiler_opts: config.llvm_compiler_opts.clone().unwrap_or_default(),
+                ffigen_config: config.ffigen_config.clone(),
                 extra_headers: config.extra_headers.clone().unwrap_or_default(),
                 dart_impl_output_path: dart_output_path_pack.dart_impl_output_path.clone(),
                 dart_output_class_name_pack: dart_output_class_name_pack.to_owned(),
                 default_external_library_loader,
                 c_symbol_prefix: c_symbol_prefix.clone(),
+                exported_symbol_prefix: exported_symbol_prefix.clone(),
                 has_ffigen: full_dep,
+                cache_directory: cache_directory.map(|x| x.to_owned()),
+                minimal_output,
+                version_in_header,
+                dart_mocks: config.dart_mocks.unwrap_or_default(),
             },
             rust: GeneratorWireRustInternalConfig {
                 rust_crate_dir: rust_crate_dir.to_owned(),
                 web_enabled,
                 rust_output_path: rust_output_path.to_owned(),
                 c_symbol_prefix: c_symbol_prefix.clone(),
+                exported_symbol_prefix,
                 has_ffigen: full_dep,
                 default_stream_sink_codec,
                 default_rust_opaque_codec,
                 rust_preamble: config.rust_preamble.clone().unwrap_or_default(),
+                minimal_output,
+                version_in_header,
             },
             c: GeneratorWireCInternalConfig {
                 enable: full_dep,
@@ -102,12 +131,14 @@ pub(super) fn parse(args: Args) -> anyhow::Result<GeneratorInternalConfig> {
                 rust_output_path: rust_output_path.to_owned(),
                 c_output_path: c_output_path.clone(),
                 c_symbol_prefix,
+                cbindgen_config: config.cbindgen_config.clone(),
+                cache_directory: cache_directory.map(|x| x.to_owned()),
             },
         },
     })
 }
 
-fn fallback_llvm_path() -> Vec<String> {
+pub(crate) fn fallback_llvm_path() -> Vec<String> {
     vec![
         "/opt/homebrew/opt/llvm".to_owned(), // Homebrew root
         "/usr/local/opt/llvm".to_owned(),    // Homebrew x86-64 root
@@ -125,35 +156,59 @@ fn fallback_llvm_path() -> Vec<String> {
     ]
 }
 
-fn compute_c_symbol_prefix(dart_root: &Path) -> anyhow::Result<String> {
+fn compute_c_symbol_prefix(dart_root: &Path, api_version: Option<&str>) -> anyhow::Result<String> {
     let package_name = get_dart_package_name(dart_root)?;
-    Ok(format!("frbgen_{package_name}_"))
+    let version_infix = api_version.map(|x| format!("{x}_")).unwrap_or_default();
+    Ok(format!("frbgen_{package_name}_{version_infix}"))
 }
 
 fn compute_default_external_library_loader(
     rust_crate_dir: &Path,
     dart_root: &Path,
     config: &Config,
-) -> GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig {
+) -> anyhow::Result<GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig> {
     const FALLBACK_DEFAULT_EXTERNAL_LIBRARY_STEM: &str = "UNKNOWN";
     const FALLBACK_DEFAULT_EXTERNAL_LIBRARY_RELATIVE_DIRECTORY: &str = "UNKNOWN";
 
-    GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig {
-        stem: compute_default_external_library_stem(rust_crate_dir)
-            .unwrap_or(FALLBACK_DEFAULT_EXTERNAL_LIBRARY_STEM.to_owned()),
-        io_directory: compute_default_external_library_relative_directory(
-            rust_crate_dir,
-            dart_root,
-        )
-        .unwrap_or(FALLBACK_DEFAULT_EXTERNAL_LIBRARY_RELATIVE_DIRECTORY.to_owned()),
-        web_prefix: config
-            .default_external_library_loader_web_prefix
-            .as_deref()
-            .unwrap_or("pkg/")
-            .into(),
+    let io_stems = BTreeMap::from_iter(
+        (config.default_external_library_loader_io_stems.clone()).unwrap_or_default(),
+    );
+    let io_paths = BTreeMap::from_iter(
+        (config.default_external_library_loader_io_paths.clone()).unwrap_or_default(),
+    );
+    let io_process =
+        (config.default_external_library_loader_io_process.clone()).unwrap_or_default();
+    for operating_system in (io_stems.keys()).chain(io_paths.keys()).chain(&io_process) {
+        ensure!(
+            IO_OPERATING_SYSTEMS.contains(&operating_system.as_str()),
+            "Unknown operating system `{operating_system}` in default_external_library_loader config, expecting one of {IO_OPERATING_SYSTEMS:?}"
+        );
     }
+
+    Ok(
+        GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig {
+            stem: compute_default_external_library_stem(rust_crate_dir)
+                .unwrap_or(FALLBACK_DEFAULT_EXTERNAL_LIBRARY_STEM.to_owned()),
+            io_directory: compute_default_external_library_relative_directory(
+                rust_crate_dir,
+                dart_root,
+            )
+            .unwrap_or(FALLBACK_DEFAULT_EXTERNAL_LIBRARY_RELATIVE_DIRECTORY.to_owned()),
+            web_prefix: config
+                .default_external_library_loader_web_prefix
+                .as_deref()
+                .unwrap_or("pkg/")
+                .into(),
+            io_stems,
+            io_paths,
+            io_process,
+        },
+    )
 }
 
+/// The values of `Platform.operatingSystem` in Dart, excluding the ones without `dart:ffi`
+const IO_OPERATING_SYSTEMS: [&str; 6] = ["android", "fuchsia", "ios", "linux", "macos", "windows"];
+
 fn compute_default_external_library_stem(rust_crate_dir: &Path) -> anyhow::Result<String> {
     let metadata = execute_cargo_metadata(&rust_crate_dir.join("Cargo.toml"))?;
     let package = metadata
@@ -173,6 +228,17 @@ fn compute_default_external_library_relative_directory(
     Ok(path_to_string(&diff.join("target").join("release/"))?.replace('\\', "/"))
 }
 
+fn compute_shared_crates(config: &Config) -> HashMap<String, String> {
+    (config.shared_crates.clone().unwrap_or_default().into_iter())
+        .map(|(crate_name, dart_import_prefix)| {
+            (
+                crate_name.replace('-', "_"),
+                dart_import_prefix.trim_end_matches('/').to_owned(),
+            )
+        })
+        .collect()
+}
+
 fn compute_dart_type_rename(config: &Config) -> anyhow::Result<HashMap<String, String>> {
     fn convert_rust_type(raw: &str) -> anyhow::Result<Vec<String>> {
         Ok(vec![
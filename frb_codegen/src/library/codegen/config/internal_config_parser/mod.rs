@@ -5,22 +5,26 @@ use crate::codegen::dumper::internal_config::DumperInternalConfig;
 use crate::codegen::generator::codec::structs::{CodecMode, CodecModePack};
 use crate::codegen::generator::wire::dart::internal_config::DartOutputClassNamePack;
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
-use crate::codegen::parser::hir::internal_config::ParserHirInternalConfig;
+use crate::codegen::parser::hir::internal_config::{ParserHirInternalConfig, RustFeatureSet};
 use crate::codegen::parser::internal_config::ParserInternalConfig;
 use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::plugin::internal_config::PluginInternalConfig;
 use crate::codegen::polisher::internal_config::PolisherInternalConfig;
 use crate::codegen::preparer::internal_config::PreparerInternalConfig;
 use crate::codegen::{Config, ConfigDumpContent};
+use crate::utils::dart_repository::dart_repo::read_formatter_page_width;
 use crate::utils::path_utils::{canonicalize_with_error_message, find_dart_package_dir};
-use anyhow::Result;
+use anyhow::{ensure, Result};
+use convert_case::{Case, Casing};
 use itertools::Itertools;
 use log::debug;
+use regex::Regex;
 use std::path::PathBuf;
 use strum::IntoEnumIterator;
 
 mod controller_parser;
 mod dart_path_parser;
-mod generator_parser;
+pub(crate) mod generator_parser;
 mod rust_path_migrator;
 mod rust_path_parser;
 
@@ -41,6 +45,8 @@ impl InternalConfig {
             rust_path_migrator::migrate_rust_input_config(&config.rust_root, &rust_input)?;
         let RustInputInfo {
             rust_crate_dir,
+            first_party_crate_names,
+            first_party_crate_dirs,
             third_party_crate_names,
             rust_input_namespace_pack,
             rust_output_path,
@@ -48,13 +54,18 @@ impl InternalConfig {
             &migrated_rust_input,
             &base_dir,
             &config.rust_output,
+            &config.third_party,
         )?;
 
         let dart_output_dir = canonicalize_with_error_message(&base_dir.join(dart_output))?;
         let dart_output_path_pack =
             dart_path_parser::compute_dart_output_path_pack(&dart_output_dir)?;
 
-        let dart_output_class_name_pack = compute_dart_output_class_name_pack(config);
+        let web_enabled = config.web.unwrap_or(true);
+        let api_version = parse_api_version(config, web_enabled)?;
+
+        let dart_output_class_name_pack =
+            compute_dart_output_class_name_pack(config, api_version.as_deref());
 
         let c_output_path = config.c_output.as_ref().map(|x| base_dir.join(x));
         let duplicated_c_output_path = config
@@ -70,22 +81,35 @@ impl InternalConfig {
                 .unwrap_or(find_dart_package_dir(&dart_output_dir)?),
         )?;
 
-        let web_enabled = config.web.unwrap_or(true);
+        let dart_format_line_length = match config.dart_format_line_length {
+            Some(line_length) => line_length,
+            None => read_formatter_page_width(&dart_root).unwrap_or(80),
+        };
 
         let dump_directory = rust_crate_dir.join("target").join("frb_dump");
+        let cache_directory = (config.cache.unwrap_or(true))
+            .then(|| rust_crate_dir.join("target").join("frb_codegen_cache"));
 
-        let full_dep = config.full_dep.unwrap_or(false);
+        let full_dep = parse_full_dep(config)?;
         let default_stream_sink_codec = generate_default_stream_sink_codec(full_dep);
         let default_rust_opaque_codec = generate_default_rust_opaque_codec(full_dep);
         let enable_local_dependency = config.local.unwrap_or_default();
         let stop_on_error = config.stop_on_error.unwrap_or_default();
 
-        let controller = controller_parser::parse(meta_config, &rust_crate_dir, &rust_output_path)?;
+        let controller = controller_parser::parse(
+            config,
+            meta_config,
+            &base_dir,
+            &rust_crate_dir,
+            &first_party_crate_dirs,
+            &rust_output_path,
+        )?;
 
         let generator = generator_parser::parse(generator_parser::Args {
             config,
             dart_root: &dart_root,
             rust_crate_dir: &rust_crate_dir,
+            first_party_crate_names: &first_party_crate_names,
             dart_output_path_pack: &dart_output_path_pack,
             dart_output_class_name_pack: &dart_output_class_name_pack,
             rust_output_path: &rust_output_path,
@@ -94,6 +118,8 @@ impl InternalConfig {
             c_output_path: &c_output_path,
             web_enabled,
             full_dep,
+            cache_directory: cache_directory.as_deref(),
+            api_version: api_version.as_deref(),
         })?;
 
         Ok(InternalConfig {
@@ -107,7 +133,13 @@ impl InternalConfig {
                 hir: ParserHirInternalConfig {
                     rust_crate_dir: rust_crate_dir.clone(),
                     rust_input_namespace_pack: rust_input_namespace_pack.clone(),
+                    first_party_crate_names,
                     third_party_crate_names,
+                    lazy_third_party: config.lazy_third_party.unwrap_or_default(),
+                    api_version: api_version.clone(),
+                    cache_directory,
+                    rust_output_path: rust_output_path.clone(),
+                    rust_feature_sets: compute_rust_feature_sets(config),
                 },
                 mir: ParserMirInternalConfig {
                     rust_input_namespace_pack: rust_input_namespace_pack.clone(),
@@ -118,12 +150,25 @@ impl InternalConfig {
                     enable_lifetime: config.enable_lifetime.unwrap_or_default(),
                     type_64bit_int: config.type_64bit_int.unwrap_or_default(),
                     default_dart_async: config.default_dart_async.unwrap_or(true),
+                    type_mappings: config.type_mappings.clone().unwrap_or_default(),
+                    web_enabled,
                 },
             },
             generator,
+            plugin: PluginInternalConfig {
+                plugin_paths: (config.plugins.clone().unwrap_or_default().iter())
+                    .map(|x| base_dir.join(x))
+                    .collect(),
+                base_dir: base_dir.clone(),
+                rust_crate_dir: rust_crate_dir.clone(),
+                dart_output_dir,
+            },
             polisher: PolisherInternalConfig {
                 duplicated_c_output_path,
-                dart_format_line_length: config.dart_format_line_length.unwrap_or(80),
+                dart_format_line_length,
+                dart_format_command: config.dart_format_command.clone(),
+                rust_format: config.rust_format.unwrap_or(true),
+                rust_format_command: config.rust_format_command.clone(),
                 add_mod_to_lib: config.add_mod_to_lib.unwrap_or(true),
                 build_runner: config.build_runner.unwrap_or(true),
                 web_enabled,
@@ -148,11 +193,59 @@ fn parse_dump_contents(config: &Config) -> Vec<ConfigDumpContent> {
     config.dump.clone().unwrap_or_default()
 }
 
-fn compute_dart_output_class_name_pack(config: &Config) -> DartOutputClassNamePack {
+/// Sorted, since the order of a map in the config file is not preserved
+fn compute_rust_feature_sets(config: &Config) -> Vec<RustFeatureSet> {
+    (config
+        .rust_feature_sets
+        .clone()
+        .unwrap_or_default()
+        .into_iter())
+    .map(|(name, features)| RustFeatureSet { name, features })
+    .sorted_by(|a, b| a.name.cmp(&b.name))
+    .collect_vec()
+}
+
+fn parse_full_dep(config: &Config) -> Result<bool> {
+    let full_dep = config.full_dep.unwrap_or(false);
+    // Only the PDE codec dispatches by function id, which `frb_method_channel_dispatch` relies on
+    ensure!(
+        !(full_dep && config.method_channel.unwrap_or_default()),
+        "`method_channel` requires `full_dep: false`, since the CST and SSE codecs of `full_dep` \
+         call the Rust functions via FFI directly, which the method channel transport cannot relay"
+    );
+    Ok(full_dep)
+}
+
+fn parse_api_version(config: &Config, web_enabled: bool) -> Result<Option<String>> {
+    let Some(api_version) = config.api_version.clone() else {
+        return Ok(None);
+    };
+    ensure!(
+        Regex::new(r"^[a-zA-Z][a-zA-Z0-9_]*$")
+            .unwrap()
+            .is_match(&api_version),
+        "`api_version` should be a simple identifier such as `v2`, but got `{api_version}`"
+    );
+    // Web exports cannot be renamed, thus two versions would clash in the same wasm module
+    ensure!(
+        !web_enabled,
+        "`api_version` is not supported on web yet, please disable web via `web: false`"
+    );
+    Ok(Some(api_version))
+}
+
+fn compute_dart_output_class_name_pack(
+    config: &Config,
+    api_version: Option<&str>,
+) -> DartOutputClassNamePack {
     const FALLBACK_DART_ENTRYPOINT_CLASS_NAME: &str = "RustLib";
 
-    let entrypoint_class_name = (config.dart_entrypoint_class_name.clone())
-        .unwrap_or(FALLBACK_DART_ENTRYPOINT_CLASS_NAME.to_owned());
+    let entrypoint_class_name = (config.dart_entrypoint_class_name.clone()).unwrap_or_else(|| {
+        format!(
+            "{FALLBACK_DART_ENTRYPOINT_CLASS_NAME}{}",
+            api_version.unwrap_or_default().to_case(Case::Pascal)
+        )
+    });
     let with_postfix = |postfix: &str| format!("{entrypoint_class_name}{postfix}");
 
     DartOutputClassNamePack {
@@ -249,4 +342,40 @@ mod tests {
             .contains("Rust output path needs to include the file name."));
         Ok(())
     }
+
+    #[test]
+    fn test_parse_full_dep() -> anyhow::Result<()> {
+        let config = |full_dep: bool, method_channel: bool| Config {
+            full_dep: Some(full_dep),
+            method_channel: Some(method_channel),
+            ..Default::default()
+        };
+
+        assert!(super::parse_full_dep(&config(true, false))?);
+        assert!(!super::parse_full_dep(&config(false, true))?);
+        let error = super::parse_full_dep(&config(true, true)).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("`method_channel` requires `full_dep: false`"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_api_version() -> anyhow::Result<()> {
+        let config = |api_version: &str| Config {
+            api_version: Some(api_version.to_owned()),
+            ..Default::default()
+        };
+
+        let api_version = super::parse_api_version(&config("v2"), false)?;
+        assert_eq!(api_version.as_deref(), Some("v2"));
+        assert_eq!(
+            super::compute_dart_output_class_name_pack(&config("v2"), api_version.as_deref())
+                .entrypoint_class_name,
+            "RustLibV2"
+        );
+        assert!(super::parse_api_version(&config("v2"), true).is_err());
+        assert!(super::parse_api_version(&config("2-beta"), false).is_err());
+        Ok(())
+    }
 }
@@ -1,14 +1,19 @@
 use crate::codegen::config::internal_config_parser::rust_path_migrator::ConfigRustRootAndRustInput;
 use crate::codegen::parser::mir::internal_config::RustInputNamespacePack;
+use crate::library::commands::cargo_metadata::execute_cargo_metadata;
 use crate::utils::crate_name::CrateName;
 use crate::utils::namespace::Namespace;
 use crate::utils::path_utils::canonicalize_with_error_message;
-use anyhow::ensure;
+use anyhow::{ensure, Context};
 use itertools::Itertools;
+use std::fs;
 use std::path::{Path, PathBuf};
 
 pub(super) struct RustInputInfo {
     pub rust_crate_dir: PathBuf,
+    pub first_party_crate_names: Vec<CrateName>,
+    /// Directories of the first-party crates, in the same order as their names
+    pub first_party_crate_dirs: Vec<PathBuf>,
     pub third_party_crate_names: Vec<CrateName>,
     pub rust_input_namespace_pack: RustInputNamespacePack,
     pub rust_output_path: PathBuf,
@@ -18,21 +23,39 @@ pub(super) fn compute_rust_path_info(
     migrated_rust_input: &ConfigRustRootAndRustInput,
     base_dir: &Path,
     config_rust_output: &Option<String>,
+    config_third_party: &Option<Vec<String>>,
 ) -> anyhow::Result<RustInputInfo> {
-    let rust_input_namespace_prefixes_raw =
-        compute_rust_input_namespace_prefixes_raw(&migrated_rust_input.rust_input);
-    sanity_check_rust_input_namespace_prefixes(&rust_input_namespace_prefixes_raw);
     let rust_crate_dir = compute_rust_crate_dir(base_dir, &migrated_rust_input.rust_root)?;
+    let rust_input_namespace_prefixes_raw = qualify_self_crate_modules(
+        compute_rust_input_namespace_prefixes_raw(&migrated_rust_input.rust_input),
+        &rust_crate_dir,
+    )?;
+    sanity_check_rust_input_namespace_prefixes(&rust_input_namespace_prefixes_raw);
     let rust_output_path = compute_rust_output_path(config_rust_output, base_dir, &rust_crate_dir)?;
 
     let rust_output_path_namespace =
         Namespace::new_from_rust_crate_path(&rust_output_path, &rust_crate_dir)?;
 
+    let OtherCrates {
+        first_party_crates,
+        third_party_crate_names,
+    } = partition_other_crate_names(
+        &rust_crate_dir,
+        compute_other_crate_names(&rust_input_namespace_prefixes_raw),
+    )?;
+    let (first_party_crate_names, first_party_crate_dirs): (Vec<_>, Vec<_>) =
+        first_party_crates.into_iter().unzip();
+    let third_party_crate_names = add_type_only_crate_names(
+        third_party_crate_names,
+        config_third_party,
+        &first_party_crate_names,
+    );
+
     Ok(RustInputInfo {
         rust_crate_dir,
-        third_party_crate_names: compute_third_party_crate_names(
-            &rust_input_namespace_prefixes_raw,
-        ),
+        first_party_crate_names,
+        first_party_crate_dirs,
+        third_party_crate_names,
         rust_input_namespace_pack: RustInputNamespacePack {
             rust_input_namespace_prefixes: tidy_rust_input_namespace_prefixes(
                 &rust_input_namespace_prefixes_raw,
@@ -63,6 +86,47 @@ fn compute_rust_input_namespace_prefixes_raw(raw_rust_input: &str) -> Vec<Namesp
         .collect()
 }
 
+/// The local module tree is checked before the crates, so that e.g. `api` means the `crate::api` module
+/// even if a dependency is also named `api`
+fn qualify_self_crate_modules(
+    rust_input_namespace_prefixes_raw: Vec<Namespace>,
+    rust_crate_dir: &Path,
+) -> anyhow::Result<Vec<Namespace>> {
+    if (rust_input_namespace_prefixes_raw.iter()).all(|x| x.crate_name().is_self_crate()) {
+        return Ok(rust_input_namespace_prefixes_raw);
+    }
+
+    let root_module_names = parse_root_module_names(rust_crate_dir)?;
+    Ok(rust_input_namespace_prefixes_raw
+        .into_iter()
+        .map(|x| {
+            if root_module_names.iter().any(|name| name == x.path()[0]) {
+                Namespace::new_self_crate(x.joined_path)
+            } else {
+                x
+            }
+        })
+        .collect())
+}
+
+fn parse_root_module_names(rust_crate_dir: &Path) -> anyhow::Result<Vec<String>> {
+    let Some(crate_root_path) = ["lib.rs", "main.rs"]
+        .iter()
+        .map(|name| rust_crate_dir.join("src").join(name))
+        .find(|path| path.exists())
+    else {
+        return Ok(vec![]);
+    };
+    let file = syn::parse_file(&fs::read_to_string(&crate_root_path)?)
+        .with_context(|| format!("Fail to parse {crate_root_path:?}"))?;
+    Ok((file.items.iter())
+        .filter_map(|item| match item {
+            syn::Item::Mod(item_mod) => Some(item_mod.ident.to_string()),
+            _ => None,
+        })
+        .collect())
+}
+
 fn tidy_rust_input_namespace_prefixes(raw: &[Namespace]) -> Vec<Namespace> {
     raw.iter()
         .map(|x| Namespace::new_raw(x.joined_path.replace('-', "_")))
@@ -98,9 +162,8 @@ fn fallback_rust_output_path(rust_crate_dir: &Path) -> PathBuf {
     rust_crate_dir.join("src").join("frb_generated.rs")
 }
 
-fn compute_third_party_crate_names(
-    rust_input_namespace_prefixes_raw: &[Namespace],
-) -> Vec<CrateName> {
+/// Crates other than the self crate, which are bridged because they are mentioned in `rust_input`
+fn compute_other_crate_names(rust_input_namespace_prefixes_raw: &[Namespace]) -> Vec<CrateName> {
     rust_input_namespace_prefixes_raw
         .iter()
         .map(|x| x.path()[0])
@@ -111,9 +174,110 @@ fn compute_third_party_crate_names(
         .collect_vec()
 }
 
+#[derive(Default)]
+struct OtherCrates {
+    first_party_crates: Vec<(CrateName, PathBuf)>,
+    third_party_crate_names: Vec<CrateName>,
+}
+
+/// The crates in the `third_party` config are only scanned for the types used by the API,
+/// while their functions are not bridged since they are not in `rust_input`.
+fn add_type_only_crate_names(
+    third_party_crate_names: Vec<CrateName>,
+    config_third_party: &Option<Vec<String>>,
+    first_party_crate_names: &[CrateName],
+) -> Vec<CrateName> {
+    let extra_crate_names = (config_third_party.iter().flatten())
+        .map(|x| CrateName::new(x.to_owned()))
+        .filter(|x| !x.is_self_crate() && !first_party_crate_names.contains(x));
+    (third_party_crate_names.into_iter())
+        .chain(extra_crate_names)
+        .unique_by(|x| x.namespace())
+        .collect_vec()
+}
+
+/// Local crates (e.g. other members of the Cargo workspace) are first-party,
+/// i.e. their API is merged into the generated Dart package as if it were in the self crate,
+/// while the crates from registries or git are third-party.
+fn partition_other_crate_names(
+    rust_crate_dir: &Path,
+    other_crate_names: Vec<CrateName>,
+) -> anyhow::Result<OtherCrates> {
+    let mut ans = OtherCrates::default();
+    if other_crate_names.is_empty() {
+        return Ok(ans);
+    }
+
+    let metadata = execute_cargo_metadata(&rust_crate_dir.join("Cargo.toml"))?;
+    for crate_name in other_crate_names {
+        let local_package = (metadata.packages.iter()).find(|package| {
+            package.source.is_none()
+                && package.name.replace('-', "_") == crate_name.namespace().joined_path
+        });
+        match local_package {
+            Some(package) => {
+                let dir = package.manifest_path.parent().unwrap().as_std_path();
+                ans.first_party_crates.push((crate_name, dir.to_owned()));
+            }
+            None => ans.third_party_crate_names.push(crate_name),
+        }
+    }
+    Ok(ans)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::test_utils::get_test_fixture_dir;
+
+    #[test]
+    fn test_local_module_and_crate_with_same_name() -> anyhow::Result<()> {
+        let rust_crate_dir = get_test_fixture_dir(
+            "library/codegen/config/internal_config_parser/local_module_and_crate",
+        );
+        let info = compute_rust_path_info(
+            &ConfigRustRootAndRustInput {
+                rust_root: ".".to_owned(),
+                rust_input: "api, helper::api".to_owned(),
+            },
+            &rust_crate_dir,
+            &None,
+            &None,
+        )?;
+
+        assert_eq!(
+            info.rust_input_namespace_pack.rust_input_namespace_prefixes,
+            vec![
+                Namespace::new_raw("crate::api".to_owned()),
+                Namespace::new_raw("helper::api".to_owned()),
+            ]
+        );
+        assert_eq!(
+            info.first_party_crate_names,
+            vec![CrateName::new("helper".to_owned())]
+        );
+        assert_eq!(info.third_party_crate_names, vec![]);
+
+        let other_crates = partition_other_crate_names(
+            &rust_crate_dir,
+            vec![
+                CrateName::new("helper".to_owned()),
+                CrateName::new("serde".to_owned()),
+            ],
+        )?;
+        assert_eq!(
+            other_crates.first_party_crates,
+            vec![(
+                CrateName::new("helper".to_owned()),
+                rust_crate_dir.join("helper")
+            )]
+        );
+        assert_eq!(
+            other_crates.third_party_crate_names,
+            vec![CrateName::new("serde".to_owned())]
+        );
+        Ok(())
+    }
 
     #[test]
     fn test_compute_rust_input_namespace_prefixes_raw() {
@@ -1,83 +1,116 @@
 use crate::codegen::config::internal_config::ControllerInternalConfig;
+use crate::utils::message_format::print_human;
 use crate::utils::path_utils::path_to_string;
+use glob::Pattern;
 use itertools::Itertools;
 use log::{debug, warn};
-use notify::{RecommendedWatcher, RecursiveMode};
-use notify_debouncer_mini::{new_debouncer, DebounceEventResult, Debouncer};
-use std::path::PathBuf;
+use notify::{Config as NotifyConfig, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+use notify_debouncer_mini::{
+    new_debouncer_opt, Config as DebouncerConfig, DebounceEventResult, Debouncer,
+};
+use std::any::Any;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc::Receiver;
 use std::time::Duration;
 
+/// `run_inner` is given the paths changed since its previous call, or `None` for the first call
 pub(super) fn run(
     config: &ControllerInternalConfig,
-    run_inner: &impl Fn() -> anyhow::Result<()>,
+    run_inner: &mut impl FnMut(Option<&[PathBuf]>) -> anyhow::Result<()>,
 ) -> anyhow::Result<()> {
     if config.watch {
-        run_watch(
-            run_inner,
-            &config.watching_paths,
-            &config.exclude_paths,
-            config.max_count.unwrap_or(10000000),
-        )
+        run_watch(run_inner, config, config.max_count.unwrap_or(10000000))
     } else {
-        run_inner()
+        run_inner(None)
     }
 }
 
 fn run_watch(
-    run_inner: &impl Fn() -> anyhow::Result<()>,
-    watching_paths: &[PathBuf],
-    exclude_paths: &[PathBuf],
+    run_inner: &mut impl FnMut(Option<&[PathBuf]>) -> anyhow::Result<()>,
+    config: &ControllerInternalConfig,
     max_count: usize,
 ) -> anyhow::Result<()> {
-    let (_watcher, fs_change_rx) = create_fs_watcher(watching_paths, exclude_paths.to_owned())?;
+    let (_watcher, fs_change_rx) = create_fs_watcher(config)?;
 
+    let mut changed_paths: Option<Vec<PathBuf>> = None;
     for _i in 0..max_count {
-        if let Err(e) = run_inner() {
+        if let Err(e) = run_inner(changed_paths.as_deref()) {
             // We do not care about the warning message
             // frb-coverage:ignore-start
             warn!("Error when running code generator: {e:?}");
             // frb-coverage:ignore-end
         }
 
-        println!(
+        print_human(&format!(
             "Watching file changes on {}...",
-            (watching_paths.iter())
+            (config.watching_paths.iter())
                 .map(|p| path_to_string(p).unwrap_or_default())
                 .join(", ")
-        );
+        ));
 
         // If `recv` call ends, then we see at least one change
-        fs_change_rx.recv()?;
+        let mut paths = fs_change_rx.recv()?;
         // Drain all other file changes
-        while fs_change_rx.try_recv().is_ok() {}
+        while let Ok(more_paths) = fs_change_rx.try_recv() {
+            paths.extend(more_paths);
+        }
+        changed_paths = Some(paths.into_iter().unique().collect());
     }
 
     Ok(())
 }
 
+type ChangedPathsReceiver = Receiver<Vec<PathBuf>>;
+
 fn create_fs_watcher(
+    config: &ControllerInternalConfig,
+) -> anyhow::Result<(Box<dyn Any>, ChangedPathsReceiver)> {
+    let filter = EventFilter::new(config)?;
+    Ok(match config.poll_interval {
+        Some(poll_interval) => {
+            let (debouncer, rx) = create_debouncer::<PollWatcher>(
+                &config.watching_paths,
+                NotifyConfig::default().with_poll_interval(poll_interval),
+                filter,
+            )?;
+            (Box::new(debouncer), rx)
+        }
+        None => {
+            let (debouncer, rx) = create_debouncer::<RecommendedWatcher>(
+                &config.watching_paths,
+                NotifyConfig::default(),
+                filter,
+            )?;
+            (Box::new(debouncer), rx)
+        }
+    })
+}
+
+fn create_debouncer<W: Watcher + 'static>(
     watching_paths: &[PathBuf],
-    exclude_paths: Vec<PathBuf>,
-) -> anyhow::Result<(Debouncer<RecommendedWatcher>, Receiver<()>)> {
+    notify_config: NotifyConfig,
+    filter: EventFilter,
+) -> anyhow::Result<(Debouncer<W>, ChangedPathsReceiver)> {
     // ref: https://github.com/notify-rs/notify/blob/main/examples/monitor_raw.rs
 
     let (tx, rx) = std::sync::mpsc::channel();
 
-    let mut debouncer = new_debouncer(
-        // Should not be too large, otherwise an event is only sent at the end of the interval
-        Duration::from_millis(300),
+    let mut debouncer = new_debouncer_opt::<_, W>(
+        DebouncerConfig::default()
+            // Should not be too large, otherwise an event is only sent at the end of the interval
+            .with_timeout(Duration::from_millis(300))
+            .with_notify_config(notify_config),
         move |event: DebounceEventResult| {
-            if is_event_interesting(&event, &exclude_paths) {
+            let interesting_paths = filter.interesting_paths(&event);
+            if !interesting_paths.is_empty() {
                 debug!("See interesting file change: {event:?}");
-                tx.send(()).unwrap()
+                tx.send(interesting_paths).unwrap()
                 // This bracket is weirdly not covered
                 // frb-coverage:ignore-start
             }
             // frb-coverage:ignore-end
         },
-    )
-    .unwrap();
+    )?;
 
     for path in watching_paths {
         debouncer.watcher().watch(path, RecursiveMode::Recursive)?;
@@ -86,13 +119,38 @@ fn create_fs_watcher(
     Ok((debouncer, rx))
 }
 
-fn is_event_interesting(event: &DebounceEventResult, exclude_paths: &[PathBuf]) -> bool {
-    if let Ok(event) = event {
-        (event.iter())
-            .map(|e| e.path.clone())
-            .all(|p| !exclude_paths.contains(&p.canonicalize().unwrap_or(p.clone())))
-    } else {
-        false
+struct EventFilter {
+    exclude_paths: Vec<PathBuf>,
+    exclude_globs: Vec<Pattern>,
+}
+
+impl EventFilter {
+    fn new(config: &ControllerInternalConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            exclude_paths: config.exclude_paths.clone(),
+            exclude_globs: (config.exclude_globs.iter())
+                .map(|pattern| Pattern::new(pattern))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    // A batch of the generated file and a real input file should still trigger a run,
+    // thus the paths are filtered one by one
+    fn interesting_paths(&self, event: &DebounceEventResult) -> Vec<PathBuf> {
+        if let Ok(event) = event {
+            (event.iter())
+                .filter(|e| !self.is_excluded(&e.path))
+                .map(|e| e.path.clone())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
+    fn is_excluded(&self, path: &Path) -> bool {
+        let path = path.canonicalize().unwrap_or(path.to_owned());
+        self.exclude_paths.contains(&path)
+            || (self.exclude_globs.iter()).any(|pattern| pattern.matches_path(&path))
     }
 }
 
@@ -111,15 +169,19 @@ mod tests {
         fs::create_dir_all(temp_dir.path().join("my_folder"))?;
 
         let run_inner_count = Mutex::new(0);
+        let mut received_changed_paths = vec![];
 
         run(
             &ControllerInternalConfig {
                 watch: true,
                 watching_paths: vec![temp_dir.path().join("my_folder")],
                 exclude_paths: vec![],
+                exclude_globs: vec![],
+                poll_interval: None,
                 max_count: Some(2),
             },
-            &|| {
+            &mut |changed_paths| {
+                received_changed_paths.push(changed_paths.map(|x| x.to_vec()));
                 let mut run_inner_count = run_inner_count.lock().unwrap();
                 *run_inner_count += 1;
                 fs::write(
@@ -131,6 +193,28 @@ mod tests {
         )?;
 
         assert_eq!(*run_inner_count.lock().unwrap(), 2);
+        assert_eq!(received_changed_paths[0], None);
+        assert!((received_changed_paths[1].as_ref().unwrap().iter())
+            .any(|path| path.ends_with("1.txt")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_event_filter_is_excluded() -> anyhow::Result<()> {
+        let filter = EventFilter::new(&ControllerInternalConfig {
+            watch: true,
+            watching_paths: vec![],
+            exclude_paths: vec![PathBuf::from("/crate/src/frb_generated.rs")],
+            exclude_globs: vec!["**/target/**".to_owned(), "/crate/src/gen_*.rs".to_owned()],
+            poll_interval: None,
+            max_count: None,
+        })?;
+
+        assert!(filter.is_excluded(Path::new("/crate/src/frb_generated.rs")));
+        assert!(filter.is_excluded(Path::new("/shared/target/debug/a.rs")));
+        assert!(filter.is_excluded(Path::new("/crate/src/gen_a.rs")));
+        assert!(!filter.is_excluded(Path::new("/crate/src/api/simple.rs")));
 
         Ok(())
     }
@@ -0,0 +1,45 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use strum_macros::Display;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum IrDumpStage {
+    /// The Rust items (functions, structs, traits, ...) after resolving modules and feature sets
+    Hir,
+    /// The API model, i.e. the functions and types that are bridged to Dart
+    Mir,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, ValueEnum, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum IrDumpFormat {
+    Json,
+}
+
+pub(crate) fn serialize<T: Serialize>(data: &T, format: IrDumpFormat) -> anyhow::Result<String> {
+    Ok(match format {
+        // Going through `Value` sorts the object keys, so the hash maps in the IR do not make the output vary
+        IrDumpFormat::Json => serde_json::to_string_pretty(&serde_json::to_value(data)?)? + "\n",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_serialize_json_is_deterministic() -> anyhow::Result<()> {
+        let data: HashMap<_, _> = (0..20).map(|i| (format!("key{i:02}"), i)).collect();
+        let text = serialize(&data, IrDumpFormat::Json)?;
+        let keys = (text.lines())
+            .filter_map(|line| line.trim().split('"').nth(1))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            (0..20).map(|i| format!("key{i:02}")).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+}
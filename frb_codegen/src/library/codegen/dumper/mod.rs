@@ -13,6 +13,7 @@ use std::path::Path;
 use strum::IntoEnumIterator;
 
 pub(super) mod internal_config;
+pub(super) mod ir;
 
 #[derive(Clone)]
 pub(crate) struct Dumper<'a> {
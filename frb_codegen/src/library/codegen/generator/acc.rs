@@ -1,5 +1,6 @@
 use crate::codegen::generator::misc::target::TargetOrCommon;
 use crate::enum_map;
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
 use std::iter::FromIterator;
 use std::ops::AddAssign;
 
@@ -41,6 +42,23 @@ impl<T> FromIterator<Acc<T>> for Acc<Vec<T>> {
     }
 }
 
+// Both `fold` and `reduce` of rayon keep the original order,
+// thus the result is the same as the sequential one
+impl<T: Send> FromParallelIterator<Acc<T>> for Acc<Vec<T>> {
+    fn from_par_iter<A: IntoParallelIterator<Item = Acc<T>>>(par_iter: A) -> Self {
+        par_iter
+            .into_par_iter()
+            .fold(Acc::<Vec<T>>::default, |mut acc, x| {
+                acc.push_acc(x);
+                acc
+            })
+            .reduce(Acc::<Vec<T>>::default, |mut acc, x| {
+                acc += x;
+                acc
+            })
+    }
+}
+
 impl<T> FromIterator<Acc<Vec<T>>> for Acc<Vec<T>> {
     fn from_iter<A: IntoIterator<Item = Acc<Vec<T>>>>(iter: A) -> Self {
         iter.into_iter()
@@ -1,4 +1,5 @@
 use crate::codegen::generator::misc::target::TargetOrCommonMap;
+use crate::utils::crate_name::CrateName;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -7,9 +8,15 @@ use std::path::PathBuf;
 pub(crate) struct GeneratorApiDartInternalConfig {
     pub dart_enums_style: bool,
     pub dart3: bool,
+    pub dart_sealed_enums: bool,
     pub dart_decl_base_output_path: PathBuf,
     pub dart_impl_output_path: TargetOrCommonMap<PathBuf>,
     pub dart_entrypoint_class_name: String,
     pub dart_preamble: String,
     pub dart_type_rename: HashMap<String, String>,
+    /// Crate name -> Dart import prefix of the package which already bridges that crate
+    pub shared_crates: HashMap<String, String>,
+    /// Crates whose API is generated next to the self crate instead of into the `third_party` directory
+    pub first_party_crate_names: Vec<CrateName>,
+    pub version_in_header: bool,
 }
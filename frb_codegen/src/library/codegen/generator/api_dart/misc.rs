@@ -1,12 +1,14 @@
 use crate::codegen::misc::THIRD_PARTY_DIR_NAME;
 use crate::utils::crate_name::CrateName;
 use crate::utils::namespace::Namespace;
-use itertools::concat;
+use itertools::{concat, Itertools};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 pub(crate) fn compute_path_from_namespace(
     dart_decl_base_output_path: &Path,
     namespace: &Namespace,
+    first_party_crate_names: &[CrateName],
 ) -> PathBuf {
     let raw_path = namespace.path();
     let chunks = if namespace == &CrateName::self_crate().namespace() {
@@ -15,6 +17,10 @@ pub(crate) fn compute_path_from_namespace(
     } else {
         match raw_path[0] {
             CrateName::SELF_CRATE => raw_path[1..].to_owned(),
+            // e.g. `core_api::sensors` becomes `core_api/sensors.dart`
+            x if (first_party_crate_names.iter()).any(|name| name.namespace().joined_path == x) => {
+                raw_path.clone()
+            }
             _ => concat([vec![THIRD_PARTY_DIR_NAME], raw_path.clone()]),
         }
     };
@@ -23,3 +29,98 @@ pub(crate) fn compute_path_from_namespace(
         (chunks.iter()).fold(dart_decl_base_output_path.to_owned(), |a, b| a.join(b));
     ans_without_extension.with_extension("dart")
 }
+
+/// If the namespace belongs to a crate bridged by another package (see `shared_crates` config),
+/// returns the Dart import of the file that package generates for the namespace.
+pub(crate) fn compute_shared_crate_import(
+    namespace: &Namespace,
+    shared_crates: &HashMap<String, String>,
+) -> Option<String> {
+    let raw_path = namespace.path();
+    let dart_import_prefix = shared_crates.get(*raw_path.first()?)?;
+    // Inside the other package, the crate is the self crate, thus reuse the same logic
+    // as when that package computes its output paths (e.g. the crate root becomes `lib.dart`)
+    let namespace_in_other_package = Namespace::new(
+        concat([vec![CrateName::SELF_CRATE], raw_path[1..].to_owned()])
+            .into_iter()
+            .map(|x| x.to_owned())
+            .collect(),
+    );
+    let path = compute_path_from_namespace(Path::new(""), &namespace_in_other_package, &[]);
+    let path = (path.iter()).map(|x| x.to_str().unwrap()).join("/");
+    Some(format!("{dart_import_prefix}/{path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::generator::api_dart::misc::{
+        compute_path_from_namespace, compute_shared_crate_import,
+    };
+    use crate::utils::crate_name::CrateName;
+    use crate::utils::namespace::Namespace;
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_compute_path_from_namespace() {
+        let first_party_crate_names = [CrateName::new("core-api".to_owned())];
+        let compute = |namespace: &str| {
+            compute_path_from_namespace(
+                Path::new("/lib/src/rust"),
+                &Namespace::new_raw(namespace.to_owned()),
+                &first_party_crate_names,
+            )
+        };
+
+        assert_eq!(compute("crate"), PathBuf::from("/lib/src/rust/lib.dart"));
+        assert_eq!(
+            compute("crate::api::user"),
+            PathBuf::from("/lib/src/rust/api/user.dart")
+        );
+        assert_eq!(
+            compute("core_api::sensors"),
+            PathBuf::from("/lib/src/rust/core_api/sensors.dart")
+        );
+        assert_eq!(
+            compute("web_audio_api::node"),
+            PathBuf::from("/lib/src/rust/third_party/web_audio_api/node.dart")
+        );
+    }
+
+    #[test]
+    fn test_compute_shared_crate_import() {
+        let shared_crates =
+            HashMap::from([("models".to_owned(), "package:models/src/rust".to_owned())]);
+        let compute = |namespace: &str| {
+            compute_shared_crate_import(&Namespace::new_raw(namespace.to_owned()), &shared_crates)
+        };
+
+        assert_eq!(
+            compute("models::api::user"),
+            Some("package:models/src/rust/api/user.dart".to_owned())
+        );
+        assert_eq!(
+            compute("models"),
+            Some("package:models/src/rust/lib.dart".to_owned())
+        );
+        assert_eq!(compute("crate::api::user"), None);
+
+        // Must be the same as the files generated by the package bridging the crate
+        let generated_path_in_other_package = compute_path_from_namespace(
+            Path::new("/lib/src/rust"),
+            &CrateName::self_crate().namespace(),
+            &[],
+        );
+        assert_eq!(
+            compute("models").unwrap(),
+            format!(
+                "package:models/src/rust/{}",
+                generated_path_in_other_package
+                    .file_name()
+                    .unwrap()
+                    .to_str()
+                    .unwrap()
+            )
+        );
+    }
+}
@@ -8,11 +8,15 @@ use crate::codegen::dumper::Dumper;
 use crate::codegen::generator::api_dart::internal_config::GeneratorApiDartInternalConfig;
 use crate::codegen::generator::misc::path_texts::PathTexts;
 use crate::codegen::ir::mir::pack::MirPack;
+use crate::utils::crate_name::CrateName;
 use anyhow::Result;
+use itertools::Itertools;
 
 pub(crate) struct GeneratorApiDartOutput {
     pub output_texts: PathTexts,
     pub needs_freezed: bool,
+    /// Crates whose API is generated into the `third_party` directory
+    pub third_party_crates: Vec<CrateName>,
 }
 
 pub(crate) fn generate(
@@ -34,6 +38,17 @@ pub(crate) fn generate(
     Ok(GeneratorApiDartOutput {
         output_texts: text.output_texts,
         needs_freezed: spec.namespaced_items.values().any(|x| x.needs_freezed),
+        third_party_crates: (spec.namespaced_items.keys())
+            .map(|namespace| namespace.path()[0])
+            .filter(|&x| x != CrateName::SELF_CRATE)
+            .filter(|&x| {
+                !(config.first_party_crate_names.iter())
+                    .any(|name| name.namespace().joined_path == x)
+            })
+            .unique()
+            .sorted()
+            .map(|x| CrateName::new(x.to_owned()))
+            .collect_vec(),
     })
 }
 
@@ -76,6 +91,18 @@ mod tests {
         )
     }
 
+    #[test]
+    #[serial]
+    fn test_lazy() -> anyhow::Result<()> {
+        body(
+            "library/codegen/generator/api_dart/mod/lazy",
+            HashMap::from([
+                ("api.dart", "expect_output.dart"),
+                ("frb_generated.dart", "expect_output2.dart"),
+            ]),
+        )
+    }
+
     fn body(fixture_name: &str, expect_outputs: HashMap<&str, &str>) -> anyhow::Result<()> {
         configure_opinionated_test_logging();
         let test_fixture_dir = get_test_fixture_dir(fixture_name);
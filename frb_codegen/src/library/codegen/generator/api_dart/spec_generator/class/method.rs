@@ -14,6 +14,7 @@ use crate::library::codegen::generator::api_dart::spec_generator::base::*;
 use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
 use crate::utils::basic_code::dart_header_code::DartHeaderCode;
 use crate::utils::dart_keywords;
+use anyhow::Context;
 use convert_case::{Case, Casing};
 use itertools::Itertools;
 
@@ -42,6 +43,8 @@ pub(crate) enum GenerateApiMethodMode {
     Nothing,
     DeclOnly,
     DeclAndImpl,
+    /// Overrides the declaration with a body throwing [UnimplementedError], e.g. for fakes
+    DeclAndStub,
 }
 
 pub(crate) struct GeneratedApiMethods {
@@ -160,11 +163,7 @@ fn generate_api_method(
 
     let default_constructor_mode = func.default_constructor_mode();
 
-    let skip_names = compute_skip_names(method_info);
-    let params = (api_dart_func.func_params.iter())
-        .filter(|param| !skip_names.contains(&&param.name_str[..]))
-        .cloned()
-        .collect_vec();
+    let params = compute_params(&api_dart_func, method_info);
     let method_name = generate_method_name(method_info, default_constructor_mode);
 
     let comments = generate_comments(func, default_constructor_mode);
@@ -178,16 +177,25 @@ fn generate_api_method(
         dart_class_name,
     );
 
-    let maybe_implementation = match config.get(&method_info.mode) {
+    let mode = config.get(&method_info.mode);
+    let maybe_implementation = match mode {
         GenerateApiMethodMode::Nothing => return None,
         GenerateApiMethodMode::DeclOnly => "".to_owned(),
         GenerateApiMethodMode::DeclAndImpl => format!(
             "=>{}",
             generate_implementation(func, context, method_info, &params)
         ),
+        GenerateApiMethodMode::DeclAndStub => {
+            format!("=> throw UnimplementedError('{dart_class_name}.{method_name}')")
+        }
+    };
+    let maybe_override = if matches!(mode, GenerateApiMethodMode::DeclAndStub) {
+        "@override "
+    } else {
+        ""
     };
 
-    let code = format!("{comments}{signature}{maybe_implementation};\n\n");
+    let code = format!("{comments}{maybe_override}{signature}{maybe_implementation};\n\n");
 
     Some(GeneratedApiMethod {
         code,
@@ -195,6 +203,38 @@ fn generate_api_method(
     })
 }
 
+/// The signature of the method as it appears in the Dart class, e.g. for the API reference
+pub(crate) fn generate_api_method_signature(
+    func: &MirFunc,
+    context: ApiDartGeneratorContext,
+    dart_class_name: &str,
+) -> anyhow::Result<String> {
+    let api_dart_func = api_dart::spec_generator::function::generate(func, context)?;
+    let method_info = if_then_some!(let MirFuncOwnerInfo::Method(info) = &func.owner, info)
+        .context("not a method")?;
+    let default_constructor_mode = func.default_constructor_mode();
+    Ok(generate_signature(
+        func,
+        method_info,
+        &compute_params(&api_dart_func, method_info),
+        default_constructor_mode,
+        &api_dart_func,
+        &generate_method_name(method_info, default_constructor_mode),
+        dart_class_name,
+    ))
+}
+
+fn compute_params(
+    api_dart_func: &ApiDartGeneratedFunction,
+    method_info: &MirFuncOwnerInfoMethod,
+) -> Vec<ApiDartGeneratedFunctionParam> {
+    let skip_names = compute_skip_names(method_info);
+    (api_dart_func.func_params.iter())
+        .filter(|param| !skip_names.contains(&&param.name_str[..]))
+        .cloned()
+        .collect_vec()
+}
+
 fn compute_skip_names(method_info: &MirFuncOwnerInfoMethod) -> Vec<&'static str> {
     let mut ans = vec![];
     if method_info.mode != MirFuncOwnerInfoMethodMode::Static {
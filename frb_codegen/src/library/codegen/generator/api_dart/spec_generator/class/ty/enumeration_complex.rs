@@ -22,6 +22,10 @@ impl<'a> EnumRefApiDartGenerator<'a> {
         extra_body: &str,
         header: DartHeaderCode,
     ) -> Option<ApiDartGeneratedClass> {
+        if self.context.config.dart_sealed_enums {
+            return self.generate_mode_sealed(src, extra_body, header);
+        }
+
         let variants = src
             .variants()
             .iter()
@@ -129,17 +133,21 @@ impl<'a> EnumRefApiDartGenerator<'a> {
     }
 
     fn generate_implements_exception(&self, variant: &MirEnumVariant) -> &str {
-        let has_backtrace = matches!(&variant.kind,
-            MirVariantKind::Struct(MirStruct {is_fields_named: true, fields, ..}) if fields.iter().any(|field| field.name.rust_style() == BACKTRACE_IDENT));
-        if self.mir.is_exception && has_backtrace {
+        if self.is_backtraced_exception(variant) {
             "@Implements<FrbBacktracedException>()"
         } else {
             ""
         }
     }
+
+    pub(super) fn is_backtraced_exception(&self, variant: &MirEnumVariant) -> bool {
+        let has_backtrace = matches!(&variant.kind,
+            MirVariantKind::Struct(MirStruct {is_fields_named: true, fields, ..}) if fields.iter().any(|field| field.name.rust_style() == BACKTRACE_IDENT));
+        self.mir.is_exception && has_backtrace
+    }
 }
 
-fn optional_boundary_index(fields: &[MirField]) -> Option<usize> {
+pub(super) fn optional_boundary_index(fields: &[MirField]) -> Option<usize> {
     fields
         .iter()
         .enumerate()
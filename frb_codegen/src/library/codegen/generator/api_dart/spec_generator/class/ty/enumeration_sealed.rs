@@ -0,0 +1,174 @@
+use crate::codegen::generator::api_dart::spec_generator::class::field::{
+    generate_field_default, generate_field_required_modifier,
+};
+use crate::codegen::generator::api_dart::spec_generator::class::ty::enumeration_complex::optional_boundary_index;
+use crate::codegen::generator::api_dart::spec_generator::class::ty::structure_non_freezed::{
+    generate_equals, generate_hashcode,
+};
+use crate::codegen::generator::api_dart::spec_generator::class::ApiDartGeneratedClass;
+use crate::codegen::generator::api_dart::spec_generator::misc::{
+    generate_dart_comments, generate_dart_maybe_implements_exception,
+};
+use crate::codegen::ir::mir::field::MirField;
+use crate::codegen::ir::mir::ty::enumeration::{MirEnum, MirEnumVariant, MirVariantKind};
+use crate::library::codegen::generator::api_dart::spec_generator::base::*;
+use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
+use crate::utils::basic_code::dart_header_code::DartHeaderCode;
+use itertools::Itertools;
+
+impl<'a> EnumRefApiDartGenerator<'a> {
+    /// Plain Dart 3 sealed class hierarchy, i.e. the same constructors and classes as the
+    /// freezed mode, but without requiring `build_runner` (thus without `when`, `copyWith` etc).
+    pub(super) fn generate_mode_sealed(
+        &self,
+        src: &MirEnum,
+        extra_body: &str,
+        header: DartHeaderCode,
+    ) -> Option<ApiDartGeneratedClass> {
+        let name = &self.mir.ident.0.name;
+        let factories = (src.variants().iter())
+            .map(|variant| self.generate_mode_sealed_factory(variant))
+            .join("\n");
+        let variant_classes = (src.variants().iter())
+            .map(|variant| self.generate_mode_sealed_variant_class(variant))
+            .join("\n\n");
+        let maybe_implements_exception =
+            generate_dart_maybe_implements_exception(self.mir.is_exception);
+
+        Some(ApiDartGeneratedClass {
+            namespace: src.name.namespace.clone(),
+            class_name: name.clone(),
+            code: format!(
+                "sealed class {name} {maybe_implements_exception} {{
+                    const {name}();
+
+                    {factories}
+
+                    {extra_body}
+                }}
+
+                {variant_classes}",
+            ),
+            needs_freezed: false,
+            header,
+        })
+    }
+
+    fn generate_mode_sealed_factory(&self, variant: &MirEnumVariant) -> String {
+        let fields = variant_fields(variant);
+        let params = self.generate_mode_sealed_params(variant, fields, false);
+        format!(
+            "{}const factory {}.{}({params}) = {};",
+            generate_dart_comments(&variant.comments),
+            self.mir.ident.0.name,
+            variant.name.dart_style(),
+            variant.wrapper_name.rust_style(),
+        )
+    }
+
+    fn generate_mode_sealed_variant_class(&self, variant: &MirEnumVariant) -> String {
+        let name = &self.mir.ident.0.name;
+        let class_name = variant.wrapper_name.rust_style();
+        let fields = variant_fields(variant);
+
+        let field_declarations = (fields.iter())
+            .map(|field| {
+                format!(
+                    "{}final {} {};",
+                    generate_dart_comments(&field.comments),
+                    ApiDartGenerator::new(field.ty.clone(), self.context).dart_api_type(),
+                    field.name.dart_style(),
+                )
+            })
+            .join("\n");
+        let params = self.generate_mode_sealed_params(variant, fields, true);
+        let maybe_implements_backtraced = if self.is_backtraced_exception(variant) {
+            "implements FrbBacktracedException"
+        } else {
+            ""
+        };
+        let to_string_args = (fields.iter())
+            .map(|field| format!("{0}: ${0}", field.name.dart_style()))
+            .join(", ");
+        let hashcode = generate_hashcode(fields);
+        let equals = generate_equals(fields, &class_name);
+
+        format!(
+            "{comments}final class {class_name} extends {name} {maybe_implements_backtraced} {{
+                {field_declarations}
+
+                const {class_name}({params});
+
+                @override
+                String toString() => '{name}.{variant_name}({to_string_args})';
+
+                {hashcode}
+
+                {equals}
+            }}",
+            comments = generate_dart_comments(&variant.comments),
+            variant_name = variant.name.dart_style(),
+        )
+    }
+
+    /// The redirecting factories take the default values from the constructors they redirect to,
+    /// thus only the latter (`initializing`) declare them.
+    fn generate_mode_sealed_params(
+        &self,
+        variant: &MirEnumVariant,
+        fields: &[MirField],
+        initializing: bool,
+    ) -> String {
+        let is_fields_named =
+            matches!(&variant.kind, MirVariantKind::Struct(st) if st.is_fields_named);
+        // Same as the freezed mode, unnamed fields can only have default values when they are all optional at the tail
+        let boundary = (!is_fields_named)
+            .then(|| optional_boundary_index(fields))
+            .flatten();
+        let allow_default = initializing && (is_fields_named || boundary.is_some());
+
+        let params = (fields.iter())
+            .map(|field| {
+                let required = if is_fields_named {
+                    generate_field_required_modifier(field)
+                } else {
+                    ""
+                };
+                let param = if initializing {
+                    format!("this.{}", field.name.dart_style())
+                } else {
+                    format!(
+                        "{} {}",
+                        ApiDartGenerator::new(field.ty.clone(), self.context).dart_api_type(),
+                        field.name.dart_style(),
+                    )
+                };
+                let default = if allow_default {
+                    generate_field_default(field, false, self.context.config.dart_enums_style)
+                } else {
+                    "".to_owned()
+                };
+                format!("{required}{param} {default},")
+            })
+            .collect_vec();
+
+        if is_fields_named {
+            if params.is_empty() {
+                "".to_owned()
+            } else {
+                format!("{{ {} }}", params.join(""))
+            }
+        } else if let Some(idx) = boundary {
+            format!("{}[{}]", params[..idx].join(""), params[idx..].join(""))
+        } else {
+            params.join("")
+        }
+    }
+}
+
+fn variant_fields(variant: &MirEnumVariant) -> &[MirField] {
+    match &variant.kind {
+        MirVariantKind::Value => &[],
+        MirVariantKind::Struct(st) => &st.fields,
+    }
+}
@@ -5,10 +5,12 @@ use enum_dispatch::enum_dispatch;
 pub(crate) mod delegate;
 pub(crate) mod enumeration;
 pub(crate) mod enumeration_complex;
+mod enumeration_sealed;
 pub(crate) mod enumeration_simple;
 pub(crate) mod rust_opaque;
 pub(crate) mod structure;
 mod structure_freezed;
+mod structure_lazy;
 mod structure_non_freezed;
 mod trait_def;
 
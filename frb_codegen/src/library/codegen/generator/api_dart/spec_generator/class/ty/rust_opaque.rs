@@ -143,8 +143,7 @@ fn generate_implements_traits(
     let interest_trait_impls = all_trait_impls
         .iter()
         .filter(|x| {
-            (get_candidate_safe_idents_for_matching(&x.impl_ty).iter())
-                .any(|x| x == &self_type.safe_ident())
+            (x.impl_ty_safe_idents_for_matching().iter()).any(|x| x == &self_type.safe_ident())
         })
         .collect_vec();
 
@@ -175,13 +174,3 @@ fn generate_implements_traits(
         },
     )
 }
-
-#[allow(clippy::single_match)]
-fn get_candidate_safe_idents_for_matching(ty: &MirType) -> Vec<String> {
-    let mut ans = vec![ty.safe_ident()];
-    match ty {
-        MirType::RustAutoOpaqueImplicit(ty) => ans.push(ty.inner.safe_ident()),
-        _ => {}
-    }
-    ans
-}
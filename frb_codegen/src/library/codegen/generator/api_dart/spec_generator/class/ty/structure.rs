@@ -36,7 +36,17 @@ impl<'a> ApiDartGeneratorClassTrait for StructRefApiDartGenerator<'a> {
         Some(ApiDartGeneratedClass {
             namespace: src.name.namespace.clone(),
             class_name: class_name.to_owned(),
-            code: if src.using_freezed() {
+            code: if src.lazy {
+                self.generate_mode_lazy(
+                    src,
+                    &comments,
+                    &metadata,
+                    &methods,
+                    constructor_postfix,
+                    extra_body,
+                    class_name,
+                )
+            } else if src.using_freezed() {
                 self.generate_mode_freezed(
                     src,
                     &comments,
@@ -57,7 +67,7 @@ impl<'a> ApiDartGeneratorClassTrait for StructRefApiDartGenerator<'a> {
                     class_name,
                 )
             },
-            needs_freezed: src.using_freezed(),
+            needs_freezed: src.using_freezed() && !src.lazy,
             header: methods.header + extra_code.header,
         })
     }
@@ -0,0 +1,135 @@
+use crate::codegen::generator::api_dart::spec_generator::class::field::{
+    generate_field_default, generate_field_required_modifier,
+};
+use crate::codegen::generator::api_dart::spec_generator::class::method::GeneratedApiMethods;
+use crate::codegen::generator::api_dart::spec_generator::class::ty::structure_non_freezed::{
+    generate_equals, generate_hashcode,
+};
+use crate::codegen::generator::api_dart::spec_generator::misc::{
+    generate_dart_comments, generate_dart_maybe_implements_exception,
+};
+use crate::codegen::ir::mir::ty::structure::MirStruct;
+use crate::library::codegen::generator::api_dart::spec_generator::base::*;
+use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
+use itertools::Itertools;
+
+impl<'a> StructRefApiDartGenerator<'a> {
+    /// Each field is stored as a `LazyField`, which holds the serialized bytes
+    /// until the getter is called for the first time (except for fields owning e.g. opaque objects,
+    /// which are decoded by the SSE codec right away).
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn generate_mode_lazy(
+        &self,
+        src: &MirStruct,
+        comments: &str,
+        metadata: &str,
+        methods: &GeneratedApiMethods,
+        constructor_postfix: &str,
+        extra_body: &str,
+        class_name: &str,
+    ) -> String {
+        let fields = (src.fields.iter())
+            .map(|f| {
+                (
+                    f,
+                    ApiDartGenerator::new(f.ty.clone(), self.context).dart_api_type(),
+                    f.name.dart_style(),
+                )
+            })
+            .collect_vec();
+
+        let field_declarations = (fields.iter())
+            .map(|(f, type_str, name_str)| {
+                let maybe_final = if f.is_final { "final " } else { "" };
+                format!("{maybe_final}LazyField<{type_str}> _{name_str};")
+            })
+            .join("\n");
+
+        let accessors = (fields.iter())
+            .map(|(f, type_str, name_str)| {
+                let comments = generate_dart_comments(&f.comments);
+                let setter = if f.is_final {
+                    "".to_owned()
+                } else {
+                    format!(
+                        "set {name_str}({type_str} value) => _{name_str} = LazyField.value(value);"
+                    )
+                };
+                format!("{comments}{type_str} get {name_str} => _{name_str}.value;\n{setter}")
+            })
+            .join("\n");
+
+        let constructor_params = (fields.iter())
+            .map(|(f, type_str, name_str)| {
+                format!(
+                    "{required}{type_str} {name_str} {default},",
+                    required = generate_field_required_modifier(f),
+                    default =
+                        generate_field_default(f, false, self.context.config.dart_enums_style),
+                )
+            })
+            .join("");
+        let constructor_initializers = (fields.iter())
+            .map(|(_, _, name_str)| format!("_{name_str} = LazyField.value({name_str})"))
+            .join(", ");
+
+        let lazy_constructor_params = (fields.iter())
+            .map(|(_, type_str, name_str)| format!("required LazyField<{type_str}> {name_str},"))
+            .join("");
+        let lazy_constructor_initializers = (fields.iter())
+            .map(|(_, _, name_str)| format!("_{name_str} = {name_str}"))
+            .join(", ");
+
+        let (constructor_params, lazy_constructor_params) = if fields.is_empty() {
+            ("".to_owned(), "".to_owned())
+        } else {
+            (
+                format!("{{{constructor_params}}}"),
+                format!("{{{lazy_constructor_params}}}"),
+            )
+        };
+        let maybe_colon = |initializers: &str| {
+            if initializers.is_empty() {
+                "".to_owned()
+            } else {
+                format!(": {initializers}")
+            }
+        };
+        let constructor_initializers = maybe_colon(&constructor_initializers);
+        let lazy_constructor_initializers = maybe_colon(&lazy_constructor_initializers);
+
+        let implements_exception = generate_dart_maybe_implements_exception(self.mir.is_exception);
+        let methods_str = &methods.code;
+
+        let hashcode = if src.generate_hash {
+            generate_hashcode(&src.fields)
+        } else {
+            "".to_owned()
+        };
+        let equals = if src.generate_eq {
+            generate_equals(&src.fields, class_name)
+        } else {
+            "".to_owned()
+        };
+
+        format!(
+            "{comments}{metadata}class {class_name} {implements_exception} {{
+                {field_declarations}
+
+                {class_name}{constructor_postfix}({constructor_params}){constructor_initializers};
+
+                @internal
+                {class_name}.lazy({lazy_constructor_params}){lazy_constructor_initializers};
+
+                {accessors}
+
+                {methods_str}
+                {extra_body}
+
+                {hashcode}
+
+                {equals}
+            }}"
+        )
+    }
+}
@@ -99,7 +99,7 @@ impl<'a> StructRefApiDartGenerator<'a> {
     }
 }
 
-fn generate_hashcode(fields: &[MirField]) -> String {
+pub(super) fn generate_hashcode(fields: &[MirField]) -> String {
     let body = if fields.is_empty() {
         "0".to_owned()
     } else {
@@ -118,7 +118,7 @@ fn generate_hashcode(fields: &[MirField]) -> String {
     )
 }
 
-fn generate_equals(fields: &[MirField], struct_name: &str) -> String {
+pub(super) fn generate_equals(fields: &[MirField], struct_name: &str) -> String {
     let cmp = fields
         .iter()
         .map(|x| x.name.dart_style())
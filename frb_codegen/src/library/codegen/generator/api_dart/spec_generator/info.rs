@@ -1,4 +1,5 @@
 use crate::codegen::generator::api_dart::spec_generator::base::*;
+use crate::codegen::generator::codec::message::message_codec_entrypoint;
 use crate::codegen::ir::mir::ty::delegate::{
     MirTypeDelegate, MirTypeDelegateArray, MirTypeDelegateArrayMode, MirTypeDelegatePrimitiveEnum,
     MirTypeDelegateTime,
@@ -85,6 +86,7 @@ impl<'a> ApiDartGeneratorInfoTrait for DelegateApiDartGenerator<'a> {
                 "RustStreamSink<{}>",
                 ApiDartGenerator::new(*mir.inner_ok.clone(), self.context).dart_api_type(),
             ),
+            MirTypeDelegate::Progress(_) => "FrbProgress".to_owned(),
             MirTypeDelegate::BigPrimitive(_) => "BigInt".to_owned(),
             MirTypeDelegate::CastedPrimitive(mir) => match mir.inner {
                 MirTypePrimitive::U64
@@ -109,6 +111,7 @@ impl<'a> ApiDartGeneratorInfoTrait for DelegateApiDartGenerator<'a> {
                 ApiDartGenerator::new(mir.api_type.clone(), self.context).dart_api_type()
             }
             MirTypeDelegate::CustomSerDes(mir) => mir.info.dart_api_type.clone(),
+            MirTypeDelegate::MessageCodec(mir) => mir.dart_type.clone(),
         }
     }
 
@@ -117,6 +120,9 @@ impl<'a> ApiDartGeneratorInfoTrait for DelegateApiDartGenerator<'a> {
             MirTypeDelegate::Uuid /*| MirTypeDelegate::Uuids*/ => {
                 Some("import 'package:uuid/uuid.dart';".to_owned())
             }
+            MirTypeDelegate::MessageCodec(mir) => message_codec_entrypoint(mir.codec).dart_import(),
+            MirTypeDelegate::CustomSerDes(mir) => (mir.info.dart_import.as_ref())
+                .map(|dart_import| format!("import '{dart_import}';")),
             _ => None,
         }
     }
@@ -90,15 +90,26 @@ fn generate_imports_from_ty(
     context: ApiDartGeneratorContext,
 ) -> anyhow::Result<String> {
     let import_ty_itself = if let Some(ty_namespace) = ty.self_namespace() {
-        if &ty_namespace != current_file_namespace {
+        if let Some(shared_import) = api_dart::misc::compute_shared_crate_import(
+            &ty_namespace,
+            &context.config.shared_crates,
+        ) {
+            format!("import '{shared_import}';\n")
+        } else if &ty_namespace != current_file_namespace {
             let dummy_base_path = PathBuf::from("/".to_owned());
 
-            let path_a =
-                api_dart::misc::compute_path_from_namespace(&dummy_base_path, &ty_namespace);
+            let first_party_crate_names = &context.config.first_party_crate_names;
+
+            let path_a = api_dart::misc::compute_path_from_namespace(
+                &dummy_base_path,
+                &ty_namespace,
+                first_party_crate_names,
+            );
 
             let path_b_inner = api_dart::misc::compute_path_from_namespace(
                 &dummy_base_path,
                 current_file_namespace,
+                first_party_crate_names,
             );
             let path_b = (path_b_inner.parent()).with_context(|| {
                 // This will stop the whole generator and tell the users, so we do not care about testing it
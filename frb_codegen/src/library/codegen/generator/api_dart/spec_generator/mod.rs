@@ -1,5 +1,6 @@
 use crate::codegen::dumper::Dumper;
 use crate::codegen::generator::api_dart::internal_config::GeneratorApiDartInternalConfig;
+use crate::codegen::generator::api_dart::misc::compute_shared_crate_import;
 use crate::codegen::generator::api_dart::spec_generator::base::{
     ApiDartGenerator, ApiDartGeneratorContext,
 };
@@ -7,7 +8,9 @@ use crate::codegen::generator::api_dart::spec_generator::class::ApiDartGenerated
 use crate::codegen::generator::api_dart::spec_generator::dump::generate_dump_info;
 use crate::codegen::generator::api_dart::spec_generator::function::ApiDartGeneratedFunction;
 use crate::codegen::generator::api_dart::spec_generator::misc::generate_imports_which_types_and_funcs_use;
-use crate::codegen::generator::api_dart::spec_generator::sanity_checker::sanity_check_class_name_duplicates;
+use crate::codegen::generator::api_dart::spec_generator::sanity_checker::{
+    sanity_check_class_name_duplicates, sanity_check_shared_crate_types,
+};
 use crate::codegen::ir::mir::func::{MirFunc, MirFuncOwnerInfo};
 use crate::codegen::ir::mir::pack::{MirPack, MirPackComputedCache};
 use crate::codegen::ir::mir::ty::MirType;
@@ -19,6 +22,7 @@ use crate::utils::basic_code::dart_header_code::DartHeaderCode;
 use crate::utils::namespace::Namespace;
 use anyhow::Result;
 use itertools::Itertools;
+use rayon::prelude::*;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use ConfigDumpContent::GeneratorInfo;
@@ -64,12 +68,19 @@ pub(crate) fn generate(
         .filter(|x| x.self_namespace().is_some())
         .into_group_map_by(|x| x.self_namespace().unwrap());
 
+    sanity_check_shared_crate_types(&grouped_namespaced_types, config)?;
+
+    // Classes and functions of shared crates are generated by the package sharing them
+    let is_shared = |namespace: &Namespace| {
+        compute_shared_crate_import(namespace, &config.shared_crates).is_some()
+    };
     let namespaces = (grouped_funcs.keys())
         .chain(grouped_namespaced_types.keys())
+        .filter(|namespace| !is_shared(namespace))
         .collect::<HashSet<_>>();
 
     let namespaced_items = namespaces
-        .iter()
+        .par_iter()
         .map(|&namespace| {
             Ok((
                 namespace.to_owned(),
@@ -1,7 +1,14 @@
+use crate::codegen::generator::api_dart::internal_config::GeneratorApiDartInternalConfig;
+use crate::codegen::generator::api_dart::misc::compute_shared_crate_import;
 use crate::codegen::generator::api_dart::spec_generator::class::ApiDartGeneratedClass;
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
+use crate::codegen::ir::mir::ty::MirType;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::namespace::Namespace;
 use anyhow::bail;
 use itertools::Itertools;
 use log::warn;
+use std::collections::HashMap;
 
 pub(crate) fn sanity_check_class_name_duplicates(
     classes: &[ApiDartGeneratedClass],
@@ -35,3 +42,39 @@ pub(crate) fn sanity_check_class_name_duplicates(
 
     Ok(())
 }
+
+/// Only plain structs and enums can be shared, since opaque types are bound
+/// to the Rust library of the package that generates them.
+pub(crate) fn sanity_check_shared_crate_types(
+    grouped_namespaced_types: &HashMap<Namespace, Vec<&MirType>>,
+    config: &GeneratorApiDartInternalConfig,
+) -> anyhow::Result<()> {
+    let unsupported_types = (grouped_namespaced_types.iter())
+        .filter(|(namespace, _)| {
+            compute_shared_crate_import(namespace, &config.shared_crates).is_some()
+        })
+        .flat_map(|(_, types)| types.iter())
+        .filter(|ty| {
+            !matches!(
+                ty,
+                MirType::StructRef(_)
+                    | MirType::EnumRef(_)
+                    | MirType::Delegate(MirTypeDelegate::PrimitiveEnum(_))
+            )
+        })
+        .map(|ty| ty.safe_ident())
+        .sorted()
+        .collect_vec();
+
+    // This will stop the whole generator and tell the users, so we do not care about testing it
+    // frb-coverage:ignore-start
+    if !unsupported_types.is_empty() {
+        bail!(
+            "Types of shared crates must be structs or enums, but found {:?}. Consider bridging them in this package instead.",
+            unsupported_types,
+        );
+    }
+    // frb-coverage:ignore-end
+
+    Ok(())
+}
@@ -6,14 +6,13 @@ use crate::codegen::generator::api_dart::spec_generator::{
 };
 use crate::codegen::generator::misc::generate_code_header;
 use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
-use crate::codegen::generator::misc::target::TargetOrCommonMap;
 use crate::utils::basic_code::dart_header_code::DartHeaderCode;
 use crate::utils::basic_code::general_code::{GeneralCode, GeneralDartCode};
 use crate::utils::path_utils::path_to_string;
 use anyhow::Context;
 use itertools::{concat, Itertools};
 use pathdiff::diff_paths;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 pub(super) struct ApiDartOutputText {
     pub(super) output_texts: PathTexts,
@@ -29,13 +28,19 @@ pub(super) fn generate(
 
     let normal_output_texts = (spec.namespaced_items.iter())
         .map(|(namespace, item)| {
-            let dart_output_path =
-                compute_path_from_namespace(&config.dart_decl_base_output_path, namespace);
-            let text =
-                generate_end_api_text(&dart_output_path, &config.dart_impl_output_path, item)?;
+            let dart_output_path = compute_path_from_namespace(
+                &config.dart_decl_base_output_path,
+                namespace,
+                &config.first_party_crate_names,
+            );
+            let text = generate_end_api_text(&dart_output_path, config, item)?;
             Ok(PathText::new(dart_output_path, text))
         })
-        .collect::<anyhow::Result<Vec<_>>>()?;
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        // Sort, since the iteration order of the namespaces differs between runs
+        .sorted_by(|a, b| a.path.cmp(&b.path))
+        .collect_vec();
 
     let extra_output_text = PathText {
         path: config.dart_impl_output_path.common.clone(),
@@ -55,7 +60,7 @@ pub(super) fn generate(
 
 fn generate_end_api_text(
     dart_output_path: &Path,
-    dart_impl_output_path: &TargetOrCommonMap<PathBuf>,
+    config: &GeneratorApiDartInternalConfig,
     item: &ApiDartOutputSpecItem,
 ) -> anyhow::Result<GeneralCode> {
     let funcs = item
@@ -67,7 +72,7 @@ fn generate_end_api_text(
     let classes = item.classes.iter().map(|c| c.code.clone()).join("\n\n");
 
     let path_frb_generated = diff_paths(
-        &dart_impl_output_path.common,
+        &config.dart_impl_output_path.common,
         dart_output_path.parent().unwrap(),
     )
     .with_context(|| "Fail to find relative path".to_string())?;
@@ -75,7 +80,7 @@ fn generate_end_api_text(
 
     let preamble = &item.preamble.as_str();
     let mut header = DartHeaderCode {
-        file_top: generate_code_header()
+        file_top: generate_code_header(config.version_in_header)
             + if !preamble.is_empty() {"\n\n"} else {""} + preamble
             + "\n\n// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import\n",
         import: format!(
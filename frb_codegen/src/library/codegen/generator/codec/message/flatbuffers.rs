@@ -0,0 +1,42 @@
+//! Uses the object API generated by `flatc --gen-object-api`, e.g. `MonsterT`, in both languages,
+//! since the other generated types borrow the buffer.
+
+use crate::codegen::generator::codec::message::MessageCodecTrait;
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegateMessageCodec;
+
+pub(super) struct FlatbuffersMessageCodec;
+
+impl MessageCodecTrait for FlatbuffersMessageCodec {
+    fn rust_encode(&self, _mir: &MirTypeDelegateMessageCodec) -> String {
+        "{ let mut builder = flatbuffers::FlatBufferBuilder::new(); let root = self.pack(&mut builder); builder.finish(root, None); builder.finished_data().to_vec() }".to_owned()
+    }
+
+    fn rust_decode(&self, mir: &MirTypeDelegateMessageCodec) -> String {
+        format!(
+            r#"flatbuffers::root::<{}>(&inner).expect("fail to decode flatbuffers message").unpack()"#,
+            table_type(mir)
+        )
+    }
+
+    fn dart_encode(&self, _mir: &MirTypeDelegateMessageCodec) -> String {
+        "(() { final builder = fb.Builder(); builder.finish(self.pack(builder)); return builder.buffer; })()".to_owned()
+    }
+
+    fn dart_decode(&self, mir: &MirTypeDelegateMessageCodec) -> String {
+        // The Dart class of the table has the same name as the Rust one
+        let table_type = table_type(mir);
+        format!(
+            "{}(inner).unpack()",
+            table_type.rsplit("::").next().unwrap_or(table_type)
+        )
+    }
+
+    fn dart_import(&self) -> Option<String> {
+        Some("import 'package:flat_buffers/flat_buffers.dart' as fb;".to_owned())
+    }
+}
+
+/// e.g. `crate::fbs::Monster` for `MonsterT`
+fn table_type(mir: &MirTypeDelegateMessageCodec) -> &str {
+    (mir.rust_table_type.as_deref()).expect("flatbuffers messages are parsed with their tables")
+}
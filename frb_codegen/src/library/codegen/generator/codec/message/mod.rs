@@ -0,0 +1,32 @@
+//! Message codecs convert a whole argument or return value into the bytes of a third-party format
+//! (e.g. protobuf), which are then transferred by the SSE codec like a `Vec<u8>`.
+
+mod flatbuffers;
+mod protobuf;
+
+use crate::codegen::ir::mir::ty::delegate::{MirMessageCodec, MirTypeDelegateMessageCodec};
+
+pub(crate) trait MessageCodecTrait {
+    /// Rust expression converting `self` into `Vec<u8>`
+    fn rust_encode(&self, mir: &MirTypeDelegateMessageCodec) -> String;
+
+    /// Rust expression converting `inner: Vec<u8>` into the message
+    fn rust_decode(&self, mir: &MirTypeDelegateMessageCodec) -> String;
+
+    /// Dart expression converting `self` into `Uint8List`
+    fn dart_encode(&self, mir: &MirTypeDelegateMessageCodec) -> String;
+
+    /// Dart expression converting `inner: Uint8List` into the message
+    fn dart_decode(&self, mir: &MirTypeDelegateMessageCodec) -> String;
+
+    fn dart_import(&self) -> Option<String> {
+        None
+    }
+}
+
+pub(crate) fn message_codec_entrypoint(codec: MirMessageCodec) -> &'static dyn MessageCodecTrait {
+    match codec {
+        MirMessageCodec::Protobuf => &protobuf::ProtobufMessageCodec,
+        MirMessageCodec::Flatbuffers => &flatbuffers::FlatbuffersMessageCodec,
+    }
+}
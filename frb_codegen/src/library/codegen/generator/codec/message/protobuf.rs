@@ -0,0 +1,27 @@
+//! Uses `prost` in Rust, and the classes generated by `protoc --dart_out` in Dart.
+
+use crate::codegen::generator::codec::message::MessageCodecTrait;
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegateMessageCodec;
+
+pub(super) struct ProtobufMessageCodec;
+
+impl MessageCodecTrait for ProtobufMessageCodec {
+    fn rust_encode(&self, _mir: &MirTypeDelegateMessageCodec) -> String {
+        "prost::Message::encode_to_vec(&self)".to_owned()
+    }
+
+    fn rust_decode(&self, mir: &MirTypeDelegateMessageCodec) -> String {
+        format!(
+            r#"<{} as prost::Message>::decode(inner.as_slice()).expect("fail to decode protobuf message")"#,
+            mir.rust_type
+        )
+    }
+
+    fn dart_encode(&self, _mir: &MirTypeDelegateMessageCodec) -> String {
+        "self.writeToBuffer()".to_owned()
+    }
+
+    fn dart_decode(&self, mir: &MirTypeDelegateMessageCodec) -> String {
+        format!("{}.fromBuffer(inner)", mir.dart_type)
+    }
+}
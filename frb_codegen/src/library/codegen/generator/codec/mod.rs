@@ -1,2 +1,3 @@
+pub(crate) mod message;
 pub(crate) mod sse;
 pub(crate) mod structs;
@@ -47,14 +47,6 @@ impl LangTrait for DartLang {
         format!("throw UnimplementedError('Unreachable ({message})')")
     }
 
-    fn for_loop(&self, lhs: &str, rhs: &str, body: &str) -> String {
-        format!("for (final {lhs} in {rhs}) {{ {body} }}")
-    }
-
-    fn for_range_loop(&self, var: &str, limit: &str, body: &str) -> String {
-        format!("for (var {var} = 0; {var} < {limit}; ++{var}) {{ {body} }}")
-    }
-
     fn switch_expr(
         &self,
         value: &str,
@@ -32,10 +32,6 @@ pub(crate) trait LangTrait {
 
     fn throw_unreachable(&self, message: &str) -> String;
 
-    fn for_loop(&self, lhs: &str, rhs: &str, body: &str) -> String;
-
-    fn for_range_loop(&self, var: &str, limit: &str, body: &str) -> String;
-
     fn switch_expr(
         &self,
         value: &str,
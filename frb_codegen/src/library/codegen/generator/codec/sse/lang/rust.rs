@@ -49,14 +49,6 @@ impl LangTrait for RustLang {
         format!(r#"unreachable!("{message}")"#)
     }
 
-    fn for_loop(&self, lhs: &str, rhs: &str, body: &str) -> String {
-        format!("for {lhs} in {rhs} {{ {body} }}")
-    }
-
-    fn for_range_loop(&self, var: &str, limit: &str, body: &str) -> String {
-        format!("for {var} in 0..{limit} {{ {body} }}")
-    }
-
     fn switch_expr(
         &self,
         value: &str,
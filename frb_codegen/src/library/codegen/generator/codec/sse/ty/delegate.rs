@@ -1,5 +1,6 @@
 use crate::codegen::generator::api_dart::spec_generator::base::ApiDartGenerator;
 use crate::codegen::generator::api_dart::spec_generator::class::proxy_variant;
+use crate::codegen::generator::codec::message::message_codec_entrypoint;
 use crate::codegen::generator::codec::sse::encode_to_enum;
 use crate::codegen::generator::codec::sse::lang::*;
 use crate::codegen::generator::codec::sse::ty::*;
@@ -19,7 +20,9 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 MirTypeDelegate::String => "utf8.encoder.convert(self)".to_owned(),
                 MirTypeDelegate::Char => "self".to_owned(),
                 MirTypeDelegate::PrimitiveEnum(_) => "self.index".to_owned(),
-                MirTypeDelegate::Backtrace | MirTypeDelegate::ProxyVariant(_) => {
+                MirTypeDelegate::Backtrace
+                | MirTypeDelegate::Progress(_)
+                | MirTypeDelegate::ProxyVariant(_) => {
                     return Some(format!("{};", lang.throw_unreachable("")));
                 }
                 MirTypeDelegate::AnyhowException => "self.message".to_owned(),
@@ -65,6 +68,9 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 MirTypeDelegate::CustomSerDes(mir) => {
                     mir.info.dart2rust.dart_code.replace("{}", "self")
                 }
+                MirTypeDelegate::MessageCodec(mir) => {
+                    message_codec_entrypoint(mir.codec).dart_encode(mir)
+                }
             },
             Lang::RustLang(_) => match &self.mir {
                 MirTypeDelegate::Array(_) => {
@@ -104,6 +110,9 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 },
                 MirTypeDelegate::Uuid => "self.as_bytes().to_vec()".to_owned(),
                 MirTypeDelegate::StreamSink(_) => return Some(lang.throw_unimplemented("")),
+                MirTypeDelegate::Progress(_) => {
+                    "(self.completed, self.total, self.message)".to_owned()
+                }
                 MirTypeDelegate::BigPrimitive(_) => "self.to_string()".to_owned(),
                 MirTypeDelegate::RustAutoOpaqueExplicit(_ir) => {
                     "flutter_rust_bridge::for_generated::rust_auto_opaque_explicit_encode(self)"
@@ -114,8 +123,9 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 | MirTypeDelegate::DynTrait(_)
                 | MirTypeDelegate::CastedPrimitive(_)
                 | MirTypeDelegate::Lifetimeable(_) => return None,
-                MirTypeDelegate::CustomSerDes(mir) => {
-                    format!("{}(self)", mir.info.rust2dart.rust_function.rust_style())
+                MirTypeDelegate::CustomSerDes(mir) => mir.info.rust2dart.rust_call("self"),
+                MirTypeDelegate::MessageCodec(mir) => {
+                    message_codec_entrypoint(mir.codec).rust_encode(mir)
                 }
             },
         };
@@ -153,6 +163,7 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                         "Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)))".to_owned()
                     }
                     MirTypeDelegate::Set(_) => "Set.from(inner)".to_owned(),
+                    MirTypeDelegate::Progress(_) => generate_progress_from_record("inner"),
                     MirTypeDelegate::Time(mir) => match mir {
                         MirTypeDelegateTime::Utc
                         | MirTypeDelegateTime::Local
@@ -183,6 +194,9 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                     MirTypeDelegate::CustomSerDes(mir) => {
                         mir.info.rust2dart.dart_code.replace("{}", "inner")
                     }
+                    MirTypeDelegate::MessageCodec(mir) => {
+                        message_codec_entrypoint(mir.codec).dart_decode(mir)
+                    }
                 }
             }
             Lang::RustLang(_) => match &self.mir {
@@ -194,7 +208,7 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 MirTypeDelegate::PrimitiveEnum(inner) => {
                     rust_decode_primitive_enum(inner, self.context.mir_pack, "inner")
                 }
-                MirTypeDelegate::Backtrace => {
+                MirTypeDelegate::Backtrace | MirTypeDelegate::Progress(_) => {
                     return Some(format!("{};", lang.throw_unreachable("")));
                 }
                 MirTypeDelegate::AnyhowException => {
@@ -230,8 +244,9 @@ impl<'a> CodecSseTyTrait for DelegateCodecSseTy<'a> {
                 | MirTypeDelegate::DynTrait(_)
                 | MirTypeDelegate::CastedPrimitive(_)
                 | MirTypeDelegate::Lifetimeable(_) => return None,
-                MirTypeDelegate::CustomSerDes(mir) => {
-                    format!("{}(inner)", mir.info.dart2rust.rust_function.rust_style())
+                MirTypeDelegate::CustomSerDes(mir) => mir.info.dart2rust.rust_call("inner"),
+                MirTypeDelegate::MessageCodec(mir) => {
+                    message_codec_entrypoint(mir.codec).rust_decode(mir)
                 }
             },
         };
@@ -304,6 +319,11 @@ pub(crate) fn generate_set_to_list(
     ans
 }
 
+/// Progress is only sent from Rust to Dart, thus only the decoder is needed
+pub(crate) fn generate_progress_from_record(var_name: &str) -> String {
+    format!("FrbProgress(completed: {var_name}.$1.toInt(), total: {var_name}.$2?.toInt(), message: {var_name}.$3)")
+}
+
 pub(crate) fn generate_stream_sink_setup_and_serialize(
     mir: &MirTypeDelegateStreamSink,
     var_name: &str,
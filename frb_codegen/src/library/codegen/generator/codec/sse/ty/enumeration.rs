@@ -67,13 +67,11 @@ fn generate_decode_variant(
     lang: &Lang,
     context: CodecSseTyContext,
 ) -> String {
-    let enum_name_str = enum_name.style(lang);
-    let enum_sep = enum_sep(lang);
+    let variant_path = variant_path(lang, enum_name, variant);
     match &variant.kind {
         MirVariantKind::Value => {
             format!(
-                "return {enum_name_str}{enum_sep}{}{};",
-                variant.name,
+                "return {variant_path}{};",
                 match lang {
                     Lang::DartLang(_) => "()",
                     Lang::RustLang(_) => "",
@@ -82,11 +80,7 @@ fn generate_decode_variant(
         }
         MirVariantKind::Struct(st) => {
             GeneralizedStructGenerator::new(st.clone(), context, StructOrRecord::Struct)
-                .generate_decode(
-                    lang,
-                    Some(format!("{enum_name_str}{enum_sep}{}", st.name.name)),
-                    false,
-                )
+                .generate_decode(lang, Some(variant_path), false)
         }
     }
 }
@@ -97,17 +91,12 @@ pub(crate) fn generate_enum_encode_rust_general(
     self_ref: &str,
     generate_branch: impl Fn(usize, &MirEnumVariant) -> String,
 ) -> String {
-    let enum_name_str = src.name.style(lang);
-    let enum_sep = enum_sep(lang);
     let variants = (src.variants().iter().enumerate())
         .map(|(idx, variant)| {
-            let variant_name = &variant.name;
+            let variant_path = variant_path(lang, &src.name, variant);
             let pattern = pattern_match_enum_variant(lang, variant);
             let body = generate_branch(idx, variant);
-            (
-                format!("{enum_name_str}{enum_sep}{variant_name}{pattern}"),
-                body,
-            )
+            (format!("{variant_path}{pattern}"), body)
         })
         .collect_vec();
 
@@ -142,10 +131,11 @@ fn pattern_match_enum_variant(lang: &Lang, variant: &MirEnumVariant) -> String {
     }
 }
 
-fn enum_sep(lang: &Lang) -> &'static str {
+/// e.g. `MyEnum::MyVariant` in Rust and `MyEnum_MyVariant` in Dart
+fn variant_path(lang: &Lang, enum_name: &NamespacedName, variant: &MirEnumVariant) -> String {
     match lang {
-        Lang::DartLang(_) => "_",
-        Lang::RustLang(_) => "::",
+        Lang::DartLang(_) => variant.wrapper_name.rust_style().to_owned(),
+        Lang::RustLang(_) => format!("{}::{}", enum_name.style(lang), variant.name),
     }
 }
 
@@ -1,7 +1,4 @@
-use crate::codegen::generator::api_dart::spec_generator::base::ApiDartGenerator;
 use crate::codegen::generator::codec::sse::ty::*;
-use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
-use crate::library::codegen::generator::codec::sse::lang::LangTrait;
 
 impl<'a> CodecSseTyTrait for GeneralListCodecSseTy<'a> {
     fn generate_encode(&self, lang: &Lang) -> Option<String> {
@@ -9,25 +6,30 @@ impl<'a> CodecSseTyTrait for GeneralListCodecSseTy<'a> {
     }
 
     fn generate_decode(&self, lang: &Lang) -> Option<String> {
-        Some(general_list_generate_decode(
-            lang,
-            &self.mir.inner,
-            self.context,
-        ))
+        Some(general_list_generate_decode(lang, &self.mir.inner))
     }
 }
 
+// The loop is implemented once by the runtime (Dart) or the boilerplate (Rust),
+// thus lists of all element types share it instead of each having a copy.
 pub(super) fn general_list_generate_encode(lang: &Lang, mir_inner: &MirType) -> String {
-    format!(
-        "{};
-        {}",
-        lang.call_encode(&LEN_TYPE, &format!("self.{}", list_len_method(lang))),
-        lang.for_loop(
-            "item",
-            "self",
-            &format!("{};", lang.call_encode(mir_inner, "item")),
-        )
-    )
+    match lang {
+        Lang::DartLang(_) => format!(
+            "sseEncodeList(self, serializer, {});",
+            dart_encode_fn(mir_inner)
+        ),
+        Lang::RustLang(_) => "sse_encode_list(self, serializer);".to_owned(),
+    }
+}
+
+pub(super) fn general_list_generate_decode(lang: &Lang, mir_inner: &MirType) -> String {
+    match lang {
+        Lang::DartLang(_) => format!(
+            "return sseDecodeList(deserializer, {});",
+            dart_decode_fn(mir_inner)
+        ),
+        Lang::RustLang(_) => "return sse_decode_list(deserializer);".to_owned(),
+    }
 }
 
 pub(super) fn list_len_method(lang: &Lang) -> &'static str {
@@ -37,39 +39,12 @@ pub(super) fn list_len_method(lang: &Lang) -> &'static str {
     }
 }
 
-pub(super) fn general_list_generate_decode(
-    lang: &Lang,
-    mir_inner: &MirType,
-    context: CodecSseTyContext,
-) -> String {
-    let var_decl = lang.var_decl();
-
-    let init = match lang {
-        Lang::DartLang(_) => format!(
-            "<{}>[]",
-            ApiDartGenerator::new(mir_inner.clone(), context.as_api_dart_context()).dart_api_type()
-        ),
-        Lang::RustLang(_) => "vec![]".to_owned(),
-    };
-    let list_push = match lang {
-        Lang::DartLang(_) => "add",
-        Lang::RustLang(_) => "push",
-    };
+pub(super) fn dart_encode_fn(ty: &MirType) -> String {
+    format!("sse_encode_{}", ty.safe_ident())
+}
 
-    format!(
-        "
-        {var_decl} len_ = {};
-        {var_decl} ans_ = {init};
-        {}
-        return ans_;
-        ",
-        lang.call_decode(&LEN_TYPE),
-        lang.for_range_loop(
-            "idx_",
-            "len_",
-            &format!("ans_.{list_push}({});", lang.call_decode(mir_inner))
-        ),
-    )
+pub(super) fn dart_decode_fn(ty: &MirType) -> String {
+    format!("sse_decode_{}", ty.safe_ident())
 }
 
 pub(super) const LEN_TYPE: MirType = Primitive(MirTypePrimitive::I32);
@@ -1,56 +1,24 @@
+use crate::codegen::generator::codec::sse::ty::general_list::{dart_decode_fn, dart_encode_fn};
 use crate::codegen::generator::codec::sse::ty::*;
-use crate::library::codegen::generator::codec::sse::lang::LangTrait;
 
 impl<'a> CodecSseTyTrait for OptionalCodecSseTy<'a> {
     fn generate_encode(&self, lang: &Lang) -> Option<String> {
-        let self_is_not_null = match lang {
-            Lang::DartLang(_) => "self != null",
-            Lang::RustLang(_) => "self.is_some()",
-        };
-        let encode_flag = lang.call_encode(&Primitive(MirTypePrimitive::Bool), self_is_not_null);
-
         Some(match lang {
             Lang::DartLang(_) => format!(
-                "
-                {encode_flag};
-                if (self != null) {{
-                    {};
-                }}
-                ",
-                lang.call_encode(&self.mir.inner, "self"),
-            ),
-            Lang::RustLang(_) => format!(
-                "
-                {encode_flag};
-                if let Some(value) = self {{
-                    {};
-                }}
-                ",
-                lang.call_encode(&self.mir.inner, "value"),
+                "sseEncodeOptional(self, serializer, {});",
+                dart_encode_fn(&self.mir.inner)
             ),
+            Lang::RustLang(_) => "sse_encode_option(self, serializer);".to_owned(),
         })
     }
 
     fn generate_decode(&self, lang: &Lang) -> Option<String> {
-        let wrapper = match lang {
-            Lang::DartLang(_) => "",
-            Lang::RustLang(_) => "Some",
-        };
-        let null = match lang {
-            Lang::DartLang(_) => "null",
-            Lang::RustLang(_) => "None",
-        };
-
-        Some(format!(
-            "
-            if ({}) {{
-                return {wrapper}({});
-            }} else {{
-                return {null};
-            }}
-            ",
-            lang.call_decode(&Primitive(MirTypePrimitive::Bool)),
-            lang.call_decode(&self.mir.inner),
-        ))
+        Some(match lang {
+            Lang::DartLang(_) => format!(
+                "return sseDecodeOptional(deserializer, {});",
+                dart_decode_fn(&self.mir.inner)
+            ),
+            Lang::RustLang(_) => "return sse_decode_option(deserializer);".to_owned(),
+        })
     }
 }
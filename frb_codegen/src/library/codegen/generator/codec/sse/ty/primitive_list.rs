@@ -3,6 +3,7 @@ use crate::codegen::generator::codec::sse::ty::general_list::{
 };
 use crate::codegen::generator::codec::sse::ty::primitive::get_serializer_dart_postfix;
 use crate::codegen::generator::codec::sse::ty::*;
+use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
 use crate::library::codegen::generator::codec::sse::lang::LangTrait;
 
 impl<'a> CodecSseTyTrait for PrimitiveListCodecSseTy<'a> {
@@ -25,15 +26,20 @@ impl<'a> CodecSseTyTrait for PrimitiveListCodecSseTy<'a> {
                     get_serializer_dart_postfix(&self.mir.primitive, true)
                 ))
             }
-            Lang::RustLang(_) => {
-                // TODO do not use naive loop
-                self.mir.strict_dart_type.then(|| {
+            Lang::RustLang(_) => self.mir.strict_dart_type.then(|| {
+                if supports_bulk(&self.mir.primitive) {
+                    format!(
+                        "{};
+                        serializer.write_primitive_list(&self);",
+                        lang.call_encode(&LEN_TYPE, &format!("self.{}", list_len_method(lang))),
+                    )
+                } else {
                     general_list_generate_encode(
                         lang,
                         &MirType::Primitive(self.mir.primitive.clone()),
                     )
-                })
-            }
+                }
+            }),
         }
     }
 
@@ -46,16 +52,32 @@ impl<'a> CodecSseTyTrait for PrimitiveListCodecSseTy<'a> {
                 lang.call_decode(&LEN_TYPE),
                 get_serializer_dart_postfix(&self.mir.primitive, true)
             )),
-            Lang::RustLang(_) => {
-                // TODO do not use naive loop
-                self.mir.strict_dart_type.then(|| {
+            Lang::RustLang(_) => self.mir.strict_dart_type.then(|| {
+                if supports_bulk(&self.mir.primitive) {
+                    format!(
+                        "{var_decl} len_ = {};
+                        return deserializer.read_primitive_list(len_).unwrap();",
+                        lang.call_decode(&LEN_TYPE),
+                    )
+                } else {
                     general_list_generate_decode(
                         lang,
                         &MirType::Primitive(self.mir.primitive.clone()),
-                        self.context,
                     )
-                })
-            }
+                }
+            }),
         }
     }
 }
+
+/// Whether the list can be copied as raw memory, i.e. the Rust type has the same layout
+/// as the serialized data (see `SseBulkPrimitive` in the runtime)
+fn supports_bulk(primitive: &MirTypePrimitive) -> bool {
+    !matches!(
+        primitive,
+        MirTypePrimitive::Usize
+            | MirTypePrimitive::Isize
+            | MirTypePrimitive::Bool
+            | MirTypePrimitive::Unit
+    )
+}
@@ -1,8 +1,12 @@
 use crate::codegen::generator::api_dart::spec_generator::class::method::dart_constructor_postfix;
+use crate::codegen::generator::codec::sse::ty::general_list::dart_decode_fn;
 use crate::codegen::generator::codec::sse::ty::*;
 use crate::codegen::generator::misc::struct_or_record::StructOrRecord;
 use crate::codegen::generator::misc::struct_or_record::StructOrRecord::Struct;
+use crate::codegen::ir::mir::field::MirField;
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::codegen::ir::mir::ty::structure::MirStruct;
+use crate::codegen::ir::mir::ty::MirType;
 use crate::library::codegen::generator::codec::sse::lang::LangTrait;
 use itertools::Itertools;
 
@@ -43,17 +47,17 @@ impl<'a> GeneralizedStructGenerator<'a> {
     pub(super) fn generate_encode(&self, lang: &Lang) -> String {
         (self.st.fields.iter().enumerate())
             .map(|(index, field)| {
-                format!(
-                    "{};\n",
-                    lang.call_encode(
-                        &field.ty,
-                        &format!(
-                            "self.{}",
-                            self.mode
-                                .field_name(index, field, self.st.is_fields_named, lang)
-                        )
-                    )
-                )
+                let var_name = format!(
+                    "self.{}",
+                    self.mode
+                        .field_name(index, field, self.st.is_fields_named, lang)
+                );
+                // Only the Rust-to-Dart direction is lazy, since it is the one with big lists
+                if self.is_lazy_field(field) && matches!(lang, Lang::RustLang(_)) {
+                    format!("sse_encode_lazy_field({var_name}, serializer);\n")
+                } else {
+                    format!("{};\n", lang.call_encode(&field.ty, &var_name))
+                }
             })
             .join("")
     }
@@ -64,13 +68,23 @@ impl<'a> GeneralizedStructGenerator<'a> {
         override_struct_name: Option<String>,
         dart_unconditionally_kwargs_ctor: bool,
     ) -> String {
+        let dart_lazy = self.is_lazy() && matches!(lang, Lang::DartLang(_));
         let decode_fields = (self.st.fields.iter())
             .map(|field| {
+                let decode = if !dart_lazy {
+                    lang.call_decode(&field.ty)
+                } else if self.is_lazy_field(field) {
+                    format!(
+                        "sseDecodeLazyField(deserializer, {})",
+                        dart_decode_fn(&field.ty)
+                    )
+                } else {
+                    format!("LazyField.value({})", lang.call_decode(&field.ty))
+                };
                 format!(
-                    "{} var_{} = {};\n",
+                    "{} var_{} = {decode};\n",
                     lang.var_decl(),
                     field.name.dart_style(),
-                    lang.call_decode(&field.ty)
                 )
             })
             .join("");
@@ -78,11 +92,15 @@ impl<'a> GeneralizedStructGenerator<'a> {
         let ctor = match self.mode {
             Struct => lang.call_constructor(
                 &override_struct_name.unwrap_or_else(|| self.st.name.style(lang)),
-                dart_constructor_postfix(
-                    &self.st.name.name,
-                    &self.context.mir_pack.funcs_with_impl(),
-                    self.context.as_api_dart_context(),
-                ),
+                if dart_lazy {
+                    ".lazy"
+                } else {
+                    dart_constructor_postfix(
+                        &self.st.name.name,
+                        &self.context.mir_pack.funcs_with_impl(),
+                        self.context.as_api_dart_context(),
+                    )
+                },
                 &(self.st.fields.iter())
                     .map(|x| x.name.style(lang))
                     .collect_vec(),
@@ -102,4 +120,35 @@ impl<'a> GeneralizedStructGenerator<'a> {
 
         format!("{decode_fields}return {ctor};")
     }
+
+    fn is_lazy(&self) -> bool {
+        matches!(self.mode, Struct) && self.st.lazy
+    }
+
+    /// Fields taking the ownership of e.g. an opaque object when decoded are still decoded eagerly,
+    /// since the object would otherwise be leaked if the field is never read
+    fn is_lazy_field(&self, field: &MirField) -> bool {
+        if !self.is_lazy() {
+            return false;
+        }
+        let mut carries_ownership = false;
+        field.ty.visit_types(
+            &mut |ty| {
+                carries_ownership |= matches!(
+                    ty,
+                    MirType::RustOpaque(_)
+                        | MirType::RustAutoOpaqueImplicit(_)
+                        | MirType::DartOpaque(_)
+                        | MirType::DartFn(_)
+                        | MirType::Delegate(
+                            MirTypeDelegate::RustAutoOpaqueExplicit(_)
+                                | MirTypeDelegate::StreamSink(_)
+                        )
+                );
+                carries_ownership
+            },
+            self.context.mir_pack,
+        );
+        !carries_ownership
+    }
 }
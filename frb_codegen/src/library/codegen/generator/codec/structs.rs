@@ -83,9 +83,15 @@ macro_rules! codegen_codec_structs {
                     cache: &MirPackComputedCache,
                     mode: EncodeOrDecode,
                 ) -> [<Wire $partial_name CodecOutputSpec>] {
+                    use rayon::prelude::*;
                     CodecMode::iter()
-                        .flat_map(|codec| [<Wire $partial_name CodecEntrypoint>]::from(codec)
+                        .collect::<Vec<_>>()
+                        .into_par_iter()
+                        .map(|codec| [<Wire $partial_name CodecEntrypoint>]::from(codec)
                             .generate(context, &get_interest_types_for_codec(cache, codec), mode))
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .flatten()
                         .collect()
                 }
             }
@@ -0,0 +1,211 @@
+use crate::codegen::generator::docs::{
+    DocsFunction, DocsMember, DocsPage, DocsReference, DocsType,
+};
+use itertools::Itertools;
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 0; display: flex; }
+nav { width: 260px; min-height: 100vh; padding: 16px; background: #f5f5f5; box-sizing: border-box; }
+nav a { display: block; margin: 4px 0; word-break: break-all; }
+main { flex: 1; padding: 16px 32px; max-width: 960px; }
+pre { background: #f5f5f5; padding: 8px; overflow-x: auto; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ddd; padding: 4px 8px; text-align: left; vertical-align: top; }
+.lang { color: #888; font-size: small; }
+";
+
+pub(super) fn generate_index(reference: &DocsReference) -> String {
+    let modules = (reference.pages.iter())
+        .map(|page| {
+            format!(
+                "<li><a href=\"{}\">{}</a> ({} functions, {} types)</li>",
+                page.file_name(),
+                escape(&page.namespace),
+                page.functions.len(),
+                page.types.len(),
+            )
+        })
+        .join("\n");
+    let type_mappings = (reference.type_mappings.iter())
+        .map(|(rust, dart)| {
+            format!(
+                "<tr><td><code>{}</code></td><td><code>{}</code></td></tr>",
+                escape(rust),
+                escape(dart)
+            )
+        })
+        .join("\n");
+
+    let body = format!(
+        "<h1>API reference</h1>
+<h2>Modules</h2>
+<ul>
+{modules}
+</ul>
+<h2 id=\"type-mapping\">Type mapping</h2>
+<p>What each Rust type becomes in Dart.</p>
+<table>
+<tr><th>Rust</th><th>Dart</th></tr>
+{type_mappings}
+</table>"
+    );
+    generate_html("API reference", reference, &body)
+}
+
+pub(super) fn generate_page(reference: &DocsReference, page: &DocsPage) -> String {
+    let mut body = format!("<h1>{}</h1>\n", escape(&page.namespace));
+    if !page.functions.is_empty() {
+        body += "<h2>Functions</h2>\n";
+        body += &(page.functions.iter())
+            .map(|func| generate_function(func, "h3"))
+            .join("\n");
+    }
+    if !page.types.is_empty() {
+        body += "<h2>Types</h2>\n";
+        body += &page.types.iter().map(generate_type).join("\n");
+    }
+    generate_html(&page.namespace, reference, &body)
+}
+
+fn generate_function(func: &DocsFunction, heading: &str) -> String {
+    format!(
+        "<{heading} id=\"{id}\">{name}</{heading}>
+<div class=\"lang\">Dart</div>
+<pre>{dart}</pre>
+<div class=\"lang\">Rust</div>
+<pre>{rust}</pre>
+{comments}",
+        id = escape(&func.name),
+        name = escape(&func.name),
+        dart = escape(&func.dart_signature),
+        rust = escape(&func.rust_signature),
+        comments = generate_comments(&func.comments),
+    )
+}
+
+fn generate_type(ty: &DocsType) -> String {
+    let mut ans = format!(
+        "<h3 id=\"{id}\">{dart}</h3>
+<p class=\"lang\">Dart <code>{dart}</code>, from Rust <code>{rust}</code></p>
+{comments}",
+        id = escape(&ty.dart_name),
+        dart = escape(&ty.dart_name),
+        rust = escape(&ty.rust_name),
+        comments = generate_comments(&ty.comments),
+    );
+    if !ty.members.is_empty() {
+        ans += &generate_members(&ty.members);
+    }
+    ans += &(ty.methods.iter())
+        .map(|method| generate_function(method, "h4"))
+        .join("\n");
+    ans
+}
+
+fn generate_members(members: &[DocsMember]) -> String {
+    let rows = (members.iter())
+        .map(|member| {
+            format!(
+                "<tr><td><code>{}</code></td><td><code>{}</code></td><td><code>{}</code></td><td><code>{}</code></td><td>{}</td></tr>",
+                escape(&member.dart_name),
+                escape(member.dart_type.as_deref().unwrap_or_default()),
+                escape(&member.rust_name),
+                escape(member.rust_type.as_deref().unwrap_or_default()),
+                escape(&member.comments).replace('\n', "<br>"),
+            )
+        })
+        .join("\n");
+    format!(
+        "<table>
+<tr><th>Dart name</th><th>Dart type</th><th>Rust name</th><th>Rust type</th><th>Description</th></tr>
+{rows}
+</table>
+"
+    )
+}
+
+// Doc comments are usually Markdown, which is displayed as-is for simplicity
+fn generate_comments(comments: &str) -> String {
+    if comments.is_empty() {
+        return String::new();
+    }
+    format!(
+        "<pre style=\"background: none; white-space: pre-wrap;\">{}</pre>\n",
+        escape(comments)
+    )
+}
+
+fn generate_html(title: &str, reference: &DocsReference, body: &str) -> String {
+    let nav = (reference.pages.iter())
+        .map(|page| {
+            format!(
+                "<a href=\"{}\">{}</a>",
+                page.file_name(),
+                escape(&page.namespace)
+            )
+        })
+        .join("\n");
+    format!(
+        "<!DOCTYPE html>
+<!-- This file is automatically generated, so please do not edit it. -->
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>{title}</title>
+<style>{STYLE}</style>
+</head>
+<body>
+<nav>
+<a href=\"index.html\"><b>API reference</b></a>
+<a href=\"index.html#type-mapping\">Type mapping</a>
+<hr>
+{nav}
+</nav>
+<main>
+{body}
+</main>
+</body>
+</html>
+",
+        title = escape(title),
+    )
+}
+
+fn escape(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_page() {
+        let page = DocsPage {
+            namespace: "crate::api::simple".to_owned(),
+            functions: vec![DocsFunction {
+                name: "greet".to_owned(),
+                comments: "Says hello.".to_owned(),
+                rust_signature: "fn greet(name: String) -> String".to_owned(),
+                dart_signature: "Future<String> greet({required String name})".to_owned(),
+            }],
+            types: vec![],
+        };
+        let reference = DocsReference {
+            pages: vec![],
+            type_mappings: vec![("Vec<u8>".to_owned(), "Uint8List".to_owned())],
+        };
+
+        assert_eq!(page.file_name(), "crate.api.simple.html");
+        let html = generate_page(&reference, &page);
+        assert!(html.contains("<pre>Future&lt;String&gt; greet({required String name})</pre>"));
+        assert!(html.contains("<pre>fn greet(name: String) -&gt; String</pre>"));
+        assert!(html.contains("Says hello."));
+        assert!(generate_index(&reference).contains(
+            "<tr><td><code>Vec&lt;u8&gt;</code></td><td><code>Uint8List</code></td></tr>"
+        ));
+    }
+}
@@ -0,0 +1,373 @@
+//! API reference of the bridged functions and types, written for the developers using the
+//! generated Dart code who never read the Rust source
+
+mod html;
+
+use crate::codegen::generator::api_dart::internal_config::GeneratorApiDartInternalConfig;
+use crate::codegen::generator::api_dart::spec_generator::base::{
+    ApiDartGenerator, ApiDartGeneratorContext,
+};
+use crate::codegen::generator::api_dart::spec_generator::class::method::generate_api_method_signature;
+use crate::codegen::generator::api_dart::spec_generator::function;
+use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
+use crate::codegen::ir::mir::comment::MirComment;
+use crate::codegen::ir::mir::field::MirField;
+use crate::codegen::ir::mir::func::{
+    MirFunc, MirFuncInput, MirFuncOwnerInfo, MirFuncOwnerInfoMethodMode,
+};
+use crate::codegen::ir::mir::pack::{DistinctTypeGatherer, MirPack};
+use crate::codegen::ir::mir::ty::enumeration::{MirEnumVariant, MirVariantKind};
+use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
+use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
+use crate::utils::basic_code::general_code::GeneralCode;
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::Path;
+
+pub(crate) fn generate(
+    mir_pack: &MirPack,
+    config: &GeneratorApiDartInternalConfig,
+    output_dir: &Path,
+) -> anyhow::Result<PathTexts> {
+    let context = ApiDartGeneratorContext { mir_pack, config };
+    let reference = DocsReference::compute(context)?;
+
+    let mut path_texts = vec![PathText::new(
+        output_dir.join("index.html"),
+        GeneralCode::new_text(html::generate_index(&reference)),
+    )];
+    for page in &reference.pages {
+        path_texts.push(PathText::new(
+            output_dir.join(page.file_name()),
+            GeneralCode::new_text(html::generate_page(&reference, page)),
+        ));
+    }
+    Ok(PathTexts(path_texts))
+}
+
+struct DocsReference {
+    pages: Vec<DocsPage>,
+    /// Rust type -> Dart type
+    type_mappings: Vec<(String, String)>,
+}
+
+/// One Rust module
+struct DocsPage {
+    namespace: String,
+    functions: Vec<DocsFunction>,
+    types: Vec<DocsType>,
+}
+
+struct DocsFunction {
+    name: String,
+    comments: String,
+    rust_signature: String,
+    dart_signature: String,
+}
+
+struct DocsType {
+    rust_name: String,
+    dart_name: String,
+    comments: String,
+    /// Fields of structs, or variants of enums
+    members: Vec<DocsMember>,
+    methods: Vec<DocsFunction>,
+}
+
+struct DocsMember {
+    rust_name: String,
+    dart_name: String,
+    rust_type: Option<String>,
+    dart_type: Option<String>,
+    comments: String,
+}
+
+impl DocsReference {
+    fn compute(context: ApiDartGeneratorContext) -> anyhow::Result<Self> {
+        let mir_pack = context.mir_pack;
+        let funcs = mir_pack.funcs_with_impl();
+
+        let mut gatherer = DistinctTypeGatherer::new();
+        for func in &funcs {
+            if let MirFuncOwnerInfo::Method(method) = &func.owner {
+                gatherer.add(&method.owner_ty);
+            }
+        }
+        for ty in mir_pack.distinct_types(None) {
+            if matches!(
+                ty,
+                MirType::StructRef(_)
+                    | MirType::EnumRef(_)
+                    | MirType::RustAutoOpaqueImplicit(_)
+                    | MirType::RustOpaque(_)
+            ) {
+                gatherer.add(&ty);
+            }
+        }
+        let types = (gatherer.gather().into_iter())
+            .filter(|ty| !ty.should_ignore(mir_pack))
+            .collect_vec();
+
+        let mut pages: Vec<DocsPage> = vec![];
+        for func in funcs
+            .iter()
+            .filter(|f| matches!(f.owner, MirFuncOwnerInfo::Function))
+        {
+            page_of(&mut pages, func.name.namespace.to_string())
+                .functions
+                .push(compute_function(func, context)?);
+        }
+        for ty in &types {
+            let Some(namespace) = ty.self_namespace() else {
+                continue;
+            };
+            let docs_type = compute_type(ty, &funcs, context)?;
+            page_of(&mut pages, namespace.to_string())
+                .types
+                .push(docs_type);
+        }
+        for page in &mut pages {
+            page.functions.sort_by(|a, b| a.name.cmp(&b.name));
+            page.types.sort_by(|a, b| a.rust_name.cmp(&b.rust_name));
+        }
+        pages.sort_by(|a, b| a.namespace.cmp(&b.namespace));
+
+        let type_mappings = (mir_pack.distinct_types(None).iter())
+            .map(|ty| (rust_type(ty), dart_type(ty, context)))
+            .sorted()
+            .dedup()
+            .collect_vec();
+
+        Ok(Self {
+            pages,
+            type_mappings,
+        })
+    }
+}
+
+impl DocsPage {
+    fn file_name(&self) -> String {
+        format!("{}.html", self.namespace.replace("::", "."))
+    }
+}
+
+fn page_of(pages: &mut Vec<DocsPage>, namespace: String) -> &mut DocsPage {
+    let index = match pages.iter().position(|x| x.namespace == namespace) {
+        Some(index) => index,
+        None => {
+            pages.push(DocsPage {
+                namespace,
+                functions: vec![],
+                types: vec![],
+            });
+            pages.len() - 1
+        }
+    };
+    &mut pages[index]
+}
+
+fn compute_function(
+    func: &MirFunc,
+    context: ApiDartGeneratorContext,
+) -> anyhow::Result<DocsFunction> {
+    let api_dart_func = function::generate(func, context)?;
+    Ok(DocsFunction {
+        name: func.name_dart_api(),
+        comments: compute_comments(&func.comments),
+        rust_signature: compute_rust_signature(&func.name.name, func),
+        dart_signature: tidy_signature(&api_dart_func.func_expr),
+    })
+}
+
+fn compute_method(
+    func: &MirFunc,
+    dart_class_name: &str,
+    context: ApiDartGeneratorContext,
+) -> anyhow::Result<DocsFunction> {
+    let MirFuncOwnerInfo::Method(method) = &func.owner else {
+        unreachable!()
+    };
+    Ok(DocsFunction {
+        name: (method.actual_method_dart_name.clone())
+            .unwrap_or_else(|| method.actual_method_name.to_case(Case::Camel)),
+        comments: compute_comments(&func.comments),
+        rust_signature: compute_rust_signature(&method.actual_method_name, func),
+        dart_signature: tidy_signature(&generate_api_method_signature(
+            func,
+            context,
+            dart_class_name,
+        )?),
+    })
+}
+
+fn compute_type(
+    ty: &MirType,
+    funcs: &[MirFunc],
+    context: ApiDartGeneratorContext,
+) -> anyhow::Result<DocsType> {
+    let dart_name = dart_type(ty, context);
+    let (comments, members) = match ty {
+        MirType::StructRef(ty) => {
+            let st = ty.get(context.mir_pack);
+            (
+                compute_comments(&st.comments),
+                (st.fields.iter())
+                    .map(|field| compute_field(field, context))
+                    .collect(),
+            )
+        }
+        MirType::EnumRef(ty) => {
+            let enu = ty.get(context.mir_pack);
+            (
+                compute_comments(&enu.comments),
+                (enu.variants.iter()).map(compute_variant).collect(),
+            )
+        }
+        // Opaque types are documented via their methods
+        _ => (String::new(), vec![]),
+    };
+
+    let methods = (funcs.iter())
+        .filter(|func| {
+            matches!(&func.owner, MirFuncOwnerInfo::Method(method)
+                if method.owner_ty.safe_ident() == ty.safe_ident())
+        })
+        .map(|func| compute_method(func, &dart_name, context))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Ok(DocsType {
+        rust_name: rust_type(ty),
+        dart_name,
+        comments,
+        members,
+        methods,
+    })
+}
+
+fn compute_field(field: &MirField, context: ApiDartGeneratorContext) -> DocsMember {
+    DocsMember {
+        rust_name: field.name.rust_style(),
+        dart_name: field.name.dart_style(),
+        rust_type: Some(rust_type(&field.ty)),
+        dart_type: Some(dart_type(&field.ty, context)),
+        comments: compute_comments(&field.comments),
+    }
+}
+
+fn compute_variant(variant: &MirEnumVariant) -> DocsMember {
+    DocsMember {
+        rust_name: variant.name.rust_style(),
+        dart_name: variant.name.dart_style(),
+        rust_type: match &variant.kind {
+            MirVariantKind::Value => None,
+            MirVariantKind::Struct(st) => Some(
+                (st.fields.iter())
+                    .map(|field| match st.is_fields_named {
+                        true => format!("{}: {}", field.name.rust_style(), rust_type(&field.ty)),
+                        false => rust_type(&field.ty),
+                    })
+                    .join(", "),
+            ),
+        },
+        dart_type: None,
+        comments: compute_comments(&variant.comments),
+    }
+}
+
+fn compute_rust_signature(name: &str, func: &MirFunc) -> String {
+    let is_instance_method = matches!(&func.owner, MirFuncOwnerInfo::Method(method)
+        if method.mode == MirFuncOwnerInfoMethodMode::Instance);
+    let params = (func.inputs.iter())
+        .enumerate()
+        .map(|(index, input)| {
+            if is_instance_method && index == 0 {
+                format!("{}self", ownership_prefix(input))
+            } else {
+                format!(
+                    "{}: {}{}",
+                    input.inner.name.rust_style(),
+                    ownership_prefix(input),
+                    rust_type(&input.inner.ty)
+                )
+            }
+        })
+        .join(", ");
+    let maybe_async = if func.rust_async { "async " } else { "" };
+    let output = match &func.output.error {
+        Some(error) => format!(
+            "Result<{}, {}>",
+            rust_type(&func.output.normal),
+            rust_type(error)
+        ),
+        None => rust_type(&func.output.normal),
+    };
+    let maybe_output = if output == "()" {
+        "".to_owned()
+    } else {
+        format!(" -> {output}")
+    };
+    format!("{maybe_async}fn {name}({params}){maybe_output}")
+}
+
+fn ownership_prefix(input: &MirFuncInput) -> &'static str {
+    match &input.inner.ty {
+        MirType::RustAutoOpaqueImplicit(ty) => ty.ownership_mode.prefix(),
+        _ => (input.ownership_mode.as_ref()).map_or("", |x| x.prefix()),
+    }
+}
+
+/// The type as written in the Rust source, instead of the wrapper used on the wire
+fn rust_type(ty: &MirType) -> String {
+    lazy_static! {
+        static ref CRATE_PATH: Regex = Regex::new(r"\bcrate::(\w+::)*").unwrap();
+    }
+    let raw = match ty {
+        MirType::RustAutoOpaqueImplicit(ty) => ty.raw.string.with_original_lifetime().to_owned(),
+        _ => ty.rust_api_type(),
+    };
+    CRATE_PATH.replace_all(&raw, "").into_owned()
+}
+
+fn dart_type(ty: &MirType, context: ApiDartGeneratorContext) -> String {
+    ApiDartGenerator::new(ty.clone(), context).dart_api_type()
+}
+
+/// Strip the `///` of the doc comments
+fn compute_comments(comments: &[MirComment]) -> String {
+    (comments.iter())
+        .flat_map(|comment| comment.0.lines())
+        .map(|line| line.trim_start().trim_start_matches("///"))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .join("\n")
+}
+
+/// The generated code relies on `dart format` to clean up the spaces, while we display it directly
+fn tidy_signature(raw: &str) -> String {
+    raw.split_whitespace()
+        .join(" ")
+        .replace(" ,", ",")
+        .replace(" }", "}")
+        .replace("( ", "(")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_comments_and_tidy_signature() {
+        assert_eq!(
+            compute_comments(&[
+                MirComment("/// Adds two numbers.".to_owned()),
+                MirComment("///\n/// Never overflows.".to_owned()),
+            ]),
+            "Adds two numbers.\n\nNever overflows."
+        );
+        assert_eq!(
+            tidy_signature("  Future<int>  add({required int a , required int b })"),
+            "Future<int> add({required int a, required int b})"
+        );
+    }
+}
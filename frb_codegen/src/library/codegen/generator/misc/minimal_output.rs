@@ -0,0 +1,37 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Which builds omit the debugging niceties from the generated code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum MinimalOutput {
+    #[default]
+    Never,
+    /// Only the release builds, i.e. Dart with `dart.vm.product` and Rust without `debug_assertions`
+    Release,
+    Always,
+}
+
+impl MinimalOutput {
+    /// A Dart expression of `minimal` or `full`, where the unused one is removed by tree shaking
+    pub(crate) fn select_dart(self, minimal: &str, full: &str) -> String {
+        match self {
+            Self::Never => full.to_owned(),
+            Self::Release => {
+                format!("(const bool.fromEnvironment('dart.vm.product') ? {minimal} : {full})")
+            }
+            Self::Always => minimal.to_owned(),
+        }
+    }
+
+    /// A Rust expression of `minimal` or `full`, where the unused one is removed by the optimizer
+    pub(crate) fn select_rust(self, minimal: &str, full: &str) -> String {
+        match self {
+            Self::Never => full.to_owned(),
+            Self::Release => {
+                format!("if cfg!(debug_assertions) {{ {full} }} else {{ {minimal} }}")
+            }
+            Self::Always => minimal.to_owned(),
+        }
+    }
+}
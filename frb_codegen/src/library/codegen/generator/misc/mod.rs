@@ -3,6 +3,7 @@ use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::codegen::ir::mir::ty::MirType;
 
 pub(crate) mod comments;
+pub(crate) mod minimal_output;
 pub(crate) mod path_texts;
 pub(crate) mod struct_or_record;
 pub(crate) mod structs_macro;
@@ -33,10 +34,28 @@ pub fn is_js_value(ty: &MirType) -> bool {
     }
 }
 
-pub(crate) fn generate_code_header() -> String {
+pub(crate) fn generate_code_header(version_in_header: bool) -> String {
+    let version = if version_in_header {
+        format!("@ {}", env!("CARGO_PKG_VERSION"))
+    } else {
+        "".to_owned()
+    };
     format!(
         "// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ {}.",
-        env!("CARGO_PKG_VERSION")
+// @generated by `flutter_rust_bridge`{version}."
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::generator::misc::generate_code_header;
+
+    #[test]
+    fn test_generate_code_header() {
+        assert!(generate_code_header(true).ends_with(&format!(
+            "// @generated by `flutter_rust_bridge`@ {}.",
+            env!("CARGO_PKG_VERSION")
+        )));
+        assert!(generate_code_header(false).ends_with("// @generated by `flutter_rust_bridge`."));
+    }
+}
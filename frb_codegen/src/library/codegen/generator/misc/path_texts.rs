@@ -2,6 +2,7 @@ use crate::codegen::generator::acc::Acc;
 use crate::codegen::generator::misc::target::{TargetOrCommon, TargetOrCommonMap};
 use crate::utils::basic_code::general_code::GeneralCode;
 use crate::utils::file_utils::create_dir_all_and_write;
+use crate::utils::message_format::{emit, Event};
 use itertools::Itertools;
 use std::ops::Add;
 use std::path::PathBuf;
@@ -56,6 +57,7 @@ impl PathTexts {
         self.assert_no_duplicate_paths();
         for item in self.0.iter() {
             create_dir_all_and_write(&item.path, item.text.all_code())?;
+            emit(&Event::GeneratedFile { path: &item.path });
         }
         Ok(())
     }
@@ -7,7 +7,9 @@ use crate::codegen::misc::GeneratorProgressBarPack;
 pub(crate) mod acc;
 pub(crate) mod api_dart;
 pub(crate) mod codec;
+pub(crate) mod docs;
 pub(crate) mod misc;
+mod third_party_attribution;
 pub(crate) mod wire;
 
 pub(crate) struct GeneratorOutput {
@@ -21,7 +23,10 @@ pub(crate) fn generate(
     dumper: &Dumper,
     progress_bar_pack: &GeneratorProgressBarPack,
 ) -> anyhow::Result<GeneratorOutput> {
+    let pb = progress_bar_pack.generate_api_dart.start();
     let api_dart_output = api_dart::generate(mir_pack, &config.api_dart, dumper)?;
+    drop(pb);
+
     let wire_output = wire::generate(
         mir_pack,
         &config.wire,
@@ -31,7 +36,15 @@ pub(crate) fn generate(
         progress_bar_pack,
     )?;
 
-    let output_texts = wire_output.output_texts + api_dart_output.output_texts;
+    let third_party_attribution_output_texts = third_party_attribution::generate(
+        &api_dart_output.third_party_crates,
+        &config.wire.rust.rust_crate_dir,
+        &config.api_dart.dart_decl_base_output_path,
+    )?;
+
+    let output_texts = wire_output.output_texts
+        + api_dart_output.output_texts
+        + third_party_attribution_output_texts;
     let output_texts = output_texts.merge();
 
     Ok(GeneratorOutput {
@@ -39,3 +52,343 @@ pub(crate) fn generate(
         dart_needs_freezed: api_dart_output.needs_freezed,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::codegen::config::config::MetaConfig;
+    use crate::codegen::config::internal_config::InternalConfig;
+    use crate::codegen::dumper::Dumper;
+    use crate::codegen::generator::generate;
+    use crate::codegen::misc::GeneratorProgressBarPack;
+    use crate::codegen::{Config, MinimalOutput};
+    use crate::utils::logs::configure_opinionated_test_logging;
+    use crate::utils::test_utils::get_test_fixture_dir;
+    use serial_test::serial;
+    use std::collections::HashMap;
+    use std::env;
+
+    #[test]
+    #[serial]
+    fn test_lazy_struct_decodes_owned_fields_eagerly() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/lazy")?;
+
+        // Opaque objects are owned by Dart once decoded, thus would leak if the bytes were never decoded
+        let dart_decode = code_block(&outputs["frb_generated.dart"], "Item sse_decode_item(");
+        assert!(dart_decode
+            .contains("var var_title = sseDecodeLazyField(deserializer, sse_decode_String);"));
+        for field in ["handle", "sharedHandle", "handles", "callback"] {
+            assert!(
+                dart_decode.contains(&format!("var var_{field} = LazyField.value(sse_decode_")),
+                "{dart_decode}"
+            );
+        }
+
+        let rust_encode = code_block(
+            &outputs["frb_generated.rs"],
+            "impl SseEncode for crate::api::Item {",
+        );
+        assert!(rust_encode.contains("sse_encode_lazy_field(self.title, serializer);"));
+        for field in ["handle", "shared_handle", "handles", "callback"] {
+            assert!(
+                rust_encode.contains(&format!(">::sse_encode(self.{field}, serializer);")),
+                "{rust_encode}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_feature_sets() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/feature_sets")?;
+        let rust = &outputs["frb_generated.rs"];
+        let dart = &outputs["frb_generated.dart"];
+
+        // The function is compiled into every build, while reporting an error in the others
+        assert!(rust.contains("#[cfg(feature = \"pro\")] fn wire__crate__api__export_pdf_impl("));
+        let unavailable = code_between(
+            rust,
+            "#[cfg(not(feature = \"pro\"))] fn wire__crate__api__export_pdf_impl(",
+            "Err(",
+        );
+        assert!(unavailable.contains("Dart2RustMessageSse::from_wire("));
+        assert!(!unavailable.contains("sse_decode"), "{unavailable}");
+        assert!(rust.contains("\"`export_pdf` is not available in the feature set that the Rust crate is built with\""));
+        assert!(!rust.contains("unimplemented!"));
+
+        // Types existing in some of the feature sets are gated as well
+        let preceding_code = |start: &str| rust[..rust.find(start).unwrap()].trim_end();
+        for start in [
+            "impl SseDecode for crate::api::PdfOptions {",
+            "impl SseEncode for crate::api::PdfOptions {",
+        ] {
+            assert!(preceding_code(start).ends_with("#[cfg(feature = \"pro\")] const _: () = {"));
+        }
+        assert!(!preceding_code("impl SseDecode for crate::api::Page {").ends_with('{'));
+
+        let dart_export_pdf = code_between(
+            dart,
+            "@override Future<String> crateApiExportPdf(",
+            "executeNormal",
+        );
+        assert!(dart_export_pdf.contains(
+            "if (const bool.hasEnvironment('FRB_RUST_FEATURES') && \
+            !(const String.fromEnvironment('FRB_RUST_FEATURES').split(',').contains('pro')))"
+        ));
+        let dart_show_upgrade_hint = code_between(
+            dart,
+            "@override Future<String> crateApiShowUpgradeHint(",
+            "executeNormal",
+        );
+        assert!(dart_show_upgrade_hint.contains("!(!(const String.fromEnvironment("));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_message_codec() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/message_codec")?;
+        let rust = &outputs["frb_generated.rs"];
+        let dart = &outputs["frb_generated.dart"];
+
+        // Messages are transferred as bytes, converted by the code generated for the codec
+        let rust_person_decode = code_block(rust, "impl SseDecode for crate::proto::Person {");
+        assert!(rust_person_decode
+            .contains("<crate::proto::Person as prost::Message>::decode(inner.as_slice())"));
+        let rust_greeting_encode = code_block(rust, "impl SseEncode for crate::proto::Greeting {");
+        assert!(rust_greeting_encode.contains("prost::Message::encode_to_vec(&self)"));
+        let rust_monster_decode = code_block(rust, "impl SseDecode for crate::fbs::MonsterT {");
+        assert!(rust_monster_decode
+            .contains("flatbuffers::root::<crate::fbs::Monster>(&inner).expect("));
+        assert!(rust.contains("let root = self.pack(&mut builder); builder.finish(root, None);"));
+
+        assert!(
+            code_block(dart, "Person sse_decode_protobuf_crate_proto_Person(")
+                .contains("return Person.fromBuffer(inner);")
+        );
+        assert!(
+            code_block(dart, "MonsterT sse_decode_flatbuffers_crate_fbs_MonsterT(")
+                .contains("return Monster(inner).unpack();")
+        );
+        assert!(dart.contains("sse_encode_list_prim_u_8_strict(self.writeToBuffer(), serializer);"));
+        assert!(dart.contains("import 'package:flat_buffers/flat_buffers.dart' as fb;"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_type_mappings() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/type_mappings")?;
+        let rust = &outputs["frb_generated.rs"];
+        let dart = &outputs["frb_generated.dart"];
+
+        // Only the mapped type is imported, thus the conversion code can refer to it by name
+        assert!(rust.contains("use std::net::Ipv4Addr;"));
+        assert!(!rust.contains("use std::net::*;"));
+        assert!(code_block(rust, "impl SseDecode for  Ipv4Addr {")
+            .contains("return inner.parse::<Ipv4Addr>().unwrap();"));
+        assert!(code_block(rust, "impl SseEncode for  Ipv4Addr {")
+            .contains("<String>::sse_encode(self.to_string(), serializer);"));
+        assert!(dart.contains("return InternetAddress(inner);"));
+        assert!(dart.contains("sse_encode_String(self.address, serializer);"));
+
+        // The struct of the same name is matched by its path, and is not hijacked by the mapping
+        assert!(
+            code_block(rust, "impl SseEncode for crate::api::legacy::Ipv4Addr {")
+                .contains("<String>::sse_encode(self.text, serializer);")
+        );
+        let legacy = &outputs["legacy.dart"];
+        assert!(legacy.contains("Future<Ipv4Addr> legacyPing({required Ipv4Addr addr })"));
+        let api = &outputs["api.dart"];
+        assert!(api.contains("Future<InternetAddress> ping({required InternetAddress addr })"));
+        assert!(api.contains("Future<BigInt> pingAll({required List<InternetAddress> addrs })"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_dart_sealed_enums() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/dart_sealed_enums")?;
+        let api = &outputs["api.dart"];
+
+        // Plain sealed classes, thus neither freezed nor build_runner is needed
+        assert!(!api.contains("freezed"));
+        assert!(api.contains("sealed class Shape  {"));
+        assert!(api.contains("const factory Shape.empty() = Shape_Empty;"));
+        assert!(api
+            .contains("const factory Shape.circle({ required double radius , }) = Shape_Circle;"));
+        // Trailing optional unnamed fields become optional positional parameters
+        assert!(api.contains(
+            "const factory Shape.polygon(Float64List field0 ,[String? field1 ,]) = Shape_Polygon;"
+        ));
+
+        let circle = code_between(
+            api,
+            "final class Shape_Circle extends Shape  {",
+            "final class Shape_Polygon",
+        );
+        assert!(circle.contains("final double radius;"));
+        assert!(circle.contains("const Shape_Circle({ required this.radius , });"));
+        assert!(circle.contains("String toString() => 'Shape.circle(radius: $radius)';"));
+        assert!(circle.contains("int get hashCode => radius.hashCode;"));
+        assert!(circle.contains("&& radius == other.radius;"));
+
+        // The variant classes are used by the generated (de)serialization as well
+        let dart = &outputs["frb_generated.dart"];
+        assert!(dart.contains("return Shape_Polygon(var_field0, var_field1);"));
+        assert!(dart.contains("case Shape_Circle(radius: final radius):"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_dart_mocks() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/dart_mocks")?;
+        let mocks = &outputs["frb_generated.mocks.dart"];
+
+        // Typed stubs of every API function, including the methods and the streams
+        let api = code_between(
+            mocks,
+            "class FakeRustLibApi implements RustLibApi {",
+            "class FakeCounter",
+        );
+        assert!(api.contains("@override Future<String> crateApiGreet({required String name }) => throw UnimplementedError('FakeRustLibApi.crateApiGreet');"));
+        assert!(api.contains("@override Stream<int> crateApiTicks() =>"));
+        assert!(api.contains("@override Future<int> crateApiCounterIncrement({required Counter that , required int by }) =>"));
+        assert!(api.contains("@override Future<Counter> crateApiCreateCounter() =>"));
+        assert!(api.contains(
+            "@override CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CounterPtr =>"
+        ));
+        assert!(!mocks.contains("noSuchMethod"));
+
+        // The auto opaque type is faked with its instance members, while the static ones are not part of the interface
+        let counter = code_between(mocks, "class FakeCounter implements Counter {", "\n}");
+        assert!(counter.contains("void dispose() => _isDisposed = true;"));
+        assert!(counter.contains("int get count=> throw UnimplementedError('FakeCounter.count');"));
+        assert!(counter.contains(
+            "Future<int>  increment({required int by })=> throw UnimplementedError('FakeCounter.increment');"
+        ));
+        assert!(!counter.contains("newInstance"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_minimal_output_release() -> anyhow::Result<()> {
+        let outputs = execute_generate("library/codegen/generator/mod/minimal_output")?;
+        let rust = &outputs["frb_generated.rs"];
+        let dart = &outputs["frb_generated.dart"];
+
+        // The debugging niceties are only dropped in release builds
+        assert!(rust.contains(r#"debug_name: if cfg!(debug_assertions) { "add" } else { "" },"#));
+        assert!(rust.contains(
+            "flutter_rust_bridge::frb_generated_default_handler!(minimal_output: if cfg!(debug_assertions) { false } else { true });"
+        ));
+        assert!(dart.contains("constMeta: (const bool.fromEnvironment('dart.vm.product') ? const TaskConstMeta(debugName: \"\", argNames: []) : kCrateApiAddConstMeta),"));
+        assert!(dart.contains("argValues: (const bool.fromEnvironment('dart.vm.product') ? const [] : [left, right]),"));
+        assert!(dart.contains("TaskConstMeta get kCrateApiAddConstMeta =>"));
+
+        // Unlike the niceties, the check that all arguments are consumed is kept
+        assert!(rust.contains("deserializer.end();"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_minimal_output_always() -> anyhow::Result<()> {
+        let outputs = execute_generate_with_config(
+            "library/codegen/generator/mod/minimal_output",
+            |config| config.minimal_output = Some(MinimalOutput::Always),
+        )?;
+        let rust = &outputs["frb_generated.rs"];
+        let dart = &outputs["frb_generated.dart"];
+
+        assert!(rust.contains(r#"debug_name: "","#));
+        assert!(!rust.contains(r#""add""#));
+        assert!(rust.contains(
+            "flutter_rust_bridge::frb_generated_default_handler!(minimal_output: true);"
+        ));
+        assert!(dart.contains("constMeta: const TaskConstMeta(debugName: \"\", argNames: []),"));
+        assert!(dart.contains("argValues: const [],"));
+        assert!(!dart.contains("kCrateApiAddConstMeta"));
+        assert!(rust.contains("deserializer.end();"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_minimal_output_never() -> anyhow::Result<()> {
+        let outputs = execute_generate_with_config(
+            "library/codegen/generator/mod/minimal_output",
+            |config| config.minimal_output = Some(MinimalOutput::Never),
+        )?;
+        let rust = &outputs["frb_generated.rs"];
+        let dart = &outputs["frb_generated.dart"];
+
+        assert!(rust.contains(r#"debug_name: "add","#));
+        assert!(rust.contains("flutter_rust_bridge::frb_generated_default_handler!();"));
+        assert!(dart.contains("constMeta: kCrateApiAddConstMeta,"));
+        assert!(dart.contains("argValues: [left, right],"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_parallel_generation_is_deterministic() -> anyhow::Result<()> {
+        // The items are generated in parallel, while the outputs should not depend on the scheduling
+        let fixture_name = "library/codegen/generator/mod/type_mappings";
+        let first = execute_generate(fixture_name)?;
+        let second = execute_generate(fixture_name)?;
+        assert_eq!(first, second);
+        Ok(())
+    }
+
+    /// The code from `start` until the first closing brace
+    fn code_block<'a>(text: &'a str, start: &str) -> &'a str {
+        let text = &text[text.find(start).unwrap()..];
+        &text[..text.find('}').unwrap()]
+    }
+
+    /// The code from `start` until the first `end`
+    fn code_between<'a>(text: &'a str, start: &str, end: &str) -> &'a str {
+        let text = &text[text.find(start).unwrap()..];
+        &text[..text.find(end).unwrap()]
+    }
+
+    /// Runs the whole generator on the fixture, and returns the output texts by file name
+    fn execute_generate(fixture_name: &str) -> anyhow::Result<HashMap<String, String>> {
+        execute_generate_with_config(fixture_name, |_| {})
+    }
+
+    /// Same as [`execute_generate`], with the config of the fixture modified by `modify_config`
+    fn execute_generate_with_config(
+        fixture_name: &str,
+        modify_config: impl FnOnce(&mut Config),
+    ) -> anyhow::Result<HashMap<String, String>> {
+        configure_opinionated_test_logging();
+        let test_fixture_dir = get_test_fixture_dir(fixture_name);
+        env::set_current_dir(&test_fixture_dir)?;
+
+        let mut config = Config::from_files_auto()?;
+        modify_config(&mut config);
+        let internal_config = InternalConfig::parse(&config, &MetaConfig { watch: false })?;
+        let dumper_config = Default::default();
+        let dumper = Dumper::new(&dumper_config);
+        let progress_bar_pack = GeneratorProgressBarPack::new();
+        let mir_pack =
+            crate::codegen::parser::parse(&internal_config.parser, &dumper, &progress_bar_pack)?;
+        let output = generate(
+            &mir_pack,
+            &internal_config.generator,
+            &dumper,
+            &progress_bar_pack,
+        )?;
+
+        Ok((output.output_texts.0.into_iter())
+            .map(|path_text| {
+                let name = path_text.path.file_name().unwrap().to_str().unwrap();
+                (name.to_owned(), path_text.text.all_code())
+            })
+            .collect())
+    }
+}
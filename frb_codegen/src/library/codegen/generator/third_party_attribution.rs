@@ -0,0 +1,263 @@
+use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
+use crate::codegen::misc::THIRD_PARTY_DIR_NAME;
+use crate::library::commands::cargo_metadata::execute_cargo_metadata;
+use crate::utils::basic_code::general_code::GeneralCode;
+use crate::utils::crate_name::CrateName;
+use anyhow::Context;
+use cargo_metadata::{Metadata, Package};
+use itertools::Itertools;
+use log::warn;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const OUTPUT_FILE_NAME: &str = "THIRD_PARTY_LICENSES.md";
+
+/// Generates the attribution file for the third-party crates whose API is bridged into
+/// the `third_party` directory, since the generated code carries e.g. their doc comments.
+pub(super) fn generate(
+    third_party_crates: &[CrateName],
+    rust_crate_dir: &Path,
+    dart_decl_base_output_path: &Path,
+) -> anyhow::Result<PathTexts> {
+    if third_party_crates.is_empty() {
+        return Ok(PathTexts(vec![]));
+    }
+
+    let metadata = execute_cargo_metadata(&rust_crate_dir.join("Cargo.toml"))?;
+    let sections = (third_party_crates.iter())
+        .map(|crate_name| generate_crate_section(crate_name, &metadata))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let text = format!(
+        "<!-- This file is automatically generated, so please do not edit it. -->
+
+# Third-party licenses
+
+The code in this directory is generated from the following crates.
+
+{}",
+        sections.join("\n")
+    );
+
+    Ok(PathTexts(vec![PathText::new(
+        (dart_decl_base_output_path.join(THIRD_PARTY_DIR_NAME)).join(OUTPUT_FILE_NAME),
+        GeneralCode::new_text(text),
+    )]))
+}
+
+fn generate_crate_section(crate_name: &CrateName, metadata: &Metadata) -> anyhow::Result<String> {
+    let packages = find_locked_packages(crate_name, metadata);
+
+    if packages.is_empty() {
+        warn!(
+            "Cannot find the license of third-party crate `{}` in the dependencies",
+            crate_name.raw()
+        );
+        return Ok(format!("## {}\n\n- License: unknown\n", crate_name.raw()));
+    }
+
+    Ok(packages
+        .into_iter()
+        .map(generate_package_section)
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .join("\n"))
+}
+
+/// The versions locked for the workspace, instead of all versions in the dependency graph,
+/// since e.g. an older version may be pulled in by another dependency
+fn find_locked_packages<'a>(crate_name: &CrateName, metadata: &'a Metadata) -> Vec<&'a Package> {
+    let Some(resolve) = &metadata.resolve else {
+        return vec![];
+    };
+    let crate_name = crate_name.raw().replace('-', "_");
+    (resolve.nodes.iter())
+        .filter(|node| metadata.workspace_members.contains(&node.id))
+        .flat_map(|node| &node.deps)
+        .filter(|dep| dep.name.replace('-', "_") == crate_name)
+        .map(|dep| &metadata[&dep.pkg])
+        .sorted_by(|a, b| a.version.cmp(&b.version))
+        .dedup_by(|a, b| a.id == b.id)
+        .collect_vec()
+}
+
+fn generate_package_section(package: &Package) -> anyhow::Result<String> {
+    if package.license.is_none() && package.license_file.is_none() {
+        warn!(
+            "Third-party crate `{}` does not declare its license",
+            package.name
+        );
+    }
+
+    let mut ans = format!(
+        "## {} {}\n\n- License: {}\n",
+        package.name,
+        package.version,
+        package.license.as_deref().unwrap_or("unknown"),
+    );
+    if let Some(repository) = &package.repository {
+        ans += &format!("- Repository: {repository}\n");
+    }
+    if !package.authors.is_empty() {
+        ans += &format!("- Authors: {}\n", package.authors.join(", "));
+    }
+
+    for path in find_license_files(package)? {
+        let content = match fs::read(&path) {
+            Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+            Err(e) => {
+                warn!("Skip license file {path:?} since it cannot be read: {e}");
+                continue;
+            }
+        };
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        ans += &format!(
+            "\n### {file_name}\n\n```text\n{}\n```\n",
+            content.trim_end()
+        );
+    }
+
+    Ok(ans)
+}
+
+fn find_license_files(package: &Package) -> anyhow::Result<Vec<PathBuf>> {
+    const PREFIXES: [&str; 4] = ["LICENSE", "LICENCE", "COPYING", "NOTICE"];
+
+    let package_dir = (package.manifest_path.parent())
+        .context("cannot find package dir")?
+        .as_std_path();
+    let conventional_files = fs::read_dir(package_dir)?
+        .map(|entry| Ok(entry?.path()))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+            PREFIXES
+                .iter()
+                .any(|x| file_name.to_uppercase().starts_with(x))
+        });
+    let declared_file = (package.license_file())
+        .map(|path| path.into_std_path_buf())
+        .filter(|path| path.is_file());
+
+    Ok(conventional_files
+        .chain(declared_file)
+        .sorted()
+        .dedup()
+        .collect_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_package_section() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs::write(
+            temp_dir.path().join("LICENSE-MIT"),
+            "MIT License\n\nHello\n",
+        )?;
+        fs::write(temp_dir.path().join("README.md"), "Not a license")?;
+
+        let package: Package = serde_json::from_value(serde_json::json!({
+            "name": "hello-world",
+            "version": "1.2.3",
+            "id": "hello-world 1.2.3",
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": temp_dir.path().join("Cargo.toml"),
+            "license": "MIT OR Apache-2.0",
+            "repository": "https://example.com/hello-world",
+            "authors": ["Alice", "Bob"],
+        }))?;
+
+        assert_eq!(
+            generate_package_section(&package)?,
+            "## hello-world 1.2.3
+
+- License: MIT OR Apache-2.0
+- Repository: https://example.com/hello-world
+- Authors: Alice, Bob
+
+### LICENSE-MIT
+
+```text
+MIT License
+
+Hello
+```
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_package_section_non_utf8_license() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        fs::write(temp_dir.path().join("LICENSE"), b"Copyright \xa9 Alice\n")?;
+
+        let package = create_package("hello", "1.0.0", temp_dir.path())?;
+
+        assert!(generate_package_section(&package)?.contains("Copyright \u{FFFD} Alice"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_locked_packages() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let packages = [
+            create_package("app", "0.1.0", temp_dir.path())?,
+            create_package("hello-world", "1.0.0", temp_dir.path())?,
+            // Only pulled in by another dependency
+            create_package("hello-world", "0.9.0", temp_dir.path())?,
+            create_package("other", "2.0.0", temp_dir.path())?,
+        ];
+        let node = |id: &str, deps: &[(&str, &str)]| {
+            serde_json::json!({
+                "id": id,
+                "dependencies": deps.iter().map(|(_, pkg)| pkg).collect_vec(),
+                "deps": deps
+                    .iter()
+                    .map(|(name, pkg)| serde_json::json!({"name": name, "pkg": pkg}))
+                    .collect_vec(),
+            })
+        };
+        let metadata: Metadata = serde_json::from_value(serde_json::json!({
+            "packages": packages,
+            "workspace_members": ["app 0.1.0"],
+            "resolve": {
+                "nodes": [
+                    node("app 0.1.0", &[("hello_world", "hello-world 1.0.0"), ("other", "other 2.0.0")]),
+                    node("other 2.0.0", &[("hello_world", "hello-world 0.9.0")]),
+                    node("hello-world 1.0.0", &[]),
+                    node("hello-world 0.9.0", &[]),
+                ],
+                "root": "app 0.1.0",
+            },
+            "workspace_root": temp_dir.path(),
+            "target_directory": temp_dir.path().join("target"),
+            "version": 1,
+        }))?;
+
+        let locked = find_locked_packages(&CrateName::new("hello-world".to_owned()), &metadata);
+        assert_eq!(
+            locked.iter().map(|x| x.id.repr.as_str()).collect_vec(),
+            vec!["hello-world 1.0.0"]
+        );
+        Ok(())
+    }
+
+    fn create_package(name: &str, version: &str, dir: &Path) -> anyhow::Result<Package> {
+        Ok(serde_json::from_value(serde_json::json!({
+            "name": name,
+            "version": version,
+            "id": format!("{name} {version}"),
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": dir.join("Cargo.toml"),
+        }))?)
+    }
+}
@@ -8,4 +8,7 @@ pub(crate) struct GeneratorWireCInternalConfig {
     pub(crate) rust_output_path: PathBuf,
     pub(crate) c_output_path: Option<PathBuf>,
     pub(crate) c_symbol_prefix: String,
+    /// Merged into the generated cbindgen config
+    pub(crate) cbindgen_config: Option<serde_json::Value>,
+    pub(crate) cache_directory: Option<PathBuf>,
 }
@@ -2,13 +2,47 @@ use crate::codegen::generator::misc::path_texts::PathTexts;
 use crate::codegen::generator::wire::c::internal_config::GeneratorWireCInternalConfig;
 use crate::codegen::misc::GeneratorProgressBarPack;
 use crate::library::commands::cbindgen::{cbindgen, CbindgenArgs};
+use crate::utils::file_cache::FileCache;
 use crate::utils::file_utils::temp_change_file;
+use itertools::Itertools;
 
 pub(crate) fn execute(
     config: &GeneratorWireCInternalConfig,
     extern_struct_names: Vec<String>,
     rust_output_texts: &PathTexts,
     progress_bar_pack: &GeneratorProgressBarPack,
+) -> anyhow::Result<String> {
+    // The extern C surface is fully determined by the generated Rust code,
+    // so cbindgen (which is slow) can be skipped if it is identical to last run.
+    let rust_output_codes = (rust_output_texts.0.iter())
+        .map(|rust_output_text| rust_output_text.text.all_code())
+        .collect_vec();
+    let extern_struct_names_joined = extern_struct_names.join(",");
+    let cbindgen_config = format!("{:?}", config.cbindgen_config);
+    let cache_inputs = [EXTRA_CODE, &extern_struct_names_joined, &cbindgen_config]
+        .into_iter()
+        .chain(rust_output_codes.iter().map(|x| x.as_str()))
+        .collect_vec();
+
+    FileCache::new(config.cache_directory.as_deref()).get_or_compute(
+        "cbindgen",
+        &cache_inputs,
+        || {
+            execute_raw(
+                config,
+                extern_struct_names,
+                rust_output_texts,
+                progress_bar_pack,
+            )
+        },
+    )
+}
+
+fn execute_raw(
+    config: &GeneratorWireCInternalConfig,
+    extern_struct_names: Vec<String>,
+    rust_output_texts: &PathTexts,
+    progress_bar_pack: &GeneratorProgressBarPack,
 ) -> anyhow::Result<String> {
     let _pb = progress_bar_pack.generate_cbindgen.start();
 
@@ -27,6 +61,7 @@ pub(crate) fn execute(
         c_struct_names: extern_struct_names,
         exclude_symbols: vec![],
         after_includes: EXTRA_CODE.to_owned(),
+        extra_config: config.cbindgen_config.as_ref(),
     })?;
 
     drop(changed_file_handles); // do not drop too early
@@ -1,5 +1,7 @@
+use crate::codegen::generator::misc::minimal_output::MinimalOutput;
 use crate::codegen::generator::misc::target::TargetOrCommonMap;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -8,6 +10,8 @@ pub(crate) struct GeneratorWireDartInternalConfig {
     pub web_enabled: bool,
     pub llvm_path: Vec<PathBuf>,
     pub llvm_compiler_opts: String,
+    /// Merged into the generated ffigen config
+    pub ffigen_config: Option<serde_json::Value>,
     pub dart_root: PathBuf,
     pub extra_headers: String,
     pub dart_impl_output_path: TargetOrCommonMap<PathBuf>,
@@ -15,6 +19,11 @@ pub(crate) struct GeneratorWireDartInternalConfig {
     pub default_external_library_loader:
         GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig,
     pub(crate) c_symbol_prefix: String,
+    pub(crate) exported_symbol_prefix: String,
+    pub(crate) cache_directory: Option<PathBuf>,
+    pub(crate) minimal_output: MinimalOutput,
+    pub(crate) version_in_header: bool,
+    pub(crate) dart_mocks: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -22,6 +31,12 @@ pub(crate) struct GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig {
     pub stem: String,
     pub io_directory: String,
     pub web_prefix: String,
+    /// Operating system -> file stem
+    pub io_stems: BTreeMap<String, String>,
+    /// Operating system -> path of the dynamic library
+    pub io_paths: BTreeMap<String, String>,
+    /// Operating systems which use `DynamicLibrary.process()`
+    pub io_process: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
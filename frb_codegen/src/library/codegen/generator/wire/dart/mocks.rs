@@ -0,0 +1,169 @@
+use crate::codegen::generator::api_dart::spec_generator::base::ApiDartGenerator;
+use crate::codegen::generator::api_dart::spec_generator::class::method::{
+    generate_api_methods, GenerateApiMethodConfig, GenerateApiMethodMode,
+};
+use crate::codegen::generator::api_dart::spec_generator::misc::generate_imports_which_types_and_funcs_use;
+use crate::codegen::generator::misc::generate_code_header;
+use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
+use crate::codegen::generator::misc::target::TargetOrCommon;
+use crate::codegen::generator::wire::dart::internal_config::DartOutputClassNamePack;
+use crate::codegen::generator::wire::dart::spec_generator::base::WireDartGeneratorContext;
+use crate::codegen::generator::wire::dart::spec_generator::misc::api_impl_body::generate_api_class_func_signature;
+use crate::codegen::generator::wire::dart::spec_generator::misc::generate_import_dart_api_layer;
+use crate::codegen::generator::wire::dart::spec_generator::misc::ty::rust_opaque::generate_rust_arc_getter_signatures;
+use crate::codegen::ir::mir::pack::MirPackComputedCache;
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
+use crate::codegen::ir::mir::ty::MirType;
+use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartGeneratorInfoTrait;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::basic_code::dart_header_code::DartHeaderCode;
+use crate::utils::basic_code::general_code::{GeneralCode, GeneralDartCode};
+use crate::utils::namespace::Namespace;
+use itertools::Itertools;
+use std::path::PathBuf;
+
+/// Generates fakes of the API class and of the opaque types, so that tests can
+/// call `initMock` and return opaque objects without loading the Rust library.
+pub(super) fn generate(
+    context: WireDartGeneratorContext,
+    api_dart_actual_output_paths: &[PathBuf],
+) -> anyhow::Result<PathTexts> {
+    if !context.config.dart_mocks {
+        return Ok(PathTexts(vec![]));
+    }
+
+    let cache = MirPackComputedCache::compute(context.mir_pack);
+    let dart_impl_output_path = &context.config.dart_impl_output_path[TargetOrCommon::Common];
+    let output_path = dart_impl_output_path.with_extension("mocks.dart");
+
+    let imports = generate_import_dart_api_layer(
+        &context.config.dart_impl_output_path,
+        api_dart_actual_output_paths,
+    )? + &generate_imports_which_types_and_funcs_use(
+        &Namespace::new_self_crate(
+            (dart_impl_output_path.file_stem().unwrap().to_str().unwrap()).to_owned(),
+        ),
+        &Some(&cache.distinct_types.iter().collect_vec()),
+        &None,
+        context.as_api_dart_context(),
+    )?;
+
+    let opaque_types = compute_opaque_types(&cache.distinct_types);
+    let api_class = generate_api_class(&opaque_types, context)?;
+    let opaque_classes = (opaque_types.iter())
+        .map(|ty| generate_opaque_class(ty, context))
+        .join("\n\n");
+
+    let header = DartHeaderCode {
+        file_top: generate_code_header(context.config.version_in_header)
+            + "\n\n// ignore_for_file: unused_import, unnecessary_import, invalid_use_of_internal_member, non_constant_identifier_names\n",
+        import: format!(
+            "import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+            import '{}';
+            {imports}",
+            dart_impl_output_path.file_name().unwrap().to_str().unwrap(),
+        ),
+        ..Default::default()
+    };
+    let body = format!("{api_class}\n\n{opaque_classes}\n");
+
+    Ok(PathTexts(vec![PathText::new(
+        output_path,
+        GeneralCode::Dart(GeneralDartCode { header, body }),
+    )]))
+}
+
+/// The Dart classes of the auto opaque types are generated from their inner opaque types
+fn compute_opaque_types(distinct_types: &[MirType]) -> Vec<MirType> {
+    (distinct_types.iter())
+        .filter_map(|ty| match ty {
+            MirType::RustOpaque(ty) => Some(ty.clone()),
+            MirType::RustAutoOpaqueImplicit(ty) => Some(ty.inner.clone()),
+            MirType::Delegate(MirTypeDelegate::RustAutoOpaqueExplicit(ty)) => {
+                Some(ty.inner.clone())
+            }
+            _ => None,
+        })
+        .map(MirType::RustOpaque)
+        .unique_by(|ty| ty.safe_ident())
+        .collect_vec()
+}
+
+fn generate_api_class(
+    opaque_types: &[MirType],
+    context: WireDartGeneratorContext,
+) -> anyhow::Result<String> {
+    let DartOutputClassNamePack {
+        entrypoint_class_name,
+        api_class_name,
+        ..
+    } = &context.config.dart_output_class_name_pack;
+    let fake_class_name = format!("Fake{api_class_name}");
+
+    let func_stubs = (context.mir_pack.funcs_with_impl().iter())
+        .map(|func| {
+            Ok(generate_stub(
+                &generate_api_class_func_signature(func, context)?,
+                &format!("{fake_class_name}.{}", func.name_dart_wire()),
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    // Only used by the opaque objects from Rust, thus never called when using the fakes
+    let rust_arc_stubs = (opaque_types.iter())
+        .flat_map(|ty| generate_rust_arc_getter_signatures(ty, context))
+        .map(|signature| {
+            let getter_name = signature.split_whitespace().last().unwrap_or_default();
+            generate_stub(&signature, &format!("{fake_class_name}.{getter_name}"))
+        })
+        .collect_vec();
+
+    Ok(format!(
+        "/// Fake of [{api_class_name}] to be passed to `{entrypoint_class_name}.initMock`.
+///
+/// Override the methods used by the code under test, while the others throw [UnimplementedError].
+class {fake_class_name} implements {api_class_name} {{
+  {func_stubs}
+
+  {rust_arc_stubs}
+}}",
+        func_stubs = func_stubs.join("\n\n"),
+        rust_arc_stubs = rust_arc_stubs.join("\n\n"),
+    ))
+}
+
+fn generate_opaque_class(ty: &MirType, context: WireDartGeneratorContext) -> String {
+    let dart_api_type =
+        ApiDartGenerator::new(ty.clone(), context.as_api_dart_context()).dart_api_type();
+    let fake_class_name = format!("Fake{dart_api_type}");
+    let methods = generate_api_methods(
+        ty,
+        context.as_api_dart_context(),
+        &GenerateApiMethodConfig {
+            mode_static: GenerateApiMethodMode::Nothing,
+            mode_non_static: GenerateApiMethodMode::DeclAndStub,
+        },
+        &fake_class_name,
+    );
+
+    format!(
+        "/// Fake of [{dart_api_type}], which can be returned by the mocked API without a Rust object behind it.
+///
+/// Override the methods used by the code under test, while the others throw [UnimplementedError].
+class {fake_class_name} implements {dart_api_type} {{
+  bool _isDisposed = false;
+
+  @override
+  void dispose() => _isDisposed = true;
+
+  @override
+  bool get isDisposed => _isDisposed;
+
+  {methods}
+}}",
+        methods = methods.code,
+    )
+}
+
+fn generate_stub(signature: &str, name: &str) -> String {
+    format!("@override {signature} => throw UnimplementedError('{name}');")
+}
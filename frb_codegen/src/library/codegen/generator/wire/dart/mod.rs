@@ -7,6 +7,7 @@ use crate::codegen::misc::GeneratorProgressBarPack;
 use std::path::PathBuf;
 
 pub(crate) mod internal_config;
+mod mocks;
 pub(crate) mod spec_generator;
 mod text_generator;
 
@@ -42,10 +43,12 @@ pub(crate) fn generate(
         &text.text.clone().map(|x, _| x.map(|x| x.all_code())),
     )?;
 
+    let mocks_output_texts = mocks::generate(context, api_dart_actual_output_paths)?;
+
     Ok(GeneratorWireDartOutput {
         output_texts: PathTexts::new_from_targets(
             &context.config.dart_impl_output_path,
             &text.text,
-        ),
+        ) + mocks_output_texts,
     })
 }
@@ -115,6 +115,7 @@ impl<'a> WireDartCodecCstGeneratorEncoderTrait for DelegateWireDartCodecCstGener
             // ))),
             MirTypeDelegate::Backtrace
             | MirTypeDelegate::AnyhowException
+            | MirTypeDelegate::Progress(_)
             | MirTypeDelegate::DynTrait(_) => {
                 Acc::distribute(Some("throw UnimplementedError();".to_string()))
             }
@@ -143,6 +144,7 @@ impl<'a> WireDartCodecCstGeneratorEncoderTrait for DelegateWireDartCodecCstGener
             | MirTypeDelegate::ProxyEnum(_)
             | MirTypeDelegate::CastedPrimitive(_)
             | MirTypeDelegate::CustomSerDes(_)
+            | MirTypeDelegate::MessageCodec(_)
             | MirTypeDelegate::Lifetimeable(_) =>
                 Acc::distribute(Some("throw UnimplementedError('Not implemented in this codec, please use the other one');".to_string()))
         }
@@ -3,6 +3,7 @@ use crate::codegen::generator::api_dart::spec_generator::base::ApiDartGenerator;
 use crate::codegen::generator::api_dart::spec_generator::function::{
     compute_params_str, ApiDartGeneratedFunction,
 };
+use crate::codegen::generator::misc::minimal_output::MinimalOutput;
 use crate::codegen::generator::wire::dart::spec_generator::base::WireDartGeneratorContext;
 use crate::codegen::generator::wire::dart::spec_generator::codec::base::WireDartCodecEntrypoint;
 use crate::codegen::generator::wire::dart::spec_generator::output_code::WireDartOutputCode;
@@ -12,6 +13,8 @@ use crate::library::codegen::generator::api_dart::spec_generator::info::ApiDartG
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use convert_case::{Case, Casing};
 use itertools::Itertools;
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprLit, Lit, Meta, MetaNameValue, Token};
 
 pub(crate) fn generate_api_impl_normal_function(
     func: &MirFunc,
@@ -31,20 +34,17 @@ pub(crate) fn generate_api_impl_normal_function(
 
     let codec = generate_rust2dart_codec_object(func);
     let call_ffi_args = generate_call_ffi_args(func);
-    let arg_values = generate_arg_values(func);
+    let minimal_output = context.config.minimal_output;
+    let const_meta = minimal_output.select_dart(
+        "const TaskConstMeta(debugName: \"\", argNames: [])",
+        &const_meta_field_name,
+    );
+    let arg_values =
+        minimal_output.select_dart("const []", &format!("[{}]", generate_arg_values(func)));
 
     let task_class = generate_task_class(func);
 
-    let ApiDartGeneratedFunction {
-        func_return_type,
-        func_params,
-        ..
-    } = api_dart_func;
-    let func_params_str = compute_params_str(&func_params, MirFuncArgMode::Named);
-    let func_expr = format!(
-        "{func_return_type} {func_name}({func_params_str})",
-        func_name = func.name_dart_wire(),
-    );
+    let func_expr = compute_func_expr(func, &api_dart_func);
 
     let call_handler = format!(
         "handler.{execute_func_name}({task_class}(
@@ -52,8 +52,8 @@ pub(crate) fn generate_api_impl_normal_function(
               {inner_func_stmt}
             }},
             codec: {codec},
-            constMeta: {const_meta_field_name},
-            argValues: [{arg_values}],
+            constMeta: {const_meta},
+            argValues: {arg_values},
             apiImpl: this,
         ))",
     );
@@ -85,6 +85,10 @@ pub(crate) fn generate_api_impl_normal_function(
     } else {
         format!("return {call_handler};")
     };
+    let function_implementation_body = format!(
+        "{}{function_implementation_body}",
+        generate_feature_guard(func)
+    );
     let function_implementation = format!(
         "@override {func_expr} {maybe_async} {{ {function_implementation_body} }}",
         maybe_async = if func.mode != MirFuncMode::Sync
@@ -97,7 +101,12 @@ pub(crate) fn generate_api_impl_normal_function(
         },
     );
 
-    let companion_field_implementation = generate_companion_field(func, &const_meta_field_name);
+    // Still referenced by the full code of the debug builds in `MinimalOutput::Release`
+    let companion_field_implementation = if minimal_output == MinimalOutput::Always {
+        "".to_owned()
+    } else {
+        generate_companion_field(func, &const_meta_field_name)
+    };
 
     Ok(WireDartOutputCode {
         api_class_body: format!("{func_expr};\n\n"),
@@ -108,6 +117,75 @@ pub(crate) fn generate_api_impl_normal_function(
     })
 }
 
+/// The signature of the function in the API class, e.g. `Future<int> crateApiAdd({required int a})`
+pub(crate) fn generate_api_class_func_signature(
+    func: &MirFunc,
+    context: WireDartGeneratorContext,
+) -> anyhow::Result<String> {
+    let api_dart_func =
+        api_dart::spec_generator::function::generate(func, context.as_api_dart_context())?;
+    Ok(compute_func_expr(func, &api_dart_func))
+}
+
+fn compute_func_expr(func: &MirFunc, api_dart_func: &ApiDartGeneratedFunction) -> String {
+    let func_params_str = compute_params_str(&api_dart_func.func_params, MirFuncArgMode::Named);
+    format!(
+        "{func_return_type} {func_name}({func_params_str})",
+        func_return_type = api_dart_func.func_return_type,
+        func_name = func.name_dart_wire(),
+    )
+}
+
+/// Fails early when the app declares the features of the Rust build via
+/// `--dart-define=FRB_RUST_FEATURES=a,b`, instead of waiting for the error from Rust
+fn generate_feature_guard(func: &MirFunc) -> String {
+    let Some(condition) = (func.rust_cfg.as_ref())
+        .and_then(|cfg| syn::parse_str::<Meta>(cfg).ok())
+        .and_then(|cfg| generate_feature_condition(&cfg))
+    else {
+        return "".to_owned();
+    };
+    format!(
+        "if (const bool.hasEnvironment('FRB_RUST_FEATURES') && !({condition})) {{
+            throw UnsupportedError('`{name}` is not available in the feature set that the Rust crate is built with');
+        }}
+        ",
+        name = func.name.name,
+    )
+}
+
+/// The Dart expression of a `#[cfg(...)]` predicate, if it only involves features
+fn generate_feature_condition(cfg: &Meta) -> Option<String> {
+    match cfg {
+        Meta::NameValue(MetaNameValue {
+            path,
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(feature),
+                    ..
+                }),
+            ..
+        }) if path.is_ident("feature") => Some(format!(
+            "const String.fromEnvironment('FRB_RUST_FEATURES').split(',').contains('{}')",
+            feature.value()
+        )),
+        Meta::List(list) => {
+            let operands = (list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated))
+                .ok()?
+                .iter()
+                .map(generate_feature_condition)
+                .collect::<Option<Vec<_>>>()?;
+            match (list.path.get_ident()?.to_string().as_str(), &operands[..]) {
+                ("not", [operand]) => Some(format!("!({operand})")),
+                ("all", [_, ..]) => Some(format!("({})", operands.join(" && "))),
+                ("any", [_, ..]) => Some(format!("({})", operands.join(" || "))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 fn generate_execute_func_name(func: &MirFunc) -> &str {
     match func.mode {
         MirFuncMode::Normal => "executeNormal",
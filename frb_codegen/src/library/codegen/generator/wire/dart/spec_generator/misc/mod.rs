@@ -2,7 +2,9 @@ use crate::codegen::generator::acc::Acc;
 use crate::codegen::generator::api_dart::spec_generator::misc::generate_imports_which_types_and_funcs_use;
 use crate::codegen::generator::misc::generate_code_header;
 use crate::codegen::generator::misc::target::{TargetOrCommon, TargetOrCommonMap};
-use crate::codegen::generator::wire::dart::internal_config::DartOutputClassNamePack;
+use crate::codegen::generator::wire::dart::internal_config::{
+    DartOutputClassNamePack, GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig,
+};
 use crate::codegen::generator::wire::dart::spec_generator::base::{
     WireDartGenerator, WireDartGeneratorContext,
 };
@@ -12,16 +14,19 @@ use crate::codegen::ir::mir::func::MirFuncMode;
 use crate::codegen::ir::mir::pack::MirPackComputedCache;
 use crate::codegen::misc::GeneratorProgressBarPack;
 use crate::library::codegen::generator::wire::dart::spec_generator::misc::ty::WireDartGeneratorMiscTrait;
+use crate::library::internal::frb_rust_source_code::compute_io_exported_symbols;
 use crate::utils::basic_code::dart_header_code::DartHeaderCode;
 use crate::utils::namespace::Namespace;
 use crate::utils::path_utils::path_to_string;
 use anyhow::Context;
 use itertools::Itertools;
 use pathdiff::diff_paths;
+use rayon::prelude::*;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
-mod api_impl_body;
+pub(crate) mod api_impl_body;
 pub(crate) mod ty;
 
 #[derive(Clone, Serialize)]
@@ -56,7 +61,7 @@ pub(crate) fn generate(
             context,
             rust_content_hash,
         )?,
-        api_impl_normal_functions: (context.mir_pack.funcs_with_impl().iter())
+        api_impl_normal_functions: (context.mir_pack.funcs_with_impl().par_iter())
             .map(|f| api_impl_body::generate_api_impl_normal_function(f, context))
             .collect::<anyhow::Result<Vec<_>>>()?,
         // wire_delegate_functions: (rust_extern_funcs.iter())
@@ -88,7 +93,7 @@ fn generate_boilerplate(
     } = &context.config.dart_output_class_name_pack;
 
     let dart_preamble = &context.api_dart_config.dart_preamble.as_str();
-    let file_top = generate_code_header()
+    let file_top = generate_code_header(context.config.version_in_header)
         + if !dart_preamble.is_empty() {"\n\n"} else {""} + dart_preamble
         + "\n\n// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field\n";
 
@@ -124,6 +129,24 @@ fn generate_boilerplate(
 
     let codegen_version = env!("CARGO_PKG_VERSION");
 
+    let exported_symbol_prefix = &context.config.exported_symbol_prefix;
+    let maybe_exported_symbol_prefix = if exported_symbol_prefix.is_empty() {
+        "".to_owned()
+    } else {
+        format!(
+            "
+                  @override
+                  String get exportedSymbolPrefix => '{exported_symbol_prefix}';
+
+                  @override
+                  Set<String> get exportedSymbols => const {{{exported_symbols}}};
+            ",
+            exported_symbols = (compute_io_exported_symbols().iter())
+                .map(|x| format!("'{x}'"))
+                .join(", "),
+        )
+    };
+
     Ok(Acc {
         common: vec![WireDartOutputCode {
             header: DartHeaderCode {
@@ -151,11 +174,15 @@ fn generate_boilerplate(
                     {api_class_name}? api,
                     BaseHandler? handler,
                     ExternalLibrary? externalLibrary,
+                    ExternalLibraryLoaderConfig? externalLibraryLoaderConfig,
+                    ExternalLibraryLoader? externalLibraryLoader,
                   }}) async {{
                     await instance.initImpl(
                       api: api,
                       handler: handler,
                       externalLibrary: externalLibrary,
+                      externalLibraryLoaderConfig: externalLibraryLoaderConfig,
+                      externalLibraryLoader: externalLibraryLoader,
                     );
                   }}
 
@@ -194,17 +221,20 @@ fn generate_boilerplate(
 
                   @override
                   int get rustContentHash => {rust_content_hash};
+                  {maybe_exported_symbol_prefix}
 
                   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
                     stem: '{stem}',
                     ioDirectory: '{io_directory}',
                     webPrefix: '{web_prefix}',
+                    {io_overrides}
                   );
                 }}
                 "#,
                 stem = context.config.default_external_library_loader.stem,
                 io_directory = context.config.default_external_library_loader.io_directory,
                 web_prefix = context.config.default_external_library_loader.web_prefix,
+                io_overrides = generate_external_library_loader_io_overrides(&context.config.default_external_library_loader),
             ),
             body: "".to_owned(),
             ..Default::default()
@@ -240,11 +270,52 @@ fn generate_boilerplate(
     })
 }
 
+fn generate_external_library_loader_io_overrides(
+    config: &GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig,
+) -> String {
+    let generate_map = |map: &BTreeMap<String, String>| {
+        (map.iter())
+            .map(|(operating_system, value)| {
+                format!(
+                    "{}: {}",
+                    generate_dart_string_literal(operating_system),
+                    generate_dart_string_literal(value)
+                )
+            })
+            .join(", ")
+    };
+
+    let mut ans = String::new();
+    if !config.io_stems.is_empty() {
+        ans += &format!("ioStems: {{{}}},\n", generate_map(&config.io_stems));
+    }
+    if !config.io_paths.is_empty() {
+        ans += &format!("ioPaths: {{{}}},\n", generate_map(&config.io_paths));
+    }
+    if !config.io_process.is_empty() {
+        let io_process = (config.io_process.iter())
+            .map(|x| generate_dart_string_literal(x))
+            .join(", ");
+        ans += &format!("ioProcess: [{io_process}],\n");
+    }
+    ans
+}
+
+/// The values are given by users, e.g. Windows paths with backslashes, or paths with quotes
+fn generate_dart_string_literal(value: &str) -> String {
+    let escaped = (value.replace('\\', "\\\\"))
+        .replace('\'', "\\'")
+        .replace('$', "\\$")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r");
+    format!("'{escaped}'")
+}
+
 fn file_stem(p: &Path) -> String {
     p.file_stem().unwrap().to_str().unwrap().into()
 }
 
-fn generate_import_dart_api_layer(
+pub(crate) fn generate_import_dart_api_layer(
     dart_impl_output_path: &TargetOrCommonMap<PathBuf>,
     api_dart_actual_output_paths: &[PathBuf],
 ) -> anyhow::Result<String> {
@@ -281,3 +352,30 @@ fn generate_import_dart_api_layer(
 //
 //     Acc::new_target(code, func.target.into())
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_external_library_loader_io_overrides() {
+        let config = GeneratorWireDartDefaultExternalLibraryLoaderInternalConfig {
+            stem: "my_lib".to_owned(),
+            io_directory: "rust/target/release/".to_owned(),
+            web_prefix: "pkg/".to_owned(),
+            io_stems: [("linux".to_owned(), "my_lib_linux".to_owned())].into(),
+            io_paths: [(
+                "windows".to_owned(),
+                r"C:\Program Files\it's $HOME\my_lib.dll".to_owned(),
+            )]
+            .into(),
+            io_process: vec!["ios".to_owned()],
+        };
+        assert_eq!(
+            generate_external_library_loader_io_overrides(&config),
+            "ioStems: {'linux': 'my_lib_linux'},\n\
+             ioPaths: {'windows': 'C:\\\\Program Files\\\\it\\'s \\$HOME\\\\my_lib.dll'},\n\
+             ioProcess: ['ios'],\n"
+        );
+    }
+}
@@ -12,7 +12,7 @@ mod primitive;
 mod primitive_list;
 mod record;
 mod rust_auto_opaque_implicit;
-mod rust_opaque;
+pub(crate) mod rust_opaque;
 mod structure;
 mod trait_def;
 
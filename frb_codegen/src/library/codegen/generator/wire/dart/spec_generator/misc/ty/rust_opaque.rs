@@ -31,19 +31,28 @@ pub(super) fn generate_rust_arc_functions(
     .map(|x, _| x.into_iter().fold(Default::default(), |a, b| a + b))
 }
 
+/// The getters of the API class which the opaque objects of the type use to manage their `Arc`s
+pub(crate) fn generate_rust_arc_getter_signatures(
+    mir: &MirType,
+    context: WireDartGeneratorContext,
+) -> Vec<String> {
+    vec![
+        rust_arc_modify_strong_count_getter_signature("increment", mir, context),
+        rust_arc_modify_strong_count_getter_signature("decrement", mir, context),
+        format!(
+            "CrossPlatformFinalizerArg get {}",
+            rust_arc_function_pointer_getter_name(mir, context)
+        ),
+    ]
+}
+
 fn generate_rust_arc_modify_strong_count(
     op_name: &str,
     mir: &MirType,
     context: WireDartGeneratorContext,
 ) -> Acc<WireDartOutputCode> {
-    let ty_dart_api_type =
-        ApiDartGenerator::new(mir.clone(), context.as_api_dart_context()).dart_api_type();
-    let op_name_pascal = op_name.to_case(Case::Pascal);
     let safe_ident = mir.safe_ident();
-
-    let definition = format!(
-            "RustArc{op_name_pascal}StrongCountFnType get rust_arc_{op_name}_strong_count_{ty_dart_api_type}"
-        );
+    let definition = rust_arc_modify_strong_count_getter_signature(op_name, mir, context);
 
     Acc {
         common: WireDartOutputCode {
@@ -57,14 +66,25 @@ fn generate_rust_arc_modify_strong_count(
     }
 }
 
-fn generate_rust_arc_function_pointer(
+fn rust_arc_modify_strong_count_getter_signature(
+    op_name: &str,
     mir: &MirType,
     context: WireDartGeneratorContext,
-) -> Acc<WireDartOutputCode> {
+) -> String {
     let ty_dart_api_type =
         ApiDartGenerator::new(mir.clone(), context.as_api_dart_context()).dart_api_type();
+    let op_name_pascal = op_name.to_case(Case::Pascal);
+    format!(
+        "RustArc{op_name_pascal}StrongCountFnType get rust_arc_{op_name}_strong_count_{ty_dart_api_type}"
+    )
+}
+
+fn generate_rust_arc_function_pointer(
+    mir: &MirType,
+    context: WireDartGeneratorContext,
+) -> Acc<WireDartOutputCode> {
     let ty_safe_ident = mir.safe_ident();
-    let getter_name = format!("rust_arc_decrement_strong_count_{ty_dart_api_type}Ptr");
+    let getter_name = rust_arc_function_pointer_getter_name(mir, context);
 
     let generate_platform_impl = |ptr_name: &str| WireDartOutputCode {
         api_impl_class_body: format!(
@@ -86,3 +106,12 @@ fn generate_rust_arc_function_pointer(
         )),
     }
 }
+
+fn rust_arc_function_pointer_getter_name(
+    mir: &MirType,
+    context: WireDartGeneratorContext,
+) -> String {
+    let ty_dart_api_type =
+        ApiDartGenerator::new(mir.clone(), context.as_api_dart_context()).dart_api_type();
+    format!("rust_arc_decrement_strong_count_{ty_dart_api_type}Ptr")
+}
@@ -5,6 +5,7 @@ use crate::codegen::generator::wire::dart::spec_generator::output_code::WireDart
 use crate::codegen::generator::wire::dart::spec_generator::wire_class::io::common::generate_wire_class_header;
 use crate::codegen::misc::GeneratorProgressBarPack;
 use crate::library::commands::ffigen::{ffigen, FfigenArgs};
+use crate::utils::file_cache::FileCache;
 use anyhow::ensure;
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -24,6 +25,26 @@ fn execute_ffigen(
     config: &GeneratorWireDartInternalConfig,
     c_file_content: &str,
     progress_bar_pack: &GeneratorProgressBarPack,
+) -> anyhow::Result<String> {
+    let cache_inputs = [
+        c_file_content,
+        &config.dart_output_class_name_pack.wire_class_name,
+        &format!("{:?}", config.llvm_path),
+        &config.llvm_compiler_opts,
+        &config.c_symbol_prefix,
+        &format!("{:?}", config.ffigen_config),
+    ];
+    FileCache::new(config.cache_directory.as_deref()).get_or_compute(
+        "ffigen",
+        &cache_inputs,
+        || execute_ffigen_raw(config, c_file_content, progress_bar_pack),
+    )
+}
+
+fn execute_ffigen_raw(
+    config: &GeneratorWireDartInternalConfig,
+    c_file_content: &str,
+    progress_bar_pack: &GeneratorProgressBarPack,
 ) -> anyhow::Result<String> {
     let _pb = progress_bar_pack.generate_ffigen.start();
     ffigen(FfigenArgs {
@@ -35,6 +56,7 @@ fn execute_ffigen(
         function_rename: Some(
             &[(format!("{}(.*)", config.c_symbol_prefix), "$1".to_owned())].into(),
         ),
+        extra_config: config.ffigen_config.as_ref(),
     })
 }
 
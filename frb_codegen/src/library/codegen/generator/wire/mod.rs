@@ -41,7 +41,9 @@ pub(crate) fn generate(
         api_dart_config,
     };
 
+    let pb = progress_bar_pack.generate_wire_rust.start();
     let rust_output = rust::generate(wire_rust_generator_context, dumper)?;
+    drop(pb);
 
     let c_output = c::generate(
         &config.c,
@@ -55,6 +57,7 @@ pub(crate) fn generate(
         progress_bar_pack,
     )?;
 
+    let pb = progress_bar_pack.generate_wire_dart.start();
     let dart_output = dart::generate(
         wire_dart_generator_context,
         &c_output.c_file_content,
@@ -64,6 +67,7 @@ pub(crate) fn generate(
         dumper,
         progress_bar_pack,
     )?;
+    drop(pb);
 
     Ok(GeneratorWireOutput {
         output_texts: rust_output.output_texts + c_output.output_texts + dart_output.output_texts,
@@ -1,4 +1,5 @@
 use crate::codegen::generator::codec::structs::CodecMode;
+use crate::codegen::generator::misc::minimal_output::MinimalOutput;
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -9,8 +10,11 @@ pub(crate) struct GeneratorWireRustInternalConfig {
     pub web_enabled: bool,
     pub rust_output_path: PathBuf,
     pub c_symbol_prefix: String,
+    pub(crate) exported_symbol_prefix: String,
     pub has_ffigen: bool,
     pub default_stream_sink_codec: CodecMode,
     pub default_rust_opaque_codec: RustOpaqueCodecMode,
     pub rust_preamble: String,
+    pub(crate) minimal_output: MinimalOutput,
+    pub(crate) version_in_header: bool,
 }
@@ -80,7 +80,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
             //         "let multiple: Vec<u8> = self.cst_decode(); flutter_rust_bridge::for_generated::decode_uuids(multiple)".into(),
             //     ),
             // ),
-            MirTypeDelegate::Backtrace | MirTypeDelegate::AnyhowException | MirTypeDelegate::DynTrait(_) => Acc::new(|target| match target {
+            MirTypeDelegate::Backtrace | MirTypeDelegate::AnyhowException | MirTypeDelegate::Progress(_) | MirTypeDelegate::DynTrait(_) => Acc::new(|target| match target {
                 TargetOrCommon::Common => None,
                 TargetOrCommon::Io | TargetOrCommon::Web => Some("unimplemented!()".into()),
             }),
@@ -110,6 +110,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                 Acc::distribute(Some(r#"unimplemented!("Not implemented in this codec, please use the other one")"#.to_string())),
             MirTypeDelegate::CastedPrimitive(_)
             | MirTypeDelegate::CustomSerDes(_)
+            | MirTypeDelegate::MessageCodec(_)
             | MirTypeDelegate::Lifetimeable(_) => Acc::distribute(None),
             // frb-coverage:ignore-end
         }
@@ -141,7 +142,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                 "self.unchecked_into::<flutter_rust_bridge::for_generated::js_sys::Uint8Array>().to_vec().into_boxed_slice().cst_decode()"
                     .into()
             }
-            MirTypeDelegate::Backtrace | MirTypeDelegate::AnyhowException | MirTypeDelegate::DynTrait(_) => "unimplemented!()".into(),
+            MirTypeDelegate::Backtrace | MirTypeDelegate::AnyhowException | MirTypeDelegate::Progress(_) | MirTypeDelegate::DynTrait(_) => "unimplemented!()".into(),
             MirTypeDelegate::Array(array) => generate_decode_array(array)
                 .into(),
             MirTypeDelegate::Map(mir) => generate_decode_map(mir).into(),
@@ -156,6 +157,7 @@ impl<'a> WireRustCodecCstGeneratorDecoderTrait for DelegateWireRustCodecCstGener
                 r#"unimplemented!("Not implemented in this codec, please use the other one")"#.into(),
             MirTypeDelegate::CastedPrimitive(_)
             | MirTypeDelegate::CustomSerDes(_)
+            | MirTypeDelegate::MessageCodec(_)
             | MirTypeDelegate::Lifetimeable(_) => return None,
             // frb-coverage:ignore-end
         })
@@ -3,6 +3,7 @@ use crate::codegen::generator::wire::rust::spec_generator::codec::base::WireRust
 use crate::codegen::generator::wire::rust::spec_generator::codec::dco::base::{
     WireRustCodecDcoGenerator, WireRustCodecDcoGeneratorContext,
 };
+use crate::codegen::generator::wire::rust::spec_generator::misc::generate_code_maybe_cfg;
 use crate::codegen::generator::wire::rust::spec_generator::output_code::WireRustOutputCode;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::library::codegen::generator::wire::rust::spec_generator::codec::dco::encoder::ty::WireRustCodecDcoGeneratorEncoderTrait;
@@ -18,7 +19,8 @@ pub(crate) fn generate(
     // inner += generate_misc();
     inner += (types.iter())
         .filter_map(|ty| {
-            WireRustCodecDcoGenerator::new(ty.clone(), context).generate_impl_into_dart()
+            (WireRustCodecDcoGenerator::new(ty.clone(), context).generate_impl_into_dart())
+                .map(|code| generate_code_maybe_cfg(ty, context.mir_pack, code))
         })
         .map(|x| Acc::<WireRustOutputCode>::new_common(x.into()))
         .collect();
@@ -7,6 +7,7 @@ use crate::codegen::generator::codec::structs::{CodecMode, EncodeOrDecode};
 use crate::codegen::generator::misc::comments::generate_codec_comments;
 use crate::codegen::generator::wire::rust::spec_generator::codec::base::WireRustCodecOutputSpec;
 use crate::codegen::generator::wire::rust::spec_generator::codec::sse::base::WireRustCodecSseGeneratorContext;
+use crate::codegen::generator::wire::rust::spec_generator::misc::generate_code_maybe_cfg;
 use crate::codegen::generator::wire::rust::spec_generator::output_code::WireRustOutputCode;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::library::codegen::generator::codec::sse::ty::CodecSseTyTrait;
@@ -60,7 +61,7 @@ fn generate_encode_or_decode_for_type(
             ),
         };
 
-        Acc::new_common(code.into())
+        Acc::new_common(generate_code_maybe_cfg(ty, context.mir_pack, code).into())
     } else {
         Acc::default()
     }
@@ -30,7 +30,7 @@ pub(crate) fn generate_wire_func(
 
     let params = dart2rust_codec.generate_func_params(func, context);
     let inner_func_args = generate_inner_func_args(func);
-    let wrap_info_obj = generate_wrap_info_obj(func);
+    let wrap_info_obj = generate_wrap_info_obj(func, context);
     let code_decode = dart2rust_codec.generate_func_call_decode(func, context);
     let code_inner_decode = generate_code_inner_decode(func);
     let code_call_inner_func_result = generate_code_call_inner_func_result(func, inner_func_args);
@@ -56,22 +56,38 @@ pub(crate) fn generate_wire_func(
             needs_ffigen: true,
         }
         .into(),
-        TargetOrCommon::Common => format!(
-            "fn {func_name}_impl({params}) {return_type} {{
-                {HANDLER_NAME}.{handler_func_name}({wrap_info_obj}, move || {{ {code_closure} }})
-            }}",
-            HANDLER_NAME = HANDLER_NAME,
-            params = params
-                .common
-                .iter()
-                .map(|param| param.rust_name_and_type())
-                .join(","),
-            return_type = return_type
-                .clone()
-                .map(|t| format!("-> {t}"))
-                .unwrap_or_default(),
-        )
-        .into(),
+        TargetOrCommon::Common => {
+            let generate_impl = |code_closure: &str| {
+                format!(
+                    "fn {func_name}_impl({params}) {return_type} {{
+                        {HANDLER_NAME}.{handler_func_name}({wrap_info_obj}, move || {{ {code_closure} }})
+                    }}",
+                    HANDLER_NAME = HANDLER_NAME,
+                    params = params
+                        .common
+                        .iter()
+                        .map(|param| param.rust_name_and_type())
+                        .join(","),
+                    return_type = return_type
+                        .clone()
+                        .map(|t| format!("-> {t}"))
+                        .unwrap_or_default(),
+                )
+            };
+
+            match &func.rust_cfg {
+                // The Dart API is the same for all feature sets, thus a missing function
+                // reports an error to Dart instead of failing to compile
+                Some(cfg) => format!(
+                    "#[cfg({cfg})] {}
+                    #[cfg(not({cfg}))] {}",
+                    generate_impl(&code_closure),
+                    generate_impl(&generate_code_closure_unavailable(func, &code_decode)),
+                ),
+                None => generate_impl(&code_closure),
+            }
+            .into()
+        }
     })
 }
 
@@ -90,10 +106,11 @@ fn generate_inner_func_args(func: &MirFunc) -> Vec<String> {
         .collect_vec()
 }
 
-fn generate_wrap_info_obj(func: &MirFunc) -> String {
+fn generate_wrap_info_obj(func: &MirFunc, context: WireRustGeneratorContext) -> String {
     format!(
-        "flutter_rust_bridge::for_generated::TaskInfo{{ debug_name: \"{name}\", port: {port}, mode: flutter_rust_bridge::for_generated::FfiCallMode::{mode} }}",
-        name = func.name.name,
+        "flutter_rust_bridge::for_generated::TaskInfo{{ debug_name: {name}, port: {port}, mode: flutter_rust_bridge::for_generated::FfiCallMode::{mode} }}",
+        name = (context.config.minimal_output)
+            .select_rust("\"\"", &format!("\"{}\"", func.name.name)),
         port = if has_port_argument(func.mode) {
             "Some(port_)"
         } else {
@@ -111,7 +128,13 @@ fn generate_code_call_inner_func_result(func: &MirFunc, inner_func_args: Vec<Str
     let mut ans = (func.rust_call_code.clone()).unwrap_or_else(|| {
         match &func.owner {
             MirFuncOwnerInfo::Function => {
-                format!("{}({})", func.name.rust_style(), inner_func_args.join(", "))
+                let name = match &func.rust_call_name {
+                    Some(rust_call_name) => {
+                        format!("{}::{rust_call_name}", func.name.namespace.joined_path)
+                    }
+                    None => func.name.rust_style(),
+                };
+                format!("{name}({})", inner_func_args.join(", "))
             }
             MirFuncOwnerInfo::Method(method) => {
                 let owner_ty_name = method.owner_ty_name().unwrap().rust_style();
@@ -144,9 +167,7 @@ fn generate_code_call_inner_func_result(func: &MirFunc, inner_func_args: Vec<Str
         ans = format!("Result::<_,()>::Ok({ans})");
     }
 
-    ans = format!("let output_ok = {ans}?;");
-
-    ans
+    format!("let output_ok = {ans}?;")
 }
 
 fn generate_code_postprocess_inner_output(func: &MirFunc) -> String {
@@ -224,27 +245,72 @@ fn generate_code_closure(
 
     match func.mode {
         MirFuncMode::Sync => {
-            format!(
-                "{code_decode}
-                {transform_result_func}((move || {{
-                    {code_inner}
-                }})())"
-            )
+            let code_result = generate_code_maybe_compress(
+                func,
+                format!(
+                    "{transform_result_func}((move || {{
+                        {code_inner}
+                    }})())"
+                ),
+            );
+            format!("{code_decode} {code_result}")
         }
         MirFuncMode::Normal => {
             let maybe_async_move = if func.rust_async { "async move" } else { "" };
             let maybe_await = if func.rust_async { ".await" } else { "" };
-            format!(
-                "{code_decode} move |context| {maybe_async_move} {{
-                    {transform_result_func}((move || {maybe_async_move} {{
+            let code_result = generate_code_maybe_compress(
+                func,
+                format!(
+                    "{transform_result_func}((move || {maybe_async_move} {{
                         {code_inner}
-                    }})(){maybe_await})
-                }}"
-            )
+                    }})(){maybe_await})"
+                ),
+            );
+            format!("{code_decode} move |context| {maybe_async_move} {{ {code_result} }}")
         }
     }
 }
 
+/// Used when the function is cfg'd out of the feature set that the Rust crate is built with.
+/// The inputs are released, and the error is reported to Dart in the same way as a panic.
+fn generate_code_closure_unavailable(func: &MirFunc, code_decode: &str) -> String {
+    let codec_mode = func.codec_mode_pack.rust2dart.delegate_or_self();
+
+    // The input types may be cfg'd out as well, thus only release the raw message
+    let code_release = if func.codec_mode_pack.dart2rust.delegate_or_self() == CodecMode::Sse {
+        "let _ = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };".to_owned()
+    } else {
+        code_decode.to_owned()
+    };
+
+    let code_result = generate_code_maybe_compress(
+        func,
+        format!(
+            "Err(<flutter_rust_bridge::for_generated::{codec_mode}Codec as flutter_rust_bridge::for_generated::BaseCodec>::encode_panic(
+                &(Box::new(\"`{name}` is not available in the feature set that the Rust crate is built with\".to_owned()) as Box<dyn std::any::Any + Send>),
+                &None,
+            ))",
+            name = func.name.name,
+        ),
+    );
+
+    match func.mode {
+        MirFuncMode::Sync => format!("{code_release} {code_result}"),
+        MirFuncMode::Normal => {
+            let maybe_async_move = if func.rust_async { "async move" } else { "" };
+            format!("{code_release} move |_| {maybe_async_move} {{ {code_result} }}")
+        }
+    }
+}
+
+fn generate_code_maybe_compress(func: &MirFunc, code_result: String) -> String {
+    if func.compress {
+        format!("flutter_rust_bridge::for_generated::compress_result_sse({code_result})")
+    } else {
+        code_result
+    }
+}
+
 fn generate_redirect_body(func: &MirFunc, params: &[ExternFuncParam]) -> String {
     format!(
         "{}_impl({})",
@@ -1,6 +1,7 @@
 use crate::codegen::generator::acc::Acc;
 use crate::codegen::generator::codec::structs::CodecMode;
 use crate::codegen::generator::misc::generate_code_header;
+use crate::codegen::generator::misc::minimal_output::MinimalOutput;
 use crate::codegen::generator::misc::target::TargetOrCommon;
 use crate::codegen::generator::wire::rust::spec_generator::base::{
     WireRustGenerator, WireRustGeneratorContext,
@@ -16,9 +17,9 @@ use crate::if_then_some;
 use crate::library::codegen::generator::wire::rust::spec_generator::misc::ty::WireRustGeneratorMiscTrait;
 use crate::utils::namespace::Namespace;
 use itertools::Itertools;
+use rayon::prelude::*;
 use serde::Serialize;
 use sha1::{Digest, Sha1};
-use std::collections::HashSet;
 
 pub(crate) mod function;
 pub(crate) mod ty;
@@ -44,17 +45,20 @@ pub(crate) fn generate(
 ) -> anyhow::Result<WireRustOutputSpecMisc> {
     let content_hash = generate_content_hash(context.mir_pack);
     Ok(WireRustOutputSpecMisc {
-        code_header: Acc::new(|_| vec![(generate_code_header() + "\n\n").into()]),
+        code_header: Acc::new(|_| {
+            vec![(generate_code_header(context.config.version_in_header) + "\n\n").into()]
+        }),
         file_attributes: Acc::new_common(vec![FILE_ATTRIBUTES.to_string().into()]),
         imports: generate_imports(&cache.distinct_types, context),
-        executor: Acc::new_common(vec![generate_handler(context.mir_pack).into()]),
+        executor: Acc::new_common(vec![generate_handler(context).into()]),
         boilerplate: generate_boilerplate(
             context.config.default_stream_sink_codec,
             context.config.default_rust_opaque_codec,
             content_hash,
             &context.config.rust_preamble,
+            &context.config.exported_symbol_prefix,
         ),
-        wire_funcs: (context.mir_pack.funcs_with_impl().iter())
+        wire_funcs: (context.mir_pack.funcs_with_impl().par_iter())
             .map(|f| generate_wire_func(f, context))
             .collect(),
         wrapper_structs: Acc::default(),
@@ -63,9 +67,7 @@ pub(crate) fn generate(
             context,
         )
         .into()]),
-        related_funcs: cache
-            .distinct_types
-            .iter()
+        related_funcs: (cache.distinct_types.par_iter())
             .map(|ty| WireRustGenerator::new(ty.clone(), context).generate_related_funcs())
             .collect(),
         extra_from_parser: Acc::new_common(vec![WireRustOutputCode {
@@ -114,8 +116,14 @@ fn generate_imports(
         .flatten()
         .filter(|namespace| namespace != &output_namespace)
         .map(|namespace| format!("use {}::*;", namespace.joined_path))
-        .collect::<HashSet<String>>()
-        .into_iter()
+        .chain(
+            (types.iter())
+                .flat_map(|ty| WireRustGenerator::new(ty.clone(), context).generate_item_imports())
+                .flatten()
+                .map(|name| format!("use {};", name.rust_style())),
+        )
+        .unique()
+        .sorted()
         .join("\n");
 
     let imports_from_functions = (context.mir_pack.funcs_with_impl().iter())
@@ -169,11 +177,21 @@ fn generate_static_checks(types: &[MirType], context: WireRustGeneratorContext)
     lines.join("\n")
 }
 
+/// Gates the code of a type by the `rust_feature_sets` it exists in.
+/// The anonymous const allows a single attribute to cover several items, e.g. multiple impls.
+pub(crate) fn generate_code_maybe_cfg(ty: &MirType, mir_pack: &MirPack, code: String) -> String {
+    match ty.rust_cfg(mir_pack) {
+        Some(cfg) => format!("#[cfg({cfg})] const _: () = {{ {code} }};"),
+        None => code,
+    }
+}
+
 fn generate_boilerplate(
     default_stream_sink_codec: CodecMode,
     default_rust_opaque_codec: RustOpaqueCodecMode,
     content_hash: i32,
     rust_preamble: &str,
+    exported_symbol_prefix: &str,
 ) -> Acc<Vec<WireRustOutputCode>> {
     let rust_preamble_formatted = if rust_preamble.is_empty() {
         "".to_owned()
@@ -184,11 +202,16 @@ fn generate_boilerplate(
     Acc::new(|target| {
         match target {
             TargetOrCommon::Io | TargetOrCommon::Web => {
+                let args = if target == TargetOrCommon::Io && !exported_symbol_prefix.is_empty() {
+                    format!("symbol_prefix = \"{exported_symbol_prefix}\"")
+                } else {
+                    "".to_owned()
+                };
                 vec![
                     // generate_boilerplate_frb_initialize_rust(target).into(),
                     // generate_boilerplate_dart_fn_deliver_output(target).into(),
                     format!(
-                        "{rust_preamble_formatted}flutter_rust_bridge::frb_generated_boilerplate_{}!();",
+                        "{rust_preamble_formatted}flutter_rust_bridge::frb_generated_boilerplate_{}!({args});",
                         target.to_string().to_lowercase()
                     )
                     .into(),
@@ -247,11 +270,16 @@ fn generate_boilerplate(
 //     }
 // }
 
-fn generate_handler(mir_pack: &MirPack) -> String {
-    if let Some(existing_handler) = &mir_pack.existing_handler {
-        format!("pub use {};", existing_handler.rust_style())
-    } else {
-        r#"flutter_rust_bridge::frb_generated_default_handler!();"#.to_owned()
+fn generate_handler(context: WireRustGeneratorContext) -> String {
+    if let Some(existing_handler) = &context.mir_pack.existing_handler {
+        return format!("pub use {};", existing_handler.rust_style());
+    }
+    match context.config.minimal_output {
+        MinimalOutput::Never => "flutter_rust_bridge::frb_generated_default_handler!();".to_owned(),
+        minimal_output => format!(
+            "flutter_rust_bridge::frb_generated_default_handler!(minimal_output: {});",
+            minimal_output.select_rust("true", "false"),
+        ),
     }
 }
 
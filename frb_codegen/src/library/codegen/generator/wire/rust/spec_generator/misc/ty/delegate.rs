@@ -2,7 +2,7 @@ use crate::codegen::generator::wire::rust::spec_generator::base::*;
 use crate::codegen::generator::wire::rust::spec_generator::misc::ty::WireRustGeneratorMiscTrait;
 use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
-use crate::utils::namespace::Namespace;
+use crate::utils::namespace::{Namespace, NamespacedName};
 use itertools::Itertools;
 
 impl<'a> WireRustGeneratorMiscTrait for DelegateWireRustGenerator<'a> {
@@ -11,7 +11,20 @@ impl<'a> WireRustGeneratorMiscTrait for DelegateWireRustGenerator<'a> {
             Some(
                 [&mir.info.rust2dart, &mir.info.dart2rust]
                     .into_iter()
-                    .map(|x| x.rust_function.namespace.clone())
+                    .filter_map(|x| x.rust_import())
+                    .collect_vec(),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn generate_item_imports(&self) -> Option<Vec<NamespacedName>> {
+        if let MirTypeDelegate::CustomSerDes(mir) = &self.mir {
+            Some(
+                [&mir.info.rust2dart, &mir.info.dart2rust]
+                    .into_iter()
+                    .filter_map(|x| x.rust_item_import())
                     .collect_vec(),
             )
         } else {
@@ -18,7 +18,7 @@ mod trait_def;
 
 use crate::codegen::generator::acc::Acc;
 use crate::codegen::generator::wire::rust::spec_generator::output_code::WireRustOutputCode;
-use crate::utils::namespace::Namespace;
+use crate::utils::namespace::{Namespace, NamespacedName};
 use enum_dispatch::enum_dispatch;
 
 #[enum_dispatch]
@@ -35,6 +35,11 @@ pub(crate) trait WireRustGeneratorMiscTrait {
         None
     }
 
+    /// Unlike `generate_imports`, only the given items are imported, instead of the whole modules
+    fn generate_item_imports(&self) -> Option<Vec<NamespacedName>> {
+        None
+    }
+
     fn generate_related_funcs(&self) -> Acc<WireRustOutputCode> {
         Default::default()
     }
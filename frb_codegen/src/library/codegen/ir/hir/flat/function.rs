@@ -16,6 +16,10 @@ pub(crate) struct HirFlatFunction {
     pub(crate) sources: Vec<HirGenerationSource>,
     #[serde(serialize_with = "serialize_generalized_item_fn")]
     pub(crate) item_fn: GeneralizedItemFn,
+    /// The function to call in Rust when it differs from `item_fn`,
+    /// e.g. `fetch::<MyDto>` for an instantiation of a generic function
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) rust_call_name: Option<String>,
 }
 
 impl HirFlatComponent<SimpleOwnerAndName> for HirFlatFunction {
@@ -0,0 +1,18 @@
+use crate::codegen::ir::hir::flat::component::HirFlatComponent;
+use crate::utils::namespace::Namespace;
+use serde::Serialize;
+
+/// A name brought into a module by `use`, e.g. `Decimal` for `use rust_decimal::Decimal;`
+#[derive(Clone, Debug, Serialize)]
+pub struct HirFlatImport {
+    pub(crate) namespace: Namespace,
+    pub(crate) ident: String,
+    /// e.g. `rust_decimal::Decimal`, or `crate::api::Person` for `use super::Person;` in `crate::api::sub`
+    pub(crate) path: String,
+}
+
+impl HirFlatComponent<(Namespace, String)> for HirFlatImport {
+    fn sort_key(&self) -> (Namespace, String) {
+        (self.namespace.clone(), self.ident.clone())
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod component;
 pub(crate) mod function;
+pub(crate) mod import;
 pub(crate) mod pack;
 pub(crate) mod struct_or_enum;
 pub(crate) mod trait_impl;
@@ -1,5 +1,6 @@
 use crate::codegen::ir::hir::flat::component::HirFlatComponent;
 use crate::codegen::ir::hir::flat::function::HirFlatFunction;
+use crate::codegen::ir::hir::flat::import::HirFlatImport;
 use crate::codegen::ir::hir::flat::struct_or_enum::{HirFlatEnum, HirFlatStruct};
 use crate::codegen::ir::hir::flat::trait_impl::HirFlatTraitImpl;
 use crate::codegen::ir::hir::flat::traits::HirFlatTrait;
@@ -16,6 +17,8 @@ pub(crate) struct HirFlatPack {
     pub traits: Vec<HirFlatTrait>,
     pub trait_impls: Vec<HirFlatTraitImpl>,
     pub types: Vec<HirFlatTypeAlias>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<HirFlatImport>,
     pub existing_handler: Option<NamespacedName>,
     pub extra_rust_output_code: String,
     pub extra_dart_output_code: GeneralDartCode,
@@ -30,6 +33,7 @@ impl HirFlatPack {
         visitor.visit(&mut self.traits);
         visitor.visit(&mut self.trait_impls);
         visitor.visit(&mut self.types);
+        visitor.visit(&mut self.imports);
     }
 }
 
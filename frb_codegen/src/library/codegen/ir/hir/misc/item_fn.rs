@@ -19,6 +19,14 @@ impl GeneralizedItemFn {
         }
     }
 
+    pub(crate) fn sig_mut(&mut self) -> &mut Signature {
+        match self {
+            Self::ItemFn(inner) => &mut inner.sig,
+            Self::ImplItemFn(inner) => &mut inner.sig,
+            Self::TraitItemFn(inner) => &mut inner.sig,
+        }
+    }
+
     pub(crate) fn name(&self) -> String {
         self.sig().ident.to_string()
     }
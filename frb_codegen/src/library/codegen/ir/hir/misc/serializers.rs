@@ -3,6 +3,7 @@ use crate::utils::syn_utils::ty_to_string;
 use itertools::Itertools;
 use quote::ToTokens;
 use serde::{Serialize, Serializer};
+use syn::ReturnType;
 
 pub(crate) fn serialize_syn<T: ToTokens, S: Serializer>(
     value: &T,
@@ -41,10 +42,34 @@ pub(crate) fn serialize_generalized_item_fn<S: Serializer>(
     x: &GeneralizedItemFn,
     s: S,
 ) -> Result<S::Ok, S::Error> {
-    s.serialize_str(&format!(
-        "GeneralizedItemFn(name={}, vis={:?}, attrs=[{}])",
-        x.name(),
-        x.vis_raw(),
-        x.attrs().iter().map(ty_to_string).join(", "),
-    ))
+    let sig = x.sig();
+    SerializedItemFn {
+        name: x.name(),
+        vis: x.vis_raw().map(ty_to_string),
+        attrs: x.attrs().iter().map(ty_to_string).collect(),
+        is_async: sig.asyncness.is_some(),
+        generics: (!sig.generics.params.is_empty()).then(|| ty_to_string(&sig.generics)),
+        where_clause: sig.generics.where_clause.as_ref().map(ty_to_string),
+        inputs: sig.inputs.iter().map(ty_to_string).collect(),
+        output: match &sig.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => Some(ty_to_string(ty)),
+        },
+    }
+    .serialize(s)
+}
+
+/// The signature is kept in a structured form, so that e.g. API diffing tools can compare the inputs
+#[derive(Serialize)]
+struct SerializedItemFn {
+    name: String,
+    vis: Option<String>,
+    attrs: Vec<String>,
+    is_async: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generics: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    where_clause: Option<String>,
+    inputs: Vec<String>,
+    output: Option<String>,
 }
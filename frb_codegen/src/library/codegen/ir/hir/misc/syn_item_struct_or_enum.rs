@@ -6,6 +6,10 @@ pub(crate) trait SynItemStructOrEnum: Clone {
     fn attrs_mut(&mut self) -> &mut Vec<Attribute>;
 
     fn generics(&self) -> &Generics;
+
+    fn ident_mut(&mut self) -> &mut Ident;
+
+    fn generics_mut(&mut self) -> &mut Generics;
 }
 
 macro_rules! impl_trait {
@@ -22,6 +26,14 @@ macro_rules! impl_trait {
             fn generics(&self) -> &syn::Generics {
                 &self.generics
             }
+
+            fn ident_mut(&mut self) -> &mut syn::Ident {
+                &mut self.ident
+            }
+
+            fn generics_mut(&mut self) -> &mut syn::Generics {
+                &mut self.generics
+            }
         }
     };
 }
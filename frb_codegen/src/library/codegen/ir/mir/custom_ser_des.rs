@@ -1,19 +1,33 @@
 use crate::codegen::ir::mir::ty::MirType;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
-use crate::utils::namespace::NamespacedName;
+use crate::utils::namespace::{Namespace, NamespacedName};
 
 crate::mir! {
 pub struct MirCustomSerDes {
     pub inner_type: Box<MirType>,
     pub rust_api_type: Box<MirType>,
     pub dart_api_type: String,
+    pub dart_import: Option<String>,
     pub dart2rust: MirCustomSerDesHalf,
     pub rust2dart: MirCustomSerDesHalf,
 }
 
 pub struct MirCustomSerDesHalf {
     pub dart_code: String,
-    pub rust_function: NamespacedName,
+    pub rust_code: MirCustomSerDesRustCode,
+}
+
+pub enum MirCustomSerDesRustCode {
+    /// A function annotated with `#[frb(rust2dart(..))]` or `#[frb(dart2rust(..))]`
+    Function(NamespacedName),
+    /// Code from the `type_mappings` config, where `{}` is replaced by the value
+    Template {
+        code: String,
+        /// The mapped Rust type as configured, e.g. `rust_decimal::Decimal`
+        rust_type: String,
+        /// The mapped Rust type, which is imported for the code to refer to it by name
+        import: Option<NamespacedName>,
+    },
 }
 }
 
@@ -26,3 +40,28 @@ impl MirCustomSerDes {
         }
     }
 }
+
+impl MirCustomSerDesHalf {
+    pub(crate) fn rust_call(&self, value: &str) -> String {
+        match &self.rust_code {
+            MirCustomSerDesRustCode::Function(name) => format!("{}({value})", name.rust_style()),
+            MirCustomSerDesRustCode::Template { code, .. } => code.replace("{}", value),
+        }
+    }
+
+    /// The module to import, i.e. the one of the function
+    pub(crate) fn rust_import(&self) -> Option<Namespace> {
+        match &self.rust_code {
+            MirCustomSerDesRustCode::Function(name) => Some(name.namespace.clone()),
+            MirCustomSerDesRustCode::Template { .. } => None,
+        }
+    }
+
+    /// The single item to import, i.e. the mapped type
+    pub(crate) fn rust_item_import(&self) -> Option<NamespacedName> {
+        match &self.rust_code {
+            MirCustomSerDesRustCode::Function(_) => None,
+            MirCustomSerDesRustCode::Template { import, .. } => import.clone(),
+        }
+    }
+}
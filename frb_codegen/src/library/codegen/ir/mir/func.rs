@@ -29,8 +29,15 @@ pub struct MirFunc {
     pub comments: Vec<MirComment>,
     pub codec_mode_pack: CodecModePack,
     pub rust_call_code: Option<String>,
+    /// Refer to `HirFlatFunction::rust_call_name`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rust_call_name: Option<String>,
     pub rust_aop_after: Option<String>,
+    pub compress: bool,
     pub impl_mode: MirFuncImplMode,
+    /// Only exists in some of the `rust_feature_sets`, e.g. `feature = "pro"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rust_cfg: Option<String>,
     // Currently, we use serde only for tests. Since lineno can be unstable, we skip this field for comparison
     #[serde(skip_serializing)]
     pub src_lineno_pseudo: usize,
@@ -192,6 +199,19 @@ impl MirFunc {
     }
 }
 
+impl MirFuncOutput {
+    pub(crate) fn rust_api_type(&self) -> String {
+        match &self.error {
+            Some(error) => format!(
+                "Result<{}, {}>",
+                self.normal.rust_api_type(),
+                error.rust_api_type()
+            ),
+            None => self.normal.rust_api_type(),
+        }
+    }
+}
+
 impl MirFuncOwnerInfoMethod {
     pub(crate) fn owner_ty_name(&self) -> Option<NamespacedName> {
         compute_interest_name_of_owner_ty(&self.owner_ty)
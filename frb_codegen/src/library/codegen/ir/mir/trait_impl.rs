@@ -1,8 +1,19 @@
 use crate::codegen::ir::mir::ty::trait_def::MirTypeTraitDef;
-use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
 
 #[derive(Clone, serde::Serialize, Debug)]
 pub struct MirTraitImpl {
     pub(crate) trait_ty: MirTypeTraitDef,
     pub(crate) impl_ty: MirType,
 }
+
+impl MirTraitImpl {
+    /// The `impl_ty` of an opaque type is owned, while the type may only be used by reference in the API
+    pub(crate) fn impl_ty_safe_idents_for_matching(&self) -> Vec<String> {
+        let mut ans = vec![self.impl_ty.safe_ident()];
+        if let MirType::RustAutoOpaqueImplicit(ty) = &self.impl_ty {
+            ans.push(ty.inner.safe_ident());
+        }
+        ans
+    }
+}
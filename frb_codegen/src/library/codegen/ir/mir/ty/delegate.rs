@@ -30,6 +30,7 @@ pub enum MirTypeDelegate {
     Map(MirTypeDelegateMap),
     Set(MirTypeDelegateSet),
     StreamSink(MirTypeDelegateStreamSink),
+    Progress(MirTypeDelegateProgress),
     BigPrimitive(MirTypeDelegateBigPrimitive),
     CastedPrimitive(MirTypeDelegateCastedPrimitive),
     RustAutoOpaqueExplicit(MirTypeDelegateRustAutoOpaqueExplicit),
@@ -38,6 +39,7 @@ pub enum MirTypeDelegate {
     DynTrait(MirTypeDelegateDynTrait),
     Lifetimeable(MirTypeDelegateLifetimeable),
     CustomSerDes(MirTypeDelegateCustomSerDes),
+    MessageCodec(MirTypeDelegateMessageCodec),
 }
 
 pub struct MirTypeDelegateArray {
@@ -81,6 +83,10 @@ pub struct MirTypeDelegateStreamSink {
     pub codec: CodecMode,
 }
 
+pub struct MirTypeDelegateProgress {
+    pub element_delegate: MirTypeRecord,
+}
+
 #[derive(Copy, strum_macros::Display)]
 pub enum MirTypeDelegateBigPrimitive {
     I128,
@@ -131,6 +137,25 @@ pub struct MirTypeDelegateDynTraitVariant {
 pub struct MirTypeDelegateCustomSerDes {
     pub info: MirCustomSerDes,
 }
+
+/// A type transferred as the bytes of a third-party format, e.g. a protobuf message
+pub struct MirTypeDelegateMessageCodec {
+    pub codec: MirMessageCodec,
+    /// e.g. `crate::proto::Person`
+    pub rust_type: String,
+    /// e.g. `Person`
+    pub dart_type: String,
+    /// The flatbuffers table type that the object type is unpacked from, e.g. `crate::fbs::Monster` for `MonsterT`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rust_table_type: Option<String>,
+}
+
+#[derive(Copy, strum_macros::Display, strum_macros::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum MirMessageCodec {
+    Protobuf,
+    Flatbuffers,
+}
 }
 
 impl MirTypeTrait for MirTypeDelegate {
@@ -175,6 +200,7 @@ impl MirTypeTrait for MirTypeDelegate {
             MirTypeDelegate::StreamSink(mir) => {
                 format!("StreamSink_{}_{}", mir.inner_ok.safe_ident(), mir.codec)
             }
+            MirTypeDelegate::Progress(_) => "FrbProgress".to_owned(),
             MirTypeDelegate::BigPrimitive(mir) => mir.to_string(),
             MirTypeDelegate::CastedPrimitive(mir) => {
                 format!("CastedPrimitive_{}", mir.inner.safe_ident())
@@ -199,6 +225,9 @@ impl MirTypeTrait for MirTypeDelegate {
             MirTypeDelegate::CustomSerDes(mir) => {
                 format!("CustomSerializer_{}", mir.info.rust_api_type.safe_ident())
             }
+            MirTypeDelegate::MessageCodec(mir) => {
+                format!("{}_{}", mir.codec, mir.rust_type.replace("::", "_"))
+            }
         }
     }
 
@@ -254,6 +283,7 @@ impl MirTypeTrait for MirTypeDelegate {
                     codec = mir.codec,
                 )
             }
+            MirTypeDelegate::Progress(_) => "flutter_rust_bridge::FrbProgress".to_owned(),
             MirTypeDelegate::BigPrimitive(mir) => match mir {
                 MirTypeDelegateBigPrimitive::I128 => "i128".to_owned(),
                 MirTypeDelegateBigPrimitive::U128 => "u128".to_owned(),
@@ -271,6 +301,7 @@ impl MirTypeTrait for MirTypeDelegate {
             MirTypeDelegate::ProxyEnum(mir) => mir.original.rust_api_type(),
             MirTypeDelegate::Lifetimeable(mir) => mir.api_type.rust_api_type(),
             MirTypeDelegate::CustomSerDes(mir) => mir.info.rust_api_type.rust_api_type(),
+            MirTypeDelegate::MessageCodec(mir) => mir.rust_type.clone(),
         }
     }
 
@@ -339,6 +370,7 @@ impl MirTypeDelegate {
             }
             MirTypeDelegate::Set(mir) => mir_list(*mir.inner.to_owned(), true),
             MirTypeDelegate::StreamSink(_) => MirType::Delegate(MirTypeDelegate::String),
+            MirTypeDelegate::Progress(mir) => MirType::Record(mir.element_delegate.clone()),
             MirTypeDelegate::BigPrimitive(_) => MirType::Delegate(MirTypeDelegate::String),
             MirTypeDelegate::CastedPrimitive(mir) => MirType::Primitive(mir.inner.clone()),
             MirTypeDelegate::RustAutoOpaqueExplicit(mir) => MirType::RustOpaque(mir.inner.clone()),
@@ -349,6 +381,10 @@ impl MirTypeDelegate {
                 MirTypeDelegate::RustAutoOpaqueExplicit(mir.delegate.clone()),
             ),
             MirTypeDelegate::CustomSerDes(mir) => *mir.info.inner_type.clone(),
+            MirTypeDelegate::MessageCodec(_) => MirType::PrimitiveList(MirTypePrimitiveList {
+                primitive: MirTypePrimitive::U8,
+                strict_dart_type: true,
+            }),
         }
     }
 }
@@ -23,6 +23,12 @@ pub struct MirEnum {
     pub variants: Vec<MirEnumVariant>,
     pub mode: MirEnumMode,
     pub ignore: bool,
+    /// The Dart classes of the variants are named after the variants only, see `#[frb(exception_hierarchy)]`
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub exception_hierarchy: bool,
+    /// Only exists in some of the `rust_feature_sets`, e.g. `feature = "pro"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rust_cfg: Option<String>,
 }
 
 #[derive(Copy)]
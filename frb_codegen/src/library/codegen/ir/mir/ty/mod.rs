@@ -19,8 +19,10 @@ use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
 use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
 use crate::utils::namespace::Namespace;
 use enum_dispatch::enum_dispatch;
+use itertools::Itertools;
 use serde::ser::SerializeStruct;
 use serde::{Serialize, Serializer};
+use std::collections::{BTreeSet, HashSet};
 
 crate::mir! {
 #[no_serde]
@@ -58,6 +60,34 @@ impl MirType {
         self.visit_children_types(f, mir_context);
     }
 
+    /// The `#[cfg(...)]` predicate of the structs and enums inside,
+    /// i.e. when some of them only exist in some of the `rust_feature_sets`
+    pub fn rust_cfg(&self, mir_context: &impl MirContext) -> Option<String> {
+        let mut visited = HashSet::new();
+        let mut cfgs = BTreeSet::new();
+        self.visit_types(
+            &mut |ty| {
+                if !visited.insert(ty.safe_ident()) {
+                    return true;
+                }
+                let cfg = match ty {
+                    MirType::StructRef(ty) => ty.get(mir_context).rust_cfg.clone(),
+                    MirType::EnumRef(ty) => ty.get(mir_context).rust_cfg.clone(),
+                    _ => None,
+                };
+                cfgs.extend(cfg);
+                false
+            },
+            mir_context,
+        );
+
+        match cfgs.len() {
+            0 => None,
+            1 => cfgs.into_iter().next(),
+            _ => Some(format!("all({})", cfgs.into_iter().join(", "))),
+        }
+    }
+
     #[inline]
     pub fn is_struct_or_enum_or_record(&self) -> bool {
         matches!(
@@ -111,6 +111,26 @@ pub struct NameComponent {
     pub args: Vec<Type>,
 }
 
+/// Same as [`NameComponent`], but with the type arguments kept as strings,
+/// because the syn types cannot be sent across threads
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+pub struct MirNameComponent {
+    pub ident: String,
+    #[serde(serialize_with = "serialize_joined")]
+    pub args: Vec<String>,
+}
+
+impl From<&NameComponent> for MirNameComponent {
+    fn from(value: &NameComponent) -> Self {
+        Self {
+            ident: value.ident.clone(),
+            args: (value.args.iter())
+                .map(|arg| quote::quote!(#arg).to_string())
+                .collect(),
+        }
+    }
+}
+
 fn serialize_vec_syn<T: ToTokens, S: Serializer>(values: &[T], s: S) -> Result<S::Ok, S::Error> {
     let str = (values.iter())
         .map(|value| quote::quote!(#value).to_string())
@@ -118,6 +138,10 @@ fn serialize_vec_syn<T: ToTokens, S: Serializer>(values: &[T], s: S) -> Result<S
     str.serialize(s)
 }
 
+fn serialize_joined<S: Serializer>(values: &[String], s: S) -> Result<S::Ok, S::Error> {
+    values.join(", ").serialize(s)
+}
+
 fn rust_type_to_sanitized_type(raw: &str, brief_name: bool) -> String {
     lazy_static! {
         static ref OPAQUE_FILTER: Regex = Regex::new(r"((\bdyn|'static|\bDartSafe|\bRustAutoOpaqueInner|\bAssertUnwindSafe|\+ (Send|Sync|UnwindSafe|RefUnwindSafe))\b)|([a-zA-Z0-9_ ]+::)").unwrap();
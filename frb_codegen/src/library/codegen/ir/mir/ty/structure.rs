@@ -25,7 +25,11 @@ pub struct MirStruct {
     pub generate_hash: bool,
     pub generate_eq: bool,
     pub ui_state: bool,
+    pub lazy: bool,
     pub comments: Vec<MirComment>,
+    /// Only exists in some of the `rust_feature_sets`, e.g. `feature = "pro"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rust_cfg: Option<String>,
 }
 }
 
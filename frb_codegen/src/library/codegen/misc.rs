@@ -9,6 +9,9 @@ pub(crate) struct GeneratorProgressBarPack {
     pub parse_hir_primary: SimpleProgressBar,
     pub parse_mir: SimpleProgressBar,
     pub generate: SimpleProgressBar,
+    pub generate_api_dart: SimpleProgressBar,
+    pub generate_wire_rust: SimpleProgressBar,
+    pub generate_wire_dart: SimpleProgressBar,
     pub generate_cbindgen: SimpleProgressBar,
     pub generate_ffigen: SimpleProgressBar,
     pub polish: SimpleProgressBar,
@@ -27,8 +30,11 @@ impl GeneratorProgressBarPack {
             parse_hir_primary: SimpleProgressBar::new("Parse HIR", 1),
             parse_mir: SimpleProgressBar::new("Parse MIR", 1),
             generate: SimpleProgressBar::new("Generate", 0),
+            generate_api_dart: SimpleProgressBar::new("Generate Dart API", 1),
+            generate_wire_rust: SimpleProgressBar::new("Generate Rust wire", 1),
+            generate_wire_dart: SimpleProgressBar::new("Generate Dart wire", 1),
             generate_cbindgen: SimpleProgressBar::new("Run cbindgen", 1),
-            generate_ffigen: SimpleProgressBar::new("Run ffigen", 1),
+            generate_ffigen: SimpleProgressBar::new("Run ffigen", 2),
             polish: SimpleProgressBar::new("Polish", 0),
             polish_dart_build_runner: SimpleProgressBar::new("Run Dart build_runner", 1),
             polish_dart_fix: SimpleProgressBar::new("Run Dart fix", 1),
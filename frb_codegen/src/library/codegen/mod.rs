@@ -1,5 +1,6 @@
 //! Code generator for `flutter_rust_bridge`
 
+mod compat;
 pub(crate) mod config;
 mod controller;
 pub(crate) mod dumper;
@@ -7,16 +8,27 @@ pub(crate) mod generator;
 pub(crate) mod ir;
 mod misc;
 pub(crate) mod parser;
+mod plugin;
 mod polisher;
 mod preparer;
 
 use crate::codegen::config::internal_config::InternalConfig;
 use crate::codegen::dumper::internal_config::ConfigDumpContent::Config as ContentConfig;
 use crate::codegen::dumper::Dumper;
+use crate::codegen::generator::misc::path_texts::PathTexts;
 use crate::codegen::misc::GeneratorProgressBarPack;
+use crate::codegen::parser::hir::raw::HirRawCache;
+use crate::utils::file_utils::create_dir_all_and_write;
+use crate::utils::message_format::print_human;
 pub use config::config::{Config, MetaConfig};
 pub use dumper::internal_config::ConfigDumpContent;
-use log::debug;
+pub use dumper::ir::{IrDumpFormat, IrDumpStage};
+pub use generator::misc::minimal_output::MinimalOutput;
+use log::{debug, info};
+pub use parser::mir::internal_config::{TypeMapping, TypeMappingHalf};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 /// Execute the main code generator
 pub fn generate(config: Config, meta_config: MetaConfig) -> anyhow::Result<()> {
@@ -30,26 +42,183 @@ pub fn generate(config: Config, meta_config: MetaConfig) -> anyhow::Result<()> {
         .with_content(ContentConfig)
         .dump("config.json", &config)?;
 
-    controller::run(&internal_config.controller, &|| {
-        generate_once(&internal_config, &dumper)
+    let mut state = IncrementalState::default();
+    controller::run(&internal_config.controller, &mut |changed_paths| {
+        generate_once(&internal_config, &dumper, &mut state, changed_paths)
     })?;
 
     Ok(())
 }
 
-fn generate_once(internal_config: &InternalConfig, dumper: &Dumper) -> anyhow::Result<()> {
+/// Generate the API reference of the Rust code, as seen from Dart, into `output_dir`
+pub fn generate_docs(config: Config, output_dir: &Path) -> anyhow::Result<()> {
+    debug!("config={config:?} output_dir={output_dir:?}");
+
+    let internal_config = InternalConfig::parse(&config, &MetaConfig { watch: false })?;
+    let dumper = Dumper::new(&internal_config.dumper);
+    let progress_bar_pack = GeneratorProgressBarPack::new();
+
+    // No need for the preparer, since the Dart toolchain is not used
+    let pb = progress_bar_pack.parse.start();
+    let mir_pack = parser::parse(&internal_config.parser, &dumper, &progress_bar_pack)?;
+    drop(pb);
+
+    let output_texts =
+        generator::docs::generate(&mir_pack, &internal_config.generator.api_dart, output_dir)?;
+    output_texts.write_to_disk()?;
+
+    info!("Write API reference to {output_dir:?}");
+    Ok(())
+}
+
+/// Serialize the intermediate representation at `stage` into `output`, for external tools to consume the API model
+pub fn dump_ir(
+    config: Config,
+    stage: IrDumpStage,
+    format: IrDumpFormat,
+    output: &Path,
+) -> anyhow::Result<()> {
+    debug!("config={config:?} stage={stage} format={format} output={output:?}");
+
+    let internal_config = InternalConfig::parse(&config, &MetaConfig { watch: false })?;
+    let dumper = Dumper::new(&internal_config.dumper);
+    let progress_bar_pack = GeneratorProgressBarPack::new();
+
+    // Similar to `generate_docs`, the preparer is not needed
+    let pb = progress_bar_pack.parse.start();
+    let text = match stage {
+        IrDumpStage::Hir => dumper::ir::serialize(
+            &parser::parse_hir(
+                &internal_config.parser,
+                &dumper,
+                &progress_bar_pack,
+                &mut HirRawCache::default(),
+            )?,
+            format,
+        )?,
+        IrDumpStage::Mir => dumper::ir::serialize(
+            &parser::parse(&internal_config.parser, &dumper, &progress_bar_pack)?,
+            format,
+        )?,
+    };
+    drop(pb);
+
+    create_dir_all_and_write(output, text)?;
+    info!("Write {stage} to {output:?}");
+    Ok(())
+}
+
+/// Compare the API with the snapshot at `snapshot_path`, failing when it changes incompatibly,
+/// or write the snapshot when `update` is true
+pub fn check_compat(config: Config, snapshot_path: &Path, update: bool) -> anyhow::Result<()> {
+    debug!("config={config:?} snapshot_path={snapshot_path:?} update={update}");
+
+    let internal_config = InternalConfig::parse(&config, &MetaConfig { watch: false })?;
+    let dumper = Dumper::new(&internal_config.dumper);
+    let progress_bar_pack = GeneratorProgressBarPack::new();
+
+    let pb = progress_bar_pack.parse.start();
+    let mir_pack = parser::parse(&internal_config.parser, &dumper, &progress_bar_pack)?;
+    drop(pb);
+
+    compat::check(&mir_pack, snapshot_path, update)
+}
+
+/// What is kept from the previous run in `--watch` mode, so that a change only re-runs the affected stages
+#[derive(Default)]
+struct IncrementalState {
+    prepared: bool,
+    hir_raw_cache: HirRawCache,
+    /// Used to skip generating when the API does not change, e.g. when only a function body is modified
+    mir_pack: Option<serde_json::Value>,
+    /// Used to only write and polish the files whose content changes
+    output_texts: HashMap<PathBuf, OutputText>,
+}
+
+/// A file written by a previous run, as given by the generator and as seen on disk after polishing
+struct OutputText {
+    generated: String,
+    on_disk: String,
+}
+
+impl IncrementalState {
+    /// Calls `write` with the files whose content changes, and only records them when it succeeds,
+    /// so that everything is written again after a failure
+    fn write_changed(
+        &mut self,
+        output_texts: PathTexts,
+        write: impl FnOnce(&PathTexts) -> anyhow::Result<()>,
+    ) -> anyhow::Result<()> {
+        let changed = PathTexts(
+            (output_texts.0.into_iter())
+                .filter(|item| {
+                    let previous = self.output_texts.get(&item.path);
+                    previous.map(|x| &x.generated) != Some(&item.text.all_code())
+                })
+                .collect(),
+        );
+
+        if let Err(e) = write(&changed) {
+            self.output_texts.clear();
+            self.mir_pack = None;
+            return Err(e);
+        }
+
+        for item in changed.0 {
+            if let Ok(on_disk) = fs::read_to_string(&item.path) {
+                let generated = item.text.all_code();
+                (self.output_texts).insert(item.path, OutputText { generated, on_disk });
+            }
+        }
+        Ok(())
+    }
+
+    /// Forgets the files modified or deleted by others since the previous run, so that they are written again,
+    /// and returns whether there are any
+    fn forget_modified_on_disk(&mut self) -> bool {
+        let len = self.output_texts.len();
+        (self.output_texts)
+            .retain(|path, text| fs::read_to_string(path).ok().as_ref() == Some(&text.on_disk));
+        self.output_texts.len() != len
+    }
+}
+
+fn generate_once(
+    internal_config: &InternalConfig,
+    dumper: &Dumper,
+    state: &mut IncrementalState,
+    changed_paths: Option<&[PathBuf]>,
+) -> anyhow::Result<()> {
     let progress_bar_pack = GeneratorProgressBarPack::new();
 
     dumper
         .with_content(ContentConfig)
         .dump("internal_config.json", &internal_config)?;
 
-    preparer::prepare(&internal_config.preparer)?;
+    if !state.prepared {
+        preparer::prepare(&internal_config.preparer)?;
+        state.prepared = true;
+    }
 
+    (state.hir_raw_cache).invalidate(changed_paths, &internal_config.parser.hir.rust_crate_dir);
     let pb = progress_bar_pack.parse.start();
-    let mir_pack = parser::parse(&internal_config.parser, dumper, &progress_bar_pack)?;
+    let mir_pack = parser::parse_with_hir_raw_cache(
+        &internal_config.parser,
+        dumper,
+        &progress_bar_pack,
+        &mut state.hir_raw_cache,
+    )?;
     drop(pb);
 
+    let mir_pack_value = serde_json::to_value(&mir_pack)?;
+    let modified_on_disk = state.forget_modified_on_disk();
+    if state.mir_pack.as_ref() == Some(&mir_pack_value) && !modified_on_disk {
+        // Plugins are outside of the incremental state, thus always run
+        plugin::run(&internal_config.plugin, &mir_pack)?.write_to_disk()?;
+        print_human("Skip generating, since the API does not change.");
+        return Ok(());
+    }
+
     let pb = progress_bar_pack.generate.start();
     let generator_output = generator::generate(
         &mir_pack,
@@ -59,18 +228,94 @@ fn generate_once(internal_config: &InternalConfig, dumper: &Dumper) -> anyhow::R
     )?;
     drop(pb);
 
-    generator_output.output_texts.write_to_disk()?;
+    state.write_changed(generator_output.output_texts, |changed_output_texts| {
+        changed_output_texts.write_to_disk()?;
 
-    let pb = progress_bar_pack.polish.start();
-    polisher::polish(
-        &internal_config.polisher,
-        generator_output.dart_needs_freezed,
-        &generator_output.output_texts.paths(),
-        &progress_bar_pack,
-    )?;
-    drop(pb);
+        plugin::run(&internal_config.plugin, &mir_pack)?.write_to_disk()?;
+
+        if !changed_output_texts.0.is_empty() {
+            let pb = progress_bar_pack.polish.start();
+            polisher::polish(
+                &internal_config.polisher,
+                generator_output.dart_needs_freezed,
+                &changed_output_texts.paths(),
+                &progress_bar_pack,
+            )?;
+            drop(pb);
+        }
+
+        Ok(())
+    })?;
 
-    println!("Done!");
+    state.mir_pack = Some(mir_pack_value);
+    print_human("Done!");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::generator::misc::path_texts::PathText;
+    use crate::utils::basic_code::general_code::GeneralCode;
+    use tempfile::tempdir;
+
+    fn create_output_texts(dir: &Path, text: &str) -> PathTexts {
+        PathTexts(vec![
+            PathText::new(dir.join("a.rs"), GeneralCode::new_rust(text.to_owned())),
+            PathText::new(dir.join("b.rs"), GeneralCode::new_rust(text.to_owned())),
+        ])
+    }
+
+    fn write_changed_paths(state: &mut IncrementalState, output_texts: PathTexts) -> Vec<PathBuf> {
+        let mut paths = vec![];
+        (state.write_changed(output_texts, |changed| {
+            paths = changed.paths();
+            changed.write_to_disk()
+        }))
+        .unwrap();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn test_write_changed_failure_then_retry() {
+        let dir = tempdir().unwrap();
+        let mut state = IncrementalState {
+            mir_pack: Some(serde_json::Value::Null),
+            ..Default::default()
+        };
+
+        let result = state.write_changed(create_output_texts(dir.path(), "one"), |changed| {
+            changed.write_to_disk()?;
+            anyhow::bail!("polish failed")
+        });
+        assert!(result.is_err());
+        assert!(state.output_texts.is_empty());
+        assert!(state.mir_pack.is_none());
+
+        let paths = write_changed_paths(&mut state, create_output_texts(dir.path(), "one"));
+        assert_eq!(
+            paths,
+            vec![dir.path().join("a.rs"), dir.path().join("b.rs")]
+        );
+
+        let paths = write_changed_paths(&mut state, create_output_texts(dir.path(), "one"));
+        assert!(paths.is_empty());
+    }
+
+    #[test]
+    fn test_forget_modified_on_disk() {
+        let dir = tempdir().unwrap();
+        let mut state = IncrementalState::default();
+        write_changed_paths(&mut state, create_output_texts(dir.path(), "one"));
+        assert!(!state.forget_modified_on_disk());
+
+        fs::write(dir.path().join("a.rs"), "modified").unwrap();
+        assert!(state.forget_modified_on_disk());
+        assert!(!state.forget_modified_on_disk());
+
+        let paths = write_changed_paths(&mut state, create_output_texts(dir.path(), "one"));
+        assert_eq!(paths, vec![dir.path().join("a.rs")]);
+    }
+}
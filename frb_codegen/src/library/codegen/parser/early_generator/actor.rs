@@ -0,0 +1,125 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::mir::func::{MirFunc, MirFuncMode, MirFuncOwnerInfoMethodMode};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::parser::early_generator::utils::method_forwarder::{
+    parse_forwarded_methods, parse_marked_type_names, ForwardedMethod,
+};
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::namespace::NamespacedName;
+use anyhow::ensure;
+use itertools::Itertools;
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    tentative_mir_pack: &MirPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let extra_codes = (parse_marked_type_names(&pack.hir_flat_pack, |attrs| attrs.actor())?.iter())
+        .map(|name| generate_actor(name, &tentative_mir_pack.funcs_all))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+fn generate_actor(
+    ty_name: &NamespacedName,
+    all_funcs: &[MirFunc],
+) -> anyhow::Result<InjectExtraCodeBlock> {
+    let actor_name = format!("{}Actor", ty_name.name);
+    let ty_path = ty_name.rust_style();
+
+    let methods = (parse_forwarded_methods(ty_name, all_funcs, "actor")?.iter())
+        .map(|method| generate_method(method, ty_name, &ty_path))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .join("\n\n");
+
+    Ok(InjectExtraCodeBlock {
+        code: format!(
+            "
+#[flutter_rust_bridge::frb(opaque)]
+pub struct {actor_name}(flutter_rust_bridge::Actor<{ty_path}>);
+
+impl {actor_name} {{
+    {methods}
+}}
+"
+        ),
+        should_parse: true,
+    })
+}
+
+fn generate_method(
+    method: &ForwardedMethod,
+    ty_name: &NamespacedName,
+    ty_path: &str,
+) -> anyhow::Result<String> {
+    let ForwardedMethod {
+        func,
+        name,
+        params,
+        call_args,
+        ..
+    } = method;
+    let params_joined = params.join(", ");
+    let maybe_await = if func.rust_async { ".await" } else { "" };
+
+    Ok(match method.mode {
+        // Queued after previous calls, thus always async in Dart
+        MirFuncOwnerInfoMethodMode::Instance => {
+            ensure!(
+                func.mode != MirFuncMode::Sync,
+                "Method `{name}` of actor type `{}` cannot be sync, since it waits for previous calls",
+                ty_name.name
+            );
+            let call = if func.rust_async {
+                format!("self.0.call_async(move |that| Box::pin(async move {{ that.{name}({call_args}).await }})).await")
+            } else {
+                format!("self.0.call(move |that| that.{name}({call_args})).await")
+            };
+            // The panic is caught by the actor to keep it alive, and resumed here to be reported as usual
+            let body =
+                format!("{call}.unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))");
+            format!(
+                "pub async fn {name}({params}) -> {output} {{
+    {body}
+}}",
+                params = ["&self".to_owned()].iter().chain(params).join(", "),
+                output = func.output.rust_api_type(),
+            )
+        }
+        // Async since spawning the owning task needs the async runtime
+        MirFuncOwnerInfoMethodMode::Static if method.returns_self => match &func.output.error {
+            Some(error) => format!(
+                "pub async fn {name}({params_joined}) -> Result<Self, {error}> {{
+    Ok(Self(flutter_rust_bridge::Actor::new({ty_path}::{name}({call_args}){maybe_await}?)))
+}}",
+                error = error.rust_api_type(),
+            ),
+            None => format!(
+                "pub async fn {name}({params_joined}) -> Self {{
+    Self(flutter_rust_bridge::Actor::new({ty_path}::{name}({call_args}){maybe_await}))
+}}"
+            ),
+        },
+        MirFuncOwnerInfoMethodMode::Static => format!(
+            "{sync_attr}pub {maybe_async}fn {name}({params_joined}) -> {output} {{
+    {ty_path}::{name}({call_args}){maybe_await}
+}}",
+            sync_attr = if func.mode == MirFuncMode::Sync {
+                "#[flutter_rust_bridge::frb(sync)]\n"
+            } else {
+                ""
+            },
+            maybe_async = if func.rust_async { "async " } else { "" },
+            output = func.output.rust_api_type(),
+        ),
+    })
+}
@@ -0,0 +1,171 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::hir::flat::function::{HirFlatFunction, HirFlatFunctionOwner};
+use crate::codegen::ir::mir::func::{MirFunc, MirFuncOwnerInfo};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::parser::early_generator::utils::type_qualifier::TypeQualifier;
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use anyhow::{bail, ensure, Context};
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    tentative_mir_pack: &MirPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let qualifier = TypeQualifier::new(&pack.hir_flat_pack);
+
+    let mut extra_codes = vec![];
+    for hir_func in
+        (pack.hir_flat_pack.functions.iter()).sorted_by_key(|x| x.owner_and_name_for_dedup())
+    {
+        if FrbAttributes::parse(hir_func.item_fn.attrs())?.background_task() {
+            extra_codes.push(generate_task(
+                hir_func,
+                &tentative_mir_pack.funcs_all,
+                &qualifier,
+            )?);
+        }
+    }
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+fn generate_task(
+    hir_func: &HirFlatFunction,
+    all_funcs: &[MirFunc],
+    qualifier: &TypeQualifier,
+) -> anyhow::Result<InjectExtraCodeBlock> {
+    let func_name = hir_func.item_fn.name();
+    ensure!(
+        matches!(hir_func.owner, HirFlatFunctionOwner::Function),
+        "Background task `{func_name}` must be a free function"
+    );
+
+    let func = (all_funcs.iter())
+        .find(|f| {
+            matches!(f.owner, MirFuncOwnerInfo::Function)
+                && f.name.namespace == hir_func.namespace
+                && f.name.name == func_name
+        })
+        .with_context(|| format!("Background task `{func_name}` cannot be parsed"))?;
+    ensure!(
+        func.rust_async,
+        "Background task `{func_name}` must be `async`"
+    );
+
+    let progress_ty = qualifier.qualify(parse_progress_type(hir_func)?);
+    let output_ty = func.output.rust_api_type();
+    // The error of joining is merged into the error of the function if any, e.g. `String`
+    let (join_output_ty, join_body) = if func.output.error.is_some() {
+        (output_ty.clone(), "self.inner.join().await?")
+    } else {
+        (
+            format!("Result<{output_ty}, flutter_rust_bridge::for_generated::anyhow::Error>"),
+            "Ok(self.inner.join().await?)",
+        )
+    };
+    let task_name = format!("{}Task", func_name.to_case(Case::Pascal));
+    let func_path = func.name.rust_style();
+
+    let args = &func.inputs[..func.inputs.len() - 1];
+    let start_params = (args.iter())
+        .map(|x| {
+            format!(
+                "{}: {}",
+                x.inner.name.rust_style(),
+                x.inner.ty.rust_api_type()
+            )
+        })
+        .join(", ");
+    let call_args = (args.iter())
+        .map(|x| format!("{}, ", x.inner.name.rust_style()))
+        .join("");
+
+    Ok(InjectExtraCodeBlock {
+        code: format!(
+            "
+#[flutter_rust_bridge::frb(opaque)]
+pub struct {task_name} {{
+    inner: flutter_rust_bridge::BackgroundTask<{progress_ty}, {output_ty}>,
+}}
+
+impl {task_name} {{
+    pub async fn start({start_params}) -> Self {{
+        Self {{
+            inner: flutter_rust_bridge::BackgroundTask::spawn(move |context| {func_path}({call_args}context)),
+        }}
+    }}
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn progress(&self, sink: StreamSink<{progress_ty}>) {{
+        self.inner.add_progress_listener(move |progress| sink.add(progress).is_ok());
+    }}
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn pause(&self) {{
+        self.inner.pause()
+    }}
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn resume(&self) {{
+        self.inner.resume()
+    }}
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn cancel(&self) {{
+        self.inner.cancel()
+    }}
+
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn is_paused(&self) -> bool {{
+        self.inner.is_paused()
+    }}
+
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn is_cancelled(&self) -> bool {{
+        self.inner.is_cancelled()
+    }}
+
+    #[flutter_rust_bridge::frb(sync, getter)]
+    pub fn is_finished(&self) -> bool {{
+        self.inner.is_finished()
+    }}
+
+    pub async fn join(&self) -> {join_output_ty} {{
+        {join_body}
+    }}
+}}
+"
+        ),
+        should_parse: true,
+    })
+}
+
+// The progress type is not parsed by MIR, since `BackgroundTaskContext` is not a bridged type
+fn parse_progress_type(hir_func: &HirFlatFunction) -> anyhow::Result<&syn::Type> {
+    let func_name = hir_func.item_fn.name();
+    if let Some(syn::FnArg::Typed(pat_type)) = hir_func.item_fn.sig().inputs.last() {
+        if let syn::Type::Path(type_path) = &*pat_type.ty {
+            if let Some(segment) = type_path.path.segments.last() {
+                if segment.ident == "BackgroundTaskContext" {
+                    if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                        if let Some(syn::GenericArgument::Type(ty)) = args.args.first() {
+                            return Ok(ty);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    bail!("The last argument of background task `{func_name}` must be `BackgroundTaskContext<YourProgressType>`")
+}
@@ -0,0 +1,197 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::hir::flat::traits::HirFlatTrait;
+use crate::codegen::ir::mir::func::{
+    MirFunc, MirFuncArgMode, MirFuncOwnerInfo, MirFuncOwnerInfoMethodMode, OwnershipMode,
+};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::dart_keywords;
+use anyhow::ensure;
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    tentative_mir_pack: &MirPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let mut extra_codes = vec![];
+    for hir_trait in (pack.hir_flat_pack.traits.iter()).sorted_by_key(|x| x.name.clone()) {
+        if FrbAttributes::parse(&hir_trait.attrs)?.dart_service() {
+            extra_codes.extend(generate_dart_proxy(
+                hir_trait,
+                &tentative_mir_pack.funcs_all,
+            )?);
+        }
+    }
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+struct ServiceMethod {
+    rust_name: String,
+    dart_name: String,
+    receiver: OwnershipMode,
+    arg_mode: MirFuncArgMode,
+    // (rust name, dart name, rust type)
+    args: Vec<(String, String, String)>,
+    output: String,
+}
+
+fn generate_dart_proxy(
+    hir_trait: &HirFlatTrait,
+    all_funcs: &[MirFunc],
+) -> anyhow::Result<Vec<InjectExtraCodeBlock>> {
+    let trait_name = &hir_trait.name.name;
+    let proxy_name = format!("{trait_name}DartProxy");
+
+    let methods = (all_funcs.iter())
+        .filter(|f| is_method_of_trait(f, hir_trait))
+        .sorted_by_key(|f| f.src_lineno_pseudo)
+        .map(|f| parse_service_method(f, trait_name))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let fn_type = |method: &ServiceMethod| {
+        format!(
+            "Fn({}) -> flutter_rust_bridge::DartFnFuture<{}> + Send + Sync",
+            method.args.iter().map(|(_, _, ty)| ty).join(", "),
+            method.output,
+        )
+    };
+
+    let fields = (methods.iter())
+        .map(|m| format!("{}: Box<dyn {}>,", m.rust_name, fn_type(m)))
+        .join("\n");
+    let constructor_params = (methods.iter())
+        .map(|m| format!("{}: impl {} + 'static,", m.rust_name, fn_type(m)))
+        .join("\n");
+    let constructor_body = (methods.iter())
+        .map(|m| format!("{name}: Box::new({name}),", name = m.rust_name))
+        .join("\n");
+    let dart_constructor_args = (methods.iter())
+        .map(|m| {
+            let params = m.args.iter().map(|(_, name, _)| name).join(", ");
+            let call_args = (m.args.iter())
+                .map(|(_, name, _)| match m.arg_mode {
+                    MirFuncArgMode::Named => format!("{name}: {name}"),
+                    MirFuncArgMode::Positional => name.to_owned(),
+                })
+                .join(", ");
+            format!(
+                "{}: ({params}) => implementation.{}({call_args}),",
+                m.rust_name.to_case(Case::Camel),
+                m.dart_name,
+            )
+        })
+        .join("\n");
+    let trait_impl_body = (methods.iter())
+        .map(|m| {
+            let params = (m.args.iter())
+                .map(|(name, _, ty)| format!(", {name}: {ty}"))
+                .join("");
+            let call_args = m.args.iter().map(|(name, _, _)| name).join(", ");
+            let maybe_output = if m.output == "()" {
+                "".to_owned()
+            } else {
+                format!(" -> {}", m.output)
+            };
+            format!(
+                "async fn {name}({receiver}self{params}){maybe_output} {{
+    (self.{name})({call_args}).await
+}}",
+                name = m.rust_name,
+                receiver = m.receiver.prefix(),
+            )
+        })
+        .join("\n\n");
+
+    let trait_path = hir_trait.name.rust_style();
+
+    Ok(vec![
+        InjectExtraCodeBlock {
+            code: format!(
+                r###"
+#[flutter_rust_bridge::frb(opaque)]
+#[flutter_rust_bridge::frb(dart_code = r#"
+    factory {proxy_name}({trait_name} implementation) => {proxy_name}.frbInternalNew(
+        {dart_constructor_args}
+    );
+"#)]
+pub struct {proxy_name} {{
+    {fields}
+}}
+
+impl {proxy_name} {{
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn frb_internal_new({constructor_params}) -> Self {{
+        Self {{ {constructor_body} }}
+    }}
+}}
+"###
+            ),
+            should_parse: true,
+        },
+        InjectExtraCodeBlock {
+            code: format!(
+                "
+impl {trait_path} for {proxy_name} {{
+    {trait_impl_body}
+}}
+"
+            ),
+            should_parse: false,
+        },
+    ])
+}
+
+fn is_method_of_trait(func: &MirFunc, hir_trait: &HirFlatTrait) -> bool {
+    matches!(&func.owner, MirFuncOwnerInfo::Method(m)
+        if matches!(&m.owner_ty, MirType::TraitDef(ty) if ty.name == hir_trait.name))
+}
+
+fn parse_service_method(func: &MirFunc, trait_name: &str) -> anyhow::Result<ServiceMethod> {
+    let MirFuncOwnerInfo::Method(method) = &func.owner else {
+        unreachable!()
+    };
+    let rust_name = &method.actual_method_name;
+
+    ensure!(
+        method.mode == MirFuncOwnerInfoMethodMode::Instance,
+        "Method `{rust_name}` of Dart service `{trait_name}` must have a `self` receiver"
+    );
+    ensure!(
+        func.rust_async,
+        "Method `{rust_name}` of Dart service `{trait_name}` must be `async`, since Dart implements it asynchronously"
+    );
+
+    let (receiver, args) = func.inputs.split_first().unwrap();
+
+    Ok(ServiceMethod {
+        rust_name: rust_name.clone(),
+        dart_name: (method.actual_method_dart_name.as_ref())
+            .map(|x| x.to_case(Case::Camel))
+            .unwrap_or_else(|| dart_keywords::escape(rust_name.to_case(Case::Camel))),
+        receiver: receiver.ownership_mode.unwrap_or(OwnershipMode::Owned),
+        arg_mode: func.arg_mode,
+        args: (args.iter())
+            .map(|x| {
+                (
+                    x.inner.name.rust_style(),
+                    x.inner.name.dart_style(),
+                    x.inner.ty.rust_api_type(),
+                )
+            })
+            .collect(),
+        output: func.output.rust_api_type(),
+    })
+}
@@ -0,0 +1,377 @@
+//! Instantiate generic functions, structs and enums with the concrete types in `#[frb(instantiate(T = MyDto))]`.
+//!
+//! A struct `Response<T>` instantiated with `T = MyDto` becomes `ResponseMyDto`, which is a type alias in the
+//! generated Rust code and a normal class in Dart. A function `fetch<T>` becomes `fetch_my_dto`, which calls
+//! `fetch::<MyDto>` in the generated Rust code.
+
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::hir::flat::function::{HirFlatFunction, HirFlatFunctionOwner};
+use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStructOrEnum;
+use crate::codegen::ir::hir::misc::syn_item_struct_or_enum::SynItemStructOrEnum;
+use crate::codegen::parser::early_generator::utils::type_qualifier::TypeQualifier;
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::utils::namespace::{Namespace, NamespacedName};
+use crate::utils::syn_utils::ty_to_string;
+use anyhow::{ensure, Context};
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use std::collections::HashMap;
+use syn::visit_mut::VisitMut;
+use syn::{Attribute, GenericArgument, GenericParam, Generics, Ident, PathArguments, Type};
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+    let hir_flat_pack = &mut pack.hir_flat_pack;
+    let qualifier = TypeQualifier::new(hir_flat_pack);
+
+    let mut type_instances = vec![];
+    let mut new_structs = vec![];
+    for item in &hir_flat_pack.structs {
+        new_structs.extend(instantiate_struct_or_enum(
+            item,
+            output_namespace,
+            &qualifier,
+            &mut type_instances,
+        )?);
+    }
+    let mut new_enums = vec![];
+    for item in &hir_flat_pack.enums {
+        new_enums.extend(instantiate_struct_or_enum(
+            item,
+            output_namespace,
+            &qualifier,
+            &mut type_instances,
+        )?);
+    }
+    hir_flat_pack.structs.extend(new_structs);
+    hir_flat_pack.enums.extend(new_enums);
+
+    let mut new_functions = vec![];
+    for function in &hir_flat_pack.functions {
+        new_functions.extend(instantiate_function(function, &qualifier)?);
+    }
+    hir_flat_pack.functions.extend(new_functions);
+
+    // Since the alias is the same type, the existing code using e.g. `Response<MyDto>` is bridged as well
+    let mut replacer = TypeReplacer {
+        params: HashMap::new(),
+        type_instances: &type_instances,
+        qualifier: &qualifier,
+    };
+    for function in &mut hir_flat_pack.functions {
+        replacer.visit_signature_mut(function.item_fn.sig_mut());
+    }
+    for item in &mut hir_flat_pack.structs {
+        item.src.visit_types_mut(&mut replacer);
+    }
+    for item in &mut hir_flat_pack.enums {
+        item.src.visit_types_mut(&mut replacer);
+    }
+
+    let extra_codes = (type_instances.iter())
+        .map(|instance| InjectExtraCodeBlock {
+            code: format!(
+                "pub type {} = {}<{}>;\n",
+                instance.name,
+                instance.generic_name.rust_style(),
+                instance.args
+            ),
+            should_parse: false,
+        })
+        .collect_vec();
+    inject_extra_codes(hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+struct TypeInstance {
+    generic_name: NamespacedName,
+    /// Fully qualified, e.g. `crate::api::MyDto, String`
+    args: String,
+    name: String,
+}
+
+fn instantiate_struct_or_enum<Item: SynItemStructOrEnum + VisitTypesMut>(
+    item: &HirFlatStructOrEnum<Item>,
+    output_namespace: &Namespace,
+    qualifier: &TypeQualifier,
+    type_instances: &mut Vec<TypeInstance>,
+) -> anyhow::Result<Vec<HirFlatStructOrEnum<Item>>> {
+    let instantiations = FrbAttributes::parse(item.src.attrs())?.instantiations();
+    let mut ans = vec![];
+    for instantiation in instantiations {
+        let args = compute_generic_args(item.src.generics(), &instantiation, &item.name.name)?;
+        let name = format!("{}{}", item.name.name, compute_type_name_suffix(&args));
+
+        let mut src = item.src.clone();
+        *src.ident_mut() = Ident::new(&name, src.ident_mut().span());
+        *src.attrs_mut() = remove_instantiate_attrs(item.src.attrs())?;
+        *src.generics_mut() = Generics::default();
+        src.visit_types_mut(&mut TypeReplacer {
+            params: instantiation.into_iter().collect(),
+            type_instances: &[],
+            qualifier,
+        });
+
+        ans.push(HirFlatStructOrEnum {
+            name: NamespacedName::new(output_namespace.to_owned(), name.clone()),
+            src,
+            ..item.clone()
+        });
+        type_instances.push(TypeInstance {
+            generic_name: item.name.clone(),
+            args: qualifier.qualify_args(&args),
+            name,
+        });
+    }
+    Ok(ans)
+}
+
+fn instantiate_function(
+    function: &HirFlatFunction,
+    qualifier: &TypeQualifier,
+) -> anyhow::Result<Vec<HirFlatFunction>> {
+    let instantiations = FrbAttributes::parse(function.item_fn.attrs())?.instantiations();
+    if instantiations.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let func_name = function.item_fn.name();
+    ensure!(
+        matches!(function.owner, HirFlatFunctionOwner::Function),
+        "Instantiating generic methods is not supported yet, please make `{func_name}` a free function"
+    );
+
+    let mut ans = vec![];
+    for instantiation in instantiations {
+        let args =
+            compute_generic_args(&function.item_fn.sig().generics, &instantiation, &func_name)?;
+        let name = format!("{func_name}_{}", compute_function_name_suffix(&args));
+
+        let mut item_fn = function.item_fn.clone();
+        *item_fn.attrs_mut() = remove_instantiate_attrs(function.item_fn.attrs())?;
+        let sig = item_fn.sig_mut();
+        sig.ident = Ident::new(&name, sig.ident.span());
+        sig.generics = Generics::default();
+        TypeReplacer {
+            params: instantiation.into_iter().collect(),
+            type_instances: &[],
+            qualifier,
+        }
+        .visit_signature_mut(sig);
+
+        ans.push(HirFlatFunction {
+            item_fn,
+            rust_call_name: Some(format!("{func_name}::<{}>", qualifier.qualify_args(&args))),
+            ..function.clone()
+        });
+    }
+    Ok(ans)
+}
+
+/// The concrete types in the order of the generic parameters, e.g. for the turbofish
+fn compute_generic_args(
+    generics: &Generics,
+    instantiation: &[(Ident, Type)],
+    item_name: &str,
+) -> anyhow::Result<Vec<Type>> {
+    let param_names = (generics.params.iter())
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(param.ident.clone()),
+            _ => None,
+        })
+        .collect_vec();
+    ensure!(
+        param_names.len() == instantiation.len()
+            && generics.params.len() == param_names.len(),
+        "`{item_name}` should only have type parameters, and each of them needs a concrete type in `#[frb(instantiate(...))]`"
+    );
+    (param_names.iter())
+        .map(|param_name| {
+            (instantiation.iter())
+                .find(|(name, _)| name == param_name)
+                .map(|(_, ty)| ty.clone())
+                .with_context(|| {
+                    format!("`#[frb(instantiate(...))]` of `{item_name}` misses `{param_name}`")
+                })
+        })
+        .collect()
+}
+
+/// e.g. `MyDtoVecU8` for `MyDto, Vec<u8>`
+fn compute_type_name_suffix(args: &[Type]) -> String {
+    (compute_name_words(args).iter())
+        .map(|word| {
+            let mut chars = word.chars();
+            (chars.next().unwrap().to_uppercase())
+                .chain(chars)
+                .collect::<String>()
+        })
+        .join("")
+}
+
+/// e.g. `my_dto_vec_u8` for `MyDto, Vec<u8>`
+fn compute_function_name_suffix(args: &[Type]) -> String {
+    (compute_name_words(args).iter())
+        // Otherwise `u8` would become `u_8`
+        .map(|word| {
+            if word.chars().any(char::is_uppercase) {
+                word.to_case(Case::Snake)
+            } else {
+                word.to_owned()
+            }
+        })
+        .join("_")
+}
+
+fn compute_name_words(args: &[Type]) -> Vec<String> {
+    (args.iter())
+        .flat_map(|ty| {
+            (ty_to_string(ty).split(|c: char| !c.is_alphanumeric()))
+                .filter(|word| !word.is_empty())
+                .map(|word| word.to_owned())
+                .collect_vec()
+        })
+        .collect_vec()
+}
+
+fn remove_instantiate_attrs(attrs: &[Attribute]) -> anyhow::Result<Vec<Attribute>> {
+    let mut ans = vec![];
+    for attr in attrs {
+        if FrbAttributes::parse(std::slice::from_ref(attr))?
+            .instantiations()
+            .is_empty()
+        {
+            ans.push(attr.clone());
+        }
+    }
+    Ok(ans)
+}
+
+struct TypeReplacer<'a> {
+    params: HashMap<Ident, Type>,
+    type_instances: &'a [TypeInstance],
+    /// Both `Response<MyDto>` and `Response<crate::api::MyDto>` refer to the same instance
+    qualifier: &'a TypeQualifier,
+}
+
+impl VisitMut for TypeReplacer<'_> {
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        syn::visit_mut::visit_type_mut(self, ty);
+
+        let Type::Path(type_path) = ty else {
+            return;
+        };
+        if type_path.qself.is_some() {
+            return;
+        }
+        if let Some(replacement) = (type_path.path.get_ident()).and_then(|x| self.params.get(x)) {
+            *ty = replacement.clone();
+            return;
+        }
+        let Some(last_segment) = type_path.path.segments.last() else {
+            return;
+        };
+        let PathArguments::AngleBracketed(args) = &last_segment.arguments else {
+            return;
+        };
+        let Some(args) = (args.args.iter())
+            .map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+        let args = self.qualifier.qualify_args(&args);
+        if let Some(instance) = (self.type_instances.iter())
+            .find(|x| last_segment.ident == x.generic_name.name && args == x.args)
+        {
+            *ty = syn::parse_str(&instance.name).unwrap();
+        }
+    }
+}
+
+trait VisitTypesMut {
+    fn visit_types_mut(&mut self, replacer: &mut TypeReplacer);
+}
+
+impl VisitTypesMut for syn::ItemStruct {
+    fn visit_types_mut(&mut self, replacer: &mut TypeReplacer) {
+        replacer.visit_item_struct_mut(self);
+    }
+}
+
+impl VisitTypesMut for syn::ItemEnum {
+    fn visit_types_mut(&mut self, replacer: &mut TypeReplacer) {
+        replacer.visit_item_enum_mut(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_replacer() {
+        let type_instances = vec![TypeInstance {
+            generic_name: NamespacedName::new(
+                Namespace::new_self_crate("api".to_owned()),
+                "Response".to_owned(),
+            ),
+            args: "crate :: api :: MyDto".to_owned(),
+            name: "ResponseMyDto".to_owned(),
+        }];
+        let qualifier = TypeQualifier {
+            names: [(
+                "MyDto".to_owned(),
+                NamespacedName::new(
+                    Namespace::new_self_crate("api".to_owned()),
+                    "MyDto".to_owned(),
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        let mut replacer = TypeReplacer {
+            params: [(
+                syn::parse_str("T").unwrap(),
+                syn::parse_str("MyDto").unwrap(),
+            )]
+            .into_iter()
+            .collect(),
+            type_instances: &type_instances,
+            qualifier: &qualifier,
+        };
+
+        let mut ty: Type = syn::parse_str("Vec<crate::api::Response<T>>").unwrap();
+        replacer.visit_type_mut(&mut ty);
+        assert_eq!(ty_to_string(&ty), "Vec < ResponseMyDto >");
+
+        let mut ty: Type = syn::parse_str("Response<crate::api::MyDto>").unwrap();
+        replacer.visit_type_mut(&mut ty);
+        assert_eq!(ty_to_string(&ty), "ResponseMyDto");
+
+        let mut ty: Type = syn::parse_str("Response<String>").unwrap();
+        replacer.visit_type_mut(&mut ty);
+        assert_eq!(ty_to_string(&ty), "Response < String >");
+    }
+
+    #[test]
+    fn test_compute_name_suffix() {
+        let args = vec![
+            syn::parse_str("MyDto").unwrap(),
+            syn::parse_str("Vec<u8>").unwrap(),
+        ];
+        assert_eq!(compute_type_name_suffix(&args), "MyDtoVecU8");
+        assert_eq!(compute_function_name_suffix(&args), "my_dto_vec_u8");
+    }
+}
@@ -0,0 +1,215 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::mir::func::{
+    compute_interest_name_of_owner_ty, MirFunc, MirFuncMode, MirFuncOwnerInfo,
+    MirFuncOwnerInfoMethodMode, OwnershipMode,
+};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::parser::early_generator::utils::method_forwarder::{
+    compute_ownership_mode, parse_forwarded_methods, parse_marked_type_names, ForwardedMethod,
+};
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::utils::basic_code::parser::parse_dart_code;
+use crate::utils::namespace::NamespacedName;
+use anyhow::ensure;
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    tentative_mir_pack: &MirPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let ty_names = parse_marked_type_names(&pack.hir_flat_pack, |attrs| attrs.lock_scope())?;
+
+    let mut extra_codes = vec![];
+    for ty_name in &ty_names {
+        check_dart_name_clash(ty_name, &tentative_mir_pack.funcs_all)?;
+        for mode in [ScopeMode::Read, ScopeMode::Write] {
+            extra_codes.push(generate_rust_scope(
+                ty_name,
+                mode,
+                &tentative_mir_pack.funcs_all,
+            )?);
+        }
+        pack.hir_flat_pack.extra_dart_output_code +=
+            parse_dart_code(&generate_dart_extension(&ty_name.name));
+    }
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+/// Names of the methods in the generated Dart extension
+const DART_EXTENSION_METHODS: [&str; 4] = ["read", "write", "tryRead", "tryWrite"];
+
+// Dart silently prefers class members over extension methods, thus a clash would hide the scope
+fn check_dart_name_clash(ty_name: &NamespacedName, all_funcs: &[MirFunc]) -> anyhow::Result<()> {
+    for func in all_funcs {
+        if let MirFuncOwnerInfo::Method(method) = &func.owner {
+            if method.owner_ty_name().as_ref() != Some(ty_name) {
+                continue;
+            }
+            let dart_name = (method.actual_method_dart_name.as_ref())
+                .unwrap_or(&method.actual_method_name)
+                .to_case(Case::Camel);
+            ensure!(
+                !DART_EXTENSION_METHODS.contains(&dart_name.as_str()),
+                "Method `{}` of lock scope type `{}` clashes with the generated `{dart_name}` extension method, please rename it (e.g. via `#[frb(name = \"...\")]`)",
+                method.actual_method_name,
+                ty_name.name,
+            );
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ScopeMode {
+    Read,
+    Write,
+}
+
+impl ScopeMode {
+    fn name(self) -> &'static str {
+        match self {
+            ScopeMode::Read => "Read",
+            ScopeMode::Write => "Write",
+        }
+    }
+
+    fn allows(self, receiver: OwnershipMode) -> bool {
+        match receiver {
+            OwnershipMode::Owned => false,
+            OwnershipMode::Ref => true,
+            OwnershipMode::RefMut => self == ScopeMode::Write,
+        }
+    }
+}
+
+fn generate_rust_scope(
+    ty_name: &NamespacedName,
+    mode: ScopeMode,
+    all_funcs: &[MirFunc],
+) -> anyhow::Result<InjectExtraCodeBlock> {
+    let scope_name = format!("{}{}Scope", ty_name.name, mode.name());
+    let inner_scope_ty = format!("flutter_rust_bridge::RustAutoOpaque{}Scope", mode.name());
+    let ty_path = ty_name.rust_style();
+    // The short name, thus it is recognized as the same type as the ones in the original API
+    let ty_short_name = &ty_name.name;
+
+    // Only instance methods can be called via the lock, others are still available on the type
+    let candidate_funcs = (all_funcs.iter())
+        .filter(|func| match &func.owner {
+            MirFuncOwnerInfo::Method(method) => {
+                matches!(method.mode, MirFuncOwnerInfoMethodMode::Instance)
+                    && mode.allows(compute_ownership_mode(&func.inputs[0]))
+                    && compute_interest_name_of_owner_ty(&func.output.normal).as_ref()
+                        != Some(ty_name)
+            }
+            MirFuncOwnerInfo::Function => false,
+        })
+        .cloned()
+        .collect_vec();
+
+    let methods = (parse_forwarded_methods(ty_name, &candidate_funcs, "lock scope")?.iter())
+        .map(generate_method)
+        .join("\n\n");
+
+    Ok(InjectExtraCodeBlock {
+        code: format!(
+            "
+#[flutter_rust_bridge::frb(opaque)]
+pub struct {scope_name}({inner_scope_ty}<{ty_path}>);
+
+impl {scope_name} {{
+    pub async fn lock(that: RustAutoOpaque<{ty_short_name}>) -> Self {{
+        Self({inner_scope_ty}::lock(that).await)
+    }}
+
+    #[flutter_rust_bridge::frb(sync)]
+    pub fn try_lock(that: RustAutoOpaque<{ty_short_name}>) -> Option<Self> {{
+        {inner_scope_ty}::try_lock(that).map(Self)
+    }}
+
+    {methods}
+}}
+"
+        ),
+        should_parse: true,
+    })
+}
+
+fn generate_method(method: &ForwardedMethod) -> String {
+    let ForwardedMethod {
+        func,
+        name,
+        params,
+        call_args,
+        ..
+    } = method;
+
+    let receiver = match compute_ownership_mode(&func.inputs[0]) {
+        OwnershipMode::RefMut => "&mut self",
+        _ => "&self",
+    };
+    let sync_attr = if func.mode == MirFuncMode::Sync {
+        "#[flutter_rust_bridge::frb(sync)]\n"
+    } else {
+        ""
+    };
+    let (maybe_async, maybe_await) = if func.rust_async {
+        ("async ", ".await")
+    } else {
+        ("", "")
+    };
+
+    format!(
+        "{sync_attr}pub {maybe_async}fn {name}({params}) -> {output} {{
+    self.0.{name}({call_args}){maybe_await}
+}}",
+        params = [receiver.to_owned()].iter().chain(params).join(", "),
+        output = func.output.rust_api_type(),
+    )
+}
+
+fn generate_dart_extension(ty_name: &str) -> String {
+    format!(
+        "
+extension {ty_name}LockScope on {ty_name} {{
+  /// Runs [action] while holding the read lock, thus no writes can happen in between.
+  Future<R> read<R>(FutureOr<R> Function({ty_name}ReadScope scope) action) async =>
+      runLockScope(await {ty_name}ReadScope.lock(that: this), action);
+
+  /// Runs [action] while holding the write lock, thus the calls inside it are atomic.
+  Future<R> write<R>(FutureOr<R> Function({ty_name}WriteScope scope) action) async =>
+      runLockScope(await {ty_name}WriteScope.lock(that: this), action);
+
+  /// Similar to [read], but returns null if the lock is not acquired within [timeout].
+  Future<R?> tryRead<R>(FutureOr<R> Function({ty_name}ReadScope scope) action,
+          {{Duration timeout = Duration.zero}}) =>
+      tryRunLockScope(
+        tryLock: () => {ty_name}ReadScope.tryLock(that: this),
+        lock: () => {ty_name}ReadScope.lock(that: this),
+        timeout: timeout,
+        action: action,
+      );
+
+  /// Similar to [write], but returns null if the lock is not acquired within [timeout].
+  Future<R?> tryWrite<R>(FutureOr<R> Function({ty_name}WriteScope scope) action,
+          {{Duration timeout = Duration.zero}}) =>
+      tryRunLockScope(
+        tryLock: () => {ty_name}WriteScope.tryLock(that: this),
+        lock: () => {ty_name}WriteScope.lock(that: this),
+        timeout: timeout,
+        action: action,
+      );
+}}
+"
+    )
+}
@@ -1,5 +1,12 @@
+mod actor;
+mod background_task;
+mod dart_service;
+mod generic_instantiation;
+mod lock_scope;
+mod patch;
 mod proxy_enum;
 mod sorter;
+mod thread_pinned;
 pub(crate) mod trait_impl_enum;
 pub(crate) mod ui_related;
 pub(crate) mod utils;
@@ -20,6 +27,9 @@ pub(crate) fn execute(
         ..Default::default()
     };
 
+    generic_instantiation::generate(&mut pack, config_mir)?;
+    dumper.dump("0_generic_instantiation.json", &pack)?;
+
     let dumper_tentative_mir = dumper.with_add_name_prefix("1_tentative_mir/");
     let tentative_mir_pack = mir::parse(
         config_mir,
@@ -37,8 +47,26 @@ pub(crate) fn execute(
     ui_related::generate(&mut pack, config_mir)?;
     dumper.dump("4_ui_related.json", &pack)?;
 
+    dart_service::generate(&mut pack, &tentative_mir_pack, config_mir)?;
+    dumper.dump("5_dart_service.json", &pack)?;
+
+    background_task::generate(&mut pack, &tentative_mir_pack, config_mir)?;
+    dumper.dump("6_background_task.json", &pack)?;
+
+    thread_pinned::generate(&mut pack, &tentative_mir_pack, config_mir)?;
+    dumper.dump("7_thread_pinned.json", &pack)?;
+
+    actor::generate(&mut pack, &tentative_mir_pack, config_mir)?;
+    dumper.dump("8_actor.json", &pack)?;
+
+    lock_scope::generate(&mut pack, &tentative_mir_pack, config_mir)?;
+    dumper.dump("9_lock_scope.json", &pack)?;
+
+    patch::generate(&mut pack, &tentative_mir_pack, config_mir)?;
+    dumper.dump("10_patch.json", &pack)?;
+
     sorter::generate(&mut pack);
-    dumper.dump("5_sorter.json", &pack)?;
+    dumper.dump("11_sorter.json", &pack)?;
 
     Ok(pack)
 }
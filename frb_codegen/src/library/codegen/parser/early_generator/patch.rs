@@ -0,0 +1,264 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStruct;
+use crate::codegen::ir::mir::ident::MirIdent;
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::ir::mir::ty::structure::{MirStruct, MirStructIdent};
+use crate::codegen::parser::early_generator::utils::method_forwarder::parse_marked_type_names;
+use crate::codegen::parser::early_generator::utils::type_qualifier::TypeQualifier;
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::codegen::parser::mir::parser::ty::structure::structure_compute_default_opaque;
+use crate::utils::namespace::NamespacedName;
+use anyhow::{bail, ensure, Context};
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use syn::{Fields, PathArguments, Type, Visibility};
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    tentative_mir_pack: &MirPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let ty_names = parse_marked_type_names(&pack.hir_flat_pack, |attrs| attrs.patch())?;
+    let qualifier = TypeQualifier::new(&pack.hir_flat_pack);
+
+    let mut extra_codes = vec![];
+    for ty_name in &ty_names {
+        let hir_struct = (pack.hir_flat_pack.structs.iter())
+            .find(|x| &x.name == ty_name)
+            .with_context(|| {
+                format!(
+                    "`#[frb(patch)]` is only supported on structs, but `{}` is not",
+                    ty_name.name
+                )
+            })?;
+        let fields = parse_patch_fields(hir_struct, &qualifier)?;
+
+        // Translatable structs are copied to Dart, thus the patch is applied on the Dart side,
+        // while opaque ones live in Rust, thus the Rust `apply_xxx_patch` is called from Dart.
+        let translatable_struct =
+            match (tentative_mir_pack.struct_pool).get(&MirStructIdent(ty_name.clone())) {
+                Some(st) if !is_opaque(hir_struct, st)? => Some(st),
+                _ => None,
+            };
+
+        extra_codes.push(InjectExtraCodeBlock {
+            code: generate_rust(ty_name, &fields, translatable_struct),
+            should_parse: true,
+        });
+    }
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+fn is_opaque(hir_struct: &HirFlatStruct, st: &MirStruct) -> anyhow::Result<bool> {
+    Ok(
+        match FrbAttributes::parse(&hir_struct.src.attrs)?.opaque() {
+            Some(opaque) => opaque,
+            None => structure_compute_default_opaque(st, &st.name.namespace.crate_name()),
+        },
+    )
+}
+
+struct PatchField {
+    name: String,
+    /// Qualified, since the generated code lives in another module
+    ty: String,
+    is_option: bool,
+}
+
+/// Only the public fields are patchable, since the generated code lives in another module
+fn parse_patch_fields(
+    hir_struct: &HirFlatStruct,
+    qualifier: &TypeQualifier,
+) -> anyhow::Result<Vec<PatchField>> {
+    let ty_name = &hir_struct.name.name;
+    ensure!(
+        hir_struct.src.generics.params.is_empty(),
+        "`#[frb(patch)]` does not support generic struct `{ty_name}`"
+    );
+    let Fields::Named(fields) = &hir_struct.src.fields else {
+        bail!("`#[frb(patch)]` requires struct `{ty_name}` to have named fields");
+    };
+
+    let mut ans = vec![];
+    for field in &fields.named {
+        if matches!(field.vis, Visibility::Inherited)
+            || FrbAttributes::parse(&field.attrs)?.ignore()
+        {
+            continue;
+        }
+        ans.push(PatchField {
+            name: field.ident.as_ref().unwrap().to_string(),
+            ty: qualifier.qualify(&field.ty),
+            is_option: is_option(&field.ty, qualifier),
+        });
+    }
+    ensure!(
+        !ans.is_empty(),
+        "`#[frb(patch)]` requires struct `{ty_name}` to have at least one public field"
+    );
+    Ok(ans)
+}
+
+/// Whether the type is the standard `Option<T>`, instead of e.g. a user type that is also named `Option`
+fn is_option(ty: &Type, qualifier: &TypeQualifier) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    let segments = (type_path.path.segments.iter())
+        .map(|x| x.ident.to_string())
+        .collect_vec();
+    let is_std_option = match segments.iter().map(|x| x.as_str()).collect_vec()[..] {
+        ["Option"] => !qualifier.names.contains_key("Option"),
+        ["std" | "core", "option", "Option"] => true,
+        _ => false,
+    };
+    type_path.qself.is_none()
+        && is_std_option
+        && matches!(
+            &type_path.path.segments.last().unwrap().arguments,
+            PathArguments::AngleBracketed(args) if args.args.len() == 1
+        )
+}
+
+fn generate_rust(
+    ty_name: &NamespacedName,
+    fields: &[PatchField],
+    translatable_struct: Option<&MirStruct>,
+) -> String {
+    // The short name, thus it is recognized as the same type as the ones in the original API
+    let ty_short_name = &ty_name.name;
+    let patch_name = format!("{ty_short_name}Patch");
+    // Named after the type, thus it does not clash with e.g. an `apply` method of the user
+    let method_name = apply_method_name(ty_short_name);
+
+    let patch_fields = (fields.iter())
+        .map(|field| {
+            // An optional field keeps its type, thus a patch can set but not clear it
+            let ty = if field.is_option {
+                field.ty.clone()
+            } else {
+                format!("Option<{}>", field.ty)
+            };
+            format!("pub {}: {ty},", field.name)
+        })
+        .join("\n");
+    let assignments = (fields.iter())
+        .map(|field| {
+            let value = if field.is_option {
+                "Some(value)"
+            } else {
+                "value"
+            };
+            format!(
+                "if let Some(value) = patch.{name} {{ self.{name} = {value}; }}",
+                name = field.name
+            )
+        })
+        .join("\n");
+    let (maybe_dart_code, maybe_ignore) = match translatable_struct {
+        Some(translatable_struct) => (
+            generate_dart_apply_to(ty_short_name, fields, translatable_struct),
+            "#[flutter_rust_bridge::frb(ignore)]\n",
+        ),
+        None => ("".to_owned(), ""),
+    };
+
+    format!(
+        "
+/// The changes to a `{ty_short_name}`, where a `None` field is left untouched.
+{maybe_dart_code}#[derive(Default)]
+pub struct {patch_name} {{
+    {patch_fields}
+}}
+
+impl {ty_short_name} {{
+    /// Sets the fields that are present in the patch.
+    {maybe_ignore}pub fn {method_name}(&mut self, patch: {patch_name}) {{
+        {assignments}
+    }}
+}}
+"
+    )
+}
+
+fn apply_method_name(ty_short_name: &str) -> String {
+    format!("apply_{}_patch", ty_short_name.to_case(Case::Snake))
+}
+
+fn generate_dart_apply_to(
+    ty_short_name: &str,
+    fields: &[PatchField],
+    translatable_struct: &MirStruct,
+) -> String {
+    let args = (translatable_struct.fields.iter())
+        .map(|field| {
+            let name = field.name.dart_style();
+            if fields.iter().any(|x| x.name == field.name.rust_style()) {
+                // The fields of the patch type are not renamed
+                let patch_name = MirIdent::new(field.name.rust_style(), None).dart_style();
+                format!("{name}: {patch_name} ?? value.{name},")
+            } else {
+                format!("{name}: value.{name},")
+            }
+        })
+        .join("\n");
+
+    format!(
+        r##"#[flutter_rust_bridge::frb(dart_code = r#"
+  /// Returns a copy of [value], with the fields that are present in this patch replaced.
+  {ty_short_name} applyTo({ty_short_name} value) => {ty_short_name}(
+    {args}
+  );
+"#)]
+"##
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::namespace::Namespace;
+
+    #[test]
+    fn test_is_option() {
+        let qualifier = TypeQualifier {
+            names: Default::default(),
+        };
+        let is_option_str = |ty: &str| is_option(&syn::parse_str(ty).unwrap(), &qualifier);
+        assert!(is_option_str("Option<i32>"));
+        assert!(is_option_str("std::option::Option<i32>"));
+        assert!(is_option_str("::core::option::Option<i32>"));
+        assert!(!is_option_str("Option"));
+        assert!(!is_option_str("my_mod::Option<i32>"));
+        assert!(!is_option_str("Vec<Option<i32>>"));
+
+        let qualifier = TypeQualifier {
+            names: [(
+                "Option".to_owned(),
+                NamespacedName::new(
+                    Namespace::new_self_crate("api".to_owned()),
+                    "Option".to_owned(),
+                ),
+            )]
+            .into_iter()
+            .collect(),
+        };
+        assert!(!is_option(
+            &syn::parse_str("Option<i32>").unwrap(),
+            &qualifier
+        ));
+        assert!(is_option(
+            &syn::parse_str("std::option::Option<i32>").unwrap(),
+            &qualifier
+        ));
+    }
+}
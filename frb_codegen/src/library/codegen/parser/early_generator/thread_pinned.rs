@@ -0,0 +1,121 @@
+use crate::codegen::ir::early_generator::pack::IrEarlyGeneratorPack;
+use crate::codegen::ir::mir::func::{MirFunc, MirFuncMode, MirFuncOwnerInfoMethodMode};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::parser::early_generator::utils::method_forwarder::{
+    parse_forwarded_methods, parse_marked_type_names, ForwardedMethod,
+};
+use crate::codegen::parser::hir::flat::extra_code_injector::{
+    inject_extra_codes, InjectExtraCodeBlock,
+};
+use crate::codegen::parser::mir::internal_config::ParserMirInternalConfig;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::namespace::NamespacedName;
+use anyhow::ensure;
+use itertools::Itertools;
+
+pub(crate) fn generate(
+    pack: &mut IrEarlyGeneratorPack,
+    tentative_mir_pack: &MirPack,
+    config_mir: &ParserMirInternalConfig,
+) -> anyhow::Result<()> {
+    let output_namespace = &(config_mir.rust_input_namespace_pack).rust_output_path_namespace;
+
+    let names = parse_marked_type_names(&pack.hir_flat_pack, |attrs| attrs.thread_pinned())?;
+    // The web has no threads to pin the values to
+    ensure!(
+        names.is_empty() || !config_mir.web_enabled,
+        "`#[frb(thread_pinned)]` (used by {}) is not supported on the web, please set `web: false` in the config",
+        names.iter().map(|name| name.rust_style()).join(", "),
+    );
+
+    let extra_codes = (names.iter())
+        .map(|name| generate_wrapper(name, &tentative_mir_pack.funcs_all))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    inject_extra_codes(&mut pack.hir_flat_pack, output_namespace, &extra_codes)?;
+
+    Ok(())
+}
+
+fn generate_wrapper(
+    ty_name: &NamespacedName,
+    all_funcs: &[MirFunc],
+) -> anyhow::Result<InjectExtraCodeBlock> {
+    let wrapper_name = format!("{}ThreadPinned", ty_name.name);
+    let ty_path = ty_name.rust_style();
+
+    let methods = (parse_forwarded_methods(ty_name, all_funcs, "thread pinned")?.iter())
+        .map(|method| generate_method(method, ty_name, &ty_path))
+        .collect::<anyhow::Result<Vec<_>>>()?
+        .join("\n\n");
+
+    Ok(InjectExtraCodeBlock {
+        code: format!(
+            "
+#[flutter_rust_bridge::frb(opaque)]
+pub struct {wrapper_name}(flutter_rust_bridge::ThreadPinned<{ty_path}>);
+
+impl {wrapper_name} {{
+    {methods}
+}}
+"
+        ),
+        should_parse: true,
+    })
+}
+
+fn generate_method(
+    method: &ForwardedMethod,
+    ty_name: &NamespacedName,
+    ty_path: &str,
+) -> anyhow::Result<String> {
+    let ForwardedMethod {
+        func,
+        name,
+        params,
+        call_args,
+        ..
+    } = method;
+    ensure!(
+        !func.rust_async,
+        "Async method `{name}` of thread pinned type `{}` is not supported",
+        ty_name.name
+    );
+
+    let params_joined = params.join(", ");
+    let sync_attr = if func.mode == MirFuncMode::Sync {
+        "#[flutter_rust_bridge::frb(sync)]\n"
+    } else {
+        ""
+    };
+
+    Ok(match method.mode {
+        MirFuncOwnerInfoMethodMode::Instance => format!(
+            "{sync_attr}pub fn {name}({params}) -> {output} {{
+    self.0.run(move |that| that.{name}({call_args}))
+}}",
+            params = ["&self".to_owned()].iter().chain(params).join(", "),
+            output = func.output.rust_api_type(),
+        ),
+        // Constructors create the value on its dedicated thread
+        MirFuncOwnerInfoMethodMode::Static if method.returns_self => match &func.output.error {
+            Some(error) => format!(
+                "{sync_attr}pub fn {name}({params_joined}) -> Result<Self, {error}> {{
+    flutter_rust_bridge::ThreadPinned::try_new(move || {ty_path}::{name}({call_args})).map(Self)
+}}",
+                error = error.rust_api_type(),
+            ),
+            None => format!(
+                "{sync_attr}pub fn {name}({params_joined}) -> Self {{
+    Self(flutter_rust_bridge::ThreadPinned::new(move || {ty_path}::{name}({call_args})))
+}}"
+            ),
+        },
+        MirFuncOwnerInfoMethodMode::Static => format!(
+            "{sync_attr}pub fn {name}({params_joined}) -> {output} {{
+    {ty_path}::{name}({call_args})
+}}",
+            output = func.output.rust_api_type(),
+        ),
+    })
+}
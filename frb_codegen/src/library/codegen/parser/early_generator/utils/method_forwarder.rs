@@ -0,0 +1,107 @@
+use crate::codegen::ir::hir::flat::pack::HirFlatPack;
+use crate::codegen::ir::mir::func::{
+    compute_interest_name_of_owner_ty, MirFunc, MirFuncImplMode, MirFuncInput, MirFuncOwnerInfo,
+    MirFuncOwnerInfoMethodMode, OwnershipMode,
+};
+use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::namespace::NamespacedName;
+use anyhow::ensure;
+use itertools::Itertools;
+
+/// A method of a user type, which is forwarded by a generated wrapper type
+pub(crate) struct ForwardedMethod<'a> {
+    pub func: &'a MirFunc,
+    pub name: String,
+    pub mode: MirFuncOwnerInfoMethodMode,
+    /// Excluding the receiver
+    pub params: Vec<String>,
+    pub call_args: String,
+    pub returns_self: bool,
+}
+
+pub(crate) fn parse_marked_type_names(
+    pack: &HirFlatPack,
+    is_marked: impl Fn(&FrbAttributes) -> bool,
+) -> anyhow::Result<Vec<NamespacedName>> {
+    let names = (pack.structs.iter())
+        .map(|x| (&x.name, x.src.attrs.as_slice()))
+        .chain((pack.enums.iter()).map(|x| (&x.name, x.src.attrs.as_slice())))
+        .filter_map(|(name, attrs)| match FrbAttributes::parse(attrs) {
+            Ok(attrs) => is_marked(&attrs).then(|| Ok(name.clone())),
+            Err(e) => Some(Err(e)),
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(names.into_iter().sorted().collect())
+}
+
+/// Since the wrapped value lives elsewhere (e.g. another thread), receivers must be borrowed,
+/// while other arguments must be owned.
+pub(crate) fn parse_forwarded_methods<'a>(
+    ty_name: &NamespacedName,
+    all_funcs: &'a [MirFunc],
+    wrapper_kind: &str,
+) -> anyhow::Result<Vec<ForwardedMethod<'a>>> {
+    (all_funcs.iter())
+        .filter_map(|func| match &func.owner {
+            MirFuncOwnerInfo::Method(method)
+                if method.trait_def.is_none()
+                    && matches!(func.impl_mode, MirFuncImplMode::Normal)
+                    && method.owner_ty_name().as_ref() == Some(ty_name) =>
+            {
+                Some((func, method))
+            }
+            _ => None,
+        })
+        .sorted_by_key(|(func, _)| func.src_lineno_pseudo)
+        .map(|(func, method)| {
+            let name = method.actual_method_name.clone();
+            let ty = &ty_name.name;
+
+            let args = match method.mode {
+                MirFuncOwnerInfoMethodMode::Instance => {
+                    ensure!(
+                        compute_ownership_mode(&func.inputs[0]) != OwnershipMode::Owned,
+                        "Method `{name}` of {wrapper_kind} type `{ty}` must take `&self` or `&mut self`"
+                    );
+                    &func.inputs[1..]
+                }
+                MirFuncOwnerInfoMethodMode::Static => &func.inputs[..],
+            };
+            for arg in args {
+                ensure!(
+                    compute_ownership_mode(arg) == OwnershipMode::Owned,
+                    "Argument `{}` of `{ty}::{name}` must be owned to be forwarded by the {wrapper_kind} wrapper",
+                    arg.inner.name.rust_style(),
+                );
+            }
+
+            let returns_self =
+                compute_interest_name_of_owner_ty(&func.output.normal).as_ref() == Some(ty_name);
+            ensure!(
+                !(returns_self && matches!(method.mode, MirFuncOwnerInfoMethodMode::Instance)),
+                "Method `{name}` of {wrapper_kind} type `{ty}` cannot return the type itself"
+            );
+
+            Ok(ForwardedMethod {
+                func,
+                name,
+                mode: method.mode.clone(),
+                params: (args.iter())
+                    .map(|x| format!("{}: {}", x.inner.name.rust_style(), x.inner.ty.rust_api_type()))
+                    .collect(),
+                call_args: args.iter().map(|x| x.inner.name.rust_style()).join(", "),
+                returns_self,
+            })
+        })
+        .collect()
+}
+
+// For opaque types, the ownership is stored in the type instead
+pub(crate) fn compute_ownership_mode(input: &MirFuncInput) -> OwnershipMode {
+    match &input.inner.ty {
+        MirType::RustAutoOpaqueImplicit(ty) => ty.ownership_mode,
+        _ => input.ownership_mode.unwrap_or(OwnershipMode::Owned),
+    }
+}
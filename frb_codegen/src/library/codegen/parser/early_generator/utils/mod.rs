@@ -1 +1,3 @@
 pub(crate) mod lockable;
+pub(crate) mod method_forwarder;
+pub(crate) mod type_qualifier;
@@ -0,0 +1,65 @@
+use crate::codegen::ir::hir::flat::pack::HirFlatPack;
+use crate::utils::namespace::NamespacedName;
+use crate::utils::syn_utils::ty_to_string;
+use itertools::Itertools;
+use std::collections::HashMap;
+use syn::visit_mut::VisitMut;
+use syn::{Path, Type, TypePath};
+
+/// The generated Rust code does not import the modules of all the types, thus the structs and enums
+/// of the API are referred to by their full paths there
+pub(crate) struct TypeQualifier {
+    pub(crate) names: HashMap<String, NamespacedName>,
+}
+
+impl TypeQualifier {
+    pub(crate) fn new(hir_flat_pack: &HirFlatPack) -> Self {
+        Self {
+            names: (hir_flat_pack.structs.iter().map(|x| x.name.clone()))
+                .chain(hir_flat_pack.enums.iter().map(|x| x.name.clone()))
+                .map(|x| (x.name.clone(), x))
+                .collect(),
+        }
+    }
+
+    pub(crate) fn qualify(&self, ty: &Type) -> String {
+        let mut ty = ty.clone();
+        TypeQualifierVisitor(self).visit_type_mut(&mut ty);
+        ty_to_string(&ty)
+    }
+
+    pub(crate) fn qualify_args(&self, args: &[Type]) -> String {
+        args.iter().map(|ty| self.qualify(ty)).join(", ")
+    }
+
+    /// The struct or enum referred to by a path without generic arguments,
+    /// e.g. `proto::Person` or `Person` for `crate::proto::Person`
+    pub(crate) fn resolve(&self, path: &Path) -> Option<&NamespacedName> {
+        if !(path.segments.iter()).all(|segment| segment.arguments.is_empty()) {
+            return None;
+        }
+        let segments = (path.segments.iter())
+            .map(|segment| segment.ident.to_string())
+            .collect_vec();
+        let name = self.names.get(segments.last()?)?;
+        let full_segments = name
+            .rust_style()
+            .split("::")
+            .map(str::to_owned)
+            .collect_vec();
+        full_segments.ends_with(&segments).then_some(name)
+    }
+}
+
+struct TypeQualifierVisitor<'a>(&'a TypeQualifier);
+
+impl VisitMut for TypeQualifierVisitor<'_> {
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        syn::visit_mut::visit_type_path_mut(self, type_path);
+        if type_path.qself.is_none() {
+            if let Some(name) = self.0.resolve(&type_path.path) {
+                type_path.path = syn::parse_str(&name.rust_style()).unwrap();
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Merge the HIR of the crate expanded once per `rust_feature_sets`.
+//!
+//! `cargo expand` drops the items disabled by `#[cfg(...)]` and strips the attribute from the enabled ones,
+//! thus an item existing in only some of the expansions gets a `#[cfg(...)]` computed from those sets.
+
+use crate::codegen::ir::hir::flat::component::HirFlatComponent;
+use crate::codegen::ir::hir::flat::pack::HirFlatPack;
+use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStructOrEnum;
+use crate::codegen::ir::hir::misc::syn_item_struct_or_enum::SynItemStructOrEnum;
+use crate::codegen::parser::hir::internal_config::RustFeatureSet;
+use crate::utils::syn_utils::{parse_attribute, ty_to_string};
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+pub(crate) fn merge(
+    feature_sets: &[RustFeatureSet],
+    packs: Vec<HirFlatPack>,
+) -> anyhow::Result<HirFlatPack> {
+    // Things other than the items do not depend on the features, e.g. the extra code
+    let base = (packs.first())
+        .map(|pack| HirFlatPack {
+            existing_handler: pack.existing_handler.clone(),
+            extra_rust_output_code: pack.extra_rust_output_code.clone(),
+            extra_dart_output_code: pack.extra_dart_output_code.clone(),
+            skips: pack.skips.clone(),
+            ..Default::default()
+        })
+        .unwrap_or_default();
+
+    let (mut functions, mut enums, mut structs, mut traits, mut trait_impls, mut types) =
+        (vec![], vec![], vec![], vec![], vec![], vec![]);
+    let mut imports = vec![];
+    for pack in packs {
+        functions.push(pack.functions);
+        enums.push(pack.enums);
+        structs.push(pack.structs);
+        traits.push(pack.traits);
+        trait_impls.push(pack.trait_impls);
+        types.push(pack.types);
+        imports.push(pack.imports);
+    }
+
+    let mut ans = HirFlatPack {
+        traits: union(traits),
+        // Unlike the other items, different impls of a trait share the same `sort_key`
+        trait_impls: (merge_components_by(trait_impls, |x| {
+            (x.trait_name.clone(), ty_to_string(&x.impl_ty))
+        })
+        .into_iter())
+        .map(|(item, _)| item)
+        .collect_vec(),
+        types: union(types),
+        imports: union(imports),
+        ..base
+    };
+
+    for (mut function, set_indices) in merge_components(functions) {
+        if let Some(cfg) = compute_cfg(feature_sets, &set_indices) {
+            let attrs = function.item_fn.attrs_mut();
+            attrs.push(parse_attribute(&format!("#[cfg({cfg})]"))?);
+            attrs.push(parse_attribute(&format!(
+                "#[doc = {:?}]",
+                compute_availability_comment(feature_sets, &set_indices)
+            ))?);
+        }
+        ans.functions.push(function);
+    }
+    ans.enums = mark_struct_or_enums(feature_sets, enums)?;
+    ans.structs = mark_struct_or_enums(feature_sets, structs)?;
+
+    Ok(ans)
+}
+
+/// The attribute is kept so that the generated code of such types can be gated in the same way.
+fn mark_struct_or_enums<Item: SynItemStructOrEnum>(
+    feature_sets: &[RustFeatureSet],
+    items_of_sets: Vec<Vec<HirFlatStructOrEnum<Item>>>,
+) -> anyhow::Result<Vec<HirFlatStructOrEnum<Item>>> {
+    let mut ans = vec![];
+    for (mut item, set_indices) in merge_components(items_of_sets) {
+        if let Some(cfg) = compute_cfg(feature_sets, &set_indices) {
+            (item.src.attrs_mut()).push(parse_attribute(&format!("#[cfg({cfg})]"))?);
+        }
+        ans.push(item);
+    }
+    Ok(ans)
+}
+
+fn union<SK: Ord, T: HirFlatComponent<SK>>(items_of_sets: Vec<Vec<T>>) -> Vec<T> {
+    (merge_components(items_of_sets).into_iter())
+        .map(|(item, _)| item)
+        .collect_vec()
+}
+
+/// Returns each distinct item together with the indices of the feature sets containing it
+fn merge_components<SK: Ord, T: HirFlatComponent<SK>>(
+    items_of_sets: Vec<Vec<T>>,
+) -> Vec<(T, Vec<usize>)> {
+    merge_components_by(items_of_sets, |item| item.sort_key())
+}
+
+fn merge_components_by<K: Ord, T>(
+    items_of_sets: Vec<Vec<T>>,
+    key: impl Fn(&T) -> K,
+) -> Vec<(T, Vec<usize>)> {
+    let mut ans: Vec<(T, Vec<usize>)> = vec![];
+    let mut index_of_key: BTreeMap<K, usize> = BTreeMap::new();
+    for (set_index, items) in items_of_sets.into_iter().enumerate() {
+        for item in items {
+            match index_of_key.get(&key(&item)) {
+                Some(&index) => {
+                    let set_indices = &mut ans[index].1;
+                    if !set_indices.contains(&set_index) {
+                        set_indices.push(set_index);
+                    }
+                }
+                None => {
+                    index_of_key.insert(key(&item), ans.len());
+                    ans.push((item, vec![set_index]));
+                }
+            }
+        }
+    }
+    ans
+}
+
+/// `None` when the item exists in all feature sets
+fn compute_cfg(feature_sets: &[RustFeatureSet], set_indices: &[usize]) -> Option<String> {
+    if set_indices.len() == feature_sets.len() {
+        return None;
+    }
+
+    let all_features = (feature_sets.iter())
+        .flat_map(|set| set.features.iter())
+        .unique()
+        .sorted()
+        .collect_vec();
+    let conditions = (set_indices.iter())
+        .map(|&index| {
+            let set = &feature_sets[index];
+            let predicates = (all_features.iter())
+                .map(|feature| {
+                    let predicate = format!("feature = {feature:?}");
+                    if set.features.contains(feature) {
+                        predicate
+                    } else {
+                        format!("not({predicate})")
+                    }
+                })
+                .collect_vec();
+            join_predicates("all", predicates)
+        })
+        .collect_vec();
+    Some(join_predicates("any", conditions))
+}
+
+fn join_predicates(op: &str, predicates: Vec<String>) -> String {
+    match predicates.len() {
+        1 => predicates.into_iter().next().unwrap(),
+        _ => format!("{op}({})", predicates.join(", ")),
+    }
+}
+
+fn compute_availability_comment(feature_sets: &[RustFeatureSet], set_indices: &[usize]) -> String {
+    let names = (set_indices.iter())
+        .map(|&index| format!("`{}`", feature_sets[index].name))
+        .join(" or ");
+    let noun = if set_indices.len() == 1 {
+        "set"
+    } else {
+        "sets"
+    };
+    format!(" Only available when the Rust crate is built with the feature {noun} {names}.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::ir::hir::flat::trait_impl::HirFlatTraitImpl;
+
+    fn create_feature_set(name: &str, features: &[&str]) -> RustFeatureSet {
+        RustFeatureSet {
+            name: name.to_owned(),
+            features: features.iter().map(|x| x.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_compute_cfg() {
+        let sets = vec![
+            create_feature_set("lite", &[]),
+            create_feature_set("pro", &["pro"]),
+            create_feature_set("full", &["pro", "extra"]),
+        ];
+        assert_eq!(compute_cfg(&sets, &[0, 1, 2]), None);
+        assert_eq!(
+            compute_cfg(&sets, &[2]).as_deref(),
+            Some(r#"all(feature = "extra", feature = "pro")"#)
+        );
+        assert_eq!(
+            compute_cfg(&sets, &[0, 1]).as_deref(),
+            Some(
+                r#"any(all(not(feature = "extra"), not(feature = "pro")), all(not(feature = "extra"), feature = "pro"))"#
+            )
+        );
+
+        let sets = vec![
+            create_feature_set("lite", &[]),
+            create_feature_set("pro", &["pro"]),
+        ];
+        assert_eq!(
+            compute_cfg(&sets, &[1]).as_deref(),
+            Some(r#"feature = "pro""#)
+        );
+    }
+
+    #[test]
+    fn test_merge_trait_impls() -> anyhow::Result<()> {
+        let create_pack = |impl_tys: &[&str]| HirFlatPack {
+            trait_impls: (impl_tys.iter())
+                .map(|impl_ty| HirFlatTraitImpl {
+                    trait_name: "Repository".to_owned(),
+                    impl_ty: syn::parse_str(impl_ty).unwrap(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let sets = vec![
+            create_feature_set("lite", &[]),
+            create_feature_set("pro", &["pro"]),
+        ];
+        let ans = merge(
+            &sets,
+            vec![
+                create_pack(&["MemoryRepository", "SqlRepository"]),
+                create_pack(&["MemoryRepository", "SqlRepository", "CloudRepository"]),
+            ],
+        )?;
+        assert_eq!(
+            (ans.trait_impls.iter())
+                .map(|x| ty_to_string(&x.impl_ty))
+                .collect_vec(),
+            vec!["MemoryRepository", "SqlRepository", "CloudRepository"]
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::codegen::ir::hir::flat::pack::HirFlatPack;
 use crate::codegen::ir::hir::flat::struct_or_enum::{HirFlatEnum, HirFlatStruct};
 use crate::codegen::ir::hir::flat::traits::HirFlatTrait;
+use crate::utils::namespace::NamespacedName;
 use log::debug;
 use std::collections::HashMap;
 use std::fmt::{Debug, Display};
@@ -23,6 +24,15 @@ impl HirFlatPack {
     pub(crate) fn types_map(&self) -> HashMap<String, Type> {
         vec_to_map_with_warn(&self.types, |x| (x.ident.clone(), x.target.clone()))
     }
+
+    pub(crate) fn imports_map(&self) -> HashMap<NamespacedName, String> {
+        (self.imports.iter())
+            .map(|x| {
+                let name = NamespacedName::new(x.namespace.clone(), x.ident.clone());
+                (name, x.path.clone())
+            })
+            .collect()
+    }
 }
 
 fn vec_to_map_with_warn<'a, T, K: Eq + Hash + Display, V: Debug + 'a>(
@@ -9,5 +9,6 @@ pub(crate) fn parse_syn_item_fn(item_fn: ItemFn, meta: &HirNaiveFlatItemMeta) ->
         owner: HirFlatFunctionOwner::Function,
         item_fn: GeneralizedItemFn::ItemFn(item_fn),
         sources: meta.sources.clone(),
+        rust_call_name: None,
     }
 }
@@ -41,6 +41,7 @@ fn parse_functions(
             },
             item_fn: GeneralizedItemFn::ImplItemFn(add_attrs(impl_item_fn, &attrs_item_impl)),
             sources: meta.sources.clone(),
+            rust_call_name: None,
         })
         .collect_vec()
 }
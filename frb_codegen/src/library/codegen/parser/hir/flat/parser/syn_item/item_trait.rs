@@ -37,6 +37,7 @@ fn parse_functions(
             },
             item_fn: GeneralizedItemFn::TraitItemFn(trait_item_fn),
             sources: meta.sources.clone(),
+            rust_call_name: None,
         })
         .collect_vec()
 }
@@ -0,0 +1,117 @@
+use crate::codegen::ir::hir::flat::import::HirFlatImport;
+use crate::codegen::ir::hir::naive_flat::item::HirNaiveFlatItemMeta;
+use crate::utils::namespace::Namespace;
+use itertools::Itertools;
+use syn::{ItemUse, UseTree};
+
+/// Only the self crate is considered, since the imports are used to resolve the types in the API
+pub(crate) fn parse_syn_item_use(
+    item_use: &ItemUse,
+    meta: &HirNaiveFlatItemMeta,
+) -> Vec<HirFlatImport> {
+    if !meta.namespace.crate_name().is_self_crate() {
+        return vec![];
+    }
+
+    let mut entries = vec![];
+    flatten_use_tree(&item_use.tree, vec![], &mut entries);
+    (entries.into_iter())
+        .filter(|(ident, _)| ident != "_")
+        .filter_map(|(ident, path)| {
+            Some(HirFlatImport {
+                namespace: meta.namespace.clone(),
+                ident,
+                path: absolutize_path(path, &meta.namespace)?,
+            })
+        })
+        .collect()
+}
+
+/// Glob imports are skipped, since the names inside are unknown
+fn flatten_use_tree(tree: &UseTree, prefix: Vec<String>, ans: &mut Vec<(String, Vec<String>)>) {
+    let join = |ident: String| {
+        // e.g. the `self` in `use a::b::{self};`, while a leading `self` is resolved later
+        if ident == "self" && !prefix.is_empty() {
+            prefix.clone()
+        } else {
+            prefix.iter().cloned().chain([ident]).collect()
+        }
+    };
+    match tree {
+        UseTree::Path(x) => flatten_use_tree(&x.tree, join(x.ident.to_string()), ans),
+        UseTree::Name(x) => {
+            let path = join(x.ident.to_string());
+            if let Some(ident) = path.last() {
+                ans.push((ident.clone(), path));
+            }
+        }
+        UseTree::Rename(x) => ans.push((x.rename.to_string(), join(x.ident.to_string()))),
+        UseTree::Glob(_) => {}
+        UseTree::Group(x) => {
+            for item in &x.items {
+                flatten_use_tree(item, prefix.clone(), ans);
+            }
+        }
+    }
+}
+
+/// e.g. `crate::api::Person` for `super::Person` in `crate::api::sub`
+fn absolutize_path(path: Vec<String>, namespace: &Namespace) -> Option<String> {
+    let mut ans = match path.first()?.as_str() {
+        "self" | "super" => namespace
+            .path()
+            .into_iter()
+            .map(str::to_owned)
+            .collect_vec(),
+        _ => vec![],
+    };
+    let mut segments = path.into_iter().peekable();
+    if segments.next_if(|x| x == "self").is_none() {
+        while segments.next_if(|x| x == "super").is_some() {
+            ans.pop()?;
+        }
+    }
+    ans.extend(segments);
+    Some(ans.join(Namespace::SEP))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_syn_item_use() -> anyhow::Result<()> {
+        let meta = HirNaiveFlatItemMeta {
+            namespace: Namespace::new_self_crate("api::sub".to_owned()),
+            sources: vec![],
+            is_module_public: true,
+        };
+        let parse = |code: &str| -> anyhow::Result<Vec<(String, String)>> {
+            Ok(parse_syn_item_use(&syn::parse_str(code)?, &meta)
+                .into_iter()
+                .map(|x| (x.ident, x.path))
+                .collect())
+        };
+        let entry = |ident: &str, path: &str| (ident.to_owned(), path.to_owned());
+
+        assert_eq!(
+            parse("use rust_decimal::{Decimal, prelude::*, Error as DecimalError};")?,
+            vec![
+                entry("Decimal", "rust_decimal::Decimal"),
+                entry("DecimalError", "rust_decimal::Error"),
+            ]
+        );
+        assert_eq!(
+            parse("use super::{Person, super::Mood};")?,
+            vec![
+                entry("Person", "crate::api::Person"),
+                entry("Mood", "crate::Mood"),
+            ]
+        );
+        assert_eq!(
+            parse("use self::inner::{self, Item as _};")?,
+            vec![entry("inner", "crate::api::sub::inner")]
+        );
+        Ok(())
+    }
+}
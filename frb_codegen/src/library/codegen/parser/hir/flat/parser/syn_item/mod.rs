@@ -3,6 +3,7 @@ pub(crate) mod item_impl;
 pub(crate) mod item_struct_or_enum;
 pub(crate) mod item_trait;
 pub(crate) mod item_type;
+pub(crate) mod item_use;
 
 use crate::codegen::ir::hir::flat::pack::HirFlatPack;
 use crate::codegen::ir::hir::naive_flat::item::HirNaiveFlatItemMeta;
@@ -13,6 +14,7 @@ use crate::codegen::parser::hir::flat::parser::syn_item::item_struct_or_enum::{
 };
 use crate::codegen::parser::hir::flat::parser::syn_item::item_trait::parse_syn_item_trait;
 use crate::codegen::parser::hir::flat::parser::syn_item::item_type::parse_syn_item_type;
+use crate::codegen::parser::hir::flat::parser::syn_item::item_use::parse_syn_item_use;
 
 pub(crate) fn parse_syn_item(
     item: syn::Item,
@@ -26,6 +28,7 @@ pub(crate) fn parse_syn_item(
         syn::Item::Fn(x) => target.functions.push(parse_syn_item_fn(x, meta)),
         syn::Item::Impl(x) => parse_syn_item_impl(target, x, meta),
         syn::Item::Trait(x) => parse_syn_item_trait(target, x, meta),
+        syn::Item::Use(x) => target.imports.extend(parse_syn_item_use(&x, meta)),
         _ => {}
     }
     Ok(())
@@ -31,6 +31,7 @@ fn compute_functions(trait_impl: &HirFlatTraitImpl, pack: &HirFlatPack) -> Vec<H
                 vec![HirGenerationSource::CopyFromTraitDef],
             ]),
             item_fn: f.item_fn.clone(),
+            rust_call_name: None,
         })
         .collect_vec()
 }
@@ -1,14 +1,24 @@
 use crate::codegen::ir::hir::flat::pack::HirFlatPack;
+use crate::codegen::ir::hir::flat::struct_or_enum::HirFlatStructOrEnum;
+use crate::codegen::ir::hir::misc::syn_item_struct_or_enum::SynItemStructOrEnum;
 use crate::codegen::misc::SELF_CRATE_THIRD_PARTY_NAMESPACE;
 use crate::codegen::parser::hir::internal_config::ParserHirInternalConfig;
 use crate::utils::namespace::Namespace;
+use anyhow::ensure;
 use itertools::Itertools;
+use std::collections::HashSet;
+use syn::visit::Visit;
 
 pub(crate) fn transform(
     mut pack: HirFlatPack,
     config: &ParserHirInternalConfig,
 ) -> anyhow::Result<HirFlatPack> {
     filter_function(&mut pack, config);
+    if config.api_version.is_some() {
+        let referenced_paths = compute_interest_referenced_paths(&pack, config);
+        filter_shadowed_by_api_version(&mut pack.structs, config, &referenced_paths)?;
+        filter_shadowed_by_api_version(&mut pack.enums, config, &referenced_paths)?;
+    }
     Ok(pack)
 }
 
@@ -21,6 +31,78 @@ fn filter_function(pack: &mut HirFlatPack, config: &ParserHirInternalConfig) {
         .collect_vec();
 }
 
+// When several versions of the API live in the same crate, each version usually defines
+// its own types with the same names, so the ones in the version being generated win.
+// If the version being generated refers to the other one by its path (e.g. `v1::User`),
+// it cannot be dropped, while types are looked up by their names, thus it is an error.
+fn filter_shadowed_by_api_version<Item: SynItemStructOrEnum>(
+    items: &mut Vec<HirFlatStructOrEnum<Item>>,
+    config: &ParserHirInternalConfig,
+    referenced_paths: &[Vec<String>],
+) -> anyhow::Result<()> {
+    let interest_names: HashSet<String> = (items.iter())
+        .filter(|x| is_interest_module(&x.name.namespace, config))
+        .map(|x| x.name.name.clone())
+        .collect();
+    let is_shadowed = |x: &HirFlatStructOrEnum<Item>| {
+        x.name.namespace.crate_name().is_self_crate()
+            && !is_interest_module(&x.name.namespace, config)
+            && interest_names.contains(&x.name.name)
+    };
+
+    let referenced_shadowed_names = (items.iter())
+        .filter(|x| is_shadowed(x))
+        .map(|x| x.name.rust_style())
+        .filter(|name| {
+            let full_path = name.split("::").map(str::to_owned).collect_vec();
+            (referenced_paths.iter()).any(|path| full_path.ends_with(path))
+        })
+        .collect_vec();
+    ensure!(
+        referenced_shadowed_names.is_empty(),
+        "Types {} are used by the API version being generated, but have the same names as types in it, \
+        please rename them",
+        referenced_shadowed_names.iter().map(|x| format!("`{x}`")).join(", "),
+    );
+
+    items.retain(|x| !is_shadowed(x));
+    Ok(())
+}
+
+/// Paths with at least two segments (excluding `crate`, `self` and `super`) used in the interest
+/// modules, which refer to types outside when the last segment alone does not
+fn compute_interest_referenced_paths(
+    pack: &HirFlatPack,
+    config: &ParserHirInternalConfig,
+) -> Vec<Vec<String>> {
+    let mut visitor = PathCollector(vec![]);
+    for f in (pack.functions.iter()).filter(|f| is_interest_module(&f.namespace, config)) {
+        visitor.visit_signature(f.item_fn.sig());
+    }
+    for x in (pack.structs.iter()).filter(|x| is_interest_module(&x.name.namespace, config)) {
+        visitor.visit_item_struct(&x.src);
+    }
+    for x in (pack.enums.iter()).filter(|x| is_interest_module(&x.name.namespace, config)) {
+        visitor.visit_item_enum(&x.src);
+    }
+    visitor.0
+}
+
+struct PathCollector(Vec<Vec<String>>);
+
+impl<'ast> Visit<'ast> for PathCollector {
+    fn visit_path(&mut self, path: &'ast syn::Path) {
+        syn::visit::visit_path(self, path);
+        let segments = (path.segments.iter())
+            .map(|segment| segment.ident.to_string())
+            .skip_while(|segment| ["crate", "self", "super"].contains(&segment.as_str()))
+            .collect_vec();
+        if segments.len() >= 2 {
+            self.0.push(segments);
+        }
+    }
+}
+
 fn is_interest_module(namespace: &Namespace, config: &ParserHirInternalConfig) -> bool {
     (config.rust_input_namespace_pack).is_interest(namespace)
         || SELF_CRATE_THIRD_PARTY_NAMESPACE.is_prefix_of(namespace)
@@ -7,5 +7,19 @@ use std::path::PathBuf;
 pub(crate) struct ParserHirInternalConfig {
     pub rust_input_namespace_pack: RustInputNamespacePack,
     pub rust_crate_dir: PathBuf,
+    /// Local crates in `rust_input`, which are always parsed and bridged like the self crate
+    pub first_party_crate_names: Vec<CrateName>,
     pub third_party_crate_names: Vec<CrateName>,
+    pub lazy_third_party: bool,
+    pub api_version: Option<String>,
+    pub cache_directory: Option<PathBuf>,
+    pub rust_output_path: PathBuf,
+    /// Empty means expanding once with the default features
+    pub rust_feature_sets: Vec<RustFeatureSet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct RustFeatureSet {
+    pub name: String,
+    pub features: Vec<String>,
 }
@@ -1,3 +1,4 @@
+pub(crate) mod feature_sets;
 pub(crate) mod flat;
 pub(crate) mod internal_config;
 pub(crate) mod naive_flat;
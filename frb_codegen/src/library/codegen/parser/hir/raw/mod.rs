@@ -2,31 +2,174 @@ use crate::codegen::dumper::Dumper;
 use crate::codegen::ir::hir::raw::crates::HirRawCrate;
 use crate::codegen::ir::hir::raw::pack::HirRawPack;
 use crate::codegen::parser::hir::internal_config::ParserHirInternalConfig;
-use crate::library::commands::cargo_expand::run_cargo_expand;
+use crate::library::commands::cargo_expand::{
+    compute_cargo_expand_fingerprint, run_cargo_expand, CargoExpandCache,
+};
 use crate::utils::crate_name::CrateName;
-use itertools::concat;
+use itertools::{concat, Itertools};
+use log::info;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
+mod referenced_crates;
+
+/// The expanded crates of the previous run in `--watch` mode, which are reused if unchanged
+#[derive(Default)]
+pub(crate) struct HirRawCache {
+    /// Keyed by the crate and the features it is expanded with
+    crates: HashMap<(CrateName, Vec<String>), HirRawCrate>,
+    /// Shared by all crates and feature sets of one run, since computing it reads the whole workspace
+    cargo_expand_fingerprint: Option<String>,
+}
+
+impl HirRawCache {
+    /// `changed_paths` being `None` means anything may have changed
+    pub(crate) fn invalidate(&mut self, changed_paths: Option<&[PathBuf]>, rust_crate_dir: &Path) {
+        self.cargo_expand_fingerprint = None;
+
+        // Other paths, e.g. shared crates in `watch_paths`, may be used by the third party crates
+        let self_crate_src_dir = rust_crate_dir.join("src");
+        let only_self_crate_changed = changed_paths.is_some_and(|paths| {
+            (paths.iter()).all(|path| {
+                (path.canonicalize().unwrap_or(path.to_owned())).starts_with(&self_crate_src_dir)
+            })
+        });
+        if only_self_crate_changed {
+            self.crates
+                .retain(|(crate_name, _), _| !crate_name.is_self_crate());
+        } else {
+            self.crates.clear();
+        }
+    }
+}
+
+/// `features` are the ones of the self crate, e.g. from one of the `rust_feature_sets`
 pub(crate) fn parse(
     config: &ParserHirInternalConfig,
+    features: &[String],
     dumper: &Dumper,
+    cache: &mut HirRawCache,
 ) -> anyhow::Result<HirRawPack> {
-    let crates = concat([
+    let first_party_crate_names = concat([
         vec![CrateName::self_crate()],
-        config.third_party_crate_names.clone(),
-    ])
-    .iter()
-    .map(|crate_name| {
-        Ok(HirRawCrate {
-            name: crate_name.to_owned(),
-            syn_file: run_cargo_expand(
-                &config.rust_crate_dir,
-                (!crate_name.is_self_crate()).then_some(crate_name),
-                dumper,
-            )?,
-        })
+        config.first_party_crate_names.clone(),
+    ]);
+    let first_party_crates =
+        parse_crates(config, &first_party_crate_names, features, dumper, cache)?;
+
+    let third_party_crate_names = compute_third_party_crate_names(config, &first_party_crates);
+    let third_party_crates =
+        parse_crates(config, &third_party_crate_names, features, dumper, cache)?;
+
+    Ok(HirRawPack {
+        crates: concat([first_party_crates, third_party_crates]),
     })
-    .collect::<anyhow::Result<Vec<_>>>()?
-    .into_iter()
-    .collect();
-    Ok(HirRawPack { crates })
+}
+
+fn parse_crates(
+    config: &ParserHirInternalConfig,
+    crate_names: &[CrateName],
+    features: &[String],
+    dumper: &Dumper,
+    cache: &mut HirRawCache,
+) -> anyhow::Result<Vec<HirRawCrate>> {
+    let cache_keys = (crate_names.iter())
+        .map(|crate_name| compute_cache_key(crate_name, features))
+        .collect_vec();
+
+    let expanding_cache_keys = (cache_keys.iter())
+        .filter(|cache_key| !cache.crates.contains_key(cache_key))
+        .collect_vec();
+    if config.cache_directory.is_some()
+        && !expanding_cache_keys.is_empty()
+        && cache.cargo_expand_fingerprint.is_none()
+    {
+        // The generated file is skipped by the parser, thus its changes do not matter
+        cache.cargo_expand_fingerprint = Some(compute_cargo_expand_fingerprint(
+            &config.rust_crate_dir,
+            std::slice::from_ref(&config.rust_output_path),
+        )?);
+    }
+
+    // Each `cargo expand` is a separate process, thus the crates are expanded in parallel.
+    // The parsing below is sequential, since the syntax trees of `syn` are not `Send`.
+    let expanded_crates = expanding_cache_keys
+        .into_par_iter()
+        .map(|(crate_name, features)| {
+            Ok((
+                crate_name.to_owned(),
+                run_cargo_expand(
+                    &config.rust_crate_dir,
+                    (!crate_name.is_self_crate()).then_some(crate_name),
+                    features,
+                    (config.cache_directory.as_deref())
+                        .zip(cache.cargo_expand_fingerprint.as_deref())
+                        .map(|(directory, fingerprint)| CargoExpandCache {
+                            directory,
+                            fingerprint,
+                        })
+                        .as_ref(),
+                )?,
+            ))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let mut expanded_crates: HashMap<_, _> = expanded_crates.into_iter().collect();
+
+    (cache_keys.into_iter())
+        .map(|cache_key| {
+            if let Some(expanded) = expanded_crates.remove(&cache_key.0) {
+                let hir_raw_crate = HirRawCrate {
+                    name: cache_key.0.clone(),
+                    syn_file: expanded.parse(dumper)?,
+                };
+                (cache.crates).insert(cache_key, hir_raw_crate.clone());
+                Ok(hir_raw_crate)
+            } else {
+                info!(
+                    "Reuse the expanded code of unchanged crate `{}`",
+                    cache_key.0.raw()
+                );
+                Ok(cache.crates[&cache_key].clone())
+            }
+        })
+        .collect()
+}
+
+/// The features are those of the self crate, which other crates may not have
+fn compute_cache_key(crate_name: &CrateName, features: &[String]) -> (CrateName, Vec<String>) {
+    let features = if crate_name.is_self_crate() {
+        features.to_owned()
+    } else {
+        vec![]
+    };
+    (crate_name.to_owned(), features)
+}
+
+fn compute_third_party_crate_names(
+    config: &ParserHirInternalConfig,
+    first_party_crates: &[HirRawCrate],
+) -> Vec<CrateName> {
+    if !config.lazy_third_party {
+        return config.third_party_crate_names.clone();
+    }
+
+    let referenced_crate_names = (first_party_crates.iter())
+        .flat_map(|hir_raw_crate| {
+            referenced_crates::compute_referenced_crate_names(
+                hir_raw_crate,
+                &config.rust_input_namespace_pack,
+            )
+        })
+        .collect::<HashSet<_>>();
+    (config.third_party_crate_names.iter())
+        .filter(|crate_name| {
+            let referenced = referenced_crate_names.contains(&crate_name.namespace().joined_path);
+            if !referenced {
+                info!("Skip parsing third-party crate `{}`, since none of its items are referenced by the first-party crates", crate_name.raw());
+            }
+            referenced
+        })
+        .cloned()
+        .collect_vec()
 }
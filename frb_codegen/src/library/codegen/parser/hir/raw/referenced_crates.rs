@@ -0,0 +1,356 @@
+use crate::codegen::ir::hir::raw::crates::HirRawCrate;
+use crate::codegen::misc::SELF_CRATE_THIRD_PARTY_NAMESPACE;
+use crate::codegen::parser::mir::internal_config::RustInputNamespacePack;
+use crate::utils::namespace::Namespace;
+use itertools::{concat, Itertools};
+use std::collections::HashSet;
+use syn::visit::Visit;
+
+/// A cheap pre-scan (no cargo-expand needed) to find out which crates are referenced
+/// by the items to be bridged, i.e. the ones in `rust_input` and the third party override folder.
+pub(super) fn compute_referenced_crate_names(
+    hir_raw_crate: &HirRawCrate,
+    rust_input_namespace_pack: &RustInputNamespacePack,
+) -> HashSet<String> {
+    let crate_namespace = hir_raw_crate.name.namespace();
+    let mut interest = Interest {
+        prefixes: concat([
+            rust_input_namespace_pack
+                .rust_input_namespace_prefixes
+                .clone(),
+            vec![SELF_CRATE_THIRD_PARTY_NAMESPACE.clone()],
+        ]),
+        modules: vec![],
+    };
+
+    // The `pub use` re-exports bring items of other modules into the bridged ones,
+    // thus those modules are scanned as well, until no new ones are found
+    loop {
+        let mut visitor = PathRootVisitor {
+            crate_namespace: crate_namespace.clone(),
+            namespace: crate_namespace.clone(),
+            roots: HashSet::new(),
+            reexports: vec![],
+        };
+        visit_items(
+            &hir_raw_crate.syn_file.items,
+            &crate_namespace,
+            &interest,
+            &mut visitor,
+        );
+
+        let reexports = (visitor.reexports.into_iter())
+            .filter(|reexport| !interest.contains(reexport))
+            .unique()
+            .collect_vec();
+        if reexports.is_empty() {
+            return visitor.roots;
+        }
+        for reexport in reexports {
+            match reexport {
+                Reexport::Module(namespace) => interest.prefixes.push(namespace),
+                Reexport::Item { module, item } => {
+                    // The item may be a module as well, e.g. `pub use crate::models;`
+                    interest.prefixes.push(module.join(&item));
+                    interest.modules.push(module);
+                }
+            }
+        }
+    }
+}
+
+/// The namespaces whose items may be bridged
+struct Interest {
+    /// Everything inside these namespaces, including the nested modules
+    prefixes: Vec<Namespace>,
+    /// Only the items directly inside these modules, e.g. the parent of a re-exported item
+    modules: Vec<Namespace>,
+}
+
+impl Interest {
+    fn contains(&self, reexport: &Reexport) -> bool {
+        match reexport {
+            Reexport::Module(namespace) => self.is_deep(namespace),
+            Reexport::Item { module, item } => {
+                self.is_deep(&module.join(item))
+                    && (self.is_deep(module) || self.modules.contains(module))
+            }
+        }
+    }
+
+    fn is_deep(&self, namespace: &Namespace) -> bool {
+        (self.prefixes.iter()).any(|x| x.is_prefix_of(namespace))
+    }
+
+    fn may_contain_descendant(&self, namespace: &Namespace) -> bool {
+        (self.prefixes.iter().chain(self.modules.iter()))
+            .any(|x| x.is_prefix_of(namespace) || namespace.is_prefix_of(x))
+    }
+}
+
+fn visit_items(
+    items: &[syn::Item],
+    namespace: &Namespace,
+    interest: &Interest,
+    visitor: &mut PathRootVisitor,
+) {
+    visitor.namespace = namespace.clone();
+    if interest.is_deep(namespace) {
+        for item in items {
+            // e.g. `crate::third_party::web_audio_api` overrides things in `web_audio_api` crate
+            if namespace == &*SELF_CRATE_THIRD_PARTY_NAMESPACE {
+                if let syn::Item::Mod(item_mod) = item {
+                    visitor.roots.insert(item_mod.ident.to_string());
+                }
+            }
+            visitor.visit_item(item);
+        }
+        return;
+    }
+
+    if interest.modules.contains(namespace) {
+        for item in items {
+            if !matches!(item, syn::Item::Mod(_)) {
+                visitor.visit_item(item);
+            }
+        }
+    }
+
+    for item in items {
+        if let syn::Item::Mod(item_mod) = item {
+            if let Some((_, content)) = &item_mod.content {
+                let child_namespace = namespace.join(&item_mod.ident.to_string());
+                if interest.may_contain_descendant(&child_namespace) {
+                    visit_items(content, &child_namespace, interest, visitor);
+                }
+            }
+        }
+    }
+}
+
+/// Collects the first segment of every path, e.g. `a` for `a::b::C`
+struct PathRootVisitor {
+    crate_namespace: Namespace,
+    /// The module of the items being visited
+    namespace: Namespace,
+    roots: HashSet<String>,
+    /// The modules and items of this crate re-exported by `pub use`
+    reexports: Vec<Reexport>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Reexport {
+    /// e.g. `pub use crate::models::*;`
+    Module(Namespace),
+    /// e.g. `pub use crate::models::Person;`
+    Item { module: Namespace, item: String },
+}
+
+impl PathRootVisitor {
+    fn visit_use_tree_roots(&mut self, tree: &syn::UseTree) {
+        match tree {
+            syn::UseTree::Path(path) => {
+                self.roots.insert(path.ident.to_string());
+            }
+            // e.g. `use crate_a;` and `use crate_a as a;`
+            syn::UseTree::Name(name) => {
+                self.roots.insert(name.ident.to_string());
+            }
+            syn::UseTree::Rename(rename) => {
+                self.roots.insert(rename.ident.to_string());
+            }
+            syn::UseTree::Group(group) => {
+                for item in &group.items {
+                    self.visit_use_tree_roots(item);
+                }
+            }
+            syn::UseTree::Glob(_) => {}
+        }
+    }
+
+    fn visit_use_tree_reexports(&mut self, tree: &syn::UseTree, module: Option<Namespace>) {
+        match tree {
+            syn::UseTree::Path(path) => {
+                let ident = path.ident.to_string();
+                let module = match (module, ident.as_str()) {
+                    (None, "crate") => Some(self.crate_namespace.clone()),
+                    (None, "self") => Some(self.namespace.clone()),
+                    (None, "super") => parent_namespace(&self.namespace),
+                    (Some(module), "super") => parent_namespace(&module),
+                    (Some(module), _) => Some(module.join(&ident)),
+                    // Other crates are covered by the roots
+                    (None, _) => None,
+                };
+                if let Some(module) = module {
+                    self.visit_use_tree_reexports(&path.tree, Some(module));
+                }
+            }
+            syn::UseTree::Name(syn::UseName { ident })
+            | syn::UseTree::Rename(syn::UseRename { ident, .. }) => {
+                let Some(module) = module else { return };
+                // e.g. `pub use crate::models::{self, Person};`
+                let reexport = if ident == "self" {
+                    let item = module.path().last().unwrap().to_string();
+                    let Some(module) = parent_namespace(&module) else {
+                        return;
+                    };
+                    Reexport::Item { module, item }
+                } else {
+                    Reexport::Item {
+                        module,
+                        item: ident.to_string(),
+                    }
+                };
+                self.reexports.push(reexport);
+            }
+            syn::UseTree::Glob(_) => {
+                if let Some(module) = module {
+                    self.reexports.push(Reexport::Module(module));
+                }
+            }
+            syn::UseTree::Group(group) => {
+                for item in &group.items {
+                    self.visit_use_tree_reexports(item, module.clone());
+                }
+            }
+        }
+    }
+}
+
+fn parent_namespace(namespace: &Namespace) -> Option<Namespace> {
+    let path = namespace.path();
+    (path.len() > 1).then(|| {
+        Namespace::new(
+            path[..path.len() - 1]
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+        )
+    })
+}
+
+impl<'ast> Visit<'ast> for PathRootVisitor {
+    fn visit_path(&mut self, node: &'ast syn::Path) {
+        if let Some(segment) = node.segments.first() {
+            self.roots.insert(segment.ident.to_string());
+        }
+        syn::visit::visit_path(self, node);
+    }
+
+    fn visit_item_use(&mut self, node: &'ast syn::ItemUse) {
+        self.visit_use_tree_roots(&node.tree);
+        if !matches!(node.vis, syn::Visibility::Inherited) {
+            self.visit_use_tree_reexports(&node.tree, None);
+        }
+        syn::visit::visit_item_use(self, node);
+    }
+
+    fn visit_item_mod(&mut self, node: &'ast syn::ItemMod) {
+        let parent_namespace = self.namespace.clone();
+        self.namespace = parent_namespace.join(&node.ident.to_string());
+        syn::visit::visit_item_mod(self, node);
+        self.namespace = parent_namespace;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::crate_name::CrateName;
+
+    #[test]
+    fn test_compute_referenced_crate_names() {
+        let actual = compute(syn::parse_quote! {
+            mod api {
+                use crate_a::Something;
+                pub fn f(a: crate_b::module::Another) -> Vec<crate_c::Third> { todo!() }
+            }
+            mod third_party {
+                mod crate_d {}
+                pub fn g(a: crate_e::Fifth) {}
+            }
+            mod not_bridged {
+                pub fn h(a: crate_f::Sixth) {}
+            }
+        });
+        for name in ["crate_a", "crate_b", "crate_c", "crate_d", "crate_e"] {
+            assert!(actual.contains(name), "name={name}");
+        }
+        assert!(!actual.contains("crate_f"));
+    }
+
+    #[test]
+    fn test_compute_referenced_crate_names_bare_use() {
+        let actual = compute(syn::parse_quote! {
+            mod api {
+                use crate_a;
+                use crate_b as b;
+                use {crate_c, crate_d::Something};
+                pub fn f(a: b::Another) {}
+            }
+        });
+        for name in ["crate_a", "crate_b", "crate_c", "crate_d"] {
+            assert!(actual.contains(name), "name={name}");
+        }
+    }
+
+    #[test]
+    fn test_compute_referenced_crate_names_pub_use_item() {
+        let actual = compute(syn::parse_quote! {
+            mod api {
+                pub use crate::models::Person;
+                mod nested {
+                    pub use super::super::shared::{self as common};
+                }
+            }
+            mod models {
+                pub struct Person { pub pet: crate_a::Pet }
+                mod unrelated {
+                    pub struct Other { pub field: crate_b::Field }
+                }
+            }
+            mod shared {
+                pub mod inner {
+                    pub fn g(a: crate_c::Third) {}
+                }
+            }
+        });
+        for name in ["crate_a", "crate_c"] {
+            assert!(actual.contains(name), "name={name}");
+        }
+        assert!(!actual.contains("crate_b"));
+    }
+
+    #[test]
+    fn test_compute_referenced_crate_names_pub_use_glob() {
+        let actual = compute(syn::parse_quote! {
+            mod api {
+                pub use self::reexported::*;
+                pub mod reexported {
+                    pub use crate::models::*;
+                }
+            }
+            mod models {
+                pub struct Person { pub pet: crate_a::Pet }
+            }
+            mod not_bridged {
+                use crate_b::Something;
+            }
+        });
+        assert!(actual.contains("crate_a"));
+        assert!(!actual.contains("crate_b"));
+    }
+
+    fn compute(file: syn::File) -> HashSet<String> {
+        compute_referenced_crate_names(
+            &HirRawCrate {
+                name: CrateName::self_crate(),
+                syn_file: file,
+            },
+            &RustInputNamespacePack {
+                rust_input_namespace_prefixes: vec![Namespace::new_self_crate("api".to_owned())],
+                rust_output_path_namespace: Namespace::new_self_crate("frb_generated".to_owned()),
+            },
+        )
+    }
+}
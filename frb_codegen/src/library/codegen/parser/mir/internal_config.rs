@@ -13,6 +13,34 @@ pub(crate) struct ParserMirInternalConfig {
     pub enable_lifetime: bool,
     pub type_64bit_int: bool,
     pub default_dart_async: bool,
+    pub type_mappings: Vec<TypeMapping>,
+    pub web_enabled: bool,
+}
+
+/// Maps a Rust type to a Dart type, converting it from/to a type that flutter_rust_bridge supports,
+/// which is the same as `#[frb(rust2dart(..))]` and `#[frb(dart2rust(..))]` but without touching the Rust code
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct TypeMapping {
+    /// e.g. `rust_decimal::Decimal`, which also matches `Decimal` in the Rust code
+    pub rust_type: String,
+    /// The type transferred between Rust and Dart, e.g. `String`
+    pub inner_type: String,
+    pub dart_type: String,
+    /// e.g. `package:decimal/decimal.dart`
+    pub dart_import: Option<String>,
+    pub rust2dart: TypeMappingHalf,
+    pub dart2rust: TypeMappingHalf,
+}
+
+/// The code converting the value in one direction, where `{}` is replaced by the value
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct TypeMappingHalf {
+    /// e.g. `{}.to_string()`, or `crate::my_mappings::decimal_to_string({})`
+    pub rust_code: String,
+    /// e.g. `Decimal.parse({})`
+    pub dart_code: String,
 }
 
 // TODO rename - this is no longer an "input-namespace"-only pack
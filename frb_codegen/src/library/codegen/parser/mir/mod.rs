@@ -20,8 +20,11 @@ pub(crate) fn parse(
     let pack = transformer::filter_trait_impl_transformer::transform(pack)?;
     dumper.dump("2_filter_trait_impl_transformer.json", &pack)?;
 
+    let pack = transformer::exception_hierarchy_transformer::transform(pack)?;
+    dumper.dump("3_exception_hierarchy_transformer.json", &pack)?;
+
     // let pack = transformer::dyn_trait_inner_transformer::transform(pack)?;
-    // dump(dumper, "3_dyn_trait_inner_transformer", &pack)?;
+    // dump(dumper, "4_dyn_trait_inner_transformer", &pack)?;
 
     Ok(pack)
 }
@@ -3,6 +3,7 @@ use crate::codegen::ir::mir::annotation::MirDartAnnotation;
 use crate::codegen::ir::mir::default::MirDefaultValue;
 use crate::codegen::ir::mir::func::MirFuncAccessorMode;
 use crate::codegen::ir::mir::import::MirDartImport;
+use crate::codegen::ir::mir::ty::delegate::MirMessageCodec;
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::if_then_some;
 use anyhow::Context;
@@ -131,6 +132,10 @@ impl FrbAttributes {
         self.any_eq(&FrbAttribute::External)
     }
 
+    pub(crate) fn exception_hierarchy(&self) -> bool {
+        self.any_eq(&FrbAttribute::ExceptionHierarchy)
+    }
+
     pub(crate) fn type_64bit_int(&self) -> bool {
         self.any_eq(&FrbAttribute::Type64bitInt)
     }
@@ -147,8 +152,15 @@ impl FrbAttributes {
         }
     }
 
+    pub(crate) fn message_codec(&self) -> Option<MirMessageCodec> {
+        (self.0.iter())
+            .filter_map(|item| if_then_some!(let FrbAttribute::Codec(inner) = item, inner.0))
+            .next()
+    }
+
     pub(crate) fn codec_mode_pack(&self) -> Option<CodecModePack> {
-        if self.any_eq(&FrbAttribute::Serialize) {
+        // The bytes of message codecs are transferred using SSE
+        if self.any_eq(&FrbAttribute::Serialize) || self.message_codec().is_some() {
             Some(CodecModePack {
                 dart2rust: CodecMode::Sse,
                 rust2dart: CodecMode::Sse,
@@ -226,6 +238,51 @@ impl FrbAttributes {
     pub(crate) fn ui_mutation(&self) -> bool {
         self.any_eq(&FrbAttribute::UiMutation)
     }
+
+    pub(crate) fn compress(&self) -> bool {
+        self.any_eq(&FrbAttribute::Compress)
+    }
+
+    pub(crate) fn dart_service(&self) -> bool {
+        self.any_eq(&FrbAttribute::DartService)
+    }
+
+    pub(crate) fn background_task(&self) -> bool {
+        self.any_eq(&FrbAttribute::BackgroundTask)
+    }
+
+    pub(crate) fn thread_pinned(&self) -> bool {
+        self.any_eq(&FrbAttribute::ThreadPinned)
+    }
+
+    pub(crate) fn actor(&self) -> bool {
+        self.any_eq(&FrbAttribute::Actor)
+    }
+
+    pub(crate) fn lock_scope(&self) -> bool {
+        self.any_eq(&FrbAttribute::LockScope)
+    }
+
+    pub(crate) fn lazy(&self) -> bool {
+        self.any_eq(&FrbAttribute::Lazy)
+    }
+
+    pub(crate) fn patch(&self) -> bool {
+        self.any_eq(&FrbAttribute::Patch)
+    }
+
+    pub(crate) fn zero_copy(&self) -> bool {
+        self.any_eq(&FrbAttribute::ZeroCopy)
+    }
+
+    /// Each item is one instantiation, i.e. the concrete type of each generic parameter
+    pub(crate) fn instantiations(&self) -> Vec<Vec<(Ident, Type)>> {
+        (self.0.iter())
+            .filter_map(
+                |item| if_then_some!(let FrbAttribute::Instantiate(inner) = item, inner.0.clone()),
+            )
+            .collect()
+    }
 }
 
 fn transform_doc_comment(attr: &Attribute) -> anyhow::Result<Attribute> {
@@ -277,6 +334,7 @@ mod frb_keyword {
     syn::custom_keyword!(positional);
     syn::custom_keyword!(proxy);
     syn::custom_keyword!(external);
+    syn::custom_keyword!(exception_hierarchy);
     syn::custom_keyword!(type_64bit_int);
     syn::custom_keyword!(generate_implementor_enum);
     syn::custom_keyword!(rust_opaque_codec_moi);
@@ -292,6 +350,17 @@ mod frb_keyword {
     syn::custom_keyword!(dart_type);
     syn::custom_keyword!(ui_state);
     syn::custom_keyword!(ui_mutation);
+    syn::custom_keyword!(compress);
+    syn::custom_keyword!(dart_service);
+    syn::custom_keyword!(background_task);
+    syn::custom_keyword!(thread_pinned);
+    syn::custom_keyword!(actor);
+    syn::custom_keyword!(lock_scope);
+    syn::custom_keyword!(lazy);
+    syn::custom_keyword!(patch);
+    syn::custom_keyword!(instantiate);
+    syn::custom_keyword!(zero_copy);
+    syn::custom_keyword!(codec);
 }
 
 struct FrbAttributesInner(Vec<FrbAttribute>);
@@ -311,13 +380,22 @@ impl Parse for FrbAttributesInner {
 // Alphabetical order
 #[derive(Eq, PartialEq, Debug, Clone)]
 enum FrbAttribute {
+    Actor,
+    BackgroundTask,
+    Codec(FrbAttributeCodec),
+    Compress,
     Dart2Rust(FrbAttributeSerDes),
     DartCode(FrbAttributeDartCode),
+    DartService,
     Default(FrbAttributeDefaultValue),
+    ExceptionHierarchy,
     External,
     Getter,
     Ignore,
     Init,
+    Instantiate(FrbAttributeInstantiate),
+    Lazy,
+    LockScope,
     Mirror(FrbAttributeMirror),
     Name(FrbAttributeName),
     NonEq,
@@ -325,6 +403,7 @@ enum FrbAttribute {
     NonHash,
     NonOpaque,
     Opaque,
+    Patch,
     Positional,
     Proxy,
     Rust2Dart(FrbAttributeSerDes),
@@ -333,7 +412,9 @@ enum FrbAttribute {
     StreamDartAwait,
     Sync,
     DartAsync,
+    ThreadPinned,
     Type64bitInt,
+    ZeroCopy,
 
     // === Mainly undocumented since may subject to change ===
 
@@ -376,6 +457,14 @@ impl Parse for FrbAttribute {
             .or_else(|| parse_keyword::<positional, _>(input, &lookahead, positional, Positional))
             .or_else(|| parse_keyword::<proxy, _>(input, &lookahead, proxy, Proxy))
             .or_else(|| parse_keyword::<external, _>(input, &lookahead, external, External))
+            .or_else(|| {
+                parse_keyword::<exception_hierarchy, _>(
+                    input,
+                    &lookahead,
+                    exception_hierarchy,
+                    ExceptionHierarchy,
+                )
+            })
             .or_else(|| {
                 parse_keyword::<type_64bit_int, _>(input, &lookahead, type_64bit_int, Type64bitInt)
             })
@@ -400,9 +489,27 @@ impl Parse for FrbAttribute {
                 parse_keyword::<semi_serialize, _>(input, &lookahead, semi_serialize, SemiSerialize)
             })
             .or_else(|| parse_keyword::<ui_state, _>(input, &lookahead, ui_state, UiState))
+            .or_else(|| parse_keyword::<ui_mutation, _>(input, &lookahead, ui_mutation, UiMutation))
+            .or_else(|| parse_keyword::<compress, _>(input, &lookahead, compress, Compress))
+            .or_else(|| {
+                parse_keyword::<dart_service, _>(input, &lookahead, dart_service, DartService)
+            })
+            .or_else(|| {
+                parse_keyword::<background_task, _>(
+                    input,
+                    &lookahead,
+                    background_task,
+                    BackgroundTask,
+                )
+            })
             .or_else(|| {
-                parse_keyword::<ui_mutation, _>(input, &lookahead, ui_mutation, UiMutation)
-            });
+                parse_keyword::<thread_pinned, _>(input, &lookahead, thread_pinned, ThreadPinned)
+            })
+            .or_else(|| parse_keyword::<actor, _>(input, &lookahead, actor, Actor))
+            .or_else(|| parse_keyword::<lock_scope, _>(input, &lookahead, lock_scope, LockScope))
+            .or_else(|| parse_keyword::<lazy, _>(input, &lookahead, lazy, Lazy))
+            .or_else(|| parse_keyword::<patch, _>(input, &lookahead, patch, Patch))
+            .or_else(|| parse_keyword::<zero_copy, _>(input, &lookahead, zero_copy, ZeroCopy));
         if let Some(keyword_output) = keyword_output {
             return keyword_output;
         }
@@ -420,10 +527,17 @@ impl Parse for FrbAttribute {
             input.parse::<dart_code>()?;
             input.parse::<Token![=]>()?;
             input.parse().map(DartCode)?
+        } else if lookahead.peek(frb_keyword::codec) {
+            input.parse::<frb_keyword::codec>()?;
+            input.parse::<Token![=]>()?;
+            input.parse().map(Codec)?
         } else if lookahead.peek(name) {
             input.parse::<name>()?;
             input.parse::<Token![=]>()?;
             input.parse().map(Name)?
+        } else if lookahead.peek(frb_keyword::instantiate) {
+            input.parse::<frb_keyword::instantiate>()?;
+            input.parse().map(Instantiate)?
         } else if lookahead.peek(frb_keyword::dart2rust) {
             input.parse::<frb_keyword::dart2rust>()?;
             input.parse().map(Dart2Rust)?
@@ -476,6 +590,21 @@ impl Parse for FrbAttributeMirror {
     }
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct FrbAttributeInstantiate(Vec<(Ident, Type)>);
+
+impl Parse for FrbAttributeInstantiate {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let content;
+        parenthesized!(content in input);
+        let params = Punctuated::<NamedOption<Ident, Type>, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .map(|x| (x.name, x.value))
+            .collect();
+        Ok(Self(params))
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct FrbAttributeDartMetadata(Vec<MirDartAnnotation>);
 
@@ -660,6 +789,22 @@ impl Parse for FrbAttributeName {
     }
 }
 
+#[derive(Clone, Serialize, Eq, PartialEq, Debug)]
+struct FrbAttributeCodec(MirMessageCodec);
+
+impl Parse for FrbAttributeCodec {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let lit = input.parse::<syn::LitStr>()?;
+        let codec = (lit.value().parse()).map_err(|_| {
+            syn::Error::new(
+                lit.span(),
+                "Unknown codec, expect `protobuf` or `flatbuffers`",
+            )
+        })?;
+        Ok(Self(codec))
+    }
+}
+
 #[derive(Clone, Serialize, Eq, PartialEq, Debug)]
 pub(crate) struct FrbAttributeSerDes {
     pub dart_type: String,
@@ -690,7 +835,9 @@ impl Parse for FrbAttributeSerDes {
 
 #[cfg(test)]
 mod tests {
+    use crate::codegen::generator::codec::structs::CodecMode;
     use crate::codegen::ir::mir::default::MirDefaultValue;
+    use crate::codegen::ir::mir::ty::delegate::MirMessageCodec;
     use crate::codegen::parser::mir::parser::attribute::{
         FrbAttribute, FrbAttributeDartCode, FrbAttributeDefaultValue, FrbAttributeMirror,
         FrbAttributeName, FrbAttributeSerDes, FrbAttributes, NamedOption,
@@ -844,6 +991,11 @@ mod tests {
         simple_keyword_tester("external", FrbAttribute::External);
     }
 
+    #[test]
+    fn test_exception_hierarchy() {
+        simple_keyword_tester("exception_hierarchy", FrbAttribute::ExceptionHierarchy);
+    }
+
     #[test]
     fn test_type_64bit_int() {
         simple_keyword_tester("type_64bit_int", FrbAttribute::Type64bitInt);
@@ -883,6 +1035,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_codec() -> anyhow::Result<()> {
+        let parsed = parse(r###"#[frb(codec = "protobuf")]"###)?;
+        assert_eq!(parsed.message_codec(), Some(MirMessageCodec::Protobuf));
+        assert_eq!(parsed.codec_mode_pack().unwrap().rust2dart, CodecMode::Sse);
+        assert!(parse(r###"#[frb(codec = "json")]"###).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_rust2dart() -> anyhow::Result<()> {
         let parsed =
@@ -940,6 +1101,73 @@ mod tests {
         simple_keyword_tester("ui_mutation", FrbAttribute::UiMutation);
     }
 
+    #[test]
+    fn test_compress() {
+        simple_keyword_tester("compress", FrbAttribute::Compress);
+    }
+
+    #[test]
+    fn test_dart_service() {
+        simple_keyword_tester("dart_service", FrbAttribute::DartService);
+    }
+
+    #[test]
+    fn test_background_task() {
+        simple_keyword_tester("background_task", FrbAttribute::BackgroundTask);
+    }
+
+    #[test]
+    fn test_thread_pinned() {
+        simple_keyword_tester("thread_pinned", FrbAttribute::ThreadPinned);
+    }
+
+    #[test]
+    fn test_actor() {
+        simple_keyword_tester("actor", FrbAttribute::Actor);
+    }
+
+    #[test]
+    fn test_lock_scope() {
+        simple_keyword_tester("lock_scope", FrbAttribute::LockScope);
+    }
+
+    #[test]
+    fn test_lazy() {
+        simple_keyword_tester("lazy", FrbAttribute::Lazy);
+    }
+
+    #[test]
+    fn test_zero_copy() {
+        simple_keyword_tester("zero_copy", FrbAttribute::ZeroCopy);
+    }
+
+    #[test]
+    fn test_patch() {
+        simple_keyword_tester("patch", FrbAttribute::Patch);
+    }
+
+    #[test]
+    fn test_instantiate() -> anyhow::Result<()> {
+        let parsed = parse(
+            "#[frb(instantiate(K = String, V = Vec<MyDto>))]\n#[frb(instantiate(K = u8, V = u8))]",
+        )?;
+        let instantiations = (parsed.instantiations().iter())
+            .map(|params| {
+                (params.iter())
+                    .map(|(name, ty)| format!("{name}={}", quote!(#ty)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            instantiations,
+            vec![
+                vec!["K=String".to_owned(), "V=Vec < MyDto >".to_owned()],
+                vec!["K=u8".to_owned(), "V=u8".to_owned()],
+            ]
+        );
+        Ok(())
+    }
+
     // Mirror(FrbAttributeMirror),
     // NonFinal,
     // Sync,
@@ -1,16 +1,19 @@
 use crate::codegen::generator::codec::structs::CodecMode;
 use crate::codegen::ir::hir::flat::function::HirFlatFunction;
-use crate::codegen::ir::mir::custom_ser_des::{MirCustomSerDes, MirCustomSerDesHalf};
+use crate::codegen::ir::mir::custom_ser_des::{
+    MirCustomSerDes, MirCustomSerDesHalf, MirCustomSerDesRustCode,
+};
 use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::mir::internal_config::{TypeMapping, TypeMappingHalf};
 use crate::codegen::parser::mir::parser::attribute::{FrbAttributeSerDes, FrbAttributes};
 use crate::codegen::parser::mir::parser::ty::{TypeParser, TypeParserParsingContext};
 use crate::codegen::parser::mir::ParseMode;
 use crate::if_then_some;
 use crate::utils::namespace::{Namespace, NamespacedName};
-use anyhow::ensure;
+use anyhow::{ensure, Context};
 use itertools::Itertools;
-use syn::{FnArg, ReturnType};
+use syn::{FnArg, ReturnType, Type};
 
 pub(crate) struct PartialContext {
     pub rust_output_path_namespace: Namespace,
@@ -41,6 +44,56 @@ pub(crate) fn parse(
     Ok(ans)
 }
 
+pub(crate) fn parse_type_mappings(
+    type_mappings: &[TypeMapping],
+    type_parser: &mut TypeParser,
+    partial_context: &PartialContext,
+) -> anyhow::Result<Vec<MirCustomSerDes>> {
+    let context = create_context(
+        partial_context.rust_output_path_namespace.clone(),
+        partial_context,
+    )?;
+    let parse_type = |ty: &str| -> anyhow::Result<Type> {
+        syn::parse_str(ty).with_context(|| format!("Invalid type `{ty}` in type_mappings"))
+    };
+    let parse_half = |half: &TypeMappingHalf, mapping: &TypeMapping| MirCustomSerDesHalf {
+        dart_code: half.dart_code.clone(),
+        rust_code: MirCustomSerDesRustCode::Template {
+            code: half.rust_code.clone(),
+            rust_type: mapping.rust_type.clone(),
+            import: compute_type_import(&mapping.rust_type),
+        },
+    };
+
+    (type_mappings.iter())
+        .map(|mapping| {
+            Ok(MirCustomSerDes {
+                inner_type: Box::new(
+                    type_parser.parse_type(&parse_type(&mapping.inner_type)?, &context)?,
+                ),
+                rust_api_type: Box::new(
+                    type_parser.parse_type_mapped(&parse_type(&mapping.rust_type)?, &context)?,
+                ),
+                dart_api_type: mapping.dart_type.clone(),
+                dart_import: mapping.dart_import.clone(),
+                dart2rust: parse_half(&mapping.dart2rust, mapping),
+                rust2dart: parse_half(&mapping.rust2dart, mapping),
+            })
+        })
+        .collect()
+}
+
+/// e.g. `rust_decimal::Decimal` for `rust_decimal::Decimal`, or none for `Decimal`
+fn compute_type_import(rust_type: &str) -> Option<NamespacedName> {
+    let ty = syn::parse_str::<syn::TypePath>(rust_type).ok()?;
+    let segments = (ty.path.segments.iter())
+        .map(|x| x.ident.to_string())
+        .collect_vec();
+    let (name, module_segments) = segments.split_last()?;
+    (!module_segments.is_empty())
+        .then(|| NamespacedName::new(Namespace::new(module_segments.to_vec()), name.to_owned()))
+}
+
 fn parse_function(
     func: &HirFlatFunction,
     type_parser: &mut TypeParser,
@@ -83,19 +136,7 @@ fn parse_function_inner(
     let input_ty = if_then_some!(let FnArg::Typed(pat_type) = sig.inputs.first().unwrap().clone(), *pat_type.ty).unwrap();
     let output_ty = if_then_some!(let ReturnType::Type(_, ty) = sig.output.clone(), *ty).unwrap();
 
-    let context = TypeParserParsingContext {
-        initiated_namespace: func.namespace.clone(),
-        func_attributes: FrbAttributes::parse(&[])?,
-        struct_or_enum_attributes: None,
-        owner: None,
-        rust_output_path_namespace: partial_context.rust_output_path_namespace.clone(),
-        default_stream_sink_codec: partial_context.default_stream_sink_codec,
-        default_rust_opaque_codec: partial_context.default_rust_opaque_codec,
-        enable_lifetime: partial_context.enable_lifetime,
-        type_64bit_int: partial_context.type_64bit_int,
-        forbid_type_self: false,
-        parse_mode: partial_context.parse_mode,
-    };
+    let context = create_context(func.namespace.clone(), partial_context)?;
 
     let input_ty = Box::new(type_parser.parse_type(&input_ty, &context)?);
     let output_ty = Box::new(type_parser.parse_type(&output_ty, &context)?);
@@ -112,11 +153,33 @@ fn parse_function_inner(
         direction,
         half: MirCustomSerDesHalf {
             dart_code: attr_ser_des.dart_code,
-            rust_function: NamespacedName::new(func.namespace.clone(), func.item_fn.name()),
+            rust_code: MirCustomSerDesRustCode::Function(NamespacedName::new(
+                func.namespace.clone(),
+                func.item_fn.name(),
+            )),
         },
     })
 }
 
+fn create_context(
+    initiated_namespace: Namespace,
+    partial_context: &PartialContext,
+) -> anyhow::Result<TypeParserParsingContext> {
+    Ok(TypeParserParsingContext {
+        initiated_namespace,
+        func_attributes: FrbAttributes::parse(&[])?,
+        struct_or_enum_attributes: None,
+        owner: None,
+        rust_output_path_namespace: partial_context.rust_output_path_namespace.clone(),
+        default_stream_sink_codec: partial_context.default_stream_sink_codec,
+        default_rust_opaque_codec: partial_context.default_rust_opaque_codec,
+        enable_lifetime: partial_context.enable_lifetime,
+        type_64bit_int: partial_context.type_64bit_int,
+        forbid_type_self: false,
+        parse_mode: partial_context.parse_mode,
+    })
+}
+
 fn merge_pair(pair: Vec<Info>) -> MirCustomSerDes {
     let [a, b]: [Info; 2] = (pair.try_into())
         .unwrap_or_else(|_| panic!("Expect a pair of serializer and deserializer"));
@@ -130,6 +193,7 @@ fn merge_pair(pair: Vec<Info>) -> MirCustomSerDes {
         inner_type: dart2rust.inner_type.clone(),
         rust_api_type: dart2rust.rust_api_type.clone(),
         dart_api_type: dart2rust.dart_api_type.clone(),
+        dart_import: None,
         dart2rust: dart2rust.half,
         rust2dart: rust2dart.half,
     }
@@ -148,3 +212,26 @@ enum Direction {
     Rust2Dart,
     Dart2Rust,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_type_import() {
+        let compute = |ty| compute_type_import(ty).map(|x| x.rust_style());
+        assert_eq!(
+            compute("rust_decimal::Decimal"),
+            Some("rust_decimal::Decimal".to_owned())
+        );
+        assert_eq!(
+            compute("chrono::DateTime<chrono::Utc>"),
+            Some("chrono::DateTime".to_owned())
+        );
+        assert_eq!(
+            compute("std::net::Ipv4Addr"),
+            Some("std::net::Ipv4Addr".to_owned())
+        );
+        assert_eq!(compute("Decimal"), None);
+    }
+}
@@ -21,7 +21,7 @@ use crate::codegen::parser::mir::parser::function::real::lifetime::parse_functio
 use crate::codegen::parser::mir::parser::function::ui_related::UI_MUTATION_FUNCTION_RUST_AOP_AFTER;
 use crate::codegen::parser::mir::parser::ty::concrete::ERROR_MESSAGE_FORBID_TYPE_SELF;
 use crate::codegen::parser::mir::parser::ty::generics::should_ignore_because_generics;
-use crate::codegen::parser::mir::parser::ty::misc::parse_comments;
+use crate::codegen::parser::mir::parser::ty::misc::{parse_cfg, parse_comments};
 use crate::codegen::parser::mir::parser::ty::{TypeParser, TypeParserParsingContext};
 use crate::codegen::parser::mir::ParseMode;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
@@ -142,16 +142,34 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             return Ok(create_output_skip(func, IgnoreBecauseFunctionNotPub));
         }
 
+        let attributes = FrbAttributes::parse(func.item_fn.attrs())?;
+        // The instantiations with concrete types are bridged instead
+        if !attributes.instantiations().is_empty() {
+            return Ok(create_output_skip(func, IgnoreSilently));
+        }
+
         // If enable lifetime, the lifetime "generics" should be acceptable (though other generics still not)
         if should_ignore_because_generics(&func.item_fn.sig().generics, enable_lifetime) {
             return Ok(create_output_skip(func, IgnoreBecauseFunctionGeneric));
         }
 
         let src_lineno = func.item_fn.span().start().line;
-        let attributes = FrbAttributes::parse(func.item_fn.attrs())?;
         if attributes.dart2rust().is_some() || attributes.rust2dart().is_some() {
             return Ok(create_output_skip(func, IgnoreSilently));
         }
+        if attributes.message_codec().is_some()
+            && !matches!(func.owner, HirFlatFunctionOwner::Function)
+        {
+            bail!(
+                "`#[frb(codec = ...)]` is only supported for free functions, please make `{}` one",
+                func.item_fn.name()
+            );
+        }
+        // The generated background task wrapper is exposed instead, while the early generator
+        // still needs the tentative parse result of the original function
+        if attributes.background_task() && parse_mode == ParseMode::Normal {
+            return Ok(create_output_skip(func, IgnoreSilently));
+        }
 
         let dart_name = parse_dart_name(&attributes, &func.item_fn.name());
 
@@ -223,9 +241,11 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
         )?)?;
         info = self.transform_fn_info(info);
 
-        let codec_mode_pack = compute_codec_mode_pack(&attributes, force_codec_mode_pack);
         let dart_async = compute_dart_async(func, &attributes, default_dart_async);
         let mode = compute_func_mode(dart_async, &info);
+        let codec_mode_pack = compute_codec_mode_pack(&attributes, force_codec_mode_pack);
+        let codec_mode_pack = apply_zero_copy(&func_name, &attributes, mode, codec_mode_pack);
+        let compress = compute_compress(&func_name, &attributes, &codec_mode_pack);
         let stream_dart_await = attributes.stream_dart_await() && dart_async;
         let namespace_refined = refine_namespace(&owner).unwrap_or(func.namespace.clone());
         let accessor = attributes.accessor();
@@ -261,9 +281,12 @@ impl<'a, 'b> FunctionParser<'a, 'b> {
             comments: parse_comments(func.item_fn.attrs()),
             codec_mode_pack,
             rust_call_code: None,
+            rust_call_name: func.rust_call_name.clone(),
             rust_aop_after: (attributes.ui_mutation())
                 .then(|| UI_MUTATION_FUNCTION_RUST_AOP_AFTER.to_owned()),
+            compress,
             impl_mode,
+            rust_cfg: parse_cfg(func.item_fn.attrs()),
             src_lineno_pseudo: src_lineno,
         }))
     }
@@ -369,6 +392,42 @@ pub(crate) fn compute_codec_mode_pack(
     force_ans.to_owned().or(attr_ans).unwrap_or(DEFAULT_ANS)
 }
 
+fn compute_compress(
+    func_name: &str,
+    attributes: &FrbAttributes,
+    codec_mode_pack: &CodecModePack,
+) -> bool {
+    if !attributes.compress() {
+        return false;
+    }
+    if codec_mode_pack.rust2dart.delegate_or_self() != CodecMode::Sse {
+        warn!("Ignore `#[frb(compress)]` of function `{func_name}`, since it is only supported for the SSE codec");
+        return false;
+    }
+    true
+}
+
+/// The DCO codec sends typed lists as external typed data owned by Rust, thus avoids copying them
+fn apply_zero_copy(
+    func_name: &str,
+    attributes: &FrbAttributes,
+    mode: MirFuncMode,
+    codec_mode_pack: CodecModePack,
+) -> CodecModePack {
+    if !attributes.zero_copy() {
+        return codec_mode_pack;
+    }
+    // The synchronous dispatcher of the PDE codec always returns SSE bytes
+    if mode == MirFuncMode::Sync && codec_mode_pack.dart2rust == CodecMode::Pde {
+        warn!("Ignore `#[frb(zero_copy)]` of synchronous function `{func_name}`, since it is only supported when full_dep=true");
+        return codec_mode_pack;
+    }
+    CodecModePack {
+        rust2dart: CodecMode::Dco,
+        ..codec_mode_pack
+    }
+}
+
 fn refine_namespace(owner: &MirFuncOwnerInfo) -> Option<Namespace> {
     if let MirFuncOwnerInfo::Method(method) = owner {
         method.owner_ty.self_namespace()
@@ -15,6 +15,8 @@ use crate::codegen::parser::mir::internal_config::{
     ParserMirInternalConfig, RustInputNamespacePack,
 };
 use crate::codegen::parser::mir::parser::ty::TypeParser;
+use crate::codegen::parser::mir::sanity_checker::feature_gated_type_checker::check_feature_gated_type_codecs;
+use crate::codegen::parser::mir::sanity_checker::lazy_struct_checker::check_lazy_struct_codecs;
 use crate::codegen::parser::mir::sanity_checker::opaque_inside_translatable_checker::check_opaque_inside_translatable;
 use crate::codegen::parser::mir::sanity_checker::unused_checker::get_unused_types;
 use crate::codegen::parser::mir::ParseMode;
@@ -33,21 +35,29 @@ pub(crate) fn parse(
     let mut type_parser = TypeParser::new_from_pack(ir_pack);
 
     // NOTE: Should parse this as early as possible
-    let custom_ser_des_infos = custom_ser_des::parse(
-        &hir_flat.functions,
-        &mut type_parser,
-        &custom_ser_des::PartialContext {
-            rust_output_path_namespace: config
-                .rust_input_namespace_pack
-                .rust_output_path_namespace
-                .clone(),
-            default_stream_sink_codec: config.default_stream_sink_codec,
-            default_rust_opaque_codec: config.default_rust_opaque_codec,
-            enable_lifetime: config.enable_lifetime,
-            type_64bit_int: config.type_64bit_int,
-            parse_mode,
-        },
-    )?;
+    let custom_ser_des_partial_context = custom_ser_des::PartialContext {
+        rust_output_path_namespace: config
+            .rust_input_namespace_pack
+            .rust_output_path_namespace
+            .clone(),
+        default_stream_sink_codec: config.default_stream_sink_codec,
+        default_rust_opaque_codec: config.default_rust_opaque_codec,
+        enable_lifetime: config.enable_lifetime,
+        type_64bit_int: config.type_64bit_int,
+        parse_mode,
+    };
+    let custom_ser_des_infos = concat([
+        custom_ser_des::parse(
+            &hir_flat.functions,
+            &mut type_parser,
+            &custom_ser_des_partial_context,
+        )?,
+        custom_ser_des::parse_type_mappings(
+            &config.type_mappings,
+            &mut type_parser,
+            &custom_ser_des_partial_context,
+        )?,
+    ]);
     type_parser
         .custom_ser_des_infos
         .extend(custom_ser_des_infos);
@@ -98,6 +108,8 @@ pub(crate) fn parse(
     )?;
 
     check_opaque_inside_translatable(&ans);
+    check_lazy_struct_codecs(&ans)?;
+    check_feature_gated_type_codecs(&ans)?;
 
     Ok(ans)
 }
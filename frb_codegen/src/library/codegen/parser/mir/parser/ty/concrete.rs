@@ -3,15 +3,17 @@ use crate::codegen::ir::mir::func::MirFuncOwnerInfo;
 use crate::codegen::ir::mir::ty::boxed::MirTypeBoxed;
 use crate::codegen::ir::mir::ty::dart_opaque::MirTypeDartOpaque;
 use crate::codegen::ir::mir::ty::delegate::{
-    MirTypeDelegate, MirTypeDelegateMap, MirTypeDelegateSet, MirTypeDelegateStreamSink,
-    MirTypeDelegateTime,
+    MirTypeDelegate, MirTypeDelegateMap, MirTypeDelegateProgress, MirTypeDelegateSet,
+    MirTypeDelegateStreamSink, MirTypeDelegateTime,
 };
 use crate::codegen::ir::mir::ty::dynamic::MirTypeDynamic;
 use crate::codegen::ir::mir::ty::general_list::mir_list;
+use crate::codegen::ir::mir::ty::optional::MirTypeOptional;
+use crate::codegen::ir::mir::ty::primitive::MirTypePrimitive;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::ir::mir::ty::MirType::{Boxed, DartOpaque, Delegate, Dynamic};
 use crate::codegen::parser::mir::parser::ty::path_data::extract_path_data;
-use crate::codegen::parser::mir::parser::ty::unencodable::{splay_segments, SplayedSegment};
+use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
 use crate::if_then_some;
 use anyhow::{bail, Context};
@@ -95,6 +97,18 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                 codec: parse_stream_sink_codec(codec)?,
             })),
 
+            ("FrbProgress", []) => self.parse_progress(),
+            ("ProgressSink", []) => Delegate(MirTypeDelegate::StreamSink(MirTypeDelegateStreamSink {
+                inner_ok: Box::new(self.parse_progress()),
+                inner_err: stream_sink_err_type(),
+                codec: self.context.default_stream_sink_codec,
+            })),
+            ("ProgressSink", [codec]) => Delegate(MirTypeDelegate::StreamSink(MirTypeDelegateStreamSink {
+                inner_ok: Box::new(self.parse_progress()),
+                inner_err: stream_sink_err_type(),
+                codec: parse_stream_sink_codec(codec)?,
+            })),
+
             _ => return Ok(None),
         }))
     }
@@ -111,13 +125,26 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         self.parse_type(&parse_str::<Type>(&enum_or_struct_name)?)
     }
 
+    fn parse_progress(&mut self) -> MirType {
+        let u64_ty = MirType::Primitive(MirTypePrimitive::U64);
+        let element_delegate = self.create_mir_record(vec![
+            u64_ty.clone(),
+            MirType::Optional(MirTypeOptional::new_with_boxed_wrapper(u64_ty)),
+            MirType::Optional(MirTypeOptional::new(Delegate(MirTypeDelegate::String))),
+        ]);
+        Delegate(MirTypeDelegate::Progress(MirTypeDelegateProgress {
+            element_delegate,
+        }))
+    }
+
     // the function signature is not covered while the whole body is covered - looks like a bug in coverage tool
     // frb-coverage:ignore-start
     fn parse_datetime(&mut self, args: &[Type]) -> anyhow::Result<MirType> {
         // frb-coverage:ignore-end
         let inner = self.parse_type(&args[0])?;
         if let MirType::RustAutoOpaqueImplicit(inner) = &inner {
-            return Ok(match splay_segments(&inner.raw.segments).last().unwrap() {
+            let segment = inner.raw.segments.last().unwrap();
+            return Ok(match (&segment.ident[..], &segment.args[..]) {
                 ("Utc", []) => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Utc)),
                 ("Local", []) => Delegate(MirTypeDelegate::Time(MirTypeDelegateTime::Local)),
                 // This will stop the whole generator and tell the users, so we do not care about testing it
@@ -1,24 +1,55 @@
-use crate::codegen::ir::mir::custom_ser_des::MirCustomSerDes;
+use crate::codegen::ir::mir::custom_ser_des::{MirCustomSerDes, MirCustomSerDesRustCode};
 use crate::codegen::ir::mir::ty::delegate::{MirTypeDelegate, MirTypeDelegateCustomSerDes};
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use crate::utils::namespace::{Namespace, NamespacedName};
+use std::collections::HashMap;
+use syn::visit_mut::VisitMut;
+use syn::{Path, Type, TypePath};
 
 impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
     pub(crate) fn parse_type_path_data_custom_ser_des(
         &mut self,
+        type_path: &TypePath,
         last_segment: &SplayedSegment,
     ) -> anyhow::Result<Option<MirType>> {
+        if self.inner.custom_ser_des_infos.is_empty() {
+            return Ok(None);
+        }
+        let resolved_type = self.resolve_type(&Type::Path(type_path.clone()), true);
+
         // use HashMap etc later if too slow; here we use filter to remain flexibility of filtering strategy
         Ok((self.inner.custom_ser_des_infos.iter())
-            .find(|info| compute_matcher_types(info).contains(&last_segment.0.to_owned()))
+            .find(|info| match &info.dart2rust.rust_code {
+                // The types in `type_mappings` may come from any crate, thus the full paths are compared
+                MirCustomSerDesRustCode::Template { rust_type, .. } => syn::parse_str(rust_type)
+                    .is_ok_and(|ty| self.resolve_type(&ty, false) == resolved_type),
+                MirCustomSerDesRustCode::Function(_) => {
+                    compute_matcher_types(info).contains(&last_segment.0.to_owned())
+                }
+            })
             .map(|info| {
                 MirType::Delegate(MirTypeDelegate::CustomSerDes(MirTypeDelegateCustomSerDes {
                     info: info.to_owned(),
                 }))
             }))
     }
+
+    /// e.g. `rust_decimal::Decimal` for `Decimal` after `use rust_decimal::Decimal;`,
+    /// or `crate::api::Money` for the `Money` struct of the API
+    fn resolve_type(&self, ty: &Type, use_imports: bool) -> String {
+        let mut ty = ty.clone();
+        if use_imports {
+            ImportResolver {
+                imports: &self.inner.src_imports,
+                namespace: &self.context.initiated_namespace,
+            }
+            .visit_type_mut(&mut ty);
+        }
+        self.inner.type_qualifier.qualify(&ty)
+    }
 }
 
 fn compute_matcher_types(info: &MirCustomSerDes) -> Vec<String> {
@@ -27,3 +58,31 @@ fn compute_matcher_types(info: &MirCustomSerDes) -> Vec<String> {
         info.cleared_rust_api_type(),
     ]
 }
+
+struct ImportResolver<'a> {
+    imports: &'a HashMap<NamespacedName, String>,
+    namespace: &'a Namespace,
+}
+
+impl VisitMut for ImportResolver<'_> {
+    fn visit_type_path_mut(&mut self, type_path: &mut TypePath) {
+        syn::visit_mut::visit_type_path_mut(self, type_path);
+        if type_path.qself.is_none() {
+            if let Some(path) = self.resolve(&type_path.path) {
+                type_path.path = path;
+            }
+        }
+    }
+}
+
+impl ImportResolver<'_> {
+    /// Replaces the first segment with the imported path, keeping its generic arguments
+    fn resolve(&self, path: &Path) -> Option<Path> {
+        let first = path.segments.first()?;
+        let name = NamespacedName::new(self.namespace.clone(), first.ident.to_string());
+        let mut ans: Path = syn::parse_str(self.imports.get(&name)?).ok()?;
+        ans.segments.last_mut()?.arguments = first.arguments.clone();
+        ans.segments.extend(path.segments.iter().skip(1).cloned());
+        Some(ans)
+    }
+}
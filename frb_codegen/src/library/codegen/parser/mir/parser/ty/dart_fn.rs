@@ -70,7 +70,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                     ident,
                     arguments:
                         PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
-                }) = path.segments.first()
+                }) = path.segments.last()
                 {
                     if &ident.to_string() == "DartFnFuture" {
                         if let GenericArgument::Type(inner_ty) = (args.iter())
@@ -5,14 +5,17 @@ use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::MirTypeRustAutoOpaqu
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::ty::generics::should_ignore_because_generics;
+use crate::codegen::parser::mir::parser::ty::misc::parse_cfg;
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::TypeParserParsingContext;
+use crate::codegen::parser::mir::ParseMode;
 use crate::library::codegen::ir::mir::ty::MirTypeTrait;
 use crate::utils::basic_code::general_code::GeneralDartCode;
 use crate::utils::basic_code::parser::parse_dart_code;
 use crate::utils::crate_name::CrateName;
 use crate::utils::namespace::{Namespace, NamespacedName};
-use log::debug;
+use anyhow::bail;
+use log::{debug, warn};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -49,9 +52,23 @@ where
             let namespaced_name = NamespacedName::new(namespace, name.to_string());
 
             let attrs = FrbAttributes::parse(src_object.src.attrs())?;
-            let attrs_opaque = override_opaque.or(attrs.opaque());
+            let attrs_opaque = override_opaque.or(attrs.opaque()).or_else(|| {
+                is_foreign_non_exhaustive(&src_object).then(|| {
+                    if (self.parser_info().warned_non_exhaustive_objects)
+                        .insert(namespaced_name.clone())
+                    {
+                        warn!(
+                            "Treat `{}` as opaque since it is `#[non_exhaustive]` in another crate, \
+                            thus cannot be mirrored. Please wrap it in an own type if its fields are needed in Dart.",
+                            namespaced_name.rust_style()
+                        );
+                    }
+                    true
+                })
+            });
             if attrs_opaque == Some(true) {
                 debug!("Treat {name} as opaque since attribute says so");
+                ensure_opaque_not_gated(name, &src_object)?;
                 return Ok(Some((
                     self.parse_opaque(&namespaced_name, path, &src_object)?,
                     attrs,
@@ -83,6 +100,7 @@ where
                     .map_or(false, |obj| Self::compute_default_opaque(obj))
             {
                 debug!("Treat {name} as opaque by compute_default_opaque");
+                ensure_opaque_not_gated(name, &src_object)?;
                 return Ok(Some((
                     self.parse_opaque(&namespaced_name, path, &src_object)?,
                     attrs,
@@ -174,6 +192,7 @@ where
 #[derive(Clone, Debug, Default)]
 pub(super) struct EnumOrStructParserInfo<Id, Obj> {
     parsing_or_parsed_objects: HashSet<NamespacedName>,
+    warned_non_exhaustive_objects: HashSet<NamespacedName>,
     pub(super) object_pool: HashMap<Id, Obj>,
 }
 
@@ -181,11 +200,35 @@ impl<Id, Obj> EnumOrStructParserInfo<Id, Obj> {
     pub fn new() -> Self {
         Self {
             parsing_or_parsed_objects: HashSet::new(),
+            warned_non_exhaustive_objects: HashSet::new(),
             object_pool: HashMap::new(),
         }
     }
 }
 
+/// A `#[non_exhaustive]` struct or enum from another crate cannot be constructed or exhaustively
+/// matched by the generated code, thus it cannot be mirrored
+fn is_foreign_non_exhaustive<Item: SynItemStructOrEnum>(
+    src_object: &HirFlatStructOrEnum<Item>,
+) -> bool {
+    !src_object.name.namespace.crate_name().is_self_crate()
+        && (src_object.src.attrs().iter()).any(|attr| attr.path().is_ident("non_exhaustive"))
+}
+
+// Opaque types are referenced by much of the generated code, thus only translatable ones can be gated
+fn ensure_opaque_not_gated<Item: SynItemStructOrEnum>(
+    name: &str,
+    src_object: &HirFlatStructOrEnum<Item>,
+) -> anyhow::Result<()> {
+    if let Some(cfg) = parse_cfg(src_object.src.attrs()) {
+        bail!(
+            "`{name}` is opaque and only exists in some of the `rust_feature_sets` (cfg: {cfg}), \
+            please declare it unconditionally and gate the functions instead"
+        );
+    }
+    Ok(())
+}
+
 fn compute_name_and_wrapper_name(
     namespace: &Namespace,
     name: &str,
@@ -208,6 +251,8 @@ pub(crate) fn parse_struct_or_enum_should_ignore<Item: SynItemStructOrEnum>(
     let attrs = FrbAttributes::parse(src_object.src.attrs()).unwrap();
 
     attrs.ignore()
+        // Exposed via the generated wrappers instead, see `early_generator::{thread_pinned,actor}`
+        || ((attrs.thread_pinned() || attrs.actor()) && context.parse_mode == ParseMode::Normal)
         // For third party crates, if a struct is not public, then it is impossible to utilize it,
         // thus we ignore it.
         || ((!crate_name.is_self_crate())  && src_object.visibility != HirVisibility::Public)
@@ -15,7 +15,7 @@ use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::ty::enum_or_struct::{
     parse_struct_or_enum_should_ignore, EnumOrStructParser, EnumOrStructParserInfo,
 };
-use crate::codegen::parser::mir::parser::ty::misc::parse_comments;
+use crate::codegen::parser::mir::parser::ty::misc::{parse_cfg, parse_comments};
 use crate::codegen::parser::mir::parser::ty::structure::structure_compute_default_opaque;
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::{TypeParserParsingContext, TypeParserWithContext};
@@ -45,14 +45,15 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         wrapper_name: Option<String>,
     ) -> anyhow::Result<MirEnum> {
         let comments = parse_comments(&src_enum.src.attrs);
+        let exception_hierarchy = FrbAttributes::parse(&src_enum.src.attrs)?.exception_hierarchy();
         let raw_variants = src_enum
             .src
             .variants
             .iter()
-            .map(|variant| self.parse_variant(src_enum, variant))
+            .map(|variant| self.parse_variant(src_enum, variant, exception_hierarchy))
             .collect::<anyhow::Result<Vec<_>>>()?;
 
-        let mode = compute_enum_mode(&raw_variants);
+        let mode = compute_enum_mode(&raw_variants, exception_hierarchy);
         let variants = maybe_field_wrap_box(raw_variants, mode);
         let ignore = parse_struct_or_enum_should_ignore(
             src_enum,
@@ -67,6 +68,8 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             variants,
             mode,
             ignore,
+            exception_hierarchy,
+            rust_cfg: parse_cfg(&src_enum.src.attrs),
         })
     }
 
@@ -74,11 +77,19 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         &mut self,
         src_enum: &HirFlatEnum,
         variant: &Variant,
+        exception_hierarchy: bool,
     ) -> anyhow::Result<MirEnumVariant> {
         let variant_name = MirIdent::new(variant.ident.to_string(), None);
         Ok(MirEnumVariant {
             name: variant_name.clone(),
-            wrapper_name: MirIdent::new(format!("{}_{}", src_enum.name.name, variant.ident), None),
+            wrapper_name: MirIdent::new(
+                compute_variant_wrapper_name(
+                    &src_enum.name.name,
+                    &variant.ident.to_string(),
+                    exception_hierarchy,
+                ),
+                None,
+            ),
             comments: parse_comments(&variant.attrs),
             kind: match variant.fields.iter().next() {
                 None => MirVariantKind::Value,
@@ -115,7 +126,9 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             generate_hash: true,
             generate_eq: true,
             ui_state: attributes.ui_state(),
+            lazy: false,
             comments: parse_comments(attrs),
+            rust_cfg: None,
             fields: variant
                 .fields
                 .iter()
@@ -252,10 +265,29 @@ fn mir_type_wrap_box(ty: &mut MirType) {
     }
 }
 
-fn compute_enum_mode(variants: &[MirEnumVariant]) -> MirEnumMode {
-    if variants
-        .iter()
-        .any(|variant| !matches!(variant.kind, MirVariantKind::Value))
+/// With `#[frb(exception_hierarchy)]`, the Dart class of a variant is named after the variant only,
+/// e.g. `on NetworkTimeoutException catch (e)` instead of `on MyError_NetworkTimeout catch (e)`,
+/// while clashing names are resolved later by `exception_hierarchy_transformer`
+fn compute_variant_wrapper_name(
+    enum_name: &str,
+    variant_name: &str,
+    exception_hierarchy: bool,
+) -> String {
+    if !exception_hierarchy {
+        format!("{enum_name}_{variant_name}")
+    } else if variant_name.ends_with("Exception") {
+        variant_name.to_owned()
+    } else {
+        format!("{variant_name}Exception")
+    }
+}
+
+fn compute_enum_mode(variants: &[MirEnumVariant], exception_hierarchy: bool) -> MirEnumMode {
+    // Even variants without fields need to be classes, thus it cannot be a Dart enum
+    if exception_hierarchy
+        || variants
+            .iter()
+            .any(|variant| !matches!(variant.kind, MirVariantKind::Value))
     {
         MirEnumMode::Complex
     } else {
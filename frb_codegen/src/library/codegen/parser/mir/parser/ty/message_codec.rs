@@ -0,0 +1,107 @@
+use crate::codegen::ir::hir::flat::pack::HirFlatPack;
+use crate::codegen::ir::mir::ty::delegate::{
+    MirMessageCodec, MirTypeDelegate, MirTypeDelegateMessageCodec,
+};
+use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
+use crate::codegen::parser::mir::parser::ty::TypeParserWithContext;
+use crate::if_then_some;
+use anyhow::Context;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use syn::{Path, PathArguments, Type};
+
+/// The types that can be transferred by each message codec, recognized by the code generated for them
+#[derive(Default)]
+pub(crate) struct MessageCodecTypes {
+    /// Types implementing `prost::Message`
+    protobuf: HashSet<String>,
+    /// Types of the flatbuffers object API, e.g. `MonsterT`, and the tables they are unpacked from, e.g. `Monster`
+    flatbuffers: HashMap<String, Path>,
+}
+
+impl MessageCodecTypes {
+    pub(crate) fn new(pack: &HirFlatPack) -> Self {
+        let protobuf = (pack.trait_impls.iter())
+            .filter(|x| x.trait_name == "Message")
+            .filter_map(|x| type_name(&x.impl_ty))
+            .collect();
+
+        // `flatc --gen-object-api` generates a `MonsterT` object for each `Monster` table,
+        // which is verifiable so that it can be read from untrusted buffers
+        let structs: HashSet<_> = (pack.structs.iter())
+            .map(|x| x.name.name.as_str())
+            .collect();
+        let flatbuffers = (pack.trait_impls.iter())
+            .filter(|x| x.trait_name == "Verifiable")
+            .filter_map(|x| {
+                let mut table = if_then_some!(let Type::Path(ty) = &x.impl_ty, ty.path.clone())?;
+                // e.g. the lifetime of `Monster<'a>`
+                (table.segments.iter_mut()).for_each(|x| x.arguments = PathArguments::None);
+                let object = format!("{}T", table.segments.last()?.ident);
+                structs.contains(object.as_str()).then_some((object, table))
+            })
+            .collect();
+
+        Self {
+            protobuf,
+            flatbuffers,
+        }
+    }
+}
+
+impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
+    /// In functions with `#[frb(codec = ...)]`, the types generated for the codec,
+    /// e.g. `proto::Person` implementing `prost::Message`, are messages of the codec
+    pub(crate) fn parse_type_path_data_message_codec(
+        &mut self,
+        path: &Path,
+        last_segment: &SplayedSegment,
+    ) -> anyhow::Result<Option<MirType>> {
+        let Some(codec) = self.context.func_attributes.message_codec() else {
+            return Ok(None);
+        };
+        if !last_segment.1.is_empty() {
+            return Ok(None);
+        }
+        let name = last_segment.0;
+
+        let types = &self.inner.message_codec_types;
+        let rust_table_type = match codec {
+            MirMessageCodec::Protobuf if types.protobuf.contains(name) => None,
+            MirMessageCodec::Flatbuffers => match types.flatbuffers.get(name) {
+                Some(table) => Some(self.qualify_message_path(table)?),
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(MirType::Delegate(MirTypeDelegate::MessageCodec(
+            MirTypeDelegateMessageCodec {
+                codec,
+                rust_type: self.qualify_message_path(path)?,
+                dart_type: name.to_owned(),
+                rust_table_type,
+            },
+        ))))
+    }
+
+    /// The generated code does not import the modules of the messages
+    fn qualify_message_path(&self, path: &Path) -> anyhow::Result<String> {
+        Ok((self.inner.type_qualifier.resolve(path))
+            .with_context(|| {
+                format!(
+                    "Cannot find the message `{}` in the crate",
+                    (path.segments.iter())
+                        .map(|x| x.ident.to_string())
+                        .join("::")
+                )
+            })?
+            .rust_style())
+    }
+}
+
+fn type_name(ty: &Type) -> Option<String> {
+    let path = &if_then_some!(let Type::Path(ty) = ty, ty)?.path;
+    Some(path.segments.last()?.ident.to_string())
+}
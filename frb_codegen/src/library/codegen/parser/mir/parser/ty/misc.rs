@@ -30,6 +30,14 @@ pub(crate) fn parse_comments(attrs: &[Attribute]) -> Vec<MirComment> {
         .collect()
 }
 
+/// The `#[cfg(...)]` of an item, e.g. added when it only exists in some of the `rust_feature_sets`
+pub(crate) fn parse_cfg(attrs: &[Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match &attr.meta {
+        Meta::List(list) if list.path.is_ident("cfg") => Some(list.tokens.to_string()),
+        _ => None,
+    })
+}
+
 fn parse_comment(input: &str) -> MirComment {
     let input = input.trim_matches('\n');
     MirComment(if input.contains('\n') {
@@ -14,14 +14,16 @@ use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
 use crate::codegen::ir::mir::ty::structure::{MirStruct, MirStructIdent};
 use crate::codegen::ir::mir::ty::MirContext;
 use crate::codegen::ir::mir::ty::MirType;
+use crate::codegen::parser::early_generator::utils::type_qualifier::TypeQualifier;
 use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::ty::array::ArrayParserInfo;
 use crate::codegen::parser::mir::parser::ty::enum_or_struct::EnumOrStructParserInfo;
+use crate::codegen::parser::mir::parser::ty::message_codec::MessageCodecTypes;
 use crate::codegen::parser::mir::parser::ty::rust_auto_opaque_implicit::RustAutoOpaqueParserInfo;
 use crate::codegen::parser::mir::parser::ty::rust_opaque::RustOpaqueParserInfo;
 use crate::codegen::parser::mir::ParseMode;
 use crate::utils::basic_code::general_code::GeneralDartCode;
-use crate::utils::namespace::Namespace;
+use crate::utils::namespace::{Namespace, NamespacedName};
 use std::collections::HashMap;
 use syn::Type;
 
@@ -34,6 +36,7 @@ pub(crate) mod enumeration;
 pub(crate) mod external_impl;
 pub(crate) mod generics;
 pub(crate) mod lifetimeable;
+mod message_codec;
 pub(crate) mod misc;
 mod namespace;
 pub(crate) mod optional;
@@ -58,9 +61,12 @@ pub(crate) struct TypeParser<'a> {
     src_enums: HashMap<String, &'a HirFlatEnum>,
     pub(super) src_traits: HashMap<String, &'a HirFlatTrait>,
     src_types: HashMap<String, Type>,
+    src_imports: HashMap<NamespacedName, String>,
     pub(super) proxied_types: Vec<IrEarlyGeneratorProxiedType>,
     pub(super) trait_def_infos: Vec<IrEarlyGeneratorTraitDefInfo>,
     pub(super) custom_ser_des_infos: Vec<MirCustomSerDes>,
+    type_qualifier: TypeQualifier,
+    message_codec_types: MessageCodecTypes,
     dart_code_of_type: HashMap<String, GeneralDartCode>,
     struct_parser_info: EnumOrStructParserInfo<MirStructIdent, MirStruct>,
     enum_parser_info: EnumOrStructParserInfo<MirEnumIdent, MirEnum>,
@@ -72,31 +78,17 @@ pub(crate) struct TypeParser<'a> {
 
 impl<'a> TypeParser<'a> {
     pub(crate) fn new_from_pack(ir_pack: &'a IrEarlyGeneratorPack) -> Self {
-        Self::new(
-            ir_pack.hir_flat_pack.structs_map(),
-            ir_pack.hir_flat_pack.enums_map(),
-            ir_pack.hir_flat_pack.traits_map(),
-            ir_pack.hir_flat_pack.types_map(),
-            ir_pack.proxied_types.clone(),
-            ir_pack.trait_def_infos.clone(),
-        )
-    }
-
-    fn new(
-        src_structs: HashMap<String, &'a HirFlatStruct>,
-        src_enums: HashMap<String, &'a HirFlatEnum>,
-        src_traits: HashMap<String, &'a HirFlatTrait>,
-        src_types: HashMap<String, Type>,
-        proxied_types: Vec<IrEarlyGeneratorProxiedType>,
-        trait_def_infos: Vec<IrEarlyGeneratorTraitDefInfo>,
-    ) -> Self {
+        let hir_flat_pack = &ir_pack.hir_flat_pack;
         TypeParser {
-            src_structs,
-            src_enums,
-            src_traits,
-            src_types,
-            proxied_types,
-            trait_def_infos,
+            src_structs: hir_flat_pack.structs_map(),
+            src_enums: hir_flat_pack.enums_map(),
+            src_traits: hir_flat_pack.traits_map(),
+            src_types: hir_flat_pack.types_map(),
+            src_imports: hir_flat_pack.imports_map(),
+            proxied_types: ir_pack.proxied_types.clone(),
+            trait_def_infos: ir_pack.trait_def_infos.clone(),
+            type_qualifier: TypeQualifier::new(hir_flat_pack),
+            message_codec_types: MessageCodecTypes::new(hir_flat_pack),
             custom_ser_des_infos: Default::default(),
             dart_code_of_type: HashMap::new(),
             struct_parser_info: EnumOrStructParserInfo::new(),
@@ -124,6 +116,17 @@ impl<'a> TypeParser<'a> {
         TypeParserWithContext::new(self, context).parse_type(ty)
     }
 
+    /// The types in `type_mappings` usually come from other crates, thus they are kept as written
+    /// instead of being looked up among the structs and enums by their names
+    pub(crate) fn parse_type_mapped(
+        &mut self,
+        ty: &Type,
+        context: &TypeParserParsingContext,
+    ) -> anyhow::Result<MirType> {
+        TypeParserWithContext::new(self, context)
+            .parse_type_rust_auto_opaque_implicit(None, ty, None, None)
+    }
+
     pub(crate) fn transform_rust_auto_opaque(
         &mut self,
         ty_raw: &MirTypeRustAutoOpaqueImplicit,
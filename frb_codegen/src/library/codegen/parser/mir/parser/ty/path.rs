@@ -33,7 +33,7 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
         let splayed_segments = splay_segments(&segments);
 
         if let Some(last_segment) = splayed_segments.last() {
-            if let Some(ans) = self.parse_type_path_data_custom_ser_des(last_segment)? {
+            if let Some(ans) = self.parse_type_path_data_custom_ser_des(type_path, last_segment)? {
                 return Ok(ans);
             }
             if let Some(ans) = self.parse_type_path_data_primitive(last_segment)? {
@@ -44,6 +44,9 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             {
                 return Ok(ans);
             }
+            if let Some(ans) = self.parse_type_path_data_message_codec(path, last_segment)? {
+                return Ok(ans);
+            }
             if let Some(ans) = self.parse_type_path_data_struct(path, last_segment, None)? {
                 return Ok(ans);
             }
@@ -1,7 +1,7 @@
 use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
+use crate::codegen::ir::mir::ty::rust_opaque::MirNameComponent;
 use crate::codegen::ir::mir::ty::MirType;
 use crate::codegen::ir::mir::ty::MirType::{EnumRef, StructRef};
-use crate::codegen::parser::mir::parser::ty::unencodable::splay_segments;
 use crate::codegen::parser::mir::parser::ty::{TypeParser, TypeParserParsingContext};
 use anyhow::Context;
 
@@ -12,11 +12,11 @@ pub(crate) fn parse_type_maybe_result(
     context: &TypeParserParsingContext,
 ) -> anyhow::Result<ResultTypeInfo> {
     if let MirType::RustAutoOpaqueImplicit(inner) = mir {
-        match splay_segments(&inner.raw.segments).last() {
-            Some(("Result", args)) => {
+        match inner.raw.segments.last() {
+            Some(MirNameComponent { ident, args }) if ident == "Result" => {
                 return parse_type_result(
                     &(args.iter())
-                        .map(|arg| type_parser.parse_type(arg, context))
+                        .map(|arg| type_parser.parse_type(&syn::parse_str(arg)?, context))
                         .collect::<anyhow::Result<Vec<_>>>()?,
                 );
             }
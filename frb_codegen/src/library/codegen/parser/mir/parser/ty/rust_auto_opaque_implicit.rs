@@ -4,7 +4,7 @@ use crate::codegen::ir::mir::ty::rust_auto_opaque_implicit::{
     MirRustAutoOpaqueRaw, MirTypeRustAutoOpaqueImplicit, MirTypeRustAutoOpaqueImplicitReason,
 };
 use crate::codegen::ir::mir::ty::rust_opaque::{
-    MirRustOpaqueInner, MirTypeRustOpaque, RustOpaqueCodecMode,
+    MirNameComponent, MirRustOpaqueInner, MirTypeRustOpaque, RustOpaqueCodecMode,
 };
 use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
 use crate::codegen::parser::mir::parser::ty::path_data::extract_path_data;
@@ -119,7 +119,7 @@ fn parse_type_rust_auto_opaque_common_raw(
     Ok((
         MirRustAutoOpaqueRaw {
             string: MirLifetimeAwareType::new(inner_str.clone()),
-            segments: raw_segments,
+            segments: raw_segments.iter().map(MirNameComponent::from).collect(),
         },
         MirTypeRustOpaque {
             namespace,
@@ -9,7 +9,7 @@ use crate::codegen::parser::mir::parser::attribute::FrbAttributes;
 use crate::codegen::parser::mir::parser::ty::enum_or_struct::{
     parse_struct_or_enum_should_ignore, EnumOrStructParser, EnumOrStructParserInfo,
 };
-use crate::codegen::parser::mir::parser::ty::misc::parse_comments;
+use crate::codegen::parser::mir::parser::ty::misc::{parse_cfg, parse_comments};
 use crate::codegen::parser::mir::parser::ty::unencodable::SplayedSegment;
 use crate::codegen::parser::mir::parser::ty::{TypeParserParsingContext, TypeParserWithContext};
 use crate::utils::basic_code::general_code::GeneralDartCode;
@@ -75,7 +75,9 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
             generate_hash: attributes.generate_hash(),
             generate_eq: attributes.generate_eq(),
             ui_state: attributes.ui_state(),
+            lazy: attributes.lazy(),
             comments,
+            rust_cfg: parse_cfg(&src_struct.src.attrs),
         })
     }
 
@@ -158,7 +160,7 @@ impl EnumOrStructParser<MirStructIdent, MirStruct, ItemStruct>
     }
 }
 
-pub(super) fn structure_compute_default_opaque(s: &MirStruct, crate_name: &CrateName) -> bool {
+pub(crate) fn structure_compute_default_opaque(s: &MirStruct, crate_name: &CrateName) -> bool {
     (s.fields.iter()).any(|f| {
         matches!(f.ty, MirType::RustAutoOpaqueImplicit(_))
             || ((!crate_name.is_self_crate()) && !f.is_rust_public.unwrap())
@@ -44,7 +44,9 @@ impl<'a, 'b, 'c> TypeParserWithContext<'a, 'b, 'c> {
                 generate_hash: true,
                 generate_eq: true,
                 ui_state: false,
+                lazy: false,
                 comments: vec![],
+                rust_cfg: None,
                 fields: values
                     .iter()
                     .enumerate()
@@ -0,0 +1,33 @@
+use crate::codegen::generator::codec::structs::CodecMode;
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::library::codegen::ir::mir::ty::MirTypeTrait;
+use anyhow::bail;
+
+/// Types existing in only some of the `rust_feature_sets` have their SSE code gated by `#[cfg(...)]`,
+/// while the other codecs generate per-type wire structures that cannot be gated, thus are rejected.
+pub(crate) fn check_feature_gated_type_codecs(pack: &MirPack) -> anyhow::Result<()> {
+    for func in &pack.funcs_all {
+        let types = (func.inputs.iter())
+            .map(|input| (&input.inner.ty, func.codec_mode_pack.dart2rust))
+            .chain(
+                ([&func.output.normal].into_iter())
+                    .chain(&func.output.error)
+                    .map(|ty| (ty, func.codec_mode_pack.rust2dart)),
+            );
+
+        for (ty, codec) in types {
+            if codec.delegate_or_self() == CodecMode::Sse {
+                continue;
+            }
+            if let Some(cfg) = ty.rust_cfg(pack) {
+                bail!(
+                    "`{}` only exists in some of the `rust_feature_sets` (cfg: {cfg}), which is only supported by the SSE codec, \
+                    but `{}` transfers it via the {codec} codec",
+                    ty.rust_api_type(),
+                    func.name.rust_style(),
+                );
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::codegen::generator::codec::structs::CodecMode;
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::ir::mir::ty::delegate::MirTypeDelegate;
+use crate::codegen::ir::mir::ty::MirType;
+use anyhow::bail;
+
+/// `#[frb(lazy)]` structs are only lazily decoded by the SSE codec,
+/// thus sending them to Dart via other codecs is rejected instead of silently decoding eagerly.
+pub(crate) fn check_lazy_struct_codecs(pack: &MirPack) -> anyhow::Result<()> {
+    for func in &pack.funcs_all {
+        let mut rust2dart_types = ([&func.output.normal].into_iter())
+            .chain(&func.output.error)
+            .map(|ty| (ty.clone(), func.codec_mode_pack.rust2dart))
+            .collect::<Vec<_>>();
+        for input in &func.inputs {
+            input.inner.ty.visit_types(
+                &mut |ty| {
+                    if let MirType::Delegate(MirTypeDelegate::StreamSink(sink)) = ty {
+                        rust2dart_types.push(((*sink.inner_ok).clone(), sink.codec));
+                        rust2dart_types.push(((*sink.inner_err).clone(), sink.codec));
+                    }
+                    false
+                },
+                pack,
+            );
+        }
+
+        for (ty, codec) in rust2dart_types {
+            if codec.delegate_or_self() == CodecMode::Sse {
+                continue;
+            }
+            if let Some(name) = find_lazy_struct(&ty, pack) {
+                bail!(
+                    "`{name}` is `#[frb(lazy)]`, which is only supported by the SSE codec, \
+                    but `{}` sends it to Dart via the {codec} codec",
+                    func.name.rust_style(),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+fn find_lazy_struct(ty: &MirType, pack: &MirPack) -> Option<String> {
+    let mut ans = None;
+    ty.visit_types(
+        &mut |ty| {
+            if let MirType::StructRef(s) = ty {
+                if ans.is_none() && s.get(pack).lazy {
+                    ans = Some(s.ident.0.name.clone());
+                }
+            }
+            ans.is_some()
+        },
+        pack,
+    );
+    ans
+}
@@ -1,4 +1,6 @@
 pub(crate) mod auto_accessor_checker;
+pub(crate) mod feature_gated_type_checker;
+pub(crate) mod lazy_struct_checker;
 pub(crate) mod misc_checker;
 pub(crate) mod opaque_inside_translatable_checker;
 pub(crate) mod unused_checker;
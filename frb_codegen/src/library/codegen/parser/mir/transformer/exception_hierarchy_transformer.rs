@@ -0,0 +1,94 @@
+use crate::codegen::ir::mir::ident::MirIdent;
+use crate::codegen::ir::mir::pack::MirPack;
+use anyhow::bail;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// Exception classes which the generated Dart code may see, from the Dart SDK and `flutter_rust_bridge`
+const DART_EXCEPTION_NAMES: &[&str] = &[
+    "Exception",
+    "FormatException",
+    "IntegerDivisionByZeroException",
+    "TimeoutException",
+    "AnyhowException",
+    "CloseStreamException",
+    "DroppableDisposedException",
+    "FrbBacktracedException",
+    "FrbException",
+    "MissingHeaderException",
+    "PanicException",
+    "PlatformMismatchException",
+    "UnmodifiableTypedListException",
+];
+
+/// The Dart classes of `#[frb(exception_hierarchy)]` variants are named after the variants only,
+/// thus the clashing ones are prefixed by the enum name, e.g. `NetworkErrorTimeoutException`
+pub(crate) fn transform(mut pack: MirPack) -> anyhow::Result<MirPack> {
+    let taken_names = compute_taken_names(&pack);
+    let name_counts = (compute_variant_wrapper_names(&pack).into_iter())
+        .map(|(_, name)| name)
+        .counts();
+
+    for enu in pack.enum_pool.values_mut() {
+        if !enu.exception_hierarchy || enu.ignore {
+            continue;
+        }
+        for variant in &mut enu.variants {
+            let name = variant.wrapper_name.rust_style();
+            if name_counts[&name] > 1 || taken_names.contains(&name) {
+                variant.wrapper_name = MirIdent::new(format!("{}{name}", enu.name.name), None);
+            }
+        }
+    }
+
+    check_no_clash(&pack, &taken_names)?;
+    Ok(pack)
+}
+
+fn compute_taken_names(pack: &MirPack) -> HashSet<String> {
+    let struct_names = (pack.struct_pool.values())
+        .filter(|x| !x.ignore)
+        .map(|x| x.name.name.clone());
+    let enum_names = (pack.enum_pool.values())
+        .filter(|x| !x.ignore)
+        .map(|x| x.name.name.clone());
+    (DART_EXCEPTION_NAMES.iter().map(|x| x.to_string()))
+        .chain(struct_names)
+        .chain(enum_names)
+        .collect()
+}
+
+/// Pairs of `Enum::Variant` and the name of its Dart class
+fn compute_variant_wrapper_names(pack: &MirPack) -> Vec<(String, String)> {
+    (pack.enum_pool.values())
+        .filter(|enu| enu.exception_hierarchy && !enu.ignore)
+        .flat_map(|enu| {
+            (enu.variants.iter()).map(|variant| {
+                (
+                    format!("{}::{}", enu.name.name, variant.name.rust_style()),
+                    variant.wrapper_name.rust_style(),
+                )
+            })
+        })
+        .sorted()
+        .collect_vec()
+}
+
+fn check_no_clash(pack: &MirPack, taken_names: &HashSet<String>) -> anyhow::Result<()> {
+    let mut variants_of_name: HashMap<String, Vec<String>> = HashMap::new();
+    for (variant, name) in compute_variant_wrapper_names(pack) {
+        variants_of_name.entry(name).or_default().push(variant);
+    }
+    let clashes = (variants_of_name.into_iter())
+        .filter(|(name, variants)| variants.len() > 1 || taken_names.contains(name))
+        .map(|(name, variants)| format!("`{name}` ({})", variants.join(", ")))
+        .sorted()
+        .collect_vec();
+    if !clashes.is_empty() {
+        bail!(
+            "The Dart exception classes {} clash with other classes, please rename the variants",
+            clashes.join(", ")
+        );
+    }
+    Ok(())
+}
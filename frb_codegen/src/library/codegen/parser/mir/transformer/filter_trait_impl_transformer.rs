@@ -9,7 +9,10 @@ pub(crate) fn transform(mut pack: MirPack) -> anyhow::Result<MirPack> {
         .collect::<HashSet<_>>();
 
     pack.trait_impls = (pack.trait_impls.drain(..))
-        .filter(|item| distinct_type_safe_idents.contains(&item.impl_ty.safe_ident()))
+        .filter(|item| {
+            (item.impl_ty_safe_idents_for_matching().iter())
+                .any(|x| distinct_type_safe_idents.contains(x))
+        })
         .collect();
 
     Ok(pack)
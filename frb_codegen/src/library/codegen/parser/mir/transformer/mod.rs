@@ -1,2 +1,3 @@
 pub(crate) mod dyn_trait_inner_transformer;
+pub(crate) mod exception_hierarchy_transformer;
 pub(crate) mod filter_trait_impl_transformer;
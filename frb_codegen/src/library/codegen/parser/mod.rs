@@ -2,6 +2,7 @@ use crate::codegen::dumper::Dumper;
 use crate::codegen::ir::hir::flat::pack::HirFlatPack;
 use crate::codegen::ir::mir::pack::MirPack;
 use crate::codegen::misc::GeneratorProgressBarPack;
+use crate::codegen::parser::hir::raw::HirRawCache;
 use crate::codegen::parser::internal_config::ParserInternalConfig;
 use crate::codegen::ConfigDumpContent;
 use crate::codegen::ConfigDumpContent::Hir;
@@ -17,30 +18,37 @@ pub(crate) fn parse(
     dumper: &Dumper,
     progress_bar_pack: &GeneratorProgressBarPack,
 ) -> anyhow::Result<MirPack> {
-    parse_inner(config, dumper, progress_bar_pack, |_| Ok(()))
+    parse_with_hir_raw_cache(
+        config,
+        dumper,
+        progress_bar_pack,
+        &mut HirRawCache::default(),
+    )
+}
+
+pub(crate) fn parse_with_hir_raw_cache(
+    config: &ParserInternalConfig,
+    dumper: &Dumper,
+    progress_bar_pack: &GeneratorProgressBarPack,
+    hir_raw_cache: &mut HirRawCache,
+) -> anyhow::Result<MirPack> {
+    parse_inner(config, dumper, progress_bar_pack, hir_raw_cache, |_| Ok(()))
 }
 
 fn parse_inner(
     config: &ParserInternalConfig,
     dumper: &Dumper,
     progress_bar_pack: &GeneratorProgressBarPack,
+    hir_raw_cache: &mut HirRawCache,
     on_hir_flat: impl FnOnce(&HirFlatPack) -> anyhow::Result<()>,
 ) -> anyhow::Result<MirPack> {
     let dumper_hir = dumper.with_content(Hir);
-    let dumper_hir_tree = dumper_hir.with_add_name_prefix("hir_tree/");
-    let dumper_hir_naive_flat = dumper_hir.with_add_name_prefix("hir_naive_flat/");
-    let dumper_hir_flat = dumper_hir.with_add_name_prefix("hir_flat/");
     let dumper_early_generator = dumper_hir.with_add_name_prefix("early_generator/");
     let dumper_mir = dumper.with_content(Mir);
 
-    let pb = progress_bar_pack.parse_hir_raw.start();
-    let hir_raw = hir::raw::parse(&config.hir, dumper)?;
-    drop(pb);
+    let hir_flat = parse_hir(config, dumper, progress_bar_pack, hir_raw_cache)?;
 
     let pb = progress_bar_pack.parse_hir_primary.start();
-    let hir_tree = hir::tree::parse(&config.hir, hir_raw, &dumper_hir_tree)?;
-    let hir_naive_flat = hir::naive_flat::parse(&config.hir, hir_tree, &dumper_hir_naive_flat)?;
-    let hir_flat = hir::flat::parse(&config.hir, hir_naive_flat, &dumper_hir_flat)?;
     on_hir_flat(&hir_flat)?;
     let ir_early_generator =
         early_generator::execute(hir_flat, &config.mir, &dumper_early_generator)?;
@@ -58,12 +66,68 @@ fn parse_inner(
     Ok(mir_pack)
 }
 
+/// Only parse until the HIR stage, merging the feature sets if any
+pub(crate) fn parse_hir(
+    config: &ParserInternalConfig,
+    dumper: &Dumper,
+    progress_bar_pack: &GeneratorProgressBarPack,
+    hir_raw_cache: &mut HirRawCache,
+) -> anyhow::Result<HirFlatPack> {
+    if config.hir.rust_feature_sets.is_empty() {
+        return parse_hir_flat(config, &[], dumper, progress_bar_pack, hir_raw_cache);
+    }
+
+    let hir_flats = (config.hir.rust_feature_sets.iter())
+        .map(|feature_set| {
+            parse_hir_flat(
+                config,
+                &feature_set.features,
+                &dumper.with_add_name_prefix(&format!("feature_set_{}/", feature_set.name)),
+                progress_bar_pack,
+                hir_raw_cache,
+            )
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    hir::feature_sets::merge(&config.hir.rust_feature_sets, hir_flats)
+}
+
+/// `features` are passed to `cargo expand` for the self crate
+fn parse_hir_flat(
+    config: &ParserInternalConfig,
+    features: &[String],
+    dumper: &Dumper,
+    progress_bar_pack: &GeneratorProgressBarPack,
+    hir_raw_cache: &mut HirRawCache,
+) -> anyhow::Result<HirFlatPack> {
+    let dumper_hir = dumper.with_content(Hir);
+    let dumper_hir_tree = dumper_hir.with_add_name_prefix("hir_tree/");
+    let dumper_hir_naive_flat = dumper_hir.with_add_name_prefix("hir_naive_flat/");
+    let dumper_hir_flat = dumper_hir.with_add_name_prefix("hir_flat/");
+
+    let pb = progress_bar_pack.parse_hir_raw.start();
+    let hir_raw = hir::raw::parse(&config.hir, features, dumper, hir_raw_cache)?;
+    drop(pb);
+
+    let pb = progress_bar_pack.parse_hir_primary.start();
+    let hir_tree = hir::tree::parse(&config.hir, hir_raw, &dumper_hir_tree)?;
+    let hir_naive_flat = hir::naive_flat::parse(&config.hir, hir_tree, &dumper_hir_naive_flat)?;
+    let hir_flat = hir::flat::parse(&config.hir, hir_naive_flat, &dumper_hir_flat)?;
+    drop(pb);
+
+    Ok(hir_flat)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::codegen::config::internal_config_parser::compute_force_codec_mode_pack;
     use crate::codegen::dumper::Dumper;
     use crate::codegen::generator::codec::structs::CodecMode;
+    use crate::codegen::ir::mir::func::{
+        MirFunc, MirFuncImplMode, MirFuncMode, MirFuncOwnerInfo, MirFuncOwnerInfoMethodMode,
+    };
+    use crate::codegen::ir::mir::ty::delegate::{MirMessageCodec, MirTypeDelegate};
     use crate::codegen::ir::mir::ty::rust_opaque::RustOpaqueCodecMode;
+    use crate::codegen::ir::mir::ty::{MirType, MirTypeTrait};
     use crate::codegen::misc::GeneratorProgressBarPack;
     use crate::codegen::parser::hir::internal_config::ParserHirInternalConfig;
     use crate::codegen::parser::internal_config::ParserInternalConfig;
@@ -71,11 +135,13 @@ mod tests {
         ParserMirInternalConfig, RustInputNamespacePack,
     };
     use crate::codegen::parser::{parse_inner, MirPack};
+    use crate::utils::crate_name::CrateName;
     use crate::utils::logs::configure_opinionated_test_logging;
     use crate::utils::namespace::Namespace;
     use crate::utils::test_utils::{
         create_path_sanitizers, get_test_fixture_dir, json_golden_test,
     };
+    use itertools::Itertools;
     use log::info;
     use serial_test::serial;
     use std::path::{Path, PathBuf};
@@ -131,12 +197,613 @@ mod tests {
         body("library/codegen/parser/mod/generics", None)
     }
 
+    #[test]
+    #[serial]
+    fn test_generic_instantiation() -> anyhow::Result<()> {
+        body("library/codegen/parser/mod/generic_instantiation", None)?;
+        let (pack, _) = execute_parse("library/codegen/parser/mod/generic_instantiation", None)?;
+
+        let fetch = find_func(&pack, "fetch_my_dto");
+        assert_eq!(input_safe_idents(fetch), vec!["box_autoadd_my_dto"]);
+        assert_eq!(fetch.output.normal.safe_ident(), "response_my_dto");
+        assert_eq!(
+            fetch.rust_call_name.as_deref(),
+            Some("fetch::<crate :: api :: MyDto>")
+        );
+        assert!(!(pack.funcs_all.iter()).any(|f| f.name.name == "fetch"));
+
+        // Existing usages of the generic types refer to the instances
+        assert_eq!(
+            find_func(&pack, "fetch_text").output.normal.safe_ident(),
+            "response_string"
+        );
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "read_entry")),
+            vec!["box_autoadd_entry_string_my_dto"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_trait_impl() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/trait_impl", None)?;
+
+        let opaque = |name: &str| {
+            format!(
+                "Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInner{name}"
+            )
+        };
+        let trait_impls = (pack.trait_impls.iter())
+            .map(|x| (x.trait_ty.name.name.clone(), x.impl_ty.safe_ident()))
+            .sorted()
+            .collect_vec();
+        // `MemoryRepository` is only used by reference, while `HiddenRepository` is not used at all
+        assert_eq!(
+            trait_impls,
+            vec![
+                ("Repository".to_owned(), opaque("FileRepository")),
+                ("Repository".to_owned(), opaque("MemoryRepository")),
+                ("Tagged".to_owned(), opaque("MemoryRepository")),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_exception_hierarchy() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/exception_hierarchy", None)?;
+
+        let variant_wrapper_names = (pack.enum_pool.values())
+            .flat_map(|enu| enu.variants.iter().map(|x| x.wrapper_name.rust_style()))
+            .sorted()
+            .collect_vec();
+        // `Timeout` and `Format` clash with the Dart classes, and `NotFound` exists in both enums
+        assert_eq!(
+            variant_wrapper_names,
+            vec![
+                "DiskFullException",
+                "NetworkErrorNotFoundException",
+                "NetworkErrorTimeoutException",
+                "OfflineException",
+                "StorageErrorFormatException",
+                "StorageErrorNotFoundException",
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_exception_hierarchy_clash() {
+        let err = execute_parse("library/codegen/parser/mod/exception_hierarchy_clash", None)
+            .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("`NetworkErrorTimeoutException` (NetworkError::Timeout)"),
+            "{err:#}"
+        );
+    }
+
     #[test]
     #[serial]
     fn test_unused_struct_enum() -> anyhow::Result<()> {
         body("library/codegen/parser/mod/unused_struct_enum", None)
     }
 
+    #[test]
+    #[serial]
+    fn test_api_version() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse_api_version("library/codegen/parser/mod/api_version")?;
+
+        assert_eq!(
+            (pack.funcs_all.iter())
+                .map(|f| f.name.rust_style())
+                .sorted()
+                .collect_vec(),
+            vec!["crate::api::v2::get_settings", "crate::api::v2::get_user"]
+        );
+
+        // The types of `v1` are shadowed by the ones with the same names in `v2`
+        assert_eq!(
+            (pack.struct_pool.keys())
+                .map(|x| x.0.rust_style())
+                .sorted()
+                .collect_vec(),
+            vec!["crate::api::v2::Settings", "crate::api::v2::User"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_api_version_referenced_shadowed() {
+        let err =
+            execute_parse_api_version("library/codegen/parser/mod/api_version_referenced_shadowed")
+                .unwrap_err();
+        // `v2::migrate_settings` takes `v1::Settings`, which cannot be told apart from `v2::Settings`
+        assert!(
+            format!("{err:#}").contains("Types `crate::api::v1::Settings` are used"),
+            "{err:#}"
+        );
+    }
+
+    fn execute_parse_api_version(fixture_name: &str) -> anyhow::Result<(MirPack, PathBuf)> {
+        execute_parse_with_config(
+            fixture_name,
+            Some(Box::new(|_rust_crate_dir| RustInputNamespacePack {
+                rust_input_namespace_prefixes: vec![Namespace::new_self_crate(
+                    "api::v2".to_owned(),
+                )],
+                rust_output_path_namespace: Namespace::new_self_crate("frb_generated".to_owned()),
+            })),
+            |config| config.hir.api_version = Some("v2".to_owned()),
+        )
+    }
+
+    #[test]
+    #[serial]
+    fn test_dart_service() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/dart_service", None)?;
+
+        // The trait is implemented in Dart, thus its methods are not callable from Dart
+        for name in [
+            "TraitDef_SecureStorage_read",
+            "TraitDef_SecureStorage_write",
+        ] {
+            assert_eq!(find_func(&pack, name).impl_mode, MirFuncImplMode::NoImpl);
+        }
+
+        // The proxy is created from one Dart callback per trait method
+        let constructor = find_func(&pack, "SecureStorageDartProxy_frb_internal_new");
+        assert_eq!(constructor.mode, MirFuncMode::Sync);
+        assert_eq!(
+            input_safe_idents(constructor),
+            vec![
+                "DartFn_Inputs_String_Output_opt_String_AnyhowException",
+                "DartFn_Inputs_String_String_Output_unit_AnyhowException",
+            ]
+        );
+
+        // ... and can be passed to Rust functions
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "use_secure_storage")),
+            vec![constructor.output.normal.safe_ident()]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_background_task() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/background_task", None)?;
+
+        // The function is only exposed via the task, whose context is not a Dart argument
+        assert!(!(pack.funcs_all.iter()).any(|f| f.name.name == "download_file"));
+        let start = find_func(&pack, "DownloadFileTask_start");
+        assert!(start.rust_async);
+        assert_eq!(input_safe_idents(start), vec!["String"]);
+        assert!(start
+            .output
+            .normal
+            .safe_ident()
+            .ends_with("DownloadFileTask"));
+
+        // Controlling the task returns immediately, while waiting for the result does not
+        for name in [
+            "pause",
+            "resume",
+            "cancel",
+            "is_paused",
+            "is_cancelled",
+            "is_finished",
+        ] {
+            let func = find_func(&pack, &format!("DownloadFileTask_{name}"));
+            assert_eq!(func.mode, MirFuncMode::Sync, "{name}");
+        }
+        let join = find_func(&pack, "DownloadFileTask_join");
+        assert_eq!(join.mode, MirFuncMode::Normal);
+        assert_eq!(join.output.normal.safe_ident(), "String");
+        assert_eq!(
+            join.output.error.as_ref().map(|x| x.safe_ident()),
+            Some("String".to_owned())
+        );
+
+        // The progress type of the context is reported via a stream
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "DownloadFileTask_progress"))[1],
+            "StreamSink_u_32_Dco"
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_background_task_struct_progress() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse(
+            "library/codegen/parser/mod/background_task_struct_progress",
+            None,
+        )?;
+
+        // The progress type lives in the API module, thus is qualified in the generated task
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "SyncFilesTask_progress"))[1],
+            "StreamSink_sync_progress_Dco"
+        );
+        assert!(pack
+            .struct_pool
+            .keys()
+            .any(|x| x.0.rust_style() == "crate::api::SyncProgress"));
+
+        // A second `join` gives an error instead of panicking
+        let join = find_func(&pack, "SyncFilesTask_join");
+        assert_eq!(join.output.normal.safe_ident(), "u_32");
+        assert_eq!(
+            join.output.error.as_ref().map(|x| x.safe_ident()),
+            Some("AnyhowException".to_owned())
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_thread_pinned() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/thread_pinned", None)?;
+
+        // Only the wrapper is exposed, and it keeps the methods of the pinned type
+        assert!(!(pack.funcs_all.iter()).any(|f| f.name.name.starts_with("Camera_")));
+        let wrapper = "Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCameraThreadPinned";
+        let new = find_func(&pack, "CameraThreadPinned_new");
+        assert_eq!(input_safe_idents(new), vec!["i_32"]);
+        assert_eq!(new.output.normal.safe_ident(), wrapper);
+        let open = find_func(&pack, "CameraThreadPinned_open");
+        assert_eq!(open.output.normal.safe_ident(), wrapper);
+        assert_eq!(
+            open.output.error.as_ref().map(|x| x.safe_ident()),
+            Some("String".to_owned())
+        );
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "CameraThreadPinned_capture"))[1],
+            "u_32"
+        );
+        assert!(input_safe_idents(find_func(&pack, "CameraThreadPinned_version")).is_empty());
+
+        // Attributes of the original methods are kept
+        assert_eq!(
+            find_func(&pack, "CameraThreadPinned_id").mode,
+            MirFuncMode::Sync
+        );
+
+        // Users refer to the wrapper in their own functions
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "use_camera")),
+            vec![wrapper]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_thread_pinned_rejected_on_web() {
+        let err =
+            execute_parse_with_config("library/codegen/parser/mod/thread_pinned", None, |config| {
+                config.mir.web_enabled = true
+            })
+            .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("not supported on the web"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_lazy_struct_rejected_by_non_sse_codec() {
+        // Parser tests default to `full_dep`, i.e. the DCO codec
+        let err = execute_parse("library/codegen/parser/mod/lazy_codec", None).unwrap_err();
+        assert!(
+            format!("{err:#}").contains(
+                "`Item` is `#[frb(lazy)]`, which is only supported by the SSE codec, \
+                but `crate::api::list_items` sends it to Dart via the Dco codec"
+            ),
+            "{err:#}"
+        );
+
+        let (pack, _) =
+            execute_parse_with_config("library/codegen/parser/mod/lazy_codec", None, |config| {
+                config.mir.force_codec_mode_pack = compute_force_codec_mode_pack(false)
+            })
+            .unwrap();
+        assert!(pack.struct_pool.values().exactly_one().unwrap().lazy);
+    }
+
+    #[test]
+    #[serial]
+    fn test_non_exhaustive_third_party() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse_with_config(
+            "library/codegen/parser/mod/non_exhaustive_third_party",
+            None,
+            |config| config.hir.third_party_crate_names = vec![CrateName::new("dep_types".into())],
+        )?;
+
+        // A foreign `#[non_exhaustive]` type cannot be constructed by the generated code
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "plain_point")),
+            vec!["box_autoadd_point"]
+        );
+        assert!(matches!(
+            find_func(&pack, "extensible_config").inputs[0].inner.ty,
+            MirType::RustAutoOpaqueImplicit(_)
+        ));
+        assert!(!(pack.struct_pool.keys()).any(|id| id.0.name == "Config"));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_patch() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/patch", None)?;
+
+        // The patch types live in `frb_generated`, thus the types of the API are qualified
+        let code = &pack.extra_rust_output_code;
+        assert!(
+            code.contains("pub theme: Option < crate :: api :: Theme >,"),
+            "{code}"
+        );
+        assert!(
+            code.contains("pub shortcuts: Option<Vec < crate :: api :: Shortcut >>,"),
+            "{code}"
+        );
+        assert!(
+            code.contains("pub theme: Option<crate :: api :: Theme>,"),
+            "{code}"
+        );
+        // An optional field keeps its type
+        assert!(code.contains("if let Some(value) = patch.theme { self.theme = Some(value); }"));
+
+        // The method does not clash with the own `apply` of the type
+        let apply = find_func(&pack, "AppState_apply_app_state_patch");
+        assert_eq!(input_safe_idents(apply)[1], "box_autoadd_app_state_patch");
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "AppState_apply"))[1],
+            "i_32"
+        );
+        // Translatable structs are patched on the Dart side
+        assert!(!(pack.funcs_all.iter()).any(|f| f.name.name.starts_with("Settings_")));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_actor() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/actor", None)?;
+
+        // Only the handle is exposed
+        assert!(!(pack.funcs_all.iter()).any(|f| f.name.name.starts_with("Counter_")));
+        let handle =
+            "Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCounterActor";
+
+        // Everything touching the state is awaited on the actor, including sync methods
+        for name in ["new", "load", "increment", "sync_to_server"] {
+            let func = find_func(&pack, &format!("CounterActor_{name}"));
+            assert!(func.rust_async, "{name}");
+            assert_eq!(func.mode, MirFuncMode::Normal, "{name}");
+        }
+        assert!(!find_func(&pack, "CounterActor_version").rust_async);
+
+        // Constructors return the handle, and methods are called on it
+        let load = find_func(&pack, "CounterActor_load");
+        assert_eq!(input_safe_idents(load), vec!["String"]);
+        assert_eq!(load.output.normal.safe_ident(), handle);
+        assert_eq!(
+            load.output.error.as_ref().map(|x| x.safe_ident()),
+            Some("String".to_owned())
+        );
+        assert_eq!(
+            find_func(&pack, "CounterActor_new")
+                .output
+                .normal
+                .safe_ident(),
+            handle
+        );
+        let increment = find_func(&pack, "CounterActor_increment");
+        assert!(matches!(
+            &increment.owner,
+            MirFuncOwnerInfo::Method(method) if method.mode == MirFuncOwnerInfoMethodMode::Instance
+        ));
+        assert_eq!(input_safe_idents(increment)[1], "i_32");
+        assert_eq!(increment.output.normal.safe_ident(), "i_32");
+
+        // Users refer to the handle in their own functions
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "use_counter")),
+            vec![handle]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_lock_scope() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/lock_scope", None)?;
+
+        // The original type is still exposed as is
+        assert_eq!(
+            input_safe_idents(find_func(&pack, "Account_deposit"))[1],
+            "i_64"
+        );
+
+        // Reading only needs `&self` methods, while writing also allows `&mut self`
+        for (scope, methods) in [
+            (
+                "AccountReadScope",
+                vec!["audit", "balance", "lock", "try_lock"],
+            ),
+            (
+                "AccountWriteScope",
+                vec!["audit", "balance", "deposit", "lock", "try_lock"],
+            ),
+        ] {
+            let prefix = format!("{scope}_");
+            let actual_methods = (pack.funcs_all.iter())
+                .filter_map(|f| f.name.name.strip_prefix(&prefix))
+                .sorted()
+                .collect_vec();
+            assert_eq!(actual_methods, methods, "{scope}");
+
+            // Waiting for the lock is async, while trying it returns immediately
+            let lock = find_func(&pack, &format!("{scope}_lock"));
+            assert!(lock.rust_async);
+            assert!(lock.output.normal.safe_ident().ends_with(scope));
+            let try_lock = find_func(&pack, &format!("{scope}_try_lock"));
+            assert_eq!(try_lock.mode, MirFuncMode::Sync);
+            assert!(try_lock.output.normal.safe_ident().starts_with("opt_"));
+
+            // Attributes of the original methods are kept
+            assert_eq!(
+                find_func(&pack, &format!("{scope}_balance")).mode,
+                MirFuncMode::Sync
+            );
+            assert!(find_func(&pack, &format!("{scope}_audit")).rust_async);
+        }
+
+        // The Dart helpers to run callbacks inside the scopes
+        let extension = &pack.extra_dart_output_code.body;
+        for name in ["read", "write", "tryRead", "tryWrite"] {
+            assert!(extension.contains(&format!(" {name}<R>(")), "{name}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_lock_scope_name_clash() {
+        let err =
+            execute_parse("library/codegen/parser/mod/lock_scope_name_clash", None).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("clashes with the generated `tryRead` extension method"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_zero_copy() -> anyhow::Result<()> {
+        let rust2dart_codecs = |pack: &MirPack| {
+            ["render_audio", "load_weights", "serialized_bytes"]
+                .map(|name| find_func(pack, name).codec_mode_pack.rust2dart)
+        };
+
+        // Typed lists are sent as external typed data by the DCO codec, even when the function uses SSE
+        let (pack, _) = execute_parse("library/codegen/parser/mod/zero_copy", None)?;
+        assert_eq!(
+            rust2dart_codecs(&pack),
+            [CodecMode::Dco, CodecMode::Dco, CodecMode::Sse]
+        );
+        assert_eq!(
+            find_func(&pack, "render_audio").codec_mode_pack.dart2rust,
+            CodecMode::Sse
+        );
+
+        // Synchronous functions cannot switch codec without `full_dep`
+        let (pack, _) =
+            execute_parse_with_config("library/codegen/parser/mod/zero_copy", None, |config| {
+                config.mir.force_codec_mode_pack = compute_force_codec_mode_pack(false)
+            })?;
+        assert_eq!(
+            rust2dart_codecs(&pack),
+            [CodecMode::Dco, CodecMode::Pde, CodecMode::Pde]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_message_codec() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/message_codec", None)?;
+        let message = |ty: &MirType| match ty {
+            MirType::Delegate(MirTypeDelegate::MessageCodec(mir)) => mir.clone(),
+            _ => panic!("{ty:?} should be a message"),
+        };
+
+        // Messages are referred to by their full paths, while other types are parsed as usual
+        let greet = find_func(&pack, "greet");
+        let person = message(&greet.inputs[0].inner.ty);
+        assert_eq!(person.codec, MirMessageCodec::Protobuf);
+        assert_eq!(person.rust_type, "crate::proto::Person");
+        assert_eq!(person.dart_type, "Person");
+        assert_eq!(
+            greet.inputs[1].inner.ty.safe_ident(),
+            "box_autoadd_greet_options"
+        );
+        assert_eq!(
+            message(&greet.output.normal).rust_type,
+            "crate::proto::Greeting"
+        );
+
+        let greet_all = find_func(&pack, "greet_all");
+        assert_eq!(
+            input_safe_idents(greet_all),
+            vec!["list_protobuf_crate_proto_Person"]
+        );
+        assert_eq!(
+            greet_all.output.normal.safe_ident(),
+            "opt_protobuf_crate_proto_Greeting"
+        );
+
+        // The object API of flatbuffers is unpacked from the table
+        let monster = message(&find_func(&pack, "heal").inputs[0].inner.ty);
+        assert_eq!(monster.codec, MirMessageCodec::Flatbuffers);
+        assert_eq!(monster.rust_type, "crate::fbs::MonsterT");
+        assert_eq!(
+            monster.rust_table_type.as_deref(),
+            Some("crate::fbs::Monster")
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_progress_sink() -> anyhow::Result<()> {
+        let (pack, _) = execute_parse("library/codegen/parser/mod/progress_sink", None)?;
+
+        // The `ProgressSink` alias is the same as the explicit `StreamSink<FrbProgress>`
+        for name in ["download_file", "extract_archive"] {
+            let func = find_func(&pack, name);
+            let MirType::Delegate(MirTypeDelegate::StreamSink(sink)) = &func.inputs[1].inner.ty
+            else {
+                panic!("{name} should take a stream sink");
+            };
+            let MirType::Delegate(MirTypeDelegate::Progress(progress)) = &*sink.inner_ok else {
+                panic!("{name} should report progress");
+            };
+            assert_eq!(
+                sink.inner_ok.rust_api_type(),
+                "flutter_rust_bridge::FrbProgress"
+            );
+            // Transferred as `(current, total, message)`
+            assert_eq!(
+                (progress.element_delegate.values.iter())
+                    .map(|ty| ty.safe_ident())
+                    .collect_vec(),
+                vec!["u_64", "opt_box_autoadd_u_64", "opt_String"]
+            );
+        }
+        Ok(())
+    }
+
+    fn find_func<'a>(pack: &'a MirPack, name: &str) -> &'a MirFunc {
+        (pack.funcs_all.iter())
+            .find(|f| f.name.name == name)
+            .unwrap_or_else(|| panic!("function {name} not found"))
+    }
+
+    fn input_safe_idents(func: &MirFunc) -> Vec<String> {
+        (func.inputs.iter())
+            .map(|input| input.inner.ty.safe_ident())
+            .collect()
+    }
+
     #[allow(clippy::type_complexity)]
     fn body(
         fixture_name: &str,
@@ -156,6 +823,15 @@ mod tests {
     fn execute_parse(
         fixture_name: &str,
         rust_input_namespace_pack: Option<Box<dyn Fn(&Path) -> RustInputNamespacePack>>,
+    ) -> anyhow::Result<(MirPack, PathBuf)> {
+        execute_parse_with_config(fixture_name, rust_input_namespace_pack, |_| {})
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn execute_parse_with_config(
+        fixture_name: &str,
+        rust_input_namespace_pack: Option<Box<dyn Fn(&Path) -> RustInputNamespacePack>>,
+        configure: impl FnOnce(&mut ParserInternalConfig),
     ) -> anyhow::Result<(MirPack, PathBuf)> {
         configure_opinionated_test_logging();
         let test_fixture_dir = get_test_fixture_dir(fixture_name);
@@ -169,11 +845,17 @@ mod tests {
                 rust_output_path_namespace: Namespace::new_self_crate("frb_generated".to_owned()),
             });
 
-        let config = ParserInternalConfig {
+        let mut config = ParserInternalConfig {
             hir: ParserHirInternalConfig {
                 rust_input_namespace_pack: rust_input_namespace_pack.clone(),
                 rust_crate_dir: rust_crate_dir.clone(),
+                first_party_crate_names: vec![],
                 third_party_crate_names: vec![],
+                lazy_third_party: false,
+                api_version: None,
+                cache_directory: None,
+                rust_output_path: rust_crate_dir.join("src/frb_generated.rs"),
+                rust_feature_sets: vec![],
             },
             mir: ParserMirInternalConfig {
                 rust_input_namespace_pack: rust_input_namespace_pack.clone(),
@@ -184,13 +866,17 @@ mod tests {
                 enable_lifetime: false,
                 type_64bit_int: false,
                 default_dart_async: true,
+                type_mappings: vec![],
+                web_enabled: false,
             },
         };
+        configure(&mut config);
 
         let pack = parse_inner(
             &config,
             &Dumper::new(&Default::default()),
             &GeneratorProgressBarPack::new(),
+            &mut Default::default(),
             |hir_flat| {
                 json_golden_test(
                     &serde_json::to_value(hir_flat).unwrap(),
@@ -0,0 +1,34 @@
+use anyhow::ensure;
+use flutter_rust_bridge_codegen_plugin::PROTOCOL_VERSION;
+use libloading::{Library, Symbol};
+use std::path::Path;
+
+type ProtocolVersionFn = extern "C" fn() -> u32;
+type GenerateFn = unsafe extern "C" fn(*const u8, usize, *mut usize) -> *mut u8;
+type FreeFn = unsafe extern "C" fn(*mut u8, usize);
+
+/// Call a plugin built with `flutter_rust_bridge_codegen_plugin::export_plugin!`
+pub(super) fn call(plugin_path: &Path, request: &[u8]) -> anyhow::Result<Vec<u8>> {
+    // SAFETY: The plugin is trusted in the same way as the build scripts of the Rust crate,
+    // and the functions have the signatures defined in `export_plugin!`.
+    unsafe {
+        let library = Library::new(plugin_path)?;
+
+        let protocol_version: Symbol<ProtocolVersionFn> =
+            library.get(b"frb_codegen_plugin_protocol_version")?;
+        let plugin_protocol_version = protocol_version();
+        ensure!(
+            plugin_protocol_version == PROTOCOL_VERSION,
+            "The plugin supports protocol version {plugin_protocol_version}, but the code generator uses {PROTOCOL_VERSION}"
+        );
+
+        let generate: Symbol<GenerateFn> = library.get(b"frb_codegen_plugin_generate")?;
+        let free: Symbol<FreeFn> = library.get(b"frb_codegen_plugin_free")?;
+
+        let mut response_len = 0;
+        let response_ptr = generate(request.as_ptr(), request.len(), &mut response_len);
+        let response = std::slice::from_raw_parts(response_ptr, response_len).to_vec();
+        free(response_ptr, response_len);
+        Ok(response)
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub(crate) struct PluginInternalConfig {
+    pub plugin_paths: Vec<PathBuf>,
+    pub base_dir: PathBuf,
+    pub rust_crate_dir: PathBuf,
+    pub dart_output_dir: PathBuf,
+}
@@ -0,0 +1,141 @@
+//! External generator plugins, which emit additional artifacts from the resolved API
+
+mod dylib;
+pub(crate) mod internal_config;
+mod wasm;
+
+use crate::codegen::generator::misc::path_texts::{PathText, PathTexts};
+use crate::codegen::ir::mir::pack::MirPack;
+use crate::codegen::plugin::internal_config::PluginInternalConfig;
+use crate::utils::basic_code::general_code::GeneralCode;
+use anyhow::{ensure, Context};
+use flutter_rust_bridge_codegen_plugin::{
+    PluginInput, PluginOutput, PluginResponse, PROTOCOL_VERSION,
+};
+use log::info;
+use std::path::{Component, Path, PathBuf};
+
+pub(crate) fn run(config: &PluginInternalConfig, mir_pack: &MirPack) -> anyhow::Result<PathTexts> {
+    if config.plugin_paths.is_empty() {
+        return Ok(PathTexts(vec![]));
+    }
+
+    let request = serde_json::to_vec(&PluginInput {
+        protocol_version: PROTOCOL_VERSION,
+        codegen_version: env!("CARGO_PKG_VERSION").to_owned(),
+        base_dir: config.base_dir.clone(),
+        rust_crate_dir: config.rust_crate_dir.clone(),
+        dart_output_dir: config.dart_output_dir.clone(),
+        mir: serde_json::to_value(mir_pack)?,
+    })?;
+
+    let mut path_texts = vec![];
+    for plugin_path in &config.plugin_paths {
+        info!("Run generator plugin {plugin_path:?}");
+        let output = run_one(plugin_path, &request)
+            .with_context(|| format!("Fail to run generator plugin {plugin_path:?}"))?;
+        for artifact in output.artifacts {
+            let path =
+                compute_artifact_path(&config.base_dir, &artifact.path).with_context(|| {
+                    format!("Generator plugin {plugin_path:?} returns an invalid artifact")
+                })?;
+            path_texts.push(PathText::new(path, GeneralCode::new_text(artifact.content)));
+        }
+    }
+    Ok(PathTexts(path_texts))
+}
+
+/// Plugins may only write inside the base directory
+fn compute_artifact_path(base_dir: &Path, path: &Path) -> anyhow::Result<PathBuf> {
+    ensure!(
+        (path.components()).all(|x| matches!(x, Component::Normal(_) | Component::CurDir))
+            && path.components().any(|x| matches!(x, Component::Normal(_))),
+        "The artifact path {path:?} should be relative to the base directory, without `..`"
+    );
+    Ok(base_dir.join(path))
+}
+
+fn run_one(plugin_path: &Path, request: &[u8]) -> anyhow::Result<PluginOutput> {
+    let response = if plugin_path.extension().is_some_and(|x| x == "wasm") {
+        wasm::call(plugin_path, request)?
+    } else {
+        dylib::call(plugin_path, request)?
+    };
+    let response: PluginResponse =
+        serde_json::from_slice(&response).context("The plugin returns an invalid response")?;
+    response.map_err(|message| anyhow::anyhow!("{message}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::get_test_fixture_dir;
+    use std::process::Command;
+
+    #[test]
+    fn test_compute_artifact_path() {
+        let base_dir = Path::new("/project");
+        assert_eq!(
+            compute_artifact_path(base_dir, Path::new("./lib/a.txt")).unwrap(),
+            Path::new("/project/lib/a.txt")
+        );
+        for path in ["/etc/passwd", "../a.txt", "lib/../../a.txt", "", "."] {
+            assert!(
+                compute_artifact_path(base_dir, Path::new(path)).is_err(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_one_dylib() -> anyhow::Result<()> {
+        // The plugin implements the ABI of `export_plugin!` by hand, thus can be built by `rustc` alone
+        let temp_dir = tempfile::tempdir()?;
+        let plugin_path = temp_dir.path().join(format!(
+            "{}plugin{}",
+            std::env::consts::DLL_PREFIX,
+            std::env::consts::DLL_SUFFIX
+        ));
+        let status = Command::new("rustc")
+            .args(["--edition", "2021", "--crate-type", "cdylib", "-o"])
+            .arg(&plugin_path)
+            .arg(get_test_fixture_dir(
+                "library/codegen/plugin/dylib_plugin.rs",
+            ))
+            .status()?;
+        assert!(status.success());
+
+        let output = run_one(&plugin_path, b"hello")?;
+        assert_eq!(output.artifacts.len(), 1);
+        assert_eq!(output.artifacts[0].path, Path::new("request.txt"));
+        assert_eq!(output.artifacts[0].content, "hello");
+
+        // Errors reported by the plugin are forwarded
+        let err = run_one(&plugin_path, b"").unwrap_err();
+        assert_eq!(err.to_string(), "The request is empty");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_wasm_call() -> anyhow::Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Stands in for `wasmtime`, which follows the stdin/stdout protocol of `run_stdio`
+        let temp_dir = tempfile::tempdir()?;
+        let runner = temp_dir.path().join("fake_wasmtime");
+        std::fs::write(
+            &runner,
+            "#!/bin/sh\n[ \"$1\" = run ] || exit 2\nprintf '%s:' \"$2\"\ncat\n",
+        )?;
+        std::fs::set_permissions(&runner, std::fs::Permissions::from_mode(0o755))?;
+
+        let response = wasm::call_with_runner(&runner, Path::new("plugin.wasm"), b"hello")?;
+        assert_eq!(response, b"plugin.wasm:hello");
+
+        let err = wasm::call_with_runner(Path::new("/bin/false"), Path::new("plugin.wasm"), b"")
+            .unwrap_err();
+        assert!(err.to_string().contains("The plugin exits with"), "{err}");
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anyhow::{bail, Context};
+use log::debug;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Run a WASI plugin via the `wasmtime` CLI, which reads the request from stdin
+/// and writes the response to stdout (see `flutter_rust_bridge_codegen_plugin::run_stdio`).
+///
+/// The plugin is not given any directory or network access.
+pub(super) fn call(plugin_path: &Path, request: &[u8]) -> anyhow::Result<Vec<u8>> {
+    call_with_runner(Path::new("wasmtime"), plugin_path, request)
+}
+
+pub(super) fn call_with_runner(
+    runner: &Path,
+    plugin_path: &Path,
+    request: &[u8],
+) -> anyhow::Result<Vec<u8>> {
+    let mut cmd = Command::new(runner);
+    cmd.arg("run")
+        .arg(plugin_path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    debug!("execute command: cmd={cmd:?}");
+
+    let mut child = cmd
+        .spawn()
+        .context("Fail to execute `wasmtime`, which is needed to run WASM plugins. Please install it via https://wasmtime.dev")?;
+
+    // Write in another thread, otherwise both processes may wait for each other when the pipes are full
+    let mut stdin = child.stdin.take().unwrap();
+    let request = request.to_owned();
+    let writer = std::thread::spawn(move || stdin.write_all(&request));
+
+    let output = child.wait_with_output()?;
+    writer.join().unwrap()?;
+
+    if !output.status.success() {
+        bail!(
+            "The plugin exits with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+    Ok(output.stdout)
+}
@@ -5,6 +5,11 @@ use std::path::PathBuf;
 pub(crate) struct PolisherInternalConfig {
     pub duplicated_c_output_path: Vec<PathBuf>,
     pub dart_format_line_length: u32,
+    /// Replaces `dart format` when specified
+    pub dart_format_command: Option<Vec<String>>,
+    pub rust_format: bool,
+    /// Replaces `rustfmt` when specified
+    pub rust_format_command: Option<Vec<String>>,
     pub add_mod_to_lib: bool,
     pub build_runner: bool,
     pub web_enabled: bool,
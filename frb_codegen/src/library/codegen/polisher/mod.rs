@@ -12,7 +12,7 @@ use itertools::Itertools;
 use lazy_static::lazy_static;
 use log::warn;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 pub(crate) mod add_mod_to_lib;
 mod auto_upgrade;
@@ -43,7 +43,7 @@ pub(super) fn polish(
         "execute_dart_format",
     );
     warn_if_fail(
-        execute_rust_format(output_paths, &config.rust_crate_dir, progress_bar_pack),
+        execute_rust_format(config, output_paths, progress_bar_pack),
         "execute_rust_format",
     );
 
@@ -127,16 +127,25 @@ fn execute_dart_format(
         &config.dart_root,
         config.dart_format_line_length,
         &["g.dart", "freezed.dart"],
+        config.dart_format_command.as_deref(),
     )
 }
 
 fn execute_rust_format(
+    config: &PolisherInternalConfig,
     output_paths: &[PathBuf],
-    base_path: &Path,
     progress_bar_pack: &GeneratorProgressBarPack,
 ) -> anyhow::Result<()> {
+    if !config.rust_format {
+        return Ok(());
+    }
+
     let _pb = progress_bar_pack.polish_rust_formatter.start();
-    format_rust(&filter_paths_by_extension(output_paths, "rs"), base_path)
+    format_rust(
+        &filter_paths_by_extension(output_paths, "rs"),
+        &config.rust_crate_dir,
+        config.rust_format_command.as_deref(),
+    )
 }
 
 fn filter_paths_by_extension(paths: &[PathBuf], extension: &str) -> Vec<PathBuf> {
@@ -0,0 +1,354 @@
+use crate::command_args;
+use crate::library::commands::command_runner::execute_command_with_data_stdout;
+use crate::utils::crate_name::CrateName;
+use crate::utils::file_cache::FileCache;
+use crate::utils::path_utils::path_to_string;
+use itertools::Itertools;
+use sha1::{Digest, Sha1};
+use std::collections::{BTreeSet, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Reuses the expanded code of previous runs when nothing affecting the expansion changes
+pub(crate) struct CargoExpandCache<'a> {
+    pub directory: &'a Path,
+    /// Computed once per run by [`compute_fingerprint`], since it reads the whole workspace
+    pub fingerprint: &'a str,
+}
+
+impl CargoExpandCache<'_> {
+    pub(super) fn get_or_compute(
+        &self,
+        rust_crate_dir: &Path,
+        interest_crate_name: Option<&CrateName>,
+        features: &[String],
+        compute: impl FnOnce() -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        let crate_name = interest_crate_name.map_or(CrateName::SELF_CRATE, |x| x.raw());
+        let inputs = [
+            path_to_string(rust_crate_dir)?,
+            self.fingerprint.to_owned(),
+            features.join(","),
+        ];
+        // Each feature set has its own entry, otherwise they would evict each other in every run
+        let features_suffix = (features.iter())
+            .map(|x| format!("_{}", x.replace(|c: char| !c.is_ascii_alphanumeric(), "_")))
+            .join("");
+        FileCache::new(Some(self.directory)).get_or_compute(
+            &format!("cargo_expand_{crate_name}{features_suffix}"),
+            &inputs.iter().map(|x| x.as_str()).collect_vec(),
+            compute,
+        )
+    }
+}
+
+/// Everything besides the crate name and the features that affects the expanded code:
+/// the toolchain, the environment, the sources (including path dependencies outside the workspace),
+/// and the inputs of build scripts (e.g. `.proto` files used to generate code into `OUT_DIR`)
+pub(crate) fn compute_fingerprint(
+    rust_crate_dir: &Path,
+    ignored_paths: &[PathBuf],
+) -> anyhow::Result<String> {
+    let workspace_dir = find_workspace_dir(rust_crate_dir);
+    let target_dir =
+        env::var_os("CARGO_TARGET_DIR").map_or_else(|| workspace_dir.join("target"), PathBuf::from);
+    let build_script_inputs = read_build_script_inputs(&target_dir);
+
+    let mut hasher = Sha1::new();
+    hasher.update(compute_tool_versions(rust_crate_dir));
+    hasher.update(compute_env_fingerprint(&build_script_inputs.env_names));
+    hasher.update(compute_sources_fingerprint(
+        &workspace_dir,
+        &build_script_inputs.paths,
+        ignored_paths,
+    )?);
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Path dependencies inside the workspace also affect the expanded code
+fn find_workspace_dir(rust_crate_dir: &Path) -> PathBuf {
+    (rust_crate_dir.ancestors())
+        .find(|dir| dir.join("Cargo.lock").exists())
+        .unwrap_or(rust_crate_dir)
+        .to_owned()
+}
+
+/// Run in the crate directory, since it may pin its own toolchain
+#[allow(clippy::vec_init_then_push)]
+fn compute_tool_versions(rust_crate_dir: &Path) -> String {
+    [
+        ("rustc", command_args!("-vV")),
+        ("cargo", command_args!("expand", "--version")),
+    ]
+    .into_iter()
+    .map(|(bin, args)| {
+        execute_command_with_data_stdout(bin, &args, Some(rust_crate_dir), None)
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+            .unwrap_or_default()
+    })
+    .join("\n")
+}
+
+/// The variables read by cargo (e.g. `RUSTFLAGS`, `CARGO_BUILD_TARGET`), which may change the `cfg`s,
+/// and the ones read by build scripts
+fn compute_env_fingerprint(build_script_env_names: &BTreeSet<String>) -> String {
+    env::vars()
+        .filter(|(name, _)| {
+            name.starts_with("CARGO_")
+                || name.starts_with("RUST")
+                || build_script_env_names.contains(name)
+        })
+        .sorted()
+        .map(|(name, value)| format!("{name}={value}"))
+        .join("\n")
+}
+
+#[derive(Default)]
+struct BuildScriptInputs {
+    env_names: BTreeSet<String>,
+    /// The absolute paths, since relative ones are inside the package and thus already hashed
+    paths: BTreeSet<PathBuf>,
+}
+
+/// The `rerun-if-*` instructions printed by build scripts of previous builds, if any
+fn read_build_script_inputs(target_dir: &Path) -> BuildScriptInputs {
+    let mut ans = BuildScriptInputs::default();
+    // `target/{profile}/build/{package}/output` and `target/{triple}/{profile}/build/{package}/output`
+    for pattern in ["*/build/*/output", "*/*/build/*/output"] {
+        let pattern = target_dir.join(pattern);
+        let Ok(paths) = glob::glob(&pattern.to_string_lossy()) else {
+            continue;
+        };
+        for output in paths
+            .flatten()
+            .filter_map(|path| fs::read_to_string(path).ok())
+        {
+            for line in output.lines() {
+                let line = line
+                    .trim_start_matches("cargo::")
+                    .trim_start_matches("cargo:");
+                if let Some(name) = line.strip_prefix("rerun-if-env-changed=") {
+                    ans.env_names.insert(name.to_owned());
+                } else if let Some(path) = line.strip_prefix("rerun-if-changed=") {
+                    let path = PathBuf::from(path);
+                    if path.is_absolute() {
+                        ans.paths.insert(path);
+                    }
+                }
+            }
+        }
+    }
+    ans
+}
+
+fn compute_sources_fingerprint(
+    workspace_dir: &Path,
+    extra_paths: &BTreeSet<PathBuf>,
+    ignored_paths: &[PathBuf],
+) -> anyhow::Result<String> {
+    let mut paths = vec![];
+    collect_source_paths(workspace_dir, false, &mut paths)?;
+    collect_outside_path_dependencies(workspace_dir, &mut paths)?;
+    for path in extra_paths {
+        if path.is_dir() {
+            collect_source_paths(path, true, &mut paths)?;
+        } else {
+            paths.push(path.to_owned());
+        }
+    }
+    // Cargo configurations may set `rustflags` or the target, and are usually in the ancestors
+    for dir in workspace_dir.ancestors() {
+        for name in ["config.toml", "config"] {
+            paths.push(dir.join(".cargo").join(name));
+        }
+    }
+
+    let mut hasher = Sha1::new();
+    for path in (paths.iter())
+        .filter(|x| !ignored_paths.contains(x))
+        .sorted()
+        .dedup()
+    {
+        // Missing files are hashed as well, since creating them matters
+        hasher.update(path_to_string(path)?.as_bytes());
+        hasher.update(fs::read(path).unwrap_or_default());
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Packages with a build script may read any file in the package, e.g. `.proto` files,
+/// thus all their files are collected, while only the Rust sources and manifests of others are
+fn collect_source_paths(dir: &Path, all_files: bool, ans: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    // Build outputs (which are tagged with `CACHEDIR.TAG` by cargo) are huge and never inputs
+    if dir.join("CACHEDIR.TAG").exists() {
+        return Ok(());
+    }
+    let all_files = all_files || dir.join("build.rs").exists();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('.') || name == "node_modules" {
+            continue;
+        }
+        if path.is_dir() {
+            collect_source_paths(&path, all_files, ans)?;
+        } else if all_files || name.ends_with(".rs") || name == "Cargo.toml" || name == "Cargo.lock"
+        {
+            ans.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Path dependencies outside the workspace, e.g. `foo = { path = "../../shared/foo" }`,
+/// including the ones of those dependencies
+fn collect_outside_path_dependencies(
+    workspace_dir: &Path,
+    ans: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let workspace_dir = workspace_dir.canonicalize()?;
+    let mut visited = HashSet::new();
+    let mut manifest_paths = (ans.iter())
+        .filter(|path| path.ends_with("Cargo.toml"))
+        .cloned()
+        .collect_vec();
+
+    while let Some(manifest_path) = manifest_paths.pop() {
+        let Some(manifest_dir) = manifest_path.parent() else {
+            continue;
+        };
+        for dependency_dir in parse_path_dependencies(&manifest_path) {
+            let Ok(dependency_dir) = manifest_dir.join(dependency_dir).canonicalize() else {
+                continue;
+            };
+            // Paths of targets, e.g. `[lib] path = "src/lib.rs"`, are files
+            if !dependency_dir.is_dir()
+                || dependency_dir.starts_with(&workspace_dir)
+                || !visited.insert(dependency_dir.clone())
+            {
+                continue;
+            }
+            let mut paths = vec![];
+            collect_source_paths(&dependency_dir, false, &mut paths)?;
+            manifest_paths.extend(paths.iter().filter(|x| x.ends_with("Cargo.toml")).cloned());
+            ans.extend(paths);
+        }
+    }
+    Ok(())
+}
+
+fn parse_path_dependencies(manifest_path: &Path) -> Vec<String> {
+    fn visit(value: &toml::Value, ans: &mut Vec<String>) {
+        if let toml::Value::Table(table) = value {
+            if let Some(toml::Value::String(path)) = table.get("path") {
+                ans.push(path.to_owned());
+            }
+            for child in table.values() {
+                visit(child, ans);
+            }
+        }
+    }
+
+    let mut ans = vec![];
+    if let Some(manifest) =
+        (fs::read_to_string(manifest_path).ok()).and_then(|text| text.parse::<toml::Value>().ok())
+    {
+        visit(&manifest, &mut ans);
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_sources_fingerprint() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        fs::create_dir_all(dir.join("src"))?;
+        fs::create_dir_all(dir.join("target"))?;
+        fs::write(dir.join("Cargo.lock"), "")?;
+        fs::write(dir.join("src/lib.rs"), "mod api;")?;
+        fs::write(dir.join("target/CACHEDIR.TAG"), "")?;
+        let ignored_paths = [dir.join("src/frb_generated.rs")];
+        let fingerprint =
+            || compute_sources_fingerprint(dir, &BTreeSet::new(), &ignored_paths).unwrap();
+
+        let original = fingerprint();
+        fs::write(dir.join("target/a.rs"), "")?;
+        fs::write(dir.join("src/frb_generated.rs"), "")?;
+        fs::write(dir.join("README.md"), "")?;
+        assert_eq!(fingerprint(), original);
+
+        fs::write(dir.join("src/lib.rs"), "mod api2;")?;
+        assert_ne!(fingerprint(), original);
+
+        assert_eq!(find_workspace_dir(&dir.join("src")), dir);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_sources_fingerprint_build_script_and_path_dependencies() -> anyhow::Result<()> {
+        let root = tempfile::tempdir()?;
+        let dir = root.path().join("workspace");
+        let shared_dir = root.path().join("shared");
+        fs::create_dir_all(dir.join("src"))?;
+        fs::create_dir_all(shared_dir.join("src"))?;
+        fs::write(dir.join("Cargo.lock"), "")?;
+        fs::write(
+            dir.join("Cargo.toml"),
+            "[dependencies]\nshared = { path = \"../shared\" }\n",
+        )?;
+        fs::write(dir.join("build.rs"), "fn main() {}")?;
+        fs::write(dir.join("src/api.proto"), "message A {}")?;
+        fs::write(
+            shared_dir.join("Cargo.toml"),
+            "[package]\nname = \"shared\"\n",
+        )?;
+        fs::write(shared_dir.join("src/lib.rs"), "pub struct A;")?;
+        fs::write(root.path().join("input.txt"), "a")?;
+        let extra_paths = [root.path().join("input.txt")].into();
+        let fingerprint = || compute_sources_fingerprint(&dir, &extra_paths, &[]).unwrap();
+
+        let original = fingerprint();
+        fs::write(dir.join("src/api.proto"), "message B {}")?;
+        let changed_proto = fingerprint();
+        assert_ne!(changed_proto, original);
+
+        fs::write(shared_dir.join("src/lib.rs"), "pub struct B;")?;
+        let changed_shared = fingerprint();
+        assert_ne!(changed_shared, changed_proto);
+
+        fs::write(root.path().join("input.txt"), "b")?;
+        assert_ne!(fingerprint(), changed_shared);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_build_script_inputs() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let output_dir = dir.path().join("debug/build/my_crate-0123/");
+        fs::create_dir_all(&output_dir)?;
+        let proto_path = dir.path().join("api.proto");
+        fs::write(
+            output_dir.join("output"),
+            format!(
+                "cargo:rerun-if-env-changed=MY_FLAG\n\
+                 cargo::rerun-if-changed={}\n\
+                 cargo:rerun-if-changed=build.rs\n\
+                 cargo:rustc-cfg=my_cfg\n",
+                proto_path.display()
+            ),
+        )?;
+
+        let inputs = read_build_script_inputs(dir.path());
+        assert_eq!(inputs.env_names, ["MY_FLAG".to_owned()].into());
+        assert_eq!(inputs.paths, [proto_path].into());
+
+        Ok(())
+    }
+}
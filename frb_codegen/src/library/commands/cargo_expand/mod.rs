@@ -1,3 +1,4 @@
+mod cache;
 mod pseudo;
 mod real;
 
@@ -7,18 +8,43 @@ use crate::utils::path_utils::{normalize_windows_unc_path, path_to_string};
 use anyhow::Result;
 use log::debug;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+pub(crate) use cache::{compute_fingerprint as compute_cargo_expand_fingerprint, CargoExpandCache};
+
+/// The expanded code is plain text, thus multiple crates can be expanded in parallel,
+/// while the `syn` syntax trees are not `Send` and are parsed afterwards by [`Self::parse`]
+pub(crate) enum CargoExpandOutput {
+    Real(String),
+    Pseudo(PathBuf),
+}
+
+impl CargoExpandOutput {
+    pub(crate) fn parse(&self, dumper: &Dumper) -> Result<syn::File> {
+        match self {
+            Self::Real(text) => real::parse(text, dumper),
+            Self::Pseudo(rust_crate_dir) => pseudo::parse(rust_crate_dir),
+        }
+    }
+}
 
 pub(crate) fn run_cargo_expand(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
-    dumper: &Dumper,
-) -> Result<syn::File> {
-    if can_execute_real(rust_crate_dir)? {
-        real::run(rust_crate_dir, interest_crate_name, dumper)
+    features: &[String],
+    cache: Option<&CargoExpandCache>,
+) -> Result<CargoExpandOutput> {
+    Ok(if can_execute_real(rust_crate_dir)? {
+        CargoExpandOutput::Real(real::run(
+            rust_crate_dir,
+            interest_crate_name,
+            features,
+            cache,
+        )?)
     } else {
-        pseudo::run(rust_crate_dir, interest_crate_name)
-    }
+        pseudo::run(rust_crate_dir, interest_crate_name)?;
+        CargoExpandOutput::Pseudo(rust_crate_dir.to_owned())
+    })
 }
 
 fn can_execute_real(rust_crate_dir: &Path) -> anyhow::Result<bool> {
@@ -11,7 +11,7 @@ use std::path::{Path, PathBuf};
 pub(super) fn run(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
-) -> anyhow::Result<syn::File> {
+) -> anyhow::Result<()> {
     warn!(
         "Skip cargo-expand on {rust_crate_dir:?}, \
          because cargo is already running and would block cargo-expand. \
@@ -22,7 +22,10 @@ pub(super) fn run(
         interest_crate_name.is_none(),
         "When parsing third party crates, need to use cargo-expand"
     );
+    Ok(())
+}
 
+pub(super) fn parse(rust_crate_dir: &Path) -> anyhow::Result<syn::File> {
     parse_file(&rust_crate_dir.join("src/lib.rs"))
 }
 
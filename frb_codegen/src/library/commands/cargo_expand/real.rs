@@ -1,7 +1,8 @@
 use crate::codegen::dumper::Dumper;
 use crate::codegen::ConfigDumpContent;
 use crate::command_args;
-use crate::library::commands::command_runner::execute_command;
+use crate::library::commands::cargo_expand::CargoExpandCache;
+use crate::library::commands::command_runner::{execute_command, execute_command_with_data_stdout};
 use crate::utils::crate_name::CrateName;
 use anyhow::{bail, Context, Result};
 use itertools::Itertools;
@@ -15,20 +16,31 @@ use std::path::Path;
 pub(super) fn run(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
-    dumper: &Dumper,
-) -> Result<syn::File> {
-    let text = run_with_frb_aware(rust_crate_dir, interest_crate_name)?;
-    (dumper.with_content(ConfigDumpContent::Source)).dump_str("cargo_expand.rs", &text)?;
-    Ok(syn::parse_file(&text)?)
+    features: &[String],
+    cache: Option<&CargoExpandCache>,
+) -> Result<String> {
+    match cache {
+        Some(cache) => cache.get_or_compute(rust_crate_dir, interest_crate_name, features, || {
+            run_with_frb_aware(rust_crate_dir, interest_crate_name, features)
+        }),
+        None => run_with_frb_aware(rust_crate_dir, interest_crate_name, features),
+    }
+}
+
+pub(super) fn parse(text: &str, dumper: &Dumper) -> Result<syn::File> {
+    (dumper.with_content(ConfigDumpContent::Source)).dump_str("cargo_expand.rs", text)?;
+    Ok(syn::parse_file(text)?)
 }
 
 fn run_with_frb_aware(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
+    features: &[String],
 ) -> Result<String> {
     Ok(decode_macro_frb_encoded_comments(&run_raw(
         rust_crate_dir,
         interest_crate_name,
+        features,
         "--cfg frb_expand",
         true,
     )?)
@@ -53,6 +65,7 @@ fn decode_macro_frb_encoded_comments(code: &str) -> Cow<str> {
 fn run_raw(
     rust_crate_dir: &Path,
     interest_crate_name: Option<&CrateName>,
+    features: &[String],
     extra_rustflags: &str,
     allow_auto_install: bool,
 ) -> Result<String> {
@@ -64,6 +77,7 @@ fn run_raw(
     } else {
         vec![]
     };
+    let features_joined = features.join(",");
 
     let args = command_args!(
         "expand",
@@ -71,6 +85,7 @@ fn run_raw(
         "--theme=none",
         "--ugly",
         *args_choosing_crate,
+        (!features.is_empty(), "--features", &features_joined),
     );
     let extra_env = [(
         "RUSTFLAGS".to_owned(),
@@ -78,8 +93,9 @@ fn run_raw(
     )]
     .into();
 
-    let output = execute_command("cargo", &args, Some(rust_crate_dir), Some(extra_env))
-        .with_context(|| format!("Could not expand rust code at path {rust_crate_dir:?}"))?;
+    let output =
+        execute_command_with_data_stdout("cargo", &args, Some(rust_crate_dir), Some(extra_env))
+            .with_context(|| format!("Could not expand rust code at path {rust_crate_dir:?}"))?;
 
     let stdout = String::from_utf8(output.stdout)?;
     let stderr = String::from_utf8(output.stderr)?;
@@ -88,7 +104,13 @@ fn run_raw(
         if stderr.contains("no such command: `expand`") && allow_auto_install {
             info!("Cargo expand is not installed. Automatically install and re-run.");
             install_cargo_expand()?;
-            return run_raw(rust_crate_dir, interest_crate_name, extra_rustflags, false);
+            return run_raw(
+                rust_crate_dir,
+                interest_crate_name,
+                features,
+                extra_rustflags,
+                false,
+            );
         }
         // This will stop the whole generator and tell the users, so we do not care about testing it
         // frb-coverage:ignore-start
@@ -1,3 +1,4 @@
+use anyhow::Context;
 use cargo_metadata::{Metadata, MetadataCommand};
 use std::path::Path;
 
@@ -6,3 +7,30 @@ pub(crate) fn execute_cargo_metadata(manifest_path: &Path) -> anyhow::Result<Met
     cmd.manifest_path(manifest_path);
     Ok(cmd.exec()?)
 }
+
+/// Only keep the dependencies which are used when compiling for the `target` triple
+pub(crate) fn execute_cargo_metadata_for_target(
+    manifest_path: &Path,
+    target: &str,
+) -> anyhow::Result<Metadata> {
+    let mut cmd = MetadataCommand::new();
+    cmd.manifest_path(manifest_path);
+    cmd.other_options(vec!["--filter-platform".to_owned(), target.to_owned()]);
+    Ok(cmd.exec()?)
+}
+
+/// Name of the library file produced for `crate_type` (e.g. `cdylib`), without prefix and extension
+pub(crate) fn find_lib_target_name(
+    metadata: &Metadata,
+    crate_type: &str,
+) -> anyhow::Result<String> {
+    let package = (metadata.root_package()).context("The Rust crate should be a package")?;
+    let target = (package.targets.iter())
+        .find(|target| target.crate_types.iter().any(|x| x == crate_type))
+        .with_context(|| {
+            format!(
+                "Please add `crate-type = [\"{crate_type}\"]` to the `[lib]` section of Cargo.toml"
+            )
+        })?;
+    Ok(target.name.replace('-', "_"))
+}
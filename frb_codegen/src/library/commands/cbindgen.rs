@@ -1,6 +1,9 @@
+use crate::utils::json_utils::merge_json;
 use crate::utils::path_utils::normalize_windows_unc_path;
+use anyhow::Context;
 use cbindgen::Error;
 use log::{debug, info};
+use serde_json::{json, Value};
 use std::fs;
 use std::path::Path;
 
@@ -9,6 +12,8 @@ pub(crate) struct CbindgenArgs<'a> {
     pub c_struct_names: Vec<String>,
     pub exclude_symbols: Vec<String>,
     pub after_includes: String,
+    /// Merged into the config, using the same keys as `cbindgen.toml`
+    pub extra_config: Option<&'a Value>,
 }
 
 pub(crate) fn cbindgen(args: CbindgenArgs) -> anyhow::Result<String> {
@@ -31,37 +36,57 @@ fn cbindgen_to_file(args: CbindgenArgs, c_output_path: &Path) -> anyhow::Result<
         rust_crate_dir = args.rust_crate_dir
     );
 
-    let default_cbindgen_config = default_cbindgen_config();
-    let config = cbindgen::Config {
-        export: cbindgen::ExportConfig {
-            include: args.c_struct_names,
-            exclude: args.exclude_symbols,
-            ..Default::default()
-        },
-        after_includes: Some(
-            args.after_includes + &default_cbindgen_config.after_includes.unwrap(),
-        ),
-        ..default_cbindgen_config
-    };
+    let config = compute_config(&args)?;
     debug!("cbindgen config: {:#?}", config);
 
     cbindgen_raw(config, args.rust_crate_dir, c_output_path)
 }
 
-pub(crate) fn default_cbindgen_config() -> cbindgen::Config {
-    cbindgen::Config {
-        // copied from: dart-sdk/dart_api.h
-        // used to convert Dart_Handle to Object.
-        after_includes: Some("typedef struct _Dart_Handle* Dart_Handle;".to_owned()),
-        language: cbindgen::Language::C,
-        sys_includes: vec![
-            "stdbool.h".to_string(),
-            "stdint.h".to_string(),
-            "stdlib.h".to_string(),
-        ],
-        no_includes: true,
-        ..Default::default()
+fn compute_config(args: &CbindgenArgs) -> anyhow::Result<cbindgen::Config> {
+    // `cbindgen::Config` cannot be serialized, thus the merging is done before deserializing it
+    let mut config = default_cbindgen_config_json();
+    merge_json(
+        &mut config,
+        &json!({
+            "export": {
+                "include": args.c_struct_names,
+                "exclude": args.exclude_symbols,
+            },
+            "after_includes": format!("{}{DART_HANDLE_TYPEDEF}", args.after_includes),
+        }),
+    );
+    if let Some(extra_config) = args.extra_config {
+        // Appended instead of replacing, since the generated code needs the `Dart_Handle` typedef
+        let mut extra_config = extra_config.clone();
+        let extra_after_includes = (extra_config.as_object_mut())
+            .and_then(|extra_config| extra_config.remove("after_includes"));
+        merge_json(&mut config, &extra_config);
+
+        if let Some(extra_after_includes) = extra_after_includes {
+            let extra_after_includes = (extra_after_includes.as_str())
+                .context("Invalid `cbindgen_config`: `after_includes` should be a string")?;
+            let after_includes = config["after_includes"].as_str().unwrap_or_default();
+            config["after_includes"] = json!(format!("{after_includes}\n{extra_after_includes}"));
+        }
     }
+    serde_json::from_value(config).context("Invalid `cbindgen_config`")
+}
+
+// copied from: dart-sdk/dart_api.h
+// used to convert Dart_Handle to Object.
+const DART_HANDLE_TYPEDEF: &str = "typedef struct _Dart_Handle* Dart_Handle;";
+
+fn default_cbindgen_config_json() -> Value {
+    json!({
+        "after_includes": DART_HANDLE_TYPEDEF,
+        "language": "C",
+        "sys_includes": ["stdbool.h", "stdint.h", "stdlib.h"],
+        "no_includes": true,
+    })
+}
+
+pub(crate) fn default_cbindgen_config() -> cbindgen::Config {
+    serde_json::from_value(default_cbindgen_config_json()).unwrap()
 }
 
 pub(crate) fn cbindgen_raw(
@@ -94,3 +119,33 @@ fn parse_crate_dir(rust_crate_dir: &Path) -> anyhow::Result<String> {
     let canonical_path = Path::new(rust_crate_dir).canonicalize()?;
     Ok(normalize_windows_unc_path(canonical_path.to_str().unwrap()).to_owned())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_compute_config_with_extra_config() -> anyhow::Result<()> {
+        let extra_config = json!({
+            "include_guard": "MY_GUARD_H",
+            "export": {"include": ["MyExtraStruct"]},
+            "after_includes": "#include \"my_types.h\"",
+        });
+        let config = compute_config(&CbindgenArgs {
+            rust_crate_dir: &PathBuf::from("."),
+            c_struct_names: vec!["wire_cst_a".to_owned()],
+            exclude_symbols: vec![],
+            after_includes: "// extra\n".to_owned(),
+            extra_config: Some(&extra_config),
+        })?;
+        assert_eq!(config.include_guard.as_deref(), Some("MY_GUARD_H"));
+        assert_eq!(config.export.include, vec!["wire_cst_a", "MyExtraStruct"]);
+        assert_eq!(
+            config.after_includes.as_deref(),
+            Some("// extra\ntypedef struct _Dart_Handle* Dart_Handle;\n#include \"my_types.h\"")
+        );
+        assert!(config.no_includes);
+        Ok(())
+    }
+}
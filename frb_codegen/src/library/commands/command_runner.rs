@@ -1,12 +1,18 @@
+use crate::utils::command_options::{command_options, CommandOptions, ShellMode};
+use crate::utils::console::MULTI_PROGRESS;
+use crate::utils::message_format::is_json_message_format;
 use crate::utils::path_utils::{normalize_windows_unc_path, path_to_string};
 use anyhow::{bail, Context};
 use itertools::Itertools;
 use log::debug;
 use log::warn;
 use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::process::Output;
+use std::process::{Output, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 /// - First argument is either a string of a command, or a function receiving a slice of [`PathBuf`].
 ///   - The command may be followed by `in <expr>` to specify the working directory.
@@ -72,6 +78,7 @@ macro_rules! command_args {
     }};
 }
 
+/// Execute the command directly, or via the shell in [`CommandOptions::shell`]
 #[allow(clippy::vec_init_then_push)]
 pub(crate) fn call_shell(
     cmd: &[PathBuf],
@@ -85,27 +92,73 @@ pub(crate) fn call_shell(
 
 pub(crate) struct CommandInfo {
     pub program: String,
-    pub args: Vec<String>,
+    pub args: Vec<PathBuf>,
 }
 
 pub(crate) fn call_shell_info(cmd: &[PathBuf]) -> CommandInfo {
-    let cmd = cmd.iter().map(|section| format!("{section:?}")).join(" ");
+    compute_command_info(cmd, command_options().shell)
+}
 
-    #[cfg(windows)]
-    return CommandInfo {
-        program: "powershell".to_owned(),
-        args: vec![
-            "-noprofile".to_owned(),
-            "-command".to_owned(),
-            format!("& {}", cmd),
-        ],
+fn compute_command_info(cmd: &[PathBuf], shell: ShellMode) -> CommandInfo {
+    let joined = || cmd.iter().map(|section| quote(section, shell)).join(" ");
+    let (program, args) = match shell {
+        ShellMode::Direct => {
+            let (program, args) = cmd.split_first().expect("command should not be empty");
+            return CommandInfo {
+                program: resolve_program(program).to_string_lossy().into_owned(),
+                args: args.to_vec(),
+            };
+        }
+        ShellMode::Sh => ("sh", vec!["-c".to_owned(), joined()]),
+        ShellMode::Powershell => (
+            "powershell",
+            vec![
+                "-noprofile".to_owned(),
+                "-command".to_owned(),
+                format!("& {}", joined()),
+            ],
+        ),
+        // Otherwise a program with the same name as a builtin (e.g. `format`) would not be executed
+        ShellMode::Nushell => ("nu", vec!["-c".to_owned(), format!("^{}", joined())]),
+        ShellMode::Fish => ("fish", vec!["-c".to_owned(), joined()]),
     };
+    CommandInfo {
+        program: program.to_owned(),
+        args: args.into_iter().map_into().collect(),
+    }
+}
 
-    #[cfg(not(windows))]
-    return CommandInfo {
-        program: "sh".to_owned(),
-        args: vec!["-c".to_owned(), cmd],
-    };
+/// Quote as a literal string, so that e.g. spaces and `$` are kept as-is
+fn quote(section: &Path, shell: ShellMode) -> String {
+    let raw = section.to_string_lossy();
+    match shell {
+        ShellMode::Direct => raw.into_owned(),
+        ShellMode::Sh => format!("'{}'", raw.replace('\'', r"'\''")),
+        ShellMode::Powershell => format!("'{}'", raw.replace('\'', "''")),
+        // Single-quoted strings have no escapes
+        ShellMode::Nushell if !raw.contains('\'') => format!("'{raw}'"),
+        ShellMode::Nushell => format!("\"{}\"", raw.replace('\\', r"\\").replace('"', r#"\""#)),
+        ShellMode::Fish => format!("'{}'", raw.replace('\\', r"\\").replace('\'', r"\'")),
+    }
+}
+
+/// On Windows, [`Command`] only looks for `.exe`, while e.g. `flutter` is `flutter.bat`
+fn resolve_program(program: &Path) -> PathBuf {
+    #[cfg(windows)]
+    if program.extension().is_none() && program.components().count() == 1 {
+        let extensions = std::env::var("PATHEXT").unwrap_or(".COM;.EXE;.BAT;.CMD".to_owned());
+        for dir in std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default()) {
+            for extension in extensions.split(';') {
+                let candidate = dir
+                    .join(program)
+                    .with_extension(extension.trim_start_matches('.'));
+                if candidate.is_file() {
+                    return candidate;
+                }
+            }
+        }
+    }
+    program.to_owned()
 }
 
 pub(crate) fn execute_command<'a>(
@@ -113,6 +166,28 @@ pub(crate) fn execute_command<'a>(
     args: impl IntoIterator<Item = &'a PathBuf>,
     current_dir: Option<&Path>,
     envs: Option<HashMap<String, String>>,
+) -> anyhow::Result<Output> {
+    execute_command_with_options(bin, args, current_dir, envs, true, &command_options())
+}
+
+/// Same as [`execute_command`], but the stdout is the data needed by the caller (e.g. the expanded code),
+/// thus it is never streamed to the terminal.
+pub(crate) fn execute_command_with_data_stdout<'a>(
+    bin: &str,
+    args: impl IntoIterator<Item = &'a PathBuf>,
+    current_dir: Option<&Path>,
+    envs: Option<HashMap<String, String>>,
+) -> anyhow::Result<Output> {
+    execute_command_with_options(bin, args, current_dir, envs, false, &command_options())
+}
+
+fn execute_command_with_options<'a>(
+    bin: &str,
+    args: impl IntoIterator<Item = &'a PathBuf>,
+    current_dir: Option<&Path>,
+    envs: Option<HashMap<String, String>>,
+    allow_stream_stdout: bool,
+    options: &CommandOptions,
 ) -> anyhow::Result<Output> {
     let args = args.into_iter().collect_vec();
     let args_display = args.iter().map(|path| path.to_string_lossy()).join(" ");
@@ -131,9 +206,17 @@ pub(crate) fn execute_command<'a>(
         bin, args_display, current_dir, cmd
     );
 
-    let result = cmd
-        .output()
-        .with_context(|| format!(r#""{bin}" "{args_display}" failed (cmd={cmd:?})"#))?;
+    let result = if options.stream_output || options.timeout.is_some() {
+        spawn_and_wait(
+            &mut cmd,
+            options.stream_output && allow_stream_stdout,
+            options.stream_output,
+            options.timeout,
+        )
+    } else {
+        cmd.output().map_err(Into::into)
+    }
+    .with_context(|| format!(r#""{bin}" "{args_display}" failed (cmd={cmd:?})"#))?;
 
     let stdout = String::from_utf8_lossy(&result.stdout);
     if result.status.success() {
@@ -160,6 +243,96 @@ pub(crate) fn execute_command<'a>(
     Ok(result)
 }
 
+/// Similar to [`Command::output`], but optionally prints each line as soon as it is written,
+/// and kills the child when it does not finish in time.
+fn spawn_and_wait(
+    cmd: &mut Command,
+    stream_stdout: bool,
+    stream_stderr: bool,
+    timeout: Option<Duration>,
+) -> anyhow::Result<Output> {
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Read in separate threads, otherwise the child blocks when the buffer of the other pipe is full
+    let stdout_reader = spawn_reader(child.stdout.take().unwrap(), stream_stdout, false);
+    let stderr_reader = spawn_reader(child.stderr.take().unwrap(), stream_stderr, true);
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if let Some(timeout) = timeout {
+            if start.elapsed() > timeout {
+                child.kill()?;
+                // Reap the child, otherwise it stays as a zombie process
+                child.wait()?;
+                join_readers_after_kill(stdout_reader, stderr_reader);
+                bail!("Command does not finish within {timeout:?}, thus it is killed");
+            }
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout_reader.join().unwrap()?,
+        stderr: stderr_reader.join().unwrap()?,
+    })
+}
+
+/// Grandchildren (e.g. of `sh -c`) are not killed and may still hold the pipes,
+/// thus the readers are only waited for a while instead of blocking forever
+fn join_readers_after_kill(
+    stdout_reader: JoinHandle<std::io::Result<Vec<u8>>>,
+    stderr_reader: JoinHandle<std::io::Result<Vec<u8>>>,
+) {
+    let deadline = Instant::now() + Duration::from_secs(1);
+    for reader in [stdout_reader, stderr_reader] {
+        while !reader.is_finished() && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(20));
+        }
+        if reader.is_finished() {
+            let _ = reader.join();
+        } else {
+            debug!("The output of the killed command is still held by its grandchildren");
+        }
+    }
+}
+
+fn spawn_reader(
+    pipe: impl Read + Send + 'static,
+    stream: bool,
+    is_stderr: bool,
+) -> JoinHandle<std::io::Result<Vec<u8>>> {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(pipe);
+        let mut ans = vec![];
+        loop {
+            let start = ans.len();
+            if reader.read_until(b'\n', &mut ans)? == 0 {
+                return Ok(ans);
+            }
+            if stream {
+                let line = String::from_utf8_lossy(&ans[start..]);
+                let line = line.trim_end_matches(['\r', '\n']);
+                // Printed above the progress bars, instead of being overwritten by them
+                MULTI_PROGRESS.suspend(|| {
+                    if is_stderr || is_json_message_format() {
+                        eprintln!("{line}");
+                    } else {
+                        println!("{line}");
+                    }
+                });
+            }
+        }
+    })
+}
+
 pub(crate) fn check_exit_code(res: &Output) -> anyhow::Result<()> {
     if !res.status.success() {
         // This will stop the whole generator and tell the users, so we do not care about testing it
@@ -170,3 +343,60 @@ pub(crate) fn check_exit_code(res: &Output) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+#[cfg(not(windows))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_execute_command_with_options() -> anyhow::Result<()> {
+        let options = CommandOptions {
+            stream_output: true,
+            timeout: Some(Duration::from_millis(500)),
+            shell: ShellMode::Direct,
+        };
+        let args = ["-c".into(), "echo hello; echo world >&2".into()];
+        let output = execute_command_with_options("sh", &args, None, None, true, &options)?;
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"hello\n");
+        assert_eq!(output.stderr, b"world\n");
+
+        let args = ["-c".into(), "sleep 10".into()];
+        let start = Instant::now();
+        let err =
+            execute_command_with_options("sh", &args, None, None, true, &options).unwrap_err();
+        assert!(format!("{err:?}").contains("does not finish"));
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        // The grandchild keeps the pipes open after the child is killed
+        let args = ["-c".into(), "sleep 10 & wait".into()];
+        let start = Instant::now();
+        let err =
+            execute_command_with_options("sh", &args, None, None, true, &options).unwrap_err();
+        assert!(format!("{err:?}").contains("does not finish"));
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_command_info_keeps_special_characters() -> anyhow::Result<()> {
+        let text = "a b $HOME 'c' \"d\" 中文";
+        for shell in [ShellMode::Direct, ShellMode::Sh] {
+            let info = compute_command_info(&["printf".into(), "%s".into(), text.into()], shell);
+            let program = &info.program;
+            let output = command_run!(program, *info.args)?;
+            assert_eq!(String::from_utf8(output.stdout)?, text);
+        }
+
+        assert_eq!(quote(Path::new("it's"), ShellMode::Powershell), "'it''s'");
+        assert_eq!(quote(Path::new("a $b"), ShellMode::Nushell), "'a $b'");
+        assert_eq!(
+            quote(Path::new(r"it's \"), ShellMode::Nushell),
+            r#""it's \\""#
+        );
+        assert_eq!(quote(Path::new(r"it's \"), ShellMode::Fish), r"'it\'s \\'");
+        Ok(())
+    }
+}
@@ -8,19 +8,29 @@ use log::debug;
 use pathdiff::diff_paths;
 use std::path::{Path, PathBuf};
 
+/// When `command` is given, it is called with the paths as extra arguments instead of `dart format`
 #[allow(clippy::vec_init_then_push)]
 pub fn dart_format(
     paths: &[PathBuf],
     base_path: &Path,
     line_length: u32,
     extra_extensions: &[&str],
+    command: Option<&[String]>,
 ) -> anyhow::Result<()> {
     if paths.is_empty() {
         return Ok(());
     }
 
     let paths = prepare_paths(paths, base_path, extra_extensions)?;
-    debug!("execute dart_format paths={paths:?} line_length={line_length}");
+    debug!("execute dart_format paths={paths:?} line_length={line_length} command={command:?}");
+
+    if let Some(command) = command {
+        return check_exit_code(&command_run!(
+            call_shell[Some(base_path), None],
+            *command,
+            *paths
+        )?);
+    }
 
     let res = command_run!(
         call_shell[Some(base_path), None],
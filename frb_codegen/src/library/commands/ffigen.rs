@@ -2,10 +2,12 @@ use super::dart_build_runner::dart_run_extra_env;
 use crate::command_run;
 use crate::commands::command_runner::call_shell;
 use crate::utils::dart_repository::dart_repo::DartRepository;
+use crate::utils::json_utils::merge_json;
 use anyhow::bail;
 use itertools::Itertools;
 use log::{debug, warn};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
@@ -18,6 +20,8 @@ pub(crate) struct FfigenArgs<'a> {
     pub llvm_compiler_opts: &'a str,
     pub dart_root: &'a Path,
     pub function_rename: Option<&'a HashMap<String, String>>,
+    /// Merged into the config, using the same keys as the `ffigen` section of `pubspec.yaml`
+    pub extra_config: Option<&'a Value>,
 }
 
 pub(crate) fn ffigen(args: FfigenArgs) -> anyhow::Result<String> {
@@ -33,6 +37,7 @@ pub(crate) fn ffigen(args: FfigenArgs) -> anyhow::Result<String> {
         llvm_compiler_opts: args.llvm_compiler_opts,
         dart_root: args.dart_root,
         function_rename: args.function_rename,
+        extra_config: args.extra_config,
     })?;
     let output_text = fs::read_to_string(temp_dart_file.path())?;
 
@@ -51,6 +56,7 @@ struct FfigenToFileArgs<'a> {
     llvm_compiler_opts: &'a str,
     dart_root: &'a Path,
     function_rename: Option<&'a HashMap<String, String>>,
+    extra_config: Option<&'a Value>,
 }
 
 fn ffigen_to_file(args: FfigenToFileArgs) -> anyhow::Result<()> {
@@ -66,11 +72,19 @@ fn ffigen_to_file(args: FfigenToFileArgs) -> anyhow::Result<()> {
 
     let config = parse_config(&args);
 
-    ffigen_raw(&config, args.dart_root)
+    ffigen_raw(&config, args.extra_config, args.dart_root)
 }
 
-pub(crate) fn ffigen_raw(config: &FfigenCommandConfig, dart_root: &Path) -> anyhow::Result<()> {
-    let config = serde_json::to_string(config)?;
+pub(crate) fn ffigen_raw(
+    config: &FfigenCommandConfig,
+    extra_config: Option<&Value>,
+    dart_root: &Path,
+) -> anyhow::Result<()> {
+    let mut config = serde_json::to_value(config)?;
+    if let Some(extra_config) = extra_config {
+        merge_json(&mut config, extra_config);
+    }
+    let config = serde_json::to_string(&config)?;
 
     let mut config_file = tempfile::NamedTempFile::new()?;
     config_file.write_all(config.as_bytes())?;
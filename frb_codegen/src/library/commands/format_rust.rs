@@ -1,19 +1,140 @@
 use crate::command_run;
 use crate::library::commands::command_runner::{call_shell, check_exit_code};
 use crate::library::commands::dart_format::prepare_paths;
+use crate::utils::path_utils::find_parent_dir_with_file;
 use log::debug;
 use std::path::{Path, PathBuf};
 
-pub fn format_rust(paths: &[PathBuf], base_path: &Path) -> anyhow::Result<()> {
+/// When `command` is given, it is called with the paths as extra arguments instead of `rustfmt`
+#[allow(clippy::vec_init_then_push)]
+pub fn format_rust(
+    paths: &[PathBuf],
+    base_path: &Path,
+    command: Option<&[String]>,
+) -> anyhow::Result<()> {
     let paths = prepare_paths(paths, base_path, &[])?;
-    debug!("execute format_rust paths={paths:?}");
+    debug!("execute format_rust paths={paths:?} command={command:?}");
 
+    if let Some(command) = command {
+        return check_exit_code(&command_run!(
+            call_shell[Some(base_path), None],
+            *command,
+            *paths
+        )?);
+    }
+
+    let edition = detect_edition(base_path);
+    let config_path = find_rustfmt_config(base_path);
     check_exit_code(&command_run!(
         call_shell[Some(base_path), None],
         "rustfmt",
-        // otherwise cannot understand `async move`
         "--edition",
-        "2018",
+        edition,
+        ?config_path.as_ref().map(|_| "--config-path"),
+        ?config_path,
         *paths
     )?)
 }
+
+/// The generated code uses e.g. `async move`, thus needs at least the 2018 edition
+fn detect_edition(base_path: &Path) -> String {
+    const MIN_EDITION: u32 = 2018;
+
+    let edition = find_parent_dir_with_file(base_path, "Cargo.toml")
+        .and_then(|dir| parse_package_edition(&dir));
+    debug!("detect_edition base_path={base_path:?} edition={edition:?}");
+    edition.unwrap_or(MIN_EDITION).max(MIN_EDITION).to_string()
+}
+
+/// Reads `package.edition` of the manifest in `package_dir`, following `edition.workspace = true`
+/// to the `[workspace.package]` table of the enclosing workspace root
+fn parse_package_edition(package_dir: &Path) -> Option<u32> {
+    let manifest = read_manifest(package_dir)?;
+    let edition = manifest.get("package")?.get("edition")?;
+    if let Some(edition) = edition.as_str() {
+        return edition.parse().ok();
+    }
+
+    if edition.get("workspace")?.as_bool() != Some(true) {
+        return None;
+    }
+    package_dir
+        .ancestors()
+        .filter_map(read_manifest)
+        .find_map(|manifest| manifest.get("workspace").cloned())?
+        .get("package")?
+        .get("edition")?
+        .as_str()?
+        .parse()
+        .ok()
+}
+
+fn read_manifest(dir: &Path) -> Option<toml::Value> {
+    let text = std::fs::read_to_string(dir.join("Cargo.toml")).ok()?;
+    toml::from_str(&text).ok()
+}
+
+fn find_rustfmt_config(base_path: &Path) -> Option<PathBuf> {
+    ["rustfmt.toml", ".rustfmt.toml"]
+        .iter()
+        .find_map(|name| find_parent_dir_with_file(base_path, name).map(|dir| dir.join(name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_detect_edition_and_find_rustfmt_config() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let src_dir = dir.path().join("src");
+        fs::create_dir_all(&src_dir)?;
+        fs::write(src_dir.join("lib.rs"), "")?;
+        assert_eq!(find_rustfmt_config(&src_dir), None);
+
+        let manifest = "[package]\nname = \"a\"\nversion = \"0.1.0\"\n";
+        fs::write(dir.path().join("Cargo.toml"), manifest)?;
+        assert_eq!(detect_edition(&src_dir), "2018");
+
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            format!("{manifest}edition = \"2021\"\n"),
+        )?;
+        assert_eq!(detect_edition(&src_dir), "2021");
+
+        fs::write(dir.path().join("rustfmt.toml"), "max_width = 120\n")?;
+        assert_eq!(
+            find_rustfmt_config(&src_dir),
+            Some(dir.path().join("rustfmt.toml"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_edition_inherited_from_workspace() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let member_dir = dir.path().join("crates").join("a");
+        let src_dir = member_dir.join("src");
+        fs::create_dir_all(&src_dir)?;
+        fs::write(src_dir.join("lib.rs"), "")?;
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/a\"]\n\n[workspace.package]\nedition = \"2021\"\n",
+        )?;
+        fs::write(
+            member_dir.join("Cargo.toml"),
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\nedition.workspace = true\n",
+        )?;
+        assert_eq!(detect_edition(&src_dir), "2021");
+
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/a\"]\n",
+        )?;
+        assert_eq!(detect_edition(&src_dir), "2018");
+
+        Ok(())
+    }
+}
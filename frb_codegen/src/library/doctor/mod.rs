@@ -0,0 +1,360 @@
+//! Diagnose the external tools used by the code generator, and suggest how to fix the problems
+
+use crate::codegen::config::internal_config_parser::generator_parser::fallback_llvm_path;
+use crate::codegen::Config;
+use crate::command_run;
+use crate::library::commands::command_runner::call_shell;
+use crate::library::commands::ensure_tools_available::FFIGEN_REQUIREMENT;
+use crate::utils::dart_repository::dart_repo::{DartDependencyMode, DartRepository};
+use crate::utils::message_format::print_human;
+use crate::utils::path_utils::find_dart_package_dir;
+use anyhow::bail;
+use cargo_metadata::{Version, VersionReq};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::env;
+use std::path::{Path, PathBuf};
+
+pub struct DoctorConfig {
+    /// The Dart/Flutter project, whose dependencies (e.g. ffigen) are checked.
+    /// Defaults to the one containing the current directory
+    pub dart_root: Option<PathBuf>,
+    /// Defaults to the `llvm_path` in the configuration file if any
+    pub llvm_path: Option<Vec<String>>,
+}
+
+pub fn doctor(config: DoctorConfig) -> anyhow::Result<()> {
+    let dart_root =
+        (config.dart_root).or_else(|| find_dart_package_dir(&env::current_dir().ok()?).ok());
+    let llvm_path =
+        (config.llvm_path).or_else(|| Config::from_files_auto_option().ok().flatten()?.llvm_path);
+
+    let diagnoses = vec![
+        check_tool(&CARGO),
+        check_tool(&RUSTFMT),
+        check_tool(&CARGO_EXPAND),
+        check_tool(&DART),
+        check_tool(&FLUTTER),
+        Diagnosis {
+            name: "cbindgen",
+            severity: Severity::Ok,
+            message: "bundled into flutter_rust_bridge_codegen".to_owned(),
+            suggestion: None,
+        },
+        check_ffigen(dart_root.as_deref()),
+        check_llvm(env::var("LIBCLANG_PATH").ok(), llvm_path),
+        check_tool(&WASM_PACK),
+        check_tool(&RUST_SRC_NIGHTLY),
+    ];
+
+    for diagnosis in &diagnoses {
+        print_human(&diagnosis.to_string());
+    }
+
+    let num_errors = (diagnoses.iter())
+        .filter(|x| x.severity == Severity::Error)
+        .count();
+    if num_errors > 0 {
+        bail!("Found {num_errors} problem(s), please follow the suggestions above");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Severity {
+    Ok,
+    /// Only needed by some features, e.g. `build-web`
+    Warning,
+    Error,
+}
+
+struct Diagnosis {
+    name: &'static str,
+    severity: Severity,
+    message: String,
+    suggestion: Option<String>,
+}
+
+impl std::fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mark = match self.severity {
+            Severity::Ok => "✓",
+            Severity::Warning => "!",
+            Severity::Error => "✗",
+        };
+        write!(f, "[{mark}] {}: {}", self.name, self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, "\n    {suggestion}")?;
+        }
+        Ok(())
+    }
+}
+
+struct Tool {
+    name: &'static str,
+    command: &'static [&'static str],
+    /// Whether the generator cannot work without it, or it is only needed by some features
+    severity_when_missing: Severity,
+    install_suggestion: &'static str,
+    requirement: Option<VersionRequirement>,
+}
+
+struct VersionRequirement {
+    version: &'static str,
+    upgrade_suggestion: &'static str,
+    severity_when_outdated: Severity,
+}
+
+const CARGO: Tool = Tool {
+    name: "cargo",
+    command: &["cargo", "--version"],
+    severity_when_missing: Severity::Error,
+    install_suggestion: "Install Rust from https://rustup.rs",
+    // The `rust-version` of the `flutter_rust_bridge` crate
+    requirement: Some(VersionRequirement {
+        version: ">=1.70.0",
+        upgrade_suggestion: "Run `rustup update`",
+        severity_when_outdated: Severity::Error,
+    }),
+};
+
+const RUSTFMT: Tool = Tool {
+    name: "rustfmt",
+    command: &["rustfmt", "--version"],
+    severity_when_missing: Severity::Warning,
+    install_suggestion: "Run `rustup component add rustfmt`, or use `--no-rust-format`",
+    // The first version supporting the 2021 edition
+    requirement: Some(VersionRequirement {
+        version: ">=1.4.38",
+        upgrade_suggestion: "Run `rustup update`",
+        severity_when_outdated: Severity::Warning,
+    }),
+};
+
+const CARGO_EXPAND: Tool = Tool {
+    name: "cargo-expand",
+    command: &["cargo", "expand", "--version"],
+    severity_when_missing: Severity::Warning,
+    install_suggestion: "Run `cargo install cargo-expand`, otherwise it is installed on first use",
+    requirement: None,
+};
+
+const DART: Tool = Tool {
+    name: "dart",
+    command: &["dart", "--version"],
+    severity_when_missing: Severity::Error,
+    install_suggestion:
+        "Install Flutter (which contains Dart) from https://docs.flutter.dev/get-started/install",
+    // Older versions are supported via `--no-dart3`
+    requirement: Some(VersionRequirement {
+        version: ">=3.0.0",
+        upgrade_suggestion: "Run `flutter upgrade`, or use `--no-dart3`",
+        severity_when_outdated: Severity::Warning,
+    }),
+};
+
+const FLUTTER: Tool = Tool {
+    name: "flutter",
+    command: &["flutter", "--version"],
+    severity_when_missing: Severity::Warning,
+    install_suggestion: "Install Flutter from https://docs.flutter.dev/get-started/install, unless only pure Dart is used",
+    requirement: None,
+};
+
+const WASM_PACK: Tool = Tool {
+    name: "wasm-pack",
+    command: &["wasm-pack", "--version"],
+    severity_when_missing: Severity::Warning,
+    install_suggestion: "Run `cargo install wasm-pack`, which is needed by `build-web`",
+    requirement: None,
+};
+
+const RUST_SRC_NIGHTLY: Tool = Tool {
+    name: "rust-src (nightly)",
+    command: &["rustup", "+nightly", "component", "list", "--installed"],
+    severity_when_missing: Severity::Warning,
+    install_suggestion:
+        "Run `rustup toolchain install nightly --component rust-src`, which is needed by `build-web`",
+    requirement: None,
+};
+
+fn check_tool(tool: &Tool) -> Diagnosis {
+    let output = command_run!(call_shell[None, None], *tool.command)
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout).into_owned()
+                + &String::from_utf8_lossy(&output.stderr)
+        });
+    // `rustup component list` succeeds even if the component is missing
+    let output = output.filter(|x| tool.name != RUST_SRC_NIGHTLY.name || x.contains("rust-src"));
+    diagnose_tool(tool, output.as_deref())
+}
+
+fn diagnose_tool(tool: &Tool, output: Option<&str>) -> Diagnosis {
+    let create = |severity, message: String, suggestion: Option<&str>| Diagnosis {
+        name: tool.name,
+        severity,
+        message,
+        suggestion: suggestion.map(|x| x.to_owned()),
+    };
+
+    let Some(output) = output else {
+        return create(
+            tool.severity_when_missing,
+            "not found".to_owned(),
+            Some(tool.install_suggestion),
+        );
+    };
+    let version = parse_version(output);
+    let version_text = version
+        .as_ref()
+        .map_or("found".to_owned(), |x| x.to_string());
+
+    if let (Some(requirement), Some(version)) = (&tool.requirement, &version) {
+        if !VersionReq::parse(requirement.version)
+            .unwrap()
+            .matches(version)
+        {
+            return create(
+                requirement.severity_when_outdated,
+                format!("{version} is too old, need {}", requirement.version),
+                Some(requirement.upgrade_suggestion),
+            );
+        }
+    }
+    create(Severity::Ok, version_text, None)
+}
+
+fn parse_version(output: &str) -> Option<Version> {
+    lazy_static! {
+        static ref VERSION: Regex = Regex::new(r"\d+\.\d+\.\d+").unwrap();
+    }
+    Version::parse(VERSION.find(output)?.as_str()).ok()
+}
+
+fn check_ffigen(dart_root: Option<&Path>) -> Diagnosis {
+    let create = |severity, message: String, suggestion: Option<&str>| Diagnosis {
+        name: "ffigen",
+        severity,
+        message,
+        suggestion: suggestion.map(|x| x.to_owned()),
+    };
+
+    let Some(dart_root) = dart_root else {
+        return create(
+            Severity::Warning,
+            "skipped, since not inside a Dart/Flutter project".to_owned(),
+            Some("Run inside the project, or use `--dart-root`"),
+        );
+    };
+    let result = DartRepository::from_path(dart_root).and_then(|repo| {
+        repo.has_specified_and_installed("ffigen", DartDependencyMode::Dev, &FFIGEN_REQUIREMENT)
+    });
+    match result {
+        Ok(()) => create(
+            Severity::Ok,
+            format!("installed ({})", *FFIGEN_REQUIREMENT),
+            None,
+        ),
+        Err(e) => create(
+            Severity::Warning,
+            e.to_string(),
+            Some("Run `dart pub add --dev ffigen`, which is needed when `full_dep: true`"),
+        ),
+    }
+}
+
+/// Looks in the same places as ffigen, i.e. the configured `llvm_path`, and `LIBCLANG_PATH` which takes precedence
+fn check_llvm(libclang_path: Option<String>, llvm_path: Option<Vec<String>>) -> Diagnosis {
+    let configured = libclang_path.is_some() || llvm_path.is_some();
+    let found = (libclang_path.into_iter())
+        .chain(llvm_path.unwrap_or_else(fallback_llvm_path))
+        .find(|path| {
+            let path = Path::new(path);
+            path.is_file()
+                || [
+                    "libclang.so",
+                    "libclang.dylib",
+                    "libclang.dll",
+                    "lib/libclang.so",
+                    "lib/libclang.dylib",
+                    "bin/libclang.dll",
+                ]
+                .iter()
+                .any(|name| path.join(name).exists())
+        });
+    Diagnosis {
+        name: "llvm",
+        severity: if found.is_some() {
+            Severity::Ok
+        } else {
+            Severity::Warning
+        },
+        message: found.as_ref().map_or_else(
+            || {
+                format!(
+                    "libclang not found in the {} locations",
+                    if configured { "configured" } else { "default" }
+                )
+            },
+            |x| format!("found at {x}"),
+        ),
+        suggestion: found.is_none().then(|| {
+            "Install LLVM (e.g. `apt install libclang-dev` or `brew install llvm`), \
+            or use `llvm_path`, which is needed by ffigen when `full_dep: true`"
+                .to_owned()
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::path_utils::path_to_string;
+    use std::fs;
+
+    #[test]
+    fn test_diagnose_tool() {
+        let diagnosis = diagnose_tool(&CARGO, Some("cargo 1.80.1 (376290515 2024-07-16)\n"));
+        assert_eq!(diagnosis.severity, Severity::Ok);
+        assert_eq!(diagnosis.message, "1.80.1");
+
+        let diagnosis = diagnose_tool(&DART, Some("Dart SDK version: 2.19.6 (stable)"));
+        assert_eq!(diagnosis.severity, Severity::Warning);
+        assert_eq!(diagnosis.to_string(), "[!] dart: 2.19.6 is too old, need >=3.0.0\n    Run `flutter upgrade`, or use `--no-dart3`");
+
+        let diagnosis = diagnose_tool(&CARGO, Some("cargo 1.65.0 (4bc8f24d3 2022-10-20)\n"));
+        assert_eq!(diagnosis.severity, Severity::Error);
+
+        let diagnosis = diagnose_tool(&WASM_PACK, None);
+        assert_eq!(diagnosis.severity, Severity::Warning);
+        assert_eq!(diagnosis.message, "not found");
+    }
+
+    #[test]
+    fn test_check_llvm() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let llvm_dir = temp_dir.path().join("llvm");
+        fs::create_dir_all(llvm_dir.join("lib"))?;
+        fs::write(llvm_dir.join("lib/libclang.so"), "")?;
+        let llvm_dir = path_to_string(&llvm_dir)?;
+        let missing_dir = path_to_string(&temp_dir.path().join("missing"))?;
+
+        let diagnosis = check_llvm(None, Some(vec![missing_dir.clone(), llvm_dir.clone()]));
+        assert_eq!(diagnosis.severity, Severity::Ok);
+        assert_eq!(diagnosis.message, format!("found at {llvm_dir}"));
+
+        let libclang_file = path_to_string(&temp_dir.path().join("llvm/lib/libclang.so"))?;
+        let diagnosis = check_llvm(Some(libclang_file.clone()), Some(vec![llvm_dir]));
+        assert_eq!(diagnosis.message, format!("found at {libclang_file}"));
+
+        let diagnosis = check_llvm(None, Some(vec![missing_dir]));
+        assert_eq!(diagnosis.severity, Severity::Warning);
+        assert_eq!(
+            diagnosis.message,
+            "libclang not found in the configured locations"
+        );
+        Ok(())
+    }
+}
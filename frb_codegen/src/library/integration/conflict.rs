@@ -0,0 +1,214 @@
+use anyhow::Result;
+use itertools::Itertools;
+use log::{info, warn};
+use std::cell::Cell;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+/// What to do when the template would overwrite an existing file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConflictPolicy {
+    /// Ask for each file, or skip it when not run in a terminal
+    #[default]
+    Interactive,
+    /// Replace the existing file with the template
+    Overwrite,
+    /// Keep the existing file untouched
+    SkipExisting,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Resolution {
+    Skip,
+    Overwrite,
+    Merge,
+}
+
+pub(super) struct ConflictResolver {
+    policy: ConflictPolicy,
+    // Set when the user chooses to apply the same resolution to all remaining files
+    remembered: Cell<Option<Resolution>>,
+}
+
+impl ConflictResolver {
+    pub(super) fn new(policy: ConflictPolicy) -> Self {
+        Self {
+            policy,
+            remembered: Cell::new(None),
+        }
+    }
+
+    /// Returns the content to be written, or `None` to keep the existing file.
+    pub(super) fn resolve(
+        &self,
+        path: &Path,
+        existing: &[u8],
+        template: &[u8],
+    ) -> Result<Option<Vec<u8>>> {
+        if existing == template {
+            return Ok(None);
+        }
+
+        let resolution = match (self.policy, self.remembered.get()) {
+            (_, Some(resolution)) => resolution,
+            (ConflictPolicy::Overwrite, _) => Resolution::Overwrite,
+            (ConflictPolicy::SkipExisting, _) => Resolution::Skip,
+            (ConflictPolicy::Interactive, _) if !atty::is(atty::Stream::Stdin) => Resolution::Skip,
+            // frb-coverage:ignore-start
+            (ConflictPolicy::Interactive, _) => {
+                let (resolution, remember) = prompt(
+                    path,
+                    existing,
+                    template,
+                    &mut std::io::stdin().lock(),
+                    &mut std::io::stdout(),
+                )?;
+                if remember {
+                    self.remembered.set(Some(resolution));
+                }
+                resolution
+            } // frb-coverage:ignore-end
+        };
+
+        Ok(apply(path, resolution, existing, template))
+    }
+}
+
+fn apply(path: &Path, resolution: Resolution, existing: &[u8], template: &[u8]) -> Option<Vec<u8>> {
+    match resolution {
+        Resolution::Skip => {
+            warn!(
+                "Skip writing to {path:?} because file already exists. \
+                It is suggested to remove that file, or use `--force`, to apply the full template."
+            );
+            None
+        }
+        Resolution::Overwrite => {
+            info!("Overwrite {path:?}");
+            Some(template.to_owned())
+        }
+        Resolution::Merge => match (std::str::from_utf8(existing), std::str::from_utf8(template)) {
+            (Ok(existing), Ok(template)) => {
+                info!("Merge into {path:?}, please resolve the conflict markers");
+                Some(merge_with_conflict_markers(existing, template).into_bytes())
+            }
+            // frb-coverage:ignore-start
+            _ => {
+                warn!("Skip writing to {path:?} because binary files cannot be merged");
+                None
+            } // frb-coverage:ignore-end
+        },
+    }
+}
+
+/// Returns the resolution, and whether to apply it to all remaining files
+fn prompt(
+    path: &Path,
+    existing: &[u8],
+    template: &[u8],
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+) -> Result<(Resolution, bool)> {
+    let texts = std::str::from_utf8(existing)
+        .ok()
+        .zip(std::str::from_utf8(template).ok());
+
+    writeln!(
+        output,
+        "{path:?} already exists and differs from the template."
+    )?;
+    loop {
+        write!(
+            output,
+            "[s]kip, [o]verwrite, {}[d]iff (uppercase to apply to all remaining files)? ",
+            if texts.is_some() { "[m]erge, " } else { "" }
+        )?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok((Resolution::Skip, false));
+        }
+        let answer = line.trim();
+        let remember = answer.chars().all(|c| c.is_uppercase()) && !answer.is_empty();
+
+        match (answer.to_lowercase().as_str(), texts) {
+            ("s", _) => return Ok((Resolution::Skip, remember)),
+            ("o", _) => return Ok((Resolution::Overwrite, remember)),
+            ("m", Some(_)) => return Ok((Resolution::Merge, remember)),
+            ("d", Some((existing, template))) => {
+                writeln!(output, "{}", diff_lines(existing, template))?
+            }
+            ("d", None) => writeln!(output, "Binary files differ")?,
+            _ => {}
+        }
+    }
+}
+
+fn diff_lines(existing: &str, template: &str) -> String {
+    (diff::lines(existing, template).into_iter())
+        .map(|line| match line {
+            diff::Result::Left(l) => format!("-{l}"),
+            diff::Result::Both(l, _) => format!(" {l}"),
+            diff::Result::Right(r) => format!("+{r}"),
+        })
+        .join("\n")
+}
+
+fn merge_with_conflict_markers(existing: &str, template: &str) -> String {
+    let mut ans = vec![];
+    let (mut ours, mut theirs) = (vec![], vec![]);
+    for line in diff::lines(existing, template) {
+        match line {
+            diff::Result::Left(l) => ours.push(l),
+            diff::Result::Right(r) => theirs.push(r),
+            diff::Result::Both(l, _) => {
+                push_conflict(&mut ans, &mut ours, &mut theirs);
+                ans.push(l);
+            }
+        }
+    }
+    push_conflict(&mut ans, &mut ours, &mut theirs);
+
+    ans.join("\n")
+}
+
+fn push_conflict<'a>(ans: &mut Vec<&'a str>, ours: &mut Vec<&'a str>, theirs: &mut Vec<&'a str>) {
+    if ours.is_empty() && theirs.is_empty() {
+        return;
+    }
+    ans.push("<<<<<<< existing");
+    ans.append(ours);
+    ans.push("=======");
+    ans.append(theirs);
+    ans.push(">>>>>>> flutter_rust_bridge template");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_with_conflict_markers() {
+        assert_eq!(
+            merge_with_conflict_markers("a\nb\nc\n", "a\nB\nc\nd\n"),
+            "a\n<<<<<<< existing\nb\n=======\nB\n>>>>>>> flutter_rust_bridge template\nc\n\
+            <<<<<<< existing\n=======\nd\n>>>>>>> flutter_rust_bridge template\n"
+        );
+    }
+
+    #[test]
+    fn test_prompt() -> Result<()> {
+        let mut output = vec![];
+        let answer = prompt(
+            Path::new("a.txt"),
+            b"hello\n",
+            b"world\n",
+            &mut &b"x\nd\nO\n"[..],
+            &mut output,
+        )?;
+        assert_eq!(answer, (Resolution::Overwrite, true));
+        assert!(String::from_utf8(output)?.contains("-hello\n+world"));
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::integration::conflict::ConflictPolicy;
 use crate::integration::integrator;
 use crate::integration::integrator::IntegrateConfig;
 use crate::library::commands::flutter::flutter_create;
@@ -49,6 +50,7 @@ pub fn create(config: CreateConfig) -> anyhow::Result<()> {
         rust_crate_name: config.rust_crate_name,
         rust_crate_dir: config.rust_crate_dir,
         template: config.template,
+        conflict_policy: ConflictPolicy::SkipExisting,
     })
 }
 
@@ -1,3 +1,4 @@
+use crate::integration::conflict::{ConflictPolicy, ConflictResolver};
 use crate::integration::utils::{overlay_dir, replace_file_content};
 use crate::library::commands::dart_fix::dart_fix;
 use crate::library::commands::dart_format::dart_format;
@@ -8,7 +9,7 @@ use crate::utils::path_utils::find_dart_package_dir;
 use anyhow::Result;
 use include_dir::{include_dir, Dir};
 use itertools::Itertools;
-use log::{debug, info, warn};
+use log::{debug, info};
 use std::collections::HashMap;
 use std::env;
 use std::ffi::OsStr;
@@ -20,6 +21,7 @@ pub struct IntegrateConfig {
     pub rust_crate_name: Option<String>,
     pub rust_crate_dir: String,
     pub template: Template,
+    pub conflict_policy: ConflictPolicy,
 }
 
 /// Integrate Rust into existing Flutter project.
@@ -35,12 +37,14 @@ pub fn integrate(config: IntegrateConfig) -> Result<()> {
         .unwrap_or(format!("rust_lib_{}", dart_package_name));
 
     info!("Overlay template onto project");
+    let resolver = ConflictResolver::new(config.conflict_policy);
     let replacements = compute_replacements(&config, &dart_package_name, &rust_crate_name);
     execute_overlay_dir(
         &TemplateDirs::SHARED,
         &replacements,
         &dart_root,
         &config,
+        &resolver,
         None,
     )?;
     let (dir, comment_out_files) = match &config.template {
@@ -55,6 +59,7 @@ pub fn integrate(config: IntegrateConfig) -> Result<()> {
         &replacements,
         &dart_root,
         &config,
+        &resolver,
         Some(&comment_out_files),
     )?;
 
@@ -80,7 +85,7 @@ pub fn integrate(config: IntegrateConfig) -> Result<()> {
     dart_fix(&dart_root)?;
 
     info!("Format Dart code");
-    dart_format(&[dart_root.clone()], &dart_root, 80, &[])?;
+    dart_format(std::slice::from_ref(&dart_root), &dart_root, 80, &[], None)?;
 
     Ok(())
 }
@@ -90,6 +95,7 @@ fn execute_overlay_dir(
     replacements: &HashMap<&'static str, String>,
     dart_root: &Path,
     config: &IntegrateConfig,
+    resolver: &ConflictResolver,
     comment_out_files: Option<&[String]>,
 ) -> Result<()> {
     overlay_dir(
@@ -103,6 +109,7 @@ fn execute_overlay_dir(
                 existing_content,
                 replacements,
                 config.enable_local_dependency,
+                resolver,
                 comment_out_files,
             )
         },
@@ -193,36 +200,43 @@ fn modify_file(
     existing_content: Option<Vec<u8>>,
     replacements: &HashMap<&str, String>,
     enable_local_dependency: bool,
+    resolver: &ConflictResolver,
     comment_out_files: Option<&[String]>,
-) -> Option<(PathBuf, Vec<u8>)> {
+) -> Result<Option<(PathBuf, Vec<u8>)>> {
     let src = replace_file_content(reference_content, replacements);
 
-    if let Some(existing_content) = existing_content {
-        if let (Some(file_name), Some(files)) = (
-            target_path.file_name().and_then(|e| e.to_str()),
-            comment_out_files,
-        ) {
-            if files.contains(&file_name.to_owned()) {
-                return comment_out_existing_file_and_write_template(
-                    existing_content,
-                    target_path,
-                    &src,
-                );
-            }
+    let Some(existing_content) = existing_content else {
+        let content = compute_template_content(&target_path, src, enable_local_dependency);
+        return Ok(Some((target_path, content)));
+    };
+
+    if let (Some(file_name), Some(files)) = (
+        target_path.file_name().and_then(|e| e.to_str()),
+        comment_out_files,
+    ) {
+        if files.contains(&file_name.to_owned()) {
+            return Ok(comment_out_existing_file_and_write_template(
+                existing_content,
+                target_path,
+                &src,
+            ));
         }
-        // We do not care about this warning
-        // frb-coverage:ignore-start
-        warn!(
-            "Skip writing to {target_path:?} because file already exists. \
-            It is suggested to remove that file before running this command to apply the full template."
-        );
-        return None;
-        // frb-coverage:ignore-end
     }
 
+    let content = compute_template_content(&target_path, src, enable_local_dependency);
+    Ok(resolver
+        .resolve(&target_path, &existing_content, &content)?
+        .map(|content| (target_path, content)))
+}
+
+fn compute_template_content(
+    target_path: &Path,
+    src: Vec<u8>,
+    enable_local_dependency: bool,
+) -> Vec<u8> {
     if target_path.iter().contains(&OsStr::new("cargokit")) {
-        if let Some(comments) = compute_cargokit_comments(&target_path) {
-            return Some((target_path, [comments.as_bytes(), &src].concat()));
+        if let Some(comments) = compute_cargokit_comments(target_path) {
+            return [comments.as_bytes(), &src].concat();
         }
     }
 
@@ -234,10 +248,10 @@ fn modify_file(
         if enable_local_dependency {
             ans += "\nlocal: true\n";
         }
-        return Some((target_path, ans.as_bytes().to_owned()));
+        return ans.into_bytes();
     }
 
-    Some((target_path, src))
+    src
 }
 
 fn comment_out_existing_file_and_write_template(
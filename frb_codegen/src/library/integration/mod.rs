@@ -1,8 +1,10 @@
 //! Integrate Flutter with Rust
 
+mod conflict;
 mod creator;
 pub(crate) mod integrator;
 mod utils;
 
+pub use conflict::ConflictPolicy;
 pub use creator::{create, CreateConfig};
 pub use integrator::{integrate, IntegrateConfig};
@@ -13,7 +13,7 @@ pub(super) fn overlay_dir(
     current_reference_dir: &Dir,
     replacements: &HashMap<&str, String>,
     base_target_path: &Path,
-    modifier: &impl Fn(&Path, &[u8], Option<Vec<u8>>) -> Option<(PathBuf, Vec<u8>)>,
+    modifier: &impl Fn(&Path, &[u8], Option<Vec<u8>>) -> Result<Option<(PathBuf, Vec<u8>)>>,
     filter: &impl Fn(&Path) -> bool,
 ) -> Result<()> {
     for entry in current_reference_dir.entries() {
@@ -26,7 +26,7 @@ pub(super) fn overlay_dir(
         let target_sub_path = compute_effective_path(&target_sub_path, replacements);
         match entry {
             DirEntry::Dir(new_reference_dir) => {
-                if let Some((modified_path, _)) = modifier(&target_sub_path, &[], None) {
+                if let Some((modified_path, _)) = modifier(&target_sub_path, &[], None)? {
                     debug!("Create dir {modified_path:?}");
                     fs::create_dir_all(&modified_path)?;
                     overlay_dir(
@@ -42,7 +42,7 @@ pub(super) fn overlay_dir(
                 let reference_content = file.contents();
                 let existing_content = fs::read(&target_sub_path).ok();
                 if let Some((modified_path, modified_data)) =
-                    modifier(&target_sub_path, reference_content, existing_content)
+                    modifier(&target_sub_path, reference_content, existing_content)?
                 {
                     debug!("Write to {modified_path:?}");
                     fs::write(&modified_path, modified_data)?;
@@ -160,6 +160,7 @@ fn ffigen(repo_base_dir: &Path) -> anyhow::Result<()> {
             ignore_source_errors: true,
             ..Default::default()
         },
+        None,
         &repo_base_dir.join("frb_dart"),
     )?;
 
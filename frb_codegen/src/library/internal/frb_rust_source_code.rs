@@ -32,31 +32,72 @@ pub(crate) fn generate_frb_rust_source_code(repo_base_dir: &Path) -> anyhow::Res
     );
 
     fs::write(&path_target, text)?;
-    format_rust(&[path_target.clone()], path_target.parent().unwrap())?;
+    format_rust(
+        std::slice::from_ref(&path_target),
+        path_target.parent().unwrap(),
+        None,
+    )?;
 
     Ok(())
 }
 
-fn generate_target(target: Target) -> String {
-    let target_lowercase = target.to_string().to_lowercase();
+/// Names of the symbols exported by `frb_generated_boilerplate_io!`, which are prefixed by its
+/// `symbol_prefix`, while the other symbols come from the runtime and are shared
+pub(crate) fn compute_io_exported_symbols() -> Vec<String> {
+    (generate_extern_funcs(Target::Io).iter())
+        .map(|f| f.func_name(""))
+        .chain(
+            // Hand-written in `boilerplate_io.rs`
+            ["frb_get_rust_content_hash", "frb_method_channel_dispatch"].map(str::to_owned),
+        )
+        .collect()
+}
 
-    let funcs = [
+fn generate_extern_funcs(target: Target) -> Vec<ExternFunc> {
+    [
         FfiDispatcherMode::iter()
             .map(|mode| generate_target_pde_dispatcher_mode(target, mode))
             .collect_vec(),
         vec![generate_dart_fn_deliver_output(target)],
     ]
-    .concat();
-    let body = funcs.iter().map(|f| f.generate("")).join("\n");
+    .concat()
+}
+
+fn generate_target(target: Target) -> String {
+    let target_lowercase = target.to_string().to_lowercase();
+
+    let funcs = generate_extern_funcs(target);
+    let body = match target {
+        // The exported names are prefixed when multiple versions of the API live in the same crate
+        Target::Io => (funcs.iter())
+            .map(|f| {
+                let attribute = format!(
+                    "#[export_name = concat!($symbol_prefix, \"{}\")]",
+                    f.func_name("")
+                );
+                f.generate_with_attribute("", &attribute)
+            })
+            .join("\n"),
+        Target::Web => funcs.iter().map(|f| f.generate("")).join("\n"),
+    };
+    let arms = match target {
+        Target::Io => format!(
+            "() => {{
+                $crate::frb_generated_{target_lowercase}_extern_func!(symbol_prefix = \"\");
+            }};
+            (symbol_prefix = $symbol_prefix:literal) => {{
+                {body}
+            }};"
+        ),
+        Target::Web => format!("() => {{ {body} }};"),
+    };
 
     format!(
         r#"
             #[doc(hidden)]
             #[macro_export]
             macro_rules! frb_generated_{target_lowercase}_extern_func {{
-                () => {{
-                    {body}
-                }};
+                {arms}
             }}
         "#
     )
@@ -124,3 +165,28 @@ fn generate_dart_fn_deliver_output(target: Target) -> ExternFunc {
         needs_ffigen: false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_io_exported_symbols() {
+        let sources = [
+            include_str!("../../../../frb_rust/src/internal_generated/mod.rs"),
+            include_str!("../../../../frb_rust/src/for_generated/boilerplate_io.rs"),
+        ]
+        .concat();
+        let exported_symbols = compute_io_exported_symbols();
+        for symbol in &exported_symbols {
+            assert!(
+                sources.contains(&format!("concat!($symbol_prefix, \"{symbol}\")")),
+                "{symbol}"
+            );
+        }
+        assert_eq!(
+            sources.matches("concat!($symbol_prefix, ").count(),
+            exported_symbols.len()
+        );
+    }
+}
@@ -1,5 +1,5 @@
 mod frb_dart_source_code;
-mod frb_rust_source_code;
+pub(crate) mod frb_rust_source_code;
 
 use crate::internal::frb_dart_source_code::generate_frb_dart_source_code;
 use crate::internal::frb_rust_source_code::generate_frb_rust_source_code;
@@ -1,10 +1,15 @@
 //! Code used in `lib.rs`
 
+pub mod build_android;
+pub mod build_apple;
 pub mod build_web;
 pub mod codegen;
 pub(crate) mod commands;
+pub mod doctor;
 pub mod integration;
 #[doc(hidden)]
 pub mod internal;
 pub mod misc;
+pub mod sbom;
+pub mod test;
 pub mod utils;
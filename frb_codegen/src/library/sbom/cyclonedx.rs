@@ -0,0 +1,46 @@
+use crate::library::sbom::{SbomComponent, SbomGraph};
+use serde_json::{json, Value};
+
+/// See <https://cyclonedx.org/docs/1.5/json/>
+pub(super) fn generate(graph: &SbomGraph) -> Value {
+    json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.5",
+        "version": 1,
+        "metadata": {
+            "tools": [{
+                "vendor": "flutter_rust_bridge",
+                "name": "flutter_rust_bridge_codegen",
+                "version": env!("CARGO_PKG_VERSION"),
+            }],
+            "component": generate_component(&graph.root, "application"),
+            "properties": [{"name": "cdx:rust:target", "value": graph.target}],
+        },
+        "components": (graph.components.iter())
+            .map(|x| generate_component(x, "library"))
+            .collect::<Vec<_>>(),
+        "dependencies": (graph.dependencies.iter())
+            .map(|x| json!({"ref": x.purl, "dependsOn": x.depends_on}))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn generate_component(component: &SbomComponent, ty: &str) -> Value {
+    let mut ans = json!({
+        "type": ty,
+        "bom-ref": component.purl(),
+        "name": component.name,
+        "version": component.version,
+        "purl": component.purl(),
+    });
+    if let Some(description) = &component.description {
+        ans["description"] = json!(description);
+    }
+    if let Some(license) = &component.license {
+        ans["licenses"] = json!([{"expression": license}]);
+    }
+    if let Some(repository) = &component.repository {
+        ans["externalReferences"] = json!([{"type": "vcs", "url": repository}]);
+    }
+    ans
+}
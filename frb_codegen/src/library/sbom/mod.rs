@@ -0,0 +1,286 @@
+//! Generate SBOM (software bill of materials) of the Rust code bundled into a Flutter app
+
+mod cyclonedx;
+mod spdx;
+
+use crate::library::commands::cargo_metadata::execute_cargo_metadata_for_target;
+use crate::utils::file_utils::create_dir_all_and_write;
+use anyhow::Context;
+use cargo_metadata::{DependencyKind, Metadata, NodeDep, Package, PackageId};
+use chrono::{DateTime, TimeZone, Utc};
+use itertools::Itertools;
+use log::info;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::env;
+use std::path::PathBuf;
+
+pub struct SbomConfig {
+    pub rust_crate_dir: PathBuf,
+    pub format: SbomFormat,
+    /// Rust target triples, or empty to use [DEFAULT_TARGETS]
+    pub targets: Vec<String>,
+    pub output_dir: PathBuf,
+}
+
+/// Please refer to `SbomFormatArg` for doc
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SbomFormat {
+    CycloneDx,
+    Spdx,
+}
+
+/// The targets of the platforms supported by Flutter
+pub const DEFAULT_TARGETS: [&str; 10] = [
+    "aarch64-linux-android",
+    "armv7-linux-androideabi",
+    "x86_64-linux-android",
+    "i686-linux-android",
+    "aarch64-apple-ios",
+    "aarch64-apple-darwin",
+    "x86_64-apple-darwin",
+    "x86_64-pc-windows-msvc",
+    "x86_64-unknown-linux-gnu",
+    "wasm32-unknown-unknown",
+];
+
+pub fn generate(config: SbomConfig) -> anyhow::Result<()> {
+    let targets = if config.targets.is_empty() {
+        DEFAULT_TARGETS.iter().map(|x| x.to_string()).collect_vec()
+    } else {
+        config.targets
+    };
+
+    for target in &targets {
+        let metadata =
+            execute_cargo_metadata_for_target(&config.rust_crate_dir.join("Cargo.toml"), target)?;
+        let graph = SbomGraph::compute(&metadata, target)?;
+
+        let (content, extension) = match config.format {
+            SbomFormat::CycloneDx => (cyclonedx::generate(&graph), "cdx.json"),
+            SbomFormat::Spdx => (
+                spdx::generate(&graph, compute_creation_time()?),
+                "spdx.json",
+            ),
+        };
+        let path = (config.output_dir).join(format!("{}-{target}.{extension}", graph.root.name));
+        create_dir_all_and_write(&path, serde_json::to_string_pretty(&content)? + "\n")?;
+        info!(
+            "Write SBOM of {} packages for {target} to {path:?}",
+            graph.components.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// The packages bundled into the binary of one target
+struct SbomGraph {
+    target: String,
+    root: SbomComponent,
+    /// Excluding the root
+    components: Vec<SbomComponent>,
+    dependencies: Vec<SbomDependency>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct SbomComponent {
+    name: String,
+    version: String,
+    description: Option<String>,
+    license: Option<String>,
+    repository: Option<String>,
+    from_crates_io: bool,
+}
+
+struct SbomDependency {
+    purl: String,
+    depends_on: Vec<String>,
+}
+
+impl SbomGraph {
+    fn compute(metadata: &Metadata, target: &str) -> anyhow::Result<Self> {
+        let resolve =
+            (metadata.resolve.as_ref()).context("cargo metadata has no dependency graph")?;
+        let root_id = (resolve.root.as_ref())
+            .context("cannot find the root package, please point to the Rust crate of the app")?;
+        let packages: HashMap<&PackageId, &Package> =
+            (metadata.packages.iter()).map(|x| (&x.id, x)).collect();
+        let nodes: HashMap<_, _> = (resolve.nodes.iter()).map(|x| (&x.id, x)).collect();
+
+        let mut visited = BTreeSet::from([root_id]);
+        let mut queue = VecDeque::from([root_id]);
+        let mut dependencies = vec![];
+        while let Some(id) = queue.pop_front() {
+            let node = nodes
+                .get(id)
+                .context("cannot find node in dependency graph")?;
+            let deps = (node.deps.iter())
+                .filter(|dep| is_bundled(dep, packages[&dep.pkg]))
+                .map(|dep| &dep.pkg)
+                .sorted()
+                .dedup()
+                .collect_vec();
+            for &dep in &deps {
+                if visited.insert(dep) {
+                    queue.push_back(dep);
+                }
+            }
+            dependencies.push(SbomDependency {
+                purl: SbomComponent::new(packages[id]).purl(),
+                depends_on: (deps.iter())
+                    .map(|x| SbomComponent::new(packages[x]).purl())
+                    .collect(),
+            });
+        }
+
+        Ok(SbomGraph {
+            target: target.to_owned(),
+            root: SbomComponent::new(packages[root_id]),
+            components: (visited.into_iter())
+                .filter(|&x| x != root_id)
+                .map(|x| SbomComponent::new(packages[x]))
+                .sorted()
+                .collect(),
+            dependencies: dependencies
+                .into_iter()
+                .sorted_by(|a, b| a.purl.cmp(&b.purl))
+                .collect(),
+        })
+    }
+}
+
+// Build dependencies and procedural macros only run on the building machine,
+// thus they are not part of the app.
+fn is_bundled(dep: &NodeDep, package: &Package) -> bool {
+    let is_normal = (dep.dep_kinds.iter()).any(|x| x.kind == DependencyKind::Normal);
+    let is_proc_macro = (package.targets.iter()).any(|x| x.kind.iter().any(|k| k == "proc-macro"));
+    is_normal && !is_proc_macro
+}
+
+impl SbomComponent {
+    fn new(package: &Package) -> Self {
+        Self {
+            name: package.name.clone(),
+            version: package.version.to_string(),
+            description: package.description.clone(),
+            license: package.license.as_deref().map(normalize_license),
+            repository: package.repository.clone(),
+            from_crates_io: (package.source.as_ref())
+                .is_some_and(|x| x.repr.contains("crates.io-index")),
+        }
+    }
+
+    fn purl(&self) -> String {
+        format!("pkg:cargo/{}@{}", self.name, self.version)
+    }
+}
+
+/// Respects `SOURCE_DATE_EPOCH`, thus the output can be reproducible
+fn compute_creation_time() -> anyhow::Result<DateTime<Utc>> {
+    Ok(match env::var("SOURCE_DATE_EPOCH") {
+        Ok(raw) => Utc
+            .timestamp_opt(raw.parse()?, 0)
+            .single()
+            .context("invalid SOURCE_DATE_EPOCH")?,
+        Err(_) => Utc::now(),
+    })
+}
+
+/// Old crates may use e.g. `MIT/Apache-2.0`, which is not a valid SPDX expression
+fn normalize_license(raw: &str) -> String {
+    raw.split('/').map(|x| x.trim()).join(" OR ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compute_graph() -> anyhow::Result<()> {
+        let metadata: Metadata = serde_json::from_value(serde_json::json!({
+            "packages": [
+                package_json("app", "0.1.0", None),
+                package_json("serde", "1.0.0", Some("MIT/Apache-2.0")),
+                package_json("cc", "1.0.0", Some("MIT")),
+                package_json("tempfile", "3.0.0", Some("MIT")),
+            ],
+            "workspace_members": ["app 0.1.0"],
+            "resolve": {
+                "root": "app 0.1.0",
+                "nodes": [
+                    {
+                        "id": "app 0.1.0",
+                        "dependencies": [],
+                        "deps": [
+                            node_dep_json("serde 1.0.0", "normal"),
+                            node_dep_json("cc 1.0.0", "build"),
+                            node_dep_json("tempfile 3.0.0", "dev"),
+                        ],
+                    },
+                    {"id": "serde 1.0.0", "dependencies": [], "deps": []},
+                    {"id": "cc 1.0.0", "dependencies": [], "deps": []},
+                    {"id": "tempfile 3.0.0", "dependencies": [], "deps": []},
+                ],
+            },
+            "workspace_root": "/app",
+            "target_directory": "/app/target",
+            "version": 1,
+        }))?;
+
+        let graph = SbomGraph::compute(&metadata, "aarch64-linux-android")?;
+        assert_eq!(graph.root.name, "app");
+        assert_eq!(
+            (graph.components.iter()).map(|x| x.purl()).collect_vec(),
+            vec!["pkg:cargo/serde@1.0.0"]
+        );
+        assert_eq!(
+            graph.components[0].license.as_deref(),
+            Some("MIT OR Apache-2.0")
+        );
+        assert_eq!(
+            (graph.dependencies.iter())
+                .map(|x| (x.purl.as_str(), x.depends_on.clone()))
+                .collect_vec(),
+            vec![
+                (
+                    "pkg:cargo/app@0.1.0",
+                    vec!["pkg:cargo/serde@1.0.0".to_owned()]
+                ),
+                ("pkg:cargo/serde@1.0.0", vec![]),
+            ]
+        );
+
+        let cyclonedx = cyclonedx::generate(&graph);
+        assert_eq!(
+            cyclonedx["components"][0]["bom-ref"],
+            "pkg:cargo/serde@1.0.0"
+        );
+
+        let spdx = spdx::generate(&graph, Utc.timestamp_opt(0, 0).unwrap());
+        assert_eq!(spdx["creationInfo"]["created"], "1970-01-01T00:00:00Z");
+        assert_eq!(spdx["packages"].as_array().unwrap().len(), 2);
+
+        Ok(())
+    }
+
+    fn package_json(name: &str, version: &str, license: Option<&str>) -> serde_json::Value {
+        serde_json::json!({
+            "name": name,
+            "version": version,
+            "id": format!("{name} {version}"),
+            "license": license,
+            "dependencies": [],
+            "targets": [],
+            "features": {},
+            "manifest_path": format!("/{name}/Cargo.toml"),
+        })
+    }
+
+    fn node_dep_json(pkg: &str, kind: &str) -> serde_json::Value {
+        serde_json::json!({
+            "name": pkg.split(' ').next().unwrap(),
+            "pkg": pkg,
+            "dep_kinds": [{"kind": kind, "target": null}],
+        })
+    }
+}
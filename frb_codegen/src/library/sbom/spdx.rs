@@ -0,0 +1,92 @@
+use crate::library::sbom::{SbomComponent, SbomGraph};
+use chrono::{DateTime, SecondsFormat, Utc};
+use itertools::Itertools;
+use serde_json::{json, Value};
+
+/// See <https://spdx.github.io/spdx-spec/v2.3/>
+pub(super) fn generate(graph: &SbomGraph, created: DateTime<Utc>) -> Value {
+    let describes = json!({
+        "spdxElementId": "SPDXRef-DOCUMENT",
+        "relationshipType": "DESCRIBES",
+        "relatedSpdxElement": compute_spdx_id(&graph.root),
+    });
+    let depends_on = (graph.dependencies.iter()).flat_map(|dependency| {
+        (dependency.depends_on.iter()).map(|x| {
+            json!({
+                "spdxElementId": compute_spdx_id_from_purl(&dependency.purl),
+                "relationshipType": "DEPENDS_ON",
+                "relatedSpdxElement": compute_spdx_id_from_purl(x),
+            })
+        })
+    });
+
+    json!({
+        "spdxVersion": "SPDX-2.3",
+        "dataLicense": "CC0-1.0",
+        "SPDXID": "SPDXRef-DOCUMENT",
+        "name": format!("{}-{}", graph.root.name, graph.target),
+        "documentNamespace": format!(
+            "https://spdx.org/spdxdocs/{}-{}-{}",
+            graph.root.name, graph.root.version, graph.target,
+        ),
+        "creationInfo": {
+            "created": created.to_rfc3339_opts(SecondsFormat::Secs, true),
+            "creators": [format!("Tool: flutter_rust_bridge_codegen-{}", env!("CARGO_PKG_VERSION"))],
+        },
+        "packages": (std::iter::once(&graph.root).chain(&graph.components))
+            .map(generate_package)
+            .collect_vec(),
+        "relationships": std::iter::once(describes).chain(depends_on).collect_vec(),
+    })
+}
+
+fn generate_package(component: &SbomComponent) -> Value {
+    let download_location = if component.from_crates_io {
+        format!(
+            "https://crates.io/api/v1/crates/{}/{}/download",
+            component.name, component.version
+        )
+    } else {
+        "NOASSERTION".to_owned()
+    };
+
+    let mut ans = json!({
+        "SPDXID": compute_spdx_id(component),
+        "name": component.name,
+        "versionInfo": component.version,
+        "downloadLocation": download_location,
+        "licenseConcluded": "NOASSERTION",
+        "licenseDeclared": component.license.as_deref().unwrap_or("NOASSERTION"),
+        "copyrightText": "NOASSERTION",
+        "externalRefs": [{
+            "referenceCategory": "PACKAGE-MANAGER",
+            "referenceType": "purl",
+            "referenceLocator": component.purl(),
+        }],
+    });
+    if let Some(description) = &component.description {
+        ans["description"] = json!(description);
+    }
+    if let Some(repository) = &component.repository {
+        ans["homepage"] = json!(repository);
+    }
+    ans
+}
+
+fn compute_spdx_id(component: &SbomComponent) -> String {
+    compute_spdx_id_from_purl(&component.purl())
+}
+
+// SPDX ids only allow letters, numbers, `.` and `-`
+fn compute_spdx_id_from_purl(purl: &str) -> String {
+    let sanitized = (purl.trim_start_matches("pkg:cargo/").chars())
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>();
+    format!("SPDXRef-Package-{sanitized}")
+}
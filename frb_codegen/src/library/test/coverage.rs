@@ -0,0 +1,131 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+#[derive(Debug, Default)]
+struct SourceFileCoverage {
+    /// name -> (line, hits)
+    functions: BTreeMap<String, (String, u64)>,
+    /// line -> hits
+    lines: BTreeMap<u32, u64>,
+}
+
+/// Merges the lcov reports (e.g. of Rust and Dart), summing up the hits of the same line.
+/// Only the line and function coverage are kept, since there is no branch coverage for Dart.
+pub(super) fn merge_lcov(reports: &[String]) -> String {
+    let mut files = BTreeMap::<String, SourceFileCoverage>::new();
+
+    for report in reports {
+        let mut current = None;
+        for line in report.lines() {
+            let (key, value) = line.split_once(':').unwrap_or((line, ""));
+            match key {
+                "SF" => current = Some(files.entry(value.to_owned()).or_default()),
+                "end_of_record" => current = None,
+                _ => {
+                    let Some(file) = current.as_mut() else {
+                        continue;
+                    };
+                    match (key, value.split_once(',')) {
+                        ("FN", Some((line, name))) => {
+                            (file.functions.entry(name.to_owned()))
+                                .or_insert_with(|| (line.to_owned(), 0));
+                        }
+                        ("FNDA", Some((hits, name))) => {
+                            let entry = (file.functions.entry(name.to_owned()))
+                                .or_insert_with(|| (String::new(), 0));
+                            entry.1 += hits.parse::<u64>().unwrap_or_default();
+                        }
+                        ("DA", Some((line, rest))) => {
+                            // The optional checksum follows the hits
+                            let hits = rest.split(',').next().unwrap_or_default();
+                            if let Ok(line) = line.parse() {
+                                *file.lines.entry(line).or_default() +=
+                                    hits.parse::<u64>().unwrap_or_default();
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+
+    let mut ans = String::new();
+    for (path, file) in files {
+        writeln!(ans, "SF:{path}").unwrap();
+        for (name, (line, _)) in &file.functions {
+            writeln!(ans, "FN:{line},{name}").unwrap();
+        }
+        for (name, (_, hits)) in &file.functions {
+            writeln!(ans, "FNDA:{hits},{name}").unwrap();
+        }
+        if !file.functions.is_empty() {
+            writeln!(ans, "FNF:{}", file.functions.len()).unwrap();
+            let num_hit = file
+                .functions
+                .values()
+                .filter(|(_, hits)| *hits > 0)
+                .count();
+            writeln!(ans, "FNH:{num_hit}").unwrap();
+        }
+        for (line, hits) in &file.lines {
+            writeln!(ans, "DA:{line},{hits}").unwrap();
+        }
+        writeln!(ans, "LF:{}", file.lines.len()).unwrap();
+        let num_hit = file.lines.values().filter(|&&hits| hits > 0).count();
+        writeln!(ans, "LH:{num_hit}").unwrap();
+        ans += "end_of_record\n";
+    }
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_lcov() {
+        let rust = "SF:/app/rust/src/api.rs
+FN:3,add
+FNDA:2,add
+FNF:1
+FNH:1
+DA:3,2
+DA:4,0
+BRDA:4,0,0,1
+LF:2
+LH:1
+end_of_record
+";
+        let dart_native = "SF:/app/lib/main.dart
+DA:1,1
+DA:2,0
+LF:2
+LH:1
+end_of_record
+SF:/app/rust/src/api.rs
+DA:4,5
+end_of_record
+";
+        assert_eq!(
+            merge_lcov(&[rust.to_owned(), dart_native.to_owned()]),
+            "SF:/app/lib/main.dart
+DA:1,1
+DA:2,0
+LF:2
+LH:1
+end_of_record
+SF:/app/rust/src/api.rs
+FN:3,add
+FNDA:2,add
+FNF:1
+FNH:1
+DA:3,2
+DA:4,5
+LF:2
+LH:2
+end_of_record
+"
+        );
+    }
+}
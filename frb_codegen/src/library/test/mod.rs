@@ -0,0 +1,350 @@
+//! Run the Rust tests, and the Dart tests of a Flutter+Rust app against both the native library and the WASM build
+
+mod coverage;
+mod report;
+
+use crate::library::commands::command_runner::{call_shell, check_exit_code};
+use crate::library::test::coverage::merge_lcov;
+use crate::library::test::report::{generate_combined_report, TestOutcome, TestReport};
+use crate::utils::dart_coverage::{dart_run, format_lcov, DartCoverageConfig};
+use crate::utils::dart_repository::dart_repo::DartRepository;
+use crate::utils::dart_repository::dart_toolchain::DartToolchain;
+use crate::utils::message_format::print_human;
+use crate::utils::path_utils::{find_dart_package_dir, path_to_string};
+use crate::{build_web, command_args, command_run};
+use anyhow::{bail, Context};
+use log::{info, warn};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::{Output, Stdio};
+use std::{env, fs};
+use strum_macros::Display;
+
+pub struct TestConfig {
+    pub dart_root: Option<PathBuf>,
+    /// Relative to `dart_root`
+    pub rust_root: PathBuf,
+    pub platforms: Vec<TestPlatform>,
+    /// Only run the tests whose names match this regular expression (or contain this text for the Rust tests)
+    pub name: Option<String>,
+    /// Test files or directories, or empty to run all tests
+    pub paths: Vec<String>,
+    pub coverage: Option<TestCoverageConfig>,
+}
+
+pub struct TestCoverageConfig {
+    /// Where the merged report (in lcov format) is written to, relative to `dart_root`
+    pub output: PathBuf,
+    /// Port of the VM service to collect the Dart coverage from, when not using Flutter
+    pub vm_service_port: u16,
+    /// Packages to collect the Dart coverage of, or the Dart package itself if empty
+    pub scope: Vec<String>,
+}
+
+/// Please refer to `TestPlatformArg` for doc
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum TestPlatform {
+    Rust,
+    Native,
+    Web,
+}
+
+pub fn test(config: TestConfig) -> anyhow::Result<()> {
+    let dart_root = match config.dart_root {
+        Some(dart_root) => fs::canonicalize(dart_root)?,
+        None => find_dart_package_dir(&env::current_dir()?)
+            .context("Please provide --dart-root, or run command inside a Flutter/Dart package")?,
+    };
+    let repo = DartRepository::from_path(&dart_root)?;
+    let rust_root = dart_root.join(&config.rust_root);
+
+    let coverage_dir = rust_root.join("target").join("frb_coverage");
+    if config.coverage.is_some() {
+        fs::create_dir_all(&coverage_dir)?;
+    }
+
+    let mut reports = vec![];
+    let mut lcov_paths = vec![];
+    for &platform in &config.platforms {
+        info!("Run the tests on {platform}");
+        // The Dart code is the same across platforms, thus its coverage is only collected once
+        let lcov_path = (config.coverage.is_some() && platform != TestPlatform::Web)
+            .then(|| coverage_dir.join(format!("{platform}.lcov.info")));
+        let dart_coverage = (config.coverage.as_ref()).zip(lcov_path.as_deref());
+        let report = match platform {
+            TestPlatform::Rust => run_rust_test(&rust_root, &config.name, lcov_path.as_deref())?,
+            TestPlatform::Native => {
+                build_native(&rust_root)?;
+                run_dart_test(&repo, platform, &config.name, &config.paths, dart_coverage)?
+            }
+            TestPlatform::Web => {
+                let _pkg_link = build_web(&dart_root, &config.rust_root)?;
+                run_dart_test(&repo, platform, &config.name, &config.paths, dart_coverage)?
+            }
+        };
+        reports.push((platform, report));
+        lcov_paths.extend(lcov_path);
+    }
+
+    let combined_report = generate_combined_report(&reports);
+    print_human(&combined_report);
+
+    if let Some(coverage) = &config.coverage {
+        let lcov_reports = (lcov_paths.iter())
+            .map(fs::read_to_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        let output = dart_root.join(&coverage.output);
+        if let Some(parent) = output.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&output, merge_lcov(&lcov_reports))?;
+        print_human(&format!("Coverage report: {}", output.display()));
+    }
+
+    let num_failed: usize = (reports.iter())
+        .map(|(_, report)| report.count(TestOutcome::Failed))
+        .sum();
+    if num_failed > 0 {
+        bail!("{num_failed} test(s) failed");
+    }
+    Ok(())
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn run_rust_test(
+    rust_root: &Path,
+    name: &Option<String>,
+    lcov_path: Option<&Path>,
+) -> anyhow::Result<TestReport> {
+    let res = match lcov_path {
+        Some(lcov_path) => command_run!(
+            call_shell[Some(rust_root), None],
+            "cargo",
+            "llvm-cov",
+            "--lcov",
+            "--output-path",
+            path_to_string(lcov_path)?,
+            // Still generate the report when some tests fail, which are then shown in the test report
+            "--ignore-run-fail",
+            ?name,
+        )?,
+        None => command_run!(call_shell[Some(rust_root), None], "cargo", "test", ?name)?,
+    };
+    let report = TestReport::parse_cargo_test(&String::from_utf8_lossy(&res.stdout));
+
+    if !res.status.success() && report.count(TestOutcome::Failed) == 0 {
+        bail!(
+            "Fail to run the Rust tests: {}",
+            String::from_utf8_lossy(&res.stderr)
+        );
+    }
+    Ok(report)
+}
+
+// The default external library loader looks for the library in `target/release`
+#[allow(clippy::vec_init_then_push)]
+fn build_native(rust_root: &Path) -> anyhow::Result<()> {
+    check_exit_code(&command_run!(
+        call_shell[Some(rust_root), None],
+        "cargo",
+        "build",
+        "--release",
+    )?)
+}
+
+// The build is kept in `target` instead of the Dart package, and the returned guard temporarily
+// links it as `test/pkg`, since `dart test` serves the `test` folder and the default
+// external library loader looks for `pkg/` relative to the test page
+fn build_web(dart_root: &Path, rust_root: &Path) -> anyhow::Result<WebPkgLink> {
+    let output = dart_root
+        .join(rust_root)
+        .join("target")
+        .join("frb_test_web");
+    build_web::build(
+        Some(dart_root.to_owned()),
+        None,
+        vec![
+            "--rust-root".to_owned(),
+            path_to_string(rust_root)?,
+            "--output".to_owned(),
+            path_to_string(&output)?,
+        ],
+    )?;
+    WebPkgLink::create(&output.join("pkg"), &dart_root.join("test").join("pkg"))
+}
+
+/// Removes the link when dropped, even if running the tests fails
+struct WebPkgLink {
+    link: PathBuf,
+}
+
+impl WebPkgLink {
+    fn create(target: &Path, link: &Path) -> anyhow::Result<Self> {
+        if fs::symlink_metadata(link).is_ok() {
+            if !fs::read_link(link).is_ok_and(|x| x == target) {
+                bail!(
+                    "{link:?} already exists, please remove it since the WASM build is linked there while testing"
+                );
+            }
+            // Left over by an interrupted run
+            remove_dir_link(link)?;
+        }
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(target, link)?;
+        #[cfg(windows)]
+        std::os::windows::fs::symlink_dir(target, link)?;
+        Ok(Self {
+            link: link.to_owned(),
+        })
+    }
+}
+
+impl Drop for WebPkgLink {
+    fn drop(&mut self) {
+        if let Err(e) = remove_dir_link(&self.link) {
+            warn!("Fail to remove {:?}: {e:?}", self.link);
+        }
+    }
+}
+
+// A directory symlink is removed via `remove_dir` on Windows, but via `remove_file` elsewhere
+fn remove_dir_link(link: &Path) -> std::io::Result<()> {
+    if cfg!(windows) {
+        fs::remove_dir(link)
+    } else {
+        fs::remove_file(link)
+    }
+}
+
+#[allow(clippy::vec_init_then_push)]
+fn run_dart_test(
+    repo: &DartRepository,
+    platform: TestPlatform,
+    name: &Option<String>,
+    paths: &[String],
+    coverage: Option<(&TestCoverageConfig, &Path)>,
+) -> anyhow::Result<TestReport> {
+    let platform_args = match platform {
+        TestPlatform::Web => vec!["--platform", "chrome"],
+        _ => vec![],
+    };
+    let test_args = command_args!(
+        "test",
+        *platform_args,
+        "--reporter",
+        "json",
+        ?name.as_ref().map(|_| "--name"),
+        ?name,
+        *paths,
+    );
+
+    let res = match (&repo.toolchain, coverage) {
+        (DartToolchain::Dart, Some((coverage, lcov_path))) => {
+            run_dart_test_with_coverage(repo, test_args, coverage, lcov_path)?
+        }
+        (DartToolchain::Dart, None) => command_run!(
+            call_shell[Some(&repo.at), None],
+            "dart",
+            *repo.command_extra_args(),
+            *test_args,
+        )?,
+        (DartToolchain::Flutter, _) => command_run!(
+            call_shell[Some(&repo.at), None],
+            "flutter",
+            *repo.command_extra_args(),
+            *test_args,
+            ?coverage.map(|_| "--coverage"),
+            ?coverage.map(|_| "--coverage-path"),
+            ?coverage.map(|(_, lcov_path)| lcov_path),
+            *coverage_packages(repo, coverage.map(|(coverage, _)| coverage)),
+        )?,
+    };
+    let report = TestReport::parse(&String::from_utf8_lossy(&res.stdout));
+
+    // e.g. compilation errors, which happen before any test runs
+    if !res.status.success() && report.count(TestOutcome::Failed) == 0 {
+        bail!(
+            "Fail to run the tests on {platform}: {}",
+            String::from_utf8_lossy(&res.stderr)
+        );
+    }
+    Ok(report)
+}
+
+// `flutter test --coverage` collects the coverage by itself, while `dart test` needs the VM service
+fn run_dart_test_with_coverage(
+    repo: &DartRepository,
+    test_args: Vec<PathBuf>,
+    coverage: &TestCoverageConfig,
+    lcov_path: &Path,
+) -> anyhow::Result<Output> {
+    let json_path = lcov_path.with_extension("json");
+    // The stdout is redirected to a file instead of a pipe, since the tests would block when the pipe
+    // is full, while the coverage is only collected after they finish
+    let stdout_path = lcov_path.with_extension("stdout");
+
+    let mut res = dart_run(
+        repo,
+        &repo.at,
+        (test_args.iter())
+            .map(|x| x.to_string_lossy().into_owned())
+            .collect(),
+        Some(&DartCoverageConfig {
+            vm_service_port: coverage.vm_service_port,
+            scope: coverage_scope(repo, coverage),
+            output: json_path.clone(),
+        }),
+        Stdio::from(File::create(&stdout_path)?),
+    )?;
+    res.stdout = fs::read(&stdout_path)?;
+
+    // `package:` URIs are resolved via `.dart_tool`, which is at the root for pub workspaces
+    format_lcov(repo.resolution_dir(), &json_path, lcov_path)?;
+    Ok(res)
+}
+
+fn coverage_packages(repo: &DartRepository, coverage: Option<&TestCoverageConfig>) -> Vec<String> {
+    let Some(coverage) = coverage else {
+        return vec![];
+    };
+    (coverage_scope(repo, coverage).into_iter())
+        .flat_map(|package| ["--coverage-package".to_owned(), format!("^{package}$")])
+        .collect()
+}
+
+fn coverage_scope(repo: &DartRepository, coverage: &TestCoverageConfig) -> Vec<String> {
+    if coverage.scope.is_empty() {
+        repo.name.iter().cloned().collect()
+    } else {
+        coverage.scope.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_web_pkg_link() -> anyhow::Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let target = temp_dir.path().join("target").join("pkg");
+        let link = temp_dir.path().join("test").join("pkg");
+        fs::create_dir_all(&target)?;
+        fs::create_dir_all(link.parent().unwrap())?;
+        fs::write(target.join("a.wasm"), "content")?;
+
+        let guard = WebPkgLink::create(&target, &link)?;
+        assert_eq!(fs::read_to_string(link.join("a.wasm"))?, "content");
+        // e.g. left over by an interrupted run
+        std::mem::forget(guard);
+        let guard = WebPkgLink::create(&target, &link)?;
+        drop(guard);
+        assert!(fs::symlink_metadata(&link).is_err());
+        assert!(target.join("a.wasm").exists());
+
+        fs::create_dir(&link)?;
+        assert!(WebPkgLink::create(&target, &link).is_err());
+        Ok(())
+    }
+}
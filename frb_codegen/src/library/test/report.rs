@@ -0,0 +1,256 @@
+use crate::library::test::TestPlatform;
+use itertools::Itertools;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TestOutcome {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct TestReport {
+    /// `<test file> <test name>` -> outcome
+    pub(super) outcomes: BTreeMap<String, TestOutcome>,
+    pub(super) errors: BTreeMap<String, String>,
+}
+
+impl TestReport {
+    /// Parses the output of `dart test --reporter json` (or `flutter test`),
+    /// see <https://github.com/dart-lang/test/blob/master/pkgs/test/doc/json_reporter.md>
+    pub(super) fn parse(stdout: &str) -> Self {
+        let mut suite_paths = HashMap::new();
+        let mut test_names = HashMap::new();
+        let mut ans = Self::default();
+
+        // Other lines, such as the ones printed by the tests, are not events
+        for event in stdout
+            .lines()
+            .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        {
+            match event["type"].as_str() {
+                Some("suite") => {
+                    let suite = &event["suite"];
+                    suite_paths.insert(
+                        suite["id"].as_i64(),
+                        suite["path"].as_str().unwrap_or_default().to_owned(),
+                    );
+                }
+                Some("testStart") => {
+                    let test = &event["test"];
+                    let path = suite_paths.get(&test["suiteID"].as_i64());
+                    let name = test["name"].as_str().unwrap_or_default();
+                    test_names.insert(
+                        test["id"].as_i64(),
+                        format!("{} {name}", path.map(|x| x.as_str()).unwrap_or_default()),
+                    );
+                }
+                Some("error") => {
+                    if let Some(name) = test_names.get(&event["testID"].as_i64()) {
+                        (ans.errors.entry(name.clone())).or_insert_with(|| {
+                            event["error"].as_str().unwrap_or_default().to_owned()
+                        });
+                    }
+                }
+                Some("testDone") => {
+                    // Hidden tests are e.g. the implicit `loading` tests which succeeded
+                    if event["hidden"].as_bool() == Some(true) {
+                        continue;
+                    }
+                    let Some(name) = test_names.get(&event["testID"].as_i64()) else {
+                        continue;
+                    };
+                    let outcome = if event["skipped"].as_bool() == Some(true) {
+                        TestOutcome::Skipped
+                    } else if event["result"].as_str() == Some("success") {
+                        TestOutcome::Passed
+                    } else {
+                        TestOutcome::Failed
+                    };
+                    ans.outcomes.insert(name.clone(), outcome);
+                }
+                _ => {}
+            }
+        }
+
+        ans
+    }
+
+    /// Parses the human-readable output of `cargo test`
+    pub(super) fn parse_cargo_test(stdout: &str) -> Self {
+        let mut ans = Self::default();
+        let mut failure_name: Option<&str> = None;
+
+        for line in stdout.lines() {
+            // e.g. `test api::tests::test_add ... ok`
+            if let Some((name, result)) =
+                (line.strip_prefix("test ")).and_then(|line| line.split_once(" ... "))
+            {
+                let outcome = match result {
+                    "ok" => TestOutcome::Passed,
+                    "FAILED" => TestOutcome::Failed,
+                    _ if result.starts_with("ignored") => TestOutcome::Skipped,
+                    _ => continue,
+                };
+                ans.outcomes.insert(name.to_owned(), outcome);
+            } else if let Some(name) =
+                (line.strip_prefix("---- ")).and_then(|line| line.strip_suffix(" stdout ----"))
+            {
+                failure_name = Some(name);
+            } else if let Some(name) = failure_name {
+                // The panic message, which is on its own line after `thread '..' panicked at ..:`
+                let is_panic_location = line.starts_with("thread '") && line.ends_with(':');
+                if !line.is_empty() && !is_panic_location {
+                    ans.errors.insert(name.to_owned(), line.to_owned());
+                    failure_name = None;
+                }
+            }
+        }
+
+        ans
+    }
+
+    pub(super) fn count(&self, outcome: TestOutcome) -> usize {
+        self.outcomes.values().filter(|&&x| x == outcome).count()
+    }
+}
+
+pub(super) fn generate_combined_report(reports: &[(TestPlatform, TestReport)]) -> String {
+    let mut ans = "Test results:\n".to_owned();
+    for (platform, report) in reports {
+        writeln!(
+            ans,
+            "  {platform:<6}: {} passed, {} failed, {} skipped",
+            report.count(TestOutcome::Passed),
+            report.count(TestOutcome::Failed),
+            report.count(TestOutcome::Skipped),
+        )
+        .unwrap();
+    }
+
+    // The Rust tests are different from the Dart ones, thus not compared
+    let dart_reports = (reports.iter())
+        .filter(|(platform, _)| *platform != TestPlatform::Rust)
+        .collect_vec();
+    if dart_reports.len() > 1 {
+        let names = (dart_reports.iter())
+            .flat_map(|(_, report)| report.outcomes.keys())
+            .collect::<BTreeSet<_>>();
+        let divergent = (names.into_iter())
+            .filter_map(|name| {
+                let outcomes = (dart_reports.iter())
+                    .map(|(platform, report)| (platform, report.outcomes.get(name)))
+                    .collect_vec();
+                let all_same = outcomes.iter().map(|(_, x)| x).all_equal();
+                (!all_same).then(|| {
+                    let description = (outcomes.iter())
+                        .map(|(platform, outcome)| match outcome {
+                            Some(outcome) => format!("{platform} {outcome:?}").to_lowercase(),
+                            None => format!("{platform} not run"),
+                        })
+                        .join(", ");
+                    format!("  {name} ({description})\n")
+                })
+            })
+            .collect_vec();
+        if !divergent.is_empty() {
+            ans += "\nTests behaving differently across platforms:\n";
+            ans += &divergent.join("");
+        }
+    }
+
+    let failures = (reports.iter())
+        .flat_map(|(platform, report)| {
+            (report.outcomes.iter())
+                .filter(|(_, &outcome)| outcome == TestOutcome::Failed)
+                .map(move |(name, _)| {
+                    let error = report
+                        .errors
+                        .get(name)
+                        .map(|x| x.lines().next().unwrap_or_default());
+                    format!("  [{platform}] {name}\n    {}\n", error.unwrap_or_default())
+                })
+        })
+        .collect_vec();
+    if !failures.is_empty() {
+        ans += "\nFailed tests:\n";
+        ans += &failures.join("");
+    }
+
+    ans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_combined_report() {
+        let native = TestReport::parse(
+            r#"{"type":"suite","suite":{"id":0,"platform":"vm","path":"test/a_test.dart"}}
+{"type":"testStart","test":{"id":1,"name":"loading test/a_test.dart","suiteID":0}}
+{"type":"testDone","testID":1,"result":"success","skipped":false,"hidden":true}
+{"type":"testStart","test":{"id":2,"name":"add","suiteID":0}}
+{"type":"testDone","testID":2,"result":"success","skipped":false,"hidden":false}
+some text printed by the tests
+{"type":"testStart","test":{"id":3,"name":"big int","suiteID":0}}
+{"type":"testDone","testID":3,"result":"success","skipped":false,"hidden":false}
+{"type":"done","success":true}"#,
+        );
+        let web = TestReport::parse(
+            r#"{"type":"suite","suite":{"id":0,"platform":"chrome","path":"test/a_test.dart"}}
+{"type":"testStart","test":{"id":1,"name":"add","suiteID":0}}
+{"type":"testDone","testID":1,"result":"success","skipped":false,"hidden":false}
+{"type":"testStart","test":{"id":2,"name":"big int","suiteID":0}}
+{"type":"error","testID":2,"error":"Expected: <1>\n  Actual: <2>","stackTrace":"","isFailure":true}
+{"type":"testDone","testID":2,"result":"failure","skipped":false,"hidden":false}
+{"type":"done","success":false}"#,
+        );
+
+        let rust = TestReport::parse_cargo_test(
+            "
+running 2 tests
+test api::tests::test_add ... ok
+test api::tests::test_div ... FAILED
+
+failures:
+
+---- api::tests::test_div stdout ----
+thread 'api::tests::test_div' panicked at src/api.rs:10:5:
+attempt to divide by zero
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    api::tests::test_div
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+",
+        );
+
+        assert_eq!(
+            generate_combined_report(&[
+                (TestPlatform::Rust, rust),
+                (TestPlatform::Native, native),
+                (TestPlatform::Web, web)
+            ]),
+            "Test results:
+  rust  : 1 passed, 1 failed, 0 skipped
+  native: 2 passed, 0 failed, 0 skipped
+  web   : 1 passed, 1 failed, 0 skipped
+
+Tests behaving differently across platforms:
+  test/a_test.dart big int (native passed, web failed)
+
+Failed tests:
+  [rust] api::tests::test_div
+    attempt to divide by zero
+  [web] test/a_test.dart big int
+    Expected: <1>
+"
+        );
+    }
+}
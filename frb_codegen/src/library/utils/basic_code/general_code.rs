@@ -8,6 +8,7 @@ pub(crate) enum GeneralCode {
     Dart(GeneralDartCode),
     Rust(GeneralRustCode),
     C(GeneralCCode),
+    Text(GeneralTextCode),
 }
 
 #[derive(Default, Clone, Debug, Serialize)]
@@ -26,12 +27,18 @@ pub(crate) struct GeneralCCode {
     pub body: String,
 }
 
+#[derive(Default, Clone, Debug, Serialize)]
+pub(crate) struct GeneralTextCode {
+    pub body: String,
+}
+
 impl GeneralCode {
     pub(crate) fn all_code(&self) -> String {
         match self {
             GeneralCode::Dart(inner) => inner.all_code(),
             GeneralCode::Rust(inner) => inner.all_code(),
             GeneralCode::C(inner) => inner.all_code(),
+            GeneralCode::Text(inner) => inner.all_code(),
         }
     }
 
@@ -43,6 +50,10 @@ impl GeneralCode {
         GeneralCode::C(GeneralCCode { body })
     }
 
+    pub(crate) fn new_text(body: String) -> GeneralCode {
+        GeneralCode::Text(GeneralTextCode { body })
+    }
+
     pub(crate) fn dart(self) -> GeneralDartCode {
         if let Self::Dart(inner) = self {
             inner
@@ -72,6 +83,16 @@ impl GeneralCode {
             // frb-coverage:ignore-end
         }
     }
+
+    pub(crate) fn text(self) -> GeneralTextCode {
+        if let Self::Text(inner) = self {
+            inner
+        } else {
+            // frb-coverage:ignore-start
+            panic!()
+            // frb-coverage:ignore-end
+        }
+    }
 }
 
 impl GeneralDartCode {
@@ -92,6 +113,12 @@ impl GeneralCCode {
     }
 }
 
+impl GeneralTextCode {
+    pub(crate) fn all_code(&self) -> String {
+        self.body.clone()
+    }
+}
+
 impl AddAssign for GeneralCode {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -99,6 +126,7 @@ impl AddAssign for GeneralCode {
             GeneralCode::Dart(inner) => inner.add_assign(rhs.dart()),
             GeneralCode::Rust(inner) => inner.add_assign(rhs.rust()),
             GeneralCode::C(inner) => inner.add_assign(rhs.c()),
+            GeneralCode::Text(inner) => inner.add_assign(rhs.text()),
         }
     }
 }
@@ -125,10 +153,18 @@ impl AddAssign for GeneralCCode {
     }
 }
 
+impl AddAssign for GeneralTextCode {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.body += &rhs.body;
+    }
+}
+
 impl_add_by_add_assign!(GeneralCode);
 simple_code_trait_impl!(GeneralDartCode);
 simple_code_trait_impl!(GeneralRustCode);
 simple_code_trait_impl!(GeneralCCode);
+simple_code_trait_impl!(GeneralTextCode);
 
 #[cfg(test)]
 mod tests {
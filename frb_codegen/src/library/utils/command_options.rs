@@ -0,0 +1,56 @@
+//! Options about how external commands (e.g. `cargo`, `dart`, `flutter`) are executed
+
+use lazy_static::lazy_static;
+use std::sync::RwLock;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default)]
+pub struct CommandOptions {
+    /// Print the output of the commands while they are running,
+    /// instead of only logging it after they finish.
+    pub stream_output: bool,
+    /// Kill a command and report an error if it does not finish in time.
+    pub timeout: Option<Duration>,
+    /// How commands such as `dart` or `cargo` are launched.
+    pub shell: ShellMode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ShellMode {
+    /// Execute the program directly, thus the arguments are passed as-is without any quoting
+    #[default]
+    Direct,
+    /// `sh -c`
+    Sh,
+    /// `powershell -command`
+    Powershell,
+    /// `nu -c`
+    Nushell,
+    /// `fish -c`
+    Fish,
+}
+
+lazy_static! {
+    static ref COMMAND_OPTIONS: RwLock<CommandOptions> = RwLock::new(Default::default());
+}
+
+/// Configure the options used by all commands executed afterwards.
+///
+/// # Example
+///
+/// ```
+/// use lib_flutter_rust_bridge_codegen::utils::command_options::{configure_command_options, CommandOptions, ShellMode};
+/// use std::time::Duration;
+/// configure_command_options(CommandOptions {
+///     stream_output: true,
+///     timeout: Some(Duration::from_secs(600)),
+///     shell: ShellMode::Direct,
+/// });
+/// ```
+pub fn configure_command_options(options: CommandOptions) {
+    *COMMAND_OPTIONS.write().unwrap() = options;
+}
+
+pub(crate) fn command_options() -> CommandOptions {
+    COMMAND_OPTIONS.read().unwrap().clone()
+}
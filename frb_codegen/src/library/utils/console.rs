@@ -1,3 +1,4 @@
+use crate::utils::message_format::{emit, Event};
 use indicatif::{MultiProgress, ProgressBar, ProgressState, ProgressStyle};
 use lazy_static::lazy_static;
 use std::fmt::Write;
@@ -5,7 +6,7 @@ use std::sync::Mutex;
 use std::time::Duration;
 
 lazy_static! {
-    pub(super) static ref MULTI_PROGRESS: MultiProgress = MultiProgress::new();
+    pub(crate) static ref MULTI_PROGRESS: MultiProgress = MultiProgress::new();
 }
 
 pub(crate) struct SimpleProgressBar {
@@ -26,21 +27,34 @@ impl SimpleProgressBar {
     pub(crate) fn start(&self) -> SimpleProgressBarHandle {
         let mut active_pb = self.active_pb.lock().unwrap();
         if active_pb.is_none() {
+            emit(&Event::StageStarted {
+                stage: &self.message,
+                level: self.level,
+            });
             *active_pb = Some(create_simple_progress_bar(self.message.clone(), self.level));
         }
         SimpleProgressBarHandle {
             pb: active_pb.as_ref().unwrap().to_owned(),
+            message: self.message.clone(),
+            level: self.level,
         }
     }
 }
 
 pub(crate) struct SimpleProgressBarHandle {
     pb: ProgressBar,
+    message: String,
+    level: usize,
 }
 
 impl Drop for SimpleProgressBarHandle {
     fn drop(&mut self) {
-        self.pb.finish()
+        self.pb.finish();
+        emit(&Event::StageFinished {
+            stage: &self.message,
+            level: self.level,
+            elapsed_ms: self.pb.elapsed().as_millis(),
+        });
     }
 }
 
@@ -0,0 +1,89 @@
+//! Collect the coverage of Dart code via the VM service, see <https://pub.dev/packages/coverage>
+
+use crate::command_run;
+use crate::library::commands::command_runner::{call_shell, call_shell_info, check_exit_code};
+use crate::utils::dart_repository::dart_repo::DartRepository;
+use crate::utils::path_utils::path_to_string;
+use itertools::Itertools;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+
+pub struct DartCoverageConfig {
+    /// Port of the VM service to collect the coverage from
+    pub vm_service_port: u16,
+    /// Only collect the coverage of these packages, or all packages if empty
+    pub scope: Vec<String>,
+    /// Where the collected coverage (in JSON) is written to
+    pub output: PathBuf,
+}
+
+/// Executes `dart run <args>`, and collects the coverage after the program finishes if `coverage` is given
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn dart_run(
+    repo: &DartRepository,
+    current_dir: &Path,
+    args: Vec<String>,
+    coverage: Option<&DartCoverageConfig>,
+    stdout: Stdio,
+) -> anyhow::Result<Output> {
+    let handle = {
+        let mut cmd_args: Vec<PathBuf> = vec!["dart".into()];
+        cmd_args.extend(repo.command_extra_args().into_iter().map_into());
+        cmd_args.push("run".into());
+        if let Some(coverage) = coverage {
+            cmd_args.extend([
+                "--pause-isolates-on-exit".into(),
+                "--disable-service-auth-codes".into(),
+                format!("--enable-vm-service={}", coverage.vm_service_port).into(),
+            ]);
+        }
+        cmd_args.extend(args.into_iter().map_into());
+
+        let info = call_shell_info(&cmd_args);
+        Command::new(info.program)
+            .args(info.args)
+            .current_dir(current_dir)
+            .stdout(stdout)
+            .spawn()?
+    };
+
+    if let Some(coverage) = coverage {
+        let res = command_run!(
+            call_shell[Some(current_dir), None],
+            "dart",
+            "pub",
+            "global",
+            "run",
+            "coverage:collect_coverage",
+            "--wait-paused",
+            format!("--uri=http://127.0.0.1:{}/", coverage.vm_service_port),
+            "-o",
+            path_to_string(&coverage.output)?,
+            "--resume-isolates",
+            *coverage.scope.iter().map(|x| format!("--scope-output={x}")).collect_vec(),
+        )?;
+        check_exit_code(&res)?;
+    }
+
+    Ok(handle.wait_with_output()?)
+}
+
+/// Converts the coverage collected by [`dart_run`] into lcov format
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn format_lcov(dart_root: &Path, input: &Path, output: &Path) -> anyhow::Result<()> {
+    check_exit_code(&command_run!(
+        call_shell[Some(dart_root), None],
+        "dart",
+        "pub",
+        "global",
+        "run",
+        "coverage:format_coverage",
+        "--lcov",
+        "--in",
+        path_to_string(input)?,
+        "--out",
+        path_to_string(output)?,
+        "--package",
+        path_to_string(dart_root)?,
+    )?)
+}
@@ -1,9 +1,11 @@
 use crate::utils::dart_repository::dart_toolchain::DartToolchain;
 use crate::utils::dart_repository::pubspec::*;
+use crate::utils::dart_repository::workspace::{DartWorkspace, DartWorkspaceKind};
 use anyhow::{anyhow, bail, Context};
 use cargo_metadata::{Version, VersionReq};
 use log::debug;
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt::Display;
@@ -13,7 +15,10 @@ use std::path::{Path, PathBuf};
 /// represents a dart / flutter repository
 pub(crate) struct DartRepository {
     pub(crate) at: PathBuf,
+    /// The package name in `pubspec.yaml`
+    pub(crate) name: Option<String>,
     pub(crate) toolchain: DartToolchain,
+    pub(crate) workspace: Option<DartWorkspace>,
 }
 
 impl DartRepository {
@@ -34,10 +39,20 @@ impl DartRepository {
 
         Ok(DartRepository {
             at: path.to_owned(),
+            name: manifest_file.name,
             toolchain,
+            workspace: DartWorkspace::detect(path)?,
         })
     }
 
+    /// Where `pubspec.lock` and `.dart_tool` are, which is the workspace root for pub workspaces
+    pub(crate) fn resolution_dir(&self) -> &Path {
+        match &self.workspace {
+            Some(workspace) if workspace.kind == DartWorkspaceKind::Pub => &workspace.root,
+            _ => &self.at,
+        }
+    }
+
     /// check whether the toolchain is available from the CLI
     pub(crate) fn toolchain_available(&self) -> bool {
         self.toolchain.available()
@@ -88,7 +103,7 @@ impl DartRepository {
         manager: DartDependencyMode,
         requirement: &VersionReq,
     ) -> anyhow::Result<()> {
-        let at = &self.at;
+        let at = self.resolution_dir();
         let filename = DartToolchain::lock_filename();
         debug!("Checking presence of {package} in {manager} at {at:?}");
 
@@ -140,7 +155,11 @@ impl DartRepository {
     }
 
     pub(crate) fn command_extra_args(&self) -> Vec<String> {
-        if self.at.join("build.dart").exists() {
+        // The packages of a pub workspace are run together, thus the hook may be at the root as well
+        if [self.at.as_path(), self.resolution_dir()]
+            .iter()
+            .any(|dir| dir.join("build.dart").exists())
+        {
             vec!["--enable-experiment=native-assets".to_owned()]
         } else {
             vec![]
@@ -209,6 +228,38 @@ impl Display for DartPackageVersion {
     }
 }
 
+#[derive(Debug, Deserialize)]
+struct AnalysisOptionsYaml {
+    formatter: Option<AnalysisOptionsFormatter>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnalysisOptionsFormatter {
+    page_width: Option<u32>,
+}
+
+/// `formatter.page_width` in `analysis_options.yaml` of the package, which `dart format` respects since Dart 3.7
+///
+/// The file is owned by the user and only consulted for a formatting preference,
+/// so a malformed one is reported and ignored instead of failing the generation.
+pub(crate) fn read_formatter_page_width(dart_root: &Path) -> Option<u32> {
+    const FILENAME: &str = "analysis_options.yaml";
+    if !dart_root.join(FILENAME).exists() {
+        return None;
+    }
+    let analysis_options: Option<AnalysisOptionsYaml> =
+        match read_file_and_parse_yaml(dart_root, FILENAME) {
+            Ok(analysis_options) => analysis_options,
+            Err(e) => {
+                log::warn!("Ignore {FILENAME} when detecting the Dart page width: {e:#}");
+                return None;
+            }
+        };
+    analysis_options
+        .and_then(|x| x.formatter)
+        .and_then(|x| x.page_width)
+}
+
 fn read_file(at: &Path, filename: &str) -> anyhow::Result<String> {
     let file = at.join(filename);
     if !file.exists() {
@@ -254,6 +305,7 @@ impl PubspecLockPackage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::path_utils::find_dart_package_dir;
 
     #[test]
     pub fn test_display() {
@@ -281,4 +333,60 @@ mod tests {
             ">=1.0.0".to_owned()
         );
     }
+
+    #[test]
+    pub fn test_read_formatter_page_width() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        assert_eq!(read_formatter_page_width(dir.path()), None);
+
+        let path = dir.path().join("analysis_options.yaml");
+        std::fs::write(&path, "include: package:lints/recommended.yaml\n")?;
+        assert_eq!(read_formatter_page_width(dir.path()), None);
+
+        std::fs::write(&path, "formatter:\n  page_width: 120\n")?;
+        assert_eq!(read_formatter_page_width(dir.path()), Some(120));
+
+        std::fs::write(&path, "formatter:\n  page_width: [120\n")?;
+        assert_eq!(read_formatter_page_width(dir.path()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_pub_workspace_resolution() -> anyhow::Result<()> {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test_fixtures/library/utils/dart_repository/pub_workspace");
+        let package_dir = root.join("packages").join("my_package");
+
+        assert_eq!(find_dart_package_dir(&root)?, package_dir);
+        assert_eq!(
+            find_dart_package_dir(&package_dir.join("lib"))?,
+            package_dir
+        );
+
+        let repo = DartRepository::from_path(&package_dir)?;
+        assert_eq!(repo.resolution_dir(), root);
+        assert!(repo.command_extra_args().is_empty());
+
+        // The package itself has no `pubspec.lock`, thus a mismatch can only be detected via the root one
+        repo.has_specified_and_installed(
+            "flutter_rust_bridge",
+            DartDependencyMode::Main,
+            &VersionReq::parse("=2.4.0")?,
+        )?;
+        repo.has_specified_and_installed(
+            "ffigen",
+            DartDependencyMode::Dev,
+            &VersionReq::parse(">=8.0.0")?,
+        )?;
+        assert!(repo
+            .has_specified_and_installed(
+                "flutter_rust_bridge",
+                DartDependencyMode::Main,
+                &VersionReq::parse("=2.5.0")?,
+            )
+            .is_err());
+
+        Ok(())
+    }
 }
@@ -46,9 +46,8 @@ impl DartToolchain {
             DartToolchain::Dart => "dart",
             DartToolchain::Flutter => "flutter",
         };
+        // Without a shell, a missing program is an error instead of a failed status
         command_run!(call_shell[None, None], toolchain, "--version")
-            .unwrap()
-            .status
-            .success()
+            .is_ok_and(|output| output.status.success())
     }
 }
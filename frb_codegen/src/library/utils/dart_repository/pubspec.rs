@@ -4,8 +4,13 @@ use std::collections::HashMap;
 /// The `pubspec.yaml` in a Dart/Flutter repository
 #[derive(Debug, Deserialize)]
 pub(crate) struct PubspecYaml {
+    pub name: Option<String>,
     pub dependencies: Option<HashMap<String, Option<PubspecYamlDependencyVersion>>>,
     pub dev_dependencies: Option<HashMap<String, Option<PubspecYamlDependencyVersion>>>,
+    /// `workspace` for members of a pub workspace
+    pub resolution: Option<String>,
+    /// Paths of the members, for the root of a pub workspace
+    pub workspace: Option<Vec<String>>,
 }
 
 /// e.g.
@@ -0,0 +1,120 @@
+use crate::utils::dart_repository::dart_toolchain::DartToolchain;
+use crate::utils::dart_repository::pubspec::PubspecYaml;
+use anyhow::Context;
+use itertools::Itertools;
+use log::debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A monorepo containing the Dart package
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct DartWorkspace {
+    pub root: PathBuf,
+    pub kind: DartWorkspaceKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DartWorkspaceKind {
+    /// A [pub workspace](https://dart.dev/tools/pub/workspaces), i.e. the root `pubspec.yaml` has `workspace:`
+    /// and the package has `resolution: workspace`.
+    /// The packages are resolved together, thus `pubspec.lock` and `.dart_tool` are at the root.
+    Pub,
+    /// A [melos](https://melos.invertase.dev) monorepo, i.e. the root has `melos.yaml`,
+    /// where each package is resolved on its own.
+    Melos,
+}
+
+impl DartWorkspace {
+    pub(crate) fn detect(package_dir: &Path) -> anyhow::Result<Option<Self>> {
+        let pubspec = read_pubspec(package_dir)?;
+        let ancestors = || package_dir.ancestors().skip(1);
+
+        let ans = if pubspec.resolution.as_deref() == Some("workspace") {
+            let root = ancestors()
+                .find(|dir| matches!(read_pubspec(dir), Ok(PubspecYaml { workspace: Some(_), .. })))
+                .with_context(|| format!("{package_dir:?} has `resolution: workspace`, but no parent directory has a pubspec.yaml with `workspace:`"))?;
+            Some(DartWorkspace {
+                root: root.to_owned(),
+                kind: DartWorkspaceKind::Pub,
+            })
+        } else {
+            ancestors()
+                .find(|dir| dir.join("melos.yaml").exists())
+                .map(|root| DartWorkspace {
+                    root: root.to_owned(),
+                    kind: DartWorkspaceKind::Melos,
+                })
+        };
+        debug!("DartWorkspace::detect package_dir={package_dir:?} ans={ans:?}");
+        Ok(ans)
+    }
+
+    /// When `root` is the root of a pub workspace instead of the package using `flutter_rust_bridge`,
+    /// e.g. when commands are run at the top of a monorepo, finds the only member depending on it
+    pub(crate) fn find_bridged_member(root: &Path) -> Option<PathBuf> {
+        const PACKAGE: &str = "flutter_rust_bridge";
+        let depends_on_package = |pubspec: &PubspecYaml| {
+            (pubspec.dependencies.as_ref()).is_some_and(|deps| deps.contains_key(PACKAGE))
+        };
+
+        let pubspec = read_pubspec(root).ok()?;
+        if depends_on_package(&pubspec) {
+            return None;
+        }
+        let ans = (pubspec.workspace?.iter())
+            .map(|member| root.join(member))
+            .filter(|dir| read_pubspec(dir).is_ok_and(|x| depends_on_package(&x)))
+            .exactly_one()
+            .ok();
+        debug!("DartWorkspace::find_bridged_member root={root:?} ans={ans:?}");
+        ans
+    }
+}
+
+fn read_pubspec(dir: &Path) -> anyhow::Result<PubspecYaml> {
+    let path = dir.join(DartToolchain::manifest_filename());
+    serde_yaml::from_str(&fs::read_to_string(&path)?)
+        .with_context(|| format!("Unable to parse {path:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let root = dir.path();
+        let package_dir = root.join("packages").join("my_package");
+        fs::create_dir_all(&package_dir)?;
+        fs::write(root.join("pubspec.yaml"), "name: root\n")?;
+        fs::write(package_dir.join("pubspec.yaml"), "name: my_package\n")?;
+        assert_eq!(DartWorkspace::detect(&package_dir)?, None);
+
+        fs::write(root.join("melos.yaml"), "name: root\n")?;
+        assert_eq!(
+            DartWorkspace::detect(&package_dir)?.map(|x| x.kind),
+            Some(DartWorkspaceKind::Melos)
+        );
+
+        fs::write(
+            package_dir.join("pubspec.yaml"),
+            "name: my_package\nresolution: workspace\n",
+        )?;
+        assert!(DartWorkspace::detect(&package_dir).is_err());
+
+        fs::write(
+            root.join("pubspec.yaml"),
+            "name: root\nworkspace:\n  - packages/my_package\n",
+        )?;
+        assert_eq!(
+            DartWorkspace::detect(&package_dir)?,
+            Some(DartWorkspace {
+                root: root.to_owned(),
+                kind: DartWorkspaceKind::Pub,
+            })
+        );
+
+        Ok(())
+    }
+}
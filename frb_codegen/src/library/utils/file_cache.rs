@@ -0,0 +1,116 @@
+use crate::utils::file_utils::create_dir_all_and_write;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Persists the output of an expensive step on disk,
+/// and reuses it in later runs as long as the inputs of the step are unchanged.
+pub(crate) struct FileCache {
+    /// `None` when the cache is disabled, e.g. via `--no-cache`
+    directory: Option<PathBuf>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileCacheEntry {
+    input_hash: String,
+    output: String,
+}
+
+impl FileCache {
+    pub(crate) fn new(directory: Option<&Path>) -> Self {
+        Self {
+            directory: directory.map(|x| x.to_owned()),
+        }
+    }
+
+    pub(crate) fn get_or_compute(
+        &self,
+        name: &str,
+        inputs: &[&str],
+        compute: impl FnOnce() -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        let Some(directory) = &self.directory else {
+            return compute();
+        };
+        let path = directory.join(format!("{name}.json"));
+        let input_hash = compute_input_hash(inputs);
+
+        if let Some(output) = read_entry(&path, &input_hash) {
+            debug!("FileCache hit name={name} input_hash={input_hash}");
+            return Ok(output);
+        }
+        debug!("FileCache miss name={name} input_hash={input_hash}");
+
+        let output = compute()?;
+        let entry = FileCacheEntry {
+            input_hash,
+            output: output.clone(),
+        };
+        create_dir_all_and_write(&path, serde_json::to_string(&entry)?)?;
+        Ok(output)
+    }
+}
+
+fn read_entry(path: &Path, input_hash: &str) -> Option<String> {
+    let entry: FileCacheEntry = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    (entry.input_hash == input_hash).then_some(entry.output)
+}
+
+fn compute_input_hash(inputs: &[&str]) -> String {
+    let mut hasher = Sha1::new();
+    // Outputs of older codegen versions may differ, thus never reuse them
+    hasher.update(env!("CARGO_PKG_VERSION").as_bytes());
+    for input in inputs {
+        // Prefix with length, such that e.g. `["ab", "c"]` and `["a", "bc"]` differ
+        hasher.update((input.len() as u64).to_le_bytes());
+        hasher.update(input.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::file_cache::FileCache;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_get_or_compute() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cache = FileCache::new(Some(dir.path()));
+        let compute_count = Cell::new(0);
+        let compute = |output: &str| {
+            compute_count.set(compute_count.get() + 1);
+            Ok(output.to_owned())
+        };
+
+        assert_eq!(
+            cache.get_or_compute("a", &["x", "y"], || compute("1"))?,
+            "1"
+        );
+        assert_eq!(
+            cache.get_or_compute("a", &["x", "y"], || compute("2"))?,
+            "1"
+        );
+        assert_eq!(compute_count.get(), 1);
+
+        assert_eq!(
+            cache.get_or_compute("a", &["xy", ""], || compute("3"))?,
+            "3"
+        );
+        assert_eq!(
+            cache.get_or_compute("b", &["xy", ""], || compute("4"))?,
+            "4"
+        );
+        assert_eq!(compute_count.get(), 3);
+
+        let disabled_cache = FileCache::new(None);
+        assert_eq!(
+            disabled_cache.get_or_compute("a", &["x", "y"], || compute("5"))?,
+            "5"
+        );
+
+        Ok(())
+    }
+}
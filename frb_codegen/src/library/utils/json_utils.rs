@@ -0,0 +1,35 @@
+use serde_json::Value;
+
+/// Objects are merged recursively, arrays are appended, and other values are replaced by `overrides`
+pub(crate) fn merge_json(base: &mut Value, overrides: &Value) {
+    match (base, overrides) {
+        (Value::Object(base), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(key) {
+                    Some(base_value) => merge_json(base_value, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overrides)) => base.extend(overrides.iter().cloned()),
+        (base, overrides) => *base = overrides.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge_json() {
+        let mut base = json!({"a": {"b": 1, "c": [1]}, "d": "x"});
+        merge_json(&mut base, &json!({"a": {"c": [2], "e": true}, "d": "y"}));
+        assert_eq!(
+            base,
+            json!({"a": {"b": 1, "c": [1, 2], "e": true}, "d": "y"})
+        );
+    }
+}
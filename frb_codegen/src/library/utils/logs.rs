@@ -1,6 +1,7 @@
 //! Utilities related to logging
 
 use crate::utils::console::MULTI_PROGRESS;
+use crate::utils::message_format::{emit, is_json_message_format, Event};
 use fern::colors::{Color, ColoredLevelConfig};
 use log::LevelFilter;
 
@@ -13,6 +14,8 @@ use log::LevelFilter;
 /// All logs with level `debug`(with parameter `verbose`=true or system variable `RUST_LOG`="debug") or above
 /// will be recorded in `./logs/<date>.log`.
 /// Logs with level `info` and above will be output to standard output, with colored tag.
+/// In the JSON message format, they are output to standard error instead,
+/// and warnings and errors are also emitted as events.
 ///
 /// # Example
 ///
@@ -33,16 +36,22 @@ pub fn configure_opinionated_logging(path: &str, verbose: bool) -> Result<(), fe
         LevelFilter::Debug => fern_logger
             .level(LevelFilter::Debug)
             .chain(fern::DateBased::new(path, "%Y-%m-%d.log"))
-            .chain(std::io::stdout()),
+            .chain(human_output()),
         LevelFilter::Info => fern_logger
             .level(LevelFilter::Info)
             .level_for("cbindgen", LevelFilter::Error)
-            .chain(std::io::stdout()),
+            .chain(human_output()),
         // frb-coverage:ignore-start
         _ => panic!("only allow \"debug\" or \"info\""),
         // frb-coverage:ignore-end
     };
 
+    if is_json_message_format() {
+        fern_logger = fern::Dispatch::new()
+            .chain(fern_logger)
+            .chain(json_message_dispatch());
+    }
+
     let (max_level, fern_logger) = fern_logger.into_log();
     let log_wrapper = indicatif_log_bridge::LogWrapper::new(MULTI_PROGRESS.clone(), fern_logger);
 
@@ -59,6 +68,25 @@ pub fn configure_opinionated_logging(path: &str, verbose: bool) -> Result<(), fe
     Ok(())
 }
 
+fn human_output() -> fern::Output {
+    if is_json_message_format() {
+        std::io::stderr().into()
+    } else {
+        std::io::stdout().into()
+    }
+}
+
+fn json_message_dispatch() -> fern::Dispatch {
+    fern::Dispatch::new()
+        .level(LevelFilter::Warn)
+        .chain(fern::Output::call(|record| {
+            emit(&Event::Message {
+                level: &record.level().as_str().to_lowercase(),
+                message: &record.args().to_string(),
+            })
+        }))
+}
+
 fn log_level_from_env_var() -> Option<LevelFilter> {
     (std::env::var("RUST_LOG").ok()).map(|value| log_level_from_str(&value))
 }
@@ -0,0 +1,110 @@
+//! Machine-readable output (`--message-format=json`), for IDE plugins and CI wrappers
+//!
+//! Each event is one line of JSON on stdout, e.g.
+//! `{"reason":"stage-finished","stage":"Parse","level":0,"elapsed_ms":1234}`,
+//! while the human-oriented output goes to stderr and the progress bars are hidden.
+
+use crate::utils::console::MULTI_PROGRESS;
+use indicatif::ProgressDrawTarget;
+use serde::Serialize;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+static JSON_MESSAGE_FORMAT: AtomicBool = AtomicBool::new(false);
+
+/// Should be called before [`crate::utils::logs::configure_opinionated_logging`],
+/// which decides where the logs are written according to the format.
+pub fn configure_message_format(format: MessageFormat) {
+    let json = format == MessageFormat::Json;
+    JSON_MESSAGE_FORMAT.store(json, Ordering::Relaxed);
+    if json {
+        MULTI_PROGRESS.set_draw_target(ProgressDrawTarget::hidden());
+    }
+}
+
+pub(crate) fn is_json_message_format() -> bool {
+    JSON_MESSAGE_FORMAT.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "reason", rename_all = "kebab-case")]
+pub(crate) enum Event<'a> {
+    StageStarted {
+        stage: &'a str,
+        level: usize,
+    },
+    StageFinished {
+        stage: &'a str,
+        level: usize,
+        elapsed_ms: u128,
+    },
+    /// Warnings and errors logged while running
+    Message {
+        level: &'a str,
+        message: &'a str,
+    },
+    GeneratedFile {
+        path: &'a Path,
+    },
+    Finished {
+        success: bool,
+        elapsed_ms: u128,
+        error: Option<&'a str>,
+    },
+}
+
+/// Does nothing unless in the JSON format
+pub(crate) fn emit(event: &Event) {
+    if !is_json_message_format() {
+        return;
+    }
+    let mut stdout = std::io::stdout().lock();
+    // Nothing can be reported if stdout is broken
+    let _ = writeln!(stdout, "{}", serde_json::to_string(event).unwrap());
+}
+
+/// Print text for humans, which is kept out of stdout in the JSON format
+pub(crate) fn print_human(text: &str) {
+    if is_json_message_format() {
+        eprintln!("{text}");
+    } else {
+        println!("{text}");
+    }
+}
+
+/// Emit the final event of a command, which tells whether it succeeds
+pub fn emit_finished(result: &anyhow::Result<()>, elapsed: Duration) {
+    let error = result.as_ref().err().map(|e| format!("{e:#}"));
+    emit(&Event::Finished {
+        success: result.is_ok(),
+        elapsed_ms: elapsed.as_millis(),
+        error: error.as_deref(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_event() {
+        let event = Event::StageFinished {
+            stage: "Parse",
+            level: 0,
+            elapsed_ms: 42,
+        };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"reason":"stage-finished","stage":"Parse","level":0,"elapsed_ms":42}"#
+        );
+    }
+}
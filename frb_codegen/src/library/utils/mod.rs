@@ -2,14 +2,19 @@
 
 pub(crate) mod basic_code;
 pub(crate) mod cbindgen_keywords;
+pub mod command_options;
 pub(crate) mod console;
 pub(crate) mod control_utils;
 pub(crate) mod crate_name;
+pub mod dart_coverage;
 pub(crate) mod dart_keywords;
 pub(crate) mod dart_repository;
 mod enum_map;
+pub(crate) mod file_cache;
 pub(crate) mod file_utils;
+pub(crate) mod json_utils;
 pub mod logs;
+pub mod message_format;
 pub(crate) mod namespace;
 pub(crate) mod path_utils;
 pub(crate) mod rust_project_utils;
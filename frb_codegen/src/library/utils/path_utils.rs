@@ -1,3 +1,4 @@
+use crate::utils::dart_repository::workspace::DartWorkspace;
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
@@ -33,12 +34,13 @@ pub(crate) fn find_parent_dir_with_file(
 }
 
 pub(crate) fn find_dart_package_dir(dart_file_path: &Path) -> Result<PathBuf> {
-    find_parent_dir_with_file(dart_file_path, "pubspec.yaml").with_context(|| {
+    let dir = find_parent_dir_with_file(dart_file_path, "pubspec.yaml").with_context(|| {
         // frb-coverage:ignore-start
         // This will stop the whole generator and tell the users, so we do not care about testing it
         format!("Fail to detect dart package from dart_file_path={dart_file_path:?}")
         // frb-coverage:ignore-end
-    })
+    })?;
+    Ok(DartWorkspace::find_bridged_member(&dir).unwrap_or(dir))
 }
 
 // pub(crate) fn find_rust_crate_dir(rust_file_path: &Path) -> Result<PathBuf> {
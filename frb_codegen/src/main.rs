@@ -5,23 +5,48 @@
 
 mod binary;
 
-use crate::binary::commands::{Cli, Commands, CreateOrIntegrateCommandCommonArgs};
+use crate::binary::commands::{
+    Cli, Commands, CreateOrIntegrateCommandCommonArgs, IntegrateCommandArgs,
+};
 use crate::binary::commands_parser::{compute_codegen_config, compute_codegen_meta_config};
 use clap::Parser;
-use lib_flutter_rust_bridge_codegen::integration::{CreateConfig, IntegrateConfig};
+use itertools::Itertools;
+use lib_flutter_rust_bridge_codegen::build_android::BuildAndroidConfig;
+use lib_flutter_rust_bridge_codegen::build_apple::BuildAppleConfig;
+use lib_flutter_rust_bridge_codegen::doctor::DoctorConfig;
+use lib_flutter_rust_bridge_codegen::integration::{ConflictPolicy, CreateConfig, IntegrateConfig};
+use lib_flutter_rust_bridge_codegen::sbom::SbomConfig;
+use lib_flutter_rust_bridge_codegen::test::{TestConfig, TestCoverageConfig};
+use lib_flutter_rust_bridge_codegen::utils::command_options::{
+    configure_command_options, CommandOptions,
+};
+use lib_flutter_rust_bridge_codegen::utils::dart_coverage::DartCoverageConfig;
 use lib_flutter_rust_bridge_codegen::utils::logs::configure_opinionated_logging;
+use lib_flutter_rust_bridge_codegen::utils::message_format::{
+    configure_message_format, emit_finished,
+};
 use lib_flutter_rust_bridge_codegen::*;
 use log::debug;
+use std::time::{Duration, Instant};
 
 fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    configure_message_format(cli.message_format.into());
     configure_opinionated_logging("./logs/", cli.verbose)?;
-    main_given_cli(cli)
+    let start = Instant::now();
+    let result = main_given_cli(cli);
+    emit_finished(&result, start.elapsed());
+    result
 }
 
 // Only use as entrypoint of tests
 fn main_given_cli(cli: Cli) -> anyhow::Result<()> {
     debug!("cli={cli:?}");
+    configure_command_options(CommandOptions {
+        stream_output: cli.stream_command_output,
+        timeout: cli.command_timeout.map(Duration::from_secs),
+        shell: cli.shell.into(),
+    });
     match cli.command {
         Commands::Generate(args) => {
             let meta_config = compute_codegen_meta_config(&args);
@@ -42,15 +67,81 @@ fn main_given_cli(cli: Cli) -> anyhow::Result<()> {
             rust_crate_name: args.common.rust_crate_name.clone(),
             rust_crate_dir: compute_rust_crate_dir(&args.common),
             template: args.template.into(),
+            conflict_policy: compute_conflict_policy(&args),
         })?,
         Commands::BuildWeb(args) => {
-            build_web::build(args.dart_root, args.dart_coverage, args.args)?
+            let dart_coverage = args.dart_coverage.then_some(DartCoverageConfig {
+                vm_service_port: args.vm_service_port,
+                scope: args.coverage_scope,
+                output: "coverage/coverage.json".into(),
+            });
+            build_web::build(args.dart_root, dart_coverage, args.args)?
         }
+        Commands::BuildAndroid(args) => build_android::build(BuildAndroidConfig {
+            dart_root: args.dart_root,
+            rust_root: args.rust_root,
+            abis: args.abis.into_iter().map_into().collect(),
+            platform: args.platform,
+            release: !args.debug,
+            strip: args.strip,
+            output: args.output,
+        })?,
+        Commands::BuildApple(args) => build_apple::build(BuildAppleConfig {
+            dart_root: args.dart_root,
+            rust_root: args.rust_root,
+            platforms: args.platforms.into_iter().map_into().collect(),
+            release: !args.debug,
+            output: args.output,
+        })?,
+        Commands::Test(args) => test::test(TestConfig {
+            dart_root: args.dart_root,
+            rust_root: args.rust_root,
+            platforms: args.platforms.into_iter().map_into().collect(),
+            name: args.name,
+            paths: args.paths,
+            coverage: args.coverage.then_some(TestCoverageConfig {
+                output: args.coverage_output,
+                vm_service_port: args.vm_service_port,
+                scope: args.coverage_scope,
+            }),
+        })?,
+        Commands::Docs(args) => {
+            let config = compute_codegen_config(args.primary)?;
+            codegen::generate_docs(config, &args.docs_output)?
+        }
+        Commands::Dump(args) => {
+            let config = compute_codegen_config(args.primary)?;
+            codegen::dump_ir(config, args.stage, args.format, &args.output)?
+        }
+        Commands::CheckCompat(args) => {
+            let config = compute_codegen_config(args.primary)?;
+            codegen::check_compat(config, &args.snapshot, args.update)?
+        }
+        Commands::Sbom(args) => sbom::generate(SbomConfig {
+            rust_crate_dir: args.rust_root,
+            format: args.format.into(),
+            targets: args.targets,
+            output_dir: args.output_dir,
+        })?,
+        Commands::Doctor(args) => doctor::doctor(DoctorConfig {
+            dart_root: args.dart_root,
+            llvm_path: args.llvm_path,
+        })?,
         Commands::InternalGenerate(_args) => internal::generate()?,
     }
     Ok(())
 }
 
+fn compute_conflict_policy(args: &IntegrateCommandArgs) -> ConflictPolicy {
+    if args.force {
+        ConflictPolicy::Overwrite
+    } else if args.skip_existing {
+        ConflictPolicy::SkipExisting
+    } else {
+        ConflictPolicy::Interactive
+    }
+}
+
 fn compute_rust_crate_dir(config: &CreateOrIntegrateCommandCommonArgs) -> String {
     config.rust_crate_dir.clone().unwrap_or("rust".to_owned())
 }
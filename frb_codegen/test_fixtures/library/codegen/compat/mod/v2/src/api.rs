@@ -0,0 +1,5 @@
+pub fn a_new_fn() {}
+
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
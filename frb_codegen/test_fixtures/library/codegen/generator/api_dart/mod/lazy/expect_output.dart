@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ {VERSION}.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            Future<List<Item>> listItems() => RustLib.instance.api.crateApiListItems();
+
+            class Item  {
+                final LazyField<String> _title;
+final LazyField<Uint8List> _thumbnail;
+LazyField<List<String>> _tags;
+
+                Item({required String title ,required Uint8List thumbnail ,required List<String> tags ,}): _title = LazyField.value(title), _thumbnail = LazyField.value(thumbnail), _tags = LazyField.value(tags);
+
+                @internal
+                Item.lazy({required LazyField<String> title,required LazyField<Uint8List> thumbnail,required LazyField<List<String>> tags,}): _title = title, _thumbnail = thumbnail, _tags = tags;
+
+                /// Shown in the list
+String get title => _title.value;
+
+Uint8List get thumbnail => _thumbnail.value;
+
+List<String> get tags => _tags.value;
+set tags(List<String> value) => _tags = LazyField.value(value);
+
+                
+                
+
+                
+        @override
+        int get hashCode => title.hashCode^thumbnail.hashCode^tags.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Item &&
+                runtimeType == other.runtimeType
+                && title == other.title&& thumbnail == other.thumbnail&& tags == other.tags;
+        
+            }
+            
\ No newline at end of file
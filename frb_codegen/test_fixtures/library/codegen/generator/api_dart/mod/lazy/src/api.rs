@@ -0,0 +1,12 @@
+#[flutter_rust_bridge_macros::frb(lazy)]
+pub struct Item {
+    /// Shown in the list
+    pub title: String,
+    pub thumbnail: Vec<u8>,
+    #[frb(non_final)]
+    pub tags: Vec<String>,
+}
+
+pub fn list_items() -> Vec<Item> {
+    vec![]
+}
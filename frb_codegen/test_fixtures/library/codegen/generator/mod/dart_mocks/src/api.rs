@@ -0,0 +1,33 @@
+use crate::frb_generated::StreamSink;
+
+#[flutter_rust_bridge_macros::frb(opaque)]
+pub struct Counter {
+    count: i32,
+}
+
+impl Counter {
+    #[flutter_rust_bridge_macros::frb(sync)]
+    pub fn new() -> Self {
+        Self { count: 0 }
+    }
+
+    pub fn increment(&mut self, by: i32) -> i32 {
+        self.count += by;
+        self.count
+    }
+
+    #[flutter_rust_bridge_macros::frb(sync, getter)]
+    pub fn count(&self) -> i32 {
+        self.count
+    }
+}
+
+pub fn create_counter() -> Counter {
+    Counter::new()
+}
+
+pub fn greet(name: String) -> String {
+    format!("Hello, {name}!")
+}
+
+pub fn ticks(sink: StreamSink<i32>) {}
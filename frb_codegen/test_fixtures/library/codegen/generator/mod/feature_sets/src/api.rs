@@ -0,0 +1,28 @@
+pub struct Page {
+    pub title: String,
+}
+
+#[cfg(feature = "pro")]
+pub struct PdfOptions {
+    pub pages: Vec<Page>,
+    pub compress: bool,
+}
+
+#[cfg(feature = "pro")]
+pub fn export_pdf(options: PdfOptions) -> String {
+    format!("{} pages", options.pages.len())
+}
+
+#[cfg(feature = "pro")]
+#[flutter_rust_bridge_macros::frb(sync)]
+pub fn default_pdf_options() -> PdfOptions {
+    PdfOptions {
+        pages: vec![],
+        compress: true,
+    }
+}
+
+#[cfg(not(feature = "pro"))]
+pub fn show_upgrade_hint(page: Page) -> String {
+    page.title
+}
@@ -0,0 +1,20 @@
+use flutter_rust_bridge::{DartOpaque, RustAutoOpaque};
+
+#[flutter_rust_bridge_macros::frb(opaque)]
+pub struct Handle {
+    id: i32,
+}
+
+#[flutter_rust_bridge_macros::frb(non_opaque)]
+#[flutter_rust_bridge_macros::frb(lazy)]
+pub struct Item {
+    pub title: String,
+    pub handle: Handle,
+    pub shared_handle: RustAutoOpaque<Handle>,
+    pub handles: Option<Vec<Handle>>,
+    pub callback: DartOpaque,
+}
+
+pub fn list_items() -> Vec<Item> {
+    vec![]
+}
@@ -0,0 +1,15 @@
+use crate::{fbs, proto};
+
+/// flutter_rust_bridge:codec="protobuf"
+pub fn greet(person: proto::Person) -> proto::Greeting {
+    proto::Greeting {
+        text: format!("Hello, {}!", person.name),
+    }
+}
+
+/// flutter_rust_bridge:codec="flatbuffers"
+pub fn heal(monster: fbs::MonsterT) -> fbs::MonsterT {
+    fbs::MonsterT {
+        hp: monster.hp + 10,
+    }
+}
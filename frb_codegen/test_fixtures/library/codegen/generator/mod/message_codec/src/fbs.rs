@@ -0,0 +1,55 @@
+// A trimmed version of the code generated by `flatc --rust --gen-object-api` for:
+// table Monster { hp: short = 100; }
+
+pub struct Monster<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for Monster<'a> {
+    type Inner = Monster<'a>;
+
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> Monster<'a> {
+    pub const VT_HP: flatbuffers::VOffsetT = 4;
+
+    pub fn hp(&self) -> i16 {
+        unsafe { self._tab.get::<i16>(Monster::VT_HP, Some(100)).unwrap() }
+    }
+
+    pub fn unpack(&self) -> MonsterT {
+        MonsterT { hp: self.hp() }
+    }
+}
+
+impl flatbuffers::Verifiable for Monster<'_> {
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        v.visit_table(pos)?
+            .visit_field::<i16>("hp", Self::VT_HP, false)?
+            .finish();
+        Ok(())
+    }
+}
+
+pub struct MonsterT {
+    pub hp: i16,
+}
+
+impl MonsterT {
+    pub fn pack<'b, A: flatbuffers::Allocator + 'b>(
+        &self,
+        fbb: &mut flatbuffers::FlatBufferBuilder<'b, A>,
+    ) -> flatbuffers::WIPOffset<Monster<'b>> {
+        let start = fbb.start_table();
+        fbb.push_slot::<i16>(Monster::VT_HP, self.hp, 100);
+        flatbuffers::WIPOffset::new(fbb.end_table(start).value())
+    }
+}
@@ -0,0 +1,15 @@
+// The code generated by `prost-build` for:
+// message Person { string name = 1; }
+// message Greeting { string text = 1; }
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Person {
+    #[prost(string, tag = "1")]
+    pub name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Greeting {
+    #[prost(string, tag = "1")]
+    pub text: String,
+}
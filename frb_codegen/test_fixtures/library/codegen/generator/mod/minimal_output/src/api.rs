@@ -0,0 +1,3 @@
+pub fn add(left: i32, right: i32) -> i32 {
+    left + right
+}
@@ -0,0 +1,7 @@
+pub struct Ipv4Addr {
+    pub text: String,
+}
+
+pub fn legacy_ping(addr: Ipv4Addr) -> Ipv4Addr {
+    addr
+}
@@ -0,0 +1,11 @@
+pub mod legacy;
+
+use std::net::Ipv4Addr;
+
+pub fn ping(addr: Ipv4Addr) -> Ipv4Addr {
+    addr
+}
+
+pub fn ping_all(addrs: Vec<std::net::Ipv4Addr>) -> usize {
+    addrs.len()
+}
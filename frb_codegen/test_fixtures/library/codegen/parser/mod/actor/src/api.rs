@@ -0,0 +1,33 @@
+use std::cell::Cell;
+
+/// flutter_rust_bridge:actor
+pub struct Counter {
+    value: Cell<i32>,
+}
+
+impl Counter {
+    pub async fn new(initial: i32) -> Self {
+        Self {
+            value: Cell::new(initial),
+        }
+    }
+
+    pub fn load(path: String) -> Result<Self, String> {
+        Err(path)
+    }
+
+    pub fn increment(&mut self, by: i32) -> i32 {
+        self.value.set(self.value.get() + by);
+        self.value.get()
+    }
+
+    pub async fn sync_to_server(&mut self, url: String) -> bool {
+        true
+    }
+
+    pub fn version() -> String {
+        "1.0".to_owned()
+    }
+}
+
+pub fn use_counter(counter: CounterActor) {}
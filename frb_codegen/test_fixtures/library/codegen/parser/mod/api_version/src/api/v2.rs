@@ -0,0 +1,21 @@
+pub struct User {
+    pub name: String,
+    pub age: u32,
+}
+
+pub struct Settings {
+    pub theme: String,
+}
+
+pub fn get_user() -> User {
+    User {
+        name: "Alice".to_owned(),
+        age: 42,
+    }
+}
+
+pub fn get_settings() -> Settings {
+    Settings {
+        theme: "dark".to_owned(),
+    }
+}
@@ -0,0 +1,17 @@
+pub struct User {
+    pub name: String,
+}
+
+pub struct Settings {
+    pub dark_mode: bool,
+}
+
+pub fn get_user() -> User {
+    User {
+        name: "Alice".to_owned(),
+    }
+}
+
+pub fn get_settings() -> Settings {
+    Settings { dark_mode: true }
+}
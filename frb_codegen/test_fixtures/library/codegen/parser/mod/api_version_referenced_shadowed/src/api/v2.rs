@@ -0,0 +1,21 @@
+pub struct User {
+    pub name: String,
+    pub age: u32,
+}
+
+pub struct Settings {
+    pub theme: String,
+}
+
+pub fn get_user() -> User {
+    User {
+        name: "Alice".to_owned(),
+        age: 42,
+    }
+}
+
+pub fn migrate_settings(old: super::v1::Settings) -> Settings {
+    Settings {
+        theme: if old.dark_mode { "dark" } else { "light" }.to_owned(),
+    }
+}
@@ -0,0 +1,7 @@
+/// flutter_rust_bridge:background_task
+pub async fn download_file(
+    url: String,
+    context: flutter_rust_bridge::BackgroundTaskContext<u32>,
+) -> Result<String, String> {
+    Ok(url)
+}
@@ -0,0 +1,12 @@
+pub struct SyncProgress {
+    pub done: u32,
+    pub total: u32,
+}
+
+/// flutter_rust_bridge:background_task
+pub async fn sync_files(
+    folder: String,
+    context: flutter_rust_bridge::BackgroundTaskContext<SyncProgress>,
+) -> u32 {
+    0
+}
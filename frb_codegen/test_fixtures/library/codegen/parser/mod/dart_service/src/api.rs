@@ -0,0 +1,8 @@
+/// flutter_rust_bridge:dart_service
+pub trait SecureStorage {
+    async fn read(&self, key: String) -> Option<String>;
+
+    async fn write(&self, key: String, value: String);
+}
+
+pub fn use_secure_storage(storage: SecureStorageDartProxy) {}
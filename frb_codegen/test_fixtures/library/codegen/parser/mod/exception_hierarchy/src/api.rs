@@ -0,0 +1,21 @@
+#[frb(exception_hierarchy)]
+pub enum NetworkError {
+    Timeout { seconds: u32 },
+    NotFound(String),
+    Offline,
+}
+
+#[frb(exception_hierarchy)]
+pub enum StorageError {
+    NotFound(String),
+    Format(String),
+    DiskFullException,
+}
+
+pub fn download(url: String) -> Result<String, NetworkError> {
+    Ok(url)
+}
+
+pub fn save(data: String) -> Result<(), StorageError> {
+    Ok(())
+}
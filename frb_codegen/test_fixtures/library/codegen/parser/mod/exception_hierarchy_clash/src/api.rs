@@ -0,0 +1,17 @@
+#[frb(exception_hierarchy)]
+pub enum NetworkError {
+    Timeout { seconds: u32 },
+}
+
+/// Same as the name of `NetworkError::Timeout` after resolving its clash with the Dart `TimeoutException`
+pub struct NetworkErrorTimeoutException {
+    pub seconds: u32,
+}
+
+pub fn download(url: String) -> Result<String, NetworkError> {
+    Ok(url)
+}
+
+pub fn last_timeout() -> NetworkErrorTimeoutException {
+    NetworkErrorTimeoutException { seconds: 0 }
+}
@@ -0,0 +1,30 @@
+pub struct MyDto {
+    pub name: String,
+}
+
+#[frb(instantiate(T = MyDto))]
+#[frb(instantiate(T = String))]
+pub struct Response<T> {
+    pub data: T,
+    pub code: i32,
+}
+
+#[frb(instantiate(K = String, V = MyDto))]
+pub enum Entry<K, V> {
+    Key(K),
+    Value(V),
+}
+
+#[frb(instantiate(T = MyDto))]
+pub fn fetch<T>(data: T) -> Response<T> {
+    Response { data, code: 0 }
+}
+
+pub fn fetch_text() -> Response<String> {
+    Response {
+        data: String::new(),
+        code: 0,
+    }
+}
+
+pub fn read_entry(entry: Entry<String, MyDto>) {}
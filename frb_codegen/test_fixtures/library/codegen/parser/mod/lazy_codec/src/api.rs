@@ -0,0 +1,8 @@
+#[frb(lazy)]
+pub struct Item {
+    pub title: String,
+}
+
+pub fn list_items() -> Vec<Item> {
+    vec![]
+}
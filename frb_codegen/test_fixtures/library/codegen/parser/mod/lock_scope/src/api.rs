@@ -0,0 +1,26 @@
+/// flutter_rust_bridge:opaque
+/// flutter_rust_bridge:lock_scope
+pub struct Account {
+    balance: i64,
+}
+
+impl Account {
+    pub fn new() -> Self {
+        Self { balance: 0 }
+    }
+
+    /// flutter_rust_bridge:sync
+    pub fn balance(&self) -> i64 {
+        self.balance
+    }
+
+    pub fn deposit(&mut self, amount: i64) {
+        self.balance += amount;
+    }
+
+    pub async fn audit(&self, note: String) -> bool {
+        true
+    }
+
+    pub fn close(self) {}
+}
@@ -0,0 +1,17 @@
+/// flutter_rust_bridge:opaque
+/// flutter_rust_bridge:lock_scope
+pub struct Document {
+    text: String,
+}
+
+impl Document {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+        }
+    }
+
+    pub fn try_read(&self) -> Option<String> {
+        Some(self.text.clone())
+    }
+}
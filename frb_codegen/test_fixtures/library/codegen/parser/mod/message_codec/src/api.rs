@@ -0,0 +1,27 @@
+use crate::{fbs, proto};
+
+pub struct GreetOptions {
+    pub times: u32,
+}
+
+/// flutter_rust_bridge:codec="protobuf"
+pub fn greet(person: proto::Person, options: GreetOptions) -> proto::Greeting {
+    proto::Greeting {
+        text: format!("Hello, {}!", person.name).repeat(options.times as usize),
+    }
+}
+
+/// flutter_rust_bridge:codec="protobuf"
+pub fn greet_all(people: Vec<crate::proto::Person>) -> Option<proto::Greeting> {
+    let names: Vec<_> = people.into_iter().map(|person| person.name).collect();
+    (!names.is_empty()).then(|| proto::Greeting {
+        text: format!("Hello, {}!", names.join(", ")),
+    })
+}
+
+/// flutter_rust_bridge:codec="flatbuffers"
+pub fn heal(monster: fbs::MonsterT) -> fbs::MonsterT {
+    fbs::MonsterT {
+        hp: monster.hp + 10,
+    }
+}
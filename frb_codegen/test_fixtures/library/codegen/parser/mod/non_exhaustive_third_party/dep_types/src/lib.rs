@@ -0,0 +1,9 @@
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[non_exhaustive]
+pub struct Config {
+    pub verbose: bool,
+}
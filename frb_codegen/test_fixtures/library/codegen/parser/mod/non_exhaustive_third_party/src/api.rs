@@ -0,0 +1,7 @@
+pub fn plain_point(point: dep_types::Point) -> dep_types::Point {
+    point
+}
+
+pub fn extensible_config(config: dep_types::Config) -> dep_types::Config {
+    config
+}
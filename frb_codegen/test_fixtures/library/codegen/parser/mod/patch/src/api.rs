@@ -0,0 +1,32 @@
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+pub struct Shortcut {
+    pub key: String,
+}
+
+/// flutter_rust_bridge:patch
+pub struct Settings {
+    pub volume: i32,
+    pub theme: Option<Theme>,
+    pub shortcuts: Vec<Shortcut>,
+}
+
+/// flutter_rust_bridge:opaque
+/// flutter_rust_bridge:patch
+pub struct AppState {
+    pub volume: i32,
+    pub theme: Theme,
+}
+
+impl AppState {
+    pub fn apply(&mut self, volume: i32) {
+        self.volume = volume;
+    }
+}
+
+pub fn get_settings() -> Settings {
+    todo!()
+}
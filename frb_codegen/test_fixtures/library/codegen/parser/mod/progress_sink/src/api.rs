@@ -0,0 +1,6 @@
+use crate::frb_generated::{ProgressSink, StreamSink};
+use flutter_rust_bridge::FrbProgress;
+
+pub fn download_file(url: String, progress: ProgressSink) {}
+
+pub fn extract_archive(path: String, progress: StreamSink<FrbProgress>) {}
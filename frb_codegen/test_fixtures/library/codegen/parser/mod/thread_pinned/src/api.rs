@@ -0,0 +1,33 @@
+use std::rc::Rc;
+
+/// flutter_rust_bridge:thread_pinned
+pub struct Camera {
+    handle: Rc<i32>,
+}
+
+impl Camera {
+    pub fn new(id: i32) -> Self {
+        Self {
+            handle: Rc::new(id),
+        }
+    }
+
+    pub fn open(path: String) -> Result<Self, String> {
+        Err(path)
+    }
+
+    pub fn capture(&mut self, width: u32) -> Vec<u8> {
+        vec![]
+    }
+
+    /// flutter_rust_bridge:sync
+    pub fn id(&self) -> i32 {
+        *self.handle
+    }
+
+    pub fn version() -> String {
+        "1.0".to_owned()
+    }
+}
+
+pub fn use_camera(camera: CameraThreadPinned) {}
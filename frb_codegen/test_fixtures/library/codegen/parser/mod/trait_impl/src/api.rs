@@ -0,0 +1,44 @@
+pub trait Repository {
+    fn count(&self) -> i32;
+}
+
+/// Opaque, and only used by reference in the API
+#[frb(opaque)]
+pub struct MemoryRepository {
+    items: Vec<String>,
+}
+
+impl Repository for MemoryRepository {
+    fn count(&self) -> i32 {
+        self.items.len() as i32
+    }
+}
+
+/// Opaque, and also used by value in the API
+#[frb(opaque)]
+pub struct FileRepository {
+    path: String,
+}
+
+impl Repository for FileRepository {
+    fn count(&self) -> i32 {
+        0
+    }
+}
+
+pub fn open_file_repository(path: String) -> FileRepository {
+    FileRepository { path }
+}
+
+/// Without methods, thus only the implementations themselves use the types
+pub trait Tagged {}
+
+impl Tagged for MemoryRepository {}
+
+/// Not used in the API at all
+#[frb(opaque)]
+pub struct HiddenRepository {
+    items: Vec<String>,
+}
+
+impl Tagged for HiddenRepository {}
@@ -0,0 +1,14 @@
+/// flutter_rust_bridge:serialize,zero_copy
+pub fn render_audio(seconds: u32) -> Vec<f32> {
+    vec![0.0; seconds as usize]
+}
+
+/// flutter_rust_bridge:sync,zero_copy
+pub fn load_weights() -> Vec<u8> {
+    vec![]
+}
+
+/// flutter_rust_bridge:serialize
+pub fn serialized_bytes() -> Vec<u8> {
+    vec![]
+}
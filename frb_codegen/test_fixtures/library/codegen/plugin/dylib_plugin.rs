@@ -0,0 +1,38 @@
+//! A generator plugin implementing the ABI of `flutter_rust_bridge_codegen_plugin::export_plugin!` by hand,
+//! which returns the request as an artifact
+
+#[no_mangle]
+pub extern "C" fn frb_codegen_plugin_protocol_version() -> u32 {
+    1
+}
+
+/// # Safety
+///
+/// Only called by the code generator, with a valid buffer.
+#[no_mangle]
+pub unsafe extern "C" fn frb_codegen_plugin_generate(
+    request_ptr: *const u8,
+    request_len: usize,
+    response_len: *mut usize,
+) -> *mut u8 {
+    let request = std::slice::from_raw_parts(request_ptr, request_len);
+    let response = if request.is_empty() {
+        r#"{"Err":"The request is empty"}"#.to_owned()
+    } else {
+        format!(
+            r#"{{"Ok":{{"artifacts":[{{"path":"request.txt","content":"{}"}}]}}}}"#,
+            String::from_utf8_lossy(request)
+        )
+    };
+    let response = response.into_bytes().into_boxed_slice();
+    *response_len = response.len();
+    Box::into_raw(response) as *mut u8
+}
+
+/// # Safety
+///
+/// Only called by the code generator, with a buffer returned by `frb_codegen_plugin_generate`.
+#[no_mangle]
+pub unsafe extern "C" fn frb_codegen_plugin_free(ptr: *mut u8, len: usize) {
+    drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)));
+}
@@ -0,0 +1,189 @@
+//! Interface between `flutter_rust_bridge_codegen` and its generator plugins.
+//!
+//! A plugin is given the resolved API of the Rust code (the MIR), and returns the extra files to write.
+//! It is either a dynamic library, which calls [`export_plugin!`],
+//! or a WASI program (e.g. compiled to `wasm32-wasip1`), whose `main` calls [`run_stdio`].
+//!
+//! ```ignore
+//! use flutter_rust_bridge_codegen_plugin::{PluginArtifact, PluginInput, PluginOutput};
+//!
+//! fn generate(input: PluginInput) -> Result<PluginOutput, String> {
+//!     Ok(PluginOutput {
+//!         artifacts: vec![PluginArtifact {
+//!             path: "schema.graphql".into(),
+//!             content: my_graphql_generator(&input.mir)?,
+//!         }],
+//!     })
+//! }
+//!
+//! flutter_rust_bridge_codegen_plugin::export_plugin!(generate);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+/// Bumped whenever [`PluginInput`] or [`PluginOutput`] changes in an incompatible way
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// What the code generator gives to the plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginInput {
+    pub protocol_version: u32,
+    /// Version of `flutter_rust_bridge_codegen`, which can be checked when relying on details of the MIR
+    pub codegen_version: String,
+    /// The directory containing the configuration, which relative artifact paths are resolved against
+    pub base_dir: PathBuf,
+    pub rust_crate_dir: PathBuf,
+    pub dart_output_dir: PathBuf,
+    /// The resolved API, in the same format as the MIR written by `--dump mir`
+    pub mir: serde_json::Value,
+}
+
+/// What the plugin gives back to the code generator
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginOutput {
+    pub artifacts: Vec<PluginArtifact>,
+}
+
+/// A file to be written by the code generator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginArtifact {
+    /// Relative to [`PluginInput::base_dir`], and should not escape it via `..`
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// The serialized form of the result, which is the same for all kinds of plugins
+pub type PluginResponse = Result<PluginOutput, String>;
+
+/// Parse the request, call the plugin, and serialize the response.
+///
+/// Errors and panics of the plugin are reported in the response,
+/// since they should not unwind across the FFI boundary.
+pub fn handle_request(generate: impl Fn(PluginInput) -> PluginResponse, request: &[u8]) -> Vec<u8> {
+    let response = match serde_json::from_slice::<PluginInput>(request) {
+        Ok(input) if input.protocol_version != PROTOCOL_VERSION => Err(format!(
+            "The plugin supports protocol version {PROTOCOL_VERSION}, but the code generator uses {}",
+            input.protocol_version
+        )),
+        Ok(input) => std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| generate(input)))
+            .unwrap_or_else(|_| Err("The plugin panicked".to_owned())),
+        Err(e) => Err(format!("Fail to parse the plugin input: {e}")),
+    };
+    serde_json::to_vec(&response).unwrap()
+}
+
+/// Entrypoint of WASI plugins, which read the request from stdin and write the response to stdout
+pub fn run_stdio(generate: impl Fn(PluginInput) -> PluginResponse) {
+    let mut request = vec![];
+    std::io::stdin().read_to_end(&mut request).unwrap();
+    let response = handle_request(generate, &request);
+    std::io::stdout().write_all(&response).unwrap();
+}
+
+/// Export the functions that the code generator looks up in a dynamic library plugin.
+///
+/// The plugin crate should use `crate-type = ["cdylib"]`.
+#[macro_export]
+macro_rules! export_plugin {
+    ($generate:path) => {
+        #[no_mangle]
+        pub extern "C" fn frb_codegen_plugin_protocol_version() -> u32 {
+            $crate::PROTOCOL_VERSION
+        }
+
+        /// # Safety
+        ///
+        /// Only called by the code generator, with a valid buffer.
+        #[no_mangle]
+        pub unsafe extern "C" fn frb_codegen_plugin_generate(
+            request_ptr: *const u8,
+            request_len: usize,
+            response_len: *mut usize,
+        ) -> *mut u8 {
+            $crate::ffi::generate($generate, request_ptr, request_len, response_len)
+        }
+
+        /// # Safety
+        ///
+        /// Only called by the code generator, with a buffer returned by `frb_codegen_plugin_generate`.
+        #[no_mangle]
+        pub unsafe extern "C" fn frb_codegen_plugin_free(ptr: *mut u8, len: usize) {
+            $crate::ffi::free(ptr, len)
+        }
+    };
+}
+
+#[doc(hidden)]
+pub mod ffi {
+    use crate::{handle_request, PluginInput, PluginResponse};
+
+    /// # Safety
+    ///
+    /// `request_ptr` should point to `request_len` bytes, and `response_len` should be writable.
+    pub unsafe fn generate(
+        generate: impl Fn(PluginInput) -> PluginResponse,
+        request_ptr: *const u8,
+        request_len: usize,
+        response_len: *mut usize,
+    ) -> *mut u8 {
+        let request = std::slice::from_raw_parts(request_ptr, request_len);
+        let response = handle_request(generate, request).into_boxed_slice();
+        *response_len = response.len();
+        Box::into_raw(response) as *mut u8
+    }
+
+    /// # Safety
+    ///
+    /// The buffer should be the one returned by [`generate`], and not be freed before.
+    pub unsafe fn free(ptr: *mut u8, len: usize) {
+        drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(ptr, len)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_request(protocol_version: u32) -> Vec<u8> {
+        serde_json::to_vec(&PluginInput {
+            protocol_version,
+            codegen_version: "2.4.0".to_owned(),
+            base_dir: "/project".into(),
+            rust_crate_dir: "/project/rust".into(),
+            dart_output_dir: "/project/lib/src/rust".into(),
+            mir: serde_json::json!({"funcs_all": []}),
+        })
+        .unwrap()
+    }
+
+    fn call(request: &[u8], generate: impl Fn(PluginInput) -> PluginResponse) -> PluginResponse {
+        serde_json::from_slice(&handle_request(generate, request)).unwrap()
+    }
+
+    #[test]
+    fn test_handle_request() {
+        let generate = |input: PluginInput| {
+            Ok(PluginOutput {
+                artifacts: vec![PluginArtifact {
+                    path: "a.txt".into(),
+                    content: input.mir.to_string(),
+                }],
+            })
+        };
+        let output = call(&create_request(PROTOCOL_VERSION), generate).unwrap();
+        assert_eq!(output.artifacts[0].content, r#"{"funcs_all":[]}"#);
+
+        assert!(call(&create_request(PROTOCOL_VERSION + 1), generate)
+            .unwrap_err()
+            .contains("protocol version"));
+        assert!(call(b"not json", generate)
+            .unwrap_err()
+            .contains("Fail to parse"));
+        assert_eq!(
+            call(&create_request(PROTOCOL_VERSION), |_| panic!()).unwrap_err(),
+            "The plugin panicked"
+        );
+    }
+}
@@ -9,7 +9,21 @@ library;
 export 'src/exceptions.dart';
 export 'src/generalized_typed_data/generalized_typed_data.dart'
     show Int64List, Uint64List;
+export 'src/loader/_common.dart'
+    show ExternalLibraryLoader, ExternalLibraryLoaderConfig;
 export 'src/loader/loader.dart' show loadExternalLibrary;
 export 'src/main_components/handler.dart' show BaseHandler;
+export 'src/method_channel/handler.dart' show MethodChannelHandler;
+export 'src/method_channel/transport.dart'
+    show MethodChannelSend, MethodChannelTransport;
+export 'src/misc/inspector.dart' show BridgeInspector;
+export 'src/misc/recorder.dart' show BridgeRecorder, ReplayHandler;
+export 'src/misc/rust_scope.dart' show RustScope, RustScopeMixin;
+export 'src/misc/transfer_progress.dart'
+    show
+        TransferProgressCallback,
+        maxChunkedTransferBytes,
+        withTransferProgress;
 export 'src/task.dart' show NormalTask, SyncTask;
+export 'src/stream/progress.dart';
 export 'src/stream/stream_sink.dart' show RustStreamSink;
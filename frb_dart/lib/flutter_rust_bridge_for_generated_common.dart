@@ -24,6 +24,8 @@ export 'src/main_components/handler.dart';
 export 'src/main_components/wire.dart';
 export 'src/manual_impl/_common.dart';
 export 'src/misc/box.dart';
+export 'src/misc/lazy_field.dart';
+export 'src/misc/lock_scope.dart';
 export 'src/misc/rust_opaque.dart';
 export 'src/misc/simple_disposable.dart';
 export 'src/rust_arc/_common.dart';
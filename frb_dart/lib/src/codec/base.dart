@@ -10,7 +10,8 @@ abstract class BaseCodec<S, E extends Object, WireSyncType> {
   S decodeObject(dynamic raw);
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  S decodeWireSyncType(WireSyncType raw);
+  S decodeWireSyncType(
+      WireSyncType raw, GeneralizedFrbRustBinding generalizedFrbRustBinding);
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void freeWireSyncRust2Dart(
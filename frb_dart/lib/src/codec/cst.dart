@@ -17,6 +17,8 @@ class CstCodec<S, E extends Object> extends BaseCodec<S, E, dynamic> {
   S decodeObject(dynamic raw) => throw UnimplementedError('unreachable');
 
   @override
-  S decodeWireSyncType(dynamic raw) => throw UnimplementedError('unreachable');
+  S decodeWireSyncType(
+          dynamic raw, GeneralizedFrbRustBinding generalizedFrbRustBinding) =>
+      throw UnimplementedError('unreachable');
 }
 // coverage:ignore-end
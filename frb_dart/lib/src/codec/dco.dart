@@ -26,8 +26,10 @@ class DcoCodec<S, E extends Object>
   }
 
   @override
-  S decodeWireSyncType(WireSyncRust2DartDco raw) =>
-      decodeObject(wireSyncRust2DartDcoIntoDart(raw));
+  S decodeWireSyncType(WireSyncRust2DartDco raw,
+          GeneralizedFrbRustBinding generalizedFrbRustBinding) =>
+      decodeObject(
+          wireSyncRust2DartDcoIntoDart(raw, generalizedFrbRustBinding));
 
   @override
   void freeWireSyncRust2Dart(WireSyncRust2DartDco raw,
@@ -0,0 +1,67 @@
+import 'dart:typed_data';
+
+/// Decompress data in the LZ4 block format, see
+/// https://github.com/lz4/lz4/blob/dev/doc/lz4_Block_format.md
+///
+/// The Rust side compresses with the `lz4_flex` crate.
+/// Malformed input throws a [FormatException] instead of reading or writing out of bounds.
+///
+/// {@macro flutter_rust_bridge.internal}
+Uint8List lz4Decompress(Uint8List input, int decompressedLength) {
+  final output = Uint8List(decompressedLength);
+  var inputPos = 0;
+  var outputPos = 0;
+
+  Never fail(String reason) =>
+      throw FormatException('lz4Decompress: $reason', input, inputPos);
+
+  int readByte() {
+    if (inputPos >= input.length) fail('unexpected end of input');
+    return input[inputPos++];
+  }
+
+  int readExtraLength(int length) {
+    while (true) {
+      final byte = readByte();
+      length += byte;
+      if (byte != 255) return length;
+    }
+  }
+
+  while (true) {
+    final token = readByte();
+
+    var literalLength = token >> 4;
+    if (literalLength == 15) literalLength = readExtraLength(literalLength);
+    if (inputPos + literalLength > input.length ||
+        outputPos + literalLength > decompressedLength) {
+      fail('literals out of bounds');
+    }
+    output.setRange(outputPos, outputPos + literalLength, input, inputPos);
+    inputPos += literalLength;
+    outputPos += literalLength;
+
+    if (inputPos == input.length) break;
+
+    final offset = readByte() | (readByte() << 8);
+    var matchLength = token & 0xF;
+    if (matchLength == 15) matchLength = readExtraLength(matchLength);
+    matchLength += 4;
+    if (offset == 0 || offset > outputPos) fail('invalid offset $offset');
+    if (outputPos + matchLength > decompressedLength) {
+      fail('match out of bounds');
+    }
+
+    // Byte by byte, since the match may overlap with itself
+    final matchStart = outputPos - offset;
+    for (var i = 0; i < matchLength; ++i) {
+      output[outputPos + i] = output[matchStart + i];
+    }
+    outputPos += matchLength;
+  }
+
+  if (outputPos != decompressedLength) {
+    fail('expects length=$decompressedLength but got $outputPos');
+  }
+  return output;
+}
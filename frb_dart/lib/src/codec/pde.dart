@@ -1,5 +1,8 @@
 import 'package:flutter_rust_bridge/src/codec/sse.dart';
 import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
+import 'package:flutter_rust_bridge/src/generalized_uint8list/generalized_uint8list.dart';
+import 'package:flutter_rust_bridge/src/misc/inspector.dart';
+import 'package:flutter_rust_bridge/src/misc/recorder.dart';
 import 'package:flutter_rust_bridge/src/platform_types/platform_types.dart';
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
@@ -9,9 +12,27 @@ WireSyncRust2DartSse? pdeCallFfi(
   required int funcId,
   NativePortType? port,
 }) {
+  final methodChannelTransport =
+      generalizedFrbRustBinding.methodChannelTransport;
+  if (methodChannelTransport != null) {
+    if (port == null) {
+      throw UnsupportedError(
+          'Synchronous functions are not supported by MethodChannelTransport');
+    }
+    final payload = serializer.buffer.intoBytes();
+    inspectRustRequest(payload.length);
+    recordRustRequest(funcId, () => payload);
+    methodChannelTransport.dispatch(
+        funcId: funcId, port: port, payload: payload);
+    return null;
+  }
+
   final raw = serializer.intoRaw();
+  inspectRustRequest(raw.dataLen);
 
   if (port != null) {
+    recordRustRequest(funcId,
+        () => platformGeneralizedUint8ListPtrAsView(raw.ptr, raw.dataLen));
     generalizedFrbRustBinding.pdeFfiDispatcherPrimary(
       funcId: funcId,
       port: port,
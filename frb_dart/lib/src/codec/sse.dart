@@ -2,8 +2,10 @@ import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/src/codec/base.dart';
 import 'package:flutter_rust_bridge/src/codec/dco.dart';
+import 'package:flutter_rust_bridge/src/codec/lz4.dart';
 import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
 import 'package:flutter_rust_bridge/src/manual_impl/manual_impl.dart';
+import 'package:flutter_rust_bridge/src/misc/lazy_field.dart';
 import 'package:flutter_rust_bridge/src/platform_types/platform_types.dart';
 import 'package:flutter_rust_bridge/src/platform_utils/platform_utils.dart';
 import 'package:flutter_rust_bridge/src/third_party/flutter_foundation_serialization/read_buffer.dart';
@@ -36,10 +38,12 @@ class SseCodec<S, E extends Object>
   }
 
   @override
-  S decodeWireSyncType(WireSyncRust2DartSse raw) =>
+  S decodeWireSyncType(WireSyncRust2DartSse raw,
+          GeneralizedFrbRustBinding generalizedFrbRustBinding) =>
       _decode(wireSyncRust2DartSseAsUint8ListView(raw));
 
   S _decode(Uint8List bytes) {
+    bytes = _maybeDecompress(bytes);
     final deserializer = SseDeserializer(bytes.buffer.asByteData());
     final action = deserializer.buffer.getUint8();
     final ans = _SseSimpleDecoder(this, deserializer).decode(action);
@@ -53,6 +57,16 @@ class SseCodec<S, E extends Object>
       generalizedFrbRustBinding.freeWireSyncRust2DartSse(raw);
 }
 
+/// NOTE: Please keep in sync with the Rust side
+const _compressedLz4Marker = 0xFF;
+
+Uint8List _maybeDecompress(Uint8List bytes) {
+  if (bytes.isEmpty || bytes[0] != _compressedLz4Marker) return bytes;
+  final decompressedLength =
+      ByteData.sublistView(bytes, 1, 5).getUint32(0, Endian.little);
+  return lz4Decompress(Uint8List.sublistView(bytes, 5), decompressedLength);
+}
+
 class _SseSimpleDecoder<S, E extends Object> extends SimpleDecoder<S, E> {
   final SseCodec<S, E> codec;
   final SseDeserializer deserializer;
@@ -99,6 +113,51 @@ class SseDeserializer {
   SseDeserializer(ByteData data) : buffer = ReadBuffer(data);
 }
 
+// NOTE: Please keep the list and optional helpers in sync with the Rust side,
+// which encodes the length as i32 and the existence as bool.
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+void sseEncodeList<T>(List<T> self, SseSerializer serializer,
+    void Function(T, SseSerializer) encodeItem) {
+  serializer.buffer.putInt32(self.length);
+  for (final item in self) {
+    encodeItem(item, serializer);
+  }
+}
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+List<T> sseDecodeList<T>(
+    SseDeserializer deserializer, T Function(SseDeserializer) decodeItem) {
+  final len = deserializer.buffer.getInt32();
+  return [for (var i = 0; i < len; ++i) decodeItem(deserializer)];
+}
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+void sseEncodeOptional<T>(T? self, SseSerializer serializer,
+    void Function(T, SseSerializer) encodeInner) {
+  serializer.buffer.putUint8(self != null ? 1 : 0);
+  if (self != null) {
+    encodeInner(self, serializer);
+  }
+}
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+T? sseDecodeOptional<T>(
+    SseDeserializer deserializer, T Function(SseDeserializer) decodeInner) {
+  if (deserializer.buffer.getUint8() != 0) {
+    return decodeInner(deserializer);
+  } else {
+    return null;
+  }
+}
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+LazyField<T> sseDecodeLazyField<T>(
+    SseDeserializer deserializer, T Function(SseDeserializer) decodeInner) {
+  final len = deserializer.buffer.getInt32();
+  return LazyField.bytes(deserializer.buffer.getUint8List(len), decodeInner);
+}
+
 S _decodeObjectOfOtherType<S>(dynamic raw) {
   // Temporary workaround before Rust panic=unwind is implemented.
   // Then, when panic happens, the Rust side WorkerPool will use JavaScript
@@ -4,6 +4,7 @@ import 'dart:ffi';
 import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/src/ffigen_generated/multi_package.dart';
+import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/_io.dart';
 import 'package:meta/meta.dart';
 
 export 'dart:ffi' show NativePort, DynamicLibrary;
@@ -12,7 +13,8 @@ export 'dart:ffi' show NativePort, DynamicLibrary;
 /// The code is used only internally and is not a public API. The comment exists mainly to satisfy the linter.
 /// {@endtemplate}
 @internal
-dynamic dartCObjectIntoDart(Dart_CObject object) {
+dynamic dartCObjectIntoDart(
+    Dart_CObject object, GeneralizedFrbRustBinding generalizedFrbRustBinding) {
   switch (object.type) {
     case Dart_CObject_Type.Dart_CObject_kNull:
       return null;
@@ -39,9 +41,11 @@ dynamic dartCObjectIntoDart(Dart_CObject object) {
 
     case Dart_CObject_Type.Dart_CObject_kArray:
       return List.generate(
-          object.value.as_array.length, (i) => dartCObjectIntoDart(
+          object.value.as_array.length,
+          (i) => dartCObjectIntoDart(
               // ignore: deprecated_member_use
-              object.value.as_array.values.elementAt(i).value.ref));
+              object.value.as_array.values.elementAt(i).value.ref,
+              generalizedFrbRustBinding));
 
     case Dart_CObject_Type.Dart_CObject_kTypedData:
       return _typedDataIntoDart(
@@ -57,41 +61,13 @@ dynamic dartCObjectIntoDart(Dart_CObject object) {
         nValues: object.value.as_external_typed_data.length,
       );
 
-      // Copy the data
-      final ans = converted.clone();
-
-      // And immediately free the original data
-      final callback = object.value.as_external_typed_data.callback
-          .cast<ffi.NativeFunction<_NativeExternalTypedDataFinalizer>>()
-          .asFunction<_DartExternalTypedDataFinalizer>();
-      callback(
-        object.value.as_external_typed_data.length,
-        object.value.as_external_typed_data.peer,
+      // Zero-copy: the memory is owned by Rust, and is freed when Dart no longer uses it
+      generalizedFrbRustBinding.attachExternalTypedDataFinalizer(
+        converted.nativeData,
+        peer: object.value.as_external_typed_data.peer,
+        callback: object.value.as_external_typed_data.callback.cast(),
       );
-
-      return ans;
-
-    // The commented approach enables zero-copy, but it does not tell
-    // Dart VM the external object size, thus Dart VM may choose to GC too
-    // sparsely.
-    //
-    // Later when the current non-zerocopy approach is too slow and we want to
-    // further optimize, we can use the `NativeFinalizer` and tweak a few pointers
-    // and maybe add an adapter function.
-    //
-    // _externalTypedDataFinalizer.attach(
-    //   externalTypedData,
-    //   // Copy the cleanup info into a finalization token:
-    //   // `value`'s underlying memory will probably be freed
-    //   // before the zero-copy finalizer is called.
-    //   _ExternalTypedDataFinalizerArgs(
-    //     length: object.value.as_external_typed_data.length,
-    //     peer: object.value.as_external_typed_data.peer,
-    //     callback: object.value.as_external_typed_data.callback
-    //         .cast<ffi.NativeFunction<_NativeExternalTypedDataFinalizer>>(),
-    //   ),
-    // );
-    // return externalTypedData;
+      return converted.view;
 
     case Dart_CObject_Type.Dart_CObject_kSendPort:
     case Dart_CObject_Type.Dart_CObject_kCapability:
@@ -112,13 +88,13 @@ _TypedData _typedDataIntoDart({
 }) {
   switch (ty) {
     case Dart_TypedData_Type.Dart_TypedData_kByteData:
+      final data = typedValues.cast<ffi.Uint8>().asTypedList(nValues);
       return _TypedData<ByteData>(
-        ByteData.view(
-          typedValues.cast<ffi.Uint8>().asTypedList(nValues).buffer,
-        ),
+        ByteData.view(data.buffer),
         (view) => ByteData.view(
           Uint8List.fromList(view.buffer.asUint8List()).buffer,
         ),
+        nativeData: data,
       );
     case Dart_TypedData_Type.Dart_TypedData_kInt8:
       final view = typedValues.cast<ffi.Int8>().asTypedList(nValues);
@@ -165,37 +141,11 @@ class _TypedData<T> {
   final T view;
   final T Function(T) _cloneView;
 
-  _TypedData(this.view, this._cloneView);
+  /// The list directly created from the native memory, which is kept alive by [view] and all views derived from it
+  final TypedData nativeData;
+
+  _TypedData(this.view, this._cloneView, {TypedData? nativeData})
+      : nativeData = nativeData ?? view as TypedData;
 
   T clone() => _cloneView(view);
 }
-
-// final _externalTypedDataFinalizer =
-//     Finalizer<_ExternalTypedDataFinalizerArgs>((externalTypedData) {
-//   final handleFinalizer =
-//       externalTypedData.callback.asFunction<_DartExternalTypedDataFinalizer>();
-//   handleFinalizer(externalTypedData.length, externalTypedData.peer);
-//
-//   debugOnExternalTypedDataFinalizer?.call(externalTypedData.length);
-// });
-//
-// /// {@macro flutter_rust_bridge.internal}
-// void Function(int dataLength)? debugOnExternalTypedDataFinalizer;
-//
-// class _ExternalTypedDataFinalizerArgs {
-//   final int length;
-//   final ffi.Pointer<ffi.Void> peer;
-//   final ffi.Pointer<ffi.NativeFunction<_NativeExternalTypedDataFinalizer>>
-//       callback;
-//
-//   _ExternalTypedDataFinalizerArgs({
-//     required this.length,
-//     required this.peer,
-//     required this.callback,
-//   });
-// }
-
-typedef _NativeExternalTypedDataFinalizer = ffi.Void Function(
-    ffi.IntPtr, ffi.Pointer<ffi.Void>);
-typedef _DartExternalTypedDataFinalizer = void Function(
-    int, ffi.Pointer<ffi.Void>);
@@ -66,3 +66,25 @@ class UnmodifiableTypedListException implements FrbException {
   @override
   String toString() => _message;
 }
+
+/// A large return value transferred from Rust in chunks is invalid,
+/// e.g. it declares a size above `maxChunkedTransferBytes`.
+class ChunkedTransferException implements FrbException {
+  /// The error message
+  final String message;
+
+  /// Constructs an exception
+  const ChunkedTransferException(this.message);
+
+  @override
+  String toString() => 'ChunkedTransferException($message)';
+}
+
+/// A call is cancelled because its `RustScope` is disposed before it completes.
+class RustScopeCancelledException implements FrbException {
+  /// Constructs an exception
+  const RustScopeCancelledException();
+
+  @override
+  String toString() => 'RustScopeCancelledException';
+}
@@ -72,3 +72,25 @@ void free_wire_sync_rust2dart_dco(WireSyncRust2DartDco value);
  * This function should never be called manually.
  */
 void free_wire_sync_rust2dart_sse(struct WireSyncRust2DartSse value);
+
+/**
+ * Cancels the running Rust function which reports to the given port, e.g. when a `RustScope` is disposed.
+ */
+void frb_cancel_task(MessagePort port);
+
+/**
+ * Dart's `NativeFinalizer` only passes one pointer to the native callback,
+ * thus the zero-copy buffers received in sync mode bundle the arguments of their free function here.
+ *
+ * # Safety
+ *
+ * This function should never be called manually.
+ */
+void *frb_external_typed_data_finalizer_token_new(void *peer, void *callback);
+
+/**
+ * # Safety
+ *
+ * This function should never be called manually.
+ */
+void frb_external_typed_data_finalize(void *token);
@@ -447,6 +447,64 @@ class MultiPackageCBinding {
           'free_wire_sync_rust2dart_sse');
   late final _free_wire_sync_rust2dart_sse = _free_wire_sync_rust2dart_ssePtr
       .asFunction<void Function(WireSyncRust2DartSse)>();
+
+  /// Cancels the running Rust function which reports to the given port, e.g. when a `RustScope` is disposed.
+  void frb_cancel_task(
+    int port,
+  ) {
+    return _frb_cancel_task(
+      port,
+    );
+  }
+
+  late final _frb_cancel_taskPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(MessagePort)>>(
+          'frb_cancel_task');
+  late final _frb_cancel_task =
+      _frb_cancel_taskPtr.asFunction<void Function(int)>();
+
+  /// Dart's `NativeFinalizer` only passes one pointer to the native callback,
+  /// thus the zero-copy buffers received in sync mode bundle the arguments of their free function here.
+  ///
+  /// # Safety
+  ///
+  /// This function should never be called manually.
+  ffi.Pointer<ffi.Void> frb_external_typed_data_finalizer_token_new(
+    ffi.Pointer<ffi.Void> peer,
+    ffi.Pointer<ffi.Void> callback,
+  ) {
+    return _frb_external_typed_data_finalizer_token_new(
+      peer,
+      callback,
+    );
+  }
+
+  late final _frb_external_typed_data_finalizer_token_newPtr = _lookup<
+      ffi.NativeFunction<
+          ffi.Pointer<ffi.Void> Function(ffi.Pointer<ffi.Void>,
+              ffi.Pointer<ffi.Void>)>>('frb_external_typed_data_finalizer_token_new');
+  late final _frb_external_typed_data_finalizer_token_new =
+      _frb_external_typed_data_finalizer_token_newPtr.asFunction<
+          ffi.Pointer<ffi.Void> Function(
+              ffi.Pointer<ffi.Void>, ffi.Pointer<ffi.Void>)>();
+
+  /// # Safety
+  ///
+  /// This function should never be called manually.
+  void frb_external_typed_data_finalize(
+    ffi.Pointer<ffi.Void> token,
+  ) {
+    return _frb_external_typed_data_finalize(
+      token,
+    );
+  }
+
+  late final _frb_external_typed_data_finalizePtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<ffi.Void>)>>(
+          'frb_external_typed_data_finalize');
+  late final _frb_external_typed_data_finalize =
+      _frb_external_typed_data_finalizePtr
+          .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 }
 
 /// A Dart_CObject is used for representing Dart objects as native C
@@ -1,22 +1,69 @@
 import 'dart:ffi' as ffi;
+import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/src/ffigen_generated/multi_package.dart';
 import 'package:flutter_rust_bridge/src/generalized_uint8list/generalized_uint8list.dart';
+import 'package:flutter_rust_bridge/src/method_channel/transport.dart';
 import 'package:flutter_rust_bridge/src/platform_types/_io.dart';
 import 'package:flutter_rust_bridge/src/platform_types/platform_types.dart';
+import 'package:meta/meta.dart';
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
 class GeneralizedFrbRustBinding {
-  final MultiPackageCBinding _binding;
+  final MultiPackageCBinding? _ffiBinding;
+  final ffi.DynamicLibrary? _dynamicLibrary;
   final String _externalLibraryDebugInfo;
 
+  /// Non-null when Rust is called via the host platform instead of FFI.
+  final MethodChannelTransport? methodChannelTransport;
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  GeneralizedFrbRustBinding(ExternalLibrary externalLibrary)
-      : _binding = MultiPackageCBinding(externalLibrary.ffiDynamicLibrary),
-        _externalLibraryDebugInfo = externalLibrary.debugInfo;
+  GeneralizedFrbRustBinding(ExternalLibrary externalLibrary,
+      {String exportedSymbolPrefix = '',
+      Set<String> exportedSymbols = const {}})
+      : _ffiBinding = exportedSymbolPrefix.isEmpty
+            ? MultiPackageCBinding(externalLibrary.ffiDynamicLibrary)
+            : MultiPackageCBinding.fromLookup(_createPrefixedLookup(
+                externalLibrary.ffiDynamicLibrary,
+                exportedSymbolPrefix,
+                exportedSymbols)),
+        _dynamicLibrary = externalLibrary.ffiDynamicLibrary,
+        _externalLibraryDebugInfo = externalLibrary.debugInfo,
+        methodChannelTransport = null;
+
+  /// {@macro flutter_rust_bridge.internal}
+  GeneralizedFrbRustBinding.methodChannel(MethodChannelTransport transport)
+      : _ffiBinding = null,
+        _dynamicLibrary = null,
+        _externalLibraryDebugInfo = '(method channel)',
+        methodChannelTransport = transport;
+
+  static ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _createPrefixedLookup(ffi.DynamicLibrary dynamicLibrary,
+          String exportedSymbolPrefix, Set<String> exportedSymbols) {
+    return <T extends ffi.NativeType>(String symbolName) =>
+        dynamicLibrary.lookup<T>(computeSymbolName(
+            symbolName, exportedSymbolPrefix, exportedSymbols));
+  }
+
+  /// The name of [symbolName] in the dynamic library. Only [exportedSymbols],
+  /// i.e. the ones exported by each generated `frb_generated.rs`, are prefixed,
+  /// while the other ones come from the runtime and are shared.
+  @visibleForTesting
+  static String computeSymbolName(String symbolName,
+          String exportedSymbolPrefix, Set<String> exportedSymbols) =>
+      exportedSymbols.contains(symbolName)
+          ? '$exportedSymbolPrefix$symbolName'
+          : symbolName;
+
+  MultiPackageCBinding get _binding =>
+      _ffiBinding ??
+      (throw UnsupportedError(
+          'This operation is not supported by MethodChannelTransport'));
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void storeDartPostCObject() {
+    if (methodChannelTransport != null) return;
     try {
       _binding.store_dart_post_cobject(ffi.NativeApi.postCObject.cast());
     } on ArgumentError catch (e, s) {
@@ -26,8 +73,10 @@ class GeneralizedFrbRustBinding {
   }
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  void initFrbDartApiDl() =>
-      _binding.init_frb_dart_api_dl(ffi.NativeApi.initializeApiDLData);
+  void initFrbDartApiDl() {
+    if (methodChannelTransport != null) return;
+    _binding.init_frb_dart_api_dl(ffi.NativeApi.initializeApiDLData);
+  }
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void pdeFfiDispatcherPrimary({
@@ -85,6 +134,15 @@ class GeneralizedFrbRustBinding {
   void dartOpaqueDropThreadBoxPersistentHandle(int ptr) =>
       _binding.dart_opaque_drop_thread_box_persistent_handle(ptr);
 
+  /// Asks the Rust function reporting to [port] to stop, see `RustScope`.
+  ///
+  /// {@macro flutter_rust_bridge.internal}
+  void cancelTask(NativePortType port) {
+    // The ports of the transport are not known by the Rust side
+    if (methodChannelTransport != null) return;
+    _binding.frb_cancel_task(port);
+  }
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void freeWireSyncRust2DartDco(WireSyncRust2DartDco val) =>
       _binding.free_wire_sync_rust2dart_dco(val);
@@ -93,6 +151,25 @@ class GeneralizedFrbRustBinding {
   void freeWireSyncRust2DartSse(WireSyncRust2DartSse val) =>
       _binding.free_wire_sync_rust2dart_sse(val);
 
+  /// Let the Rust-owned memory behind [data] be freed by [callback]
+  /// when [data] is garbage collected, instead of copying it.
+  ///
+  /// {@macro flutter_rust_bridge.internal}
+  void attachExternalTypedDataFinalizer(
+    TypedData data, {
+    required ffi.Pointer<ffi.Void> peer,
+    required ffi.Pointer<ffi.Void> callback,
+  }) {
+    final token =
+        _binding.frb_external_typed_data_finalizer_token_new(peer, callback);
+    // Tell the Dart VM about the size, otherwise it may GC too sparsely
+    _externalTypedDataFinalizer.attach(data, token,
+        externalSize: data.lengthInBytes);
+  }
+
+  late final _externalTypedDataFinalizer = ffi.NativeFinalizer(_dynamicLibrary!
+      .lookup<ffi.NativeFinalizerFunction>('frb_external_typed_data_finalize'));
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   ffi.Pointer<ffi.Uint8> rustVecU8New(int len) => _binding.rust_vec_u8_new(len);
 
@@ -3,12 +3,27 @@
 import 'dart:js_interop';
 
 import 'package:flutter_rust_bridge/src/generalized_uint8list/generalized_uint8list.dart';
+import 'package:flutter_rust_bridge/src/method_channel/transport.dart';
 import 'package:flutter_rust_bridge/src/platform_types/_web.dart';
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
 class GeneralizedFrbRustBinding {
   /// {@macro flutter_rust_bridge.only_for_generated_code}
-  GeneralizedFrbRustBinding(ExternalLibrary externalLibrary);
+  GeneralizedFrbRustBinding(ExternalLibrary externalLibrary,
+      {String exportedSymbolPrefix = '',
+      Set<String> exportedSymbols = const {}}) {
+    if (exportedSymbolPrefix.isNotEmpty) {
+      throw UnsupportedError('API versions are not supported on web yet');
+    }
+  }
+
+  /// {@macro flutter_rust_bridge.internal}
+  GeneralizedFrbRustBinding.methodChannel(MethodChannelTransport transport) {
+    throw UnsupportedError('MethodChannelTransport is not supported on web');
+  }
+
+  /// Always null, since the web does not support [MethodChannelTransport].
+  MethodChannelTransport? get methodChannelTransport => null;
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void storeDartPostCObject() {}
@@ -63,6 +78,11 @@ class GeneralizedFrbRustBinding {
   void dartOpaqueDropThreadBoxPersistentHandle(int ptr) =>
       _dart_opaque_drop_thread_box_persistent_handle(ptr);
 
+  /// Does nothing, since the ports cannot be identified on the Rust side of the web.
+  ///
+  /// {@macro flutter_rust_bridge.internal}
+  void cancelTask(NativePortType port) {}
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   void freeWireSyncRust2DartDco(WireSyncRust2DartDco raw) {}
 
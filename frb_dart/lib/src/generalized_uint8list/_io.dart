@@ -1,4 +1,5 @@
 import 'dart:ffi' as ffi;
+import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/src/generalized_uint8list/rust_vec_u8.dart';
 
@@ -7,3 +8,8 @@ typedef PlatformGeneralizedUint8List = RustVecU8;
 
 /// {@macro flutter_rust_bridge.internal}
 typedef PlatformGeneralizedUint8ListPtr = ffi.Pointer<ffi.Uint8>;
+
+/// {@macro flutter_rust_bridge.internal}
+Uint8List platformGeneralizedUint8ListPtrAsView(
+        PlatformGeneralizedUint8ListPtr ptr, int length) =>
+    ptr.asTypedList(length);
@@ -1,4 +1,5 @@
 import 'dart:js_interop';
+import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/src/generalized_uint8list/adapted_uint8list.dart';
 
@@ -7,3 +8,8 @@ typedef PlatformGeneralizedUint8List = AdaptedUint8List;
 
 /// {@macro flutter_rust_bridge.internal}
 typedef PlatformGeneralizedUint8ListPtr = JSUint8Array;
+
+/// {@macro flutter_rust_bridge.internal}
+Uint8List platformGeneralizedUint8ListPtrAsView(
+        PlatformGeneralizedUint8ListPtr ptr, int length) =>
+    Uint8List.sublistView(ptr.toDart, 0, length);
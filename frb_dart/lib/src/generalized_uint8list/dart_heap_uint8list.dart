@@ -0,0 +1,35 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/generalized_uint8list/generalized_uint8list.dart';
+
+/// A [Uint8List] on the Dart heap, used when the bytes are not handed to Rust via FFI.
+class DartHeapUint8List implements BaseGeneralizedUint8List<Uint8List> {
+  Uint8List _inner;
+
+  /// {@macro flutter_rust_bridge.internal}
+  DartHeapUint8List(int length) : _inner = Uint8List(length);
+
+  @override
+  int get length => _inner.length;
+
+  @override
+  void operator []=(int index, int value) => _inner[index] = value;
+
+  @override
+  void dispose() {}
+
+  @override
+  BaseGeneralizedUint8ListRaw<Uint8List> intoRaw() =>
+      (ptr: _inner, length: _inner.length);
+
+  @override
+  void resize(int newLen) {
+    final old = _inner;
+    _inner = Uint8List(newLen);
+    _inner.setRange(0, old.length, old);
+  }
+
+  @override
+  void setRange(int start, int end, Uint8List data) =>
+      _inner.setRange(start, end, data);
+}
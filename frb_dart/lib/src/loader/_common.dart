@@ -1,3 +1,11 @@
+import 'dart:async';
+
+import 'package:flutter_rust_bridge/src/platform_types/platform_types.dart';
+
+/// Loads the [ExternalLibrary] according to the config, e.g. to replace `loadExternalLibrary`
+typedef ExternalLibraryLoader = FutureOr<ExternalLibrary> Function(
+    ExternalLibraryLoaderConfig config);
+
 /// Arguments of `loadExternalLibary`
 class ExternalLibraryLoaderConfig {
   /// The file stem
@@ -9,10 +17,43 @@ class ExternalLibraryLoaderConfig {
   /// In Web, the prefix path for the wasm
   final String? webPrefix;
 
+  /// In io (native), the file stem for some operating systems (keyed by `Platform.operatingSystem`),
+  /// overriding [stem]
+  final Map<String, String> ioStems;
+
+  /// In io (native), the explicit path of the dynamic library for some operating systems
+  /// (keyed by `Platform.operatingSystem`), which is opened directly without searching
+  final Map<String, String> ioPaths;
+
+  /// In io (native), the operating systems (as in `Platform.operatingSystem`) where the Rust code
+  /// is linked into the executable, thus the symbols are looked up via `DynamicLibrary.process()`
+  final List<String> ioProcess;
+
   /// Arguments of `loadExternalLibary`
   const ExternalLibraryLoaderConfig({
     required this.stem,
     required this.ioDirectory,
     required this.webPrefix,
+    this.ioStems = const {},
+    this.ioPaths = const {},
+    this.ioProcess = const [],
   });
+
+  /// Copy the config with some of the fields replaced
+  ExternalLibraryLoaderConfig copyWith({
+    String? stem,
+    String? ioDirectory,
+    String? webPrefix,
+    Map<String, String>? ioStems,
+    Map<String, String>? ioPaths,
+    List<String>? ioProcess,
+  }) =>
+      ExternalLibraryLoaderConfig(
+        stem: stem ?? this.stem,
+        ioDirectory: ioDirectory ?? this.ioDirectory,
+        webPrefix: webPrefix ?? this.webPrefix,
+        ioStems: ioStems ?? this.ioStems,
+        ioPaths: ioPaths ?? this.ioPaths,
+        ioProcess: ioProcess ?? this.ioProcess,
+      );
 }
@@ -8,13 +8,28 @@ import 'package:flutter_rust_bridge/src/platform_types/_io.dart';
 /// 1. When `flutter run`, or when a real app is bundled.
 /// 2. When running Flutter widget tests.
 /// 3. When `dart test`, `dart run`, `dart compile exe`, etc.
+///
+/// The `ioProcess`, `ioPaths` and `ioStems` of the config, when given for the current
+/// operating system, take precedence over the default search.
 FutureOr<ExternalLibrary> loadExternalLibrary(
     ExternalLibraryLoaderConfig config) async {
+  final operatingSystem = Platform.operatingSystem;
+
+  if (config.ioProcess.contains(operatingSystem)) {
+    return ExternalLibrary.process(
+        iKnowHowToUseIt: true, debugInfo: ' (by ioProcess)');
+  }
+
+  final path = config.ioPaths[operatingSystem];
+  if (path != null) {
+    return ExternalLibrary.open(path, debugInfo: ' (by ioPaths)');
+  }
+
   final ioDirectory = config.ioDirectory;
   return loadExternalLibraryRaw(
     nativeLibDirWhenNonPackaged:
         ioDirectory == null ? null : Directory.current.uri.resolve(ioDirectory),
-    stem: config.stem,
+    stem: config.ioStems[operatingSystem] ?? config.stem,
   );
 }
 
@@ -98,3 +113,8 @@ extension on String {
   Uri toUriDirectory() => Uri.directory(this);
 }
 // coverage:ignore-end
+
+/// {@macro flutter_rust_bridge.internal}
+/// Used when Rust is not called via FFI, e.g. with `MethodChannelHandler`.
+ExternalLibrary placeholderExternalLibrary() =>
+    ExternalLibrary.process(iKnowHowToUseIt: true, debugInfo: ' (placeholder)');
@@ -17,3 +17,7 @@ Future<ExternalLibrary> loadExternalLibraryRaw(
   await initializeWasmModule(root: moduleRoot);
   return ExternalLibrary(debugInfo: 'moduleRoot=$moduleRoot');
 }
+
+/// {@macro flutter_rust_bridge.internal}
+ExternalLibrary placeholderExternalLibrary() =>
+    const ExternalLibrary(debugInfo: '(placeholder)');
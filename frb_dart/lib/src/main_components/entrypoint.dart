@@ -6,6 +6,7 @@ import 'package:flutter_rust_bridge/src/main_components/api_impl.dart';
 import 'package:flutter_rust_bridge/src/main_components/handler.dart';
 import 'package:flutter_rust_bridge/src/main_components/port_manager.dart';
 import 'package:flutter_rust_bridge/src/main_components/wire.dart';
+import 'package:flutter_rust_bridge/src/method_channel/handler.dart';
 import 'package:flutter_rust_bridge/src/misc/version.dart';
 import 'package:flutter_rust_bridge/src/platform_types/platform_types.dart';
 import 'package:meta/meta.dart';
@@ -38,6 +39,8 @@ abstract class BaseEntrypoint<A extends BaseApi, AI extends BaseApiImpl,
     A? api,
     BaseHandler? handler,
     ExternalLibrary? externalLibrary,
+    ExternalLibraryLoaderConfig? externalLibraryLoaderConfig,
+    ExternalLibraryLoader? externalLibraryLoader,
   }) async {
     if (__state != null) {
       throw StateError('Should not initialize flutter_rust_bridge twice');
@@ -45,11 +48,21 @@ abstract class BaseEntrypoint<A extends BaseApi, AI extends BaseApiImpl,
 
     _sanityCheckCodegenVersion();
 
-    externalLibrary ??= await _loadDefaultExternalLibrary();
+    final GeneralizedFrbRustBinding generalizedFrbRustBinding;
+    if (handler is MethodChannelHandler) {
+      // Rust is not loaded into this process, thus nothing to check via FFI
+      externalLibrary ??= placeholderExternalLibrary();
+      generalizedFrbRustBinding =
+          GeneralizedFrbRustBinding.methodChannel(handler.transport);
+    } else {
+      externalLibrary ??= await (externalLibraryLoader ?? loadExternalLibrary)(
+          externalLibraryLoaderConfig ?? defaultExternalLibraryLoaderConfig);
+      generalizedFrbRustBinding = GeneralizedFrbRustBinding(externalLibrary,
+          exportedSymbolPrefix: exportedSymbolPrefix,
+          exportedSymbols: exportedSymbols);
+      _sanityCheckContentHash(generalizedFrbRustBinding);
+    }
     handler ??= BaseHandler();
-    final generalizedFrbRustBinding =
-        GeneralizedFrbRustBinding(externalLibrary);
-    _sanityCheckContentHash(generalizedFrbRustBinding);
     final portManager = PortManager(generalizedFrbRustBinding, handler);
     api ??= _createDefaultApi(
         handler, generalizedFrbRustBinding, portManager, externalLibrary);
@@ -133,13 +146,19 @@ abstract class BaseEntrypoint<A extends BaseApi, AI extends BaseApiImpl,
   @protected
   int get rustContentHash;
 
+  /// Prefix of the symbols exported by the generated Rust code,
+  /// which is non-empty when multiple API versions live in the same library.
+  @protected
+  String get exportedSymbolPrefix => '';
+
+  /// The symbols which are prefixed by [exportedSymbolPrefix].
+  @protected
+  Set<String> get exportedSymbols => const {};
+
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   @protected
   Future<void> executeRustInitializers();
 
-  Future<ExternalLibrary> _loadDefaultExternalLibrary() async =>
-      await loadExternalLibrary(defaultExternalLibraryLoaderConfig);
-
   A _createDefaultApi(
     BaseHandler handler,
     GeneralizedFrbRustBinding generalizedFrbRustBinding,
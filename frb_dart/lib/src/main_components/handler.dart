@@ -4,26 +4,44 @@ import 'package:flutter_rust_bridge/src/dart_opaque/dart_opaque.dart';
 import 'package:flutter_rust_bridge/src/exceptions.dart';
 import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
 import 'package:flutter_rust_bridge/src/generalized_isolate/generalized_isolate.dart';
+import 'package:flutter_rust_bridge/src/misc/inspector.dart';
+import 'package:flutter_rust_bridge/src/misc/recorder.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_scope.dart';
+import 'package:flutter_rust_bridge/src/misc/transfer_progress.dart';
 import 'package:flutter_rust_bridge/src/task.dart';
-import 'package:flutter_rust_bridge/src/utils/single_complete_port.dart';
+import 'package:flutter_rust_bridge/src/utils/chunked_complete_port.dart';
 
 /// Generically handles a Dart-Rust call.
 class BaseHandler {
   /// Execute a normal ffi call. Usually called by generated code instead of manually called.
-  Future<S> executeNormal<S, E extends Object>(NormalTask<S, E> task) {
-    final completer = Completer<dynamic>();
-    final SendPort sendPort = singleCompletePort(completer);
-    task.callFfi(sendPort.nativePort);
-    return completer.future.then(task.codec.decodeObject);
-  }
+  Future<S> executeNormal<S, E extends Object>(NormalTask<S, E> task) =>
+      trackRustCall((tracked) {
+        final call = inspectRustCall(task, isSync: false);
+        final recorded = recordRustCall(task);
+        final completer = Completer<dynamic>();
+        final SendPort sendPort = chunkedCompletePort(completer,
+            onProgress: currentTransferProgressCallback);
+        tracked?.cancel = () => task.apiImpl.generalizedFrbRustBinding
+            .cancelTask(sendPort.nativePort);
+        recordRustFfi(
+            recorded,
+            () => inspectRustFfi(
+                call, () => task.callFfi(sendPort.nativePort)));
+        return inspectRustResult(
+            call,
+            recordRustResponse(recorded, completer.future),
+            task.codec.decodeObject);
+      });
 
   /// Similar to [executeNormal], except that this will return synchronously
   S executeSync<S, E extends Object, WireSyncType>(
       SyncTask<S, E, WireSyncType> task) {
+    final call = inspectRustCall(task, isSync: true);
     final WireSyncType syncReturn;
     try {
-      syncReturn = task.callFfi();
+      syncReturn = inspectRustFfi(call, task.callFfi);
     } catch (e, s) {
+      call?.done(e);
       if (e is FrbException) rethrow;
       // When in Web, because Rust only support `abort` (and not `unwind`)
       // we will get `JSObject0:<RuntimeError: unreachable>`.
@@ -31,7 +49,13 @@ class BaseHandler {
       throw PanicException('EXECUTE_SYNC_ABORT $e $s');
     }
     try {
-      return task.codec.decodeWireSyncType(syncReturn);
+      final ans = task.codec.decodeWireSyncType(
+          syncReturn, task.apiImpl.generalizedFrbRustBinding);
+      call?.done();
+      return ans;
+    } catch (e) {
+      call?.done(e);
+      rethrow;
     } finally {
       task.codec.freeWireSyncRust2Dart(
           syncReturn, task.apiImpl.generalizedFrbRustBinding);
@@ -1,6 +1,7 @@
 import 'dart:ffi';
 
 import 'package:flutter_rust_bridge/src/dart_c_object_into_dart/_io.dart';
+import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/_io.dart';
 import 'package:flutter_rust_bridge/src/platform_types/_io.dart';
 import 'package:flutter_rust_bridge/src/generalized_typed_data/_io.dart';
 import 'dart:typed_data' as $data;
@@ -8,8 +9,9 @@ import 'dart:typed_data' as $data;
 /// Generates the dynamic Dart object from either an FFI struct or a JS value
 ///
 /// {@macro flutter_rust_bridge.internal}
-List<dynamic> wireSyncRust2DartDcoIntoDart(WireSyncRust2DartDco syncReturn) =>
-    dartCObjectIntoDart(syncReturn.ref);
+List<dynamic> wireSyncRust2DartDcoIntoDart(WireSyncRust2DartDco syncReturn,
+        GeneralizedFrbRustBinding generalizedFrbRustBinding) =>
+    dartCObjectIntoDart(syncReturn.ref, generalizedFrbRustBinding);
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
 int dcoDecodeI64(int raw) => raw;
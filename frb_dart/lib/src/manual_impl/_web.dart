@@ -1,9 +1,11 @@
+import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/_web.dart';
 import 'package:flutter_rust_bridge/src/generalized_typed_data/_web.dart';
 import 'package:flutter_rust_bridge/src/platform_types/_web.dart';
 import 'package:flutter_rust_bridge/src/platform_utils/_web.dart';
 
 /// {@macro flutter_rust_bridge.internal}
-List<dynamic> wireSyncRust2DartDcoIntoDart(WireSyncRust2DartDco syncReturn) =>
+List<dynamic> wireSyncRust2DartDcoIntoDart(WireSyncRust2DartDco syncReturn,
+        GeneralizedFrbRustBinding generalizedFrbRustBinding) =>
     syncReturn;
 
 /// {@macro flutter_rust_bridge.only_for_generated_code}
@@ -0,0 +1,60 @@
+import 'dart:async';
+
+import 'package:flutter_rust_bridge/src/main_components/handler.dart';
+import 'package:flutter_rust_bridge/src/method_channel/transport.dart';
+import 'package:flutter_rust_bridge/src/misc/inspector.dart';
+import 'package:flutter_rust_bridge/src/misc/recorder.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_scope.dart';
+import 'package:flutter_rust_bridge/src/misc/transfer_progress.dart';
+import 'package:flutter_rust_bridge/src/task.dart';
+import 'package:flutter_rust_bridge/src/utils/chunked_complete_port.dart';
+
+/// Executes the calls via a [MethodChannelTransport] instead of FFI.
+///
+/// Only asynchronous functions using the SSE codec are supported.
+class MethodChannelHandler extends BaseHandler {
+  /// {@macro flutter_rust_bridge.internal}
+  final MethodChannelTransport transport;
+
+  /// Create the handler.
+  MethodChannelHandler(this.transport);
+
+  @override
+  Future<S> executeNormal<S, E extends Object>(NormalTask<S, E> task) =>
+      // Not cancelled on the Rust side, since it does not know the ports of the transport
+      trackRustCall((_) {
+        final call = inspectRustCall(task, isSync: false);
+        final recorded = recordRustCall(task);
+        final completer = Completer<dynamic>();
+        late final int port;
+        port = transport.openPort(chunkedMessageHandler(
+          (message) {
+            transport.closePort(port);
+            completer.complete(message);
+          },
+          onProgress: currentTransferProgressCallback,
+        ), onError: completer.completeError);
+        try {
+          // The port is an `int` here, even though `NativePortType` is not on web
+          recordRustFfi(
+              recorded,
+              () => inspectRustFfi(
+                  call, () => task.callFfi(port as dynamic)));
+        } catch (e) {
+          call?.done(e);
+          transport.closePort(port);
+          rethrow;
+        }
+        return inspectRustResult(
+            call,
+            recordRustResponse(recorded, completer.future),
+            task.codec.decodeObject);
+      });
+
+  @override
+  S executeSync<S, E extends Object, WireSyncType>(
+      SyncTask<S, E, WireSyncType> task) {
+    throw UnsupportedError(
+        'Synchronous functions are not supported by MethodChannelHandler');
+  }
+}
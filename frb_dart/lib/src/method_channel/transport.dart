@@ -0,0 +1,186 @@
+import 'dart:async';
+import 'dart:convert';
+import 'dart:typed_data';
+
+import 'package:meta/meta.dart';
+
+/// Sends a message to the host platform, which should pass it to the Rust
+/// `frb_method_channel_dispatch` function.
+///
+/// For example, `(message) => ServicesBinding.instance.defaultBinaryMessenger.send('my_channel', message)`.
+typedef MethodChannelSend = FutureOr<void> Function(ByteData message);
+
+/// Relays calls between Dart and Rust via the host platform (e.g. Kotlin or Swift),
+/// instead of calling Rust via FFI directly.
+///
+/// Use it via [MethodChannelHandler], e.g. `RustLib.init(handler: MethodChannelHandler(transport))`.
+class MethodChannelTransport {
+  final MethodChannelSend _send;
+  final _handlers = <int, void Function(dynamic)>{};
+  final _errorHandlers = <int, void Function(Object, StackTrace)>{};
+
+  // Ports of the Dart VM are always positive, thus negative ones never collide with them
+  var _nextPort = -1;
+
+  /// Create the transport.
+  MethodChannelTransport({required MethodChannelSend send}) : _send = send;
+
+  /// Should be called with every message the host platform receives from
+  /// the callback registered by `frb_method_channel_set_post_callback`.
+  void handleMessageFromRust(ByteData message) {
+    final port = message.getInt64(0, Endian.little);
+    final handler = _handlers[port];
+    if (handler == null) {
+      throw StateError('Received message for unknown port $port');
+    }
+    final (value, _) = _decodeValue(message, 8);
+    handler(value);
+  }
+
+  /// {@macro flutter_rust_bridge.internal}
+  ///
+  /// [onError] is called if the host fails to deliver a request, e.g. when
+  /// `frb_method_channel_dispatch` rejects a malformed one.
+  int openPort(void Function(dynamic message) handler,
+      {void Function(Object error, StackTrace stackTrace)? onError}) {
+    final port = _nextPort--;
+    _handlers[port] = handler;
+    if (onError != null) _errorHandlers[port] = onError;
+    return port;
+  }
+
+  /// {@macro flutter_rust_bridge.internal}
+  void closePort(int port) {
+    _handlers.remove(port);
+    _errorHandlers.remove(port);
+  }
+
+  /// {@macro flutter_rust_bridge.internal}
+  void dispatch(
+      {required int funcId, required Object port, required Uint8List payload}) {
+    // NOTE: Please keep in sync with the Rust side
+    final message = ByteData(12 + payload.length)
+      ..setInt32(0, funcId, Endian.little)
+      ..setInt64(4, port as int, Endian.little);
+    message.buffer.asUint8List(12).setAll(0, payload);
+    Future.sync(() => _send(message)).catchError((Object error, StackTrace st) {
+      final onError = _errorHandlers[port];
+      closePort(port);
+      onError?.call(error, st);
+    });
+  }
+}
+
+// NOTE: Please keep in sync with the Rust side
+const _tagNull = 0;
+const _tagFalse = 1;
+const _tagTrue = 2;
+const _tagInt = 3;
+const _tagDouble = 4;
+const _tagString = 5;
+const _tagUint8List = 6;
+const _tagList = 7;
+
+/// Decodes a value encoded by Rust for the method channel.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+dynamic decodeMethodChannelValue(ByteData data) => _decodeValue(data, 0).$1;
+
+/// Encodes a value in the same format as Rust does for the method channel,
+/// thus e.g. recorded responses can be compared with the ones produced by Rust.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+Uint8List encodeMethodChannelValue(Object? value) {
+  final builder = BytesBuilder(copy: false);
+  _encodeValue(value, builder);
+  return builder.takeBytes();
+}
+
+void _encodeValue(Object? value, BytesBuilder builder) {
+  if (value == null) {
+    builder.addByte(_tagNull);
+  } else if (value is bool) {
+    builder.addByte(value ? _tagTrue : _tagFalse);
+  } else if (value is int) {
+    builder
+      ..addByte(_tagInt)
+      ..add((ByteData(8)..setInt64(0, value, Endian.little))
+          .buffer
+          .asUint8List());
+  } else if (value is double) {
+    builder
+      ..addByte(_tagDouble)
+      ..add((ByteData(8)..setFloat64(0, value, Endian.little))
+          .buffer
+          .asUint8List());
+  } else if (value is String) {
+    builder.addByte(_tagString);
+    _encodeBytes(utf8.encode(value), builder);
+  } else if (value is Uint8List) {
+    builder.addByte(_tagUint8List);
+    _encodeBytes(value, builder);
+  } else if (value is List) {
+    builder
+      ..addByte(_tagList)
+      ..add(_encodeUint32(value.length));
+    for (final item in value) {
+      _encodeValue(item, builder);
+    }
+  } else {
+    throw ArgumentError.value(
+        value, 'value', 'Cannot be encoded for the method channel');
+  }
+}
+
+void _encodeBytes(Uint8List bytes, BytesBuilder builder) => builder
+  ..add(_encodeUint32(bytes.length))
+  ..add(bytes);
+
+Uint8List _encodeUint32(int value) =>
+    (ByteData(4)..setUint32(0, value, Endian.little)).buffer.asUint8List();
+
+(dynamic, int) _decodeValue(ByteData data, int offset) {
+  final tag = data.getUint8(offset);
+  offset += 1;
+  switch (tag) {
+    case _tagNull:
+      return (null, offset);
+    case _tagFalse:
+      return (false, offset);
+    case _tagTrue:
+      return (true, offset);
+    case _tagInt:
+      return (data.getInt64(offset, Endian.little), offset + 8);
+    case _tagDouble:
+      return (data.getFloat64(offset, Endian.little), offset + 8);
+    case _tagString:
+      final (bytes, end) = _decodeBytes(data, offset);
+      return (utf8.decode(bytes), end);
+    case _tagUint8List:
+      final (bytes, end) = _decodeBytes(data, offset);
+      return (Uint8List.fromList(bytes), end);
+    case _tagList:
+      final length = data.getUint32(offset, Endian.little);
+      offset += 4;
+      final items = <dynamic>[];
+      for (var i = 0; i < length; ++i) {
+        final (item, end) = _decodeValue(data, offset);
+        items.add(item);
+        offset = end;
+      }
+      return (items, offset);
+    default:
+      throw StateError('Unknown tag $tag');
+  }
+}
+
+(Uint8List, int) _decodeBytes(ByteData data, int offset) {
+  final length = data.getUint32(offset, Endian.little);
+  final start = offset + 4;
+  return (
+    data.buffer.asUint8List(data.offsetInBytes + start, length),
+    start + length
+  );
+}
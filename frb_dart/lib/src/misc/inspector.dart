@@ -0,0 +1,232 @@
+import 'dart:collection';
+import 'dart:convert';
+import 'dart:developer' as developer;
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/misc/rust_opaque.dart';
+import 'package:flutter_rust_bridge/src/task.dart';
+import 'package:meta/meta.dart';
+
+const _kIsProduct = bool.fromEnvironment('dart.vm.product');
+const _kEnabledByDefine = bool.fromEnvironment('flutter_rust_bridge.inspector');
+const _kMaxCalls = 200;
+const _kServiceExtensionPrefix = 'ext.flutter_rust_bridge';
+
+/// Collects the recent bridge activity, i.e. calls, live streams and open opaque handles.
+///
+/// It is disabled by default, and can be enabled via [enable]
+/// or `--dart-define=flutter_rust_bridge.inspector=true` in debug and profile builds.
+/// It is what the flutter_rust_bridge DevTools extension shows.
+/// The same data is available via [snapshot],
+/// or via the `ext.flutter_rust_bridge.getSnapshot` service extension.
+class BridgeInspector {
+  BridgeInspector._() {
+    developer.registerExtension('$_kServiceExtensionPrefix.getSnapshot',
+        (method, parameters) async {
+      return developer.ServiceExtensionResponse.result(
+          jsonEncode(_toJson()));
+    });
+    developer.registerExtension('$_kServiceExtensionPrefix.clearCalls',
+        (method, parameters) async {
+      _calls.clear();
+      return developer.ServiceExtensionResponse.result('{}');
+    });
+  }
+
+  static BridgeInspector? _instance;
+  static var _enabled = _kEnabledByDefine;
+
+  static BridgeInspector? get _maybeInstance =>
+      !_kIsProduct && _enabled ? (_instance ??= BridgeInspector._()) : null;
+
+  /// Starts recording, which does nothing in release builds.
+  static void enable() => _enabled = true;
+
+  final _calls = ListQueue<InspectedCall>();
+  final _streams = <InspectedStream>{};
+  final _opaques = <WeakReference<RustOpaque>>[];
+  var _opaqueAddsSinceCompact = 0;
+  InspectedCall? _currentCall;
+
+  /// The recent activity in JSON-compatible form, or `null` when disabled.
+  static Map<String, dynamic>? snapshot() => _maybeInstance?._toJson();
+
+  Map<String, dynamic> _toJson() {
+    _compactOpaques();
+    final opaqueCounts = <String, int>{};
+    for (final ref in _opaques) {
+      final name = ref.target?.runtimeType.toString();
+      if (name != null) opaqueCounts[name] = (opaqueCounts[name] ?? 0) + 1;
+    }
+
+    return {
+      'calls': _calls.map((x) => x._toJson()).toList(),
+      'streams': _streams.map((x) => x._toJson()).toList(),
+      'opaqueHandles': [
+        for (final entry in opaqueCounts.entries)
+          {'type': entry.key, 'count': entry.value},
+      ],
+    };
+  }
+
+  void _addCall(InspectedCall call) {
+    _calls.addLast(call);
+    while (_calls.length > _kMaxCalls) {
+      _calls.removeFirst();
+    }
+  }
+
+  void _addOpaque(RustOpaque opaque) {
+    _opaques.add(WeakReference(opaque));
+    if (++_opaqueAddsSinceCompact >= 256) _compactOpaques();
+  }
+
+  void _compactOpaques() {
+    _opaqueAddsSinceCompact = 0;
+    _opaques.removeWhere((ref) => ref.target?.isDisposed ?? true);
+  }
+}
+
+/// A call recorded by [BridgeInspector].
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+class InspectedCall {
+  final String _name;
+  final bool _isSync;
+  final DateTime _startTime = DateTime.now();
+  final _stopwatch = Stopwatch()..start();
+  int? _argBytes;
+  int? _resultBytes;
+  Duration? _duration;
+  String? _error;
+
+  InspectedCall._(BaseTask<dynamic, dynamic, dynamic> task, this._isSync)
+      : _name = task.constMeta.debugName;
+
+  /// Records the raw data received from Rust.
+  void received(Object? raw) => _resultBytes = _bufferBytes(raw);
+
+  /// Records the end of the call.
+  void done([Object? error]) {
+    _stopwatch.stop();
+    _duration = _stopwatch.elapsed;
+    _error = error?.toString();
+  }
+
+  Map<String, dynamic> _toJson() => {
+        'name': _name,
+        'isSync': _isSync,
+        'startTime': _startTime.toIso8601String(),
+        'durationMicros': _duration?.inMicroseconds,
+        'argBytes': _argBytes,
+        'resultBytes': _resultBytes,
+        'error': _error,
+      };
+}
+
+/// A stream recorded by [BridgeInspector].
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+class InspectedStream {
+  final String? _callName;
+  final DateTime _startTime = DateTime.now();
+  var _itemCount = 0;
+  var _itemBytes = 0;
+
+  InspectedStream._(this._callName);
+
+  /// Records an item received from Rust.
+  void received(Object? raw) {
+    _itemCount++;
+    _itemBytes += _bufferBytes(raw) ?? 0;
+  }
+
+  /// Records that the stream is closed.
+  void closed() => BridgeInspector._instance?._streams.remove(this);
+
+  Map<String, dynamic> _toJson() => {
+        'name': _callName,
+        'startTime': _startTime.toIso8601String(),
+        'itemCount': _itemCount,
+        'itemBytes': _itemBytes,
+      };
+}
+
+/// Returns `null` when the inspector is disabled.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+InspectedCall? inspectRustCall(BaseTask<dynamic, dynamic, dynamic> task,
+    {required bool isSync}) {
+  final inspector = BridgeInspector._maybeInstance;
+  if (inspector == null) return null;
+  final call = InspectedCall._(task, isSync);
+  inspector._addCall(call);
+  return call;
+}
+
+/// Runs [callFfi] while remembering [call], thus streams created when encoding
+/// the arguments, as well as the encoded request, are attributed to it.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+T inspectRustFfi<T>(InspectedCall? call, T Function() callFfi) {
+  final inspector = BridgeInspector._instance;
+  if (call == null || inspector == null) return callFfi();
+  final previous = inspector._currentCall;
+  inspector._currentCall = call;
+  try {
+    return callFfi();
+  } finally {
+    inspector._currentCall = previous;
+  }
+}
+
+/// Records the size of the encoded arguments of the current call.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+void inspectRustRequest(int bytes) =>
+    BridgeInspector._instance?._currentCall?._argBytes = bytes;
+
+/// Decodes the [raw] result of [call], and records its size and outcome.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+Future<S> inspectRustResult<S>(
+    InspectedCall? call, Future<dynamic> raw, S Function(dynamic) decode) {
+  if (call == null) return raw.then(decode);
+  return raw.then((value) {
+    call.received(value);
+    try {
+      final ans = decode(value);
+      call.done();
+      return ans;
+    } catch (e) {
+      call.done(e);
+      rethrow;
+    }
+  });
+}
+
+/// Returns `null` when the inspector is disabled.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+InspectedStream? inspectRustStream() {
+  final inspector = BridgeInspector._maybeInstance;
+  if (inspector == null) return null;
+  final stream = InspectedStream._(inspector._currentCall?._name);
+  inspector._streams.add(stream);
+  return stream;
+}
+
+/// {@macro flutter_rust_bridge.internal}
+@internal
+void inspectRustOpaque(RustOpaque opaque) =>
+    BridgeInspector._maybeInstance?._addOpaque(opaque);
+
+/// The size of the encoded data, or `null` if it is not a buffer (e.g. for the DCO codec).
+int? _bufferBytes(Object? raw) => raw is TypedData ? raw.lengthInBytes : null;
@@ -0,0 +1,33 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/codec/sse.dart';
+
+/// A field of a `#[frb(lazy)]` struct, which keeps the serialized bytes
+/// and only decodes them when the value is read for the first time.
+class LazyField<T> {
+  T? _value;
+  Uint8List? _bytes;
+  T Function(SseDeserializer)? _decode;
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  LazyField.value(T value) : _value = value;
+
+  /// {@macro flutter_rust_bridge.only_for_generated_code}
+  LazyField.bytes(Uint8List bytes, T Function(SseDeserializer) decode)
+      : _bytes = bytes,
+        _decode = decode;
+
+  /// The decoded value
+  T get value {
+    final bytes = _bytes;
+    if (bytes != null) {
+      _value = _decode!(SseDeserializer(ByteData.sublistView(bytes)));
+      _bytes = null;
+      _decode = null;
+    }
+    return _value as T;
+  }
+
+  /// Whether [value] has been decoded (or was given directly)
+  bool get isDecoded => _bytes == null;
+}
@@ -0,0 +1,36 @@
+import 'dart:async';
+
+import 'package:flutter_rust_bridge/src/misc/rust_opaque.dart';
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+Future<R> runLockScope<S extends RustOpaqueInterface, R>(
+    S scope, FutureOr<R> Function(S scope) action) async {
+  try {
+    return await action(scope);
+  } finally {
+    // Releases the lock
+    scope.dispose();
+  }
+}
+
+/// {@macro flutter_rust_bridge.only_for_generated_code}
+Future<R?> tryRunLockScope<S extends RustOpaqueInterface, R>({
+  required S? Function() tryLock,
+  required Future<S> Function() lock,
+  required Duration timeout,
+  required FutureOr<R> Function(S scope) action,
+}) async {
+  var scope = tryLock();
+  if (scope == null && timeout > Duration.zero) {
+    final pending = lock();
+    try {
+      scope = await pending.timeout(timeout);
+    } on TimeoutException {
+      // Nobody waits for the lock anymore, thus release it as soon as it is acquired
+      unawaited(pending.then((scope) => scope.dispose()));
+      return null;
+    }
+  }
+  if (scope == null) return null;
+  return runLockScope(scope, action);
+}
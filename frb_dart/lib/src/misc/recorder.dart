@@ -0,0 +1,170 @@
+import 'dart:collection';
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/main_components/handler.dart';
+import 'package:flutter_rust_bridge/src/method_channel/transport.dart';
+import 'package:flutter_rust_bridge/src/task.dart';
+import 'package:meta/meta.dart';
+
+/// Records the serialized requests and responses of asynchronous Rust calls,
+/// thus the session can be replayed later by [ReplayHandler] (on the Dart side)
+/// or by `flutter_rust_bridge::replay_recording` (on the Rust side).
+///
+/// Only functions using the SSE codec are recorded. Synchronous functions and the items
+/// of streams are not recorded.
+///
+/// Each call is written as one line of `funcId<TAB>name<TAB>request<TAB>response`,
+/// where the request and response are hex-encoded.
+class BridgeRecorder {
+  static BridgeRecorder? _active;
+
+  final StringSink _output;
+
+  BridgeRecorder._(this._output);
+
+  /// Starts recording into [output], e.g. `File('session.frbrec').openWrite()`.
+  /// Any recorder started previously is stopped.
+  static BridgeRecorder start(StringSink output) =>
+      _active = BridgeRecorder._(output);
+
+  /// Whether this recorder is recording.
+  bool get isActive => identical(_active, this);
+
+  /// Stops recording. Closing the output is up to the caller.
+  void stop() {
+    if (isActive) _active = null;
+  }
+}
+
+/// A call recorded by [BridgeRecorder].
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+class RecordedCall {
+  final BridgeRecorder _recorder;
+  final String _name;
+  int? _funcId;
+  Uint8List? _request;
+
+  RecordedCall._(this._recorder, this._name);
+
+  void _write(Object? response) {
+    final funcId = _funcId;
+    final request = _request;
+    if (funcId == null || request == null || !_recorder.isActive) return;
+
+    final Uint8List encodedResponse;
+    try {
+      encodedResponse = encodeMethodChannelValue(response);
+    } on ArgumentError {
+      return;
+    }
+    _recorder._output.writeln([
+      funcId,
+      _name,
+      _toHex(request),
+      _toHex(encodedResponse),
+    ].join('\t'));
+  }
+}
+
+RecordedCall? _currentCall;
+
+/// Returns `null` when not recording.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+RecordedCall? recordRustCall(BaseTask<dynamic, dynamic, dynamic> task) {
+  final recorder = BridgeRecorder._active;
+  return recorder == null
+      ? null
+      : RecordedCall._(recorder, task.constMeta.debugName);
+}
+
+/// Runs [callFfi], during which [recordRustRequest] is attributed to [call].
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+T recordRustFfi<T>(RecordedCall? call, T Function() callFfi) {
+  if (call == null) return callFfi();
+  final previous = _currentCall;
+  _currentCall = call;
+  try {
+    return callFfi();
+  } finally {
+    _currentCall = previous;
+  }
+}
+
+/// Records the serialized arguments. The [payload] is only read when recording,
+/// and must be read before the bytes are handed over to Rust.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+void recordRustRequest(int funcId, Uint8List Function() payload) {
+  final call = _currentCall;
+  if (call == null) return;
+  call._funcId = funcId;
+  call._request = Uint8List.fromList(payload());
+}
+
+/// Records the raw response of [call] when it arrives.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+Future<dynamic> recordRustResponse(RecordedCall? call, Future<dynamic> raw) {
+  if (call == null) return raw;
+  return raw.then((value) {
+    call._write(value);
+    return value;
+  });
+}
+
+/// Replays the responses recorded by [BridgeRecorder], instead of calling Rust.
+///
+/// Use it in e.g. `RustLib.init(handler: ReplayHandler.parse(recording))`.
+/// Each call receives the next recorded response of the function with the same name,
+/// thus Dart code can be tested deterministically against a captured session.
+class ReplayHandler extends BaseHandler {
+  final Map<String, Queue<Uint8List>> _responses;
+
+  /// Create the handler from the content of a recording.
+  factory ReplayHandler.parse(String recording) {
+    final responses = <String, Queue<Uint8List>>{};
+    for (final line in recording.split('\n')) {
+      if (line.trim().isEmpty) continue;
+      final [_, name, _, response] = line.split('\t');
+      responses.putIfAbsent(name, Queue.new).add(_fromHex(response));
+    }
+    return ReplayHandler._(responses);
+  }
+
+  ReplayHandler._(this._responses);
+
+  @override
+  Future<S> executeNormal<S, E extends Object>(NormalTask<S, E> task) async {
+    final name = task.constMeta.debugName;
+    final responses = _responses[name];
+    if (responses == null || responses.isEmpty) {
+      throw StateError('No recorded response left for `$name`');
+    }
+    final raw = decodeMethodChannelValue(
+        responses.removeFirst().buffer.asByteData());
+    return task.codec.decodeObject(raw);
+  }
+
+  @override
+  S executeSync<S, E extends Object, WireSyncType>(
+          SyncTask<S, E, WireSyncType> task) =>
+      throw UnsupportedError(
+          'ReplayHandler does not support synchronous functions, '
+          'but `${task.constMeta.debugName}` is called');
+}
+
+String _toHex(Uint8List bytes) =>
+    bytes.map((x) => x.toRadixString(16).padLeft(2, '0')).join();
+
+Uint8List _fromHex(String hex) => Uint8List.fromList([
+      for (var i = 0; i < hex.length; i += 2)
+        int.parse(hex.substring(i, i + 2), radix: 16),
+    ]);
@@ -1,3 +1,4 @@
+import 'package:flutter_rust_bridge/src/misc/inspector.dart';
 import 'package:flutter_rust_bridge/src/rust_arc/_common.dart';
 import 'package:meta/meta.dart';
 
@@ -49,7 +50,9 @@ abstract class RustOpaque implements RustOpaqueInterface {
           ptr: ptr,
           externalSizeOnNative: externalSizeOnNative,
           staticData: staticData,
-        );
+        ) {
+    inspectRustOpaque(this);
+  }
 
   /// {@macro flutter_rust_bridge.only_for_generated_code}
   @internal
@@ -0,0 +1,165 @@
+import 'dart:async';
+
+import 'package:flutter_rust_bridge/src/exceptions.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_opaque.dart';
+import 'package:meta/meta.dart';
+
+final _zoneKey = Object();
+
+/// Tracks the Rust calls and streams started inside [run],
+/// and cancels them altogether when disposed.
+///
+/// For example, create one scope per screen and dispose it in `State.dispose`
+/// (or use [RustScopeMixin]), thus navigating away reliably cancels the in-flight work of that screen.
+class RustScope {
+  final _pendingCalls = <Completer<dynamic>, TrackedRustCall>{};
+  final _streamClosers = <void Function()>{};
+  var _isDisposed = false;
+
+  /// Whether [dispose] has been called.
+  bool get isDisposed => _isDisposed;
+
+  /// Runs [body] and tracks the calls and streams it starts,
+  /// including the ones started after `await`s inside it.
+  R run<R>(R Function() body) {
+    if (_isDisposed) throw StateError('Cannot run inside a disposed RustScope');
+    return runZoned(body, zoneValues: {_zoneKey: this});
+  }
+
+  /// Cancels the tracked work:
+  ///
+  /// * Pending calls complete with a [RustScopeCancelledException],
+  ///   thus code awaiting them does not continue normally
+  ///   (e.g. it will not call `setState` on a disposed widget).
+  /// * The running Rust functions are told to stop via their `CancellationToken`.
+  ///   Opaque objects returned by them later are disposed.
+  /// * Streams are closed, thus adding to their `StreamSink` in Rust returns an error.
+  /// * Calls started later inside this scope are not executed,
+  ///   and complete with a [RustScopeCancelledException] as well.
+  void dispose() {
+    if (_isDisposed) return;
+    _isDisposed = true;
+
+    final pendingCalls = Map.of(_pendingCalls);
+    _pendingCalls.clear();
+    for (final MapEntry(key: completer, value: tracked) in pendingCalls.entries) {
+      tracked.cancel?.call();
+      completer.completeError(const RustScopeCancelledException());
+    }
+
+    for (final close in _streamClosers.toList()) {
+      close();
+    }
+    _streamClosers.clear();
+  }
+
+  Future<S> _trackCall<S>(Future<S> Function(TrackedRustCall tracked) call) {
+    if (_isDisposed) return Future.error(const RustScopeCancelledException());
+
+    final tracked = TrackedRustCall._();
+    final future = call(tracked);
+    final completer = Completer<S>();
+    _pendingCalls[completer] = tracked;
+    future.then(
+      (value) {
+        if (_pendingCalls.remove(completer) != null) {
+          completer.complete(value);
+        } else {
+          // Nobody receives the result, thus release the Rust resources in it
+          _disposeLateResult(value);
+        }
+      },
+      onError: (Object error, StackTrace stackTrace) {
+        if (_pendingCalls.remove(completer) != null) {
+          completer.completeError(error, stackTrace);
+        }
+      },
+    );
+    return completer.future;
+  }
+
+  void Function() _trackStream(void Function() close) {
+    if (_isDisposed) {
+      close();
+      return () {};
+    }
+    _streamClosers.add(close);
+    return () => _streamClosers.remove(close);
+  }
+}
+
+void _disposeLateResult(Object? value) {
+  if (value is RustOpaqueInterface) {
+    value.dispose();
+  } else if (value is Iterable) {
+    value.forEach(_disposeLateResult);
+  }
+}
+
+/// Gives a [RustScope] to objects with a `dispose` lifecycle, e.g. Flutter's `State`:
+///
+/// ```dart
+/// class _MyPageState extends State<MyPage> with RustScopeMixin {
+///   @override
+///   void initState() {
+///     super.initState();
+///     runInRustScope(() async {
+///       final result = await heavyComputation();
+///       setState(() => this.result = result);
+///     });
+///   }
+///
+///   @override
+///   void dispose() {
+///     disposeRustScope();
+///     super.dispose();
+///   }
+/// }
+/// ```
+mixin RustScopeMixin {
+  RustScope? _rustScope;
+
+  /// The scope of this object, created when first used.
+  RustScope get rustScope => _rustScope ??= RustScope();
+
+  /// Runs [body] inside [rustScope] without waiting for it, see [RustScope.run].
+  ///
+  /// Since the scope is usually disposed when the result is no longer needed,
+  /// a [RustScopeCancelledException] thrown by [body] is ignored.
+  void runInRustScope(FutureOr<void> Function() body) {
+    unawaited(Future.sync(() => rustScope.run(body)).catchError(
+        (Object _) {},
+        test: (error) => error is RustScopeCancelledException));
+  }
+
+  /// Cancels the work in [rustScope], usually called in the `dispose` of this object.
+  void disposeRustScope() => _rustScope?.dispose();
+}
+
+/// A call tracked by a [RustScope].
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+class TrackedRustCall {
+  TrackedRustCall._();
+
+  /// Tells the Rust side to stop, which is called if the scope is disposed before the call completes.
+  void Function()? cancel;
+}
+
+/// {@macro flutter_rust_bridge.internal}
+@internal
+Future<S> trackRustCall<S>(
+    Future<S> Function(TrackedRustCall? tracked) call) {
+  final scope = Zone.current[_zoneKey] as RustScope?;
+  return scope == null ? call(null) : scope._trackCall(call);
+}
+
+/// Returns a function to stop tracking, which should be called when the stream is done.
+///
+/// {@macro flutter_rust_bridge.internal}
+@internal
+void Function() trackRustStream(void Function() close) {
+  final scope = Zone.current[_zoneKey] as RustScope?;
+  return scope == null ? () {} : scope._trackStream(close);
+}
@@ -0,0 +1,26 @@
+import 'dart:async';
+
+/// Called when a part of a large return value has arrived from Rust.
+typedef TransferProgressCallback = void Function(
+    int receivedBytes, int totalBytes);
+
+final _zoneKey = Object();
+
+/// The largest return value (in bytes) accepted from Rust in chunks.
+///
+/// The size is declared by Rust before sending the chunks, and a larger one
+/// is rejected with a `ChunkedTransferException` before allocating any memory for it.
+int maxChunkedTransferBytes = 1 << 31;
+
+/// Run [body], and report the progress of the large return values
+/// of Rust functions called inside it to [onProgress].
+///
+/// Only return values transferred in chunks (i.e. larger than the threshold
+/// in `ChunkedTransferConfig` on the Rust side) report progress.
+R withTransferProgress<R>(
+        R Function() body, TransferProgressCallback onProgress) =>
+    runZoned(body, zoneValues: {_zoneKey: onProgress});
+
+/// {@macro flutter_rust_bridge.internal}
+TransferProgressCallback? get currentTransferProgressCallback =>
+    Zone.current[_zoneKey] as TransferProgressCallback?;
@@ -0,0 +1,36 @@
+/// The progress of a long operation, sent from Rust via a `ProgressSink`.
+class FrbProgress {
+  /// The amount of work done
+  final int completed;
+
+  /// The total amount of work, or null if unknown
+  final int? total;
+
+  /// An optional human readable description of the current step
+  final String? message;
+
+  /// Constructs the progress
+  const FrbProgress({required this.completed, this.total, this.message});
+
+  /// The ratio of completed work in `[0, 1]`, or null if [total] is unknown
+  double? get fraction {
+    final total = this.total;
+    if (total == null) return null;
+    if (total == 0) return 1;
+    return (completed / total).clamp(0, 1).toDouble();
+  }
+
+  @override
+  bool operator ==(Object other) =>
+      other is FrbProgress &&
+      completed == other.completed &&
+      total == other.total &&
+      message == other.message;
+
+  @override
+  int get hashCode => Object.hash(completed, total, message);
+
+  @override
+  String toString() =>
+      'FrbProgress(completed: $completed, total: $total, message: $message)';
+}
@@ -3,6 +3,8 @@ import 'dart:async';
 import 'package:async/async.dart';
 import 'package:flutter_rust_bridge/src/codec/base.dart';
 import 'package:flutter_rust_bridge/src/generalized_isolate/generalized_isolate.dart';
+import 'package:flutter_rust_bridge/src/misc/inspector.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_scope.dart';
 import 'package:flutter_rust_bridge/src/utils/port_generator.dart';
 
 /// The Rust `StreamSink<T>` on the Dart side.
@@ -29,10 +31,13 @@ class _State<T> {
 _State<T> _setup<T>(BaseCodec<T, dynamic, dynamic> codec) {
   final portName = ExecuteStreamPortGenerator.create('RustStreamSink');
   final receivePort = broadcastPort(portName);
+  final untrack = trackRustStream(receivePort.close);
+  final inspected = inspectRustStream();
 
   final Stream<T> rawStream = () async* {
     try {
       await for (final raw in receivePort) {
+        inspected?.received(raw);
         try {
           yield codec.decodeObject(raw);
         } on CloseStreamException {
@@ -40,6 +45,8 @@ _State<T> _setup<T>(BaseCodec<T, dynamic, dynamic> codec) {
         }
       }
     } finally {
+      inspected?.closed();
+      untrack();
       receivePort.close();
     }
   }();
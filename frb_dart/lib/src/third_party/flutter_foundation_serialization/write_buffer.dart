@@ -6,6 +6,7 @@ import 'dart:typed_data';
 
 import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/generalized_frb_rust_binding.dart';
 import 'package:flutter_rust_bridge/src/generalized_typed_data/generalized_typed_data.dart';
+import 'package:flutter_rust_bridge/src/generalized_uint8list/dart_heap_uint8list.dart';
 import 'package:flutter_rust_bridge/src/generalized_uint8list/generalized_uint8list.dart';
 
 // NOTE MAIN MODIFICATION:
@@ -26,8 +27,12 @@ class WriteBuffer {
     assert(startCapacity > 0);
     final ByteData eightBytes = ByteData(8);
     final Uint8List eightBytesAsList = eightBytes.buffer.asUint8List();
-    return WriteBuffer._(PlatformGeneralizedUint8List(startCapacity, binding),
-        eightBytes, eightBytesAsList);
+    // NOTE MODIFIED The bytes stay on the Dart heap when not passed via FFI
+    final BaseGeneralizedUint8List buffer =
+        binding.methodChannelTransport != null
+            ? DartHeapUint8List(startCapacity)
+            : PlatformGeneralizedUint8List(startCapacity, binding);
+    return WriteBuffer._(buffer, eightBytes, eightBytesAsList);
   }
 
   WriteBuffer._(this._buffer, this._eightBytes, this._eightBytesAsList);
@@ -246,13 +251,24 @@ class WriteBuffer {
   /// a memory copy. Other implementations may be faster, but still
   /// it is overhead.
   WriteBufferRaw intoRaw() {
+    _markDone();
+    final raw = _buffer.intoRaw();
+    return (ptr: raw.ptr, rustVecLen: raw.length, dataLen: _currentSize);
+  }
+
+  /// Similar to [intoRaw], but for buffers on the Dart heap (i.e. when not using FFI).
+  Uint8List intoBytes() {
+    _markDone();
+    final raw = (_buffer as DartHeapUint8List).intoRaw();
+    return Uint8List.sublistView(raw.ptr, 0, _currentSize);
+  }
+
+  void _markDone() {
     if (_isDone) {
       throw StateError(
           'done() must not be called more than once on the same $runtimeType.');
     }
     _isDone = true;
-    final raw = _buffer.intoRaw();
-    return (ptr: raw.ptr, rustVecLen: raw.length, dataLen: _currentSize);
   }
 }
 
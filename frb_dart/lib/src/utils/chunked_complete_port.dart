@@ -0,0 +1,83 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/exceptions.dart';
+import 'package:flutter_rust_bridge/src/generalized_isolate/generalized_isolate.dart';
+import 'package:flutter_rust_bridge/src/misc/transfer_progress.dart';
+import 'package:flutter_rust_bridge/src/platform_utils/platform_utils.dart';
+
+/// NOTE: Please keep in sync with the Rust side
+const _chunkedTransferStart = 4;
+
+/// Similar to `singleCompletePort`, except that the message may also be
+/// split into chunks by the Rust side, which are reassembled here.
+///
+/// A chunked message starts with a header `[_chunkedTransferStart, totalBytes]`,
+/// followed by [Uint8List] chunks whose lengths sum to `totalBytes`.
+/// The header is validated against [maxBytes] (by default [maxChunkedTransferBytes])
+/// before allocating the buffer, and each chunk is copied into it as it arrives.
+SendPort chunkedCompletePort(Completer<dynamic> completer,
+    {TransferProgressCallback? onProgress, int? maxBytes}) {
+  final port = RawReceivePort();
+  final zone = Zone.current;
+  final handle = chunkedMessageHandler(
+    (message) {
+      port.close();
+      completer.complete(message);
+    },
+    onProgress: onProgress,
+    maxBytes: maxBytes,
+  );
+
+  port.handler = (dynamic message) {
+    try {
+      zone.runUnary(handle, message);
+    } catch (error, stack) {
+      port.close();
+      completer.completeError(error, stack);
+    }
+  };
+  return port.sendPort;
+}
+
+/// Reassembles the (possibly chunked) messages of one port,
+/// and calls [onComplete] with the full message.
+void Function(dynamic) chunkedMessageHandler(
+    void Function(dynamic message) onComplete,
+    {TransferProgressCallback? onProgress, int? maxBytes}) {
+  Uint8List? buffer;
+  var received = 0;
+
+  return (dynamic message) {
+    final currentBuffer = buffer;
+    if (currentBuffer == null) {
+      final totalBytes = _parseHeader(message);
+      if (totalBytes == null) return onComplete(message);
+      final limit = maxBytes ?? maxChunkedTransferBytes;
+      if (totalBytes < 0 || totalBytes > limit) {
+        throw ChunkedTransferException(
+            'declared size $totalBytes bytes exceeds the limit of $limit bytes');
+      }
+      buffer = Uint8List(totalBytes);
+      return;
+    }
+
+    final chunk = maybeDartify(message) as Uint8List;
+    if (received + chunk.length > currentBuffer.length) {
+      throw ChunkedTransferException(
+          'received more than the declared ${currentBuffer.length} bytes');
+    }
+    currentBuffer.setRange(received, received + chunk.length, chunk);
+    received += chunk.length;
+    onProgress?.call(received, currentBuffer.length);
+    if (received == currentBuffer.length) onComplete(currentBuffer);
+  };
+}
+
+int? _parseHeader(dynamic message) {
+  final dartified = maybeDartify(message);
+  if (dartified is! List || dartified.length != 2) return null;
+  final [action, totalBytes] = dartified;
+  if (action is! num || action.toInt() != _chunkedTransferStart) return null;
+  return (totalBytes as num).toInt();
+}
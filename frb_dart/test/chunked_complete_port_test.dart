@@ -0,0 +1,44 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/exceptions.dart';
+import 'package:flutter_rust_bridge/src/utils/chunked_complete_port.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('when message is not chunked', () async {
+    final completer = Completer<dynamic>();
+    final sendPort = chunkedCompletePort(completer);
+    sendPort.send([0, 'hello']);
+    expect(await completer.future, [0, 'hello']);
+  });
+
+  test('when message is chunked', () async {
+    final completer = Completer<dynamic>();
+    final progress = <(int, int)>[];
+    final sendPort = chunkedCompletePort(completer,
+        onProgress: (received, total) => progress.add((received, total)));
+    sendPort.send([4, 5]);
+    sendPort.send(Uint8List.fromList([1, 2, 3]));
+    sendPort.send(Uint8List.fromList([4, 5]));
+    expect(await completer.future, [1, 2, 3, 4, 5]);
+    expect(progress, [(3, 5), (5, 5)]);
+  })
+  test('when declared size exceeds the limit', () async {
+    final completer = Completer<dynamic>();
+    final sendPort = chunkedCompletePort(completer, maxBytes: 1024);
+    // Would take a terabyte if the buffer were allocated before validating
+    sendPort.send([4, 1 << 40]);
+    await expectLater(
+        completer.future, throwsA(isA<ChunkedTransferException>()));
+  });
+
+  test('when chunks exceed the declared size', () async {
+    final completer = Completer<dynamic>();
+    final sendPort = chunkedCompletePort(completer);
+    sendPort.send([4, 2]);
+    sendPort.send(Uint8List.fromList([1, 2, 3]));
+    await expectLater(
+        completer.future, throwsA(isA<ChunkedTransferException>()));
+  });
+}
@@ -0,0 +1,19 @@
+@TestOn('vm')
+import 'package:flutter_rust_bridge/src/generalized_frb_rust_binding/_io.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('GeneralizedFrbRustBinding.computeSymbolName', () {
+    const exportedSymbols = {'frb_pde_ffi_dispatcher_primary'};
+
+    // Only the symbols exported by the generated code are prefixed
+    expect(
+        GeneralizedFrbRustBinding.computeSymbolName(
+            'frb_pde_ffi_dispatcher_primary', 'v2_', exportedSymbols),
+        'v2_frb_pde_ffi_dispatcher_primary');
+    expect(
+        GeneralizedFrbRustBinding.computeSymbolName(
+            'store_dart_post_cobject', 'v2_', exportedSymbols),
+        'store_dart_post_cobject');
+  });
+}
@@ -0,0 +1,64 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:flutter_rust_bridge/src/codec/base.dart';
+import 'package:flutter_rust_bridge/src/misc/inspector.dart';
+import 'package:mocktail/mocktail.dart';
+import 'package:test/test.dart';
+
+class _MockBaseApiImpl extends Mock implements BaseApiImpl {}
+
+class _MockBaseCodec<S, E extends Object, WireSyncType> extends Mock
+    implements BaseCodec<S, E, WireSyncType> {}
+
+NormalTask<int, Object> _createTask(String name) => NormalTask(
+      callFfi: (_) {},
+      codec: _MockBaseCodec(),
+      constMeta: TaskConstMeta(debugName: name, argNames: const ['a']),
+      argValues: [Uint8List(100)],
+      apiImpl: _MockBaseApiImpl(),
+    );
+
+void main() {
+  test('disabled by default', () {
+    expect(inspectRustCall(_createTask('f'), isSync: false), null);
+    expect(BridgeInspector.snapshot(), null);
+  });
+
+  test('records calls and the streams they create', () async {
+    BridgeInspector.enable();
+    final call = inspectRustCall(_createTask('f'), isSync: false);
+    final stream = inspectRustFfi(call, () {
+      inspectRustRequest(10);
+      return inspectRustStream();
+    });
+    stream!.received(Uint8List(3));
+
+    expect(
+        await inspectRustResult(
+            call, Future.value(Uint8List(5)), (raw) => (raw as List).length),
+        5);
+
+    final snapshot = BridgeInspector.snapshot()!;
+    expect(snapshot['calls'].last, containsPair('name', 'f'));
+    expect(snapshot['calls'].last, containsPair('argBytes', 10));
+    expect(snapshot['calls'].last, containsPair('resultBytes', 5));
+    expect(snapshot['calls'].last, containsPair('error', null));
+    expect(snapshot['streams'].last, containsPair('name', 'f'));
+    expect(snapshot['streams'].last, containsPair('itemBytes', 3));
+
+    stream.closed();
+    expect(BridgeInspector.snapshot()!['streams'], isEmpty);
+  });
+
+  test('records errors', () async {
+    final call = inspectRustCall(_createTask('g'), isSync: false);
+    await expectLater(
+        inspectRustResult<int>(
+            call, Future.value(null), (_) => throw StateError('bad')),
+        throwsStateError);
+
+    expect(BridgeInspector.snapshot()!['calls'].last,
+        containsPair('error', contains('bad')));
+  });
+}
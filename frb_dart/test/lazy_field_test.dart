@@ -0,0 +1,38 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/codec/sse.dart';
+import 'package:flutter_rust_bridge/src/misc/lazy_field.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('sseDecodeLazyField only decodes on first access', () {
+    // Two fields, each prefixed with its length: an int32 and a uint8
+    final data = ByteData(13)
+      ..setInt32(0, 4, Endian.host)
+      ..setInt32(4, 42, Endian.host)
+      ..setInt32(8, 1, Endian.host)
+      ..setUint8(12, 7);
+    final deserializer = SseDeserializer(data);
+
+    var numDecodes = 0;
+    final a = sseDecodeLazyField(deserializer, (d) {
+      numDecodes++;
+      return d.buffer.getInt32();
+    });
+    final b = sseDecodeLazyField(deserializer, (d) => d.buffer.getUint8());
+
+    expect(numDecodes, 0);
+    expect(a.isDecoded, false);
+    expect(a.value, 42);
+    expect(a.value, 42);
+    expect(numDecodes, 1);
+    expect(a.isDecoded, true);
+    expect(b.value, 7);
+  });
+
+  test('LazyField.value', () {
+    final field = LazyField<String?>.value(null);
+    expect(field.isDecoded, true);
+    expect(field.value, null);
+  });
+}
@@ -0,0 +1,57 @@
+@TestOn('vm')
+import 'dart:io';
+
+import 'package:flutter_rust_bridge/src/loader/_common.dart';
+import 'package:flutter_rust_bridge/src/loader/_io.dart';
+import 'package:test/test.dart';
+
+void main() {
+  final operatingSystem = Platform.operatingSystem;
+  const notExistPath = '/not_exist/libfrb_loader_io_test.so';
+
+  test('loadExternalLibrary prefers ioProcess over ioPaths', () async {
+    final library = await loadExternalLibrary(ExternalLibraryLoaderConfig(
+      stem: 'frb_loader_io_test',
+      ioDirectory: null,
+      webPrefix: null,
+      ioPaths: {operatingSystem: notExistPath},
+      ioProcess: [operatingSystem],
+    ));
+    expect(library.debugInfo, 'by process() (by ioProcess)');
+  });
+
+  test('loadExternalLibrary prefers ioPaths over the default search', () async {
+    await expectLater(
+      Future(() => loadExternalLibrary(ExternalLibraryLoaderConfig(
+            stem: 'frb_loader_io_test',
+            ioDirectory: null,
+            webPrefix: null,
+            ioStems: {operatingSystem: 'frb_loader_io_test_stem'},
+            ioPaths: {operatingSystem: notExistPath},
+          ))),
+      throwsA(predicate((e) => e.toString().contains(notExistPath))),
+    );
+  });
+
+  test('loadExternalLibrary ignores the overrides of other operating systems',
+      () async {
+    await expectLater(
+      Future(() => loadExternalLibrary(ExternalLibraryLoaderConfig(
+            stem: 'frb_loader_io_test',
+            ioDirectory: null,
+            webPrefix: null,
+            ioStems: {
+              operatingSystem: 'frb_loader_io_test_stem',
+              'fuchsia': 'frb_loader_io_test_other_stem',
+            },
+            ioPaths: {'fuchsia': notExistPath},
+            ioProcess: ['fuchsia'],
+          ))),
+      throwsA(predicate((e) {
+        final message = e.toString();
+        return message.contains('frb_loader_io_test_stem') &&
+            !message.contains(notExistPath);
+      })),
+    );
+  });
+}
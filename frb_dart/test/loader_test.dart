@@ -0,0 +1,20 @@
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_common.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('ExternalLibraryLoaderConfig.copyWith', () {
+    const config = ExternalLibraryLoaderConfig(
+      stem: 'my_lib',
+      ioDirectory: 'rust/target/release/',
+      webPrefix: 'pkg/',
+      ioProcess: ['ios'],
+    );
+
+    final copied = config.copyWith(ioPaths: {'linux': '/opt/libmy_lib.so'});
+    expect(copied.stem, 'my_lib');
+    expect(copied.ioDirectory, 'rust/target/release/');
+    expect(copied.ioStems, isEmpty);
+    expect(copied.ioPaths, {'linux': '/opt/libmy_lib.so'});
+    expect(copied.ioProcess, ['ios']);
+  });
+}
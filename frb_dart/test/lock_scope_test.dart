@@ -0,0 +1,81 @@
+import 'dart:async';
+
+import 'package:flutter_rust_bridge/src/misc/lock_scope.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_opaque.dart';
+import 'package:test/test.dart';
+
+class _FakeScope implements RustOpaqueInterface {
+  @override
+  bool isDisposed = false;
+
+  @override
+  void dispose() => isDisposed = true;
+}
+
+void main() {
+  test('runLockScope disposes after action', () async {
+    final scope = _FakeScope();
+    expect(await runLockScope(scope, (s) async => s.isDisposed), false);
+    expect(scope.isDisposed, true);
+
+    final failingScope = _FakeScope();
+    await expectLater(
+        runLockScope(failingScope, (_) => throw StateError('x')),
+        throwsStateError);
+    expect(failingScope.isDisposed, true);
+  });
+
+  test('tryRunLockScope when lock is free', () async {
+    final scope = _FakeScope();
+    final output = await tryRunLockScope(
+      tryLock: () => scope,
+      lock: () => throw UnimplementedError(),
+      timeout: Duration.zero,
+      action: (_) => 42,
+    );
+    expect(output, 42);
+    expect(scope.isDisposed, true);
+  });
+
+  test('tryRunLockScope waits for lock within timeout', () async {
+    final completer = Completer<_FakeScope>();
+    final output = tryRunLockScope<_FakeScope, int>(
+      tryLock: () => null,
+      lock: () => completer.future,
+      timeout: const Duration(seconds: 10),
+      action: (s) => s.isDisposed ? -1 : 42,
+    );
+    final scope = _FakeScope();
+    completer.complete(scope);
+    expect(await output, 42);
+    expect(scope.isDisposed, true);
+  });
+
+  test('tryRunLockScope when lock is busy', () async {
+    expect(
+      await tryRunLockScope<_FakeScope, int>(
+        tryLock: () => null,
+        lock: () => throw UnimplementedError(),
+        timeout: Duration.zero,
+        action: (_) => 42,
+      ),
+      null,
+    );
+
+    final lateCompleter = Completer<_FakeScope>();
+    expect(
+      await tryRunLockScope<_FakeScope, int>(
+        tryLock: () => null,
+        lock: () => lateCompleter.future,
+        timeout: const Duration(milliseconds: 10),
+        action: (_) => 42,
+      ),
+      null,
+    );
+    // Lock acquired after timeout should be released immediately
+    final lateScope = _FakeScope();
+    lateCompleter.complete(lateScope);
+    await Future<void>.delayed(Duration.zero);
+    expect(lateScope.isDisposed, true);
+  });
+}
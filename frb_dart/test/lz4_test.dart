@@ -0,0 +1,48 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/codec/lz4.dart';
+import 'package:test/test.dart';
+
+void main() {
+  // Produced by `lz4_flex::block::compress` on the Rust side
+  final vectors = {
+    'abcdabcdabcdabcdabcd': [
+      70, 97, 98, 99, 100, 4, 0, 96, 99, 100, 97, 98, 99, 100, //
+    ],
+    'hello world, hello world, hello world, hello world!': [
+      223, 104, 101, 108, 108, 111, 32, 119, 111, 114, 108, 100, 44, 32, //
+      13, 0, 13, 96, 119, 111, 114, 108, 100, 33,
+    ],
+    String.fromCharCodes(List.filled(300, 7)): [
+      31, 7, 1, 0, 255, 19, 96, 7, 7, 7, 7, 7, 7, //
+    ],
+  };
+
+  test('lz4Decompress', () {
+    for (final MapEntry(key: expected, value: compressed) in vectors.entries) {
+      expect(
+          String.fromCharCodes(lz4Decompress(
+              Uint8List.fromList(compressed), expected.length)),
+          expected);
+    }
+  });
+
+  test('lz4Decompress with malformed input', () {
+    final compressed = vectors['abcdabcdabcdabcdabcd']!;
+    for (final malformed in [
+      <int>[],
+      // Truncated
+      compressed.sublist(0, 6),
+      // Offset pointing before the start of the output
+      [...compressed.sublist(0, 5), 200, 0, ...compressed.sublist(7)],
+      // Literal length beyond the end of the input
+      [0xF0, 100],
+    ]) {
+      expect(() => lz4Decompress(Uint8List.fromList(malformed), 20),
+          throwsFormatException);
+    }
+    // Declared length mismatch
+    expect(() => lz4Decompress(Uint8List.fromList(compressed), 19),
+        throwsFormatException);
+  });
+}
@@ -0,0 +1,68 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/src/method_channel/transport.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('dispatch', () {
+    final sent = <ByteData>[];
+    final transport = MethodChannelTransport(send: sent.add);
+    transport.dispatch(
+        funcId: 7, port: -3, payload: Uint8List.fromList([1, 2]));
+    expect(sent.single.buffer.asUint8List(), [
+      ...[7, 0, 0, 0],
+      ...[253, 255, 255, 255, 255, 255, 255, 255],
+      ...[1, 2],
+    ]);
+  });
+
+  test('dispatch when the host reports an error', () async {
+    final transport = MethodChannelTransport(
+        send: (_) => throw StateError('frb_method_channel_dispatch failed'));
+    final completer = Completer<dynamic>();
+    final port = transport.openPort(completer.complete,
+        onError: completer.completeError);
+    transport.dispatch(funcId: 7, port: port, payload: Uint8List(0));
+    await expectLater(completer.future, throwsStateError);
+
+    final message = ByteData(8)..setInt64(0, port, Endian.little);
+    expect(() => transport.handleMessageFromRust(message), throwsStateError);
+  });
+
+  test('handleMessageFromRust', () {
+    final transport = MethodChannelTransport(send: (_) {});
+    final received = <dynamic>[];
+    final port = transport.openPort(received.add);
+    expect(port, -1);
+
+    final message = ByteData(8)..setInt64(0, port, Endian.little);
+    final value = Uint8List.fromList([
+      // list of length 3
+      ...[7, 3, 0, 0, 0],
+      // int 4
+      ...[3, 4, 0, 0, 0, 0, 0, 0, 0],
+      // string "hi"
+      ...[5, 2, 0, 0, 0, 104, 105],
+      // Uint8List [9]
+      ...[6, 1, 0, 0, 0, 9],
+    ]);
+    transport.handleMessageFromRust(
+        (BytesBuilder()
+              ..add(message.buffer.asUint8List())
+              ..add(value))
+            .toBytes()
+            .buffer
+            .asByteData());
+    expect(received, [
+      [
+        4,
+        'hi',
+        [9]
+      ]
+    ]);
+
+    transport.closePort(port);
+    expect(() => transport.handleMessageFromRust(message), throwsStateError);
+  });
+}
@@ -0,0 +1,17 @@
+import 'package:flutter_rust_bridge/src/stream/progress.dart';
+import 'package:test/test.dart';
+
+void main() {
+  test('fraction', () {
+    expect(const FrbProgress(completed: 3).fraction, null);
+    expect(const FrbProgress(completed: 3, total: 4).fraction, 0.75);
+    expect(const FrbProgress(completed: 0, total: 0).fraction, 1);
+  });
+
+  test('equality', () {
+    expect(const FrbProgress(completed: 1, total: 2, message: 'a'),
+        const FrbProgress(completed: 1, total: 2, message: 'a'));
+    expect(const FrbProgress(completed: 1, message: 'a'),
+        isNot(const FrbProgress(completed: 1, message: 'b')));
+  });
+}
@@ -0,0 +1,48 @@
+import 'dart:typed_data';
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:flutter_rust_bridge/src/codec/base.dart';
+import 'package:flutter_rust_bridge/src/misc/recorder.dart';
+import 'package:mocktail/mocktail.dart';
+import 'package:test/test.dart';
+
+class _MockBaseApiImpl extends Mock implements BaseApiImpl {}
+
+class _MockBaseCodec<S, E extends Object, WireSyncType> extends Mock
+    implements BaseCodec<S, E, WireSyncType> {}
+
+NormalTask<int, Object> _createTask(BaseCodec<int, Object, dynamic> codec) =>
+    NormalTask(
+      callFfi: (_) {},
+      codec: codec,
+      constMeta: const TaskConstMeta(debugName: 'f', argNames: []),
+      argValues: const [],
+      apiImpl: _MockBaseApiImpl(),
+    );
+
+void main() {
+  test('replays the recorded responses', () async {
+    final output = StringBuffer();
+    final recorder = BridgeRecorder.start(output);
+    final rawResponse = [Uint8List.fromList([1, 2]), 'a', null];
+
+    final call = recordRustCall(_createTask(_MockBaseCodec()));
+    recordRustFfi(
+        call, () => recordRustRequest(7, () => Uint8List.fromList([255])));
+    await recordRustResponse(call, Future.value(rawResponse));
+    recorder.stop();
+
+    expect(output.toString(), startsWith('7\tf\tff\t'));
+    expect(recordRustCall(_createTask(_MockBaseCodec())), isNull);
+
+    final codec = _MockBaseCodec<int, Object, dynamic>();
+    when(() => codec.decodeObject(any())).thenReturn(42);
+    final handler = ReplayHandler.parse(output.toString());
+
+    expect(await handler.executeNormal(_createTask(codec)), 42);
+    expect(verify(() => codec.decodeObject(captureAny())).captured.single,
+        rawResponse);
+    await expectLater(
+        handler.executeNormal(_createTask(codec)), throwsStateError);
+  });
+}
@@ -0,0 +1,143 @@
+import 'dart:async';
+
+import 'package:flutter_rust_bridge/src/exceptions.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_opaque.dart';
+import 'package:flutter_rust_bridge/src/misc/rust_scope.dart';
+import 'package:test/test.dart';
+
+class _FakeOpaque implements RustOpaqueInterface {
+  @override
+  bool isDisposed = false;
+
+  @override
+  void dispose() => isDisposed = true;
+}
+
+class _FakePage with RustScopeMixin {}
+
+void main() {
+  test('calls outside a scope are not tracked', () async {
+    expect(await trackRustCall((tracked) async => tracked), null);
+  });
+
+  test('calls inside a scope complete before dispose', () async {
+    final scope = RustScope();
+    expect(await scope.run(() => trackRustCall((_) async => 42)), 42);
+    await expectLater(
+        scope.run(() => trackRustCall<int>((_) async => throw StateError('x'))),
+        throwsStateError);
+  });
+
+  test('dispose cancels pending calls', () async {
+    final scope = RustScope();
+    final rustSide = Completer<int>();
+    var cancelledOnRust = 0;
+    final future = scope.run(() => trackRustCall((tracked) {
+          tracked!.cancel = () => cancelledOnRust++;
+          return rustSide.future;
+        }));
+
+    scope.dispose();
+    expect(cancelledOnRust, 1);
+    await expectLater(future, throwsA(isA<RustScopeCancelledException>()));
+
+    // Results arriving later are ignored, and the Rust side is not told twice
+    rustSide.complete(42);
+    await Future<void>.delayed(Duration.zero);
+    expect(cancelledOnRust, 1);
+
+    expect(() => scope.run(() {}), throwsStateError);
+  });
+
+  test('completed calls are not cancelled', () async {
+    final scope = RustScope();
+    var cancelledOnRust = false;
+    await scope.run(() => trackRustCall((tracked) async {
+          tracked!.cancel = () => cancelledOnRust = true;
+          return 42;
+        }));
+
+    scope.dispose();
+    expect(cancelledOnRust, false);
+  });
+
+  test('dispose releases opaque objects returned later', () async {
+    final scope = RustScope();
+    final single = Completer<_FakeOpaque>();
+    final list = Completer<List<_FakeOpaque>>();
+    final singleFuture = scope.run(() => trackRustCall((_) => single.future));
+    final listFuture = scope.run(() => trackRustCall((_) => list.future));
+    scope.dispose();
+    await expectLater(
+        singleFuture, throwsA(isA<RustScopeCancelledException>()));
+    await expectLater(listFuture, throwsA(isA<RustScopeCancelledException>()));
+
+    final opaque = _FakeOpaque();
+    final opaqueList = [_FakeOpaque(), _FakeOpaque()];
+    single.complete(opaque);
+    list.complete(opaqueList);
+    await Future<void>.delayed(Duration.zero);
+    expect(opaque.isDisposed, true);
+    expect(opaqueList.map((x) => x.isDisposed), [true, true]);
+  });
+
+  test('calls after await inside a disposed scope are not executed', () async {
+    final scope = RustScope();
+    final gate = Completer<void>();
+    var called = false;
+    final future = scope.run(() async {
+      await gate.future;
+      await trackRustCall((_) async => called = true);
+    });
+
+    scope.dispose();
+    gate.complete();
+    await expectLater(future, throwsA(isA<RustScopeCancelledException>()));
+    expect(called, false);
+  });
+
+  test('dispose closes tracked streams', () {
+    final scope = RustScope();
+    var closedA = false, closedB = false;
+    scope.run(() {
+      trackRustStream(() => closedA = true);
+      final untrackB = trackRustStream(() => closedB = true);
+      untrackB();
+    });
+
+    scope.dispose();
+    expect(closedA, true);
+    expect(closedB, false);
+  });
+
+  test('RustScopeMixin stops the work when the owner is disposed', () async {
+    final page = _FakePage();
+    final rustSide = Completer<int>();
+    var cancelledOnRust = false;
+    int? result;
+    page.runInRustScope(() async {
+      result = await trackRustCall((tracked) {
+        tracked!.cancel = () => cancelledOnRust = true;
+        return rustSide.future;
+      });
+    });
+
+    // The cancellation is not reported as an uncaught error
+    page.disposeRustScope();
+    rustSide.complete(42);
+    await Future<void>.delayed(Duration.zero);
+    expect(page.rustScope.isDisposed, true);
+    expect(cancelledOnRust, true);
+    expect(result, null);
+  });
+
+  test('RustScopeMixin reports other errors', () async {
+    final errors = <Object>[];
+    await runZonedGuarded(() async {
+      _FakePage().runInRustScope(
+          () => trackRustCall<int>((_) async => throw StateError('x')));
+      await Future<void>.delayed(Duration.zero);
+    }, (error, _) => errors.add(error));
+    expect(errors, [isA<StateError>()]);
+  });
+}
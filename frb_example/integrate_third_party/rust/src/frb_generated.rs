@@ -31024,38 +31024,21 @@ impl SseDecode for isize {
 impl SseDecode for Vec<MediaStreamTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<MediaStreamTrack>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<web_audio_api::AudioParamDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<web_audio_api::AudioParamDescriptor>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Vec<f32>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -31063,11 +31046,7 @@ impl SseDecode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -31075,11 +31054,7 @@ impl SseDecode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -31087,11 +31062,7 @@ impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -31110,44 +31081,28 @@ impl SseDecode for web_audio_api::media_streams::MediaStreamTrackState {
 impl SseDecode for Option<AudioBuffer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<AudioBuffer>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<PeriodicWave> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<PeriodicWave>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -37779,30 +37734,21 @@ impl SseEncode for isize {
 impl SseEncode for Vec<MediaStreamTrack> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <MediaStreamTrack>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<web_audio_api::AudioParamDescriptor> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <web_audio_api::AudioParamDescriptor>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Vec<f32>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -37810,9 +37756,7 @@ impl SseEncode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -37820,9 +37764,7 @@ impl SseEncode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -37830,9 +37772,7 @@ impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -37855,40 +37795,28 @@ impl SseEncode for web_audio_api::media_streams::MediaStreamTrackState {
 impl SseEncode for Option<AudioBuffer> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <AudioBuffer>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<PeriodicWave> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <PeriodicWave>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -133740,15 +133740,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OpaqueItemTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
@@ -133756,15 +133750,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi);
   }
 
   @protected
@@ -133772,15 +133760,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -133788,15 +133770,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -133804,15 +133780,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinRustAsyncMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi);
   }
 
   @protected
@@ -133820,15 +133790,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse);
   }
 
   @protected
@@ -133836,15 +133800,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinRustAsyncSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi);
   }
 
   @protected
@@ -133852,15 +133810,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse);
   }
 
   @protected
@@ -133868,15 +133820,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi);
   }
 
   @protected
@@ -133884,15 +133830,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
@@ -133900,15 +133840,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSyncMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi);
   }
 
   @protected
@@ -133916,15 +133850,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse);
   }
 
   @protected
@@ -133932,15 +133860,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSyncSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi);
   }
 
   @protected
@@ -133948,102 +133870,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OpaqueItemTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
   List<Duration> sse_decode_list_Chrono_Duration(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Duration>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Chrono_Duration(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Chrono_Duration);
   }
 
   @protected
   List<DateTime> sse_decode_list_Chrono_Local(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DateTime>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Chrono_Local(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Chrono_Local);
   }
 
   @protected
   List<DateTime> sse_decode_list_Chrono_Naive(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DateTime>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Chrono_Naive(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Chrono_Naive);
   }
 
   @protected
   List<Object> sse_decode_list_DartOpaque(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Object>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_DartOpaque(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_DartOpaque);
   }
 
   @protected
   List<HideDataTwinMoi> sse_decode_list_RustOpaque_HideDataTwinMoi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinMoi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_RustOpaque_HideDataTwinMoi);
   }
 
   @protected
   List<HideDataTwinNormal> sse_decode_list_RustOpaque_HideDataTwinNormal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinNormal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
   List<HideDataTwinRustAsync> sse_decode_list_RustOpaque_HideDataTwinRustAsync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinRustAsync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
@@ -134051,13 +133927,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_RustOpaque_HideDataTwinRustAsyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinRustAsyncMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinRustAsyncMoi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinRustAsyncMoi);
   }
 
   @protected
@@ -134065,13 +133936,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_RustOpaque_HideDataTwinRustAsyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinRustAsyncSse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinRustAsyncSse);
   }
 
   @protected
@@ -134079,78 +133945,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_RustOpaque_HideDataTwinRustAsyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinRustAsyncSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinRustAsyncSseMoi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinRustAsyncSseMoi);
   }
 
   @protected
   List<HideDataTwinSse> sse_decode_list_RustOpaque_HideDataTwinSse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_RustOpaque_HideDataTwinSse);
   }
 
   @protected
   List<HideDataTwinSseMoi> sse_decode_list_RustOpaque_HideDataTwinSseMoi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSseMoi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinSseMoi);
   }
 
   @protected
   List<HideDataTwinSync> sse_decode_list_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   List<HideDataTwinSyncMoi> sse_decode_list_RustOpaque_HideDataTwinSyncMoi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSyncMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSyncMoi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinSyncMoi);
   }
 
   @protected
   List<HideDataTwinSyncSse> sse_decode_list_RustOpaque_HideDataTwinSyncSse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSyncSse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinSyncSse);
   }
 
   @protected
@@ -134158,180 +133992,98 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_RustOpaque_HideDataTwinSyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSyncSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSyncSseMoi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinSyncSseMoi);
   }
 
   @protected
   List<RustStreamSink<int>> sse_decode_list_StreamSink_i_32_Dco(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RustStreamSink<int>>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_StreamSink_i_32_Dco(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_StreamSink_i_32_Dco);
   }
 
   @protected
   List<RustStreamSink<int>> sse_decode_list_StreamSink_i_32_Sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RustStreamSink<int>>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_StreamSink_i_32_Sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_StreamSink_i_32_Sse);
   }
 
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_String);
   }
 
   @protected
   List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UuidValue>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Uuid(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Uuid);
   }
 
   @protected
   List<ApplicationEnvVar> sse_decode_list_application_env_var(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApplicationEnvVar>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_application_env_var(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_application_env_var);
   }
 
   @protected
   List<ApplicationMode> sse_decode_list_application_mode(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApplicationMode>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_application_mode(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_application_mode);
   }
 
   @protected
   List<ApplicationSettings> sse_decode_list_application_settings(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApplicationSettings>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_application_settings(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_application_settings);
   }
 
   @protected
   List<AttributeTwinNormal> sse_decode_list_attribute_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_normal);
   }
 
   @protected
   List<AttributeTwinRustAsync> sse_decode_list_attribute_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_rust_async);
   }
 
   @protected
   List<AttributeTwinRustAsyncSse> sse_decode_list_attribute_twin_rust_async_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_attribute_twin_rust_async_sse);
   }
 
   @protected
   List<AttributeTwinSse> sse_decode_list_attribute_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_sse);
   }
 
   @protected
   List<AttributeTwinSync> sse_decode_list_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_sync);
   }
 
   @protected
   List<AttributeTwinSyncSse> sse_decode_list_attribute_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_sync_sse);
   }
 
   @protected
@@ -134339,13 +134091,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_general_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -134353,13 +134100,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_general_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_general_enum_twin_rust_async);
   }
 
   @protected
@@ -134367,39 +134109,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_general_enum_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_general_enum_twin_rust_async_sse);
   }
 
   @protected
   List<BasicGeneralEnumTwinSse> sse_decode_list_basic_general_enum_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_general_enum_twin_sse);
   }
 
   @protected
   List<BasicGeneralEnumTwinSync> sse_decode_list_basic_general_enum_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -134407,13 +134132,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_general_enum_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_general_enum_twin_sync_sse);
   }
 
   @protected
@@ -134421,13 +134141,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -134435,13 +134150,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -134449,27 +134159,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_basic_primitive_enum_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_rust_async_sse);
   }
 
   @protected
   List<BasicPrimitiveEnumTwinSse> sse_decode_list_basic_primitive_enum_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_sse);
   }
 
   @protected
@@ -134477,13 +134176,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_sync);
   }
 
   @protected
@@ -134491,39 +134185,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_sync_sse);
   }
 
   @protected
   List<BasicStructTwinNormal> sse_decode_list_basic_struct_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_normal);
   }
 
   @protected
   List<BasicStructTwinRustAsync> sse_decode_list_basic_struct_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_rust_async);
   }
 
   @protected
@@ -134531,181 +134208,98 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_struct_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_struct_twin_rust_async_sse);
   }
 
   @protected
   List<BasicStructTwinSse> sse_decode_list_basic_struct_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_sse);
   }
 
   @protected
   List<BasicStructTwinSync> sse_decode_list_basic_struct_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_sync);
   }
 
   @protected
   List<BasicStructTwinSyncSse> sse_decode_list_basic_struct_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_sync_sse);
   }
 
   @protected
   List<bool> sse_decode_list_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <bool>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_bool(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_bool);
   }
 
   @protected
   List<ElementTwinNormal> sse_decode_list_element_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_normal);
   }
 
   @protected
   List<ElementTwinRustAsync> sse_decode_list_element_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_rust_async);
   }
 
   @protected
   List<ElementTwinRustAsyncSse> sse_decode_list_element_twin_rust_async_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_rust_async_sse);
   }
 
   @protected
   List<ElementTwinSse> sse_decode_list_element_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_sse);
   }
 
   @protected
   List<ElementTwinSync> sse_decode_list_element_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_sync);
   }
 
   @protected
   List<ElementTwinSyncSse> sse_decode_list_element_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_sync_sse);
   }
 
   @protected
   List<EnumOpaqueTwinMoi> sse_decode_list_enum_opaque_twin_moi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_moi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_moi);
   }
 
   @protected
   List<EnumOpaqueTwinNormal> sse_decode_list_enum_opaque_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_normal);
   }
 
   @protected
   List<EnumOpaqueTwinRustAsync> sse_decode_list_enum_opaque_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_rust_async);
   }
 
   @protected
@@ -134713,13 +134307,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_enum_opaque_twin_rust_async_moi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinRustAsyncMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_rust_async_moi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_enum_opaque_twin_rust_async_moi);
   }
 
   @protected
@@ -134727,13 +134316,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_enum_opaque_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_enum_opaque_twin_rust_async_sse);
   }
 
   @protected
@@ -134741,154 +134325,84 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_enum_opaque_twin_rust_async_sse_moi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinRustAsyncSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_rust_async_sse_moi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_enum_opaque_twin_rust_async_sse_moi);
   }
 
   @protected
   List<EnumOpaqueTwinSse> sse_decode_list_enum_opaque_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_sse);
   }
 
   @protected
   List<EnumOpaqueTwinSseMoi> sse_decode_list_enum_opaque_twin_sse_moi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sse_moi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_sse_moi);
   }
 
   @protected
   List<EnumOpaqueTwinSync> sse_decode_list_enum_opaque_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_sync);
   }
 
   @protected
   List<EnumOpaqueTwinSyncMoi> sse_decode_list_enum_opaque_twin_sync_moi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSyncMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sync_moi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_sync_moi);
   }
 
   @protected
   List<EnumOpaqueTwinSyncSse> sse_decode_list_enum_opaque_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_sync_sse);
   }
 
   @protected
   List<EnumOpaqueTwinSyncSseMoi> sse_decode_list_enum_opaque_twin_sync_sse_moi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSyncSseMoi>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sync_sse_moi(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_enum_opaque_twin_sync_sse_moi);
   }
 
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_list_prim_u_8_strict);
   }
 
   @protected
   List<MyEnum> sse_decode_list_my_enum(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyEnum>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_enum(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_enum);
   }
 
   @protected
   List<MySize> sse_decode_list_my_size(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MySize>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_size(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_size);
   }
 
   @protected
   List<MyTreeNodeTwinNormal> sse_decode_list_my_tree_node_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_normal);
   }
 
   @protected
   List<MyTreeNodeTwinRustAsync> sse_decode_list_my_tree_node_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_rust_async);
   }
 
   @protected
@@ -134896,65 +134410,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_my_tree_node_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_my_tree_node_twin_rust_async_sse);
   }
 
   @protected
   List<MyTreeNodeTwinSse> sse_decode_list_my_tree_node_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_sse);
   }
 
   @protected
   List<MyTreeNodeTwinSync> sse_decode_list_my_tree_node_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_sync);
   }
 
   @protected
   List<MyTreeNodeTwinSyncSse> sse_decode_list_my_tree_node_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_sync_sse);
   }
 
   @protected
   List<NestedRawStringMirrored> sse_decode_list_nested_raw_string_mirrored(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NestedRawStringMirrored>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_nested_raw_string_mirrored(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_nested_raw_string_mirrored);
   }
 
   @protected
@@ -134968,13 +134453,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   List<String?> sse_decode_list_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_String(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_opt_String);
   }
 
   @protected
@@ -134982,13 +134461,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_attribute_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinNormal?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_attribute_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
@@ -134996,14 +134470,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_attribute_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinRustAsync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_opt_box_autoadd_attribute_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
@@ -135011,40 +134479,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_attribute_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinRustAsyncSse?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_attribute_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_rust_async_sse);
   }
 
   @protected
   List<AttributeTwinSse?> sse_decode_list_opt_box_autoadd_attribute_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSse?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_attribute_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_sse);
   }
 
   @protected
   List<AttributeTwinSync?> sse_decode_list_opt_box_autoadd_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_attribute_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
@@ -135052,27 +134504,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_attribute_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSyncSse?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_opt_box_autoadd_attribute_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_sync_sse);
   }
 
   @protected
   List<int?> sse_decode_list_opt_box_autoadd_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <int?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_i_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_opt_box_autoadd_i_32);
   }
 
   @protected
@@ -135080,13 +134520,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_weekdays_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinNormal?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
@@ -135094,14 +134529,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_weekdays_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinRustAsync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_opt_box_autoadd_weekdays_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
@@ -135109,40 +134538,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_weekdays_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinRustAsyncSse?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_rust_async_sse);
   }
 
   @protected
   List<WeekdaysTwinSse?> sse_decode_list_opt_box_autoadd_weekdays_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSse?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_sse);
   }
 
   @protected
   List<WeekdaysTwinSync?> sse_decode_list_opt_box_autoadd_weekdays_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
@@ -135150,104 +134563,57 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_weekdays_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSyncSse?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_sync_sse);
   }
 
   @protected
   List<Int32List?> sse_decode_list_opt_list_prim_i_32_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Int32List?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_list_prim_i_32_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_opt_list_prim_i_32_strict);
   }
 
   @protected
   List<PointTwinNormal> sse_decode_list_point_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_normal);
   }
 
   @protected
   List<PointTwinRustAsync> sse_decode_list_point_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_rust_async);
   }
 
   @protected
   List<PointTwinRustAsyncSse> sse_decode_list_point_twin_rust_async_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_rust_async_sse);
   }
 
   @protected
   List<PointTwinSse> sse_decode_list_point_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_sse);
   }
 
   @protected
   List<PointTwinSync> sse_decode_list_point_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_sync);
   }
 
   @protected
   List<PointTwinSyncSse> sse_decode_list_point_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_sync_sse);
   }
 
   @protected
@@ -135380,26 +134746,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   List<RawStringEnumMirrored> sse_decode_list_raw_string_enum_mirrored(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RawStringEnumMirrored>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_raw_string_enum_mirrored(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_raw_string_enum_mirrored);
   }
 
   @protected
   List<RawStringMirrored> sse_decode_list_raw_string_mirrored(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RawStringMirrored>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_raw_string_mirrored(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_raw_string_mirrored);
   }
 
   @protected
@@ -135407,14 +134761,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_general_enum_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -135422,14 +134770,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_general_enum_twin_rust_async(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_general_enum_twin_rust_async);
   }
 
   @protected
@@ -135437,14 +134780,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinRustAsyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_general_enum_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_general_enum_twin_rust_async_sse);
   }
 
   @protected
@@ -135452,14 +134790,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_general_enum_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_general_enum_twin_sse);
   }
 
   @protected
@@ -135467,14 +134799,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_general_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -135482,14 +134808,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinSyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_general_enum_twin_sync_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_general_enum_twin_sync_sse);
   }
 
   @protected
@@ -135497,14 +134817,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_primitive_enum_twin_normal(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -135512,14 +134826,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_primitive_enum_twin_rust_async(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -135527,14 +134836,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinRustAsyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_primitive_enum_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_primitive_enum_twin_rust_async_sse);
   }
 
   @protected
@@ -135542,14 +134846,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_primitive_enum_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_primitive_enum_twin_sse);
   }
 
   @protected
@@ -135557,14 +134855,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_primitive_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_primitive_enum_twin_sync);
   }
 
   @protected
@@ -135572,14 +134864,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinSyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_primitive_enum_twin_sync_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_primitive_enum_twin_sync_sse);
   }
 
   @protected
@@ -135587,13 +134874,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_normal);
   }
 
   @protected
@@ -135601,14 +134883,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_struct_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_rust_async);
   }
 
   @protected
@@ -135616,14 +134892,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinRustAsyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_rust_async_sse);
   }
 
   @protected
@@ -135631,13 +134901,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_sse);
   }
 
   @protected
@@ -135645,13 +134910,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_sync);
   }
 
   @protected
@@ -135659,234 +134919,128 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinSyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_sync_sse);
   }
 
   @protected
   List<(int, bool)> sse_decode_list_record_i_32_bool(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, bool)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_bool(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_bool);
   }
 
   @protected
   List<(int, double)> sse_decode_list_record_i_32_f_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, double)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_f_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_f_32);
   }
 
   @protected
   List<(int, double)> sse_decode_list_record_i_32_f_64(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, double)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_f_64(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_f_64);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_i_128(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_128(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_128);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_i_16(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_16(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_16);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_32);
   }
 
   @protected
   List<(int, PlatformInt64)> sse_decode_list_record_i_32_i_64(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, PlatformInt64)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_64(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_64);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_i_8(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_8(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_8);
   }
 
   @protected
   List<(int, PlatformInt64)> sse_decode_list_record_i_32_isize(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, PlatformInt64)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_isize(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_isize);
   }
 
   @protected
   List<(int, Uint8List)> sse_decode_list_record_i_32_list_prim_u_8_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, Uint8List)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_list_prim_u_8_strict);
   }
 
   @protected
   List<(int, String)> sse_decode_list_record_i_32_string(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_string(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_string);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_u_128(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_128(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_128);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_u_16(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_16(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_16);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_u_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_32);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_u_64(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_64(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_64);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_u_8(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_8(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_8);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_usize(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_usize(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_usize);
   }
 
   @protected
@@ -135894,13 +135048,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_enum_simple_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_normal);
   }
 
   @protected
@@ -135908,14 +135057,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_enum_simple_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_rust_async);
   }
 
   @protected
@@ -135923,14 +135066,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinRustAsyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_enum_simple_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_rust_async_sse);
   }
 
   @protected
@@ -135938,13 +135075,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_enum_simple_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_sse);
   }
 
   @protected
@@ -135952,13 +135084,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_enum_simple_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_sync);
   }
 
   @protected
@@ -135966,40 +135093,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinSyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_enum_simple_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_sync_sse);
   }
 
   @protected
   List<(String, HashMapValue)> sse_decode_list_record_string_hash_map_value(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, HashMapValue)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_hash_map_value(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_hash_map_value);
   }
 
   @protected
   List<(String, int)> sse_decode_list_record_string_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_i_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_i_32);
   }
 
   @protected
@@ -136007,13 +135116,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_kitchen_sink_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_normal);
   }
 
   @protected
@@ -136021,14 +135125,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_kitchen_sink_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_rust_async);
   }
 
   @protected
@@ -136036,14 +135134,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinRustAsyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_kitchen_sink_twin_rust_async_sse(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_string_kitchen_sink_twin_rust_async_sse);
   }
 
   @protected
@@ -136051,13 +135144,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_kitchen_sink_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_sse);
   }
 
   @protected
@@ -136065,13 +135153,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_kitchen_sink_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_sync);
   }
 
   @protected
@@ -136079,92 +135162,52 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinSyncSse)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_kitchen_sink_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_sync_sse);
   }
 
   @protected
   List<(String, Uint8List)> sse_decode_list_record_string_list_prim_u_8_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, Uint8List)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_list_prim_u_8_strict);
   }
 
   @protected
   List<(String, MySize)> sse_decode_list_record_string_my_size(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, MySize)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_my_size(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_my_size);
   }
 
   @protected
   List<(String, String)> sse_decode_list_record_string_string(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_string);
   }
 
   @protected
   List<(int, ApplicationMode)> sse_decode_list_record_u_8_application_mode(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, ApplicationMode)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_8_application_mode(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_u_8_application_mode);
   }
 
   @protected
   List<SimpleStructTwinNormal> sse_decode_list_simple_struct_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_simple_struct_twin_normal);
   }
 
   @protected
   List<SimpleStructTwinRustAsync> sse_decode_list_simple_struct_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_simple_struct_twin_rust_async);
   }
 
   @protected
@@ -136172,286 +135215,155 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_simple_struct_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_simple_struct_twin_rust_async_sse);
   }
 
   @protected
   List<SimpleStructTwinSse> sse_decode_list_simple_struct_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_simple_struct_twin_sse);
   }
 
   @protected
   List<SimpleStructTwinSync> sse_decode_list_simple_struct_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_simple_struct_twin_sync);
   }
 
   @protected
   List<SimpleStructTwinSyncSse> sse_decode_list_simple_struct_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_simple_struct_twin_sync_sse);
   }
 
   @protected
   List<SumWithTwinNormal> sse_decode_list_sum_with_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_normal);
   }
 
   @protected
   List<SumWithTwinRustAsync> sse_decode_list_sum_with_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_rust_async);
   }
 
   @protected
   List<SumWithTwinRustAsyncSse> sse_decode_list_sum_with_twin_rust_async_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_rust_async_sse);
   }
 
   @protected
   List<SumWithTwinSse> sse_decode_list_sum_with_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_sse);
   }
 
   @protected
   List<SumWithTwinSync> sse_decode_list_sum_with_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_sync);
   }
 
   @protected
   List<SumWithTwinSyncSse> sse_decode_list_sum_with_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_sync_sse);
   }
 
   @protected
   List<TestIdTwinNormal> sse_decode_list_test_id_twin_normal(
       SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_normal(deserializer));
-    }
-    return ans_;
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_normal);
   }
 
   @protected
   List<TestIdTwinRustAsync> sse_decode_list_test_id_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_rust_async);
   }
 
   @protected
   List<TestIdTwinRustAsyncSse> sse_decode_list_test_id_twin_rust_async_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_rust_async_sse);
   }
 
   @protected
   List<TestIdTwinSse> sse_decode_list_test_id_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_sse);
   }
 
   @protected
   List<TestIdTwinSync> sse_decode_list_test_id_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_sync);
   }
 
   @protected
   List<TestIdTwinSyncSse> sse_decode_list_test_id_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_sync_sse);
   }
 
   @protected
   List<WeekdaysTwinNormal> sse_decode_list_weekdays_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_normal);
   }
 
   @protected
   List<WeekdaysTwinRustAsync> sse_decode_list_weekdays_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_rust_async);
   }
 
   @protected
   List<WeekdaysTwinRustAsyncSse> sse_decode_list_weekdays_twin_rust_async_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinRustAsyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_rust_async_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_rust_async_sse);
   }
 
   @protected
   List<WeekdaysTwinSse> sse_decode_list_weekdays_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_sse);
   }
 
   @protected
   List<WeekdaysTwinSync> sse_decode_list_weekdays_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_sync);
   }
 
   @protected
   List<WeekdaysTwinSyncSse> sse_decode_list_weekdays_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSyncSse>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_sync_sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_sync_sse);
   }
 
   @protected
@@ -137420,34 +136332,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   BigInt? sse_decode_opt_I128(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_I128(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_I128);
   }
 
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_String);
   }
 
   @protected
   BigInt? sse_decode_opt_U128(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_U128(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_U128);
   }
 
   @protected
@@ -137455,13 +136352,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi);
   }
 
   @protected
@@ -137469,13 +136362,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -137483,13 +136372,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -137497,13 +136382,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi);
   }
 
   @protected
@@ -137511,13 +136392,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse);
   }
 
   @protected
@@ -137525,13 +136402,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi);
   }
 
   @protected
@@ -137539,13 +136412,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse);
   }
 
   @protected
@@ -137553,13 +136422,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi);
   }
 
   @protected
@@ -137567,13 +136432,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
@@ -137581,13 +136442,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi);
   }
 
   @protected
@@ -137595,13 +136452,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse);
   }
 
   @protected
@@ -137609,60 +136462,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi);
   }
 
   @protected
   Duration? sse_decode_opt_box_autoadd_Chrono_Duration(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Chrono_Duration(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_Chrono_Duration);
   }
 
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Naive(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Chrono_Naive(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_Chrono_Naive);
   }
 
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Chrono_Utc(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_Chrono_Utc);
   }
 
   @protected
   Object? sse_decode_opt_box_autoadd_DartOpaque(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_DartOpaque(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_DartOpaque);
   }
 
   @protected
@@ -137670,13 +136500,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinMoi);
   }
 
   @protected
@@ -137684,13 +136509,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal);
   }
 
   @protected
@@ -137698,13 +136519,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinSse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinSse);
   }
 
   @protected
@@ -137712,38 +136528,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinSseMoi);
   }
 
   @protected
   HideDataTwinMoi? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinMoi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinMoi(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinMoi);
   }
 
   @protected
   HideDataTwinNormal? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinNormal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinNormal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
@@ -137751,13 +136554,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
@@ -137765,13 +136563,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi);
   }
 
   @protected
@@ -137779,13 +136573,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSse);
   }
 
   @protected
@@ -137793,50 +136583,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSseMoi);
   }
 
   @protected
   HideDataTwinSse? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSse);
   }
 
   @protected
   HideDataTwinSseMoi? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSseMoi(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSseMoi);
   }
 
   @protected
   HideDataTwinSync? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSync);
   }
 
   @protected
@@ -137844,13 +136617,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSyncMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncMoi);
   }
 
   @protected
@@ -137858,13 +136626,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncSse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncSse);
   }
 
   @protected
@@ -137872,61 +136635,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi);
   }
 
   @protected
   ApplicationEnv? sse_decode_opt_box_autoadd_application_env(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_application_env(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_application_env);
   }
 
   @protected
   ApplicationMode? sse_decode_opt_box_autoadd_application_mode(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_application_mode(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_application_mode);
   }
 
   @protected
   AttributeTwinNormal? sse_decode_opt_box_autoadd_attribute_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   AttributeTwinRustAsync? sse_decode_opt_box_autoadd_attribute_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
@@ -137934,49 +136676,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_attribute_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_rust_async_sse);
   }
 
   @protected
   AttributeTwinSse? sse_decode_opt_box_autoadd_attribute_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_sse);
   }
 
   @protected
   AttributeTwinSync? sse_decode_opt_box_autoadd_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   AttributeTwinSyncSse? sse_decode_opt_box_autoadd_attribute_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_sync_sse);
   }
 
   @protected
@@ -137984,13 +136709,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -137998,13 +136718,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_general_enum_twin_rust_async);
   }
 
   @protected
@@ -138012,13 +136728,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_general_enum_twin_rust_async_sse);
   }
 
   @protected
@@ -138026,12 +136738,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_general_enum_twin_sse);
   }
 
   @protected
@@ -138039,13 +136747,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_sync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -138053,13 +136756,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_sync_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_general_enum_twin_sync_sse);
   }
 
   @protected
@@ -138067,13 +136765,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -138081,13 +136774,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -138095,13 +136784,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_primitive_enum_twin_rust_async_sse);
   }
 
   @protected
@@ -138109,13 +136794,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_primitive_enum_twin_sse);
   }
 
   @protected
@@ -138123,13 +136803,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_sync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_primitive_enum_twin_sync);
   }
 
   @protected
@@ -138137,25 +136812,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_sync_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_primitive_enum_twin_sync_sse);
   }
 
   @protected
   BasicStructTwinNormal? sse_decode_opt_box_autoadd_basic_struct_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_normal);
   }
 
   @protected
@@ -138163,13 +136830,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_struct_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_rust_async);
   }
 
   @protected
@@ -138177,84 +136839,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_struct_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_rust_async_sse);
   }
 
   @protected
   BasicStructTwinSse? sse_decode_opt_box_autoadd_basic_struct_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_sse);
   }
 
   @protected
   BasicStructTwinSync? sse_decode_opt_box_autoadd_basic_struct_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_sync);
   }
 
   @protected
   BasicStructTwinSyncSse? sse_decode_opt_box_autoadd_basic_struct_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_sync_sse);
   }
 
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_bool);
   }
 
   @protected
   ElementTwinNormal? sse_decode_opt_box_autoadd_element_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_normal);
   }
 
   @protected
   ElementTwinRustAsync? sse_decode_opt_box_autoadd_element_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_rust_async);
   }
 
   @protected
@@ -138262,48 +136894,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_element_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_rust_async_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_rust_async_sse);
   }
 
   @protected
   ElementTwinSse? sse_decode_opt_box_autoadd_element_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_sse);
   }
 
   @protected
   ElementTwinSync? sse_decode_opt_box_autoadd_element_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_sync);
   }
 
   @protected
   ElementTwinSyncSse? sse_decode_opt_box_autoadd_element_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_sync_sse);
   }
 
   @protected
@@ -138311,13 +136927,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_normal);
   }
 
   @protected
@@ -138325,13 +136936,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_rust_async);
   }
 
   @protected
@@ -138339,25 +136945,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_exotic_optionals_twin_rust_async_sse);
   }
 
   @protected
   ExoticOptionalsTwinSse? sse_decode_opt_box_autoadd_exotic_optionals_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_sse);
   }
 
   @protected
@@ -138365,12 +136963,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_sync);
   }
 
   @protected
@@ -138378,115 +136972,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_sync_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_sync_sse);
   }
 
   @protected
   double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_f_32);
   }
 
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_f_64);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_i_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_16(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_16);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_32);
   }
 
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_64);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_i_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_8);
   }
 
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_isize(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_isize(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_isize);
   }
 
   @protected
   MeasureTwinNormal? sse_decode_opt_box_autoadd_measure_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_normal);
   }
 
   @protected
   MeasureTwinRustAsync? sse_decode_opt_box_autoadd_measure_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_rust_async);
   }
 
   @protected
@@ -138494,60 +137040,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_measure_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_rust_async_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_rust_async_sse);
   }
 
   @protected
   MeasureTwinSse? sse_decode_opt_box_autoadd_measure_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_sse);
   }
 
   @protected
   MeasureTwinSync? sse_decode_opt_box_autoadd_measure_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_sync);
   }
 
   @protected
   MeasureTwinSyncSse? sse_decode_opt_box_autoadd_measure_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_sync_sse);
   }
 
   @protected
   NewTypeIntTwinNormal? sse_decode_opt_box_autoadd_new_type_int_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_normal);
   }
 
   @protected
@@ -138555,13 +137081,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_new_type_int_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_rust_async);
   }
 
   @protected
@@ -138569,140 +137090,86 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_new_type_int_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_rust_async_sse);
   }
 
   @protected
   NewTypeIntTwinSse? sse_decode_opt_box_autoadd_new_type_int_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_sse);
   }
 
   @protected
   NewTypeIntTwinSync? sse_decode_opt_box_autoadd_new_type_int_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_sync);
   }
 
   @protected
   NewTypeIntTwinSyncSse? sse_decode_opt_box_autoadd_new_type_int_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_sync_sse);
   }
 
   @protected
   (String, int)? sse_decode_opt_box_autoadd_record_string_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_record_string_i_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_record_string_i_32);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_16(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_16);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_32);
   }
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_64);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_8);
   }
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_usize);
   }
 
   @protected
   WeekdaysTwinNormal? sse_decode_opt_box_autoadd_weekdays_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
   WeekdaysTwinRustAsync? sse_decode_opt_box_autoadd_weekdays_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
@@ -138710,49 +137177,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_weekdays_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_rust_async_sse);
   }
 
   @protected
   WeekdaysTwinSse? sse_decode_opt_box_autoadd_weekdays_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_sse);
   }
 
   @protected
   WeekdaysTwinSync? sse_decode_opt_box_autoadd_weekdays_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
   WeekdaysTwinSyncSse? sse_decode_opt_box_autoadd_weekdays_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_sync_sse);
   }
 
   @protected
@@ -138760,12 +137210,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_normal);
   }
 
   @protected
@@ -138773,13 +137219,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_rust_async);
   }
 
   @protected
@@ -138787,25 +137228,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_rust_async_sse);
   }
 
   @protected
   BenchmarkBinaryTreeTwinSse? sse_decode_opt_box_benchmark_binary_tree_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_sse);
   }
 
   @protected
@@ -138813,12 +137245,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_sync);
   }
 
   @protected
@@ -138826,35 +137254,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_sync_sse);
   }
 
   @protected
   bool? sse_decode_opt_box_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_bool(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_bool);
   }
 
   @protected
   ExoticOptionalsTwinNormal? sse_decode_opt_box_exotic_optionals_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_normal);
   }
 
   @protected
@@ -138862,12 +137277,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_exotic_optionals_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_rust_async);
   }
 
   @protected
@@ -138875,128 +137286,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_exotic_optionals_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_rust_async_sse);
   }
 
   @protected
   ExoticOptionalsTwinSse? sse_decode_opt_box_exotic_optionals_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_sse);
   }
 
   @protected
   ExoticOptionalsTwinSync? sse_decode_opt_box_exotic_optionals_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_sync);
   }
 
   @protected
   ExoticOptionalsTwinSyncSse? sse_decode_opt_box_exotic_optionals_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_sync_sse);
   }
 
   @protected
   double? sse_decode_opt_box_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_f_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_f_64);
   }
 
   @protected
   int? sse_decode_opt_box_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_i_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_i_32);
   }
 
   @protected
   PlatformInt64? sse_decode_opt_box_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_i_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_i_64);
   }
 
   @protected
   int? sse_decode_opt_box_i_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_i_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_i_8);
   }
 
   @protected
   int? sse_decode_opt_box_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_u_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_u_8);
   }
 
   @protected
   List<AttributeTwinNormal>? sse_decode_opt_list_attribute_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_attribute_twin_normal);
   }
 
   @protected
   List<AttributeTwinRustAsync>? sse_decode_opt_list_attribute_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_attribute_twin_rust_async);
   }
 
   @protected
@@ -139004,72 +137365,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_attribute_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_rust_async_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_attribute_twin_rust_async_sse);
   }
 
   @protected
   List<AttributeTwinSse>? sse_decode_opt_list_attribute_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_attribute_twin_sse);
   }
 
   @protected
   List<AttributeTwinSync>? sse_decode_opt_list_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_attribute_twin_sync);
   }
 
   @protected
   List<AttributeTwinSyncSse>? sse_decode_opt_list_attribute_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_attribute_twin_sync_sse);
   }
 
   @protected
   List<ElementTwinNormal>? sse_decode_opt_list_element_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_element_twin_normal);
   }
 
   @protected
   List<ElementTwinRustAsync>? sse_decode_opt_list_element_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_element_twin_rust_async);
   }
 
   @protected
@@ -139077,48 +137411,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_element_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_rust_async_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_element_twin_rust_async_sse);
   }
 
   @protected
   List<ElementTwinSse>? sse_decode_opt_list_element_twin_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_element_twin_sse);
   }
 
   @protected
   List<ElementTwinSync>? sse_decode_opt_list_element_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_element_twin_sync);
   }
 
   @protected
   List<ElementTwinSyncSse>? sse_decode_opt_list_element_twin_sync_sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_sync_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_element_twin_sync_sse);
   }
 
   @protected
@@ -139126,13 +137442,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
@@ -139140,13 +137451,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_list_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
@@ -139154,13 +137461,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_rust_async_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_rust_async_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_list_opt_box_autoadd_attribute_twin_rust_async_sse);
   }
 
   @protected
@@ -139168,12 +137471,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_sse(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_opt_box_autoadd_attribute_twin_sse);
   }
 
   @protected
@@ -139181,13 +137480,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_sync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
@@ -139195,71 +137489,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_sync_sse(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_sync_sse(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_opt_box_autoadd_attribute_twin_sync_sse);
   }
 
   @protected
   Float32List? sse_decode_opt_list_prim_f_32_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_f_32_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_f_32_strict);
   }
 
   @protected
   Float64List? sse_decode_opt_list_prim_f_64_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_f_64_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_f_64_strict);
   }
 
   @protected
   Int32List? sse_decode_opt_list_prim_i_32_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_i_32_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_i_32_strict);
   }
 
   @protected
   Int8List? sse_decode_opt_list_prim_i_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_i_8_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_i_8_strict);
   }
 
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_u_8_strict);
   }
 
   @protected
@@ -159802,11 +158066,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           List<OpaqueItemTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
@@ -159814,11 +158077,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
           List<NonCloneSimpleTwinMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi);
   }
 
   @protected
@@ -159826,11 +158088,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           List<NonCloneSimpleTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -159838,11 +158099,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           List<NonCloneSimpleTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -159850,11 +158110,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
           List<NonCloneSimpleTwinRustAsyncMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi);
   }
 
   @protected
@@ -159862,11 +158121,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
           List<NonCloneSimpleTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse);
   }
 
   @protected
@@ -159875,11 +158133,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           List<NonCloneSimpleTwinRustAsyncSseMoi> self,
           SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi);
   }
 
   @protected
@@ -159887,11 +158144,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
           List<NonCloneSimpleTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse);
   }
 
   @protected
@@ -159899,11 +158155,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
           List<NonCloneSimpleTwinSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi);
   }
 
   @protected
@@ -159911,11 +158166,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           List<NonCloneSimpleTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
@@ -159923,11 +158177,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
           List<NonCloneSimpleTwinSyncMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi);
   }
 
   @protected
@@ -159935,11 +158188,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
           List<NonCloneSimpleTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse);
   }
 
   @protected
@@ -159947,11 +158199,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
           List<NonCloneSimpleTwinSyncSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi);
   }
 
   @protected
@@ -159959,765 +158210,551 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           List<OpaqueItemTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
   void sse_encode_list_Chrono_Duration(
       List<Duration> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Chrono_Duration(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Chrono_Duration);
   }
 
   @protected
   void sse_encode_list_Chrono_Local(
       List<DateTime> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Chrono_Local(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Chrono_Local);
   }
 
   @protected
   void sse_encode_list_Chrono_Naive(
       List<DateTime> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Chrono_Naive(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Chrono_Naive);
   }
 
   @protected
   void sse_encode_list_DartOpaque(List<Object> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_DartOpaque(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_DartOpaque);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinMoi(
       List<HideDataTwinMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinMoi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinMoi);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinNormal(
       List<HideDataTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinNormal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinRustAsync(
       List<HideDataTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinRustAsync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinRustAsyncMoi(
       List<HideDataTwinRustAsyncMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinRustAsyncMoi(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_RustOpaque_HideDataTwinRustAsyncMoi);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinRustAsyncSse(
       List<HideDataTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinRustAsyncSse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_RustOpaque_HideDataTwinRustAsyncSse);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinRustAsyncSseMoi(
       List<HideDataTwinRustAsyncSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinRustAsyncSseMoi(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_RustOpaque_HideDataTwinRustAsyncSseMoi);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSse(
       List<HideDataTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinSse);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSseMoi(
       List<HideDataTwinSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSseMoi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinSseMoi);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSync(
       List<HideDataTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSyncMoi(
       List<HideDataTwinSyncMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSyncMoi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinSyncMoi);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSyncSse(
       List<HideDataTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSyncSse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinSyncSse);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSyncSseMoi(
       List<HideDataTwinSyncSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSyncSseMoi(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_RustOpaque_HideDataTwinSyncSseMoi);
   }
 
   @protected
   void sse_encode_list_StreamSink_i_32_Dco(
       List<RustStreamSink<int>> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_StreamSink_i_32_Dco(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_StreamSink_i_32_Dco);
   }
 
   @protected
   void sse_encode_list_StreamSink_i_32_Sse(
       List<RustStreamSink<int>> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_StreamSink_i_32_Sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_StreamSink_i_32_Sse);
   }
 
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_String);
   }
 
   @protected
   void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Uuid(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Uuid);
   }
 
   @protected
   void sse_encode_list_application_env_var(
       List<ApplicationEnvVar> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_application_env_var(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_application_env_var);
   }
 
   @protected
   void sse_encode_list_application_mode(
       List<ApplicationMode> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_application_mode(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_application_mode);
   }
 
   @protected
   void sse_encode_list_application_settings(
       List<ApplicationSettings> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_application_settings(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_application_settings);
   }
 
   @protected
   void sse_encode_list_attribute_twin_normal(
       List<AttributeTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_list_attribute_twin_rust_async(
       List<AttributeTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_attribute_twin_rust_async_sse(
       List<AttributeTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_attribute_twin_sse(
       List<AttributeTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_sse);
   }
 
   @protected
   void sse_encode_list_attribute_twin_sync(
       List<AttributeTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_list_attribute_twin_sync_sse(
       List<AttributeTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_normal(
       List<BasicGeneralEnumTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_general_enum_twin_normal);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_rust_async(
       List<BasicGeneralEnumTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_general_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_rust_async_sse(
       List<BasicGeneralEnumTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_general_enum_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_sse(
       List<BasicGeneralEnumTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_general_enum_twin_sse);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_sync(
       List<BasicGeneralEnumTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_general_enum_twin_sync);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_sync_sse(
       List<BasicGeneralEnumTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_general_enum_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_normal(
       List<BasicPrimitiveEnumTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_primitive_enum_twin_normal);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_rust_async(
       List<BasicPrimitiveEnumTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_rust_async_sse(
       List<BasicPrimitiveEnumTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_primitive_enum_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_sse(
       List<BasicPrimitiveEnumTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_primitive_enum_twin_sse);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_sync(
       List<BasicPrimitiveEnumTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_primitive_enum_twin_sync);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_sync_sse(
       List<BasicPrimitiveEnumTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_primitive_enum_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_normal(
       List<BasicStructTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_normal);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_rust_async(
       List<BasicStructTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_rust_async_sse(
       List<BasicStructTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_struct_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_sse(
       List<BasicStructTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_sse);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_sync(
       List<BasicStructTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_sync);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_sync_sse(
       List<BasicStructTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_bool(List<bool> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_bool(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_bool);
   }
 
   @protected
   void sse_encode_list_element_twin_normal(
       List<ElementTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_normal);
   }
 
   @protected
   void sse_encode_list_element_twin_rust_async(
       List<ElementTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_element_twin_rust_async_sse(
       List<ElementTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_element_twin_sse(
       List<ElementTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_sse);
   }
 
   @protected
   void sse_encode_list_element_twin_sync(
       List<ElementTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_sync);
   }
 
   @protected
   void sse_encode_list_element_twin_sync_sse(
       List<ElementTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_moi(
       List<EnumOpaqueTwinMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_moi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_moi);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_normal(
       List<EnumOpaqueTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_normal);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_rust_async(
       List<EnumOpaqueTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_rust_async_moi(
       List<EnumOpaqueTwinRustAsyncMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_rust_async_moi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_rust_async_moi);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_rust_async_sse(
       List<EnumOpaqueTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_rust_async_sse_moi(
       List<EnumOpaqueTwinRustAsyncSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_rust_async_sse_moi(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_enum_opaque_twin_rust_async_sse_moi);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sse(
       List<EnumOpaqueTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sse);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sse_moi(
       List<EnumOpaqueTwinSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sse_moi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sse_moi);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sync(
       List<EnumOpaqueTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sync);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sync_moi(
       List<EnumOpaqueTwinSyncMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sync_moi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sync_moi);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sync_sse(
       List<EnumOpaqueTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sync_sse_moi(
       List<EnumOpaqueTwinSyncSseMoi> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sync_sse_moi(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sync_sse_moi);
   }
 
   @protected
   void sse_encode_list_list_prim_u_8_strict(
       List<Uint8List> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_list_prim_u_8_strict);
   }
 
   @protected
   void sse_encode_list_my_enum(List<MyEnum> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_enum(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_enum);
   }
 
   @protected
   void sse_encode_list_my_size(List<MySize> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_size(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_size);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_normal(
       List<MyTreeNodeTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_normal);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_rust_async(
       List<MyTreeNodeTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_rust_async_sse(
       List<MyTreeNodeTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_my_tree_node_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_sse(
       List<MyTreeNodeTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_sse);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_sync(
       List<MyTreeNodeTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_sync);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_sync_sse(
       List<MyTreeNodeTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_nested_raw_string_mirrored(
       List<NestedRawStringMirrored> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_nested_raw_string_mirrored(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_nested_raw_string_mirrored);
   }
 
   @protected
@@ -160731,211 +158768,163 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_list_opt_String(
       List<String?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_String(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_opt_String);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_normal(
       List<AttributeTwinNormal?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_rust_async(
       List<AttributeTwinRustAsync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_rust_async_sse(
       List<AttributeTwinRustAsyncSse?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_rust_async_sse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_opt_box_autoadd_attribute_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_sse(
       List<AttributeTwinSse?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_sse);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_sync(
       List<AttributeTwinSync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_sync_sse(
       List<AttributeTwinSyncSse?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_i_32(
       List<int?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_i_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_opt_box_autoadd_i_32);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_normal(
       List<WeekdaysTwinNormal?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_rust_async(
       List<WeekdaysTwinRustAsync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_rust_async_sse(
       List<WeekdaysTwinRustAsyncSse?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_opt_box_autoadd_weekdays_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_sse(
       List<WeekdaysTwinSse?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_sse);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_sync(
       List<WeekdaysTwinSync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_sync_sse(
       List<WeekdaysTwinSyncSse?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_opt_list_prim_i_32_strict(
       List<Int32List?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_list_prim_i_32_strict(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_opt_list_prim_i_32_strict);
   }
 
   @protected
   void sse_encode_list_point_twin_normal(
       List<PointTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_normal);
   }
 
   @protected
   void sse_encode_list_point_twin_rust_async(
       List<PointTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_point_twin_rust_async_sse(
       List<PointTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_point_twin_sse(
       List<PointTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_sse);
   }
 
   @protected
   void sse_encode_list_point_twin_sync(
       List<PointTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_sync);
   }
 
   @protected
   void sse_encode_list_point_twin_sync_sse(
       List<PointTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_sync_sse);
   }
 
   @protected
@@ -161094,30 +159083,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_list_raw_string_enum_mirrored(
       List<RawStringEnumMirrored> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_raw_string_enum_mirrored(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_raw_string_enum_mirrored);
   }
 
   @protected
   void sse_encode_list_raw_string_mirrored(
       List<RawStringMirrored> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_raw_string_mirrored(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_raw_string_mirrored);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_general_enum_twin_normal(
       List<(int, BasicGeneralEnumTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -161125,11 +159108,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicGeneralEnumTwinRustAsync)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_rust_async(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_general_enum_twin_rust_async);
   }
 
   @protected
@@ -161137,41 +159119,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicGeneralEnumTwinRustAsyncSse)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_rust_async_sse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_general_enum_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_general_enum_twin_sse(
       List<(int, BasicGeneralEnumTwinSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_general_enum_twin_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_general_enum_twin_sync(
       List<(int, BasicGeneralEnumTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_general_enum_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_general_enum_twin_sync_sse(
       List<(int, BasicGeneralEnumTwinSyncSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_general_enum_twin_sync_sse);
   }
 
   @protected
@@ -161179,10 +159156,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicPrimitiveEnumTwinNormal)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -161190,11 +159167,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicPrimitiveEnumTwinRustAsync)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_rust_async(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -161202,31 +159178,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicPrimitiveEnumTwinRustAsyncSse)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_rust_async_sse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_primitive_enum_twin_sse(
       List<(int, BasicPrimitiveEnumTwinSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_primitive_enum_twin_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_primitive_enum_twin_sync(
       List<(int, BasicPrimitiveEnumTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_sync);
   }
 
   @protected
@@ -161234,261 +159207,196 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicPrimitiveEnumTwinSyncSse)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_sync_sse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_normal(
       List<(int, BasicStructTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_normal);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_rust_async(
       List<(int, BasicStructTwinRustAsync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_rust_async_sse(
       List<(int, BasicStructTwinRustAsyncSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_struct_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_sse(
       List<(int, BasicStructTwinSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_sync(
       List<(int, BasicStructTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_sync_sse(
       List<(int, BasicStructTwinSyncSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_record_i_32_bool(
       List<(int, bool)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_bool(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_bool);
   }
 
   @protected
   void sse_encode_list_record_i_32_f_32(
       List<(int, double)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_f_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_f_32);
   }
 
   @protected
   void sse_encode_list_record_i_32_f_64(
       List<(int, double)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_f_64(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_f_64);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_128(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_128(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_128);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_16(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_16(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_16);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_32(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_32);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_64(
       List<(int, PlatformInt64)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_64(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_64);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_8(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_8(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_8);
   }
 
   @protected
   void sse_encode_list_record_i_32_isize(
       List<(int, PlatformInt64)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_isize(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_isize);
   }
 
   @protected
   void sse_encode_list_record_i_32_list_prim_u_8_strict(
       List<(int, Uint8List)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_list_prim_u_8_strict(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_list_prim_u_8_strict);
   }
 
   @protected
   void sse_encode_list_record_i_32_string(
       List<(int, String)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_string(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_string);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_128(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_128(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_128);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_16(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_16(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_16);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_32(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_32);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_64(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_64(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_64);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_8(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_8(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_8);
   }
 
   @protected
   void sse_encode_list_record_i_32_usize(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_usize(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_usize);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_normal(
       List<(String, EnumSimpleTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_normal);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_rust_async(
       List<(String, EnumSimpleTwinRustAsync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_rust_async);
   }
 
   @protected
@@ -161496,81 +159404,66 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(String, EnumSimpleTwinRustAsyncSse)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_rust_async_sse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_string_enum_simple_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_sse(
       List<(String, EnumSimpleTwinSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_sse);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_sync(
       List<(String, EnumSimpleTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_sync_sse(
       List<(String, EnumSimpleTwinSyncSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_record_string_hash_map_value(
       List<(String, HashMapValue)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_hash_map_value(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_hash_map_value);
   }
 
   @protected
   void sse_encode_list_record_string_i_32(
       List<(String, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_i_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_i_32);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_normal(
       List<(String, KitchenSinkTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_kitchen_sink_twin_normal);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_rust_async(
       List<(String, KitchenSinkTwinRustAsync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_string_kitchen_sink_twin_rust_async);
   }
 
   @protected
@@ -161578,321 +159471,232 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(String, KitchenSinkTwinRustAsyncSse)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_rust_async_sse(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_string_kitchen_sink_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_sse(
       List<(String, KitchenSinkTwinSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_kitchen_sink_twin_sse);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_sync(
       List<(String, KitchenSinkTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_kitchen_sink_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_sync_sse(
       List<(String, KitchenSinkTwinSyncSse)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_kitchen_sink_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_record_string_list_prim_u_8_strict(
       List<(String, Uint8List)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_list_prim_u_8_strict(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_list_prim_u_8_strict);
   }
 
   @protected
   void sse_encode_list_record_string_my_size(
       List<(String, MySize)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_my_size(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_my_size);
   }
 
   @protected
   void sse_encode_list_record_string_string(
       List<(String, String)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_string);
   }
 
   @protected
   void sse_encode_list_record_u_8_application_mode(
       List<(int, ApplicationMode)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_8_application_mode(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_u_8_application_mode);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_normal(
       List<SimpleStructTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_normal);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_rust_async(
       List<SimpleStructTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_rust_async_sse(
       List<SimpleStructTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_simple_struct_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_sse(
       List<SimpleStructTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_sse);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_sync(
       List<SimpleStructTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_sync);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_sync_sse(
       List<SimpleStructTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_normal(
       List<SumWithTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_normal);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_rust_async(
       List<SumWithTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_rust_async_sse(
       List<SumWithTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_sse(
       List<SumWithTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_sse);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_sync(
       List<SumWithTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_sync);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_sync_sse(
       List<SumWithTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_test_id_twin_normal(
       List<TestIdTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_normal);
   }
 
   @protected
   void sse_encode_list_test_id_twin_rust_async(
       List<TestIdTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_test_id_twin_rust_async_sse(
       List<TestIdTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_test_id_twin_sse(
       List<TestIdTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_sse);
   }
 
   @protected
   void sse_encode_list_test_id_twin_sync(
       List<TestIdTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_sync);
   }
 
   @protected
   void sse_encode_list_test_id_twin_sync_sse(
       List<TestIdTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_sync_sse);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_normal(
       List<WeekdaysTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_normal);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_rust_async(
       List<WeekdaysTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_rust_async_sse(
       List<WeekdaysTwinRustAsyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_rust_async_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_sse(
       List<WeekdaysTwinSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_sse);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_sync(
       List<WeekdaysTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_sync);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_sync_sse(
       List<WeekdaysTwinSyncSse> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_sync_sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_sync_sse);
   }
 
   @protected
@@ -162698,31 +160502,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_opt_I128(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_I128(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_I128);
   }
 
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_String);
   }
 
   @protected
   void sse_encode_opt_U128(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_U128(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_U128);
   }
 
   @protected
@@ -162730,12 +160522,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
           NonCloneSimpleTwinMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinMoi);
   }
 
   @protected
@@ -162743,12 +160533,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           NonCloneSimpleTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -162756,12 +160544,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           NonCloneSimpleTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -162769,12 +160555,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
           NonCloneSimpleTwinRustAsyncMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncMoi);
   }
 
   @protected
@@ -162782,12 +160566,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
           NonCloneSimpleTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSse);
   }
 
   @protected
@@ -162795,12 +160577,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
           NonCloneSimpleTwinRustAsyncSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsyncSseMoi);
   }
 
   @protected
@@ -162808,12 +160588,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
           NonCloneSimpleTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSse);
   }
 
   @protected
@@ -162821,12 +160599,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
           NonCloneSimpleTwinSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSseMoi);
   }
 
   @protected
@@ -162834,12 +160610,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           NonCloneSimpleTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
@@ -162847,12 +160621,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
           NonCloneSimpleTwinSyncMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncMoi);
   }
 
   @protected
@@ -162860,12 +160632,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
           NonCloneSimpleTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSse);
   }
 
   @protected
@@ -162873,1454 +160643,1066 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
           NonCloneSimpleTwinSyncSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSyncSseMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Duration(
       Duration? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Chrono_Duration(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_Chrono_Duration);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Naive(
       DateTime? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Chrono_Naive(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_Chrono_Naive);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Utc(
       DateTime? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Chrono_Utc(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_Chrono_Utc);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_DartOpaque(
       Object? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_DartOpaque(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_DartOpaque);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinMoi(
       HideDataAnotherTwinMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
       HideDataAnotherTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinSse(
       HideDataAnotherTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinSse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinSse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinSseMoi(
       HideDataAnotherTwinSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinSseMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinSseMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinMoi(
       HideDataTwinMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinMoi(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinNormal(
       HideDataTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinNormal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsync(
       HideDataTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsync(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi(
       HideDataTwinRustAsyncMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsyncMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsyncSse(
       HideDataTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsyncSseMoi(
       HideDataTwinRustAsyncSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSseMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsyncSseMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSse(
       HideDataTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinSse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSseMoi(
       HideDataTwinSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSseMoi(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinSseMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       HideDataTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSyncMoi(
       HideDataTwinSyncMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncMoi(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSse(
       HideDataTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncSse(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncSse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
       HideDataTwinSyncSseMoi? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinSyncSseMoi);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_application_env(
       ApplicationEnv? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_application_env(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_application_env);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_application_mode(
       ApplicationMode? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_application_mode(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_application_mode);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_normal(
       AttributeTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_rust_async(
       AttributeTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_rust_async_sse(
       AttributeTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_sse(
       AttributeTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_sync(
       AttributeTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_sync_sse(
       AttributeTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_normal(
       BasicGeneralEnumTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_general_enum_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_rust_async(
       BasicGeneralEnumTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_rust_async(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_general_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_rust_async_sse(
       BasicGeneralEnumTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_rust_async_sse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_general_enum_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_sse(
       BasicGeneralEnumTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_general_enum_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_sync(
       BasicGeneralEnumTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_general_enum_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_sync_sse(
       BasicGeneralEnumTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_general_enum_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_normal(
       BasicPrimitiveEnumTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_rust_async(
       BasicPrimitiveEnumTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_rust_async(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_rust_async_sse(
       BasicPrimitiveEnumTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_rust_async_sse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_sse(
       BasicPrimitiveEnumTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_primitive_enum_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_sync(
       BasicPrimitiveEnumTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_sync_sse(
       BasicPrimitiveEnumTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_sync_sse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_normal(
       BasicStructTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_rust_async(
       BasicStructTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_rust_async_sse(
       BasicStructTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_struct_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_sse(
       BasicStructTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_sync(
       BasicStructTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_sync_sse(
       BasicStructTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_bool);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_normal(
       ElementTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_rust_async(
       ElementTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_rust_async_sse(
       ElementTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_sse(
       ElementTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_sync(
       ElementTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_sync_sse(
       ElementTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_normal(
       ExoticOptionalsTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_exotic_optionals_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_rust_async(
       ExoticOptionalsTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_exotic_optionals_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_rust_async_sse(
       ExoticOptionalsTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_rust_async_sse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_exotic_optionals_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_sse(
       ExoticOptionalsTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_exotic_optionals_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_sync(
       ExoticOptionalsTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_exotic_optionals_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_sync_sse(
       ExoticOptionalsTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_exotic_optionals_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_f_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_f_64);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_16(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_16);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_64(
       PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_64);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_8);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_isize(
       PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_isize(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_isize);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_normal(
       MeasureTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_rust_async(
       MeasureTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_rust_async_sse(
       MeasureTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_sse(
       MeasureTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_sync(
       MeasureTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_sync_sse(
       MeasureTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_normal(
       NewTypeIntTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_rust_async(
       NewTypeIntTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_rust_async_sse(
       NewTypeIntTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_new_type_int_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_sse(
       NewTypeIntTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_sync(
       NewTypeIntTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_sync_sse(
       NewTypeIntTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_record_string_i_32(
       (String, int)? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_record_string_i_32(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_record_string_i_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_16(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_16);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_64);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_8);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_usize(
       BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_usize);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_normal(
       WeekdaysTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_rust_async(
       WeekdaysTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_rust_async_sse(
       WeekdaysTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_sse(
       WeekdaysTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_sync(
       WeekdaysTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_sync_sse(
       WeekdaysTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_normal(
       BenchmarkBinaryTreeTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_rust_async(
       BenchmarkBinaryTreeTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_rust_async_sse(
       BenchmarkBinaryTreeTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_rust_async_sse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_benchmark_binary_tree_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_sse(
       BenchmarkBinaryTreeTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_sync(
       BenchmarkBinaryTreeTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_sync_sse(
       BenchmarkBinaryTreeTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_bool(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_bool);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_normal(
       ExoticOptionalsTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_rust_async(
       ExoticOptionalsTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_rust_async_sse(
       ExoticOptionalsTwinRustAsyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_sse(
       ExoticOptionalsTwinSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_sse);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_sync(
       ExoticOptionalsTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_sync_sse(
       ExoticOptionalsTwinSyncSse? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_box_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_f_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_f_64);
   }
 
   @protected
   void sse_encode_opt_box_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_i_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_i_32);
   }
 
   @protected
   void sse_encode_opt_box_i_64(PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_i_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_i_64);
   }
 
   @protected
   void sse_encode_opt_box_i_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_i_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_i_8);
   }
 
   @protected
   void sse_encode_opt_box_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_u_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_u_8);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_normal(
       List<AttributeTwinNormal>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_rust_async(
       List<AttributeTwinRustAsync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_rust_async_sse(
       List<AttributeTwinRustAsyncSse>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_attribute_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_sse(
       List<AttributeTwinSse>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_attribute_twin_sse);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_sync(
       List<AttributeTwinSync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_sync_sse(
       List<AttributeTwinSyncSse>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_attribute_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_normal(
       List<ElementTwinNormal>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_element_twin_normal);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_rust_async(
       List<ElementTwinRustAsync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_element_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_rust_async_sse(
       List<ElementTwinRustAsyncSse>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_rust_async_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_element_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_sse(
       List<ElementTwinSse>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_element_twin_sse);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_sync(
       List<ElementTwinSync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_element_twin_sync);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_sync_sse(
       List<ElementTwinSyncSse>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_element_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_normal(
       List<AttributeTwinNormal?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_list_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_rust_async(
       List<AttributeTwinRustAsync?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_rust_async(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_list_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_rust_async_sse(
       List<AttributeTwinRustAsyncSse?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_rust_async_sse(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_list_opt_box_autoadd_attribute_twin_rust_async_sse);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_sse(
       List<AttributeTwinSse?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_opt_box_autoadd_attribute_twin_sse);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_sync(
       List<AttributeTwinSync?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_sync_sse(
       List<AttributeTwinSyncSse?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_sync_sse(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_list_opt_box_autoadd_attribute_twin_sync_sse);
   }
 
   @protected
   void sse_encode_opt_list_prim_f_32_strict(
       Float32List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_f_32_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_f_32_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_f_64_strict(
       Float64List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_f_64_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_f_64_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_i_32_strict(
       Int32List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_i_32_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_i_32_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_i_8_strict(
       Int8List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_i_8_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_i_8_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_u_8_strict(
       Uint8List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_u_8_strict);
   }
 
   @protected
@@ -59405,492 +59405,266 @@ impl SseDecode for crate::api::pseudo_manual::enumeration_twin_sync_sse::Kitchen
 impl SseDecode for Vec<RustAutoOpaqueNom<OpaqueItemTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustAutoOpaqueNom<OpaqueItemTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinNormal>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinRustAsyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinRustAsyncMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinRustAsyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinRustAsyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinRustAsyncSseMoi>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSseMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSyncMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSyncSseMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<OpaqueItemTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<OpaqueItemTwinNormal>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<chrono::Duration>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<chrono::DateTime<chrono::Local>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<chrono::DateTime<chrono::Local>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<chrono::NaiveDateTime>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<flutter_rust_bridge::DartOpaque>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinMoi>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueNom<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueNom<HideDataTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueNom<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueNom<HideDataTwinRustAsync>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinRustAsyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinRustAsyncMoi>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueNom<HideDataTwinRustAsyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueNom<HideDataTwinRustAsyncSse>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueNom<HideDataTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueNom<HideDataTwinSse>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueNom<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueNom<HideDataTwinSync>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinSyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinSyncMoi>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueNom<HideDataTwinSyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueNom<HideDataTwinSyncSse>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinSyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinSyncSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<StreamSink<i32, flutter_rust_bridge::for_generated::DcoCodec>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<StreamSink<
-                i32,
-                flutter_rust_bridge::for_generated::DcoCodec,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<StreamSink<i32, flutter_rust_bridge::for_generated::SseCodec>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<StreamSink<
-                i32,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<uuid::Uuid> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<uuid::Uuid>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::ApplicationEnvVar> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::ApplicationEnvVar>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::ApplicationMode>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::ApplicationSettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::ApplicationSettings>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::optional::AttributeTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -59899,12 +59673,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -59913,72 +59682,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -59987,12 +59719,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60001,44 +59728,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicGeneralEnumTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60047,28 +59751,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_sync_sse::BasicGeneralEnumTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60077,12 +59767,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60093,40 +59778,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicPrimitiveEnumTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60135,38 +59801,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_sync_sse::BasicPrimitiveEnumTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60175,94 +59824,49 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicStructTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<bool>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::optional::ElementTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60271,90 +59875,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_rust_async_sse::ElementTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::rust_opaque_twin_moi::EnumOpaqueTwinMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::rust_opaque_twin_moi::EnumOpaqueTwinMoi>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::rust_opaque::EnumOpaqueTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::rust_opaque::EnumOpaqueTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60363,12 +59919,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_rust_async::EnumOpaqueTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60377,12 +59928,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_rust_async_moi::EnumOpaqueTwinRustAsyncMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60391,64 +59937,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_rust_async_sse::EnumOpaqueTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::rust_opaque_twin_rust_async_sse_moi::EnumOpaqueTwinRustAsyncSseMoi> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ { ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_rust_async_sse_moi::EnumOpaqueTwinRustAsyncSseMoi>::sse_decode(deserializer)); }
-        return ans_;}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return sse_decode_list(deserializer);
+    }
+}
 
 impl SseDecode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sse::EnumOpaqueTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::rust_opaque_twin_sse::EnumOpaqueTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sse_moi::EnumOpaqueTwinSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_sse_moi::EnumOpaqueTwinSseMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60457,12 +59974,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_sync_moi::EnumOpaqueTwinSyncMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60471,12 +59983,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_sync_sse::EnumOpaqueTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60485,64 +59992,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_sync_sse_moi::EnumOpaqueTwinSyncSseMoi>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Vec<u8>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::auxiliary::sample_types::MyEnum> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::auxiliary::sample_types::MyEnum>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::auxiliary::sample_types::MySize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::auxiliary::sample_types::MySize>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::misc_example::MyTreeNodeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::misc_example::MyTreeNodeTwinNormal>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60551,12 +60029,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_rust_async::MyTreeNodeTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60565,44 +60038,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_rust_async_sse::MyTreeNodeTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sse::MyTreeNodeTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::misc_example_twin_sse::MyTreeNodeTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60611,26 +60061,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_sync_sse::MyTreeNodeTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::NestedRawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::NestedRawStringMirrored>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60646,26 +60084,14 @@ impl SseDecode for crate::api::mirror::ListOfNestedRawStringMirrored {
 impl SseDecode for Vec<Option<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<String>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <Option<crate::api::optional::AttributeTwinNormal>>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60674,14 +60100,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60692,42 +60111,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60736,40 +60134,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<i32>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::misc_example::WeekdaysTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <Option<crate::api::misc_example::WeekdaysTwinNormal>>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60778,14 +60157,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60798,26 +60170,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<crate::api::pseudo_manual::misc_example_twin_rust_async_sse::WeekdaysTwinRustAsyncSse>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60826,14 +60186,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60842,56 +60195,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<Vec<i32>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<Vec<i32>>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::array::PointTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::array::PointTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60900,58 +60225,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::array_twin_rust_async_sse::PointTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sse::PointTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sse::PointTwinSse>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sync::PointTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sync::PointTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sync_sse::PointTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sync_sse::PointTwinSyncSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -60959,11 +60254,7 @@ impl SseDecode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -60971,11 +60262,7 @@ impl SseDecode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -60983,11 +60270,7 @@ impl SseDecode for Vec<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i16>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -60995,11 +60278,7 @@ impl SseDecode for Vec<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -61007,11 +60286,7 @@ impl SseDecode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -61019,11 +60294,7 @@ impl SseDecode for Vec<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -61031,11 +60302,7 @@ impl SseDecode for Vec<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u16>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -61043,11 +60310,7 @@ impl SseDecode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -61055,11 +60318,7 @@ impl SseDecode for Vec<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -61067,39 +60326,21 @@ impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::RawStringEnumMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::RawStringEnumMirrored>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::RawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::RawStringMirrored>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61111,15 +60352,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61131,15 +60364,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61151,12 +60376,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32,crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicGeneralEnumTwinRustAsyncSse,)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61168,15 +60388,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61188,15 +60400,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61208,15 +60412,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync_sse::BasicGeneralEnumTwinSyncSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61228,15 +60424,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61248,15 +60436,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61268,12 +60448,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32,crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicPrimitiveEnumTwinRustAsyncSse,)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61285,15 +60460,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61305,15 +60472,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61325,30 +60484,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync_sse::BasicPrimitiveEnumTwinSyncSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, crate::api::pseudo_manual::basic::BasicStructTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic::BasicStructTwinNormal,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61360,15 +60503,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61380,15 +60515,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicStructTwinRustAsyncSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61400,15 +60527,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61420,15 +60539,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61440,233 +60551,133 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, bool)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, bool)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, f32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, f32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, f64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, f64)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i128)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i16)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i64)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i8)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, isize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, isize)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, Vec<u8>)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u128)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u16)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u64)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u8)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, usize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, usize)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::api::enumeration::EnumSimpleTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <(String, crate::api::enumeration::EnumSimpleTwinNormal)>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61678,15 +60689,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::EnumSimpleTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61698,12 +60701,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String,crate::api::pseudo_manual::enumeration_twin_rust_async_sse::EnumSimpleTwinRustAsyncSse,)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61715,15 +60713,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sse::EnumSimpleTwinSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61735,15 +60725,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::EnumSimpleTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61755,57 +60737,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync_sse::EnumSimpleTwinSyncSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::api::mirror::HashMapValue)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, crate::api::mirror::HashMapValue)>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, i32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::api::enumeration::KitchenSinkTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <(String, crate::api::enumeration::KitchenSinkTwinNormal)>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61817,15 +60770,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::KitchenSinkTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61837,12 +60782,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String,crate::api::pseudo_manual::enumeration_twin_rust_async_sse::KitchenSinkTwinRustAsyncSse,)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61854,15 +60794,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sse::KitchenSinkTwinSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61874,15 +60806,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::KitchenSinkTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61894,79 +60818,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync_sse::KitchenSinkTwinSyncSse,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, Vec<u8>)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::auxiliary::sample_types::MySize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, crate::auxiliary::sample_types::MySize)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(u8, crate::api::mirror::ApplicationMode)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(u8, crate::api::mirror::ApplicationMode)>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::method::SimpleStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::method::SimpleStructTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61975,12 +60862,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_rust_async::SimpleStructTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -61989,82 +60871,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_rust_async_sse::SimpleStructTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sse::SimpleStructTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sse::SimpleStructTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sync_sse::SimpleStructTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_sync_sse::SimpleStructTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::method::SumWithTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::method::SumWithTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -62073,90 +60915,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_rust_async_sse::SumWithTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sse::SumWithTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sse::SumWithTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sync_sse::SumWithTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sync_sse::SumWithTwinSyncSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::array::TestIdTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::array::TestIdTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -62165,74 +60959,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::array_twin_rust_async_sse::TestIdTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sse::TestIdTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sse::TestIdTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sync_sse::TestIdTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sync_sse::TestIdTwinSyncSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::misc_example::WeekdaysTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -62241,12 +60996,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -62255,56 +61005,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_rust_async_sse::WeekdaysTwinRustAsyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -63500,452 +62222,266 @@ impl SseDecode
 impl SseDecode for Option<i128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i128>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u128>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinMoi>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinNormal>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinRustAsyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinRustAsyncMoi>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinRustAsyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinRustAsyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinRustAsyncSseMoi>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSseMoi>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSyncMoi>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSyncSseMoi>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::Duration>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::NaiveDateTime>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<chrono::DateTime<chrono::Utc>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::DateTime<chrono::Utc>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<flutter_rust_bridge::DartOpaque>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataAnotherTwinMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataAnotherTwinMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataAnotherTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataAnotherTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataAnotherTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataAnotherTwinSse>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataAnotherTwinSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataAnotherTwinSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinMoi>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataTwinRustAsync>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinRustAsyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinRustAsyncMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataTwinRustAsyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataTwinRustAsyncSse>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataTwinSse>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataTwinSync>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinSyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinSyncMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueNom<HideDataTwinSyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueNom<HideDataTwinSyncSse>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinSyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinSyncSseMoi>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::mirror::ApplicationEnv> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::mirror::ApplicationEnv>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::mirror::ApplicationMode>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::optional::AttributeTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -63954,11 +62490,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -63967,67 +62499,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64036,11 +62536,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64051,41 +62547,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicGeneralEnumTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64094,26 +62570,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_sync_sse::BasicGeneralEnumTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64122,11 +62586,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64137,37 +62597,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicPrimitiveEnumTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64176,24 +62620,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_sync_sse::BasicPrimitiveEnumTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64202,11 +62636,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64215,76 +62645,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicStructTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::optional::ElementTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64293,11 +62689,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64306,69 +62698,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async_sse::ElementTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::optional::ExoticOptionalsTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::optional::ExoticOptionalsTwinNormal>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64377,11 +62735,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64392,37 +62746,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async_sse::ExoticOptionalsTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64431,101 +62769,63 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_sync_sse::ExoticOptionalsTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i16>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i8>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<isize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<isize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::enumeration::MeasureTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::enumeration::MeasureTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64534,11 +62834,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::enumeration_twin_rust_async::MeasureTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64547,41 +62843,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::enumeration_twin_rust_async_sse::MeasureTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::enumeration_twin_sse::MeasureTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::enumeration_twin_sse::MeasureTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64590,24 +62866,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::enumeration_twin_sync_sse::MeasureTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::newtype_pattern::NewTypeIntTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::newtype_pattern::NewTypeIntTwinNormal>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64616,11 +62882,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_rust_async::NewTypeIntTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64631,22 +62893,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_rust_async_sse::NewTypeIntTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::newtype_pattern_twin_sse::NewTypeIntTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_sse::NewTypeIntTwinSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64655,11 +62909,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_sync::NewTypeIntTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64668,90 +62918,56 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_sync_sse::NewTypeIntTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<(String, i32)>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u16>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u8>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<usize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<usize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::misc_example::WeekdaysTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64760,11 +62976,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64775,41 +62987,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::misc_example_twin_rust_async_sse::WeekdaysTwinRustAsyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64818,11 +63010,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64831,46 +63019,30 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::benchmark_api::BenchmarkBinaryTreeTwinNormal,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
-                return Some(<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>>::sse_decode(deserializer));
-            } else {
-                return None;
-            }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return sse_decode_option(deserializer);
+    }
+}
 
 impl SseDecode for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async_sse::BenchmarkBinaryTreeTwinRustAsyncSse>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
-                return Some(<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async_sse::BenchmarkBinaryTreeTwinRustAsyncSse>>::sse_decode(deserializer));
-            } else {
-                return None;
-            }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return sse_decode_option(deserializer);
+    }
+}
 
 impl SseDecode
     for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_sse::BenchmarkBinaryTreeTwinSse>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::benchmark_api_twin_sse::BenchmarkBinaryTreeTwinSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64879,13 +63051,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::benchmark_api_twin_sync::BenchmarkBinaryTreeTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64896,35 +63062,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<crate::api::pseudo_manual::benchmark_api_twin_sync_sse::BenchmarkBinaryTreeTwinSyncSse>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<bool>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<bool>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<crate::api::optional::ExoticOptionalsTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <Box<crate::api::optional::ExoticOptionalsTwinNormal>>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64935,37 +63087,23 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<crate::api::pseudo_manual::optional_twin_rust_async_sse::ExoticOptionalsTwinRustAsyncSse>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
-                return Some(<Box<crate::api::pseudo_manual::optional_twin_rust_async_sse::ExoticOptionalsTwinRustAsyncSse>>::sse_decode(deserializer));
-            } else {
-                return None;
-            }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return sse_decode_option(deserializer);
+    }
+}
 
 impl SseDecode
     for Option<Box<crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64974,13 +63112,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -64989,81 +63121,49 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::optional_twin_sync_sse::ExoticOptionalsTwinSyncSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<f64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<i32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<i64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<i64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<i8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<u8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <Vec<crate::api::optional::AttributeTwinNormal>>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65072,13 +63172,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65089,39 +63183,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65130,26 +63206,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::optional::ElementTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<crate::api::optional::ElementTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65158,13 +63222,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65175,39 +63233,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_rust_async_sse::ElementTwinRustAsyncSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65216,26 +63256,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<Option<crate::api::optional::AttributeTwinNormal>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <Vec<Option<crate::api::optional::AttributeTwinNormal>>>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65246,13 +63274,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                Option<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65267,11 +63289,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<Option<crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65280,13 +63298,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65295,13 +63307,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -65310,68 +63316,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                Option<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<i32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<i8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<u8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -96123,384 +94103,266 @@ crate::api::pseudo_manual::enumeration_twin_sync_sse::KitchenSinkTwinSyncSse::En
 impl SseEncode for Vec<RustAutoOpaqueNom<OpaqueItemTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustAutoOpaqueNom<OpaqueItemTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinRustAsyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinRustAsyncMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinRustAsyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinRustAsyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinRustAsyncSseMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSseMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSyncMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSyncSseMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<OpaqueItemTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <OpaqueItemTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <chrono::Duration>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<chrono::DateTime<chrono::Local>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <chrono::DateTime<chrono::Local>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <chrono::NaiveDateTime>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <flutter_rust_bridge::DartOpaque>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinMoi>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueNom<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueNom<HideDataTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueNom<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueNom<HideDataTwinRustAsync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinRustAsyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinRustAsyncMoi>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueNom<HideDataTwinRustAsyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueNom<HideDataTwinRustAsyncSse>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueNom<HideDataTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueNom<HideDataTwinSse>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinSseMoi>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueNom<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueNom<HideDataTwinSync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinSyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinSyncMoi>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueNom<HideDataTwinSyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueNom<HideDataTwinSyncSse>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinSyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinSyncSseMoi>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<StreamSink<i32, flutter_rust_bridge::for_generated::DcoCodec>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <StreamSink<i32, flutter_rust_bridge::for_generated::DcoCodec>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<StreamSink<i32, flutter_rust_bridge::for_generated::SseCodec>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <StreamSink<i32, flutter_rust_bridge::for_generated::SseCodec>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<uuid::Uuid> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <uuid::Uuid>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::ApplicationEnvVar> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::ApplicationEnvVar>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::ApplicationMode>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::ApplicationSettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::ApplicationSettings>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::optional::AttributeTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96509,10 +94371,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96521,58 +94380,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96581,10 +94417,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96593,34 +94426,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicGeneralEnumTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96629,22 +94449,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync_sse::BasicGeneralEnumTwinSyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96653,10 +94465,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96667,34 +94476,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicPrimitiveEnumTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96703,30 +94499,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync_sse::BasicPrimitiveEnumTwinSyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96735,78 +94522,49 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicStructTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <bool>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::optional::ElementTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96815,68 +94573,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_rust_async_sse::ElementTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::rust_opaque_twin_moi::EnumOpaqueTwinMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_moi::EnumOpaqueTwinMoi>::sse_encode(
-                item, serializer,
-            );
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::rust_opaque::EnumOpaqueTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::rust_opaque::EnumOpaqueTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96885,10 +94617,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_rust_async::EnumOpaqueTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96897,10 +94626,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_rust_async_moi::EnumOpaqueTwinRustAsyncMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96909,52 +94635,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_rust_async_sse::EnumOpaqueTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::rust_opaque_twin_rust_async_sse_moi::EnumOpaqueTwinRustAsyncSseMoi> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
-        for item in self { <crate::api::pseudo_manual::rust_opaque_twin_rust_async_sse_moi::EnumOpaqueTwinRustAsyncSseMoi>::sse_encode(item, serializer); }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_list(self, serializer);
+    }
+}
 
 impl SseEncode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sse::EnumOpaqueTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sse::EnumOpaqueTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sse_moi::EnumOpaqueTwinSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sse_moi::EnumOpaqueTwinSseMoi>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96963,10 +94672,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sync_moi::EnumOpaqueTwinSyncMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96975,10 +94681,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sync_sse::EnumOpaqueTwinSyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -96987,50 +94690,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sync_sse_moi::EnumOpaqueTwinSyncSseMoi>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Vec<u8>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::auxiliary::sample_types::MyEnum> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::auxiliary::sample_types::MyEnum>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::auxiliary::sample_types::MySize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::auxiliary::sample_types::MySize>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::misc_example::MyTreeNodeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::misc_example::MyTreeNodeTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97039,10 +94727,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async::MyTreeNodeTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97051,34 +94736,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async_sse::MyTreeNodeTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sse::MyTreeNodeTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sse::MyTreeNodeTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97087,20 +94759,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sync_sse::MyTreeNodeTwinSyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::NestedRawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::NestedRawStringMirrored>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97114,20 +94780,14 @@ impl SseEncode for crate::api::mirror::ListOfNestedRawStringMirrored {
 impl SseEncode for Vec<Option<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<String>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::optional::AttributeTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97136,10 +94796,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97150,36 +94807,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<
-                crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse,
-            >>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97188,30 +94830,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<i32>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::misc_example::WeekdaysTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::misc_example::WeekdaysTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97220,10 +94853,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97236,22 +94866,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_rust_async_sse::WeekdaysTwinRustAsyncSse>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97260,10 +94882,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97272,42 +94891,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<Vec<i32>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<Vec<i32>>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::array::PointTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::array::PointTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97316,44 +94921,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_rust_async_sse::PointTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sse::PointTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sse::PointTwinSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sync::PointTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sync::PointTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sync_sse::PointTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sync_sse::PointTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97361,9 +94950,7 @@ impl SseEncode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97371,9 +94958,7 @@ impl SseEncode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97381,9 +94966,7 @@ impl SseEncode for Vec<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i16>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97391,9 +94974,7 @@ impl SseEncode for Vec<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97401,9 +94982,7 @@ impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97411,9 +94990,7 @@ impl SseEncode for Vec<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i8>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97421,9 +94998,7 @@ impl SseEncode for Vec<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u16>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97431,9 +95006,7 @@ impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97441,9 +95014,7 @@ impl SseEncode for Vec<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -97451,29 +95022,21 @@ impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::RawStringEnumMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::RawStringEnumMirrored>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::RawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::RawStringMirrored>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97485,13 +95048,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97503,13 +95060,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97521,10 +95072,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32,crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicGeneralEnumTwinRustAsyncSse,)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97536,13 +95084,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97554,13 +95096,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97572,13 +95108,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync_sse::BasicGeneralEnumTwinSyncSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97590,13 +95120,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97608,13 +95132,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97626,10 +95144,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32,crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicPrimitiveEnumTwinRustAsyncSse,)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97641,13 +95156,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97659,13 +95168,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97677,25 +95180,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync_sse::BasicPrimitiveEnumTwinSyncSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, crate::api::pseudo_manual::basic::BasicStructTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, crate::api::pseudo_manual::basic::BasicStructTwinNormal)>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97707,13 +95199,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97725,13 +95211,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicStructTwinRustAsyncSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97743,13 +95223,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97761,13 +95235,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97779,193 +95247,133 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, bool)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, bool)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, f32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, f32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, f64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, f64)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i128)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i16)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i64)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i8)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, isize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, isize)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, Vec<u8>)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, String)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u128)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u16)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u64)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u8)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, usize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, usize)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::api::enumeration::EnumSimpleTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::api::enumeration::EnumSimpleTwinNormal)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97977,13 +95385,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::EnumSimpleTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -97995,10 +95397,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String,crate::api::pseudo_manual::enumeration_twin_rust_async_sse::EnumSimpleTwinRustAsyncSse,)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98010,13 +95409,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sse::EnumSimpleTwinSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98028,13 +95421,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::EnumSimpleTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98046,45 +95433,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync_sse::EnumSimpleTwinSyncSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::api::mirror::HashMapValue)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::api::mirror::HashMapValue)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, i32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::api::enumeration::KitchenSinkTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::api::enumeration::KitchenSinkTwinNormal)>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98096,13 +95466,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::KitchenSinkTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98114,10 +95478,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String,crate::api::pseudo_manual::enumeration_twin_rust_async_sse::KitchenSinkTwinRustAsyncSse,)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98129,13 +95490,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sse::KitchenSinkTwinSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98147,13 +95502,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::KitchenSinkTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98165,63 +95514,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync_sse::KitchenSinkTwinSyncSse,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, Vec<u8>)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::auxiliary::sample_types::MySize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::auxiliary::sample_types::MySize)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, String)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(u8, crate::api::mirror::ApplicationMode)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(u8, crate::api::mirror::ApplicationMode)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::method::SimpleStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::method::SimpleStructTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98230,10 +95558,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_rust_async::SimpleStructTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98242,68 +95567,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_rust_async_sse::SimpleStructTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sse::SimpleStructTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sse::SimpleStructTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sync_sse::SimpleStructTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sync_sse::SimpleStructTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::method::SumWithTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::method::SumWithTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98312,68 +95611,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_rust_async_sse::SumWithTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sse::SumWithTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sse::SumWithTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sync_sse::SumWithTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sync_sse::SumWithTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::array::TestIdTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::array::TestIdTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98382,56 +95655,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_rust_async_sse::TestIdTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sse::TestIdTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sse::TestIdTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sync_sse::TestIdTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sync_sse::TestIdTwinSyncSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::misc_example::WeekdaysTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98440,10 +95692,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -98452,44 +95701,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async_sse::WeekdaysTwinRustAsyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -99387,380 +96620,266 @@ impl SseEncode
 impl SseEncode for Option<i128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i128>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u128>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinMoi>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinRustAsyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinRustAsyncMoi>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinRustAsyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinRustAsyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinRustAsyncSseMoi>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSseMoi>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSyncMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSyncMoi>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSyncSseMoi> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSyncSseMoi>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <chrono::Duration>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <chrono::NaiveDateTime>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<chrono::DateTime<chrono::Utc>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <chrono::DateTime<chrono::Utc>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <flutter_rust_bridge::DartOpaque>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataAnotherTwinMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataAnotherTwinMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataAnotherTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataAnotherTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataAnotherTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataAnotherTwinSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataAnotherTwinSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataAnotherTwinSseMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinRustAsyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinRustAsyncMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataTwinRustAsyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataTwinRustAsyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinRustAsyncSseMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataTwinSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinSseMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataTwinSync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinSyncMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinSyncMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueNom<HideDataTwinSyncSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueNom<HideDataTwinSyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinSyncSseMoi>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinSyncSseMoi>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::mirror::ApplicationEnv> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::mirror::ApplicationEnv>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::mirror::ApplicationMode>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::optional::AttributeTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99769,10 +96888,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99781,58 +96897,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99841,10 +96934,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99855,34 +96945,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicGeneralEnumTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sse::BasicGeneralEnumTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99891,22 +96968,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync_sse::BasicGeneralEnumTwinSyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99915,10 +96984,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99929,34 +96995,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicPrimitiveEnumTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sse::BasicPrimitiveEnumTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99965,22 +97018,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync_sse::BasicPrimitiveEnumTwinSyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -99989,10 +97034,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100001,66 +97043,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async_sse::BasicStructTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sse::BasicStructTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync_sse::BasicStructTwinSyncSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::optional::ElementTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100069,12 +97087,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100083,56 +97096,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async_sse::ElementTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::optional::ExoticOptionalsTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::optional::ExoticOptionalsTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100141,10 +97133,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100155,34 +97144,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async_sse::ExoticOptionalsTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100191,90 +97167,63 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync_sse::ExoticOptionalsTwinSyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f64>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i16>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i8>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<isize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <isize>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::enumeration::MeasureTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::enumeration::MeasureTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100283,10 +97232,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_rust_async::MeasureTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100295,34 +97241,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_rust_async_sse::MeasureTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::enumeration_twin_sse::MeasureTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_sse::MeasureTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100331,22 +97264,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_sync_sse::MeasureTwinSyncSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::newtype_pattern::NewTypeIntTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::newtype_pattern::NewTypeIntTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100355,10 +97280,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_rust_async::NewTypeIntTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100369,22 +97291,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_rust_async_sse::NewTypeIntTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::newtype_pattern_twin_sse::NewTypeIntTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_sse::NewTypeIntTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100393,12 +97307,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_sync::NewTypeIntTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100407,80 +97316,56 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_sync_sse::NewTypeIntTwinSyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <(String, i32)>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u16>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u8>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<usize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <usize>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::misc_example::WeekdaysTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100489,10 +97374,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100503,34 +97385,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async_sse::WeekdaysTwinRustAsyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_sse::WeekdaysTwinSse>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100539,10 +97408,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_sync_sse::WeekdaysTwinSyncSse>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100551,38 +97417,30 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::benchmark_api::BenchmarkBinaryTreeTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
-                if let Some(value) = self {
-                    <Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>>::sse_encode(value, serializer);
-                }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_option(self, serializer);
+    }
+}
 
 impl SseEncode for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async_sse::BenchmarkBinaryTreeTwinRustAsyncSse>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
-                if let Some(value) = self {
-                    <Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async_sse::BenchmarkBinaryTreeTwinRustAsyncSse>>::sse_encode(value, serializer);
-                }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_option(self, serializer);
+    }
+}
 
 impl SseEncode
     for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_sse::BenchmarkBinaryTreeTwinSse>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::benchmark_api_twin_sse::BenchmarkBinaryTreeTwinSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100591,10 +97449,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::benchmark_api_twin_sync::BenchmarkBinaryTreeTwinSync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100605,30 +97460,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::benchmark_api_twin_sync_sse::BenchmarkBinaryTreeTwinSyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<bool>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<bool>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<crate::api::optional::ExoticOptionalsTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::optional::ExoticOptionalsTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100639,32 +97485,23 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<crate::api::pseudo_manual::optional_twin_rust_async_sse::ExoticOptionalsTwinRustAsyncSse>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
-                if let Some(value) = self {
-                    <Box<crate::api::pseudo_manual::optional_twin_rust_async_sse::ExoticOptionalsTwinRustAsyncSse>>::sse_encode(value, serializer);
-                }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_option(self, serializer);
+    }
+}
 
 impl SseEncode
     for Option<Box<crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::optional_twin_sse::ExoticOptionalsTwinSse>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100673,10 +97510,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100685,70 +97519,49 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::optional_twin_sync_sse::ExoticOptionalsTwinSyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<f64>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<i32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<i64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<i64>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<i8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<u8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::optional::AttributeTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100757,10 +97570,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100771,34 +97581,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100807,20 +97604,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::optional::ElementTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::optional::ElementTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100829,10 +97620,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100843,34 +97631,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_rust_async_sse::ElementTwinRustAsyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sse::ElementTwinSse>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100879,20 +97654,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sync_sse::ElementTwinSyncSse>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<Option<crate::api::optional::AttributeTwinNormal>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::optional::AttributeTwinNormal>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100903,12 +97672,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<
-                Option<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>,
-            >>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100923,10 +97687,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::pseudo_manual::optional_twin_rust_async_sse::AttributeTwinRustAsyncSse>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100935,10 +97696,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::pseudo_manual::optional_twin_sse::AttributeTwinSse>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100947,10 +97705,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -100959,60 +97714,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::pseudo_manual::optional_twin_sync_sse::AttributeTwinSyncSse>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f64>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<i32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<i8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<u8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -60172,15 +60172,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OpaqueItemTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
@@ -60188,15 +60182,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -60204,15 +60192,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -60220,15 +60202,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NonCloneSimpleTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
@@ -60236,217 +60212,117 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OpaqueItemTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-              deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
   List<Duration> sse_decode_list_Chrono_Duration(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Duration>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Chrono_Duration(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Chrono_Duration);
   }
 
   @protected
   List<DateTime> sse_decode_list_Chrono_Local(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DateTime>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Chrono_Local(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Chrono_Local);
   }
 
   @protected
   List<DateTime> sse_decode_list_Chrono_Naive(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DateTime>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Chrono_Naive(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Chrono_Naive);
   }
 
   @protected
   List<Object> sse_decode_list_DartOpaque(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Object>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_DartOpaque(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_DartOpaque);
   }
 
   @protected
   List<HideDataTwinNormal> sse_decode_list_RustOpaque_HideDataTwinNormal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinNormal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
   List<HideDataTwinRustAsync> sse_decode_list_RustOpaque_HideDataTwinRustAsync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinRustAsync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
   List<HideDataTwinSync> sse_decode_list_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <HideDataTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_RustOpaque_HideDataTwinSync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   List<RustStreamSink<int>> sse_decode_list_StreamSink_i_32_Sse(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RustStreamSink<int>>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_StreamSink_i_32_Sse(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_StreamSink_i_32_Sse);
   }
 
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_String);
   }
 
   @protected
   List<UuidValue> sse_decode_list_Uuid(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <UuidValue>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_Uuid(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_Uuid);
   }
 
   @protected
   List<ApplicationEnvVar> sse_decode_list_application_env_var(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApplicationEnvVar>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_application_env_var(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_application_env_var);
   }
 
   @protected
   List<ApplicationMode> sse_decode_list_application_mode(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApplicationMode>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_application_mode(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_application_mode);
   }
 
   @protected
   List<ApplicationSettings> sse_decode_list_application_settings(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApplicationSettings>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_application_settings(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_application_settings);
   }
 
   @protected
   List<AttributeTwinNormal> sse_decode_list_attribute_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_normal);
   }
 
   @protected
   List<AttributeTwinRustAsync> sse_decode_list_attribute_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_rust_async);
   }
 
   @protected
   List<AttributeTwinSync> sse_decode_list_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_attribute_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_attribute_twin_sync);
   }
 
   @protected
@@ -60454,13 +60330,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_general_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -60468,26 +60339,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_general_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_general_enum_twin_rust_async);
   }
 
   @protected
   List<BasicGeneralEnumTwinSync> sse_decode_list_basic_general_enum_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicGeneralEnumTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_general_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -60495,13 +60355,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -60509,13 +60364,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -60523,231 +60373,124 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_basic_primitive_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicPrimitiveEnumTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_primitive_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_basic_primitive_enum_twin_sync);
   }
 
   @protected
   List<BasicStructTwinNormal> sse_decode_list_basic_struct_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_normal);
   }
 
   @protected
   List<BasicStructTwinRustAsync> sse_decode_list_basic_struct_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_rust_async);
   }
 
   @protected
   List<BasicStructTwinSync> sse_decode_list_basic_struct_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <BasicStructTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_basic_struct_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_basic_struct_twin_sync);
   }
 
   @protected
   List<bool> sse_decode_list_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <bool>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_bool(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_bool);
   }
 
   @protected
   List<ElementTwinNormal> sse_decode_list_element_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_normal);
   }
 
   @protected
   List<ElementTwinRustAsync> sse_decode_list_element_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_rust_async);
   }
 
   @protected
   List<ElementTwinSync> sse_decode_list_element_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ElementTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_element_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_element_twin_sync);
   }
 
   @protected
   List<EnumOpaqueTwinNormal> sse_decode_list_enum_opaque_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_normal);
   }
 
   @protected
   List<EnumOpaqueTwinRustAsync> sse_decode_list_enum_opaque_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_rust_async);
   }
 
   @protected
   List<EnumOpaqueTwinSync> sse_decode_list_enum_opaque_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <EnumOpaqueTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_enum_opaque_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_enum_opaque_twin_sync);
   }
 
   @protected
   List<Uint8List> sse_decode_list_list_prim_u_8_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_list_prim_u_8_strict);
   }
 
   @protected
   List<MyEnum> sse_decode_list_my_enum(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyEnum>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_enum(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_enum);
   }
 
   @protected
   List<MySize> sse_decode_list_my_size(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MySize>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_size(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_size);
   }
 
   @protected
   List<MyTreeNodeTwinNormal> sse_decode_list_my_tree_node_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_normal);
   }
 
   @protected
   List<MyTreeNodeTwinRustAsync> sse_decode_list_my_tree_node_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_rust_async);
   }
 
   @protected
   List<MyTreeNodeTwinSync> sse_decode_list_my_tree_node_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MyTreeNodeTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_my_tree_node_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_my_tree_node_twin_sync);
   }
 
   @protected
   List<NestedRawStringMirrored> sse_decode_list_nested_raw_string_mirrored(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <NestedRawStringMirrored>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_nested_raw_string_mirrored(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_nested_raw_string_mirrored);
   }
 
   @protected
@@ -60761,13 +60504,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   List<String?> sse_decode_list_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_String(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_opt_String);
   }
 
   @protected
@@ -60775,13 +60512,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_attribute_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinNormal?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_attribute_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
@@ -60789,40 +60521,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_attribute_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinRustAsync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_opt_box_autoadd_attribute_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   List<AttributeTwinSync?> sse_decode_list_opt_box_autoadd_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AttributeTwinSync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_attribute_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   List<int?> sse_decode_list_opt_box_autoadd_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <int?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_i_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_opt_box_autoadd_i_32);
   }
 
   @protected
@@ -60830,13 +60545,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_weekdays_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinNormal?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
@@ -60844,79 +60554,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_opt_box_autoadd_weekdays_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinRustAsync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_opt_box_autoadd_weekdays_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
   List<WeekdaysTwinSync?> sse_decode_list_opt_box_autoadd_weekdays_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSync?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_box_autoadd_weekdays_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_opt_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
   List<Int32List?> sse_decode_list_opt_list_prim_i_32_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Int32List?>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_opt_list_prim_i_32_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_opt_list_prim_i_32_strict);
   }
 
   @protected
   List<PointTwinNormal> sse_decode_list_point_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_normal);
   }
 
   @protected
   List<PointTwinRustAsync> sse_decode_list_point_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_rust_async);
   }
 
   @protected
   List<PointTwinSync> sse_decode_list_point_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PointTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_point_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_point_twin_sync);
   }
 
   @protected
@@ -61049,26 +60724,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   List<RawStringEnumMirrored> sse_decode_list_raw_string_enum_mirrored(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RawStringEnumMirrored>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_raw_string_enum_mirrored(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_raw_string_enum_mirrored);
   }
 
   @protected
   List<RawStringMirrored> sse_decode_list_raw_string_mirrored(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RawStringMirrored>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_raw_string_mirrored(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_raw_string_mirrored);
   }
 
   @protected
@@ -61076,14 +60739,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_general_enum_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -61091,14 +60748,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_general_enum_twin_rust_async(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_general_enum_twin_rust_async);
   }
 
   @protected
@@ -61106,14 +60758,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_general_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicGeneralEnumTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_general_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -61121,14 +60767,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_primitive_enum_twin_normal(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -61136,14 +60776,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_primitive_enum_twin_rust_async(
-          deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer,
+        sse_decode_record_i_32_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -61151,14 +60786,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_primitive_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicPrimitiveEnumTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_primitive_enum_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_primitive_enum_twin_sync);
   }
 
   @protected
@@ -61166,13 +60795,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_normal);
   }
 
   @protected
@@ -61180,14 +60804,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_i_32_basic_struct_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_rust_async);
   }
 
   @protected
@@ -61195,234 +60813,128 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_i_32_basic_struct_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BasicStructTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_basic_struct_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_basic_struct_twin_sync);
   }
 
   @protected
   List<(int, bool)> sse_decode_list_record_i_32_bool(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, bool)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_bool(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_bool);
   }
 
   @protected
   List<(int, double)> sse_decode_list_record_i_32_f_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, double)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_f_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_f_32);
   }
 
   @protected
   List<(int, double)> sse_decode_list_record_i_32_f_64(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, double)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_f_64(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_f_64);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_i_128(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_128(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_128);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_i_16(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_16(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_16);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_32);
   }
 
   @protected
   List<(int, PlatformInt64)> sse_decode_list_record_i_32_i_64(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, PlatformInt64)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_64(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_64);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_i_8(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_i_8(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_i_8);
   }
 
   @protected
   List<(int, PlatformInt64)> sse_decode_list_record_i_32_isize(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, PlatformInt64)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_isize(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_isize);
   }
 
   @protected
   List<(int, Uint8List)> sse_decode_list_record_i_32_list_prim_u_8_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, Uint8List)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_i_32_list_prim_u_8_strict);
   }
 
   @protected
   List<(int, String)> sse_decode_list_record_i_32_string(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_string(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_string);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_u_128(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_128(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_128);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_u_16(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_16(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_16);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_u_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_32);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_u_64(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_64(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_64);
   }
 
   @protected
   List<(int, int)> sse_decode_list_record_i_32_u_8(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_u_8(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_u_8);
   }
 
   @protected
   List<(int, BigInt)> sse_decode_list_record_i_32_usize(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, BigInt)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_i_32_usize(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_i_32_usize);
   }
 
   @protected
@@ -61430,13 +60942,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_enum_simple_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_normal);
   }
 
   @protected
@@ -61444,14 +60951,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_enum_simple_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_rust_async);
   }
 
   @protected
@@ -61459,39 +60960,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_enum_simple_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, EnumSimpleTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_enum_simple_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_enum_simple_twin_sync);
   }
 
   @protected
   List<(String, HashMapValue)> sse_decode_list_record_string_hash_map_value(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, HashMapValue)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_hash_map_value(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_hash_map_value);
   }
 
   @protected
   List<(String, int)> sse_decode_list_record_string_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, int)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_i_32(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_i_32);
   }
 
   @protected
@@ -61499,13 +60983,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinNormal)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_kitchen_sink_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_normal);
   }
 
   @protected
@@ -61513,14 +60992,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinRustAsync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(
-          sse_decode_record_string_kitchen_sink_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_rust_async);
   }
 
   @protected
@@ -61528,221 +61001,122 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_list_record_string_kitchen_sink_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, KitchenSinkTwinSync)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_kitchen_sink_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_kitchen_sink_twin_sync);
   }
 
   @protected
   List<(String, Uint8List)> sse_decode_list_record_string_list_prim_u_8_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, Uint8List)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_record_string_list_prim_u_8_strict);
   }
 
   @protected
   List<(String, MySize)> sse_decode_list_record_string_my_size(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, MySize)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_my_size(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_my_size);
   }
 
   @protected
   List<(String, String)> sse_decode_list_record_string_string(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_string_string);
   }
 
   @protected
   List<(int, ApplicationMode)> sse_decode_list_record_u_8_application_mode(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(int, ApplicationMode)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_u_8_application_mode(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_record_u_8_application_mode);
   }
 
   @protected
   List<SimpleStructTwinNormal> sse_decode_list_simple_struct_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_simple_struct_twin_normal);
   }
 
   @protected
   List<SimpleStructTwinRustAsync> sse_decode_list_simple_struct_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(
+        deserializer, sse_decode_simple_struct_twin_rust_async);
   }
 
   @protected
   List<SimpleStructTwinSync> sse_decode_list_simple_struct_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SimpleStructTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_simple_struct_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_simple_struct_twin_sync);
   }
 
   @protected
   List<SumWithTwinNormal> sse_decode_list_sum_with_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_normal);
   }
 
   @protected
   List<SumWithTwinRustAsync> sse_decode_list_sum_with_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_rust_async);
   }
 
   @protected
   List<SumWithTwinSync> sse_decode_list_sum_with_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SumWithTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_sum_with_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_sum_with_twin_sync);
   }
 
   @protected
   List<TestIdTwinNormal> sse_decode_list_test_id_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_normal);
   }
 
   @protected
   List<TestIdTwinRustAsync> sse_decode_list_test_id_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_rust_async);
   }
 
   @protected
   List<TestIdTwinSync> sse_decode_list_test_id_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TestIdTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_test_id_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_test_id_twin_sync);
   }
 
   @protected
   List<WeekdaysTwinNormal> sse_decode_list_weekdays_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinNormal>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_normal(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_normal);
   }
 
   @protected
   List<WeekdaysTwinRustAsync> sse_decode_list_weekdays_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinRustAsync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_rust_async(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_rust_async);
   }
 
   @protected
   List<WeekdaysTwinSync> sse_decode_list_weekdays_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <WeekdaysTwinSync>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_weekdays_twin_sync(deserializer));
-    }
-    return ans_;
+    return sseDecodeList(deserializer, sse_decode_weekdays_twin_sync);
   }
 
   @protected
@@ -62235,34 +61609,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   BigInt? sse_decode_opt_I128(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_I128(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_I128);
   }
 
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_String);
   }
 
   @protected
   BigInt? sse_decode_opt_U128(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_U128(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_U128);
   }
 
   @protected
@@ -62270,13 +61629,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -62284,13 +61639,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -62298,60 +61649,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
   Duration? sse_decode_opt_box_autoadd_Chrono_Duration(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Chrono_Duration(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_Chrono_Duration);
   }
 
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Naive(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Chrono_Naive(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_Chrono_Naive);
   }
 
   @protected
   DateTime? sse_decode_opt_box_autoadd_Chrono_Utc(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_Chrono_Utc(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_Chrono_Utc);
   }
 
   @protected
   Object? sse_decode_opt_box_autoadd_DartOpaque(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_DartOpaque(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_DartOpaque);
   }
 
   @protected
@@ -62359,26 +61687,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal);
   }
 
   @protected
   HideDataTwinNormal? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinNormal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinNormal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
@@ -62386,85 +61705,56 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
   HideDataTwinSync? sse_decode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_RustOpaque_HideDataTwinSync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   ApplicationEnv? sse_decode_opt_box_autoadd_application_env(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_application_env(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_application_env);
   }
 
   @protected
   ApplicationMode? sse_decode_opt_box_autoadd_application_mode(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_application_mode(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_application_mode);
   }
 
   @protected
   AttributeTwinNormal? sse_decode_opt_box_autoadd_attribute_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   AttributeTwinRustAsync? sse_decode_opt_box_autoadd_attribute_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   AttributeTwinSync? sse_decode_opt_box_autoadd_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_attribute_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_attribute_twin_sync);
   }
 
   @protected
@@ -62472,13 +61762,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -62486,13 +61771,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_general_enum_twin_rust_async);
   }
 
   @protected
@@ -62500,13 +61781,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_general_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_general_enum_twin_sync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -62514,13 +61790,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -62528,13 +61799,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_box_autoadd_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
@@ -62542,25 +61809,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_primitive_enum_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_primitive_enum_twin_sync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_primitive_enum_twin_sync);
   }
 
   @protected
   BasicStructTwinNormal? sse_decode_opt_box_autoadd_basic_struct_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_normal);
   }
 
   @protected
@@ -62568,72 +61826,46 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_basic_struct_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_rust_async);
   }
 
   @protected
   BasicStructTwinSync? sse_decode_opt_box_autoadd_basic_struct_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_basic_struct_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_basic_struct_twin_sync);
   }
 
   @protected
   bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_bool);
   }
 
   @protected
   ElementTwinNormal? sse_decode_opt_box_autoadd_element_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_normal);
   }
 
   @protected
   ElementTwinRustAsync? sse_decode_opt_box_autoadd_element_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_rust_async);
   }
 
   @protected
   ElementTwinSync? sse_decode_opt_box_autoadd_element_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_element_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_element_twin_sync);
   }
 
   @protected
@@ -62641,13 +61873,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_normal);
   }
 
   @protected
@@ -62655,13 +61882,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_rust_async);
   }
 
   @protected
@@ -62669,138 +61891,83 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_exotic_optionals_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_exotic_optionals_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_exotic_optionals_twin_sync);
   }
 
   @protected
   double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_f_32);
   }
 
   @protected
   double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_f_64);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_i_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_16(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_16);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_32);
   }
 
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_64);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_i_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_i_8);
   }
 
   @protected
   PlatformInt64? sse_decode_opt_box_autoadd_isize(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_isize(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_isize);
   }
 
   @protected
   MeasureTwinNormal? sse_decode_opt_box_autoadd_measure_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_normal);
   }
 
   @protected
   MeasureTwinRustAsync? sse_decode_opt_box_autoadd_measure_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_rust_async);
   }
 
   @protected
   MeasureTwinSync? sse_decode_opt_box_autoadd_measure_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_measure_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_measure_twin_sync);
   }
 
   @protected
   NewTypeIntTwinNormal? sse_decode_opt_box_autoadd_new_type_int_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_normal);
   }
 
   @protected
@@ -62808,128 +61975,78 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_autoadd_new_type_int_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_rust_async);
   }
 
   @protected
   NewTypeIntTwinSync? sse_decode_opt_box_autoadd_new_type_int_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_new_type_int_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_new_type_int_twin_sync);
   }
 
   @protected
   (String, int)? sse_decode_opt_box_autoadd_record_string_i_32(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_record_string_i_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_record_string_i_32);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_16(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_16);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_32);
   }
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_64);
   }
 
   @protected
   int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_u_8);
   }
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_autoadd_usize);
   }
 
   @protected
   WeekdaysTwinNormal? sse_decode_opt_box_autoadd_weekdays_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
   WeekdaysTwinRustAsync? sse_decode_opt_box_autoadd_weekdays_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
   WeekdaysTwinSync? sse_decode_opt_box_autoadd_weekdays_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_weekdays_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
@@ -62937,12 +62054,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_normal);
   }
 
   @protected
@@ -62950,13 +62063,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_rust_async);
   }
 
   @protected
@@ -62964,35 +62072,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_benchmark_binary_tree_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_benchmark_binary_tree_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_benchmark_binary_tree_twin_sync);
   }
 
   @protected
   bool? sse_decode_opt_box_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_bool(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_bool);
   }
 
   @protected
   ExoticOptionalsTwinNormal? sse_decode_opt_box_exotic_optionals_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_normal);
   }
 
   @protected
@@ -63000,151 +62095,91 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_box_exotic_optionals_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_rust_async);
   }
 
   @protected
   ExoticOptionalsTwinSync? sse_decode_opt_box_exotic_optionals_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_exotic_optionals_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_box_exotic_optionals_twin_sync);
   }
 
   @protected
   double? sse_decode_opt_box_f_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_f_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_f_64);
   }
 
   @protected
   int? sse_decode_opt_box_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_i_32(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_i_32);
   }
 
   @protected
   PlatformInt64? sse_decode_opt_box_i_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_i_64(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_i_64);
   }
 
   @protected
   int? sse_decode_opt_box_i_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_i_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_i_8);
   }
 
   @protected
   int? sse_decode_opt_box_u_8(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_u_8(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_box_u_8);
   }
 
   @protected
   List<AttributeTwinNormal>? sse_decode_opt_list_attribute_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_attribute_twin_normal);
   }
 
   @protected
   List<AttributeTwinRustAsync>? sse_decode_opt_list_attribute_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_attribute_twin_rust_async);
   }
 
   @protected
   List<AttributeTwinSync>? sse_decode_opt_list_attribute_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_attribute_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_attribute_twin_sync);
   }
 
   @protected
   List<ElementTwinNormal>? sse_decode_opt_list_element_twin_normal(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_normal(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_element_twin_normal);
   }
 
   @protected
   List<ElementTwinRustAsync>? sse_decode_opt_list_element_twin_rust_async(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_rust_async(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_element_twin_rust_async);
   }
 
   @protected
   List<ElementTwinSync>? sse_decode_opt_list_element_twin_sync(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_element_twin_sync(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_element_twin_sync);
   }
 
   @protected
@@ -63152,13 +62187,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_normal(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_normal(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
@@ -63166,13 +62196,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_rust_async(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_rust_async(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer,
+        sse_decode_list_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
@@ -63180,71 +62206,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_decode_opt_list_opt_box_autoadd_attribute_twin_sync(
           SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_opt_box_autoadd_attribute_twin_sync(
-          deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(
+        deserializer, sse_decode_list_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   Float32List? sse_decode_opt_list_prim_f_32_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_f_32_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_f_32_strict);
   }
 
   @protected
   Float64List? sse_decode_opt_list_prim_f_64_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_f_64_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_f_64_strict);
   }
 
   @protected
   Int32List? sse_decode_opt_list_prim_i_32_strict(
       SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_i_32_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_i_32_strict);
   }
 
   @protected
   Int8List? sse_decode_opt_list_prim_i_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_i_8_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_i_8_strict);
   }
 
   @protected
   Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
+    return sseDecodeOptional(deserializer, sse_decode_list_prim_u_8_strict);
   }
 
   @protected
@@ -71943,11 +70939,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           List<OpaqueItemTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_AutoExplicit_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
@@ -71955,11 +70950,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           List<NonCloneSimpleTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -71967,11 +70961,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           List<NonCloneSimpleTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -71979,11 +70972,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           List<NonCloneSimpleTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
@@ -71991,395 +70983,281 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
           List<OpaqueItemTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerOpaqueItemTwinNormal);
   }
 
   @protected
   void sse_encode_list_Chrono_Duration(
       List<Duration> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Chrono_Duration(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Chrono_Duration);
   }
 
   @protected
   void sse_encode_list_Chrono_Local(
       List<DateTime> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Chrono_Local(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Chrono_Local);
   }
 
   @protected
   void sse_encode_list_Chrono_Naive(
       List<DateTime> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Chrono_Naive(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Chrono_Naive);
   }
 
   @protected
   void sse_encode_list_DartOpaque(List<Object> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_DartOpaque(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_DartOpaque);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinNormal(
       List<HideDataTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinNormal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinRustAsync(
       List<HideDataTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinRustAsync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
   void sse_encode_list_RustOpaque_HideDataTwinSync(
       List<HideDataTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_RustOpaque_HideDataTwinSync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   void sse_encode_list_StreamSink_i_32_Sse(
       List<RustStreamSink<int>> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_StreamSink_i_32_Sse(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_StreamSink_i_32_Sse);
   }
 
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_String);
   }
 
   @protected
   void sse_encode_list_Uuid(List<UuidValue> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_Uuid(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_Uuid);
   }
 
   @protected
   void sse_encode_list_application_env_var(
       List<ApplicationEnvVar> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_application_env_var(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_application_env_var);
   }
 
   @protected
   void sse_encode_list_application_mode(
       List<ApplicationMode> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_application_mode(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_application_mode);
   }
 
   @protected
   void sse_encode_list_application_settings(
       List<ApplicationSettings> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_application_settings(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_application_settings);
   }
 
   @protected
   void sse_encode_list_attribute_twin_normal(
       List<AttributeTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_list_attribute_twin_rust_async(
       List<AttributeTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_attribute_twin_sync(
       List<AttributeTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_attribute_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_normal(
       List<BasicGeneralEnumTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_general_enum_twin_normal);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_rust_async(
       List<BasicGeneralEnumTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_general_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_basic_general_enum_twin_sync(
       List<BasicGeneralEnumTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_general_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_general_enum_twin_sync);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_normal(
       List<BasicPrimitiveEnumTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_primitive_enum_twin_normal);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_rust_async(
       List<BasicPrimitiveEnumTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_basic_primitive_enum_twin_sync(
       List<BasicPrimitiveEnumTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_primitive_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_primitive_enum_twin_sync);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_normal(
       List<BasicStructTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_normal);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_rust_async(
       List<BasicStructTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_basic_struct_twin_sync(
       List<BasicStructTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_basic_struct_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_basic_struct_twin_sync);
   }
 
   @protected
   void sse_encode_list_bool(List<bool> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_bool(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_bool);
   }
 
   @protected
   void sse_encode_list_element_twin_normal(
       List<ElementTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_normal);
   }
 
   @protected
   void sse_encode_list_element_twin_rust_async(
       List<ElementTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_element_twin_sync(
       List<ElementTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_element_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_element_twin_sync);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_normal(
       List<EnumOpaqueTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_normal);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_rust_async(
       List<EnumOpaqueTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_enum_opaque_twin_sync(
       List<EnumOpaqueTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_enum_opaque_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_enum_opaque_twin_sync);
   }
 
   @protected
   void sse_encode_list_list_prim_u_8_strict(
       List<Uint8List> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_list_prim_u_8_strict);
   }
 
   @protected
   void sse_encode_list_my_enum(List<MyEnum> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_enum(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_enum);
   }
 
   @protected
   void sse_encode_list_my_size(List<MySize> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_size(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_size);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_normal(
       List<MyTreeNodeTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_normal);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_rust_async(
       List<MyTreeNodeTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_my_tree_node_twin_sync(
       List<MyTreeNodeTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_my_tree_node_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_my_tree_node_twin_sync);
   }
 
   @protected
   void sse_encode_list_nested_raw_string_mirrored(
       List<NestedRawStringMirrored> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_nested_raw_string_mirrored(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_nested_raw_string_mirrored);
   }
 
   @protected
@@ -72393,120 +71271,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_list_opt_String(
       List<String?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_String(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_opt_String);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_normal(
       List<AttributeTwinNormal?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_rust_async(
       List<AttributeTwinRustAsync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_attribute_twin_sync(
       List<AttributeTwinSync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_attribute_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_i_32(
       List<int?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_i_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_opt_box_autoadd_i_32);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_normal(
       List<WeekdaysTwinNormal?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_rust_async(
       List<WeekdaysTwinRustAsync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_opt_box_autoadd_weekdays_twin_sync(
       List<WeekdaysTwinSync?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_box_autoadd_weekdays_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_opt_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
   void sse_encode_list_opt_list_prim_i_32_strict(
       List<Int32List?> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_opt_list_prim_i_32_strict(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_opt_list_prim_i_32_strict);
   }
 
   @protected
   void sse_encode_list_point_twin_normal(
       List<PointTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_normal);
   }
 
   @protected
   void sse_encode_list_point_twin_rust_async(
       List<PointTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_point_twin_sync(
       List<PointTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_point_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_point_twin_sync);
   }
 
   @protected
@@ -72665,30 +71513,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_list_raw_string_enum_mirrored(
       List<RawStringEnumMirrored> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_raw_string_enum_mirrored(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_raw_string_enum_mirrored);
   }
 
   @protected
   void sse_encode_list_raw_string_mirrored(
       List<RawStringMirrored> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_raw_string_mirrored(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_raw_string_mirrored);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_general_enum_twin_normal(
       List<(int, BasicGeneralEnumTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_general_enum_twin_normal);
   }
 
   @protected
@@ -72696,21 +71538,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicGeneralEnumTwinRustAsync)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_rust_async(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_general_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_general_enum_twin_sync(
       List<(int, BasicGeneralEnumTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_general_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_general_enum_twin_sync);
   }
 
   @protected
@@ -72718,10 +71557,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicPrimitiveEnumTwinNormal)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_normal);
   }
 
   @protected
@@ -72729,461 +71568,341 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       List<(int, BasicPrimitiveEnumTwinRustAsync)> self,
       SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_rust_async(
-          item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_primitive_enum_twin_sync(
       List<(int, BasicPrimitiveEnumTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_primitive_enum_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_i_32_basic_primitive_enum_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_normal(
       List<(int, BasicStructTwinNormal)> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_normal(item, serializer);
-    }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_normal);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_rust_async(
       List<(int, BasicStructTwinRustAsync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_record_i_32_basic_struct_twin_sync(
       List<(int, BasicStructTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_basic_struct_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_basic_struct_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_i_32_bool(
       List<(int, bool)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_bool(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_bool);
   }
 
   @protected
   void sse_encode_list_record_i_32_f_32(
       List<(int, double)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_f_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_f_32);
   }
 
   @protected
   void sse_encode_list_record_i_32_f_64(
       List<(int, double)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_f_64(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_f_64);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_128(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_128(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_128);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_16(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_16(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_16);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_32(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_32);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_64(
       List<(int, PlatformInt64)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_64(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_64);
   }
 
   @protected
   void sse_encode_list_record_i_32_i_8(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_i_8(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_i_8);
   }
 
   @protected
   void sse_encode_list_record_i_32_isize(
       List<(int, PlatformInt64)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_isize(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_isize);
   }
 
   @protected
   void sse_encode_list_record_i_32_list_prim_u_8_strict(
       List<(int, Uint8List)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_list_prim_u_8_strict(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_i_32_list_prim_u_8_strict);
   }
 
   @protected
   void sse_encode_list_record_i_32_string(
       List<(int, String)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_string(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_string);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_128(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_128(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_128);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_16(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_16(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_16);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_32(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_32);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_64(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_64(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_64);
   }
 
   @protected
   void sse_encode_list_record_i_32_u_8(
       List<(int, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_u_8(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_u_8);
   }
 
   @protected
   void sse_encode_list_record_i_32_usize(
       List<(int, BigInt)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_i_32_usize(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_i_32_usize);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_normal(
       List<(String, EnumSimpleTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_normal);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_rust_async(
       List<(String, EnumSimpleTwinRustAsync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_record_string_enum_simple_twin_sync(
       List<(String, EnumSimpleTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_enum_simple_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_enum_simple_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_string_hash_map_value(
       List<(String, HashMapValue)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_hash_map_value(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_hash_map_value);
   }
 
   @protected
   void sse_encode_list_record_string_i_32(
       List<(String, int)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_i_32(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_i_32);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_normal(
       List<(String, KitchenSinkTwinNormal)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_normal(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_kitchen_sink_twin_normal);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_rust_async(
       List<(String, KitchenSinkTwinRustAsync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(
+        self,
+        serializer,
+        sse_encode_record_string_kitchen_sink_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_record_string_kitchen_sink_twin_sync(
       List<(String, KitchenSinkTwinSync)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_kitchen_sink_twin_sync(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_kitchen_sink_twin_sync);
   }
 
   @protected
   void sse_encode_list_record_string_list_prim_u_8_strict(
       List<(String, Uint8List)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_list_prim_u_8_strict(item, serializer);
-    }
+    sseEncodeList(
+        self, serializer, sse_encode_record_string_list_prim_u_8_strict);
   }
 
   @protected
   void sse_encode_list_record_string_my_size(
       List<(String, MySize)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_my_size(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_my_size);
   }
 
   @protected
   void sse_encode_list_record_string_string(
       List<(String, String)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_string_string);
   }
 
   @protected
   void sse_encode_list_record_u_8_application_mode(
       List<(int, ApplicationMode)> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_u_8_application_mode(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_record_u_8_application_mode);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_normal(
       List<SimpleStructTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_normal);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_rust_async(
       List<SimpleStructTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_simple_struct_twin_sync(
       List<SimpleStructTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_simple_struct_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_simple_struct_twin_sync);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_normal(
       List<SumWithTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_normal);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_rust_async(
       List<SumWithTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_sum_with_twin_sync(
       List<SumWithTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_sum_with_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_sum_with_twin_sync);
   }
 
   @protected
   void sse_encode_list_test_id_twin_normal(
       List<TestIdTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_normal);
   }
 
   @protected
   void sse_encode_list_test_id_twin_rust_async(
       List<TestIdTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_test_id_twin_sync(
       List<TestIdTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_test_id_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_test_id_twin_sync);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_normal(
       List<WeekdaysTwinNormal> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_normal(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_normal);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_rust_async(
       List<WeekdaysTwinRustAsync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_rust_async(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_rust_async);
   }
 
   @protected
   void sse_encode_list_weekdays_twin_sync(
       List<WeekdaysTwinSync> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_weekdays_twin_sync(item, serializer);
-    }
+    sseEncodeList(self, serializer, sse_encode_weekdays_twin_sync);
   }
 
   @protected
@@ -73598,31 +72317,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   @protected
   void sse_encode_opt_I128(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_I128(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_I128);
   }
 
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_String);
   }
 
   @protected
   void sse_encode_opt_U128(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_U128(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_U128);
   }
 
   @protected
@@ -73630,12 +72337,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
           NonCloneSimpleTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinNormal);
   }
 
   @protected
@@ -73643,12 +72348,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
           NonCloneSimpleTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinRustAsync);
   }
 
   @protected
@@ -73656,847 +72359,597 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
           NonCloneSimpleTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNonCloneSimpleTwinSync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Duration(
       Duration? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Chrono_Duration(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_Chrono_Duration);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Naive(
       DateTime? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Chrono_Naive(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_Chrono_Naive);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_Chrono_Utc(
       DateTime? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_Chrono_Utc(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_Chrono_Utc);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_DartOpaque(
       Object? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_DartOpaque(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_DartOpaque);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
       HideDataAnotherTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataAnotherTwinNormal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinNormal(
       HideDataTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinNormal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinNormal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinRustAsync(
       HideDataTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsync(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_RustOpaque_HideDataTwinRustAsync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_RustOpaque_HideDataTwinSync(
       HideDataTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_RustOpaque_HideDataTwinSync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_RustOpaque_HideDataTwinSync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_application_env(
       ApplicationEnv? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_application_env(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_application_env);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_application_mode(
       ApplicationMode? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_application_mode(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_application_mode);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_normal(
       AttributeTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_rust_async(
       AttributeTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_attribute_twin_sync(
       AttributeTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_attribute_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_normal(
       BasicGeneralEnumTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_general_enum_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_rust_async(
       BasicGeneralEnumTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_rust_async(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_general_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_general_enum_twin_sync(
       BasicGeneralEnumTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_general_enum_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_general_enum_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_normal(
       BasicPrimitiveEnumTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_rust_async(
       BasicPrimitiveEnumTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_rust_async(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_primitive_enum_twin_sync(
       BasicPrimitiveEnumTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_primitive_enum_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_basic_primitive_enum_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_normal(
       BasicStructTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_rust_async(
       BasicStructTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_basic_struct_twin_sync(
       BasicStructTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_basic_struct_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_basic_struct_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_bool);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_normal(
       ElementTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_rust_async(
       ElementTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_element_twin_sync(
       ElementTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_element_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_element_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_normal(
       ExoticOptionalsTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_exotic_optionals_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_rust_async(
       ExoticOptionalsTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_box_autoadd_exotic_optionals_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_exotic_optionals_twin_sync(
       ExoticOptionalsTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_exotic_optionals_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_exotic_optionals_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_f_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_f_64);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_16(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_16);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_64(
       PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_64);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_i_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_i_8);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_isize(
       PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_isize(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_isize);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_normal(
       MeasureTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_rust_async(
       MeasureTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_measure_twin_sync(
       MeasureTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_measure_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_measure_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_normal(
       NewTypeIntTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_rust_async(
       NewTypeIntTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_new_type_int_twin_sync(
       NewTypeIntTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_new_type_int_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_new_type_int_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_record_string_i_32(
       (String, int)? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_record_string_i_32(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_record_string_i_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_16(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_16);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_32);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_64);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_u_8);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_usize(
       BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_autoadd_usize);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_normal(
       WeekdaysTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_rust_async(
       WeekdaysTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_autoadd_weekdays_twin_sync(
       WeekdaysTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_weekdays_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_autoadd_weekdays_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_normal(
       BenchmarkBinaryTreeTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_rust_async(
       BenchmarkBinaryTreeTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_benchmark_binary_tree_twin_sync(
       BenchmarkBinaryTreeTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_benchmark_binary_tree_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_benchmark_binary_tree_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_bool(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_bool);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_normal(
       ExoticOptionalsTwinNormal? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_normal);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_rust_async(
       ExoticOptionalsTwinRustAsync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_box_exotic_optionals_twin_sync(
       ExoticOptionalsTwinSync? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_exotic_optionals_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_box_exotic_optionals_twin_sync);
   }
 
   @protected
   void sse_encode_opt_box_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_f_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_f_64);
   }
 
   @protected
   void sse_encode_opt_box_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_i_32(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_i_32);
   }
 
   @protected
   void sse_encode_opt_box_i_64(PlatformInt64? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_i_64(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_i_64);
   }
 
   @protected
   void sse_encode_opt_box_i_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_i_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_i_8);
   }
 
   @protected
   void sse_encode_opt_box_u_8(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_u_8(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_box_u_8);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_normal(
       List<AttributeTwinNormal>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_rust_async(
       List<AttributeTwinRustAsync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_list_attribute_twin_sync(
       List<AttributeTwinSync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_attribute_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_normal(
       List<ElementTwinNormal>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_element_twin_normal);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_rust_async(
       List<ElementTwinRustAsync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_rust_async(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_element_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_list_element_twin_sync(
       List<ElementTwinSync>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_element_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_element_twin_sync);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_normal(
       List<AttributeTwinNormal?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_normal(self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_list_opt_box_autoadd_attribute_twin_normal);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_rust_async(
       List<AttributeTwinRustAsync?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_rust_async(
-          self, serializer);
-    }
+    sseEncodeOptional(
+        self,
+        serializer,
+        sse_encode_list_opt_box_autoadd_attribute_twin_rust_async);
   }
 
   @protected
   void sse_encode_opt_list_opt_box_autoadd_attribute_twin_sync(
       List<AttributeTwinSync?>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_opt_box_autoadd_attribute_twin_sync(self, serializer);
-    }
+    sseEncodeOptional(
+        self, serializer, sse_encode_list_opt_box_autoadd_attribute_twin_sync);
   }
 
   @protected
   void sse_encode_opt_list_prim_f_32_strict(
       Float32List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_f_32_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_f_32_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_f_64_strict(
       Float64List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_f_64_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_f_64_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_i_32_strict(
       Int32List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_i_32_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_i_32_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_i_8_strict(
       Int8List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_i_8_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_i_8_strict);
   }
 
   @protected
   void sse_encode_opt_list_prim_u_8_strict(
       Uint8List? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
+    sseEncodeOptional(self, serializer, sse_encode_list_prim_u_8_strict);
   }
 
   @protected
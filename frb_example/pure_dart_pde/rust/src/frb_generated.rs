@@ -36024,245 +36024,133 @@ impl SseDecode for crate::api::pseudo_manual::enumeration_twin_sync::KitchenSink
 impl SseDecode for Vec<RustAutoOpaqueMoi<OpaqueItemTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustAutoOpaqueMoi<OpaqueItemTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinNormal>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<NonCloneSimpleTwinSync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<OpaqueItemTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<OpaqueItemTwinNormal>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<chrono::Duration>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<chrono::DateTime<chrono::Local>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<chrono::DateTime<chrono::Local>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<chrono::NaiveDateTime>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<flutter_rust_bridge::DartOpaque>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinRustAsync>>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<RustOpaqueMoi<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<RustOpaqueMoi<HideDataTwinSync>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<StreamSink<i32, flutter_rust_bridge::for_generated::SseCodec>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<StreamSink<
-                i32,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<uuid::Uuid> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<uuid::Uuid>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::ApplicationEnvVar> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::ApplicationEnvVar>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::ApplicationMode>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::ApplicationSettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::ApplicationSettings>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::optional::AttributeTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36271,44 +36159,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36317,44 +36182,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36363,134 +36205,70 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<bool>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::optional::ElementTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::rust_opaque::EnumOpaqueTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::rust_opaque::EnumOpaqueTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36499,80 +36277,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::rust_opaque_twin_rust_async::EnumOpaqueTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Vec<u8>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::auxiliary::sample_types::MyEnum> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::auxiliary::sample_types::MyEnum>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::auxiliary::sample_types::MySize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::auxiliary::sample_types::MySize>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::misc_example::MyTreeNodeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::misc_example::MyTreeNodeTwinNormal>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36581,42 +36321,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_rust_async::MyTreeNodeTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::NestedRawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::NestedRawStringMirrored>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36632,26 +36351,14 @@ impl SseDecode for crate::api::mirror::ListOfNestedRawStringMirrored {
 impl SseDecode for Vec<Option<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<String>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <Option<crate::api::optional::AttributeTwinNormal>>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36660,54 +36367,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<i32>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<crate::api::misc_example::WeekdaysTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <Option<crate::api::misc_example::WeekdaysTwinNormal>>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36716,14 +36397,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36732,72 +36406,35 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<
-                crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync,
-            >>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<Option<Vec<i32>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<Option<Vec<i32>>>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::array::PointTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::array::PointTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sync::PointTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sync::PointTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36805,11 +36442,7 @@ impl SseDecode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36817,11 +36450,7 @@ impl SseDecode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<f64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36829,11 +36458,7 @@ impl SseDecode for Vec<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i16>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36841,11 +36466,7 @@ impl SseDecode for Vec<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36853,11 +36474,7 @@ impl SseDecode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36865,11 +36482,7 @@ impl SseDecode for Vec<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<i8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36877,11 +36490,7 @@ impl SseDecode for Vec<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u16>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36889,11 +36498,7 @@ impl SseDecode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u32>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36901,11 +36506,7 @@ impl SseDecode for Vec<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u64>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
@@ -36913,39 +36514,21 @@ impl SseDecode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+        return deserializer.read_primitive_list(len_).unwrap();
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::RawStringEnumMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::RawStringEnumMirrored>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::mirror::RawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::mirror::RawStringMirrored>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36957,15 +36540,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36977,15 +36552,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -36997,15 +36564,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37017,15 +36576,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37037,15 +36588,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37057,30 +36600,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, crate::api::pseudo_manual::basic::BasicStructTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic::BasicStructTwinNormal,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37092,15 +36619,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37112,233 +36631,133 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, bool)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, bool)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, f32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, f32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, f64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, f64)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i128)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i16)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i64)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, i8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, i8)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, isize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, isize)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, Vec<u8>)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u128)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u16)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u64)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, u8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, u8)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(i32, usize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(i32, usize)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::api::enumeration::EnumSimpleTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <(String, crate::api::enumeration::EnumSimpleTwinNormal)>::sse_decode(deserializer),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37350,15 +36769,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::EnumSimpleTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37370,57 +36781,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::EnumSimpleTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::api::mirror::HashMapValue)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, crate::api::mirror::HashMapValue)>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, i32)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::api::enumeration::KitchenSinkTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <(String, crate::api::enumeration::KitchenSinkTwinNormal)>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37432,15 +36814,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::KitchenSinkTwinRustAsync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37452,79 +36826,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::KitchenSinkTwinSync,
-            )>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, Vec<u8>)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, crate::auxiliary::sample_types::MySize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, crate::auxiliary::sample_types::MySize)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(String, String)>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<(u8, crate::api::mirror::ApplicationMode)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<(u8, crate::api::mirror::ApplicationMode)>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::method::SimpleStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::method::SimpleStructTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37533,130 +36870,63 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_rust_async::SimpleStructTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::method::SumWithTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::method::SumWithTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::array::TestIdTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::array::TestIdTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::misc_example::WeekdaysTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -37665,28 +36935,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_decode(deserializer));
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
 impl SseDecode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(
-                <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        }
-        return ans_;
+        return sse_decode_list(deserializer);
     }
 }
 
@@ -38308,199 +37564,119 @@ impl SseDecode for crate::api::pseudo_manual::rust_opaque_twin_sync::OpaqueNeste
 impl SseDecode for Option<i128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i128>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u128>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinNormal>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<NonCloneSimpleTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::Duration>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::NaiveDateTime>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<chrono::DateTime<chrono::Utc>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<chrono::DateTime<chrono::Utc>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<flutter_rust_bridge::DartOpaque>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataAnotherTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataAnotherTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinRustAsync>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<RustOpaqueMoi<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<RustOpaqueMoi<HideDataTwinSync>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::mirror::ApplicationEnv> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::mirror::ApplicationEnv>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::mirror::ApplicationMode>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::optional::AttributeTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38509,41 +37685,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38552,41 +37708,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38595,35 +37731,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38632,50 +37754,28 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<bool>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::optional::ElementTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38684,39 +37784,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::optional::ExoticOptionalsTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::optional::ExoticOptionalsTwinNormal>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38725,112 +37807,70 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<f64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i16>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<i8>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<isize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<isize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::enumeration::MeasureTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::enumeration::MeasureTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38839,39 +37879,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::enumeration_twin_rust_async::MeasureTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::newtype_pattern::NewTypeIntTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::newtype_pattern::NewTypeIntTwinNormal>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38880,11 +37902,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_rust_async::NewTypeIntTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38893,90 +37911,56 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::newtype_pattern_twin_sync::NewTypeIntTwinSync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<(String, i32)>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u16>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u32>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u8>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<usize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<usize>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::misc_example::WeekdaysTwinNormal>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -38985,26 +37969,14 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_decode(
-                    deserializer,
-                ),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39013,61 +37985,37 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::benchmark_api::BenchmarkBinaryTreeTwinNormal,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
-                return Some(<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>>::sse_decode(deserializer));
-            } else {
-                return None;
-            }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return sse_decode_option(deserializer);
+    }
+}
 
 impl SseDecode
     for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_sync::BenchmarkBinaryTreeTwinSync>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::benchmark_api_twin_sync::BenchmarkBinaryTreeTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<bool>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<bool>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<crate::api::optional::ExoticOptionalsTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <Box<crate::api::optional::ExoticOptionalsTwinNormal>>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39078,13 +38026,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39093,81 +38035,49 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<
-                crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<f64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<i32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<i64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<i64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<i8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Box<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Box<u8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <Vec<crate::api::optional::AttributeTwinNormal>>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39176,39 +38086,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::optional::ElementTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<crate::api::optional::ElementTwinNormal>>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39217,39 +38109,21 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<Option<crate::api::optional::AttributeTwinNormal>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(
-                <Vec<Option<crate::api::optional::AttributeTwinNormal>>>::sse_decode(deserializer),
-            );
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39260,13 +38134,7 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                Option<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -39275,68 +38143,42 @@ impl SseDecode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<
-                Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>,
-            >>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<f64>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<i32>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<i8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
 impl SseDecode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<Vec<u8>>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+        return sse_decode_option(deserializer);
     }
 }
 
@@ -55795,192 +54637,133 @@ impl SseEncode for crate::api::pseudo_manual::enumeration_twin_sync::KitchenSink
 impl SseEncode for Vec<RustAutoOpaqueMoi<OpaqueItemTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustAutoOpaqueMoi<OpaqueItemTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <NonCloneSimpleTwinSync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<OpaqueItemTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <OpaqueItemTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <chrono::Duration>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<chrono::DateTime<chrono::Local>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <chrono::DateTime<chrono::Local>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <chrono::NaiveDateTime>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <flutter_rust_bridge::DartOpaque>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinRustAsync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<RustOpaqueMoi<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <RustOpaqueMoi<HideDataTwinSync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<StreamSink<i32, flutter_rust_bridge::for_generated::SseCodec>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <StreamSink<i32, flutter_rust_bridge::for_generated::SseCodec>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<uuid::Uuid> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <uuid::Uuid>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::ApplicationEnvVar> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::ApplicationEnvVar>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::ApplicationMode>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::ApplicationSettings> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::ApplicationSettings>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::optional::AttributeTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -55989,34 +54772,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56025,34 +54795,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56061,108 +54818,70 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <bool>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::optional::ElementTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::rust_opaque::EnumOpaqueTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::rust_opaque::EnumOpaqueTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56171,62 +54890,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_rust_async::EnumOpaqueTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::rust_opaque_twin_sync::EnumOpaqueTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Vec<u8>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::auxiliary::sample_types::MyEnum> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::auxiliary::sample_types::MyEnum>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::auxiliary::sample_types::MySize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::auxiliary::sample_types::MySize>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::misc_example::MyTreeNodeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::misc_example::MyTreeNodeTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56235,32 +54934,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async::MyTreeNodeTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sync::MyTreeNodeTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::NestedRawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::NestedRawStringMirrored>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56273,21 +54961,15 @@ impl SseEncode for crate::api::mirror::ListOfNestedRawStringMirrored {
 
 impl SseEncode for Vec<Option<String>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<String>>::sse_encode(item, serializer);
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::optional::AttributeTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56296,42 +54978,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<i32>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<crate::api::misc_example::WeekdaysTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::misc_example::WeekdaysTwinNormal>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56340,10 +55008,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56352,54 +55017,35 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<Option<Vec<i32>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <Option<Vec<i32>>>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::array::PointTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::array::PointTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_rust_async::PointTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sync::PointTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sync::PointTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56407,9 +55053,7 @@ impl SseEncode for Vec<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56417,9 +55061,7 @@ impl SseEncode for Vec<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <f64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56427,9 +55069,7 @@ impl SseEncode for Vec<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i16>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56437,9 +55077,7 @@ impl SseEncode for Vec<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56447,9 +55085,7 @@ impl SseEncode for Vec<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56457,9 +55093,7 @@ impl SseEncode for Vec<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <i8>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56467,9 +55101,7 @@ impl SseEncode for Vec<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u16>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56477,9 +55109,7 @@ impl SseEncode for Vec<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u32>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56487,9 +55117,7 @@ impl SseEncode for Vec<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u64>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
@@ -56497,29 +55125,21 @@ impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        serializer.write_primitive_list(&self);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::RawStringEnumMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::RawStringEnumMirrored>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::mirror::RawStringMirrored> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::mirror::RawStringMirrored>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56531,13 +55151,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56549,13 +55163,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56567,13 +55175,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56585,13 +55187,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56603,13 +55199,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56621,25 +55211,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, crate::api::pseudo_manual::basic::BasicStructTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, crate::api::pseudo_manual::basic::BasicStructTwinNormal)>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56651,13 +55230,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56669,193 +55242,133 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                i32,
-                crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, bool)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, bool)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, f32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, f32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, f64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, f64)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i128)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i16)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i64)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, i8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, i8)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, isize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, isize)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, Vec<u8>)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, String)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u128)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u128)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u16)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u16)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u64)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u64)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, u8)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, u8)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(i32, usize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(i32, usize)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::api::enumeration::EnumSimpleTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::api::enumeration::EnumSimpleTwinNormal)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56867,13 +55380,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::EnumSimpleTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56885,45 +55392,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::EnumSimpleTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::api::mirror::HashMapValue)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::api::mirror::HashMapValue)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, i32)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::api::enumeration::KitchenSinkTwinNormal)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::api::enumeration::KitchenSinkTwinNormal)>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56935,13 +55425,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_rust_async::KitchenSinkTwinRustAsync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -56953,63 +55437,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(
-                String,
-                crate::api::pseudo_manual::enumeration_twin_sync::KitchenSinkTwinSync,
-            )>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, Vec<u8>)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, Vec<u8>)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, crate::auxiliary::sample_types::MySize)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, crate::auxiliary::sample_types::MySize)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(String, String)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<(u8, crate::api::mirror::ApplicationMode)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <(u8, crate::api::mirror::ApplicationMode)>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::method::SimpleStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::method::SimpleStructTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -57018,100 +55481,63 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_rust_async::SimpleStructTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sync::SimpleStructTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::method::SumWithTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::method::SumWithTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_rust_async::SumWithTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::method_twin_sync::SumWithTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::array::TestIdTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::array::TestIdTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_rust_async::TestIdTwinRustAsync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::array_twin_sync::TestIdTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::misc_example::WeekdaysTwinNormal>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -57120,22 +55546,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_encode(item, serializer);
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
 impl SseEncode for Vec<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_encode(
-                item, serializer,
-            );
-        }
+        sse_encode_list(self, serializer);
     }
 }
 
@@ -57594,170 +56012,119 @@ impl SseEncode for crate::api::pseudo_manual::rust_opaque_twin_sync::OpaqueNeste
 impl SseEncode for Option<i128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i128>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u128> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u128>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinRustAsync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<NonCloneSimpleTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <NonCloneSimpleTwinSync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<chrono::Duration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <chrono::Duration>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<chrono::NaiveDateTime> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <chrono::NaiveDateTime>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<chrono::DateTime<chrono::Utc>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <chrono::DateTime<chrono::Utc>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<flutter_rust_bridge::DartOpaque> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <flutter_rust_bridge::DartOpaque>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataAnotherTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataAnotherTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinRustAsync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<RustOpaqueMoi<HideDataTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <RustOpaqueMoi<HideDataTwinSync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::mirror::ApplicationEnv> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::mirror::ApplicationEnv>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::mirror::ApplicationMode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::mirror::ApplicationMode>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::optional::AttributeTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::optional::AttributeTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -57766,34 +56133,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic::BasicGeneralEnumTwinNormal>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -57802,34 +56156,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicGeneralEnumTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicGeneralEnumTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic::BasicPrimitiveEnumTwinNormal>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -57838,34 +56179,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicPrimitiveEnumTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicPrimitiveEnumTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic::BasicStructTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic::BasicStructTwinNormal>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -57874,42 +56202,28 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_rust_async::BasicStructTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::basic_twin_sync::BasicStructTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<bool> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <bool>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::optional::ElementTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::optional::ElementTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -57918,34 +56232,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::optional::ExoticOptionalsTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::optional::ExoticOptionalsTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -57954,102 +56255,70 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<f32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<f64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <f64>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i16>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i64>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<i8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <i8>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<isize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <isize>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::enumeration::MeasureTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::enumeration::MeasureTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58058,32 +56327,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_rust_async::MeasureTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::enumeration_twin_sync::MeasureTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::newtype_pattern::NewTypeIntTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::newtype_pattern::NewTypeIntTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58092,10 +56350,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_rust_async::NewTypeIntTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58104,82 +56359,56 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::newtype_pattern_twin_sync::NewTypeIntTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<(String, i32)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <(String, i32)>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u16>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u32>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <u8>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<usize> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <usize>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::misc_example::WeekdaysTwinNormal> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::misc_example::WeekdaysTwinNormal>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58188,22 +56417,14 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_rust_async::WeekdaysTwinRustAsync>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::pseudo_manual::misc_example_twin_sync::WeekdaysTwinSync>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58212,50 +56433,37 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::benchmark_api::BenchmarkBinaryTreeTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>> {
-                    // Codec=Sse (Serialization based), see doc to use other codecs
-                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
-                if let Some(value) = self {
-                    <Box<crate::api::pseudo_manual::benchmark_api_twin_rust_async::BenchmarkBinaryTreeTwinRustAsync>>::sse_encode(value, serializer);
-                }}
-                }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        sse_encode_option(self, serializer);
+    }
+}
 
 impl SseEncode
     for Option<Box<crate::api::pseudo_manual::benchmark_api_twin_sync::BenchmarkBinaryTreeTwinSync>>
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::benchmark_api_twin_sync::BenchmarkBinaryTreeTwinSync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<bool>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<bool>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<crate::api::optional::ExoticOptionalsTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::optional::ExoticOptionalsTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58266,10 +56474,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::optional_twin_rust_async::ExoticOptionalsTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58278,70 +56483,49 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<crate::api::pseudo_manual::optional_twin_sync::ExoticOptionalsTwinSync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<f64>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<i32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<i64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<i64>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<i8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Box<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Box<u8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::optional::AttributeTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::optional::AttributeTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58350,32 +56534,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::optional::ElementTwinNormal>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::optional::ElementTwinNormal>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58384,32 +56557,21 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_rust_async::ElementTwinRustAsync>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<crate::api::pseudo_manual::optional_twin_sync::ElementTwinSync>>::sse_encode(
-                value, serializer,
-            );
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<Option<crate::api::optional::AttributeTwinNormal>>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::optional::AttributeTwinNormal>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58420,12 +56582,7 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<
-                Option<crate::api::pseudo_manual::optional_twin_rust_async::AttributeTwinRustAsync>,
-            >>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
@@ -58434,60 +56591,42 @@ impl SseEncode
 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<Option<crate::api::pseudo_manual::optional_twin_sync::AttributeTwinSync>>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<f32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<f64>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<f64>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<i32>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<i32>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<i8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<i8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
 impl SseEncode for Option<Vec<u8>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <Vec<u8>>::sse_encode(value, serializer);
-        }
+        sse_encode_option(self, serializer);
     }
 }
 
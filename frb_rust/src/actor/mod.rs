@@ -0,0 +1,163 @@
+//! Owns a stateful value in a task, and serializes all operations on it via message passing,
+//! thus neither locks nor `Sync` are needed. Usually used via `#[frb(actor)]`.
+
+use crate::rust_async::spawn;
+use futures::FutureExt;
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use tokio::sync::{mpsc, oneshot};
+
+/// The future returned by the closures given to [`Actor::call_async`].
+pub type ActorFuture<'a, R> = Pin<Box<dyn Future<Output = R> + Send + 'a>>;
+
+type Job<T> = Box<dyn for<'a> FnOnce(&'a mut T) -> ActorFuture<'a, ()> + Send>;
+
+/// A handle to the task owning the value. The task stops (and the value is dropped)
+/// when this is dropped.
+pub struct Actor<T> {
+    sender: mpsc::UnboundedSender<Job<T>>,
+}
+
+impl<T: Send + 'static> Actor<T> {
+    /// Moves the value into a newly spawned task.
+    pub fn new(mut value: T) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel::<Job<T>>();
+        spawn(async move {
+            while let Some(job) = receiver.recv().await {
+                job(&mut value).await;
+            }
+        });
+        Self { sender }
+    }
+
+    /// Runs `f` after all previously queued operations finish.
+    ///
+    /// If `f` panics, the panic is returned as an error, while the value stays in the task
+    /// and later operations still run.
+    pub async fn call<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut T) -> R + Send + 'static,
+    ) -> Result<R, ActorPanicked> {
+        self.call_async(move |value| {
+            let output = f(value);
+            Box::pin(async move { output })
+        })
+        .await
+    }
+
+    /// Similar to [`Actor::call`], except that `f` is async.
+    /// No other operation runs until the returned future completes.
+    pub async fn call_async<R, F>(&self, f: F) -> Result<R, ActorPanicked>
+    where
+        R: Send + 'static,
+        F: for<'a> FnOnce(&'a mut T) -> ActorFuture<'a, R> + Send + 'static,
+    {
+        let (output_sender, output_receiver) = oneshot::channel();
+        self.sender
+            .send(Box::new(move |value| {
+                Box::pin(async move {
+                    // Creating the future may panic as well, thus it is inside the guarded block
+                    let output = AssertUnwindSafe(async move { f(value).await })
+                        .catch_unwind()
+                        .await;
+                    let _ = output_sender.send(output.map_err(ActorPanicked));
+                })
+            }))
+            .unwrap_or_else(|_| panic!("Actor task has stopped"));
+        output_receiver.await.expect("Actor task has stopped")
+    }
+}
+
+/// Returned by [`Actor::call`] and [`Actor::call_async`] when the operation panics.
+pub struct ActorPanicked(Box<dyn Any + Send>);
+
+impl ActorPanicked {
+    /// The payload of the panic, e.g. to be given to [`std::panic::resume_unwind`].
+    pub fn into_panic(self) -> Box<dyn Any + Send> {
+        self.0
+    }
+}
+
+impl fmt::Debug for ActorPanicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ActorPanicked").finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for ActorPanicked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (
+            self.0.downcast_ref::<&str>(),
+            self.0.downcast_ref::<String>(),
+        ) {
+            (Some(message), _) => write!(f, "actor operation panicked: {message}"),
+            (_, Some(message)) => write!(f, "actor operation panicked: {message}"),
+            _ => write!(f, "actor operation panicked"),
+        }
+    }
+}
+
+impl std::error::Error for ActorPanicked {}
+
+#[cfg(test)]
+mod tests {
+    use crate::actor::Actor;
+    use std::cell::Cell;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_calls_are_serialized() {
+        // `Cell` is `Send` but not `Sync`
+        let actor = Arc::new(Actor::new(Cell::new(0)));
+
+        let handles = (0..10)
+            .map(|_| {
+                let actor = actor.clone();
+                tokio::spawn(async move {
+                    actor
+                        .call_async(|value| {
+                            Box::pin(async move {
+                                let old = value.get();
+                                tokio::task::yield_now().await;
+                                value.set(old + 1);
+                            })
+                        })
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.await.unwrap().unwrap();
+        }
+
+        assert_eq!(actor.call(|value| value.get()).await.unwrap(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_panic_is_returned() {
+        let actor = Actor::new(10);
+
+        let err = actor
+            .call(|_| -> i32 { panic!("operation failed") })
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "actor operation panicked: operation failed"
+        );
+        let err = actor
+            .call_async::<(), _>(|_| Box::pin(async { panic!("async operation failed") }))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.into_panic().downcast_ref::<&str>(),
+            Some(&"async operation failed")
+        );
+
+        // The actor survives the panics
+        assert_eq!(actor.call(|value| *value).await.unwrap(), 10);
+    }
+}
@@ -0,0 +1,277 @@
+//! Long-running tasks whose progress can be observed, and which can be paused, resumed
+//! or cancelled while running. Usually used via `#[frb(background_task)]`.
+
+use crate::rust_async::{spawn, JoinHandle};
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Running,
+    Paused,
+    Cancelled,
+}
+
+/// Returns `false` when it no longer wants progress (e.g. the stream is closed),
+/// thus it is removed.
+type ProgressListener<P> = Box<dyn Fn(P) -> bool + Send + Sync>;
+
+struct Shared<P> {
+    state: watch::Sender<State>,
+    listeners: Mutex<Vec<ProgressListener<P>>>,
+    latest_progress: Mutex<Option<P>>,
+    finished: AtomicBool,
+}
+
+/// A running task, created by [`BackgroundTask::spawn`].
+pub struct BackgroundTask<P, O> {
+    shared: Arc<Shared<P>>,
+    join_handle: Mutex<Option<JoinHandle<O>>>,
+}
+
+impl<P: Clone + Send + 'static, O: Send + 'static> BackgroundTask<P, O> {
+    /// Spawns the future created by `f` onto the async runtime.
+    pub fn spawn<F, Fut>(f: F) -> Self
+    where
+        F: FnOnce(BackgroundTaskContext<P>) -> Fut,
+        Fut: Future<Output = O> + Send + 'static,
+    {
+        let shared = Arc::new(Shared {
+            state: watch::channel(State::Running).0,
+            listeners: Mutex::new(vec![]),
+            latest_progress: Mutex::new(None),
+            finished: AtomicBool::new(false),
+        });
+        let future = f(BackgroundTaskContext {
+            shared: shared.clone(),
+        });
+        let shared_for_task = shared.clone();
+        let join_handle = spawn(async move {
+            let output = future.await;
+            shared_for_task.finished.store(true, Ordering::Relaxed);
+            output
+        });
+        Self {
+            shared,
+            join_handle: Mutex::new(Some(join_handle)),
+        }
+    }
+
+    /// The listener is called with the latest progress (if any) immediately,
+    /// thus listeners added later (e.g. after a UI rebuild) do not miss the current state.
+    /// It is removed as soon as it returns `false`.
+    pub fn add_progress_listener(&self, listener: impl Fn(P) -> bool + Send + Sync + 'static) {
+        let mut listeners = self.shared.listeners.lock().unwrap();
+        if let Some(progress) = self.shared.latest_progress.lock().unwrap().clone() {
+            if !listener(progress) {
+                return;
+            }
+        }
+        listeners.push(Box::new(listener));
+    }
+
+    pub fn pause(&self) {
+        self.transit(State::Running, State::Paused);
+    }
+
+    pub fn resume(&self) {
+        self.transit(State::Paused, State::Running);
+    }
+
+    /// Cancellation is cooperative: the task stops at its next
+    /// [`BackgroundTaskContext::checkpoint`].
+    pub fn cancel(&self) {
+        self.shared.state.send_replace(State::Cancelled);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.shared.state.borrow() == State::Paused
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.shared.state.borrow() == State::Cancelled
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.shared.finished.load(Ordering::Relaxed)
+    }
+
+    /// Waits for the output of the task. Can only be called once,
+    /// and later calls return [`BackgroundTaskJoinError::AlreadyJoined`].
+    pub async fn join(&self) -> Result<O, BackgroundTaskJoinError> {
+        let join_handle = (self.join_handle.lock().unwrap().take())
+            .ok_or(BackgroundTaskJoinError::AlreadyJoined)?;
+        join_handle
+            .await
+            .map_err(|_| BackgroundTaskJoinError::Failed)
+    }
+
+    fn transit(&self, from: State, to: State) {
+        self.shared.state.send_if_modified(|state| {
+            let modified = *state == from;
+            if modified {
+                *state = to;
+            }
+            modified
+        });
+    }
+}
+
+/// Given to the function running in background, to report progress and to respond to
+/// pause and cancellation requests.
+pub struct BackgroundTaskContext<P> {
+    shared: Arc<Shared<P>>,
+}
+
+impl<P: Clone> BackgroundTaskContext<P> {
+    pub fn report_progress(&self, progress: P) {
+        let mut listeners = self.shared.listeners.lock().unwrap();
+        listeners.retain(|listener| listener(progress.clone()));
+        *self.shared.latest_progress.lock().unwrap() = Some(progress);
+    }
+
+    /// Waits while the task is paused, and returns an error if it is cancelled.
+    /// Call it regularly, e.g. `context.checkpoint().await?`.
+    pub async fn checkpoint(&self) -> Result<(), BackgroundTaskCancelled> {
+        let mut receiver = self.shared.state.subscribe();
+        loop {
+            let state = *receiver.borrow_and_update();
+            match state {
+                State::Running => return Ok(()),
+                State::Cancelled => return Err(BackgroundTaskCancelled),
+                State::Paused => {}
+            }
+            // Cannot fail, since the sender lives in `self.shared`
+            let _ = receiver.changed().await;
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.shared.state.borrow() == State::Cancelled
+    }
+}
+
+/// Returned by [`BackgroundTaskContext::checkpoint`] when the task is cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackgroundTaskCancelled;
+
+impl fmt::Display for BackgroundTaskCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "background task is cancelled")
+    }
+}
+
+impl std::error::Error for BackgroundTaskCancelled {}
+
+/// Returned by [`BackgroundTask::join`] when the output cannot be given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundTaskJoinError {
+    /// The output has been taken by an earlier call.
+    AlreadyJoined,
+    /// The task panicked or was aborted.
+    Failed,
+}
+
+impl fmt::Display for BackgroundTaskJoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyJoined => write!(f, "background task is already joined"),
+            Self::Failed => write!(f, "background task failed to complete"),
+        }
+    }
+}
+
+impl std::error::Error for BackgroundTaskJoinError {}
+
+/// Allows `?` in the generated `join` of tasks returning `Result<_, String>`
+impl From<BackgroundTaskJoinError> for String {
+    fn from(err: BackgroundTaskJoinError) -> Self {
+        err.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::background_task::{
+        BackgroundTask, BackgroundTaskCancelled, BackgroundTaskJoinError,
+    };
+    use std::sync::{Arc, Mutex};
+    use tokio::sync::oneshot;
+
+    #[tokio::test]
+    async fn test_progress_and_join() {
+        let (start_tx, start_rx) = oneshot::channel::<()>();
+        let task = BackgroundTask::spawn(|context| async move {
+            context.report_progress(1);
+            start_rx.await.unwrap();
+            context.report_progress(2);
+            42
+        });
+
+        let received = Arc::new(Mutex::new(vec![]));
+        let received_clone = received.clone();
+        // Listeners added later should still receive the latest progress
+        while task.shared.latest_progress.lock().unwrap().is_none() {
+            tokio::task::yield_now().await;
+        }
+        task.add_progress_listener(move |x| {
+            received_clone.lock().unwrap().push(x);
+            true
+        });
+        start_tx.send(()).unwrap();
+
+        assert_eq!(task.join().await, Ok(42));
+        assert!(task.is_finished());
+        assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+        assert_eq!(
+            task.join().await,
+            Err(BackgroundTaskJoinError::AlreadyJoined)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_progress_listener_removed() {
+        let (start_tx, start_rx) = oneshot::channel::<()>();
+        let task = BackgroundTask::spawn(|context| async move {
+            start_rx.await.unwrap();
+            for i in 0..3 {
+                context.report_progress(i);
+            }
+            context.shared.listeners.lock().unwrap().len()
+        });
+
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+        // Like a sink whose stream is closed after the first progress
+        task.add_progress_listener(move |_| {
+            *calls_clone.lock().unwrap() += 1;
+            false
+        });
+        start_tx.send(()).unwrap();
+
+        assert_eq!(task.join().await, Ok(0));
+        assert_eq!(*calls.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume_cancel() {
+        let task: BackgroundTask<(), _> =
+            BackgroundTask::spawn(|context| async move { context.checkpoint().await });
+        task.pause();
+        task.resume();
+        assert!(!task.is_paused());
+        assert_eq!(task.join().await, Ok(Ok(())));
+
+        let task: BackgroundTask<(), _> =
+            BackgroundTask::spawn(|context| async move { context.checkpoint().await });
+        task.pause();
+        assert!(task.is_paused());
+        task.cancel();
+        task.resume();
+        assert!(task.is_cancelled());
+        assert_eq!(task.join().await, Ok(Err(BackgroundTaskCancelled)));
+    }
+}
@@ -0,0 +1,167 @@
+//! Cancellation of running Rust functions, requested by the Dart side (e.g. a disposed `RustScope`).
+
+use crate::platform_types::MessagePort;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// Tells whether the Dart side is still interested in the result of a Rust function.
+///
+/// Long-running functions can check [CancellationToken::current] from time to time and stop early.
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The token of the Rust function being executed, which works for both normal and async functions.
+    /// Outside of them (e.g. in sync functions), a token that is never cancelled is returned.
+    pub fn current() -> Self {
+        CURRENT
+            .with(|current| current.borrow().clone())
+            .unwrap_or_default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Runs `f` with this token as [CancellationToken::current].
+    pub(crate) fn enter<R>(&self, f: impl FnOnce() -> R) -> R {
+        struct RestoreGuard(Option<CancellationToken>);
+
+        impl Drop for RestoreGuard {
+            fn drop(&mut self) {
+                CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _guard = RestoreGuard(CURRENT.with(|current| current.replace(Some(self.clone()))));
+        f()
+    }
+
+    /// Makes this token [CancellationToken::current] whenever the future is polled.
+    pub(crate) fn wrap_future<F: Future>(self, inner: F) -> impl Future<Output = F::Output> {
+        WithCancellationToken {
+            token: self,
+            inner: Box::pin(inner),
+        }
+    }
+}
+
+struct WithCancellationToken<F> {
+    token: CancellationToken,
+    inner: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for WithCancellationToken<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        this.token.enter(|| this.inner.as_mut().poll(cx))
+    }
+}
+
+/// The token of a task, which can be cancelled via the port of the task until dropped.
+pub(crate) struct TaskCancellationToken {
+    pub token: CancellationToken,
+    #[cfg(not(wasm))]
+    port: MessagePort,
+}
+
+#[cfg(not(wasm))]
+lazy_static::lazy_static! {
+    static ref RUNNING_TASKS: std::sync::Mutex<std::collections::HashMap<MessagePort, CancellationToken>> =
+        Default::default();
+}
+
+impl TaskCancellationToken {
+    // Cancelling via ports is not supported on the web, since the ports are JavaScript objects there
+    #[allow(unused_variables)]
+    pub(crate) fn new(port: &MessagePort) -> Self {
+        let token = CancellationToken::new();
+        #[cfg(not(wasm))]
+        (RUNNING_TASKS.lock().unwrap()).insert(*port, token.clone());
+        Self {
+            token,
+            #[cfg(not(wasm))]
+            port: *port,
+        }
+    }
+}
+
+impl Drop for TaskCancellationToken {
+    fn drop(&mut self) {
+        #[cfg(not(wasm))]
+        (RUNNING_TASKS.lock().unwrap()).remove(&self.port);
+    }
+}
+
+/// Cancels the running task which reports to the given port, if any.
+#[cfg(not(wasm))]
+pub(crate) fn cancel_task(port: MessagePort) {
+    if let Some(token) = (RUNNING_TASKS.lock().unwrap()).get(&port) {
+        token.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current() {
+        assert!(!CancellationToken::current().is_cancelled());
+
+        let token = CancellationToken::new();
+        token.cancel();
+        token.enter(|| {
+            assert!(CancellationToken::current().is_cancelled());
+            CancellationToken::new()
+                .enter(|| assert!(!CancellationToken::current().is_cancelled()));
+            assert!(CancellationToken::current().is_cancelled());
+        });
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+
+    #[cfg(not(wasm))]
+    #[test]
+    fn test_cancel_task() {
+        let task = TaskCancellationToken::new(&12345);
+        cancel_task(54321);
+        assert!(!task.token.is_cancelled());
+        cancel_task(12345);
+        assert!(task.token.is_cancelled());
+
+        drop(task);
+        assert!(!(RUNNING_TASKS.lock().unwrap()).contains_key(&12345));
+    }
+
+    #[cfg(not(wasm))]
+    #[tokio::test]
+    async fn test_wrap_future() {
+        let token = CancellationToken::new();
+        let future = token.clone().wrap_future(async {
+            let before = CancellationToken::current().is_cancelled();
+            tokio::task::yield_now().await;
+            (before, CancellationToken::current().is_cancelled())
+        });
+        token.cancel();
+        assert_eq!(future.await, (true, true));
+        assert!(!CancellationToken::current().is_cancelled());
+    }
+}
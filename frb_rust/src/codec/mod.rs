@@ -1,4 +1,5 @@
 use crate::platform_types::DartAbi;
+use crate::rust2dart::sender::Rust2DartSender;
 use std::any::Any;
 use std::backtrace::Backtrace;
 
@@ -22,6 +23,15 @@ pub trait Rust2DartMessageTrait {
 
     fn into_dart_abi(self) -> DartAbi;
 
+    /// Post the message to Dart, possibly split into multiple chunks.
+    #[doc(hidden)]
+    fn send_or_warn(self, sender: &Rust2DartSender)
+    where
+        Self: Sized,
+    {
+        sender.send_or_warn(self.into_dart_abi());
+    }
+
     /// # Safety
     ///
     /// This should never be called manually.
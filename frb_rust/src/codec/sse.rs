@@ -3,11 +3,13 @@ use crate::generalized_isolate::IntoDart;
 use crate::handler::error::error_to_string;
 use crate::platform_types::{DartAbi, PlatformGeneralizedUint8ListPtr, WireSyncRust2DartSse};
 use crate::rust2dart::action::Rust2DartAction;
+use crate::rust2dart::chunked::send_bytes_or_warn;
+use crate::rust2dart::sender::Rust2DartSender;
 use byteorder::NativeEndian;
 use byteorder::WriteBytesExt;
 use std::any::Any;
 use std::backtrace::Backtrace;
-use std::io::Cursor;
+use std::io::{Cursor, Read, Write};
 
 /// Codec that does a simple serialization
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -51,6 +53,44 @@ impl SseCodec {
 
 pub struct Rust2DartMessageSse(Vec<u8>);
 
+/// Replaces the action byte of a message, whose remaining bytes are
+/// the uncompressed length (u32, little endian) and the LZ4-compressed original message.
+///
+/// NOTE: Please keep in sync with the Dart side
+const COMPRESSED_LZ4_MARKER: u8 = 0xFF;
+
+/// Small messages are not worth the compression overhead
+const COMPRESS_THRESHOLD: usize = 1024;
+
+impl Rust2DartMessageSse {
+    fn compress(self) -> Self {
+        if self.0.len() <= COMPRESS_THRESHOLD {
+            return self;
+        }
+
+        let compressed = lz4_flex::block::compress(&self.0);
+        let header_len = 1 + std::mem::size_of::<u32>();
+        if compressed.len() + header_len >= self.0.len() {
+            return self;
+        }
+
+        let mut data = Vec::with_capacity(header_len + compressed.len());
+        data.push(COMPRESSED_LZ4_MARKER);
+        data.extend_from_slice(&(self.0.len() as u32).to_le_bytes());
+        data.extend_from_slice(&compressed);
+        Self(data)
+    }
+}
+
+// Only to be used by generated code, thus hidden in doc
+#[doc(hidden)]
+pub fn compress_result_sse(
+    raw: Result<Rust2DartMessageSse, Rust2DartMessageSse>,
+) -> Result<Rust2DartMessageSse, Rust2DartMessageSse> {
+    raw.map(Rust2DartMessageSse::compress)
+        .map_err(Rust2DartMessageSse::compress)
+}
+
 impl Rust2DartMessageTrait for Rust2DartMessageSse {
     type WireSyncRust2DartType = WireSyncRust2DartSse;
 
@@ -62,6 +102,10 @@ impl Rust2DartMessageTrait for Rust2DartMessageSse {
         self.0.into_dart()
     }
 
+    fn send_or_warn(self, sender: &Rust2DartSender) {
+        send_bytes_or_warn(sender, self.0);
+    }
+
     unsafe fn from_raw_wire_sync(raw: Self::WireSyncRust2DartType) -> Self {
         #[cfg(not(wasm))]
         {
@@ -128,6 +172,36 @@ impl SseDeserializer {
     pub fn end(self) {
         assert_eq!(self.data_len as u64, self.cursor.position());
     }
+
+    /// Read `len` primitives with a single memory copy, instead of decoding them one by one.
+    ///
+    /// The length comes from the wire, thus it is checked against the remaining bytes
+    /// before allocating, so that a corrupt message cannot cause a huge allocation.
+    pub fn read_primitive_list<T: SseBulkPrimitive>(
+        &mut self,
+        len: i32,
+    ) -> std::io::Result<Vec<T>> {
+        let remaining_bytes =
+            (self.cursor.get_ref().len() as u64).saturating_sub(self.cursor.position());
+        let byte_len = usize::try_from(len)
+            .ok()
+            .and_then(|len| len.checked_mul(std::mem::size_of::<T>()))
+            .filter(|&byte_len| byte_len as u64 <= remaining_bytes)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid list length {len}, while only {remaining_bytes} bytes remain"),
+                )
+            })?;
+
+        let mut ans = vec![T::default(); len as usize];
+        // SAFETY: `SseBulkPrimitive` types have no padding and every bit pattern is valid,
+        // and the data is in native endian, which is what the Dart side writes.
+        let bytes =
+            unsafe { std::slice::from_raw_parts_mut(ans.as_mut_ptr() as *mut u8, byte_len) };
+        self.cursor.read_exact(bytes)?;
+        Ok(ans)
+    }
 }
 
 pub struct SseSerializer {
@@ -146,11 +220,40 @@ impl SseSerializer {
             cursor: Cursor::new(vec![]),
         }
     }
+
+    /// Write the primitives with a single memory copy, instead of encoding them one by one.
+    pub fn write_primitive_list<T: SseBulkPrimitive>(&mut self, list: &[T]) {
+        // SAFETY: `SseBulkPrimitive` types have no padding,
+        // and the data is in native endian, which is what the Dart side reads.
+        let bytes = unsafe {
+            std::slice::from_raw_parts(list.as_ptr() as *const u8, std::mem::size_of_val(list))
+        };
+        self.cursor.write_all(bytes).unwrap();
+    }
 }
 
+/// Primitives whose lists can be (de)serialized as raw memory.
+///
+/// This is sealed, since the implementors must be plain old data.
+pub trait SseBulkPrimitive: sealed::Sealed + Default + Copy {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_sse_bulk_primitive {
+    ($($ty:ty),*) => {$(
+        impl sealed::Sealed for $ty {}
+        impl SseBulkPrimitive for $ty {}
+    )*};
+}
+
+impl_sse_bulk_primitive!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
 #[cfg(test)]
 mod tests {
-    use crate::for_generated::{Rust2DartMessageSse, SseSerializer};
+    use super::Rust2DartMessageSse;
+    use crate::for_generated::{Dart2RustMessageSse, SseDeserializer, SseSerializer};
 
     #[test]
     fn test_simplest() {
@@ -158,6 +261,78 @@ mod tests {
         assert_eq!(Rust2DartMessageSse::simplest().0, vec![]);
     }
 
+    #[test]
+    fn test_compress() {
+        assert_eq!(Rust2DartMessageSse(vec![0; 10]).compress().0, vec![0; 10]);
+
+        let compressed = Rust2DartMessageSse(vec![0; 10000]).compress().0;
+        assert_eq!(compressed[0], super::COMPRESSED_LZ4_MARKER);
+        assert_eq!(compressed[1..5], 10000u32.to_le_bytes());
+        assert!(compressed.len() < 1000);
+    }
+
+    #[test]
+    fn test_compress_roundtrip() {
+        // A simple xorshift, such that inputs with all kinds of match patterns are covered
+        let mut state = 0x2545f4914f6cdd1du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..200 {
+            let len = (next() % 5000) as usize + 1025;
+            let alphabet = (next() % 8) as u8 + 1;
+            let input = (0..len)
+                .map(|_| (next() % alphabet as u64) as u8)
+                .collect::<Vec<_>>();
+
+            let compressed = Rust2DartMessageSse(input.clone()).compress().0;
+            if compressed[0] != super::COMPRESSED_LZ4_MARKER {
+                assert_eq!(compressed, input);
+                continue;
+            }
+            let decompressed_len = u32::from_le_bytes(compressed[1..5].try_into().unwrap());
+            assert_eq!(
+                lz4_flex::block::decompress(&compressed[5..], decompressed_len as usize).unwrap(),
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_primitive_list() {
+        let list = vec![1.5f32, -2.0, 3.25];
+        let mut serializer = SseSerializer::new();
+        serializer.write_primitive_list(&list);
+        let bytes = serializer.cursor.into_inner();
+        assert_eq!(bytes[4..8], (-2.0f32).to_ne_bytes());
+
+        let mut deserializer = SseDeserializer::new(Dart2RustMessageSse {
+            data_len: bytes.len() as _,
+            vec: bytes,
+        });
+        assert_eq!(deserializer.read_primitive_list::<f32>(3).unwrap(), list);
+        deserializer.end();
+    }
+
+    #[test]
+    fn test_primitive_list_invalid_len() {
+        let bytes = vec![0u8; 8];
+        for len in [-1, 3, i32::MAX] {
+            let mut deserializer = SseDeserializer::new(Dart2RustMessageSse {
+                data_len: bytes.len() as _,
+                vec: bytes.clone(),
+            });
+            let err = deserializer.read_primitive_list::<f32>(len).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+            // Nothing is consumed, since the length is checked before reading
+            assert_eq!(deserializer.cursor.position(), 0);
+        }
+    }
+
     #[test]
     fn test_serializer_default() {
         assert_eq!(SseSerializer::default().cursor.into_inner(), vec![]);
@@ -1,7 +1,7 @@
 use crate::codec::dco::Rust2DartMessageDco;
 use crate::codec::sse::Rust2DartMessageSse;
 use crate::codec::Rust2DartMessageTrait;
-use crate::platform_types::{WireSyncRust2DartDco, WireSyncRust2DartSse};
+use crate::platform_types::{MessagePort, WireSyncRust2DartDco, WireSyncRust2DartSse};
 
 /// # Safety
 ///
@@ -29,3 +29,69 @@ pub unsafe extern "C" fn free_wire_sync_rust2dart_dco(value: WireSyncRust2DartDc
 pub unsafe extern "C" fn free_wire_sync_rust2dart_sse(value: WireSyncRust2DartSse) {
     let _ = Rust2DartMessageSse::from_raw_wire_sync(value);
 }
+
+/// Cancels the running Rust function which reports to the given port, e.g. when a `RustScope` is disposed.
+#[no_mangle]
+pub extern "C" fn frb_cancel_task(port: MessagePort) {
+    crate::cancellation::cancel_task(port);
+}
+
+/// The free function of the external typed data in `DartCObject`, i.e. `Dart_HandleFinalizer`
+type ExternalTypedDataCallback =
+    unsafe extern "C" fn(isolate_callback_data: *mut std::ffi::c_void, peer: *mut std::ffi::c_void);
+
+struct ExternalTypedDataFinalizerToken {
+    peer: *mut std::ffi::c_void,
+    callback: ExternalTypedDataCallback,
+}
+
+/// Dart's `NativeFinalizer` only passes one pointer to the native callback,
+/// thus the zero-copy buffers received in sync mode bundle the arguments of their free function here.
+///
+/// # Safety
+///
+/// This function should never be called manually.
+#[no_mangle]
+pub unsafe extern "C" fn frb_external_typed_data_finalizer_token_new(
+    peer: *mut std::ffi::c_void,
+    callback: *mut std::ffi::c_void,
+) -> *mut std::ffi::c_void {
+    let token = ExternalTypedDataFinalizerToken {
+        peer,
+        callback: std::mem::transmute::<*mut std::ffi::c_void, ExternalTypedDataCallback>(callback),
+    };
+    Box::into_raw(Box::new(token)).cast()
+}
+
+/// # Safety
+///
+/// This function should never be called manually.
+#[no_mangle]
+pub unsafe extern "C" fn frb_external_typed_data_finalize(token: *mut std::ffi::c_void) {
+    let token = Box::from_raw(token.cast::<ExternalTypedDataFinalizerToken>());
+    (token.callback)(std::ptr::null_mut(), token.peer);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static FREED_PEER: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe extern "C" fn record_free(_: *mut std::ffi::c_void, peer: *mut std::ffi::c_void) {
+        FREED_PEER.store(peer as usize, Ordering::SeqCst);
+    }
+
+    #[test]
+    fn test_external_typed_data_finalizer() {
+        unsafe {
+            let token = frb_external_typed_data_finalizer_token_new(
+                42 as *mut std::ffi::c_void,
+                record_free as *mut std::ffi::c_void,
+            );
+            frb_external_typed_data_finalize(token);
+        }
+        assert_eq!(FREED_PEER.load(Ordering::SeqCst), 42);
+    }
+}
@@ -96,6 +96,54 @@ macro_rules! frb_generated_sse_codec {
             fn sse_encode(self, serializer: &mut $crate::for_generated::SseSerializer);
         }
 
+        // Shared by the codec of all lists and optionals, instead of generating
+        // a near-identical body for each of them
+        fn sse_encode_list<T: SseEncode>(
+            list: Vec<T>,
+            serializer: &mut $crate::for_generated::SseSerializer,
+        ) {
+            <i32>::sse_encode(list.len() as _, serializer);
+            for item in list {
+                item.sse_encode(serializer);
+            }
+        }
+
+        fn sse_decode_list<T: SseDecode>(
+            deserializer: &mut $crate::for_generated::SseDeserializer,
+        ) -> Vec<T> {
+            let len = <i32>::sse_decode(deserializer);
+            (0..len).map(|_| T::sse_decode(deserializer)).collect()
+        }
+
+        fn sse_encode_option<T: SseEncode>(
+            value: Option<T>,
+            serializer: &mut $crate::for_generated::SseSerializer,
+        ) {
+            <bool>::sse_encode(value.is_some(), serializer);
+            if let Some(value) = value {
+                value.sse_encode(serializer);
+            }
+        }
+
+        fn sse_decode_option<T: SseDecode>(
+            deserializer: &mut $crate::for_generated::SseDeserializer,
+        ) -> Option<T> {
+            <bool>::sse_decode(deserializer).then(|| T::sse_decode(deserializer))
+        }
+
+        // For fields of `#[frb(lazy)]` structs, which Dart keeps undecoded until first accessed,
+        // thus each of them is prefixed with its length to be skippable
+        fn sse_encode_lazy_field<T: SseEncode>(
+            value: T,
+            serializer: &mut $crate::for_generated::SseSerializer,
+        ) {
+            let mut chunk = $crate::for_generated::SseSerializer::new();
+            value.sse_encode(&mut chunk);
+            let bytes = chunk.cursor.into_inner();
+            <i32>::sse_encode(bytes.len() as _, serializer);
+            serializer.write_primitive_list(&bytes);
+        }
+
         fn transform_result_sse<T, E>(
             raw: Result<T, E>,
         ) -> Result<
@@ -132,6 +180,11 @@ macro_rules! frb_generated_stream_sink {
             base: $crate::for_generated::StreamSinkBase<T, Rust2DartCodec>,
         }
 
+        /// Reports [`FrbProgress`]($crate::FrbProgress), received as `Stream<FrbProgress>` in Dart.
+        pub type ProgressSink<
+            Rust2DartCodec = $crate::for_generated::$default_stream_sink_codec,
+        > = StreamSink<$crate::FrbProgress, Rust2DartCodec>;
+
         impl<T, Rust2DartCodec: $crate::for_generated::BaseCodec> StreamSink<T, Rust2DartCodec> {
             pub fn deserialize(raw: String) -> Self {
                 Self { base: $crate::for_generated::StreamSinkBase::deserialize(raw) }
@@ -208,6 +261,17 @@ macro_rules! frb_generated_stream_sink {
 #[macro_export]
 macro_rules! frb_generated_default_handler {
     () => {
+        $crate::frb_generated_default_handler!(@inner {});
+    };
+    // The code generated with `minimal_output` does not capture panic backtraces
+    (minimal_output: $enabled:expr) => {
+        $crate::frb_generated_default_handler!(@inner {
+            if $enabled {
+                $crate::PanicBacktrace::set_enabled(false);
+            }
+        });
+    };
+    (@inner $setup:block) => {
         #[cfg(not(target_family = "wasm"))]
         $crate::for_generated::lazy_static! {
             pub static ref FLUTTER_RUST_BRIDGE_HANDLER:$crate::DefaultHandler<$crate::for_generated::SimpleThreadPool> = {
@@ -218,6 +282,7 @@ macro_rules! frb_generated_default_handler {
                     FLUTTER_RUST_BRIDGE_CODEGEN_VERSION,
                     flutter_rust_bridge::for_generated::FLUTTER_RUST_BRIDGE_RUNTIME_VERSION,
                 );
+                $setup
 
                 $crate::DefaultHandler::new_simple(Default::default())
             };
@@ -231,7 +296,10 @@ macro_rules! frb_generated_default_handler {
         #[cfg(target_family = "wasm")]
         $crate::for_generated::lazy_static! {
             pub static ref FLUTTER_RUST_BRIDGE_HANDLER: $crate::DefaultHandler<&'static std::thread::LocalKey<$crate::for_generated::SimpleThreadPool>>
-                = $crate::DefaultHandler::new_simple(&THREAD_POOL);
+                = {
+                    $setup
+                    $crate::DefaultHandler::new_simple(&THREAD_POOL)
+                };
         }
     }
 }
@@ -2,9 +2,14 @@
 #[macro_export]
 macro_rules! frb_generated_boilerplate_io {
     () => {
+        $crate::frb_generated_boilerplate_io!(symbol_prefix = "");
+    };
+    // The prefix is used when multiple versions of the API are generated into the same crate
+    (symbol_prefix = $symbol_prefix:literal) => {
         $crate::frb_generated_io_cst_codec!();
-        $crate::frb_generated_io_content_hash!();
-        $crate::frb_generated_io_extern_func!();
+        $crate::frb_generated_io_content_hash!(symbol_prefix = $symbol_prefix);
+        $crate::frb_generated_io_extern_func!(symbol_prefix = $symbol_prefix);
+        $crate::frb_generated_io_method_channel!(symbol_prefix = $symbol_prefix);
     };
 }
 
@@ -27,10 +32,33 @@ macro_rules! frb_generated_io_cst_codec {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! frb_generated_io_content_hash {
-    () => {
-        #[no_mangle]
+    (symbol_prefix = $symbol_prefix:literal) => {
+        #[export_name = concat!($symbol_prefix, "frb_get_rust_content_hash")]
         pub extern "C" fn frb_get_rust_content_hash() -> i32 {
             FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH
         }
     };
 }
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! frb_generated_io_method_channel {
+    (symbol_prefix = $symbol_prefix:literal) => {
+        /// Called by the host platform with a request received from the Dart `MethodChannelTransport`.
+        ///
+        /// Returns `false` if the request is malformed, which the host should report as an error of the channel.
+        #[export_name = concat!($symbol_prefix, "frb_method_channel_dispatch")]
+        pub unsafe extern "C" fn frb_method_channel_dispatch(data: *const u8, len: usize) -> bool {
+            let Some((func_id, port_, payload)) =
+                $crate::for_generated::method_channel_parse_dispatch_request(
+                    $crate::for_generated::method_channel_dispatch_request_slice(data, len),
+                )
+            else {
+                return false;
+            };
+            let (ptr_, rust_vec_len_) = $crate::for_generated::into_leak_vec_ptr(payload);
+            pde_ffi_dispatcher_primary_impl(func_id, port_, ptr_, rust_vec_len_, rust_vec_len_);
+            true
+        }
+    };
+}
@@ -11,7 +11,8 @@ mod pointer;
 
 pub use crate::codec::dco::{transform_result_dco, Rust2DartMessageDco};
 pub use crate::codec::sse::{
-    Dart2RustMessageSse, Rust2DartMessageSse, SseDeserializer, SseSerializer,
+    compress_result_sse, Dart2RustMessageSse, Rust2DartMessageSse, SseBulkPrimitive,
+    SseDeserializer, SseSerializer,
 };
 pub use crate::codec::Rust2DartMessageTrait;
 pub use crate::codec::{cst::CstCodec, dco::DcoCodec, sse::SseCodec, BaseCodec};
@@ -38,6 +39,11 @@ pub use crate::lockable::{
     base::Lockable, order::LockableOrder, order_computer::lockable_compute_decode_order,
     order_info::LockableOrderInfo,
 };
+#[cfg(not(wasm))]
+pub use crate::method_channel::{
+    dispatch_request_slice as method_channel_dispatch_request_slice,
+    parse_dispatch_request as method_channel_parse_dispatch_request,
+};
 #[allow(unused)]
 pub use crate::misc::manual_impl::*;
 pub use crate::misc::version::FLUTTER_RUST_BRIDGE_RUNTIME_VERSION;
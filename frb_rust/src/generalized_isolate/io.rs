@@ -1,6 +1,26 @@
-pub use allo_isolate::{IntoDart, IntoDartExceptPrimitive};
-pub type Channel = allo_isolate::Isolate;
+use crate::method_channel;
+use crate::platform_types::MessagePort;
 pub use allo_isolate::ZeroCopyBuffer;
+pub use allo_isolate::{IntoDart, IntoDartExceptPrimitive};
+
+/// Similar to `allo_isolate::Isolate`, except that it also supports ports of the method channel transport
+#[derive(Copy, Clone, Debug)]
+pub struct Channel {
+    port: MessagePort,
+}
+
+impl Channel {
+    pub fn new(port: MessagePort) -> Self {
+        Self { port }
+    }
+
+    pub fn post(&self, msg: impl IntoDart) -> bool {
+        if method_channel::is_method_channel_port(self.port) {
+            return method_channel::post(self.port, msg);
+        }
+        allo_isolate::Isolate::new(self.port).post(msg)
+    }
+}
 
 /// A channel that implements `Send`
 pub type SendableChannelHandle = Channel;
@@ -1,3 +1,4 @@
+use crate::cancellation::CancellationToken;
 use crate::codec::sse::Dart2RustMessageSse;
 use crate::codec::BaseCodec;
 use crate::codec::Rust2DartMessageTrait;
@@ -97,9 +98,10 @@ pub trait TaskRetFutTrait {}
 #[cfg(wasm)]
 impl<T> TaskRetFutTrait for T {}
 
-// Originally there were things for StreamSink, but it was moved, so now it is empty
 /// A context for task execution
-pub struct TaskContext {}
+pub struct TaskContext {
+    cancellation_token: CancellationToken,
+}
 
 // frb-coverage:ignore-start
 impl Default for TaskContext {
@@ -111,6 +113,15 @@ impl Default for TaskContext {
 
 impl TaskContext {
     pub fn new() -> Self {
-        Self {}
+        Self::new_with_cancellation_token(CancellationToken::new())
+    }
+
+    pub(crate) fn new_with_cancellation_token(cancellation_token: CancellationToken) -> Self {
+        Self { cancellation_token }
+    }
+
+    /// Cancelled when the Dart side is no longer interested in the result.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
     }
 }
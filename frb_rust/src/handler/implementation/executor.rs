@@ -1,3 +1,4 @@
+use crate::cancellation::TaskCancellationToken;
 use crate::codec::BaseCodec;
 use crate::codec::Rust2DartMessageTrait;
 use crate::generalized_isolate::Channel;
@@ -61,6 +62,8 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
 
         let TaskInfo { port, .. } = task_info;
         let port: MessagePort = port.unwrap();
+        // Registered before being queued, thus it can be cancelled before it starts
+        let cancellation = TaskCancellationToken::new(&port);
 
         self.thread_pool
             .execute(transfer!(|port: crate::platform_types::MessagePort| {
@@ -69,9 +72,10 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
                 let thread_result = PanicBacktrace::catch_unwind(AssertUnwindSafe(|| {
                     #[allow(clippy::clone_on_copy)]
                     let sender = Rust2DartSender::new(Channel::new(port2.clone()));
-                    let task_context = TaskContext::new();
+                    let token = cancellation.token.clone();
+                    let task_context = TaskContext::new_with_cancellation_token(token.clone());
 
-                    let ret = task(task_context);
+                    let ret = token.enter(|| task(task_context));
 
                     ExecuteNormalOrAsyncUtils::handle_result::<Rust2DartCodec, _>(ret, sender, el2);
                 }));
@@ -111,6 +115,8 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
         let el = self.error_listener;
         let el2 = self.error_listener;
 
+        let cancellation = TaskCancellationToken::new(task_info.port.as_ref().unwrap());
+
         self.async_runtime.spawn(async move {
             let TaskInfo { port, .. } = task_info;
             let port = port.unwrap();
@@ -120,9 +126,10 @@ impl<EL: ErrorListener + Sync, TP: BaseThreadPool, AR: BaseAsyncRuntime> Executo
             let async_result = AssertUnwindSafe(async {
                 #[allow(clippy::clone_on_copy)]
                 let sender = Rust2DartSender::new(Channel::new(port2.clone()));
-                let task_context = TaskContext::new();
+                let token = cancellation.token.clone();
+                let task_context = TaskContext::new_with_cancellation_token(token.clone());
 
-                let ret = task(task_context).await;
+                let ret = token.wrap_future(task(task_context)).await;
 
                 ExecuteNormalOrAsyncUtils::handle_result::<Rust2DartCodec, _>(ret, sender, el2);
             })
@@ -150,11 +157,11 @@ impl ExecuteNormalOrAsyncUtils {
     {
         match ret {
             Ok(result) => {
-                sender.send_or_warn(result.into_dart_abi());
+                result.send_or_warn(&sender);
             }
             Err(error) => {
                 el.on_error(Error::CustomError);
-                sender.send_or_warn(error.into_dart_abi());
+                error.send_or_warn(&sender);
             }
         };
     }
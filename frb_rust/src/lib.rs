@@ -8,6 +8,11 @@ mod rust2dart;
 pub(crate) mod third_party;
 pub(crate) mod thread_pool;
 
+#[cfg(feature = "rust-async")]
+pub(crate) mod actor;
+#[cfg(feature = "rust-async")]
+pub(crate) mod background_task;
+pub(crate) mod cancellation;
 pub(crate) mod codec;
 #[cfg(all(feature = "rust-async", feature = "dart-opaque"))]
 pub(crate) mod dart_fn;
@@ -20,14 +25,27 @@ pub(crate) mod generalized_arc;
 pub(crate) mod internal_generated;
 pub(crate) mod lifetimeable;
 pub(crate) mod lockable;
+#[cfg(not(wasm))]
+pub(crate) mod method_channel;
+#[cfg(not(wasm))]
+pub(crate) mod replay;
 #[doc(hidden)] // only to be used as `for_generated::rust_async`
 pub mod rust_async;
 #[cfg(feature = "rust-async")]
 pub(crate) mod rust_auto_opaque;
 pub(crate) mod rust_opaque;
 pub(crate) mod stream;
+#[cfg(not(wasm))]
+pub(crate) mod thread_pinned;
 pub(crate) mod web_transfer;
 
+#[cfg(feature = "rust-async")]
+pub use crate::actor::{Actor, ActorFuture, ActorPanicked};
+#[cfg(feature = "rust-async")]
+pub use crate::background_task::{
+    BackgroundTask, BackgroundTaskCancelled, BackgroundTaskContext, BackgroundTaskJoinError,
+};
+pub use crate::cancellation::CancellationToken;
 pub use crate::codec::sse::Dart2RustMessageSse;
 pub use crate::codec::sse::SseCodec;
 pub use crate::codec::{BaseCodec, Rust2DartMessageTrait};
@@ -44,15 +62,23 @@ pub use crate::misc::panic_backtrace::{CatchUnwindWithBacktrace, PanicBacktrace}
 #[cfg(feature = "user-utils")]
 pub use crate::misc::user_utils::setup_default_user_utils;
 pub use crate::platform_types::DartAbi;
+#[cfg(not(wasm))]
+pub use crate::replay::{replay_recording, ReplayDispatch, ReplayMismatch};
+pub use crate::rust2dart::chunked::ChunkedTransferConfig;
 pub use crate::rust2dart::sender::Rust2DartSendError;
 #[cfg(all(feature = "rust-async", feature = "thread-pool"))]
 pub use crate::rust_async::spawn_blocking_with;
 #[cfg(feature = "rust-async")]
 pub use crate::rust_async::{spawn, spawn_local, BaseAsyncRuntime, JoinHandle, SimpleAsyncRuntime};
 #[cfg(feature = "rust-async")]
+pub use crate::rust_auto_opaque::scope::{RustAutoOpaqueReadScope, RustAutoOpaqueWriteScope};
+#[cfg(feature = "rust-async")]
 pub use crate::rust_auto_opaque::RustAutoOpaqueNom;
 #[allow(deprecated)]
 pub use crate::rust_opaque::{DartSafe, RustOpaqueNom};
+pub use crate::stream::progress::FrbProgress;
+#[cfg(not(wasm))]
+pub use crate::thread_pinned::ThreadPinned;
 #[cfg(feature = "thread-pool")]
 pub use crate::thread_pool::{BaseThreadPool, SimpleThreadPool};
 pub use flutter_rust_bridge_macros::frb;
@@ -0,0 +1,112 @@
+//! Encodes the messages to Dart into bytes.
+//!
+//! NOTE: Please keep in sync with the Dart side
+
+use allo_isolate::ffi::{DartCObject, DartCObjectType, DartTypedDataType};
+use std::ffi::CStr;
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_DOUBLE: u8 = 4;
+const TAG_STRING: u8 = 5;
+const TAG_UINT8_LIST: u8 = 6;
+const TAG_LIST: u8 = 7;
+
+/// Returns `None` for values that cannot be transferred, e.g. typed data except `Uint8List`.
+pub(super) fn encode(value: &DartCObject, output: &mut Vec<u8>) -> Option<()> {
+    unsafe {
+        match value.ty {
+            DartCObjectType::DartNull => output.push(TAG_NULL),
+            DartCObjectType::DartBool => output.push(if value.value.as_bool {
+                TAG_TRUE
+            } else {
+                TAG_FALSE
+            }),
+            DartCObjectType::DartInt32 => encode_int(value.value.as_int32 as i64, output),
+            DartCObjectType::DartInt64 => encode_int(value.value.as_int64, output),
+            DartCObjectType::DartDouble => {
+                output.push(TAG_DOUBLE);
+                output.extend_from_slice(&value.value.as_double.to_le_bytes());
+            }
+            DartCObjectType::DartString => {
+                output.push(TAG_STRING);
+                encode_bytes(CStr::from_ptr(value.value.as_string).to_bytes(), output);
+            }
+            DartCObjectType::DartTypedData => {
+                let data = value.value.as_typed_data;
+                if data.ty != DartTypedDataType::Uint8 {
+                    return None;
+                }
+                output.push(TAG_UINT8_LIST);
+                encode_bytes(slice_or_empty(data.values, data.length), output);
+            }
+            DartCObjectType::DartExternalTypedData => {
+                let data = value.value.as_external_typed_data;
+                if data.ty != DartTypedDataType::Uint8 {
+                    return None;
+                }
+                output.push(TAG_UINT8_LIST);
+                encode_bytes(slice_or_empty(data.data, data.length), output);
+            }
+            DartCObjectType::DartArray => {
+                let array = value.value.as_array;
+                output.push(TAG_LIST);
+                output.extend_from_slice(&(array.length as u32).to_le_bytes());
+                for item in slice_or_empty(array.values, array.length) {
+                    encode(&**item, output)?;
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(())
+}
+
+fn encode_int(value: i64, output: &mut Vec<u8>) {
+    output.push(TAG_INT);
+    output.extend_from_slice(&value.to_le_bytes());
+}
+
+fn encode_bytes(bytes: &[u8], output: &mut Vec<u8>) {
+    output.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    output.extend_from_slice(bytes);
+}
+
+unsafe fn slice_or_empty<'a, T>(ptr: *const T, len: isize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, len as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::method_channel::codec::encode;
+    use allo_isolate::IntoDart;
+
+    fn encode_value(value: impl IntoDart) -> Option<Vec<u8>> {
+        let mut output = vec![];
+        encode(&value.into_dart(), &mut output)?;
+        Some(output)
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(encode_value(()), Some(vec![0]));
+        assert_eq!(encode_value(true), Some(vec![2]));
+        assert_eq!(encode_value(5i32), Some(vec![3, 5, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(
+            encode_value("hi".to_owned()),
+            Some(vec![5, 2, 0, 0, 0, b'h', b'i'])
+        );
+        assert_eq!(encode_value(vec![7u8, 8]), Some(vec![6, 2, 0, 0, 0, 7, 8]));
+        assert_eq!(
+            encode_value(vec![4i64.into_dart(), ().into_dart()]),
+            Some(vec![7, 2, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(encode_value(vec![1i32, 2]), None);
+    }
+}
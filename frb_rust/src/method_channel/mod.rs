@@ -0,0 +1,121 @@
+//! Lets code of the host platform (e.g. Kotlin or Swift) relay calls between Dart and Rust,
+//! for environments where Dart cannot call Rust via FFI directly.
+//!
+//! * Dart -> Rust: The host receives `[func_id: i32][port: i64][SSE-encoded arguments]`
+//!   (little endian) from the Dart `MethodChannelTransport`, and passes it to
+//!   `frb_method_channel_dispatch` unchanged. It returns `false` for a malformed request,
+//!   which the host should report as an error of the channel.
+//! * Rust -> Dart: Messages to the (negative) ports allocated by Dart are not posted via
+//!   `Dart_PostCObject`, but given to the callback registered by `frb_method_channel_set_post_callback`
+//!   as `[port: i64][value]`, which the host sends to `MethodChannelTransport.handleMessageFromRust`.
+
+use crate::generalized_isolate::IntoDart;
+use crate::misc::logs::log_warn_or_println;
+use crate::platform_types::{DartAbi, MessagePort};
+use std::sync::RwLock;
+
+mod codec;
+
+/// Receives a message that should be forwarded to Dart.
+/// The data is only valid during the call, thus the host should copy it if needed.
+pub type MethodChannelPostCallback = unsafe extern "C" fn(data: *const u8, len: usize);
+
+static POST_CALLBACK: RwLock<Option<MethodChannelPostCallback>> = RwLock::new(None);
+
+/// Called once by the host platform before Dart uses the `MethodChannelTransport`.
+#[no_mangle]
+pub extern "C" fn frb_method_channel_set_post_callback(callback: MethodChannelPostCallback) {
+    *POST_CALLBACK.write().unwrap() = Some(callback);
+}
+
+/// Sets the callback and returns the previous one, e.g. to restore it later.
+pub(crate) fn replace_post_callback(
+    callback: Option<MethodChannelPostCallback>,
+) -> Option<MethodChannelPostCallback> {
+    std::mem::replace(&mut *POST_CALLBACK.write().unwrap(), callback)
+}
+
+/// Dart allocates ports of the method channel transport from negative numbers,
+/// which never collide with ports of the Dart VM.
+pub(crate) fn is_method_channel_port(port: MessagePort) -> bool {
+    port < 0
+}
+
+pub(crate) fn post(port: MessagePort, msg: impl IntoDart) -> bool {
+    let msg = msg.into_dart();
+    let data = encode_message(port, &msg);
+    // The message is consumed here instead of by the Dart VM, thus we are the one to free it
+    unsafe { allo_isolate::ffi::run_destructors(&msg) };
+    drop(msg);
+
+    let Some(data) = data else {
+        log_warn_or_println("MethodChannelTransport does not support this message type");
+        return false;
+    };
+    let Some(callback) = *POST_CALLBACK.read().unwrap() else {
+        log_warn_or_println("frb_method_channel_set_post_callback has not been called");
+        return false;
+    };
+    unsafe { callback(data.as_ptr(), data.len()) };
+    true
+}
+
+pub(crate) fn encode_message(port: MessagePort, msg: &DartAbi) -> Option<Vec<u8>> {
+    let mut data = port.to_le_bytes().to_vec();
+    codec::encode(msg, &mut data)?;
+    Some(data)
+}
+
+/// Splits a request from Dart into `(func_id, port, payload)`,
+/// or returns `None` if it is too short to be one, e.g. truncated by the host.
+// Only to be used by generated code
+#[doc(hidden)]
+pub fn parse_dispatch_request(data: &[u8]) -> Option<(i32, MessagePort, Vec<u8>)> {
+    let func_id = i32::from_le_bytes(data.get(..4)?.try_into().ok()?);
+    let port = MessagePort::from_le_bytes(data.get(4..12)?.try_into().ok()?);
+    Some((func_id, port, data[12..].to_vec()))
+}
+
+/// # Safety
+///
+/// `data` must be null or point to `len` readable bytes.
+// Only to be used by generated code
+#[doc(hidden)]
+pub unsafe fn dispatch_request_slice<'a>(data: *const u8, len: usize) -> &'a [u8] {
+    if data.is_null() {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::method_channel::{dispatch_request_slice, encode_message, parse_dispatch_request};
+
+    #[test]
+    fn test_parse_dispatch_request() {
+        let data = [[7, 0, 0, 0].as_slice(), &(-3i64).to_le_bytes(), &[1, 2]].concat();
+        assert_eq!(parse_dispatch_request(&data), Some((7, -3, vec![1, 2])));
+        assert_eq!(parse_dispatch_request(&data[..12]), Some((7, -3, vec![])));
+    }
+
+    #[test]
+    fn test_parse_dispatch_request_malformed() {
+        let data = [[7, 0, 0, 0].as_slice(), &(-3i64).to_le_bytes()].concat();
+        assert_eq!(parse_dispatch_request(&[]), None);
+        assert_eq!(parse_dispatch_request(&data[..3]), None);
+        assert_eq!(parse_dispatch_request(&data[..11]), None);
+        assert_eq!(
+            parse_dispatch_request(unsafe { dispatch_request_slice(std::ptr::null(), 0) }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_encode_message() {
+        let msg = allo_isolate::IntoDart::into_dart(vec![1u8, 2]);
+        let data = encode_message(-1, &msg).unwrap();
+        assert_eq!(&data[..8], &(-1i64).to_le_bytes());
+    }
+}
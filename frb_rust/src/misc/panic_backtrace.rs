@@ -2,11 +2,14 @@ use std::any::Any;
 use std::backtrace::Backtrace;
 use std::cell::RefCell;
 use std::panic::UnwindSafe;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 thread_local! {
     static BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
 }
 
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
 /// Utility for tracking panic backtrace.
 ///
 /// This is originally used internally, and only exposed because it is needed outside flutter_rust_bridge.
@@ -17,13 +20,21 @@ impl PanicBacktrace {
     pub fn setup() {
         let old_hook = std::panic::take_hook();
         std::panic::set_hook(Box::new(move |arg| {
-            let trace = Backtrace::capture();
-            BACKTRACE.with(move |b| b.borrow_mut().replace(trace));
+            if ENABLED.load(Ordering::Relaxed) {
+                let trace = Backtrace::capture();
+                BACKTRACE.with(move |b| b.borrow_mut().replace(trace));
+            }
 
             old_hook(arg);
         }));
     }
 
+    /// When disabled, e.g. by the code generated with `minimal_output`,
+    /// panics do not capture backtraces even if [`Self::setup`] is called.
+    pub fn set_enabled(enabled: bool) {
+        ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
     pub fn catch_unwind<F: FnOnce() -> R + UnwindSafe, R>(
         f: F,
     ) -> Result<R, CatchUnwindWithBacktrace> {
@@ -0,0 +1,251 @@
+//! Re-drives a session recorded by the Dart `BridgeRecorder` against the Rust code,
+//! e.g. to reproduce a bug captured in production, or as a regression test.
+//!
+//! The requests are dispatched in the same way as the method channel transport does,
+//! thus the responses are posted back to us instead of to Dart.
+
+use crate::method_channel::{replace_post_callback, MethodChannelPostCallback};
+use crate::platform_types::MessagePort;
+use crate::rust2dart::chunked::ChunkedTransferConfig;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The `frb_method_channel_dispatch` function in the generated `frb_generated.rs`.
+pub type ReplayDispatch = unsafe extern "C" fn(data: *const u8, len: usize) -> bool;
+
+/// A recorded call whose response is different when replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayMismatch {
+    /// The line of the call in the recording, starting from zero
+    pub index: usize,
+    pub name: String,
+    pub expected: Vec<u8>,
+    /// `None` if there is no response within the timeout
+    pub actual: Option<Vec<u8>>,
+}
+
+static RESPONSE_SENDER: Mutex<Option<Sender<Vec<u8>>>> = Mutex::new(None);
+/// Held while a replay changes the global state, i.e. the post callback and the chunked transfer config
+pub(crate) static REPLAY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Replays the calls of `recording` one by one via `dispatch` (usually
+/// `crate::frb_generated::frb_method_channel_dispatch`), and returns the ones whose
+/// response differs from the recorded one.
+///
+/// Responses containing e.g. pointers of opaque objects naturally differ across runs.
+///
+/// # Panics
+///
+/// If the recording is malformed.
+pub fn replay_recording(
+    recording: &str,
+    dispatch: ReplayDispatch,
+    timeout: Duration,
+) -> Vec<ReplayMismatch> {
+    // The post callback is global, thus replays cannot run concurrently
+    let _lock = REPLAY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let (sender, receiver) = channel();
+    *RESPONSE_SENDER.lock().unwrap() = Some(sender);
+    // Restored when dropped, even if the replay panics
+    let _state = ReplayStateGuard {
+        previous_post_callback: replace_post_callback(Some(on_post)),
+        previous_chunked_config: ChunkedTransferConfig::get(),
+    };
+    // Dart reassembles the chunks before they are recorded
+    ChunkedTransferConfig::DISABLED.set();
+
+    (recording.lines().enumerate())
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| {
+            let call = RecordedCall::parse(line)
+                .unwrap_or_else(|| panic!("Malformed recording at line {index}"));
+            let port = -(index as MessagePort) - 1;
+            let request = [
+                &call.func_id.to_le_bytes()[..],
+                &port.to_le_bytes(),
+                &call.request,
+            ]
+            .concat();
+            let dispatched = unsafe { dispatch(request.as_ptr(), request.len()) };
+            assert!(dispatched, "Malformed request at line {index}");
+
+            let actual = receive_response(&receiver, port, timeout);
+            (actual.as_ref() != Some(&call.response)).then_some(ReplayMismatch {
+                index,
+                name: call.name,
+                expected: call.response,
+                actual,
+            })
+        })
+        .collect()
+}
+
+struct ReplayStateGuard {
+    previous_post_callback: Option<MethodChannelPostCallback>,
+    previous_chunked_config: ChunkedTransferConfig,
+}
+
+impl Drop for ReplayStateGuard {
+    fn drop(&mut self) {
+        replace_post_callback(self.previous_post_callback);
+        self.previous_chunked_config.set();
+        *RESPONSE_SENDER
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+    }
+}
+
+unsafe extern "C" fn on_post(data: *const u8, len: usize) {
+    let data = std::slice::from_raw_parts(data, len).to_vec();
+    if let Some(sender) = RESPONSE_SENDER.lock().unwrap().as_ref() {
+        let _ = sender.send(data);
+    }
+}
+
+fn receive_response(
+    receiver: &Receiver<Vec<u8>>,
+    port: MessagePort,
+    timeout: Duration,
+) -> Option<Vec<u8>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let data = receiver
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            .ok()?;
+        // Messages to other ports (e.g. of streams) are not recorded
+        if data[..8] == port.to_le_bytes() {
+            return Some(data[8..].to_vec());
+        }
+    }
+}
+
+/// NOTE: Please keep in sync with the Dart side
+#[derive(Debug, PartialEq, Eq)]
+struct RecordedCall {
+    func_id: i32,
+    name: String,
+    request: Vec<u8>,
+    response: Vec<u8>,
+}
+
+impl RecordedCall {
+    fn parse(line: &str) -> Option<Self> {
+        let mut parts = line.split('\t');
+        let ans = Self {
+            func_id: parts.next()?.parse().ok()?,
+            name: parts.next()?.to_owned(),
+            request: decode_hex(parts.next()?)?,
+            response: decode_hex(parts.next()?)?,
+        };
+        parts.next().is_none().then_some(ans)
+    }
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generalized_isolate::IntoDart;
+    use crate::method_channel::{
+        dispatch_request_slice, encode_message, parse_dispatch_request, post, replace_post_callback,
+    };
+    use crate::replay::{replay_recording, RecordedCall, ReplayMismatch, REPLAY_LOCK};
+    use crate::rust2dart::chunked::ChunkedTransferConfig;
+    use std::sync::PoisonError;
+    use std::time::Duration;
+
+    /// Responds with `func_id + payload length`
+    unsafe extern "C" fn fake_dispatch(data: *const u8, len: usize) -> bool {
+        let Some((func_id, port, payload)) =
+            parse_dispatch_request(dispatch_request_slice(data, len))
+        else {
+            return false;
+        };
+        post(port, func_id + payload.len() as i32)
+    }
+
+    unsafe extern "C" fn fake_post_callback(_data: *const u8, _len: usize) {}
+
+    fn encode_response(value: i32) -> Vec<u8> {
+        encode_message(0, &value.into_dart()).unwrap()[8..].to_vec()
+    }
+
+    fn encode_hex(data: &[u8]) -> String {
+        data.iter().map(|x| format!("{x:02x}")).collect()
+    }
+
+    #[test]
+    fn test_replay_recording() {
+        let recording = format!(
+            "7\tf\tff00\t{}\n\n3\tg\t\t{}\n",
+            encode_hex(&encode_response(9)),
+            encode_hex(&encode_response(100)),
+        );
+        assert_eq!(
+            replay_recording(&recording, fake_dispatch, Duration::from_secs(5)),
+            vec![ReplayMismatch {
+                index: 2,
+                name: "g".to_owned(),
+                expected: encode_response(100),
+                actual: Some(encode_response(3)),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_replay_recording_restores_state() {
+        let config = ChunkedTransferConfig {
+            threshold: 100,
+            chunk_size: 10,
+        };
+        let assert_restored = || {
+            let _lock = REPLAY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+            assert_eq!(ChunkedTransferConfig::get(), config);
+            let callback = replace_post_callback(Some(fake_post_callback));
+            assert_eq!(
+                callback.map(|x| x as *const ()),
+                Some(fake_post_callback as *const ())
+            );
+        };
+
+        {
+            let _lock = REPLAY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+            replace_post_callback(Some(fake_post_callback));
+            config.set();
+        }
+
+        replay_recording("", fake_dispatch, Duration::from_secs(5));
+        assert_restored();
+
+        let result = std::panic::catch_unwind(|| {
+            replay_recording("malformed", fake_dispatch, Duration::from_secs(5))
+        });
+        assert!(result.is_err());
+        assert_restored();
+
+        let _lock = REPLAY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        replace_post_callback(None);
+        ChunkedTransferConfig::default().set();
+    }
+
+    #[test]
+    fn test_parse_recorded_call() {
+        assert_eq!(
+            RecordedCall::parse("7\tf\tff00\t0601000000aa"),
+            Some(RecordedCall {
+                func_id: 7,
+                name: "f".to_owned(),
+                request: vec![0xff, 0],
+                response: vec![6, 1, 0, 0, 0, 0xaa],
+            })
+        );
+        assert_eq!(RecordedCall::parse("7\tf\tf"), None);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::generalized_isolate::IntoDart;
+use crate::rust2dart::action::Rust2DartAction;
+use crate::rust2dart::sender::Rust2DartSender;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(ChunkedTransferConfig::DEFAULT.threshold);
+static CHUNK_SIZE: AtomicUsize = AtomicUsize::new(ChunkedTransferConfig::DEFAULT.chunk_size);
+
+/// Controls how large return values of Rust functions are transferred to Dart.
+///
+/// A message whose size exceeds `threshold` bytes is posted as a sequence of chunks,
+/// each at most `chunk_size` bytes, instead of as one single message.
+/// Dart reassembles them transparently, and reports the progress to `withTransferProgress`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkedTransferConfig {
+    pub threshold: usize,
+    pub chunk_size: usize,
+}
+
+impl ChunkedTransferConfig {
+    pub const DEFAULT: Self = Self {
+        threshold: 16 * 1024 * 1024,
+        chunk_size: 1024 * 1024,
+    };
+
+    /// Never split messages into chunks.
+    pub const DISABLED: Self = Self {
+        threshold: usize::MAX,
+        chunk_size: usize::MAX,
+    };
+
+    pub fn get() -> Self {
+        Self {
+            threshold: THRESHOLD.load(Ordering::Relaxed),
+            chunk_size: CHUNK_SIZE.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn set(self) {
+        assert!(self.chunk_size > 0, "chunk_size must be positive");
+        THRESHOLD.store(self.threshold, Ordering::Relaxed);
+        CHUNK_SIZE.store(self.chunk_size, Ordering::Relaxed);
+    }
+}
+
+impl Default for ChunkedTransferConfig {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+pub(crate) fn send_bytes_or_warn(sender: &Rust2DartSender, bytes: Vec<u8>) {
+    let config = ChunkedTransferConfig::get();
+    if bytes.len() <= config.threshold {
+        sender.send_or_warn(bytes);
+        return;
+    }
+
+    // NOTE: Please keep in sync with the Dart side
+    sender.send_or_warn(vec![
+        Rust2DartAction::ChunkedTransferStart.into_dart(),
+        bytes.len().into_dart(),
+    ]);
+    for chunk in bytes.chunks(config.chunk_size) {
+        sender.send_or_warn(chunk.to_vec());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::replay::REPLAY_LOCK;
+    use crate::rust2dart::chunked::ChunkedTransferConfig;
+    use std::sync::PoisonError;
+
+    #[test]
+    fn test_config_set_and_get() {
+        let _lock = REPLAY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let config = ChunkedTransferConfig {
+            threshold: 100,
+            chunk_size: 10,
+        };
+        config.set();
+        assert_eq!(ChunkedTransferConfig::get(), config);
+        ChunkedTransferConfig::default().set();
+        assert_eq!(ChunkedTransferConfig::get(), ChunkedTransferConfig::DEFAULT);
+    }
+}
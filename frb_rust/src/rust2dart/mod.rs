@@ -1,2 +1,3 @@
 pub(crate) mod action;
+pub(crate) mod chunked;
 pub(crate) mod sender;
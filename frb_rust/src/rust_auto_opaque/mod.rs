@@ -8,6 +8,7 @@ pub(crate) mod dart2rust_implicit;
 pub(crate) mod inner;
 pub(crate) mod rust2dart_common;
 pub(crate) mod rust2dart_explicit;
+pub(crate) mod scope;
 pub(crate) mod utils;
 
 #[derive(Debug)]
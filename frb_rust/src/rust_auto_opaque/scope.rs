@@ -0,0 +1,131 @@
+//! Locks held across multiple calls, usually created via `#[frb(lock_scope)]`.
+
+use crate::generalized_arc::base_arc::BaseArc;
+use crate::rust_async::{RwLockReadGuard, RwLockWriteGuard};
+use crate::rust_auto_opaque::inner::RustAutoOpaqueInner;
+use crate::rust_auto_opaque::RustAutoOpaqueBase;
+use std::ops::{Deref, DerefMut};
+
+/// Holds the read lock of a `RustAutoOpaque` until dropped.
+pub struct RustAutoOpaqueReadScope<T: 'static> {
+    // Declared before `_owner`, thus dropped before the data it borrows
+    guard: RwLockReadGuard<'static, T>,
+    _owner: Box<dyn Send + Sync>,
+}
+
+/// Holds the write lock of a `RustAutoOpaque` until dropped.
+pub struct RustAutoOpaqueWriteScope<T: 'static> {
+    // Declared before `_owner`, thus dropped before the data it borrows
+    guard: RwLockWriteGuard<'static, T>,
+    _owner: Box<dyn Send + Sync>,
+}
+
+macro_rules! impl_scope {
+    ($scope:ident, $guard:ident, $lock:ident, $try_lock:ident) => {
+        impl<T: Send + Sync + 'static> $scope<T> {
+            pub async fn lock<A>(owner: RustAutoOpaqueBase<T, A>) -> Self
+            where
+                A: BaseArc<RustAutoOpaqueInner<T>> + Send + Sync + 'static,
+            {
+                let guard = owner.$lock().await;
+                // SAFETY: The guard borrows the data inside the arc, which is kept alive by `_owner`
+                // and outlives the guard. Moving `owner` does not move the data.
+                let guard =
+                    unsafe { std::mem::transmute::<$guard<'_, T>, $guard<'static, T>>(guard) };
+                Self {
+                    guard,
+                    _owner: Box::new(owner),
+                }
+            }
+
+            /// Returns `None` if the lock is held by others.
+            pub fn try_lock<A>(owner: RustAutoOpaqueBase<T, A>) -> Option<Self>
+            where
+                A: BaseArc<RustAutoOpaqueInner<T>> + Send + Sync + 'static,
+            {
+                let guard = owner.$try_lock().ok()?;
+                // SAFETY: Same as `lock`
+                let guard =
+                    unsafe { std::mem::transmute::<$guard<'_, T>, $guard<'static, T>>(guard) };
+                Some(Self {
+                    guard,
+                    _owner: Box::new(owner),
+                })
+            }
+        }
+
+        impl<T> Deref for $scope<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.guard
+            }
+        }
+    };
+}
+
+impl_scope!(RustAutoOpaqueReadScope, RwLockReadGuard, read, try_read);
+impl_scope!(RustAutoOpaqueWriteScope, RwLockWriteGuard, write, try_write);
+
+impl<T> DerefMut for RustAutoOpaqueWriteScope<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rust_auto_opaque::scope::{RustAutoOpaqueReadScope, RustAutoOpaqueWriteScope};
+    use crate::RustAutoOpaqueNom;
+
+    #[test]
+    fn test_scope() {
+        let opaque = RustAutoOpaqueNom::new(42);
+
+        let mut write = RustAutoOpaqueWriteScope::try_lock(opaque.clone()).unwrap();
+        *write += 1;
+        assert!(opaque.try_read().is_err());
+        assert!(RustAutoOpaqueReadScope::try_lock(opaque.clone()).is_none());
+        drop(write);
+
+        let read_a = RustAutoOpaqueReadScope::try_lock(opaque.clone()).unwrap();
+        let read_b = RustAutoOpaqueReadScope::try_lock(opaque.clone()).unwrap();
+        assert_eq!((*read_a, *read_b), (43, 43));
+        assert!(RustAutoOpaqueWriteScope::try_lock(opaque.clone()).is_none());
+        drop((read_a, read_b));
+
+        // The scope keeps the data alive even if other handles are dropped
+        let write = RustAutoOpaqueWriteScope::try_lock(opaque).unwrap();
+        assert_eq!(*write, 43);
+    }
+
+    #[cfg(not(wasm))]
+    #[tokio::test]
+    async fn test_scope_waits_for_lock() {
+        let opaque = RustAutoOpaqueNom::new(42);
+
+        // Reads wait until the write scope is dropped, and see the written value
+        let mut write = RustAutoOpaqueWriteScope::lock(opaque.clone()).await;
+        let read = RustAutoOpaqueReadScope::lock(opaque.clone());
+        futures::pin_mut!(read);
+        assert!(futures::poll!(&mut read).is_pending());
+        *write += 1;
+        drop(write);
+        let read = read.await;
+        assert_eq!(*read, 43);
+
+        // Writes wait until all read scopes are dropped
+        let other_read = RustAutoOpaqueReadScope::lock(opaque.clone()).await;
+        let write = RustAutoOpaqueWriteScope::lock(opaque.clone());
+        futures::pin_mut!(write);
+        assert!(futures::poll!(&mut write).is_pending());
+        drop(read);
+        assert!(futures::poll!(&mut write).is_pending());
+        drop(other_read);
+        let mut write = write.await;
+        *write += 1;
+        drop(write);
+
+        assert_eq!(*opaque.read().await, 44);
+    }
+}
@@ -1,2 +1,3 @@
 mod closer;
+pub(crate) mod progress;
 pub(crate) mod stream_sink;
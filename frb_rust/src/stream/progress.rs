@@ -0,0 +1,33 @@
+use crate::generalized_isolate::IntoDart;
+use crate::platform_types::DartAbi;
+
+/// The progress of a long operation, usually sent to Dart via a `ProgressSink`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FrbProgress {
+    pub completed: u64,
+    /// `None` if the total amount of work is unknown.
+    pub total: Option<u64>,
+    pub message: Option<String>,
+}
+
+impl FrbProgress {
+    pub fn new(completed: u64, total: Option<u64>) -> Self {
+        Self {
+            completed,
+            total,
+            message: None,
+        }
+    }
+
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+// Same layout as the record that it delegates to
+impl IntoDart for FrbProgress {
+    fn into_dart(self) -> DartAbi {
+        (self.completed, self.total, self.message).into_dart()
+    }
+}
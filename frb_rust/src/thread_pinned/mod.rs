@@ -0,0 +1,115 @@
+//! Lets values that are not `Send` (e.g. wrappers of thread-affine C libraries) be used from
+//! any thread, by keeping each of them on a dedicated thread. Usually used via `#[frb(thread_pinned)]`.
+
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+
+type Job<T> = Box<dyn FnOnce(&mut T) + Send>;
+
+/// Owns a value living on its own thread, which is stopped (and the value dropped there)
+/// when this is dropped.
+pub struct ThreadPinned<T: 'static> {
+    // `Mutex` is only to make it `Sync` on older Rust versions
+    sender: Mutex<mpsc::Sender<Job<T>>>,
+}
+
+impl<T: 'static> ThreadPinned<T> {
+    /// Creates the value on a new thread.
+    pub fn new(create: impl FnOnce() -> T + Send + 'static) -> Self {
+        Self::try_new(move || Ok::<_, std::convert::Infallible>(create()))
+            .unwrap_or_else(|e| match e {})
+    }
+
+    /// Similar to [`ThreadPinned::new`], except that creating the value may fail.
+    pub fn try_new<E: Send + 'static>(
+        create: impl FnOnce() -> Result<T, E> + Send + 'static,
+    ) -> Result<Self, E> {
+        let (sender, receiver) = mpsc::channel::<Job<T>>();
+        let (created_sender, created_receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name("frb_thread_pinned".to_owned())
+            .spawn(move || {
+                let mut value = match catch_unwind(AssertUnwindSafe(create)) {
+                    Ok(Ok(value)) => {
+                        created_sender.send(Ok(Ok(()))).unwrap();
+                        value
+                    }
+                    Ok(Err(e)) => return created_sender.send(Ok(Err(e))).unwrap(),
+                    Err(panic) => return created_sender.send(Err(panic)).unwrap(),
+                };
+                // Finishes when all senders are dropped
+                for job in receiver {
+                    job(&mut value);
+                }
+            })
+            .expect("Failed to spawn thread for ThreadPinned");
+        (created_receiver.recv().unwrap()).unwrap_or_else(|panic| resume_unwind(panic))?;
+        Ok(Self {
+            sender: Mutex::new(sender),
+        })
+    }
+
+    /// Runs `f` on the dedicated thread, and blocks until it finishes.
+    ///
+    /// If `f` panics, the panic is resumed on the calling thread (thus reported to Dart as usual),
+    /// while the dedicated thread keeps serving later calls.
+    pub fn run<R: Send + 'static>(&self, f: impl FnOnce(&mut T) -> R + Send + 'static) -> R {
+        let (output_sender, output_receiver) = mpsc::channel();
+        (self.sender.lock().unwrap())
+            .send(Box::new(move |value| {
+                let _ = output_sender.send(catch_unwind(AssertUnwindSafe(|| f(value))));
+            }))
+            .expect("ThreadPinned thread has stopped");
+        (output_receiver.recv().unwrap()).unwrap_or_else(|panic| resume_unwind(panic))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::thread_pinned::ThreadPinned;
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+    use std::rc::Rc;
+    use std::thread;
+
+    #[test]
+    fn test_run_on_same_thread() {
+        let pinned = ThreadPinned::new(|| (Rc::new(10), thread::current().id()));
+        let (value, creator_thread) =
+            pinned.run(|(value, creator)| (**value, *creator == thread::current().id()));
+        assert_eq!((value, creator_thread), (10, true));
+
+        let pinned = std::sync::Arc::new(pinned);
+        let pinned_clone = pinned.clone();
+        thread::spawn(move || pinned_clone.run(|(value, _)| *value = Rc::new(20)))
+            .join()
+            .unwrap();
+        assert_eq!(pinned.run(|(value, _)| **value), 20);
+    }
+
+    #[test]
+    fn test_try_new_error() {
+        let result = ThreadPinned::<Rc<i32>>::try_new(|| Err("failed"));
+        assert_eq!(result.err(), Some("failed"));
+    }
+
+    #[test]
+    fn test_run_panic() {
+        let pinned = ThreadPinned::new(|| Rc::new(10));
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            pinned.run(|_| -> i32 { panic!("job failed") })
+        }));
+        let panic = result.unwrap_err();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"job failed"));
+
+        // The thread survives the panic
+        assert_eq!(pinned.run(|value| **value), 10);
+    }
+
+    #[test]
+    fn test_new_panic() {
+        let result = catch_unwind(|| ThreadPinned::<Rc<i32>>::new(|| panic!("create failed")));
+        let panic = result.err().unwrap();
+        assert_eq!(panic.downcast_ref::<&str>(), Some(&"create failed"));
+    }
+}
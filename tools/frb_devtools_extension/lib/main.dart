@@ -0,0 +1,179 @@
+import 'dart:async';
+
+import 'package:devtools_extensions/devtools_extensions.dart';
+import 'package:flutter/material.dart';
+
+void main() => runApp(const DevToolsExtension(child: InspectorPage()));
+
+class InspectorPage extends StatefulWidget {
+  const InspectorPage({super.key});
+
+  @override
+  State<InspectorPage> createState() => _InspectorPageState();
+}
+
+class _InspectorPageState extends State<InspectorPage> {
+  static const _kPollInterval = Duration(seconds: 1);
+
+  Timer? _timer;
+  Map<String, dynamic>? _snapshot;
+  String? _error;
+
+  @override
+  void initState() {
+    super.initState();
+    _timer = Timer.periodic(_kPollInterval, (_) => _refresh());
+    unawaited(_refresh());
+  }
+
+  @override
+  void dispose() {
+    _timer?.cancel();
+    super.dispose();
+  }
+
+  Future<void> _refresh() async {
+    try {
+      final response = await serviceManager.callServiceExtensionOnMainIsolate(
+          'ext.flutter_rust_bridge.getSnapshot');
+      if (!mounted) return;
+      setState(() {
+        _snapshot = response.json;
+        _error = null;
+      });
+    } catch (e) {
+      if (!mounted) return;
+      setState(() => _error = 'Cannot read the bridge data ($e). '
+          'Is BridgeInspector.enable() called in a debug or profile build?');
+    }
+  }
+
+  Future<void> _clearCalls() async {
+    await serviceManager.callServiceExtensionOnMainIsolate(
+        'ext.flutter_rust_bridge.clearCalls');
+    await _refresh();
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    final snapshot = _snapshot;
+    if (_error != null) return Center(child: Text(_error!));
+    if (snapshot == null) {
+      return const Center(child: CircularProgressIndicator());
+    }
+
+    final calls = (snapshot['calls'] as List).reversed.toList();
+    final streams = snapshot['streams'] as List;
+    final opaqueHandles = snapshot['opaqueHandles'] as List;
+
+    return DefaultTabController(
+      length: 3,
+      child: Column(
+        children: [
+          Row(
+            children: [
+              Expanded(
+                child: TabBar(tabs: [
+                  Tab(text: 'Calls (${calls.length})'),
+                  Tab(text: 'Live streams (${streams.length})'),
+                  Tab(text: 'Opaque handles (${opaqueHandles.length})'),
+                ]),
+              ),
+              IconButton(
+                tooltip: 'Clear calls',
+                icon: const Icon(Icons.delete_outline),
+                onPressed: _clearCalls,
+              ),
+            ],
+          ),
+          Expanded(
+            child: TabBarView(children: [
+              _Table(
+                columns: const [
+                  'Function',
+                  'Sync',
+                  'Started',
+                  'Duration',
+                  'Arguments',
+                  'Result',
+                  'Error',
+                ],
+                rows: [
+                  for (final call in calls)
+                    [
+                      call['name'],
+                      call['isSync'] ? 'yes' : '',
+                      call['startTime'],
+                      _formatDuration(call['durationMicros']),
+                      _formatBytes(call['argBytes']),
+                      _formatBytes(call['resultBytes']),
+                      call['error'] ?? '',
+                    ],
+                ],
+              ),
+              _Table(
+                columns: const ['Created by', 'Started', 'Items', 'Received'],
+                rows: [
+                  for (final stream in streams)
+                    [
+                      stream['name'] ?? '(unknown)',
+                      stream['startTime'],
+                      '${stream['itemCount']}',
+                      _formatBytes(stream['itemBytes']),
+                    ],
+                ],
+              ),
+              _Table(
+                columns: const ['Type', 'Open handles'],
+                rows: [
+                  for (final handle in opaqueHandles)
+                    [handle['type'], '${handle['count']}'],
+                ],
+              ),
+            ]),
+          ),
+        ],
+      ),
+    );
+  }
+}
+
+class _Table extends StatelessWidget {
+  final List<String> columns;
+  final List<List<dynamic>> rows;
+
+  const _Table({required this.columns, required this.rows});
+
+  @override
+  Widget build(BuildContext context) {
+    return SingleChildScrollView(
+      child: SingleChildScrollView(
+        scrollDirection: Axis.horizontal,
+        child: DataTable(
+          columns: [
+            for (final column in columns) DataColumn(label: Text(column)),
+          ],
+          rows: [
+            for (final row in rows)
+              DataRow(cells: [
+                for (final cell in row) DataCell(Text('$cell')),
+              ]),
+          ],
+        ),
+      ),
+    );
+  }
+}
+
+String _formatDuration(int? micros) {
+  if (micros == null) return 'pending';
+  if (micros < 1000) return '$micros µs';
+  return '${(micros / 1000).toStringAsFixed(1)} ms';
+}
+
+String _formatBytes(int? bytes) {
+  if (bytes == null) return '';
+  if (bytes < 1024) return '$bytes B';
+  if (bytes < 1024 * 1024) return '${(bytes / 1024).toStringAsFixed(1)} KB';
+  return '${(bytes / 1024 / 1024).toStringAsFixed(1)} MB';
+}
@@ -20,6 +20,7 @@ const kRustPackagesAllowWeb = [
 
 const kRustPackagesDisallowWeb = [
   'frb_codegen',
+  'frb_codegen_plugin',
   'frb_macros',
 ];
 
@@ -56,6 +57,7 @@ const kDartNonExamplePackages = [
 const kDartPackages = [
   ...kDartNonExamplePackages,
   ...kDartExamplePackages,
+  'tools/frb_devtools_extension',
 ];
 
 enum DartMode { dart, flutter }
@@ -64,6 +66,7 @@ const kDartModeOfPackage = {
   'frb_dart': DartMode.dart,
   'frb_utils': DartMode.dart,
   'tools/frb_internal': DartMode.dart,
+  'tools/frb_devtools_extension': DartMode.flutter,
   'frb_example/dart_minimal': DartMode.dart,
   'frb_example/pure_dart': DartMode.dart,
   'frb_example/pure_dart_pde': DartMode.dart,
@@ -170,6 +170,9 @@ Future<void> generateInternalBookHelp(GenerateConfig config) async {
       ('create', ''),
       ('integrate', ''),
       ('build-web', '--dart-root ${exec.pwd}frb_example/pure_dart'),
+      ('test', ''),
+      ('docs', ''),
+      ('sbom', ''),
     ]) {
       final resp = await executeFrbCodegen(
         '$cmd $extraArgs --help',
@@ -56,13 +56,18 @@ Future<void> releaseUpdateVersion() async {
     '${exec.pwd}Cargo.toml',
     ', version = "=${versionInfo.oldVersion}" }\n',
     ', version = "=${versionInfo.newVersion}" }\n',
-    expectReplaceCount: 3,
+    expectReplaceCount: 4,
   );
   simpleReplaceFile(
     '${exec.pwd}frb_dart/pubspec.yaml',
     '\nversion: ${versionInfo.oldVersion}\n',
     '\nversion: ${versionInfo.newVersion}\n',
   );
+  simpleReplaceFile(
+    '${exec.pwd}frb_dart/extension/devtools/config.yaml',
+    '\nversion: ${versionInfo.oldVersion}\n',
+    '\nversion: ${versionInfo.newVersion}\n',
+  );
 }
 
 Future<void> releaseUpdateCode() async {
@@ -158,9 +163,12 @@ Future<void> releaseUpdateGithub() async {
 }
 
 Future<void> releasePublishAll() async {
+  await exec('cd frb_codegen_plugin && cargo publish');
   await exec('cd frb_codegen && cargo publish');
   await exec('cd frb_macros && cargo publish');
   await exec('cd frb_rust && cargo publish');
+  await exec('cd tools/frb_devtools_extension && flutter pub get && '
+      'dart run devtools_extensions build_and_copy --source=. --dest=../../frb_dart/extension/devtools');
   await exec(
       'cd frb_dart && flutter pub publish --force --server=https://pub.dartlang.org');
 }
@@ -70,6 +70,7 @@ const sidebars = {
                                         'guides/types/translatable/detailed/tuple',
                                         'guides/types/translatable/detailed/option',
                                         'guides/types/translatable/detailed/alias',
+                                        'guides/types/translatable/detailed/generics',
                                         'guides/types/translatable/detailed/map_set',
                                         'guides/types/translatable/detailed/chrono',
                                         'guides/types/translatable/detailed/uuid',
@@ -79,6 +80,8 @@ const sidebars = {
                                 'guides/types/translatable/return',
                                 'guides/types/translatable/stream',
                                 'guides/types/translatable/custom',
+                                'guides/types/translatable/message-codec',
+                                'guides/types/translatable/patch',
                                 'guides/types/translatable/zero-copy',
                                 {
                                     type: 'category',
@@ -119,6 +122,7 @@ const sidebars = {
                                         'guides/types/arbitrary/rust-auto-opaque/properties',
                                         'guides/types/arbitrary/rust-auto-opaque/opaque-in-translatable',
                                         'guides/types/arbitrary/rust-auto-opaque/ownership',
+                                        'guides/types/arbitrary/rust-auto-opaque/lock-scope',
                                         'guides/types/arbitrary/rust-auto-opaque/dispose',
                                         'guides/types/arbitrary/rust-auto-opaque/struct',
                                     ],
@@ -141,6 +145,7 @@ const sidebars = {
                     items: [
                         'guides/direction/dart-call-rust',
                         'guides/direction/rust-call-dart',
+                        'guides/direction/dart-services',
                     ],
                 },
                 {
@@ -250,6 +255,10 @@ const sidebars = {
                         'guides/concurrency/async-rust',
                         'guides/concurrency/sync-dart',
                         'guides/concurrency/async-dart',
+                        'guides/concurrency/background-task',
+                        'guides/concurrency/thread-pinned',
+                        'guides/concurrency/actor',
+                        'guides/concurrency/rust-scope',
                     ],
                 },
                 {
@@ -272,6 +281,8 @@ const sidebars = {
                             items: [
                                 'guides/custom/codegen/inputs',
                                 'guides/custom/codegen/full-list',
+                                'guides/custom/codegen/plugin',
+                                'guides/custom/codegen/message-format',
                             ],
                         },
                         {
@@ -328,6 +339,7 @@ const sidebars = {
                         'guides/cross-platform/async',
                         'guides/cross-platform/thread-pool',
                         'guides/cross-platform/isolate',
+                        'guides/cross-platform/method-channel',
                     ],
                 },
                 {
@@ -346,6 +358,8 @@ const sidebars = {
                         'guides/misc-features/multi-input',
                         'guides/misc-features/modules',
                         'guides/misc-features/dart-type-rename',
+                        'guides/misc-features/cbindgen-ffigen-config',
+                        'guides/misc-features/shared-crates',
                         'guides/misc-features/ignoring',
                         'guides/misc-features/override-prefix',
                         'guides/misc-features/build-rs',
@@ -396,6 +410,7 @@ const sidebars = {
                     items: [
                         'guides/how-to/stack-trace',
                         'guides/how-to/debug',
+                        'guides/how-to/record-replay',
                         'guides/how-to/logging',
                         'guides/how-to/report-error',
                         'guides/how-to/test',
@@ -412,6 +427,13 @@ const sidebars = {
                         'guides/how-to/rust-compilation',
                         'guides/how-to/cargo-workspaces',
                         'guides/how-to/cross-origin',
+                        'guides/how-to/api-reference',
+                        'guides/how-to/sbom',
+                        'guides/how-to/dump-ir',
+                        'guides/how-to/check-compat',
+                        'guides/how-to/doctor',
+                        'guides/how-to/api-versions',
+                        'guides/how-to/feature-sets',
                     ],
                 },
                 'guides/users',
@@ -425,6 +447,10 @@ const sidebars = {
                     },
                     items: [
                         'guides/performance/overview',
+                        'guides/performance/chunked-transfer',
+                        'guides/performance/compression',
+                        'guides/performance/lazy-decoding',
+                        'guides/performance/minimal-output',
                         {
                             type: 'link',
                             label: 'Dashboard',